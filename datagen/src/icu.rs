@@ -0,0 +1,575 @@
+use crate::tables::{Keyword, KeywordType, Tables, KEYWORD_TABLES};
+use crate::Error;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use unicode_locale_parser::DataTable;
+
+const MISC: &str = "misc";
+const METADATA: &str = "metadata.txt";
+const SUPPLEMENTAL_DATA: &str = "supplementalData.txt";
+const LIKELY_SUBTAGS: &str = "likelySubtags.txt";
+const KEY_TYPE_DATA: &str = "keyTypeData.txt";
+// the types following the keys, e.g. `table` of `supplementalData:table(nofallback)`
+const RESOURCE_TYPES: [&str; 12] = [
+  "alias",
+  "array",
+  "bin",
+  "binary",
+  "import",
+  "include",
+  "int",
+  "integer",
+  "intvector",
+  "process",
+  "string",
+  "table",
+];
+
+/// A resource of the ICU resource bundle source, i.e. a table, an array or a string.
+///
+/// This is enough for the files of ICU generated from CLDR, whose integers and binaries are read as the strings.
+#[derive(Debug, PartialEq)]
+pub enum Resource {
+  Table(Vec<(String, Resource)>),
+  Array(Vec<Resource>),
+  String(String),
+}
+
+impl Resource {
+  /// Find the resource of the keys in the nested tables.
+  pub fn get(&self, pointer: &[&str]) -> Option<&Resource> {
+    pointer.iter().try_fold(self, |resource, key| {
+      resource
+        .entries()
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value)
+    })
+  }
+
+  /// The entries of the table, or none if the resource is not a table.
+  pub fn entries(&self) -> &[(String, Resource)] {
+    match self {
+      Resource::Table(entries) => entries,
+      _ => &[],
+    }
+  }
+
+  pub fn string(&self) -> Option<&str> {
+    match self {
+      Resource::String(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  /// The strings of the resource, in the nested arrays too. A string is an array of one string.
+  pub fn strings(&self) -> Vec<&str> {
+    match self {
+      Resource::Table(_) => vec![],
+      Resource::Array(values) => values.iter().flat_map(Resource::strings).collect(),
+      Resource::String(value) => vec![value],
+    }
+  }
+}
+
+/// Find the `misc` directory of the ICU data (`icu4c/source/data/misc`), or the directory itself.
+pub fn find(dir: &Path) -> Option<PathBuf> {
+  [dir.join(MISC), dir.to_path_buf()]
+    .into_iter()
+    .find(|dir| dir.join(METADATA).exists())
+}
+
+/// Read the tables from the `misc` directory of the ICU data, which is generated from CLDR.
+///
+/// The version of CLDR is the `cldrVersion` of `supplementalData.txt`.
+pub fn read(misc: &Path) -> Result<Tables, Error> {
+  let mut tables = Tables::default();
+  let path = misc.join(METADATA);
+  let metadata = load(&path)?;
+  let aliases = metadata
+    .get(&["alias"])
+    .ok_or_else(|| Error::Format(path.clone(), "no `alias`".to_string()))?;
+  for (name, table) in [
+    ("language", DataTable::LanguageAliases),
+    ("script", DataTable::ScriptAliases),
+    ("territory", DataTable::RegionAliases),
+    ("variant", DataTable::VariantAliases),
+    ("subdivision", DataTable::SubdivisionAliases),
+  ] {
+    tables.touch(table);
+    for (rule, alias) in aliases
+      .get(&[name])
+      .map(Resource::entries)
+      .unwrap_or_default()
+    {
+      if let Some(replacement) = alias.get(&["replacement"]).and_then(Resource::string) {
+        tables.insert_alias(table, rule, replacement);
+      }
+    }
+  }
+
+  let path = misc.join(LIKELY_SUBTAGS);
+  if path.exists() {
+    tables.touch(DataTable::LikelySubtags);
+    for (from, to) in load(&path)?.entries() {
+      if let Some(to) = to.string() {
+        tables.insert_likely_subtags(from, to);
+      }
+    }
+  }
+
+  let path = misc.join(SUPPLEMENTAL_DATA);
+  if path.exists() {
+    read_supplemental_data(&mut tables, &load(&path)?);
+  }
+
+  let path = misc.join(KEY_TYPE_DATA);
+  if path.exists() {
+    KEYWORD_TABLES
+      .into_iter()
+      .for_each(|table| tables.touch(table));
+    for keyword in keywords(&load(&path)?) {
+      tables.insert_keyword(&keyword);
+    }
+  }
+  Ok(tables)
+}
+
+fn read_supplemental_data(tables: &mut Tables, data: &Resource) {
+  if let Some(version) = data.get(&["cldrVersion"]).and_then(Resource::string) {
+    tables.set_version(version);
+  }
+
+  if let Some(parent_locales) = data.get(&["parentLocales"]) {
+    tables.touch(DataTable::ParentLocales);
+    for (parent, locales) in parent_locales.entries() {
+      tables.insert_parent_locales(parent, &locales.strings().join(" "));
+    }
+  }
+
+  if let Some(containment) = data.get(&["territoryContainment"]) {
+    tables.touch(DataTable::RegionContainment);
+    for (region, contains) in containment.entries() {
+      // the groupings and the deprecated containments are in the tables, e.g. `grouping`
+      if let Resource::Table(_) = contains {
+        continue;
+      }
+      tables.insert_containment(region, contains.strings().into_iter());
+    }
+  }
+
+  for (name, ids) in data
+    .get(&["idValidity"])
+    .map(Resource::entries)
+    .unwrap_or_default()
+  {
+    let table = match name.as_str() {
+      "language" => DataTable::Languages,
+      "script" => DataTable::Scripts,
+      "region" => DataTable::Regions,
+      "variant" => DataTable::Variants,
+      "subdivision" => DataTable::Subdivisions,
+      "unit" => DataTable::Units,
+      // the currencies are read from the BCP 47 data, as the `cu` key
+      _ => continue,
+    };
+    for (status, codes) in ids.entries() {
+      tables.insert_validity(table, status, &codes.strings().join(" "));
+    }
+  }
+}
+
+/// Read the keys of the BCP 47 data from `keyTypeData.txt`, whose keys and types are mapped from the legacy names, e.g. `calendar` to `ca`.
+fn keywords(data: &Resource) -> Vec<Keyword> {
+  let is_true = |resource: Option<&Resource>| resource.and_then(Resource::string) == Some("true");
+  let mut keywords = vec![];
+  for (name, key) in data
+    .get(&["keyMap"])
+    .map(Resource::entries)
+    .unwrap_or_default()
+  {
+    // the empty mappings are the same names
+    let key = key.string().filter(|key| !key.is_empty()).unwrap_or(name);
+    let type_map = data.get(&["typeMap", name]);
+    let bcp_type = |legacy: &str| {
+      // the time zones are separated by `:` in the keys of the tables
+      type_map
+        .and_then(|types| types.get(&[&legacy.replace('/', ":")]))
+        .and_then(Resource::string)
+        .filter(|bcp_type| !bcp_type.is_empty())
+        .unwrap_or(legacy)
+        .to_string()
+    };
+
+    let mut types = BTreeMap::new();
+    for (legacy, _) in type_map.map(Resource::entries).unwrap_or_default() {
+      let bcp_type = bcp_type(legacy);
+      let aliases = &mut keyword_type(&mut types, &bcp_type).aliases;
+      if *legacy != bcp_type {
+        aliases.push(legacy.clone());
+      }
+    }
+    for (alias, legacy) in data
+      .get(&["typeAlias", name])
+      .map(Resource::entries)
+      .unwrap_or_default()
+    {
+      if let Some(legacy) = legacy.string() {
+        let bcp_type = bcp_type(legacy);
+        keyword_type(&mut types, &bcp_type)
+          .aliases
+          .push(alias.clone());
+      }
+    }
+    for (deprecated, preferred) in data
+      .get(&["bcpTypeAlias", key])
+      .map(Resource::entries)
+      .unwrap_or_default()
+    {
+      let t = keyword_type(&mut types, deprecated);
+      t.deprecated = true;
+      t.preferred = preferred.string().map(str::to_string);
+    }
+    for (deprecated, value) in data
+      .get(&["typeInfo", "deprecated", key])
+      .map(Resource::entries)
+      .unwrap_or_default()
+    {
+      keyword_type(&mut types, deprecated).deprecated = is_true(Some(value));
+    }
+
+    keywords.push(Keyword {
+      // the tkeys end with a digit, e.g. `m0`
+      extension: match key.as_bytes() {
+        [_, last] if last.is_ascii_digit() => "t",
+        _ => "u",
+      }
+      .to_string(),
+      key: key.to_string(),
+      deprecated: is_true(data.get(&["keyInfo", "deprecated", key])),
+      types: types.into_values().collect(),
+    });
+  }
+  keywords
+}
+
+fn keyword_type<'a>(
+  types: &'a mut BTreeMap<String, KeywordType>,
+  name: &str,
+) -> &'a mut KeywordType {
+  types
+    .entry(name.to_string())
+    .or_insert_with(|| KeywordType {
+      name: name.to_string(),
+      ..Default::default()
+    })
+}
+
+fn load(path: &Path) -> Result<Resource, Error> {
+  let source = fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+  parse(&source)
+    .map(|(_, resource)| resource)
+    .map_err(|message| Error::Format(path.to_path_buf(), message))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Open,
+  Close,
+  Comma,
+  /// A quoted string, unescaped.
+  Quoted(String),
+  /// An unquoted string, e.g. a key or an integer.
+  Word(String),
+}
+
+/// Parse the source of a resource bundle, into the name and the root resource.
+pub fn parse(source: &str) -> Result<(String, Resource), String> {
+  let tokens = tokenize(source)?;
+  let mut tokens = tokens.iter().peekable();
+  let name = match (tokens.next(), tokens.next()) {
+    (Some(Token::Word(name) | Token::Quoted(name)), Some(Token::Open)) => key(name),
+    _ => return Err("no root resource".to_string()),
+  };
+  let resource = parse_resource(&mut tokens)?;
+  match tokens.next() {
+    None => Ok((name, resource)),
+    Some(token) => Err(format!("unexpected {:?}", token)),
+  }
+}
+
+/// Parse the resource in `{` and `}`, and the closing `}`.
+fn parse_resource<'a>(
+  tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a Token> + Clone>,
+) -> Result<Resource, String> {
+  // a table, whose entries are the keys followed by `{`
+  let mut ahead = tokens.clone();
+  if let (Some(Token::Word(_) | Token::Quoted(_)), Some(Token::Open)) = (ahead.next(), ahead.next())
+  {
+    let mut entries = vec![];
+    loop {
+      match (tokens.next(), tokens.next()) {
+        (Some(Token::Word(name)), Some(Token::Open)) => {
+          entries.push((key(name), parse_resource(tokens)?))
+        }
+        (Some(Token::Quoted(name)), Some(Token::Open)) => {
+          entries.push((name.clone(), parse_resource(tokens)?))
+        }
+        (Some(token), _) => return Err(format!("expected a key, found {:?}", token)),
+        (None, _) => return Err("unclosed `{`".to_string()),
+      }
+      if tokens.next_if_eq(&&Token::Close).is_some() {
+        return Ok(Resource::Table(entries));
+      }
+    }
+  }
+
+  let mut values = vec![];
+  loop {
+    match tokens.next() {
+      Some(Token::Close) if values.is_empty() => return Ok(Resource::Table(vec![])),
+      Some(Token::Close) => break,
+      Some(Token::Open) => values.push(parse_resource(tokens)?),
+      Some(Token::Quoted(value)) => {
+        // the adjacent strings are concatenated
+        let mut value = value.clone();
+        while let Some(Token::Quoted(next)) = tokens.next_if(|t| matches!(t, Token::Quoted(_))) {
+          value.push_str(next);
+        }
+        values.push(Resource::String(value));
+      }
+      Some(Token::Word(value)) => values.push(Resource::String(value.clone())),
+      Some(Token::Comma) => return Err("unexpected `,`".to_string()),
+      None => return Err("unclosed `{`".to_string()),
+    }
+    // the commas following the arrays and the tables are optional
+    match tokens.peek() {
+      Some(Token::Comma) => {
+        tokens.next();
+      }
+      Some(Token::Close) => {}
+      Some(Token::Open) if !matches!(values.last(), Some(Resource::String(_))) => {}
+      Some(token) => return Err(format!("expected `,` or `}}`, found {:?}", token)),
+      None => return Err("unclosed `{`".to_string()),
+    }
+  }
+  match values.len() {
+    1 if matches!(values[0], Resource::String(_)) => Ok(values.remove(0)),
+    _ => Ok(Resource::Array(values)),
+  }
+}
+
+/// Strip the type following the key, e.g. `start:intvector` to `start`.
+fn key(name: &str) -> String {
+  match name.rsplit_once(':') {
+    Some((key, resource_type))
+      if RESOURCE_TYPES.contains(&resource_type.split('(').next().unwrap_or_default()) =>
+    {
+      key.to_string()
+    }
+    _ => name.to_string(),
+  }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+  let mut tokens = vec![];
+  let mut chars = source.char_indices().peekable();
+  while let Some((index, c)) = chars.next() {
+    match c {
+      '{' => tokens.push(Token::Open),
+      '}' => tokens.push(Token::Close),
+      ',' => tokens.push(Token::Comma),
+      '"' => {
+        let mut value = String::new();
+        loop {
+          match chars.next() {
+            Some((_, '"')) => break,
+            Some((_, '\\')) => value.push(unescape(&mut chars)?),
+            Some((_, c)) => value.push(c),
+            None => return Err(format!("unclosed string at {}", index)),
+          }
+        }
+        tokens.push(Token::Quoted(value));
+      }
+      '/' if source[index..].starts_with("//") => {
+        while chars.next_if(|(_, c)| *c != '\n').is_some() {}
+      }
+      '/' if source[index..].starts_with("/*") => {
+        let end = source[index + 2..]
+          .find("*/")
+          .ok_or_else(|| format!("unclosed comment at {}", index))?;
+        while chars.next_if(|(i, _)| *i < index + 2 + end + 2).is_some() {}
+      }
+      c if c.is_whitespace() => {}
+      _ => {
+        let mut value = c.to_string();
+        while let Some((_, c)) =
+          chars.next_if(|(_, c)| !c.is_whitespace() && !matches!(c, '{' | '}' | ',' | '"'))
+        {
+          value.push(c);
+        }
+        tokens.push(Token::Word(value));
+      }
+    }
+  }
+  Ok(tokens)
+}
+
+/// Unescape the character following `\`, e.g. `é`.
+fn unescape(chars: &mut impl Iterator<Item = (usize, char)>) -> Result<char, String> {
+  let (index, c) = chars.next().ok_or("unclosed string")?;
+  let len = match c {
+    'u' => 4,
+    'U' => 8,
+    'n' => return Ok('\n'),
+    't' => return Ok('\t'),
+    c => return Ok(c),
+  };
+  let hex = chars.take(len).map(|(_, c)| c).collect::<String>();
+  u32::from_str_radix(&hex, 16)
+    .ok()
+    .and_then(char::from_u32)
+    .ok_or_else(|| format!("invalid escape at {}", index))
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse() {
+  assert_eq!(
+    Ok((
+      "supplementalData".to_string(),
+      Resource::Table(vec![
+        (
+          "cldrVersion".to_string(),
+          Resource::String("44".to_string())
+        ),
+        (
+          "start".to_string(),
+          Resource::Array(vec![
+            Resource::String("1".to_string()),
+            Resource::String("2".to_string())
+          ])
+        ),
+        (
+          "Africa:Asmara".to_string(),
+          Resource::String("Africa/Asmera".to_string())
+        ),
+        (
+          "names".to_string(),
+          Resource::Array(vec![
+            Resource::Array(vec![
+              Resource::String("a\"é".to_string()),
+              Resource::String("bc".to_string())
+            ]),
+            Resource::Table(vec![])
+          ])
+        ),
+      ])
+    )),
+    parse(
+      r#"// © 2016 and later: Unicode, Inc. and others.
+supplementalData:table(nofallback){
+    cldrVersion{"44"}
+    start:intvector{ 1, 2 }
+    /* the time zones */
+    "Africa:Asmara"{ "Africa/Asmera" }
+    names{
+        { "a\"é", "b" "c", }
+        {}
+    }
+}
+"#
+    )
+  );
+}
+
+#[test]
+fn fail_parse() {
+  assert_eq!(Err("no root resource".to_string()), parse("{}"));
+  assert_eq!(Err("unclosed `{`".to_string()), parse("a{ b"));
+  assert_eq!(Err("unclosed string at 3".to_string()), parse("a{ \"b }"));
+  assert_eq!(
+    Err("expected `,` or `}`, found Word(\"c\")".to_string()),
+    parse("a{ b c }")
+  );
+  assert_eq!(
+    Err("expected a key, found Word(\"c\")".to_string()),
+    parse("a{ b{} c }")
+  );
+  assert_eq!(Err("unclosed `{`".to_string()), parse("a{ b{}"));
+  assert_eq!(Err("unexpected Close".to_string()), parse("a{}}"));
+}
+
+#[test]
+fn success_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/icu");
+  let tables = read(&find(&dir).unwrap()).unwrap();
+  let get = |table, key| {
+    tables
+      .get(table)
+      .and_then(|entries| entries.get(key))
+      .map(String::as_str)
+  };
+
+  assert_eq!(Some("44"), tables.version());
+  assert_eq!(Some("he"), get(DataTable::LanguageAliases, "iw"));
+  assert_eq!(
+    Some("und-alalc97"),
+    get(DataTable::LanguageAliases, "und-hepburn-heploc")
+  );
+  assert_eq!(
+    Some("RU AM AZ BY EE GE KZ KG LV LT MD TJ TM UA UZ"),
+    get(DataTable::RegionAliases, "SU")
+  );
+  assert_eq!(None, get(DataTable::VariantAliases, "aaland"));
+  assert_eq!(Some("zh-Hant-TW"), get(DataTable::LikelySubtags, "zh-TW"));
+  assert_eq!(Some("en-001"), get(DataTable::ParentLocales, "en-AU"));
+  assert_eq!(Some("und"), get(DataTable::ParentLocales, "zh-Hant"));
+  assert_eq!(
+    Some("AT BE CH DE FR LI LU MC NL"),
+    get(DataTable::RegionContainment, "155")
+  );
+  assert_eq!(None, get(DataTable::RegionContainment, "EU"));
+  assert_eq!(None, get(DataTable::RegionContainment, "grouping"));
+  assert_eq!(Some("regular"), get(DataTable::Languages, "aab"));
+  assert_eq!(None, get(DataTable::Languages, "qaa"));
+  assert_eq!(Some("macroregion"), get(DataTable::Regions, "001"));
+  assert_eq!(Some("special"), get(DataTable::Regions, "XA"));
+  assert_eq!(Some("regular"), get(DataTable::Currencies, "jpy"));
+  assert_eq!(Some("currency"), get(DataTable::UnicodeKeys, "cu"));
+  assert_eq!(
+    Some("codepoints deprecated"),
+    get(DataTable::UnicodeKeys, "vt")
+  );
+  assert_eq!(Some("regular"), get(DataTable::UnicodeTypes, "ca-gregory"));
+  assert_eq!(
+    Some("deprecated"),
+    get(DataTable::UnicodeTypes, "co-direct")
+  );
+  assert_eq!(
+    Some("islamic-civil"),
+    get(DataTable::UnicodeKeywordAliases, "ca-islamicc")
+  );
+  assert_eq!(
+    Some("ethioaa"),
+    get(DataTable::UnicodeKeywordAliases, "ca-ethiopic-amete-alem")
+  );
+  assert_eq!(Some("regular"), get(DataTable::UnicodeTypes, "ks-level4"));
+  assert_eq!(Some("regular"), get(DataTable::UnicodeTypes, "tz-erasm"));
+  assert_eq!(Some("private_use"), get(DataTable::TransformedKeys, "x0"));
+  assert_eq!(
+    Some("prprname"),
+    get(DataTable::TransformedKeywordAliases, "m0-names")
+  );
+}
+
+#[test]
+fn fail_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/cldr-xml");
+  assert_eq!(None, find(&dir));
+  assert!(matches!(read(&dir), Err(Error::Io(_, _))));
+}
@@ -0,0 +1,233 @@
+use crate::tables::{Keyword, KeywordType, Tables, KEYWORD_TABLES};
+use crate::Error;
+use serde_json::{Map, Value as Json};
+use std::fs;
+use std::path::{Path, PathBuf};
+use unicode_locale_parser::DataTable;
+
+const ALIASES: &str = "supplemental/aliases.json";
+const LIKELY_SUBTAGS: &str = "supplemental/likelySubtags.json";
+const PARENT_LOCALES: &str = "supplemental/parentLocales.json";
+const TERRITORY_CONTAINMENT: &str = "supplemental/territoryContainment.json";
+const BCP47: &str = "bcp47";
+// the packages of cldr-json, when the directory is the whole release
+const PACKAGES: [&str; 2] = ["cldr-core", "cldr-bcp47"];
+
+/// Read the tables from a directory of CLDR JSON.
+///
+/// CLDR JSON has no validity data of the languages, scripts, regions, variants, subdivisions and units, so those tables are not read.
+/// The version of CLDR is the `_cldrVersion` of the supplemental data.
+pub fn read(dir: &Path) -> Result<Tables, Error> {
+  let mut tables = Tables::default();
+
+  if let Some(path) = find(dir, ALIASES) {
+    let aliases = load(&path)?;
+    read_version(&mut tables, &aliases);
+    let aliases = object(&path, &aliases, &["supplemental", "metadata", "alias"])?;
+    for (name, table) in [
+      ("languageAlias", DataTable::LanguageAliases),
+      ("scriptAlias", DataTable::ScriptAliases),
+      ("territoryAlias", DataTable::RegionAliases),
+      ("variantAlias", DataTable::VariantAliases),
+      ("subdivisionAlias", DataTable::SubdivisionAliases),
+    ] {
+      tables.touch(table);
+      for (rule, alias) in aliases
+        .get(name)
+        .and_then(Json::as_object)
+        .into_iter()
+        .flatten()
+      {
+        if let Some(replacement) = alias.get("_replacement").and_then(Json::as_str) {
+          tables.insert_alias(table, rule, replacement);
+        }
+      }
+    }
+  }
+
+  if let Some(path) = find(dir, LIKELY_SUBTAGS) {
+    let likely_subtags = load(&path)?;
+    read_version(&mut tables, &likely_subtags);
+    tables.touch(DataTable::LikelySubtags);
+    for (from, to) in object(&path, &likely_subtags, &["supplemental", "likelySubtags"])? {
+      if let Some(to) = to.as_str() {
+        tables.insert_likely_subtags(from, to);
+      }
+    }
+  }
+
+  if let Some(path) = find(dir, PARENT_LOCALES) {
+    let parent_locales = load(&path)?;
+    read_version(&mut tables, &parent_locales);
+    tables.touch(DataTable::ParentLocales);
+    let pointer = ["supplemental", "parentLocales", "parentLocale"];
+    for (locale, parent) in object(&path, &parent_locales, &pointer)? {
+      if let Some(parent) = parent.as_str() {
+        tables.insert_parent_locales(parent, locale);
+      }
+    }
+  }
+
+  if let Some(path) = find(dir, TERRITORY_CONTAINMENT) {
+    let containment = load(&path)?;
+    read_version(&mut tables, &containment);
+    tables.touch(DataTable::RegionContainment);
+    let pointer = ["supplemental", "territoryContainment"];
+    for (region, group) in object(&path, &containment, &pointer)? {
+      // the groupings and the deprecated containments, e.g. `001-status-deprecated`
+      if region.contains('-') || is_true(group.get("_grouping")) {
+        continue;
+      }
+      let contains = group.get("_contains").and_then(Json::as_array);
+      tables.insert_containment(
+        region,
+        contains.into_iter().flatten().filter_map(Json::as_str),
+      );
+    }
+  }
+
+  if let Some(bcp47) = find(dir, BCP47).filter(|bcp47| bcp47.is_dir()) {
+    KEYWORD_TABLES
+      .into_iter()
+      .for_each(|table| tables.touch(table));
+    let mut paths = fs::read_dir(&bcp47)
+      .map_err(|err| Error::Io(bcp47.clone(), err))?
+      .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+      .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+      .collect::<Vec<_>>();
+    paths.sort();
+    for path in paths {
+      let json = load(&path)?;
+      for (extension, keys) in object(&path, &json, &["keyword"])? {
+        for (key, types) in keys.as_object().into_iter().flatten() {
+          if let Some(types) = types.as_object() {
+            tables.insert_keyword(&keyword(extension, key, types));
+          }
+        }
+      }
+    }
+  }
+  Ok(tables)
+}
+
+fn read_version(tables: &mut Tables, json: &Json) {
+  let version = json.pointer("/supplemental/version/_cldrVersion");
+  if let Some(version) = version.and_then(Json::as_str) {
+    tables.set_version(version);
+  }
+}
+
+fn keyword(extension: &str, key: &str, types: &Map<String, Json>) -> Keyword {
+  Keyword {
+    extension: extension.to_string(),
+    key: key.to_string(),
+    deprecated: is_true(types.get("_deprecated")),
+    types: types
+      .iter()
+      .filter(|(name, _)| !name.starts_with('_'))
+      .map(|(name, attributes)| KeywordType {
+        name: name.to_string(),
+        deprecated: is_true(attributes.get("_deprecated")),
+        preferred: attributes
+          .get("_preferred")
+          .and_then(Json::as_str)
+          .map(str::to_string),
+        aliases: attributes
+          .get("_alias")
+          .and_then(Json::as_str)
+          .into_iter()
+          .flat_map(str::split_whitespace)
+          .map(str::to_string)
+          .collect(),
+      })
+      .collect(),
+  }
+}
+
+/// Find the file in the directory, or in the packages of cldr-json in the directory.
+fn find(dir: &Path, file: &str) -> Option<PathBuf> {
+  PACKAGES
+    .iter()
+    .map(|package| dir.join(package).join(file))
+    .chain([dir.join(file)])
+    .find(|path| path.exists())
+}
+
+fn load(path: &Path) -> Result<Json, Error> {
+  let text = fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+  serde_json::from_str(&text).map_err(|err| Error::Format(path.to_path_buf(), err.to_string()))
+}
+
+fn object<'a>(
+  path: &Path,
+  json: &'a Json,
+  pointer: &[&str],
+) -> Result<&'a Map<String, Json>, Error> {
+  pointer
+    .iter()
+    .try_fold(json, |json, name| json.get(name))
+    .and_then(Json::as_object)
+    .ok_or_else(|| Error::Format(path.to_path_buf(), format!("no `{}`", pointer.join("."))))
+}
+
+fn is_true(json: Option<&Json>) -> bool {
+  json.and_then(Json::as_str) == Some("true")
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/cldr-json");
+  let tables = read(&dir).unwrap();
+  let get = |table, key| {
+    tables
+      .get(table)
+      .and_then(|entries| entries.get(key))
+      .map(String::as_str)
+  };
+
+  assert_eq!(Some("44"), tables.version());
+  assert_eq!(Some("he"), get(DataTable::LanguageAliases, "iw"));
+  assert_eq!(
+    Some("und-alalc97"),
+    get(DataTable::LanguageAliases, "und-hepburn-heploc")
+  );
+  assert_eq!(None, get(DataTable::VariantAliases, "aaland"));
+  assert_eq!(Some("zh-Hant-TW"), get(DataTable::LikelySubtags, "zh-TW"));
+  assert_eq!(Some("en-001"), get(DataTable::ParentLocales, "en-AU"));
+  assert_eq!(Some("und"), get(DataTable::ParentLocales, "zh-Hant"));
+  assert_eq!(
+    Some("AT BE CH DE FR LI LU MC NL"),
+    get(DataTable::RegionContainment, "155")
+  );
+  assert_eq!(None, get(DataTable::RegionContainment, "EU"));
+  assert_eq!(
+    Some("005 013 021 029"),
+    get(DataTable::RegionContainment, "019")
+  );
+  assert_eq!(
+    Some("islamic-civil"),
+    get(DataTable::UnicodeKeywordAliases, "ca-islamicc")
+  );
+  assert_eq!(Some("private_use"), get(DataTable::TransformedKeys, "x0"));
+  // no validity data in CLDR JSON
+  assert_eq!(None, tables.get(DataTable::Languages));
+}
+
+#[test]
+fn fail_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/cldr");
+  assert!(read(&dir).unwrap().is_empty());
+
+  let path = Path::new("aliases.json");
+  let json = serde_json::json!({ "supplemental": {} });
+  assert_eq!(
+    "Invalid CLDR data aliases.json: no `supplemental.metadata.alias`",
+    object(path, &json, &["supplemental", "metadata", "alias"])
+      .unwrap_err()
+      .to_string()
+  );
+}
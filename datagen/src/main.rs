@@ -0,0 +1,145 @@
+//! Generate the CLDR data tables embedded in `unicode-locale-parser` (`src/data`), from an unpacked release of CLDR XML, CLDR JSON or the ICU data generated from CLDR.
+//!
+//! ```sh
+//! cargo run -p unicode-locale-datagen -- <cldr-dir> [<out-dir>]
+//! ```
+//!
+//! The directory is either a release of CLDR XML (with `common`), the ICU data (`icu4c/source/data`, with `misc`), or a release of CLDR JSON (with the `cldr-core` and `cldr-bcp47` packages).
+//! The tables are sorted by the keys in byte order to be binary-searched, and formatted as rustfmt does, so the regenerated data diffs cleanly.
+//! The version of CLDR is recorded in the generated modules.
+//! The modules whose tables are not all in the release are skipped, e.g. CLDR JSON has no validity data.
+
+mod icu;
+mod json;
+mod modules;
+mod tables;
+mod xml;
+
+use crate::modules::MODULES;
+use crate::tables::Tables;
+use std::env;
+use std::error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: unicode-locale-datagen <cldr-dir> [<out-dir>]";
+
+/// An error reading the CLDR data, or writing the tables.
+#[derive(Debug)]
+pub enum Error {
+  /// A file that cannot be read or written.
+  Io(PathBuf, io::Error),
+  /// A file that is not in the format of CLDR.
+  Format(PathBuf, String),
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::Io(path, err) => write!(f, "Cannot access {}: {}", path.display(), err),
+      Error::Format(path, message) => {
+        write!(f, "Invalid CLDR data {}: {}", path.display(), message)
+      }
+    }
+  }
+}
+
+impl error::Error for Error {}
+
+fn main() -> ExitCode {
+  let args = env::args().skip(1).collect::<Vec<_>>();
+  let default_out = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/data");
+  let (dir, out) = match args.as_slice() {
+    [dir] => (Path::new(dir), default_out.as_path()),
+    [dir, out] => (Path::new(dir), Path::new(out)),
+    _ => {
+      eprintln!("{}", USAGE);
+      return ExitCode::FAILURE;
+    }
+  };
+
+  match generate(dir, out) {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(err) => {
+      eprintln!("{}", err);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+/// Read the tables from CLDR XML, the ICU data or CLDR JSON in the directory.
+fn read(dir: &Path) -> Result<Tables, Error> {
+  let tables = if let Some(common) = xml::find(dir) {
+    xml::read(&common)?
+  } else if let Some(misc) = icu::find(dir) {
+    icu::read(&misc)?
+  } else {
+    json::read(dir)?
+  };
+  if tables.is_empty() {
+    return Err(Error::Format(dir.to_path_buf(), "no CLDR data".to_string()));
+  }
+  Ok(tables)
+}
+
+fn generate(dir: &Path, out: &Path) -> Result<(), Error> {
+  let tables = read(dir)?;
+  for module in MODULES {
+    let path = out.join(module.file);
+    match module.render(&tables) {
+      Ok(source) => {
+        fs::write(&path, source).map_err(|err| Error::Io(path.clone(), err))?;
+        eprintln!("Generated {}", path.display());
+      }
+      Err(missing) => eprintln!("Skipped {}: no {:?}", path.display(), missing),
+    }
+  }
+  Ok(())
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_generate() {
+  let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus");
+  let out = env::temp_dir().join(format!("unicode-locale-datagen-{}", std::process::id()));
+  fs::create_dir_all(&out).unwrap();
+
+  generate(&corpus.join("cldr-json"), &out).unwrap();
+  let aliases = fs::read_to_string(out.join("aliases.rs")).unwrap();
+  assert!(aliases.contains("  (\"iw\", \"he\"),\n"));
+  assert!(aliases.contains("//! Generated by `unicode-locale-datagen` from CLDR 44.\n"));
+  assert!(!out.join("validity.rs").exists());
+
+  // the same output for the same data
+  generate(&corpus.join("cldr-xml"), &out).unwrap();
+  let validity = fs::read_to_string(out.join("validity.rs")).unwrap();
+  generate(&corpus.join("cldr-xml").join("common"), &out).unwrap();
+  assert_eq!(
+    validity,
+    fs::read_to_string(out.join("validity.rs")).unwrap()
+  );
+  generate(&corpus.join("icu"), &out).unwrap();
+  let validity = fs::read_to_string(out.join("validity.rs")).unwrap();
+  generate(&corpus.join("icu").join("misc"), &out).unwrap();
+  assert_eq!(
+    validity,
+    fs::read_to_string(out.join("validity.rs")).unwrap()
+  );
+
+  fs::remove_dir_all(&out).unwrap();
+}
+
+#[test]
+fn fail_generate() {
+  let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus");
+  assert_eq!(
+    format!("Invalid CLDR data {}: no CLDR data", corpus.display()),
+    generate(&corpus, &corpus).unwrap_err().to_string()
+  );
+}
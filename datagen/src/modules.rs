@@ -0,0 +1,287 @@
+use crate::tables::Tables;
+use std::collections::BTreeMap;
+use unicode_locale_parser::DataTable;
+
+// the widths of rustfmt, over which an array or a tuple is split into the lines
+const MAX_WIDTH: usize = 100;
+const ARRAY_WIDTH: usize = 60;
+const TUPLE_WIDTH: usize = 60;
+const INDENT: &str = "  ";
+// the line of the module doc comment recording the release of CLDR, followed by the version
+const GENERATED: &str = "Generated by `unicode-locale-datagen` from CLDR ";
+
+/// A module of `src/data` in `unicode-locale-parser`, with its tables.
+pub struct Module {
+  pub file: &'static str,
+  /// The lines of the module doc comment.
+  pub doc: &'static [&'static str],
+  /// The tables, with the names of the statics and the doc comments.
+  pub tables: &'static [(DataTable, &'static str, &'static str)],
+}
+
+pub static MODULES: &[Module] = &[
+  Module {
+    file: "aliases.rs",
+    doc: &[
+      "The alias data of [CLDR `supplementalMetadata`](https://unicode.org/reports/tr35/#Alias_Data), sorted by the `type` to be binary-searched.",
+      "",
+      "The types and the replacements are in the canonical syntax, joined with `-`. The replacements of the regions are separated by a space.",
+    ],
+    tables: &[
+      (DataTable::LanguageAliases, "LANGUAGE_ALIASES", "The `languageAlias` rules."),
+      (DataTable::ScriptAliases, "SCRIPT_ALIASES", "The `scriptAlias` rules."),
+      (
+        DataTable::RegionAliases,
+        "REGION_ALIASES",
+        "The `territoryAlias` rules, whose first replacement is the default.",
+      ),
+      (DataTable::VariantAliases, "VARIANT_ALIASES", "The `variantAlias` rules."),
+      (
+        DataTable::SubdivisionAliases,
+        "SUBDIVISION_ALIASES",
+        "The `subdivisionAlias` rules, whose first replacement is the default. The replacements may be the regions.",
+      ),
+      (
+        DataTable::UnicodeKeywordAliases,
+        "UNICODE_KEYWORD_ALIASES",
+        "The type aliases of the unicode locale extension keys in the BCP 47 data, by the key and the type.",
+      ),
+      (
+        DataTable::TransformedKeywordAliases,
+        "TRANSFORMED_KEYWORD_ALIASES",
+        "The type aliases of the transformed extension keys in the BCP 47 data, by the key and the type.",
+      ),
+    ],
+  },
+  Module {
+    file: "containment.rs",
+    doc: &[
+      "The [CLDR territory containment data](https://unicode.org/reports/tr35/tr35-info.html#Territory_Containment_Data), sorted by the region to be binary-searched.",
+      "",
+      "The contained regions are sorted, and separated by a space. The groupings (e.g. `EU` and `419`) and the deprecated containments are not included, so that each region is contained in one region.",
+    ],
+    tables: &[(
+      DataTable::RegionContainment,
+      "REGION_CONTAINMENT",
+      "The regions contained in each macroregion.",
+    )],
+  },
+  Module {
+    file: "likely_subtags.rs",
+    doc: &[
+      "The [CLDR likely subtags data](https://unicode.org/reports/tr35/#Likely_Subtags), sorted by the source to be binary-searched.",
+      "",
+      "The sources and the maximized identifiers are in the canonical syntax, joined with `-`.",
+    ],
+    tables: &[(
+      DataTable::LikelySubtags,
+      "LIKELY_SUBTAGS",
+      "The likely subtags, from a language identifier to its maximized one.",
+    )],
+  },
+  Module {
+    file: "parent_locales.rs",
+    doc: &[
+      "The [CLDR parent locales data](https://unicode.org/reports/tr35/#Parent_Locales), sorted by the locale to be binary-searched.",
+      "",
+      "The locales and the parents are in the canonical syntax, joined with `-`. The root locale is `und`.",
+    ],
+    tables: &[(
+      DataTable::ParentLocales,
+      "PARENT_LOCALES",
+      "The parent locales that are not found by truncating the last subtag.",
+    )],
+  },
+  Module {
+    file: "validity.rs",
+    doc: &[
+      "The validity data of [CLDR `common/validity`](https://unicode.org/reports/tr35/#Validity_Data) and [`common/bcp47`](https://unicode.org/reports/tr35/#Key_And_Type_Definitions_), sorted by the code to be binary-searched.",
+      "",
+      "The statuses are the `idStatus` of CLDR: `regular`, `special`, `macroregion`, `deprecated`, `private_use` and `unknown`.",
+      "The private use and reserved ranges are not listed, since they are defined by the ranges of the codes, which are private use in BCP 47.",
+    ],
+    tables: &[
+      (DataTable::Languages, "LANGUAGES", "The statuses of the language subtags."),
+      (DataTable::Scripts, "SCRIPTS", "The statuses of the script subtags."),
+      (DataTable::Regions, "REGIONS", "The statuses of the region subtags."),
+      (DataTable::Variants, "VARIANTS", "The statuses of the variant subtags."),
+      (DataTable::Subdivisions, "SUBDIVISIONS", "The statuses of the subdivisions."),
+      (DataTable::Units, "UNITS", "The statuses of the measure units."),
+      (
+        DataTable::Currencies,
+        "CURRENCIES",
+        "The statuses of the currencies, in lowercase as the `cu` key.",
+      ),
+      (
+        DataTable::UnicodeKeys,
+        "UNICODE_KEYS",
+        "The ukeys, with the kind of their values, followed by `deprecated` for the deprecated keys.",
+      ),
+      (
+        DataTable::UnicodeTypes,
+        "UNICODE_TYPES",
+        "The statuses of the types of the ukeys whose values are `type` or `reorder`, by the key and the type.",
+      ),
+      (
+        DataTable::TransformedKeys,
+        "TRANSFORMED_KEYS",
+        "The tkeys, with the kind of their values, followed by `deprecated` for the deprecated keys.",
+      ),
+      (
+        DataTable::TransformedTypes,
+        "TRANSFORMED_TYPES",
+        "The statuses of the types of the tkeys whose values are `type`, by the key and the type.",
+      ),
+    ],
+  },
+];
+
+impl Module {
+  /// Render the module as formatted by rustfmt, or return the tables that are not read.
+  ///
+  /// The version of CLDR is recorded in the module doc comment, if the release has the version.
+  pub fn render(&self, tables: &Tables) -> Result<String, Vec<DataTable>> {
+    let missing = self
+      .tables
+      .iter()
+      .map(|(table, _, _)| *table)
+      .filter(|table| tables.get(*table).is_none())
+      .collect::<Vec<_>>();
+    if !missing.is_empty() {
+      return Err(missing);
+    }
+
+    let mut source = String::new();
+    for line in self.doc {
+      source.push_str(format!("//! {}", line).trim_end());
+      source.push('\n');
+    }
+    if let Some(version) = tables.version() {
+      source.push_str(&format!("//!\n//! {}{}.\n", GENERATED, version));
+    }
+    for (table, name, doc) in self.tables {
+      source.push('\n');
+      source.push_str(&render_table(name, doc, tables.get(*table).unwrap()));
+    }
+    Ok(source)
+  }
+}
+
+fn render_table(name: &str, doc: &str, entries: &BTreeMap<String, String>) -> String {
+  let head = format!("pub static {}: &[(&str, &str)] = &[", name);
+  let items = entries
+    .iter()
+    .map(|(key, value)| format!("{:?}, {:?}", key, value))
+    .collect::<Vec<_>>();
+
+  let line = items
+    .iter()
+    .map(|item| format!("({})", item))
+    .collect::<Vec<_>>()
+    .join(", ");
+  if line.len() <= ARRAY_WIDTH && head.len() + line.len() + 2 <= MAX_WIDTH {
+    return format!("/// {}\n{}{}];\n", doc, head, line);
+  }
+
+  let mut source = format!("/// {}\n{}\n", doc, head);
+  for (item, (key, value)) in items.iter().zip(entries) {
+    if item.len() <= TUPLE_WIDTH {
+      source.push_str(&format!("{}({}),\n", INDENT, item));
+    } else {
+      source.push_str(&format!(
+        "{0}(\n{0}{0}{1:?},\n{0}{0}{2:?},\n{0}),\n",
+        INDENT, key, value
+      ));
+    }
+  }
+  source.push_str("];\n");
+  source
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn embedded_data(module: &Module) -> (String, Tables) {
+  let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("../src/data")
+    .join(module.file);
+  let source = std::fs::read_to_string(path).unwrap();
+
+  // the statics are in the order of the module
+  let mut tables = Tables::default();
+  if let Some(version) = source
+    .lines()
+    .find_map(|line| line.strip_prefix("//! ")?.strip_prefix(GENERATED))
+  {
+    tables.set_version(version.trim_end_matches('.'));
+  }
+  let statics = source.split("pub static ").skip(1);
+  for ((table, _, _), body) in module.tables.iter().zip(statics) {
+    tables.touch(*table);
+    let strings = body.split('"').skip(1).step_by(2).collect::<Vec<_>>();
+    for entry in strings.chunks(2) {
+      tables.insert(*table, entry[0], entry[1]);
+    }
+  }
+  (source, tables)
+}
+
+#[test]
+fn success_render_embedded_data() {
+  let mut versions = vec![];
+  for module in MODULES {
+    let (source, tables) = embedded_data(module);
+    assert_eq!(Ok(source), module.render(&tables), "{}", module.file);
+    versions.push(tables.version().map(str::to_string));
+  }
+  // all the modules are generated from the same release of CLDR
+  assert!(versions[0].is_some());
+  assert!(versions.iter().all(|version| *version == versions[0]));
+}
+
+#[test]
+fn success_render() {
+  let mut tables = Tables::default();
+  tables.insert(DataTable::LikelySubtags, "zh-TW", "zh-Hant-TW");
+  tables.insert(DataTable::LikelySubtags, "en", "en-Latn-US");
+  assert_eq!(
+    Ok(
+      r#"//! The [CLDR likely subtags data](https://unicode.org/reports/tr35/#Likely_Subtags), sorted by the source to be binary-searched.
+//!
+//! The sources and the maximized identifiers are in the canonical syntax, joined with `-`.
+
+/// The likely subtags, from a language identifier to its maximized one.
+pub static LIKELY_SUBTAGS: &[(&str, &str)] = &[("en", "en-Latn-US"), ("zh-TW", "zh-Hant-TW")];
+"#
+      .to_string()
+    ),
+    MODULES[2].render(&tables)
+  );
+
+  tables.set_version("44");
+  assert!(MODULES[2].render(&tables).unwrap().contains(
+    "joined with `-`.\n//!\n//! Generated by `unicode-locale-datagen` from CLDR 44.\n\n"
+  ));
+
+  tables.touch(DataTable::RegionContainment);
+  assert_eq!(
+    "/// The regions contained in each macroregion.\npub static REGION_CONTAINMENT: &[(&str, &str)] = &[];\n",
+    MODULES[1].render(&tables).unwrap().split("\n\n").last().unwrap()
+  );
+}
+
+#[test]
+fn fail_render() {
+  let mut tables = Tables::default();
+  tables.touch(DataTable::Languages);
+  tables.touch(DataTable::Scripts);
+  assert_eq!(
+    Err(vec![DataTable::LanguageAliases]),
+    MODULES[0]
+      .render(&tables)
+      .map_err(|missing| missing[..1].to_vec())
+  );
+  assert_eq!(9, MODULES[4].render(&tables).unwrap_err().len());
+}
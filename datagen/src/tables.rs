@@ -0,0 +1,402 @@
+use std::collections::{BTreeMap, BTreeSet};
+use unicode_locale_parser::{DataTable, Value, Variant};
+
+const SEP: char = '-';
+const LEGACY_SEP: char = '_';
+const RANGE_SEP: char = '~';
+
+const STATUS_REGULAR: &str = "regular";
+const STATUS_DEPRECATED: &str = "deprecated";
+const STATUS_PRIVATE_USE: &str = "private_use";
+const STATUS_RESERVED: &str = "reserved";
+
+/// The tables of the BCP 47 data, which are read from the same files.
+pub const KEYWORD_TABLES: [DataTable; 7] = [
+  DataTable::UnicodeKeys,
+  DataTable::UnicodeTypes,
+  DataTable::UnicodeKeywordAliases,
+  DataTable::Currencies,
+  DataTable::TransformedKeys,
+  DataTable::TransformedTypes,
+  DataTable::TransformedKeywordAliases,
+];
+
+/// The tables read from CLDR, in the forms of the tables embedded in `unicode-locale-parser`.
+///
+/// The entries are sorted by the keys in byte order, so the output does not depend on the order of the files.
+#[derive(Debug, Default)]
+pub struct Tables {
+  tables: BTreeMap<DataTable, BTreeMap<String, String>>,
+  version: Option<String>,
+}
+
+/// A key of the BCP 47 data, with its types.
+#[derive(Debug, Default)]
+pub struct Keyword {
+  /// The singleton of the extension, `u` or `t`.
+  pub extension: String,
+  pub key: String,
+  pub deprecated: bool,
+  pub types: Vec<KeywordType>,
+}
+
+/// A type of a key of the BCP 47 data.
+#[derive(Debug, Default)]
+pub struct KeywordType {
+  pub name: String,
+  pub deprecated: bool,
+  pub preferred: Option<String>,
+  pub aliases: Vec<String>,
+}
+
+impl Tables {
+  pub fn is_empty(&self) -> bool {
+    self.tables.is_empty()
+  }
+
+  /// Get the version of CLDR, e.g. `43`, or `None` if the release has no version.
+  pub fn version(&self) -> Option<&str> {
+    self.version.as_deref()
+  }
+
+  pub fn set_version(&mut self, version: &str) {
+    self.version = Some(version.to_string());
+  }
+
+  /// Get the entries of the table, or `None` if the table is not read.
+  pub fn get(&self, table: DataTable) -> Option<&BTreeMap<String, String>> {
+    self.tables.get(&table)
+  }
+
+  /// Mark the table as read, even if it has no entries.
+  pub fn touch(&mut self, table: DataTable) {
+    self.tables.entry(table).or_default();
+  }
+
+  pub fn insert(&mut self, table: DataTable, key: &str, value: &str) {
+    self
+      .tables
+      .entry(table)
+      .or_default()
+      .insert(key.to_string(), value.to_string());
+  }
+
+  /// Insert an alias rule, e.g. `languageAlias`.
+  pub fn insert_alias(&mut self, table: DataTable, rule: &str, replacement: &str) {
+    let replacement = normalize(replacement);
+    // the variants replaced with the other subtags are in the language aliases
+    if table == DataTable::VariantAliases && Variant::try_from_str(&replacement).is_err() {
+      return;
+    }
+    self.insert(table, &normalize(rule), &replacement);
+  }
+
+  pub fn insert_likely_subtags(&mut self, from: &str, to: &str) {
+    self.insert(DataTable::LikelySubtags, &normalize(from), &normalize(to));
+  }
+
+  /// Insert the locales separated by spaces, whose parent is the given one.
+  pub fn insert_parent_locales(&mut self, parent: &str, locales: &str) {
+    let parent = match parent {
+      "root" => "und".to_string(),
+      parent => normalize(parent),
+    };
+    for locale in locales.split_whitespace() {
+      self.insert(DataTable::ParentLocales, &normalize(locale), &parent);
+    }
+  }
+
+  /// Insert the regions contained in the region, merged with the ones already inserted.
+  pub fn insert_containment<'a>(&mut self, region: &str, contains: impl Iterator<Item = &'a str>) {
+    let mut regions: BTreeSet<String> = self
+      .get(DataTable::RegionContainment)
+      .and_then(|entries| entries.get(region))
+      .map(|regions| regions.split(' ').map(str::to_string).collect())
+      .unwrap_or_default();
+    regions.extend(contains.map(str::to_string));
+    let regions = regions.into_iter().collect::<Vec<_>>().join(" ");
+    self.insert(DataTable::RegionContainment, region, &regions);
+  }
+
+  /// Insert the codes of the validity data separated by spaces, which may be the ranges, e.g. `aaa~c`.
+  ///
+  /// The private use codes and the reserved ones are not inserted, since they are defined by the ranges of the codes,
+  /// e.g. `qaa..qtz` of the languages, which are private use in BCP 47.
+  pub fn insert_validity(&mut self, table: DataTable, status: &str, codes: &str) {
+    self.touch(table);
+    if status == STATUS_PRIVATE_USE || status == STATUS_RESERVED {
+      return;
+    }
+    for code in codes.split_whitespace().flat_map(expand) {
+      let code = match table {
+        DataTable::Scripts => title_case(&code),
+        DataTable::Regions => code.to_ascii_uppercase(),
+        _ => code.to_ascii_lowercase(),
+      };
+      self.insert(table, &code, status);
+    }
+  }
+
+  /// Insert the key of the BCP 47 data, and its types and their aliases.
+  pub fn insert_keyword(&mut self, keyword: &Keyword) {
+    let (keys, statuses, aliases) = match keyword.extension.as_str() {
+      "u" => (
+        DataTable::UnicodeKeys,
+        DataTable::UnicodeTypes,
+        DataTable::UnicodeKeywordAliases,
+      ),
+      "t" => (
+        DataTable::TransformedKeys,
+        DataTable::TransformedTypes,
+        DataTable::TransformedKeywordAliases,
+      ),
+      _ => return,
+    };
+    let key = keyword.key.as_str();
+    // the special types are in uppercase, e.g. `SCRIPT_CODE`
+    let special = |special: &str| keyword.types.iter().any(|t| t.name == special);
+    let kind = if special("SCRIPT_CODE") {
+      "script"
+    } else if special("RG_KEY_VALUE") {
+      "region"
+    } else if special("SUBDIVISION_CODE") {
+      "subdivision"
+    } else if special("PRIVATE_USE") {
+      "private_use"
+    } else if special("CODEPOINTS") {
+      "codepoints"
+    } else if special("REORDER_CODE") {
+      "reorder"
+    } else if key == "cu" {
+      "currency"
+    } else {
+      "type"
+    };
+    // the deprecated keys are kept with their status, e.g. `vt` to `codepoints deprecated`
+    if keyword.deprecated {
+      self.insert(keys, key, &format!("{} {}", kind, STATUS_DEPRECATED));
+    } else {
+      self.insert(keys, key, kind);
+    }
+    if !matches!(kind, "type" | "currency" | "reorder") {
+      return;
+    }
+
+    for t in keyword.types.iter().filter(|t| is_type(&t.name)) {
+      let status = if t.deprecated {
+        STATUS_DEPRECATED
+      } else {
+        STATUS_REGULAR
+      };
+      if kind == "currency" {
+        self.insert(DataTable::Currencies, &t.name, status);
+        continue;
+      }
+      let name = format!("{}{}{}", key, SEP, t.name);
+      self.insert(statuses, &name, status);
+      if let Some(preferred) = t
+        .preferred
+        .as_deref()
+        .filter(|preferred| is_type(preferred))
+      {
+        self.insert(aliases, &name, preferred);
+      }
+      // the aliases may be the legacy identifiers in mixed case, e.g. `Cuba` of `tz`
+      for alias in t.aliases.iter().map(|alias| alias.to_ascii_lowercase()) {
+        if alias != t.name && is_type(&alias) {
+          self.insert(aliases, &format!("{}{}{}", key, SEP, alias), &t.name);
+        }
+      }
+    }
+  }
+}
+
+/// Normalize the separators of the identifiers in CLDR, e.g. `zh_TW` to `zh-TW`.
+fn normalize(id: &str) -> String {
+  id.replace(LEGACY_SEP, &SEP.to_string())
+}
+
+fn title_case(code: &str) -> String {
+  code
+    .char_indices()
+    .map(|(index, c)| match index {
+      0 => c.to_ascii_uppercase(),
+      _ => c.to_ascii_lowercase(),
+    })
+    .collect()
+}
+
+/// Whether the type is an uvalue or a tvalue, i.e. 3 to 8 alphanumerics separated by `-`.
+fn is_type(name: &str) -> bool {
+  name
+    .split(SEP)
+    .all(|value| value.len() >= 3 && Value::try_from_str(value).is_ok())
+}
+
+/// Expand the range of the validity data, whose end replaces the last characters of the start, e.g. `aaa~c` to `aaa aab aac`.
+///
+/// Each of the last characters ranges independently, as CLDR does, e.g. `aa~bb` to `aa ab ba bb`.
+fn expand(code: &str) -> Vec<String> {
+  let Some((start, end)) = code.split_once(RANGE_SEP) else {
+    return vec![code.to_string()];
+  };
+  let Some(prefix) = start.len().checked_sub(end.len()).map(|len| &start[..len]) else {
+    return vec![];
+  };
+  let ranges = start[prefix.len()..]
+    .bytes()
+    .zip(end.bytes())
+    .map(|(from, to)| from..=to)
+    .collect::<Vec<_>>();
+  ranges
+    .iter()
+    .fold(vec![prefix.to_string()], |codes, range| {
+      codes
+        .iter()
+        .flat_map(|code| range.clone().map(move |c| format!("{}{}", code, c as char)))
+        .collect()
+    })
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_expand() {
+  assert_eq!(vec!["aa"], expand("aa"));
+  assert_eq!(vec!["aaa", "aab", "aac"], expand("aaa~c"));
+  assert_eq!(vec!["aa", "ab", "ba", "bb"], expand("aa~bb"));
+  assert_eq!(vec!["AA", "AB"], expand("AA~AB"));
+  assert_eq!(Vec::<String>::new(), expand("a~bc"));
+}
+
+#[test]
+fn success_insert() {
+  let mut tables = Tables::default();
+  tables.insert_alias(DataTable::LanguageAliases, "zh_TW_hakka", "hak_TW");
+  tables.insert_alias(DataTable::VariantAliases, "aaland", "AX");
+  tables.insert_parent_locales("root", "az_Arab zh_Hant");
+  tables.insert_containment("155", ["FR", "DE"].into_iter());
+  tables.insert_containment("155", ["AT", "DE"].into_iter());
+  tables.insert_validity(DataTable::Scripts, "regular", "ADLM Aghb");
+  tables.insert_validity(DataTable::Languages, "private_use", "qfz~qtz");
+  tables.insert_validity(DataTable::Languages, "reserved", "qaa~qfy");
+
+  let get = |table, key| {
+    tables
+      .get(table)
+      .and_then(|entries| entries.get(key))
+      .map(String::as_str)
+  };
+  assert_eq!(
+    Some("hak-TW"),
+    get(DataTable::LanguageAliases, "zh-TW-hakka")
+  );
+  assert_eq!(None, get(DataTable::VariantAliases, "aaland"));
+  assert_eq!(Some("und"), get(DataTable::ParentLocales, "zh-Hant"));
+  assert_eq!(Some("AT DE FR"), get(DataTable::RegionContainment, "155"));
+  assert_eq!(Some("regular"), get(DataTable::Scripts, "Adlm"));
+  assert_eq!(Some(&Default::default()), tables.get(DataTable::Languages));
+  assert_eq!(None, tables.get(DataTable::Regions));
+}
+
+#[test]
+fn success_insert_keyword() {
+  let mut tables = Tables::default();
+  tables.insert_keyword(&Keyword {
+    extension: "u".to_string(),
+    key: "ca".to_string(),
+    deprecated: false,
+    types: vec![
+      KeywordType {
+        name: "ethioaa".to_string(),
+        aliases: vec!["ethiopic-amete-alem".to_string()],
+        ..Default::default()
+      },
+      KeywordType {
+        name: "islamicc".to_string(),
+        deprecated: true,
+        preferred: Some("islamic-civil".to_string()),
+        ..Default::default()
+      },
+    ],
+  });
+  tables.insert_keyword(&Keyword {
+    extension: "u".to_string(),
+    key: "tz".to_string(),
+    deprecated: false,
+    types: vec![KeywordType {
+      name: "cuhav".to_string(),
+      aliases: vec!["America/Havana".to_string(), "Cuba".to_string()],
+      ..Default::default()
+    }],
+  });
+  tables.insert_keyword(&Keyword {
+    extension: "u".to_string(),
+    key: "kr".to_string(),
+    deprecated: false,
+    types: vec![
+      KeywordType {
+        name: "REORDER_CODE".to_string(),
+        ..Default::default()
+      },
+      KeywordType {
+        name: "space".to_string(),
+        ..Default::default()
+      },
+    ],
+  });
+  tables.insert_keyword(&Keyword {
+    extension: "u".to_string(),
+    key: "vt".to_string(),
+    deprecated: true,
+    types: vec![KeywordType {
+      name: "CODEPOINTS".to_string(),
+      ..Default::default()
+    }],
+  });
+  tables.insert_keyword(&Keyword {
+    extension: "t".to_string(),
+    key: "x0".to_string(),
+    deprecated: false,
+    types: vec![KeywordType {
+      name: "PRIVATE_USE".to_string(),
+      ..Default::default()
+    }],
+  });
+
+  let get = |table, key| {
+    tables
+      .get(table)
+      .and_then(|entries| entries.get(key))
+      .map(String::as_str)
+  };
+  assert_eq!(Some("type"), get(DataTable::UnicodeKeys, "ca"));
+  assert_eq!(Some("reorder"), get(DataTable::UnicodeKeys, "kr"));
+  assert_eq!(Some("regular"), get(DataTable::UnicodeTypes, "kr-space"));
+  assert_eq!(None, get(DataTable::UnicodeTypes, "kr-REORDER_CODE"));
+  assert_eq!(
+    Some("codepoints deprecated"),
+    get(DataTable::UnicodeKeys, "vt")
+  );
+  assert_eq!(
+    Some("deprecated"),
+    get(DataTable::UnicodeTypes, "ca-islamicc")
+  );
+  assert_eq!(
+    Some("islamic-civil"),
+    get(DataTable::UnicodeKeywordAliases, "ca-islamicc")
+  );
+  assert_eq!(
+    Some("ethioaa"),
+    get(DataTable::UnicodeKeywordAliases, "ca-ethiopic-amete-alem")
+  );
+  assert_eq!(
+    Some("cuhav"),
+    get(DataTable::UnicodeKeywordAliases, "tz-cuba")
+  );
+  assert_eq!(None, get(DataTable::UnicodeKeywordAliases, "tz-Cuba"));
+  assert_eq!(Some("private_use"), get(DataTable::TransformedKeys, "x0"));
+  assert_eq!(None, tables.get(DataTable::TransformedTypes));
+}
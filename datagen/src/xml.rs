@@ -0,0 +1,444 @@
+use crate::tables::{Keyword, KeywordType, Tables, KEYWORD_TABLES};
+use crate::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use unicode_locale_parser::DataTable;
+
+const COMMON: &str = "common";
+const SUPPLEMENTAL_METADATA: &str = "supplemental/supplementalMetadata.xml";
+const SUPPLEMENTAL_DATA: &str = "supplemental/supplementalData.xml";
+const LIKELY_SUBTAGS: &str = "supplemental/likelySubtags.xml";
+const VALIDITY: &str = "validity";
+const BCP47: &str = "bcp47";
+// the DTD fixing the `cldrVersion` of the `version` elements
+const SUPPLEMENTAL_DTD: &str = "dtd/ldmlSupplemental.dtd";
+const CLDR_VERSION: &str = "cldrVersion CDATA #FIXED ";
+
+/// An element of XML, with its attributes, children and text.
+///
+/// This is enough for the files of CLDR, which have no namespaces and no CDATA sections.
+#[derive(Debug, Default, PartialEq)]
+pub struct Element {
+  pub name: String,
+  pub attributes: Vec<(String, String)>,
+  pub children: Vec<Element>,
+  pub text: String,
+}
+
+impl Element {
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|(n, _)| n == name)
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// Find the descendant elements with the name, in the document order.
+  pub fn descendants<'a>(&'a self, name: &str) -> Vec<&'a Element> {
+    let mut elements = vec![];
+    for child in &self.children {
+      if child.name == name {
+        elements.push(child);
+      }
+      elements.extend(child.descendants(name));
+    }
+    elements
+  }
+}
+
+/// Find the `common` directory of CLDR XML in the directory, or the directory itself.
+pub fn find(dir: &Path) -> Option<PathBuf> {
+  [dir.join(COMMON), dir.to_path_buf()]
+    .into_iter()
+    .find(|dir| dir.join(SUPPLEMENTAL_METADATA).exists())
+}
+
+/// Read the tables from the `common` directory of CLDR XML.
+///
+/// The version of CLDR is the `cldrVersion` fixed in `dtd/ldmlSupplemental.dtd`.
+pub fn read(common: &Path) -> Result<Tables, Error> {
+  let mut tables = Tables::default();
+  let path = common.join(SUPPLEMENTAL_DTD);
+  if path.exists() {
+    let dtd = fs::read_to_string(&path).map_err(|err| Error::Io(path.clone(), err))?;
+    if let Some(version) = dtd
+      .split(CLDR_VERSION)
+      .nth(1)
+      .and_then(|rest| rest.split('"').nth(1))
+    {
+      tables.set_version(version);
+    }
+  }
+  read_aliases(&mut tables, &load(&common.join(SUPPLEMENTAL_METADATA))?);
+
+  let path = common.join(LIKELY_SUBTAGS);
+  if path.exists() {
+    tables.touch(DataTable::LikelySubtags);
+    for likely_subtag in load(&path)?.descendants("likelySubtag") {
+      if let (Some(from), Some(to)) = (
+        likely_subtag.attribute("from"),
+        likely_subtag.attribute("to"),
+      ) {
+        tables.insert_likely_subtags(from, to);
+      }
+    }
+  }
+
+  let path = common.join(SUPPLEMENTAL_DATA);
+  if path.exists() {
+    read_supplemental_data(&mut tables, &load(&path)?);
+  }
+
+  for path in files(&common.join(VALIDITY))? {
+    for id in load(&path)?.descendants("id") {
+      let table = match id.attribute("type") {
+        Some("language") => DataTable::Languages,
+        Some("script") => DataTable::Scripts,
+        Some("region") => DataTable::Regions,
+        Some("variant") => DataTable::Variants,
+        Some("subdivision") => DataTable::Subdivisions,
+        Some("unit") => DataTable::Units,
+        // the currencies are read from the BCP 47 data, as the `cu` key
+        _ => continue,
+      };
+      if let Some(status) = id.attribute("idStatus") {
+        tables.insert_validity(table, status, &id.text);
+      }
+    }
+  }
+
+  let paths = files(&common.join(BCP47))?;
+  if !paths.is_empty() {
+    KEYWORD_TABLES
+      .into_iter()
+      .for_each(|table| tables.touch(table));
+  }
+  for path in paths {
+    for key in load(&path)?.descendants("key") {
+      tables.insert_keyword(&keyword(key));
+    }
+  }
+  Ok(tables)
+}
+
+fn read_aliases(tables: &mut Tables, metadata: &Element) {
+  for (name, table) in [
+    ("languageAlias", DataTable::LanguageAliases),
+    ("scriptAlias", DataTable::ScriptAliases),
+    ("territoryAlias", DataTable::RegionAliases),
+    ("variantAlias", DataTable::VariantAliases),
+    ("subdivisionAlias", DataTable::SubdivisionAliases),
+  ] {
+    tables.touch(table);
+    for alias in metadata.descendants(name) {
+      if let (Some(rule), Some(replacement)) =
+        (alias.attribute("type"), alias.attribute("replacement"))
+      {
+        tables.insert_alias(table, rule, replacement);
+      }
+    }
+  }
+}
+
+fn read_supplemental_data(tables: &mut Tables, data: &Element) {
+  // the parent locales of the components, e.g. `segmentations`, are not for the locales
+  let parent_locales = data.descendants("parentLocales");
+  if let Some(parent_locales) = parent_locales
+    .iter()
+    .find(|p| p.attribute("component").is_none())
+  {
+    tables.touch(DataTable::ParentLocales);
+    for parent_locale in parent_locales.descendants("parentLocale") {
+      if let (Some(parent), Some(locales)) = (
+        parent_locale.attribute("parent"),
+        parent_locale.attribute("locales"),
+      ) {
+        tables.insert_parent_locales(parent, locales);
+      }
+    }
+  }
+
+  if let Some(containment) = data.descendants("territoryContainment").first() {
+    tables.touch(DataTable::RegionContainment);
+    for group in containment.descendants("group") {
+      // the groupings and the deprecated containments
+      if group.attribute("grouping") == Some("true") || group.attribute("status").is_some() {
+        continue;
+      }
+      if let (Some(region), Some(contains)) = (group.attribute("type"), group.attribute("contains"))
+      {
+        tables.insert_containment(region, contains.split_whitespace());
+      }
+    }
+  }
+}
+
+fn keyword(key: &Element) -> Keyword {
+  let is_true = |element: &Element, name| element.attribute(name) == Some("true");
+  Keyword {
+    extension: key.attribute("extension").unwrap_or("u").to_string(),
+    key: key.attribute("name").unwrap_or_default().to_string(),
+    deprecated: is_true(key, "deprecated"),
+    types: key
+      .descendants("type")
+      .into_iter()
+      .map(|t| KeywordType {
+        name: t.attribute("name").unwrap_or_default().to_string(),
+        deprecated: is_true(t, "deprecated"),
+        preferred: t.attribute("preferred").map(str::to_string),
+        aliases: t
+          .attribute("alias")
+          .into_iter()
+          .flat_map(str::split_whitespace)
+          .map(str::to_string)
+          .collect(),
+      })
+      .collect(),
+  }
+}
+
+/// The XML files in the directory, sorted by the names, or none if the directory does not exist.
+fn files(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+  if !dir.is_dir() {
+    return Ok(vec![]);
+  }
+  let mut paths = fs::read_dir(dir)
+    .map_err(|err| Error::Io(dir.to_path_buf(), err))?
+    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+    .filter(|path| path.extension().is_some_and(|ext| ext == "xml"))
+    .collect::<Vec<_>>();
+  paths.sort();
+  Ok(paths)
+}
+
+fn load(path: &Path) -> Result<Element, Error> {
+  let source = fs::read_to_string(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+  parse(&source).map_err(|message| Error::Format(path.to_path_buf(), message))
+}
+
+/// Parse the XML document, and return its root element.
+pub fn parse(source: &str) -> Result<Element, String> {
+  let mut stack = vec![Element::default()];
+  let mut rest = source;
+  while let Some(start) = rest.find('<') {
+    stack
+      .last_mut()
+      .unwrap()
+      .text
+      .push_str(&unescape(&rest[..start]));
+    rest = &rest[start..];
+    if let Some(after) = rest.strip_prefix("<!--") {
+      rest = skip(after, "-->")?;
+    } else if let Some(after) = rest.strip_prefix("<?") {
+      rest = skip(after, "?>")?;
+    } else if let Some(after) = rest.strip_prefix("<!") {
+      // the internal subset of `DOCTYPE` is in the brackets
+      let end = match (after.find('['), after.find('>')) {
+        (Some(bracket), Some(end)) if bracket < end => after.find("]>").map(|end| end + 1),
+        (_, end) => end,
+      };
+      rest = &after[end.ok_or("unclosed declaration")? + 1..];
+    } else if let Some(after) = rest.strip_prefix("</") {
+      let end = after.find('>').ok_or("unclosed end tag")?;
+      let name = after[..end].trim();
+      let element = stack
+        .pop()
+        .filter(|_| !stack.is_empty())
+        .ok_or(format!("unexpected `</{}>`", name))?;
+      if element.name != name {
+        return Err(format!("`<{}>` closed by `</{}>`", element.name, name));
+      }
+      stack.last_mut().unwrap().children.push(element);
+      rest = &after[end + 1..];
+    } else {
+      let end = tag_end(rest).ok_or("unclosed tag")?;
+      let (tag, empty) = match rest[1..end].strip_suffix('/') {
+        Some(tag) => (tag, true),
+        None => (&rest[1..end], false),
+      };
+      let element = parse_tag(tag)?;
+      if empty {
+        stack.last_mut().unwrap().children.push(element);
+      } else {
+        stack.push(element);
+      }
+      rest = &rest[end + 1..];
+    }
+  }
+
+  match stack.pop() {
+    Some(document) if stack.is_empty() => document
+      .children
+      .into_iter()
+      .next()
+      .ok_or("no root element".to_string()),
+    _ => Err("unclosed element".to_string()),
+  }
+}
+
+fn skip<'a>(source: &'a str, end: &str) -> Result<&'a str, String> {
+  source
+    .find(end)
+    .map(|index| &source[index + end.len()..])
+    .ok_or(format!("no `{}`", end))
+}
+
+/// Find the `>` of the tag, which is not in the attribute values.
+fn tag_end(source: &str) -> Option<usize> {
+  let mut quote = None;
+  for (index, c) in source.char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (Some(q), _) if q == c => quote = None,
+      (None, '>') => return Some(index),
+      _ => {}
+    }
+  }
+  None
+}
+
+fn parse_tag(tag: &str) -> Result<Element, String> {
+  let tag = tag.trim();
+  let (name, mut rest) = tag.split_at(tag.find(char::is_whitespace).unwrap_or(tag.len()));
+  let mut element = Element {
+    name: name.to_string(),
+    ..Default::default()
+  };
+  loop {
+    rest = rest.trim_start();
+    if rest.is_empty() {
+      return Ok(element);
+    }
+    let (attribute, value) = rest
+      .split_once('=')
+      .ok_or(format!("no value of the attribute in `<{}>`", tag))?;
+    let value = value.trim_start();
+    let quote = value
+      .chars()
+      .next()
+      .filter(|c| *c == '"' || *c == '\'')
+      .ok_or(format!("unquoted attribute in `<{}>`", tag))?;
+    let end = value[1..]
+      .find(quote)
+      .ok_or(format!("unclosed attribute in `<{}>`", tag))?;
+    element
+      .attributes
+      .push((attribute.trim().to_string(), unescape(&value[1..end + 1])));
+    rest = &value[end + 2..];
+  }
+}
+
+fn unescape(text: &str) -> String {
+  text
+    .replace("&lt;", "<")
+    .replace("&gt;", ">")
+    .replace("&quot;", "\"")
+    .replace("&apos;", "'")
+    .replace("&amp;", "&")
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse() {
+  let root = parse(
+    r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!DOCTYPE supplementalData SYSTEM "../../common/dtd/ldmlSupplemental.dtd">
+<!-- Copyright © 1991-2023 Unicode, Inc. -->
+<supplementalData>
+  <version number="$Revision$"/>
+  <idValidity>
+    <id type='language' idStatus="regular">aa ab
+      ace~f</id>
+  </idValidity>
+  <note text="a &lt; b &amp; c"></note>
+</supplementalData>"#,
+  )
+  .unwrap();
+  assert_eq!("supplementalData", root.name);
+  assert_eq!(3, root.children.len());
+  let id = root.descendants("id")[0];
+  assert_eq!(Some("language"), id.attribute("type"));
+  assert_eq!(Some("regular"), id.attribute("idStatus"));
+  assert_eq!(
+    vec!["aa", "ab", "ace~f"],
+    id.text.split_whitespace().collect::<Vec<_>>()
+  );
+  assert_eq!(
+    Some("a < b & c"),
+    root.descendants("note")[0].attribute("text")
+  );
+  assert_eq!(None, root.descendants("note")[0].attribute("type"));
+}
+
+#[test]
+fn fail_parse() {
+  assert_eq!(Err("`<a>` closed by `</b>`".to_string()), parse("<a></b>"));
+  assert_eq!(Err("unexpected `</a>`".to_string()), parse("</a>"));
+  assert_eq!(Err("unclosed element".to_string()), parse("<a><b/>"));
+  assert_eq!(Err("unclosed tag".to_string()), parse("<a b='>"));
+  assert_eq!(Err("no root element".to_string()), parse("<!-- -->"));
+  assert_eq!(
+    Err("unquoted attribute in `<a b=c>`".to_string()),
+    parse("<a b=c/>")
+  );
+}
+
+#[test]
+fn success_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/cldr-xml");
+  let tables = read(&find(&dir).unwrap()).unwrap();
+  let get = |table, key| {
+    tables
+      .get(table)
+      .and_then(|entries| entries.get(key))
+      .map(String::as_str)
+  };
+
+  assert_eq!(Some("44"), tables.version());
+  assert_eq!(Some("he"), get(DataTable::LanguageAliases, "iw"));
+  assert_eq!(
+    Some("hak-TW"),
+    get(DataTable::LanguageAliases, "zh-TW-hakka")
+  );
+  assert_eq!(Some("MM"), get(DataTable::RegionAliases, "BU"));
+  assert_eq!(None, get(DataTable::VariantAliases, "aaland"));
+  assert_eq!(Some("zh-Hant-TW"), get(DataTable::LikelySubtags, "zh-TW"));
+  assert_eq!(Some("en-001"), get(DataTable::ParentLocales, "en-AU"));
+  assert_eq!(Some("und"), get(DataTable::ParentLocales, "zh-Hant"));
+  // the parent locales of `segmentations`
+  assert_eq!(None, get(DataTable::ParentLocales, "en-GB"));
+  assert_eq!(
+    Some("AT BE CH DE FR LI LU MC NL"),
+    get(DataTable::RegionContainment, "155")
+  );
+  assert_eq!(None, get(DataTable::RegionContainment, "EU"));
+  assert_eq!(Some("regular"), get(DataTable::Languages, "aab"));
+  assert_eq!(None, get(DataTable::Languages, "qaa"));
+  assert_eq!(Some("unknown"), get(DataTable::Scripts, "Zzzz"));
+  assert_eq!(Some("macroregion"), get(DataTable::Regions, "001"));
+  assert_eq!(Some("regular"), get(DataTable::Variants, "1901"));
+  assert_eq!(Some("regular"), get(DataTable::Subdivisions, "gbsct"));
+  assert_eq!(Some("regular"), get(DataTable::Units, "length-meter"));
+  assert_eq!(Some("regular"), get(DataTable::Currencies, "jpy"));
+  assert_eq!(Some("currency"), get(DataTable::UnicodeKeys, "cu"));
+  assert_eq!(
+    Some("islamic-civil"),
+    get(DataTable::UnicodeKeywordAliases, "ca-islamicc")
+  );
+  assert_eq!(Some("private_use"), get(DataTable::TransformedKeys, "x0"));
+  assert_eq!(
+    Some("prprname"),
+    get(DataTable::TransformedKeywordAliases, "m0-names")
+  );
+}
+
+#[test]
+fn fail_read() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/corpus/cldr-json");
+  assert_eq!(None, find(&dir));
+  assert!(matches!(read(&dir), Err(Error::Io(_, _))));
+}
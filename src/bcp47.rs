@@ -0,0 +1,694 @@
+use crate::constants::{LANG_UND, SEP};
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::extensions::other::OtherExtensions;
+use crate::extensions::pu::PuExtensions;
+use crate::extensions::transformed::TransformedExtensions;
+use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
+use crate::locale::{parse_unicode_locale_id, UnicodeLocaleIdentifier};
+use crate::options::{GrammarProfile, ParseOptions};
+use crate::shared::{check_subtags, split_str, Fragment, ParserContext};
+use crate::subtags::{region_subtag, script_subtag, variant_subtag, Value};
+
+use std::fmt::{self, Write};
+use std::iter::Peekable;
+use std::str::FromStr;
+
+// The grandfathered tags of BCP 47, and their replacements in Unicode locale identifiers
+// https://unicode.org/reports/tr35/#BCP_47_Language_Tag_Conversion
+const GRANDFATHERED: [(&str, &str); 26] = [
+  // irregular
+  ("en-GB-oed", "en-GB-oxendict"),
+  ("i-ami", "ami"),
+  ("i-bnn", "bnn"),
+  ("i-default", "en-x-i-default"),
+  ("i-enochian", "und-x-i-enochian"),
+  ("i-hak", "hak"),
+  ("i-klingon", "tlh"),
+  ("i-lux", "lb"),
+  ("i-mingo", "see-x-i-mingo"),
+  ("i-navajo", "nv"),
+  ("i-pwn", "pwn"),
+  ("i-tao", "tao"),
+  ("i-tay", "tay"),
+  ("i-tsu", "tsu"),
+  ("sgn-BE-FR", "sfb"),
+  ("sgn-BE-NL", "vgt"),
+  ("sgn-CH-DE", "sgg"),
+  // regular
+  ("art-lojban", "jbo"),
+  ("cel-gaulish", "xtg"),
+  ("no-bok", "nb"),
+  ("no-nyn", "nn"),
+  ("zh-guoyu", "zh"),
+  ("zh-hakka", "hak"),
+  ("zh-min", "nan-x-zh-min"),
+  ("zh-min-nan", "nan"),
+  ("zh-xiang", "hsn"),
+];
+
+/// Enum representing a [BCP 47 language tag](https://www.rfc-editor.org/rfc/rfc5646#section-2.1).
+#[derive(Debug, PartialEq)]
+pub enum LanguageTag {
+  /// A `langtag`, e.g. `zh-yue-HK`.
+  Langtag(Langtag),
+  /// A `privateuse` tag, e.g. `x-whatever`.
+  PrivateUse(PuExtensions),
+  /// A `grandfathered` tag in the registered casing, e.g. `i-klingon`.
+  Grandfathered(String),
+}
+
+/// The subtags of a BCP 47 `langtag`.
+#[derive(Debug, PartialEq)]
+pub struct Langtag {
+  pub language: String,
+  pub extlang: Option<Vec<String>>,
+  pub script: Option<String>,
+  pub region: Option<String>,
+  pub variants: Option<Vec<String>>,
+  pub extensions: Option<Vec<OtherExtensions>>,
+  pub private_use: Option<PuExtensions>,
+}
+
+impl fmt::Display for Langtag {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.language)?;
+    for extlang in self.extlang.iter().flatten() {
+      f.write_char(SEP)?;
+      f.write_str(extlang)?;
+    }
+    if let Some(ref script) = self.script {
+      f.write_char(SEP)?;
+      f.write_str(script)?;
+    }
+    if let Some(ref region) = self.region {
+      f.write_char(SEP)?;
+      f.write_str(region)?;
+    }
+    for variant in self.variants.iter().flatten() {
+      f.write_char(SEP)?;
+      f.write_str(variant)?;
+    }
+    for extension in self.extensions.iter().flatten() {
+      write!(f, "{}{}", SEP, extension)?;
+    }
+    if let Some(ref pu) = self.private_use {
+      write!(f, "{}{}", SEP, pu)?;
+    }
+    Ok(())
+  }
+}
+
+impl fmt::Display for LanguageTag {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      LanguageTag::Langtag(langtag) => langtag.fmt(f),
+      LanguageTag::PrivateUse(pu) => pu.fmt(f),
+      LanguageTag::Grandfathered(tag) => f.write_str(tag),
+    }
+  }
+}
+
+impl FromStr for LanguageTag {
+  type Err = SyntaxError;
+
+  fn from_str(source: &str) -> Result<Self, Self::Err> {
+    parse_bcp47_language_tag(source)
+  }
+}
+
+impl LanguageTag {
+  /// Convert the language tag to an Unicode Locale Identifier.
+  ///
+  /// This follows [BCP 47 Language Tag to Unicode BCP 47 Locale Identifier](https://unicode.org/reports/tr35/#BCP_47_Language_Tag_to_Unicode_BCP_47_Locale_Identifier):
+  /// a grandfathered tag is replaced with its replacement, an extlang replaces the primary language, and a private use tag gets the `und` language.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_bcp47_tag;
+  ///
+  /// let locale = parse_bcp47_tag("zh-yue-HK").unwrap().to_unicode_locale_id().unwrap();
+  /// assert_eq!("yue-HK", format!("{}", locale));
+  ///
+  /// let locale = parse_bcp47_tag("i-klingon").unwrap().to_unicode_locale_id().unwrap();
+  /// assert_eq!("tlh", format!("{}", locale));
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// This function returns the errors of [`parse_locale_id`](crate::parse_locale_id) for the converted identifier,
+  /// e.g. [`ParserError::InvalidLanguage`] for the 4 letters language subtag that BCP 47 reserves, or an invalid unicode locale extension.
+  pub fn to_unicode_locale_id(&self) -> Result<UnicodeLocaleIdentifier, SyntaxError> {
+    let source = match self {
+      LanguageTag::Grandfathered(tag) => match find_grandfathered(tag) {
+        Some((_, replacement)) => replacement.to_string(),
+        None => return Err(ParserError::InvalidLanguage.into()),
+      },
+      LanguageTag::PrivateUse(pu) => format!("{}{}{}", LANG_UND, SEP, pu),
+      LanguageTag::Langtag(langtag) => {
+        // the extlang is the primary language, e.g. `zh-yue` is `yue`
+        let language = match langtag.extlang.as_ref().and_then(|e| e.first()) {
+          Some(extlang) => extlang,
+          None => &langtag.language,
+        };
+        let mut source = language.to_string();
+        for subtag in langtag.script.iter().chain(langtag.region.iter()) {
+          source.push(SEP);
+          source.push_str(subtag);
+        }
+        for variant in langtag.variants.iter().flatten() {
+          source.push(SEP);
+          source.push_str(variant);
+        }
+        for extension in langtag.extensions.iter().flatten() {
+          source.push_str(&format!("{}{}", SEP, extension));
+        }
+        if let Some(ref pu) = langtag.private_use {
+          source.push_str(&format!("{}{}", SEP, pu));
+        }
+        source
+      }
+    };
+    parse_unicode_locale_id(&source)
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  /// Convert the Unicode Locale Identifier to a BCP 47 language tag.
+  ///
+  /// This follows [Unicode BCP 47 Locale Identifier to BCP 47 Language Tag](https://unicode.org/reports/tr35/#Unicode_Locale_Identifier_CLDR_to_BCP_47):
+  /// the `root` and empty languages become `und`, and `_` separators become `-`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_locale_id;
+  ///
+  /// let tag = parse_locale_id("root_u_ca_buddhist").unwrap().to_bcp47_tag();
+  /// assert_eq!("und-u-ca-buddhist", format!("{}", tag));
+  /// ```
+  pub fn to_bcp47_tag(&self) -> LanguageTag {
+    let language = &self.language;
+    let mut variants: Vec<String> = vec![];
+    for variant in language.variants.iter().flatten() {
+      // BCP 47 does not allow the duplicate variants
+      if !variants.iter().any(|v| v.eq_ignore_ascii_case(variant)) {
+        variants.push(variant.to_string());
+      }
+    }
+
+    let extensions = &self.extensions;
+    let mut other: Vec<OtherExtensions> = vec![];
+    let unicode_locale = extensions
+      .unicode_locale
+      .iter()
+      .flatten()
+      .map(|u| ('u', unicode_locale_subtags(u)));
+    let transformed = extensions
+      .transformed
+      .iter()
+      .flatten()
+      .map(|t| ('t', transformed_subtags(t)));
+    for (extension, values) in unicode_locale.chain(transformed) {
+      if values.is_empty() {
+        continue;
+      }
+      // BCP 47 does not allow the duplicate singletons, so merge them
+      match other.iter_mut().find(|o| o.extension == extension) {
+        Some(o) => o.values.extend(values),
+        None => other.push(OtherExtensions { values, extension }),
+      }
+    }
+    for o in extensions.other.iter().flatten() {
+      other.push(OtherExtensions {
+        values: o.values.clone(),
+        extension: o.extension,
+      });
+    }
+
+    LanguageTag::Langtag(Langtag {
+      language: if language.language.is_empty() {
+        LANG_UND.to_string()
+      } else {
+        language.language.to_string()
+      },
+      extlang: None,
+      script: language.script.map(|script| script.to_string()),
+      region: language.region.map(|region| region.to_string()),
+      variants: if variants.is_empty() {
+        None
+      } else {
+        Some(variants)
+      },
+      extensions: if other.is_empty() { None } else { Some(other) },
+      private_use: extensions
+        .pu
+        .as_ref()
+        .filter(|pu| !pu.values.is_empty())
+        .map(|pu| PuExtensions {
+          values: pu.values.clone(),
+        }),
+    })
+  }
+}
+
+/// The subtags of the unicode locale extensions after the singleton, as the extension subtags of BCP 47.
+fn unicode_locale_subtags(u: &UnicodeLocaleExtensions) -> Vec<Value> {
+  let keywords = u.ufield.iter().flat_map(|(key, values)| {
+    Value::try_from_str(key)
+      .into_iter()
+      .chain(values.iter().copied())
+  });
+  u.attribute.iter().copied().chain(keywords).collect()
+}
+
+/// The subtags of the transformed extensions after the singleton, as the extension subtags of BCP 47.
+fn transformed_subtags(t: &TransformedExtensions) -> Vec<Value> {
+  let mut subtags = vec![];
+  if let Some(tlang) = &t.tlang {
+    let language = match tlang.language.as_str() {
+      "" => LANG_UND,
+      language => language,
+    };
+    let tlang = [language]
+      .into_iter()
+      .chain(tlang.script.as_deref())
+      .chain(tlang.region.as_deref())
+      .chain(
+        tlang
+          .variants
+          .iter()
+          .flatten()
+          .map(|variant| variant.as_str()),
+      );
+    subtags.extend(tlang.filter_map(|subtag| Value::try_from_str(subtag).ok()));
+  }
+  for (key, values) in &t.tfield {
+    subtags.extend(Value::try_from_str(key));
+    subtags.extend(values.iter().copied());
+  }
+  subtags
+}
+
+/// Parse the given string as a BCP 47 language tag.
+///
+/// This function parses according to [`Language-Tag` ABNF defined in BCP 47](https://www.rfc-editor.org/rfc/rfc5646#section-2.1),
+/// including the extlang subtags and the grandfathered tags that [`parse_locale_id`](crate::parse_locale_id) does not accept.
+/// As well as [`GrammarProfile::Bcp47`], the duplicate variants and extension singletons are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_bcp47_tag, LanguageTag};
+///
+/// let tag = parse_bcp47_tag("zh-yue-HK").unwrap();
+/// match tag {
+///   LanguageTag::Langtag(langtag) => {
+///     assert_eq!("zh", langtag.language);
+///     assert_eq!(Some(vec!["yue".to_string()]), langtag.extlang);
+///     assert_eq!(Some("HK".to_string()), langtag.region);
+///   }
+///   _ => unreachable!(),
+/// }
+///
+/// assert_eq!(
+///   LanguageTag::Grandfathered("sgn-BE-FR".to_string()),
+///   parse_bcp47_tag("sgn-be-fr").unwrap()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// This function returns a [`SyntaxError`] with the position of the offending subtag, whose [`SyntaxError::kind`] is:
+///
+/// - [`ParserError::Missing`] if the given tag is empty.
+/// - [`ParserError::InvalidLanguage`] if the given tag does not start with a valid language subtag.
+/// - [`ParserError::InvalidSubtag`] if the given tag has an invalid subtag.
+/// - [`ParserError::InvalidExtension`] if an extension or the private use of the given tag has no subtag.
+/// - [`ParserError::DuplicateVariant`] or [`ParserError::DuplicateExtension`] if the given tag has the same variant or extension singleton more than once.
+/// - [`ParserError::LegacySeparator`], [`ParserError::EmptySubtag`], [`ParserError::LeadingSeparator`], [`ParserError::TrailingSeparator`] or [`ParserError::NonAsciiCharacter`] if the given tag is not separated with `-` properly.
+pub fn parse_bcp47_language_tag(tag: &str) -> Result<LanguageTag, SyntaxError> {
+  parse_bcp47_language_tag_from_str(tag).map_err(|e| e.locate(tag))
+}
+
+fn parse_bcp47_language_tag_from_str(tag: &str) -> Result<LanguageTag, SyntaxError> {
+  // check empty
+  if tag.is_empty() {
+    return Err(ParserError::Missing.into());
+  }
+  let options = ParseOptions {
+    profile: GrammarProfile::Bcp47,
+    ..Default::default()
+  };
+  check_subtags(tag, &mut ParserContext::with_options(&options))?;
+
+  if let Some((grandfathered, _)) = find_grandfathered(tag) {
+    return Ok(LanguageTag::Grandfathered(grandfathered.to_string()));
+  }
+
+  let mut iter = split_str(tag).peekable();
+  if let Some(singleton) = iter.next_if(|subtag| subtag.eq_ignore_ascii_case("x")) {
+    return Ok(LanguageTag::PrivateUse(parse_private_use(
+      singleton, &mut iter,
+    )?));
+  }
+  Ok(LanguageTag::Langtag(parse_langtag(&mut iter)?))
+}
+
+fn find_grandfathered(tag: &str) -> Option<&(&'static str, &'static str)> {
+  GRANDFATHERED
+    .iter()
+    .find(|(grandfathered, _)| grandfathered.eq_ignore_ascii_case(tag))
+}
+
+fn is_language(subtag: &str) -> bool {
+  // 2-3 letters with extlang, 4 letters reserved, or 5-8 letters registered
+  (2..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_extlang(subtag: &str) -> bool {
+  subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_extension_value(subtag: &str) -> bool {
+  (2..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_private_use_value(subtag: &str) -> bool {
+  (1..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn parse_langtag<'a>(
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+) -> Result<Langtag, SyntaxError> {
+  // language
+  let language = iter.next().unwrap_or(Fragment::new(0, ""));
+  if !is_language(&language) {
+    return Err(SyntaxError::at(
+      ParserError::InvalidLanguage,
+      language,
+      Some(Production::Language),
+    ));
+  }
+
+  // extlang, up to 3 subtags after the 2 or 3 letters language
+  let mut extlang = vec![];
+  if language.len() <= 3 {
+    while extlang.len() < 3 {
+      match iter.next_if(|subtag| is_extlang(subtag)) {
+        Some(subtag) => extlang.push(subtag.to_string()),
+        None => break,
+      }
+    }
+  }
+
+  // script
+  let script = iter
+    .next_if(|subtag| script_subtag(subtag).is_ok())
+    .map(|subtag| subtag.to_string());
+
+  // region
+  let region = iter
+    .next_if(|subtag| region_subtag(subtag).is_ok())
+    .map(|subtag| subtag.to_string());
+
+  // variants
+  let mut variants: Vec<String> = vec![];
+  while let Some(subtag) = iter.next_if(|subtag| variant_subtag(subtag).is_ok()) {
+    if variants.iter().any(|v| v.eq_ignore_ascii_case(&subtag)) {
+      return Err(SyntaxError::at(ParserError::DuplicateVariant, subtag, None));
+    }
+    variants.push(subtag.to_string());
+  }
+
+  // extensions and privateuse
+  let mut extensions: Vec<OtherExtensions> = vec![];
+  let mut private_use = None;
+  while let Some(subtag) = iter.next() {
+    match subtag.as_bytes() {
+      [b'x' | b'X'] => private_use = Some(parse_private_use(subtag, iter)?),
+      [singleton] if singleton.is_ascii_alphanumeric() => {
+        let extension = char::from(singleton.to_ascii_lowercase());
+        if extensions.iter().any(|e| e.extension == extension) {
+          return Err(SyntaxError::at(
+            ParserError::DuplicateExtension,
+            subtag,
+            None,
+          ));
+        }
+        let mut values = vec![];
+        while let Some(value) = iter.next_if(|subtag| is_extension_value(subtag)) {
+          values.push(Value::from_str_unchecked(&value));
+        }
+        if values.is_empty() {
+          return Err(match iter.peek() {
+            Some(&value) if value.len() != 1 => SyntaxError::at(
+              ParserError::InvalidSubtag,
+              value,
+              Some(Production::OtherValue),
+            ),
+            _ => SyntaxError::at(ParserError::InvalidExtension, subtag, None),
+          });
+        }
+        extensions.push(OtherExtensions { values, extension });
+      }
+      _ => {
+        let expected = if extensions.is_empty() {
+          Production::Variant
+        } else {
+          Production::Singleton
+        };
+        return Err(SyntaxError::at(
+          ParserError::InvalidSubtag,
+          subtag,
+          Some(expected),
+        ));
+      }
+    }
+  }
+
+  Ok(Langtag {
+    language: language.to_string(),
+    extlang: if extlang.is_empty() {
+      None
+    } else {
+      Some(extlang)
+    },
+    script,
+    region,
+    variants: if variants.is_empty() {
+      None
+    } else {
+      Some(variants)
+    },
+    extensions: if extensions.is_empty() {
+      None
+    } else {
+      Some(extensions)
+    },
+    private_use,
+  })
+}
+
+fn parse_private_use<'a>(
+  singleton: Fragment,
+  iter: &mut impl Iterator<Item = Fragment<'a>>,
+) -> Result<PuExtensions, SyntaxError> {
+  let mut values = vec![];
+  for subtag in iter {
+    if !is_private_use_value(&subtag) {
+      return Err(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(Production::PuValue),
+      ));
+    }
+    values.push(Value::from_str_unchecked(&subtag));
+  }
+  if values.is_empty() {
+    // the missing value after the singleton
+    return Err(SyntaxError::at(
+      ParserError::InvalidExtension,
+      Fragment::new(singleton.end(), ""),
+      Some(Production::PuValue),
+    ));
+  }
+  Ok(PuExtensions { values })
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse_bcp47_language_tag() {
+  // extlang
+  let tag = parse_bcp47_language_tag("zh-yue-HK").unwrap();
+  let LanguageTag::Langtag(langtag) = tag else {
+    panic!("not a langtag");
+  };
+  assert_eq!("zh", langtag.language);
+  assert_eq!(Some(vec!["yue".to_string()]), langtag.extlang);
+  assert_eq!(None, langtag.script);
+  assert_eq!(Some("HK".to_string()), langtag.region);
+
+  // full case
+  let tag =
+    parse_bcp47_language_tag("sl-Latn-IT-rozaj-biske-1994-a-foo-u-ca-buddhist-x-123").unwrap();
+  assert_eq!(
+    "sl-Latn-IT-rozaj-biske-1994-a-foo-u-ca-buddhist-x-123",
+    format!("{}", tag)
+  );
+
+  // grandfathered
+  assert_eq!(
+    LanguageTag::Grandfathered("i-klingon".to_string()),
+    parse_bcp47_language_tag("I-Klingon").unwrap()
+  );
+  assert_eq!(
+    LanguageTag::Grandfathered("en-GB-oed".to_string()),
+    parse_bcp47_language_tag("en-gb-oed").unwrap()
+  );
+
+  // private use
+  assert_eq!(
+    LanguageTag::PrivateUse(PuExtensions {
+      values: vec![Value::try_from_str("whatever").unwrap()]
+    }),
+    parse_bcp47_language_tag("x-whatever").unwrap()
+  );
+
+  // reserved and registered languages
+  assert!(parse_bcp47_language_tag("abcd").is_ok());
+  assert!(parse_bcp47_language_tag("abcdefgh").is_ok());
+
+  // FromStr trait implementation
+  let tag: LanguageTag = "de-CH-1996".parse().unwrap();
+  assert_eq!("de-CH-1996", format!("{}", tag));
+}
+
+#[test]
+fn fail_parse_bcp47_language_tag() {
+  // missing
+  assert_eq!(
+    ParserError::Missing,
+    parse_bcp47_language_tag("").unwrap_err()
+  );
+
+  // invalid language
+  let err = parse_bcp47_language_tag("i-foo").unwrap_err();
+  assert_eq!(ParserError::InvalidLanguage, err.kind());
+  assert_eq!(0..1, err.span());
+
+  // too many extlang
+  let err = parse_bcp47_language_tag("zh-abc-def-ghi-jkl").unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(15..18, err.span());
+
+  // duplicate variant
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_bcp47_language_tag("de-1901-1901").unwrap_err()
+  );
+
+  // duplicate extension
+  assert_eq!(
+    ParserError::DuplicateExtension,
+    parse_bcp47_language_tag("en-a-foo-A-bar").unwrap_err()
+  );
+
+  // empty extension
+  let err = parse_bcp47_language_tag("en-a-b-foo").unwrap_err();
+  assert_eq!(ParserError::InvalidExtension, err.kind());
+  assert_eq!(3..4, err.span());
+
+  // empty private use
+  let err = parse_bcp47_language_tag("en-x").unwrap_err();
+  assert_eq!(ParserError::InvalidExtension, err.kind());
+  assert_eq!(Some(Production::PuValue), err.expected());
+
+  // legacy separator
+  assert_eq!(
+    ParserError::LegacySeparator,
+    parse_bcp47_language_tag("en_US").unwrap_err()
+  );
+}
+
+#[test]
+fn success_to_unicode_locale_id() {
+  let to_unicode = |tag: &str| {
+    format!(
+      "{}",
+      parse_bcp47_language_tag(tag)
+        .unwrap()
+        .to_unicode_locale_id()
+        .unwrap()
+    )
+  };
+  assert_eq!("yue-HK", to_unicode("zh-yue-HK"));
+  assert_eq!("ase", to_unicode("sgn-ase"));
+  assert_eq!("jbo", to_unicode("art-lojban"));
+  assert_eq!("en-GB-oxendict", to_unicode("en-GB-oed"));
+  assert_eq!("sfb", to_unicode("sgn-BE-FR"));
+  assert_eq!("und-x-i-enochian", to_unicode("i-enochian"));
+  assert_eq!("und-x-whatever", to_unicode("x-whatever"));
+  assert_eq!("und-Latn", to_unicode("und-Latn"));
+  assert_eq!(
+    "en-US-u-ca-buddhist-x-foo",
+    to_unicode("en-US-u-ca-buddhist-x-foo")
+  );
+
+  // reserved language is not allowed in Unicode locale identifiers
+  assert_eq!(
+    ParserError::InvalidLanguage,
+    parse_bcp47_language_tag("abcd")
+      .unwrap()
+      .to_unicode_locale_id()
+      .unwrap_err()
+  );
+}
+
+#[test]
+fn success_to_bcp47_tag() {
+  let to_bcp47 = |locale_id: &str| {
+    format!(
+      "{}",
+      parse_unicode_locale_id(locale_id).unwrap().to_bcp47_tag()
+    )
+  };
+  assert_eq!("und", to_bcp47("root"));
+  assert_eq!("und-Latn-DE", to_bcp47("und_Latn_DE"));
+  assert_eq!("de-DE-1996", to_bcp47("de-DE-1996-1996"));
+  assert_eq!(
+    "en-u-ca-buddhist-hc-h12",
+    to_bcp47("en-u-ca-buddhist-u-hc-h12")
+  );
+  assert_eq!(
+    "ja-JP-u-ca-japanese-t-it-a-foo-x-bar",
+    to_bcp47("ja_JP_u_ca_japanese_t_it_a_foo_x_bar")
+  );
+  assert_eq!(
+    "en-u-attr-kn-t-und-latn-h0-hybrid-m0-bgn-ungegn",
+    to_bcp47("en-u-attr-kn-t-und-latn-m0-bgn-ungegn-h0-hybrid")
+  );
+
+  // the keys and the values are kept as the subtags of the extensions
+  let tag = parse_unicode_locale_id("en-u-ca-islamic-civil-t-ja-Jpan-JP-1996-m0-names")
+    .unwrap()
+    .to_bcp47_tag();
+  let LanguageTag::Langtag(langtag) = tag else {
+    panic!("not a langtag");
+  };
+  let extensions = langtag.extensions.unwrap();
+  assert_eq!(vec!["ca", "islamic", "civil"], extensions[0].values);
+  assert_eq!(
+    vec!["ja", "Jpan", "JP", "1996", "m0", "names"],
+    extensions[1].values
+  );
+
+  // round trip
+  let tag = parse_unicode_locale_id("en-US-u-ca-buddhist")
+    .unwrap()
+    .to_bcp47_tag();
+  assert_eq!(tag, parse_bcp47_language_tag(&format!("{}", tag)).unwrap());
+}
@@ -0,0 +1,658 @@
+use crate::constants::{LANG_UND, LEGACY_SEP, SEP};
+use crate::errors::{ParserError, SyntaxError};
+use crate::extensions::other::OtherExtensions;
+use crate::extensions::pu::PuExtensions;
+use crate::extensions::transformed::TransformedExtensions;
+use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
+use crate::extensions::Extensions;
+use crate::lang::UnicodeLanguageIdentifier;
+use crate::locale::{parse_unicode_locale_id, UnicodeLocaleIdentifier};
+use crate::shared::split_str;
+use crate::subtags::{
+  language_subtag, region_subtag, script_subtag, Key, Language, Region, Script, Value, Variant,
+};
+use crate::tokenizer::{tokenize, TokenKind};
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// A borrowed sequence of subtags, e.g. the variants of [`UnicodeLanguageIdentifierRef`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subtags<'a>(&'a str);
+
+impl<'a> Subtags<'a> {
+  /// Returns the subtags as written in the source, with the separators.
+  pub fn as_str(&self) -> &'a str {
+    self.0
+  }
+
+  /// Returns the iterator over the subtags.
+  pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+    split_str(self.0)
+      .filter(|subtag| !subtag.is_empty())
+      .map(|subtag| subtag.text)
+  }
+
+  /// Returns the number of the subtags.
+  pub fn len(&self) -> usize {
+    self.iter().count()
+  }
+
+  /// Returns whether there is no subtag.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  fn to_values(self) -> Vec<Value> {
+    self.iter().map(Value::from_str_unchecked).collect()
+  }
+}
+
+impl fmt::Display for Subtags<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for (i, subtag) in self.iter().enumerate() {
+      if i > 0 {
+        f.write_char(SEP)?;
+      }
+      f.write_str(subtag)?;
+    }
+    Ok(())
+  }
+}
+
+/// The borrowed keywords of unicode locale extensions, or the tfields of transformed extensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fields<'a>(&'a str);
+
+impl<'a> Fields<'a> {
+  /// Returns the iterator over the keys and their values, in the source order.
+  pub fn iter(&self) -> impl Iterator<Item = (&'a str, Subtags<'a>)> {
+    let mut rest = self.0;
+    std::iter::from_fn(move || {
+      if rest.is_empty() {
+        return None;
+      }
+      let (key, values) = split_first(rest);
+      let (values, next) = split_before(values, |subtag| subtag.len() == 2);
+      rest = next;
+      Some((key, Subtags(values)))
+    })
+  }
+
+  /// Returns the values of the first occurrence of the given key.
+  pub fn get(&self, key: &str) -> Option<Subtags<'a>> {
+    self
+      .iter()
+      .find(|(k, _)| *k == key)
+      .map(|(_, values)| values)
+  }
+
+  /// Returns whether there is no key.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Write the fields sorted by the key, and the values of a repeated key merged, as [`BTreeMap`] of the owned types.
+  fn write_sorted(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut last: Option<&str> = None;
+    while let Some(key) = self
+      .iter()
+      .map(|(key, _)| key)
+      .filter(|key| last.map_or(true, |last| *key > last))
+      .min()
+    {
+      f.write_char(SEP)?;
+      f.write_str(key)?;
+      for (_, values) in self.iter().filter(|(k, _)| *k == key) {
+        for value in values.iter() {
+          f.write_char(SEP)?;
+          f.write_str(value)?;
+        }
+      }
+      last = Some(key);
+    }
+    Ok(())
+  }
+
+  fn to_owned_map(self) -> BTreeMap<Key, Vec<Value>> {
+    let mut map: BTreeMap<Key, Vec<Value>> = BTreeMap::new();
+    for (key, values) in self.iter() {
+      map
+        .entry(Key::from_str_unchecked(key))
+        .or_default()
+        .extend(values.iter().map(Value::from_str_unchecked));
+    }
+    map
+  }
+}
+
+/// The borrowed counterpart of [`UnicodeLanguageIdentifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeLanguageIdentifierRef<'a> {
+  pub language: &'a str,
+  pub script: Option<&'a str>,
+  pub region: Option<&'a str>,
+  pub variants: Option<Subtags<'a>>,
+}
+
+impl<'a> UnicodeLanguageIdentifierRef<'a> {
+  /// Make the view of a well-formed `unicode_language_id`.
+  fn from_source(source: &'a str) -> Self {
+    let (language, mut rest) = split_first(source);
+    let language = language_subtag(language).unwrap_or(language);
+    let mut script = None;
+    let mut region = None;
+    if let Some((subtag, next)) = Some(split_first(rest)).filter(|(s, _)| script_subtag(s).is_ok())
+    {
+      script = Some(subtag);
+      rest = next;
+    }
+    if let Some((subtag, next)) = Some(split_first(rest)).filter(|(s, _)| region_subtag(s).is_ok())
+    {
+      region = Some(subtag);
+      rest = next;
+    }
+    UnicodeLanguageIdentifierRef {
+      language,
+      script,
+      region,
+      variants: Some(Subtags(rest)).filter(|v| !v.is_empty()),
+    }
+  }
+
+  /// Returns the iterator over the variants, without the repeated ones as [`UnicodeLanguageIdentifier`].
+  fn unique_variants(self) -> impl Iterator<Item = &'a str> {
+    let mut previous = None;
+    self
+      .variants
+      .into_iter()
+      .flat_map(|v| v.iter())
+      .filter(move |variant| previous.replace(*variant) != Some(*variant))
+  }
+
+  /// Convert to the owned [`UnicodeLanguageIdentifier`].
+  pub fn to_owned(&self) -> UnicodeLanguageIdentifier {
+    let variants: Vec<Variant> = self
+      .unique_variants()
+      .map(Variant::from_str_unchecked)
+      .collect();
+    UnicodeLanguageIdentifier {
+      language: Language::from_str_unchecked(self.language),
+      script: self.script.map(Script::from_str_unchecked),
+      region: self.region.map(Region::from_str_unchecked),
+      variants: Some(variants).filter(|v| !v.is_empty()),
+    }
+  }
+}
+
+impl fmt::Display for UnicodeLanguageIdentifierRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.language.is_empty() {
+      f.write_str(LANG_UND)?;
+    } else {
+      f.write_str(self.language)?;
+    }
+    for subtag in self.script.iter().chain(self.region.iter()) {
+      f.write_char(SEP)?;
+      f.write_str(subtag)?;
+    }
+    for variant in self.unique_variants() {
+      f.write_char(SEP)?;
+      f.write_str(variant)?;
+    }
+    Ok(())
+  }
+}
+
+/// The borrowed counterpart of [`UnicodeLocaleExtensions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeLocaleExtensionsRef<'a> {
+  pub attribute: Subtags<'a>,
+  pub ufield: Fields<'a>,
+}
+
+impl<'a> UnicodeLocaleExtensionsRef<'a> {
+  fn from_body(body: &'a str) -> Self {
+    let (attribute, ufield) = split_before(body, |subtag| subtag.len() == 2);
+    UnicodeLocaleExtensionsRef {
+      attribute: Subtags(attribute),
+      ufield: Fields(ufield),
+    }
+  }
+
+  /// Convert to the owned [`UnicodeLocaleExtensions`].
+  pub fn to_owned(&self) -> UnicodeLocaleExtensions {
+    UnicodeLocaleExtensions {
+      attribute: self.attribute.to_values(),
+      ufield: self.ufield.to_owned_map(),
+    }
+  }
+}
+
+impl fmt::Display for UnicodeLocaleExtensionsRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_char('u')?;
+    for attribute in self.attribute.iter() {
+      f.write_char(SEP)?;
+      f.write_str(attribute)?;
+    }
+    self.ufield.write_sorted(f)
+  }
+}
+
+/// The borrowed counterpart of [`TransformedExtensions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformedExtensionsRef<'a> {
+  pub tlang: Option<UnicodeLanguageIdentifierRef<'a>>,
+  pub tfield: Fields<'a>,
+}
+
+impl<'a> TransformedExtensionsRef<'a> {
+  fn from_body(body: &'a str) -> Self {
+    // tkey is a letter and a digit, unlike the subtags of tlang
+    let (tlang, tfield) = split_before(body, |subtag| {
+      subtag.len() == 2 && subtag.as_bytes()[1].is_ascii_digit()
+    });
+    TransformedExtensionsRef {
+      tlang: Some(tlang)
+        .filter(|tlang| !tlang.is_empty())
+        .map(UnicodeLanguageIdentifierRef::from_source),
+      tfield: Fields(tfield),
+    }
+  }
+
+  /// Convert to the owned [`TransformedExtensions`].
+  pub fn to_owned(&self) -> TransformedExtensions {
+    TransformedExtensions {
+      tlang: self.tlang.map(|tlang| tlang.to_owned()),
+      tfield: self.tfield.to_owned_map(),
+    }
+  }
+}
+
+impl fmt::Display for TransformedExtensionsRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_char('t')?;
+    if let Some(tlang) = self.tlang {
+      write!(f, "{}{}", SEP, tlang)?;
+    }
+    self.tfield.write_sorted(f)
+  }
+}
+
+/// The borrowed counterpart of [`OtherExtensions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtherExtensionsRef<'a> {
+  pub values: Subtags<'a>,
+  pub extension: char,
+}
+
+impl OtherExtensionsRef<'_> {
+  /// Convert to the owned [`OtherExtensions`].
+  pub fn to_owned(&self) -> OtherExtensions {
+    OtherExtensions {
+      values: self.values.to_values(),
+      extension: self.extension,
+    }
+  }
+}
+
+impl fmt::Display for OtherExtensionsRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_char(self.extension)?;
+    for value in self.values.iter() {
+      f.write_char(SEP)?;
+      f.write_str(value)?;
+    }
+    Ok(())
+  }
+}
+
+/// The borrowed counterpart of [`PuExtensions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PuExtensionsRef<'a> {
+  pub values: Subtags<'a>,
+}
+
+impl PuExtensionsRef<'_> {
+  /// Convert to the owned [`PuExtensions`].
+  pub fn to_owned(&self) -> PuExtensions {
+    PuExtensions {
+      values: self.values.to_values(),
+    }
+  }
+}
+
+impl fmt::Display for PuExtensionsRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_char('x')?;
+    for value in self.values.iter() {
+      f.write_char(SEP)?;
+      f.write_str(value)?;
+    }
+    Ok(())
+  }
+}
+
+/// The borrowed counterpart of [`Extensions`].
+///
+/// The extensions are found lazily from the source, so the accessors are the methods returning iterators instead of the fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtensionsRef<'a>(&'a str);
+
+impl<'a> ExtensionsRef<'a> {
+  /// Returns the iterator over the singletons and the bodies of the extensions, in the source order.
+  fn iter(&self) -> impl Iterator<Item = (char, &'a str)> {
+    let mut rest = self.0;
+    std::iter::from_fn(move || {
+      if rest.is_empty() {
+        return None;
+      }
+      let (singleton, body) = split_first(rest);
+      let singleton = char::from(singleton.as_bytes().first()?.to_ascii_lowercase());
+      let (body, next) = if singleton == 'x' {
+        (body, "")
+      } else {
+        split_before(body, |subtag| subtag.len() == 1)
+      };
+      rest = next;
+      Some((singleton, body))
+    })
+  }
+
+  /// Returns the unicode locale extensions.
+  pub fn unicode_locale(&self) -> impl Iterator<Item = UnicodeLocaleExtensionsRef<'a>> {
+    self
+      .iter()
+      .filter(|(singleton, _)| *singleton == 'u')
+      .map(|(_, body)| UnicodeLocaleExtensionsRef::from_body(body))
+  }
+
+  /// Returns the transformed extensions.
+  pub fn transformed(&self) -> impl Iterator<Item = TransformedExtensionsRef<'a>> {
+    self
+      .iter()
+      .filter(|(singleton, _)| *singleton == 't')
+      .map(|(_, body)| TransformedExtensionsRef::from_body(body))
+  }
+
+  /// Returns the other extensions.
+  pub fn other(&self) -> impl Iterator<Item = OtherExtensionsRef<'a>> {
+    self
+      .iter()
+      .filter(|(singleton, _)| !matches!(singleton, 'u' | 't' | 'x'))
+      .map(|(extension, body)| OtherExtensionsRef {
+        values: Subtags(body),
+        extension,
+      })
+  }
+
+  /// Returns the private use extensions.
+  pub fn pu(&self) -> Option<PuExtensionsRef<'a>> {
+    self
+      .iter()
+      .find(|(singleton, _)| *singleton == 'x')
+      .map(|(_, body)| PuExtensionsRef {
+        values: Subtags(body),
+      })
+  }
+
+  /// Returns whether there is no extension.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Convert to the owned [`Extensions`].
+  pub fn to_owned(&self) -> Extensions {
+    let unicode_locale: Vec<UnicodeLocaleExtensions> =
+      self.unicode_locale().map(|u| u.to_owned()).collect();
+    let transformed: Vec<TransformedExtensions> =
+      self.transformed().map(|t| t.to_owned()).collect();
+    let other: Vec<OtherExtensions> = self.other().map(|o| o.to_owned()).collect();
+    Extensions {
+      unicode_locale: Some(unicode_locale).filter(|u| !u.is_empty()),
+      transformed: Some(transformed).filter(|t| !t.is_empty()),
+      other: Some(other).filter(|o| !o.is_empty()),
+      pu: self.pu().map(|pu| pu.to_owned()),
+    }
+  }
+}
+
+impl fmt::Display for ExtensionsRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    // same order as `Extensions`
+    let mut first = true;
+    let mut sep = |f: &mut fmt::Formatter| {
+      if first {
+        first = false;
+        Ok(())
+      } else {
+        f.write_char(SEP)
+      }
+    };
+    for u in self.unicode_locale() {
+      sep(f)?;
+      u.fmt(f)?;
+    }
+    for t in self.transformed() {
+      sep(f)?;
+      t.fmt(f)?;
+    }
+    for o in self.other() {
+      sep(f)?;
+      o.fmt(f)?;
+    }
+    if let Some(pu) = self.pu() {
+      sep(f)?;
+      pu.fmt(f)?;
+    }
+    Ok(())
+  }
+}
+
+/// The borrowed counterpart of [`UnicodeLocaleIdentifier`], which holds the slices of the source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeLocaleIdentifierRef<'a> {
+  pub language: UnicodeLanguageIdentifierRef<'a>,
+  pub extensions: ExtensionsRef<'a>,
+}
+
+impl UnicodeLocaleIdentifierRef<'_> {
+  /// Convert to the owned [`UnicodeLocaleIdentifier`].
+  pub fn to_owned(&self) -> UnicodeLocaleIdentifier {
+    UnicodeLocaleIdentifier {
+      language: self.language.to_owned(),
+      extensions: self.extensions.to_owned(),
+    }
+  }
+}
+
+impl fmt::Display for UnicodeLocaleIdentifierRef<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    self.language.fmt(f)?;
+    if !self.extensions.is_empty() {
+      write!(f, "{}{}", SEP, self.extensions)?;
+    }
+    Ok(())
+  }
+}
+
+/// Parse the given string as a borrowed Unicode Locale Identifier, without allocation.
+///
+/// This function accepts the same identifiers as [`parse_locale_id`](crate::parse_locale_id), and the view has the same `Display`.
+/// Only on failure, the source is parsed again to report the error.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::parse_locale_id_ref;
+///
+/// let locale = parse_locale_id_ref("en-US-u-hc-h12").unwrap();
+/// assert_eq!("en", locale.language.language);
+/// assert_eq!(Some("US"), locale.language.region);
+/// let u = locale.extensions.unicode_locale().next().unwrap();
+/// assert_eq!("h12", u.ufield.get("hc").unwrap().as_str());
+///
+/// // convert to the owned types
+/// let owned = locale.to_owned();
+/// assert_eq!(Some("US"), owned.language.region.as_deref());
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_locale_id`](crate::parse_locale_id).
+pub fn parse_unicode_locale_id_ref(
+  locale_id: &str,
+) -> Result<UnicodeLocaleIdentifierRef<'_>, SyntaxError> {
+  let fail = || {
+    Err(
+      parse_unicode_locale_id(locale_id)
+        .err()
+        .unwrap_or_else(|| ParserError::Unexpected.into()),
+    )
+  };
+
+  let mut language_end = 0;
+  let mut extensions_start = None;
+  let mut tkey = false;
+  let mut singleton = false;
+  for token in tokenize(locale_id) {
+    match token.kind {
+      TokenKind::Invalid => return fail(),
+      // a tkey must have a tvalue
+      TokenKind::TKey | TokenKind::Singleton(_) if tkey => return fail(),
+      // a singleton must have a subtag
+      TokenKind::Singleton(_) if singleton => return fail(),
+      TokenKind::TKey => tkey = true,
+      TokenKind::TValue => tkey = false,
+      _ => {}
+    }
+    singleton = matches!(token.kind, TokenKind::Singleton(_));
+    if singleton {
+      extensions_start.get_or_insert(token.span.start);
+    }
+    if extensions_start.is_none() {
+      language_end = token.span.end;
+    }
+  }
+  if locale_id.is_empty() || tkey || singleton {
+    return fail();
+  }
+
+  let (language, extensions) = match extensions_start {
+    Some(start) => (locale_id.get(..language_end), locale_id.get(start..)),
+    None => (Some(locale_id), Some("")),
+  };
+  match (language, extensions) {
+    (Some(language), Some(extensions)) => Ok(UnicodeLocaleIdentifierRef {
+      language: UnicodeLanguageIdentifierRef::from_source(language),
+      extensions: ExtensionsRef(extensions),
+    }),
+    _ => fail(),
+  }
+}
+
+/// Split the first subtag, and the rest after the separator.
+fn split_first(source: &str) -> (&str, &str) {
+  match source.find(|c| c == SEP || c == LEGACY_SEP) {
+    Some(i) => (&source[..i], &source[i + 1..]),
+    None => (source, ""),
+  }
+}
+
+/// Split before the first subtag matching the predicate, without the separator between.
+fn split_before(source: &str, predicate: impl Fn(&str) -> bool) -> (&str, &str) {
+  for subtag in split_str(source) {
+    if predicate(&subtag) {
+      return (&source[..subtag.start.max(1) - 1], &source[subtag.start..]);
+    }
+  }
+  (source, "")
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse_unicode_locale_id_ref() {
+  // basic
+  let locale = parse_unicode_locale_id_ref("ja-Latn-JP-macos-1996").unwrap();
+  assert_eq!("ja", locale.language.language);
+  assert_eq!(Some("Latn"), locale.language.script);
+  assert_eq!(Some("JP"), locale.language.region);
+  assert_eq!(
+    vec!["macos", "1996"],
+    locale
+      .language
+      .variants
+      .unwrap()
+      .iter()
+      .collect::<Vec<&str>>()
+  );
+  assert!(locale.extensions.is_empty());
+
+  // extensions
+  let locale =
+    parse_unicode_locale_id_ref("en_U_attr1_kz_value2_t_en_Latn_m0_ungegn_a_vue_x_foo_u").unwrap();
+  let u = locale.extensions.unicode_locale().next().unwrap();
+  assert_eq!("attr1", u.attribute.as_str());
+  assert_eq!(Some(Subtags("value2")), u.ufield.get("kz"));
+  let t = locale.extensions.transformed().next().unwrap();
+  assert_eq!("en-Latn", format!("{}", t.tlang.unwrap()));
+  assert_eq!("ungegn", t.tfield.get("m0").unwrap().as_str());
+  let a = locale.extensions.other().next().unwrap();
+  assert_eq!('a', a.extension);
+  assert_eq!(
+    vec!["foo", "u"],
+    locale
+      .extensions
+      .pu()
+      .unwrap()
+      .values
+      .iter()
+      .collect::<Vec<&str>>()
+  );
+
+  // same Display and conversion as the owned types
+  for source in [
+    "en",
+    "root",
+    "und-Latn",
+    "en-1996-1996",
+    "en-u-ca",
+    "en-t-en-1996-1996-m0-foo",
+    "en-a-foo-u-bar-a-baz",
+    "en-u-nu-thai-ca-buddhist-ca-chinese-u-attr",
+    "ja-Latn-JP-macos-U-attr1-kz-value2-t-en-Latn-US-linux-t1-value1-value2-a-vue-rust-x-foo-123",
+  ] {
+    let owned = format!("{}", parse_unicode_locale_id(source).unwrap());
+    let locale = parse_unicode_locale_id_ref(source).unwrap();
+    assert_eq!(owned, format!("{}", locale), "{}", source);
+    assert_eq!(owned, format!("{}", locale.to_owned()), "{}", source);
+  }
+}
+
+#[test]
+fn fail_parse_unicode_locale_id_ref() {
+  for source in [
+    "",
+    "x-foo",
+    "en-",
+    "en--US",
+    "en-t-m0",
+    "en-t-m0-u-ca",
+    "en-x",
+    "en-u",
+    "en-t",
+    "en-US-foo",
+    "日本",
+  ] {
+    assert_eq!(
+      parse_unicode_locale_id(source).unwrap_err(),
+      parse_unicode_locale_id_ref(source).unwrap_err(),
+      "{}",
+      source
+    );
+  }
+}
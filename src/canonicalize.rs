@@ -0,0 +1,387 @@
+use crate::constants::{LANG_UND, SEP};
+use crate::lang::{parse_unicode_language_id, UnicodeLanguageIdentifier};
+use crate::locale::UnicodeLocaleIdentifier;
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+use crate::subtags::{Key, Region, Script, Value, Variant};
+
+use std::collections::BTreeMap;
+use std::iter::once;
+
+// the aliases may not be cyclic, but stop anyway
+const MAX_REPLACEMENTS: usize = 32;
+
+impl UnicodeLanguageIdentifier {
+  /// Canonicalize with the alias data of CLDR, following [the algorithm of UTS #35 Annex C](https://unicode.org/reports/tr35/#LocaleId_Canonicalization).
+  ///
+  /// After [`UnicodeLanguageIdentifier::canonicalize_syntax`], the language, script, region and variant aliases are replaced until none of them matches.
+  /// If a region has several replacements, the one of the likely subtags of the language and the script is chosen, or the first one.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let mut lang = parse_language_id("iw-BU").unwrap();
+  /// lang.canonicalize();
+  /// assert_eq!("he-MM", format!("{}", lang));
+  ///
+  /// let mut lang = parse_language_id("hy-SU").unwrap();
+  /// lang.canonicalize();
+  /// assert_eq!("hy-AM", format!("{}", lang));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn canonicalize(&mut self) {
+    self.canonicalize_with(&EmbeddedDataProvider);
+  }
+
+  /// Canonicalize with the alias data of the given provider, as [`UnicodeLanguageIdentifier::canonicalize`].
+  pub fn canonicalize_with(&mut self, provider: &dyn LocaleDataProvider) {
+    self.canonicalize_syntax();
+    for _ in 0..MAX_REPLACEMENTS {
+      if !self.replace_alias(provider) {
+        break;
+      }
+    }
+  }
+
+  /// Replace the first alias that matches, and returns whether it is replaced.
+  fn replace_alias(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    if let Some((rule, replacement)) = self.find_language_alias(provider) {
+      self.apply_language_alias(&rule, &replacement);
+      return true;
+    }
+
+    if let Some(script) = self.script {
+      if let Some(replacement) = provider.lookup(DataTable::ScriptAliases, &script) {
+        self.script = Script::try_from_str(replacement).ok();
+        return true;
+      }
+    }
+
+    if let Some(region) = self.region {
+      if let Some(replacement) = provider.lookup(DataTable::RegionAliases, &region) {
+        self.region = self.preferred_region(replacement, provider);
+        return true;
+      }
+    }
+
+    let variants = self.variants.as_deref().unwrap_or_default();
+    let alias = variants.iter().enumerate().find_map(|(index, variant)| {
+      Some((index, provider.lookup(DataTable::VariantAliases, variant)?))
+    });
+    if let Some((index, replacement)) = alias {
+      if let (Some(variants), Ok(replacement)) =
+        (&mut self.variants, Variant::try_from_str(replacement))
+      {
+        variants[index] = replacement;
+        variants.sort();
+        variants.dedup();
+        return true;
+      }
+    }
+
+    false
+  }
+
+  /// Find the language alias rule, in the order of the rules that have more subtags.
+  fn find_language_alias(
+    &self,
+    provider: &dyn LocaleDataProvider,
+  ) -> Option<(UnicodeLanguageIdentifier, UnicodeLanguageIdentifier)> {
+    let language = if self.language.is_empty() {
+      LANG_UND
+    } else {
+      &self.language
+    };
+    let variants = self.variants.as_deref().unwrap_or_default();
+
+    let mut types = vec![];
+    for (i, first) in variants.iter().enumerate() {
+      for second in &variants[i + 1..] {
+        types.push(format!("{}{}{}{}{}", language, SEP, first, SEP, second));
+        types.push(format!("{}{}{}{}{}", LANG_UND, SEP, first, SEP, second));
+      }
+    }
+    for variant in variants {
+      types.push(format!("{}{}{}", language, SEP, variant));
+      types.push(format!("{}{}{}", LANG_UND, SEP, variant));
+    }
+    if let Some(region) = self.region {
+      types.push(format!("{}{}{}", language, SEP, region));
+    }
+    types.push(language.to_string());
+
+    types.iter().find_map(|rule| {
+      let replacement = provider.lookup(DataTable::LanguageAliases, rule)?;
+      Some((
+        parse_unicode_language_id(rule).ok()?,
+        parse_unicode_language_id(replacement).ok()?,
+      ))
+    })
+  }
+
+  fn apply_language_alias(
+    &mut self,
+    rule: &UnicodeLanguageIdentifier,
+    replacement: &UnicodeLanguageIdentifier,
+  ) {
+    // the subtags in the rule are replaced, and the others are only filled if missing
+    if !rule.language.is_empty() || self.language.is_empty() {
+      self.language = replacement.language;
+    }
+    if rule.script.is_some() || self.script.is_none() {
+      self.script = replacement.script;
+    }
+    if rule.region.is_some() || self.region.is_none() {
+      self.region = replacement.region;
+    }
+
+    let mut variants = self.variants.take().unwrap_or_default();
+    if let Some(removed) = &rule.variants {
+      variants.retain(|variant| !removed.contains(variant));
+    }
+    if let Some(added) = &replacement.variants {
+      variants.extend(added);
+    }
+    variants.sort();
+    variants.dedup();
+    self.variants = Some(variants).filter(|variants| !variants.is_empty());
+  }
+
+  /// Choose the region of the replacements, with the likely subtags of the language and the script.
+  fn preferred_region(
+    &self,
+    replacement: &str,
+    provider: &dyn LocaleDataProvider,
+  ) -> Option<Region> {
+    // the likely subtags of the language and the script
+    let likely = UnicodeLanguageIdentifier {
+      language: self.language,
+      script: self.script,
+      region: None,
+      variants: None,
+    }
+    .likely_subtags(provider)
+    .and_then(|likely| likely.region);
+    let region = replacement
+      .split(' ')
+      .find(|region| likely.is_some_and(|likely| likely == *region))
+      .or_else(|| replacement.split(' ').next())?;
+    Region::try_from_str(region).ok()
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  /// Canonicalize with the alias data of CLDR, following [the algorithm of UTS #35 Annex C](https://unicode.org/reports/tr35/#LocaleId_Canonicalization).
+  ///
+  /// The language and the `tlang` of the transformed extensions are canonicalized as [`UnicodeLanguageIdentifier::canonicalize`].
+  /// The values of the unicode locale and transformed extension keys are replaced with the alias data of BCP 47 in CLDR, and the subdivisions of `rg` and `sd` with the subdivision aliases.
+  /// Finally, the extensions are normalized as [`UnicodeLocaleIdentifier::canonicalize_syntax`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_locale_id;
+  ///
+  /// let mut locale = parse_locale_id("sh-YU-u-CA-gregory").unwrap();
+  /// locale.canonicalize();
+  /// assert_eq!("sr-Latn-RS-u-ca-gregory", format!("{}", locale));
+  ///
+  /// let mut locale = parse_locale_id("ja-u-ca-ethiopic-amete-alem-kb-yes-t-iw-m0-names").unwrap();
+  /// locale.canonicalize();
+  /// assert_eq!("ja-u-ca-ethioaa-kb-t-he-m0-prprname", format!("{}", locale));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn canonicalize(&mut self) {
+    self.canonicalize_with(&EmbeddedDataProvider);
+  }
+
+  /// Canonicalize with the alias data of the given provider, as [`UnicodeLocaleIdentifier::canonicalize`].
+  pub fn canonicalize_with(&mut self, provider: &dyn LocaleDataProvider) {
+    self.canonicalize_syntax();
+    self.language.canonicalize_with(provider);
+
+    if let Some(unicode_locale) = &mut self.extensions.unicode_locale {
+      for u in unicode_locale.iter_mut() {
+        replace_keyword_aliases(&mut u.ufield, provider, DataTable::UnicodeKeywordAliases);
+        for key in ["rg", "sd"] {
+          if let Some(values) = u.ufield.get_mut(key) {
+            replace_subdivision_alias(values, provider);
+          }
+        }
+      }
+    }
+    if let Some(transformed) = &mut self.extensions.transformed {
+      for t in transformed.iter_mut() {
+        if let Some(tlang) = &mut t.tlang {
+          tlang.canonicalize_with(provider);
+        }
+        replace_keyword_aliases(
+          &mut t.tfield,
+          provider,
+          DataTable::TransformedKeywordAliases,
+        );
+      }
+    }
+
+    // the replaced values may be `true`, and the `tlang` is lowercased
+    self.extensions.canonicalize_syntax();
+  }
+}
+
+fn replace_keyword_aliases(
+  fields: &mut BTreeMap<Key, Vec<Value>>,
+  provider: &dyn LocaleDataProvider,
+  aliases: DataTable,
+) {
+  for (key, values) in fields.iter_mut() {
+    let keyword = once(key.as_str())
+      .chain(values.iter().map(|value| value.as_str()))
+      .collect::<Vec<_>>()
+      .join(&SEP.to_string());
+    if let Some(replacement) = provider.lookup(aliases, &keyword) {
+      *values = replacement
+        .split(SEP)
+        .filter_map(|value| Value::try_from_str(value).ok())
+        .collect();
+    }
+  }
+}
+
+fn replace_subdivision_alias(values: &mut Vec<Value>, provider: &dyn LocaleDataProvider) {
+  let [subdivision] = values.as_slice() else {
+    return;
+  };
+  let Some(replacement) = provider.lookup(DataTable::SubdivisionAliases, subdivision) else {
+    return;
+  };
+  // the first replacement is the default, and a region is the whole of the region
+  let replacement = replacement.split(' ').next().unwrap_or_default();
+  let replacement = if replacement.len() <= 3 {
+    format!("{}zzzz", replacement.to_ascii_lowercase())
+  } else {
+    replacement.to_string()
+  };
+  if let Ok(value) = Value::try_from_str(&replacement) {
+    *values = vec![value];
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[cfg(feature = "embedded-data")]
+#[allow(dead_code)] // for unit tests
+fn canonicalize(source: &str) -> String {
+  let mut lang = parse_unicode_language_id(source).unwrap();
+  lang.canonicalize();
+  format!("{}", lang)
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_canonicalize_language_alias() {
+  // legacy
+  assert_eq!("he", canonicalize("iw"));
+  assert_eq!("id-ID", canonicalize("in-ID"));
+  assert_eq!("sr-Latn", canonicalize("sh"));
+  assert_eq!("sr-Cyrl", canonicalize("sh-Cyrl"));
+
+  // overlong and bibliographic
+  assert_eq!("en-US", canonicalize("eng-US"));
+  assert_eq!("de", canonicalize("ger"));
+
+  // macrolanguage
+  assert_eq!("zh-Hant-TW", canonicalize("cmn-Hant-TW"));
+
+  // with region or variants
+  assert_eq!("bzs", canonicalize("sgn-BR"));
+  assert_eq!("jbo", canonicalize("art-lojban"));
+  assert_eq!("hak-TW", canonicalize("zh-TW-hakka"));
+  assert_eq!("sv-AX", canonicalize("sv-aaland"));
+  assert_eq!("ja-Latn-alalc97", canonicalize("ja-Latn-hepburn-heploc"));
+  assert_eq!("sr-ME", canonicalize("cnr"));
+  assert_eq!("sr-Latn-ME", canonicalize("cnr-Latn"));
+  assert_eq!("sr-Latn-RS", canonicalize("sh-RS"));
+  assert_eq!("sr-Latn-BA", canonicalize("cnr-Latn-BA"));
+
+  // deprecated
+  assert_eq!("aas", canonicalize("aam"));
+  assert_eq!("aeb-TN", canonicalize("ajt-TN"));
+  assert_eq!("sr-Latn-RS", canonicalize("hbs-RS"));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_canonicalize_subtag_alias() {
+  // script
+  assert_eq!("und-Zinh", canonicalize("und-Qaai"));
+
+  // region
+  assert_eq!("my-MM", canonicalize("my-BU"));
+  assert_eq!("de-DE", canonicalize("de-DD"));
+  assert_eq!("en-US", canonicalize("en-840"));
+
+  // region with several replacements
+  assert_eq!("ru-RU", canonicalize("ru-SU"));
+  assert_eq!("uk-UA", canonicalize("uk-SU"));
+  assert_eq!("en-RU", canonicalize("en-SU"));
+  assert_eq!("sr-ME", canonicalize("sr-ME"));
+  assert_eq!("sr-RS", canonicalize("sr-CS"));
+  assert_eq!("sr-Latn-RS", canonicalize("sr-Latn-YU"));
+
+  // variant
+  assert_eq!("el-polyton", canonicalize("el-polytoni"));
+  assert_eq!("ja-alalc97", canonicalize("ja-heploc"));
+
+  // multiple aliases, and the syntax
+  assert_eq!("he-IL-1994-polyton", canonicalize("IW-il-POLYTONI-1994"));
+  assert_eq!("en-US", canonicalize("en-US"));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_canonicalize_extensions() {
+  let canonicalize = |source: &str| {
+    let mut locale = crate::locale::parse_unicode_locale_id(source).unwrap();
+    locale.canonicalize();
+    format!("{}", locale)
+  };
+
+  // unicode locale extension keys
+  assert_eq!("en-u-ca-islamic-civil", canonicalize("en-u-ca-islamicc"));
+  assert_eq!(
+    "en-u-ca-ethioaa",
+    canonicalize("en-u-ca-ethiopic-amete-alem")
+  );
+  assert_eq!("en-GB-u-ms-uksystem", canonicalize("en-GB-u-ms-imperial"));
+  assert_eq!("en-NZ-u-tz-nzakl", canonicalize("en-NZ-u-tz-aqams"));
+  assert_eq!("de-u-kb-ks-level1", canonicalize("de-u-kb-yes-ks-primary"));
+
+  // subdivisions
+  assert_eq!("fr-u-sd-frges", canonicalize("fr-u-sd-fra"));
+  assert_eq!("en-u-rg-cnbj", canonicalize("en-u-rg-cn11"));
+  assert_eq!("sv-u-sd-axzzzz", canonicalize("sv-u-sd-fi01"));
+  assert_eq!("lb-u-sd-lucl", canonicalize("lb-u-sd-lud"));
+
+  // transformed extensions
+  assert_eq!(
+    "ja-Latn-t-ja-hani-m0-prprname",
+    canonicalize("ja-Latn-t-ja-Hani-m0-names")
+  );
+  assert_eq!(
+    "am-t-am-latn-m0-betamets",
+    canonicalize("am-t-am-latn-m0-beta-metsehaf")
+  );
+  assert_eq!("en-t-he-latn-ru", canonicalize("en-t-iw-Latn-SU"));
+
+  // the equivalent values are equal after the canonicalization
+  let mut islamicc = crate::locale::parse_unicode_locale_id("en-u-ca-islamicc").unwrap();
+  let mut islamic_civil = crate::locale::parse_unicode_locale_id("en-u-CA-islamic-civil").unwrap();
+  islamicc.canonicalize();
+  islamic_civil.canonicalize();
+  assert_eq!(islamicc, islamic_civil);
+}
@@ -0,0 +1,116 @@
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+use crate::subtags::Region;
+
+// the macroregion containing all the regions
+const REGION_WORLD: &str = "001";
+
+impl Region {
+  /// Whether the macroregion contains the region, directly or through the other macroregions, with the territory containment data of CLDR.
+  ///
+  /// The [territory containment](https://unicode.org/reports/tr35/tr35-info.html#Territory_Containment_Data) without the groupings (e.g. `EU` and `419`) and the deprecated ones is followed, so that each region is contained in one region.
+  /// A region does not contain itself.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::Region;
+  ///
+  /// let europe = Region::try_from_str("150").unwrap();
+  /// assert!(europe.contains(&Region::try_from_str("DE").unwrap()));
+  /// assert!(!europe.contains(&Region::try_from_str("JP").unwrap()));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn contains(&self, region: &Region) -> bool {
+    self.contains_with(region, &EmbeddedDataProvider)
+  }
+
+  /// Whether the macroregion contains the region, with the territory containment data of the given provider, as [`Region::contains`].
+  pub fn contains_with(&self, region: &Region, provider: &dyn LocaleDataProvider) -> bool {
+    self.path_to(region, provider).is_some()
+  }
+
+  /// Returns the macroregions containing the region, from the nearest one to the world `001`, with the territory containment data of CLDR.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("de-DE").unwrap();
+  /// let regions = lang.region.unwrap().containing_regions();
+  /// assert_eq!(vec!["155", "150", "001"], regions.iter().map(|r| r.as_str()).collect::<Vec<_>>());
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn containing_regions(&self) -> Vec<Region> {
+    self.containing_regions_with(&EmbeddedDataProvider)
+  }
+
+  /// Returns the macroregions containing the region, with the territory containment data of the given provider, as [`Region::containing_regions`].
+  pub fn containing_regions_with(&self, provider: &dyn LocaleDataProvider) -> Vec<Region> {
+    let world = Region::from_str_unchecked(REGION_WORLD);
+    let mut regions = world.path_to(self, provider).unwrap_or_default();
+    regions.reverse();
+    regions
+  }
+
+  /// Find the macroregions from this one to the one directly containing the region, or `None` if the region is not contained.
+  fn path_to(&self, region: &Region, provider: &dyn LocaleDataProvider) -> Option<Vec<Region>> {
+    let contained = provider.lookup(DataTable::RegionContainment, self)?;
+    contained
+      .split(' ')
+      .filter_map(|code| Region::try_from_str(code).ok())
+      .find_map(|child| {
+        if child == *region {
+          return Some(vec![]);
+        }
+        child.path_to(region, provider)
+      })
+      .map(|mut path| {
+        path.insert(0, *self);
+        path
+      })
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn region(code: &str) -> Region {
+  Region::try_from_str(code).unwrap()
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_contains() {
+  assert!(region("001").contains(&region("JP")));
+  assert!(region("150").contains(&region("155")));
+  assert!(region("155").contains(&region("DE")));
+  assert!(!region("155").contains(&region("JP")));
+  assert!(!region("DE").contains(&region("DE")));
+  assert!(!region("155").contains(&region("155")));
+
+  // the groupings are not followed
+  assert!(!region("EU").contains(&region("DE")));
+  assert!(!region("419").contains(&region("MX")));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_containing_regions() {
+  let codes = |code: &str| {
+    region(code)
+      .containing_regions()
+      .iter()
+      .map(|region| region.to_string())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(vec!["155", "150", "001"], codes("DE"));
+  assert_eq!(vec!["030", "142", "001"], codes("JP"));
+  assert_eq!(vec!["150", "001"], codes("155"));
+  assert_eq!(Vec::<String>::new(), codes("001"));
+  assert_eq!(Vec::<String>::new(), codes("ZZ"));
+}
@@ -0,0 +1,70 @@
+pub mod aliases;
+pub mod containment;
+pub mod iso15924;
+pub mod iso639;
+pub mod likely_subtags;
+pub mod parent_locales;
+pub mod validity;
+
+/// Find the value of the given key, in the table sorted by the keys.
+pub fn lookup(table: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+  table
+    .binary_search_by(|(k, _)| (*k).cmp(key))
+    .ok()
+    .map(|index| table[index].1)
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_lookup() {
+  assert_eq!(Some("he"), lookup(aliases::LANGUAGE_ALIASES, "iw"));
+  assert_eq!(Some("sr-Latn"), lookup(aliases::LANGUAGE_ALIASES, "sh"));
+  assert_eq!(Some("MM"), lookup(aliases::REGION_ALIASES, "BU"));
+  assert_eq!(
+    Some("zh-Hant-TW"),
+    lookup(likely_subtags::LIKELY_SUBTAGS, "zh-TW")
+  );
+  assert_eq!(None, lookup(aliases::LANGUAGE_ALIASES, "en"));
+}
+
+#[test]
+fn sorted_tables() {
+  for table in [
+    aliases::LANGUAGE_ALIASES,
+    aliases::SCRIPT_ALIASES,
+    aliases::REGION_ALIASES,
+    aliases::VARIANT_ALIASES,
+    aliases::SUBDIVISION_ALIASES,
+    aliases::UNICODE_KEYWORD_ALIASES,
+    aliases::TRANSFORMED_KEYWORD_ALIASES,
+    likely_subtags::LIKELY_SUBTAGS,
+    parent_locales::PARENT_LOCALES,
+    containment::REGION_CONTAINMENT,
+    iso15924::NUMERIC_CODES,
+    iso15924::NAMES,
+    iso15924::PROPERTY_VALUE_ALIASES,
+    iso15924::DIRECTIONS,
+    iso15924::UNIONS,
+    iso15924::VARIANTS,
+    iso639::ISO639_1,
+    iso639::ISO639_2,
+    iso639::MACROLANGUAGES,
+    iso639::SCOPES,
+    validity::LANGUAGES,
+    validity::SCRIPTS,
+    validity::REGIONS,
+    validity::VARIANTS,
+    validity::SUBDIVISIONS,
+    validity::UNITS,
+    validity::CURRENCIES,
+    validity::UNICODE_KEYS,
+    validity::UNICODE_TYPES,
+    validity::TRANSFORMED_KEYS,
+    validity::TRANSFORMED_TYPES,
+  ] {
+    assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+  }
+}
@@ -0,0 +1,681 @@
+//! The code tables of [ISO 15924](https://www.unicode.org/iso15924/), sorted by the alpha-4 code to be binary-searched.
+//!
+//! The property value aliases and the directions are from the Unicode Character Database. The private use codes `Qaaa..Qabx` are not listed.
+
+/// The numeric codes, in 3 digits, e.g. `Latn` to `215`.
+pub static NUMERIC_CODES: &[(&str, &str)] = &[
+  ("Adlm", "166"),
+  ("Afak", "439"),
+  ("Aghb", "239"),
+  ("Ahom", "338"),
+  ("Arab", "160"),
+  ("Aran", "161"),
+  ("Armi", "124"),
+  ("Armn", "230"),
+  ("Avst", "134"),
+  ("Bali", "360"),
+  ("Bamu", "435"),
+  ("Bass", "259"),
+  ("Batk", "365"),
+  ("Beng", "325"),
+  ("Bhks", "334"),
+  ("Blis", "550"),
+  ("Bopo", "285"),
+  ("Brah", "300"),
+  ("Brai", "570"),
+  ("Bugi", "367"),
+  ("Buhd", "372"),
+  ("Cakm", "349"),
+  ("Cans", "440"),
+  ("Cari", "201"),
+  ("Cham", "358"),
+  ("Cher", "445"),
+  ("Chrs", "109"),
+  ("Cirt", "291"),
+  ("Copt", "204"),
+  ("Cpmn", "402"),
+  ("Cprt", "403"),
+  ("Cyrl", "220"),
+  ("Cyrs", "221"),
+  ("Deva", "315"),
+  ("Diak", "342"),
+  ("Dogr", "328"),
+  ("Dsrt", "250"),
+  ("Dupl", "755"),
+  ("Egyd", "070"),
+  ("Egyh", "060"),
+  ("Egyp", "050"),
+  ("Elba", "226"),
+  ("Elym", "128"),
+  ("Ethi", "430"),
+  ("Geok", "241"),
+  ("Geor", "240"),
+  ("Glag", "225"),
+  ("Gong", "312"),
+  ("Gonm", "313"),
+  ("Goth", "206"),
+  ("Gran", "343"),
+  ("Grek", "200"),
+  ("Gujr", "320"),
+  ("Guru", "310"),
+  ("Hanb", "503"),
+  ("Hang", "286"),
+  ("Hani", "500"),
+  ("Hano", "371"),
+  ("Hans", "501"),
+  ("Hant", "502"),
+  ("Hatr", "127"),
+  ("Hebr", "125"),
+  ("Hira", "410"),
+  ("Hluw", "080"),
+  ("Hmng", "450"),
+  ("Hmnp", "451"),
+  ("Hrkt", "412"),
+  ("Hung", "176"),
+  ("Inds", "610"),
+  ("Ital", "210"),
+  ("Jamo", "284"),
+  ("Java", "361"),
+  ("Jpan", "413"),
+  ("Jurc", "510"),
+  ("Kali", "357"),
+  ("Kana", "411"),
+  ("Kawi", "368"),
+  ("Khar", "305"),
+  ("Khmr", "355"),
+  ("Khoj", "322"),
+  ("Kitl", "505"),
+  ("Kits", "288"),
+  ("Knda", "345"),
+  ("Kore", "287"),
+  ("Kpel", "436"),
+  ("Kthi", "317"),
+  ("Lana", "351"),
+  ("Laoo", "356"),
+  ("Latf", "217"),
+  ("Latg", "216"),
+  ("Latn", "215"),
+  ("Leke", "364"),
+  ("Lepc", "335"),
+  ("Limb", "336"),
+  ("Lina", "400"),
+  ("Linb", "401"),
+  ("Lisu", "399"),
+  ("Loma", "437"),
+  ("Lyci", "202"),
+  ("Lydi", "116"),
+  ("Mahj", "314"),
+  ("Maka", "366"),
+  ("Mand", "140"),
+  ("Mani", "139"),
+  ("Marc", "332"),
+  ("Maya", "090"),
+  ("Medf", "265"),
+  ("Mend", "438"),
+  ("Merc", "101"),
+  ("Mero", "100"),
+  ("Mlym", "347"),
+  ("Modi", "324"),
+  ("Mong", "145"),
+  ("Moon", "218"),
+  ("Mroo", "264"),
+  ("Mtei", "337"),
+  ("Mult", "323"),
+  ("Mymr", "350"),
+  ("Nagm", "295"),
+  ("Nand", "311"),
+  ("Narb", "106"),
+  ("Nbat", "159"),
+  ("Newa", "333"),
+  ("Nkdb", "085"),
+  ("Nkgb", "420"),
+  ("Nkoo", "165"),
+  ("Nshu", "499"),
+  ("Ogam", "212"),
+  ("Olck", "261"),
+  ("Orkh", "175"),
+  ("Orya", "327"),
+  ("Osge", "219"),
+  ("Osma", "260"),
+  ("Ougr", "143"),
+  ("Palm", "126"),
+  ("Pauc", "263"),
+  ("Pcun", "015"),
+  ("Pelm", "016"),
+  ("Perm", "227"),
+  ("Phag", "331"),
+  ("Phli", "131"),
+  ("Phlp", "132"),
+  ("Phlv", "133"),
+  ("Phnx", "115"),
+  ("Piqd", "293"),
+  ("Plrd", "282"),
+  ("Prti", "130"),
+  ("Psin", "103"),
+  ("Ranj", "303"),
+  ("Rjng", "363"),
+  ("Rohg", "167"),
+  ("Roro", "620"),
+  ("Runr", "211"),
+  ("Samr", "123"),
+  ("Sara", "292"),
+  ("Sarb", "105"),
+  ("Saur", "344"),
+  ("Sgnw", "095"),
+  ("Shaw", "281"),
+  ("Shrd", "319"),
+  ("Shui", "530"),
+  ("Sidd", "302"),
+  ("Sind", "318"),
+  ("Sinh", "348"),
+  ("Sogd", "141"),
+  ("Sogo", "142"),
+  ("Sora", "398"),
+  ("Soyo", "329"),
+  ("Sund", "362"),
+  ("Sylo", "316"),
+  ("Syrc", "135"),
+  ("Syre", "138"),
+  ("Syrj", "137"),
+  ("Syrn", "136"),
+  ("Tagb", "373"),
+  ("Takr", "321"),
+  ("Tale", "353"),
+  ("Talu", "354"),
+  ("Taml", "346"),
+  ("Tang", "520"),
+  ("Tavt", "359"),
+  ("Telu", "340"),
+  ("Teng", "290"),
+  ("Tfng", "120"),
+  ("Tglg", "370"),
+  ("Thaa", "170"),
+  ("Thai", "352"),
+  ("Tibt", "330"),
+  ("Tirh", "326"),
+  ("Tnsa", "275"),
+  ("Toto", "294"),
+  ("Ugar", "040"),
+  ("Vaii", "470"),
+  ("Visp", "280"),
+  ("Vith", "228"),
+  ("Wara", "262"),
+  ("Wcho", "283"),
+  ("Wole", "480"),
+  ("Xpeo", "030"),
+  ("Xsux", "020"),
+  ("Yezi", "192"),
+  ("Yiii", "460"),
+  ("Zanb", "339"),
+  ("Zinh", "994"),
+  ("Zmth", "995"),
+  ("Zsye", "993"),
+  ("Zsym", "996"),
+  ("Zxxx", "997"),
+  ("Zyyy", "998"),
+  ("Zzzz", "999"),
+];
+
+/// The English names, e.g. `Hani` to `Han (Hanzi, Kanji, Hanja)`.
+pub static NAMES: &[(&str, &str)] = &[
+  ("Adlm", "Adlam"),
+  ("Afak", "Afaka"),
+  ("Aghb", "Caucasian Albanian"),
+  ("Ahom", "Ahom, Tai Ahom"),
+  ("Arab", "Arabic"),
+  ("Aran", "Arabic (Nastaliq variant)"),
+  ("Armi", "Imperial Aramaic"),
+  ("Armn", "Armenian"),
+  ("Avst", "Avestan"),
+  ("Bali", "Balinese"),
+  ("Bamu", "Bamum"),
+  ("Bass", "Bassa Vah"),
+  ("Batk", "Batak"),
+  ("Beng", "Bengali (Bangla)"),
+  ("Bhks", "Bhaiksuki"),
+  ("Blis", "Blissymbols"),
+  ("Bopo", "Bopomofo"),
+  ("Brah", "Brahmi"),
+  ("Brai", "Braille"),
+  ("Bugi", "Buginese"),
+  ("Buhd", "Buhid"),
+  ("Cakm", "Chakma"),
+  ("Cans", "Unified Canadian Aboriginal Syllabics"),
+  ("Cari", "Carian"),
+  ("Cham", "Cham"),
+  ("Cher", "Cherokee"),
+  ("Chrs", "Chorasmian"),
+  ("Cirt", "Cirth"),
+  ("Copt", "Coptic"),
+  ("Cpmn", "Cypro-Minoan"),
+  ("Cprt", "Cypriot syllabary"),
+  ("Cyrl", "Cyrillic"),
+  ("Cyrs", "Cyrillic (Old Church Slavonic variant)"),
+  ("Deva", "Devanagari (Nagari)"),
+  ("Diak", "Dives Akuru"),
+  ("Dogr", "Dogra"),
+  ("Dsrt", "Deseret (Mormon)"),
+  ("Dupl", "Duployan shorthand, Duployan stenography"),
+  ("Egyd", "Egyptian demotic"),
+  ("Egyh", "Egyptian hieratic"),
+  ("Egyp", "Egyptian hieroglyphs"),
+  ("Elba", "Elbasan"),
+  ("Elym", "Elymaic"),
+  ("Ethi", "Ethiopic (Geʻez)"),
+  ("Geok", "Khutsuri (Asomtavruli and Nuskhuri)"),
+  ("Geor", "Georgian (Mkhedruli and Mtavruli)"),
+  ("Glag", "Glagolitic"),
+  ("Gong", "Gunjala Gondi"),
+  ("Gonm", "Masaram Gondi"),
+  ("Goth", "Gothic"),
+  ("Gran", "Grantha"),
+  ("Grek", "Greek"),
+  ("Gujr", "Gujarati"),
+  ("Guru", "Gurmukhi"),
+  ("Hanb", "Han with Bopomofo (alias for Han + Bopomofo)"),
+  ("Hang", "Hangul (Hangŭl, Hangeul)"),
+  ("Hani", "Han (Hanzi, Kanji, Hanja)"),
+  ("Hano", "Hanunoo (Hanunóo)"),
+  ("Hans", "Han (Simplified variant)"),
+  ("Hant", "Han (Traditional variant)"),
+  ("Hatr", "Hatran"),
+  ("Hebr", "Hebrew"),
+  ("Hira", "Hiragana"),
+  ("Hluw", "Anatolian Hieroglyphs (Luwian Hieroglyphs, Hittite Hieroglyphs)"),
+  ("Hmng", "Pahawh Hmong"),
+  ("Hmnp", "Nyiakeng Puachue Hmong"),
+  ("Hrkt", "Japanese syllabaries (alias for Hiragana + Katakana)"),
+  ("Hung", "Old Hungarian (Hungarian Runic)"),
+  ("Inds", "Indus (Harappan)"),
+  ("Ital", "Old Italic (Etruscan, Oscan, etc.)"),
+  ("Jamo", "Jamo (alias for Jamo subset of Hangul)"),
+  ("Java", "Javanese"),
+  ("Jpan", "Japanese (alias for Han + Hiragana + Katakana)"),
+  ("Jurc", "Jurchen"),
+  ("Kali", "Kayah Li"),
+  ("Kana", "Katakana"),
+  ("Kawi", "Kawi"),
+  ("Khar", "Kharoshthi"),
+  ("Khmr", "Khmer"),
+  ("Khoj", "Khojki"),
+  ("Kitl", "Khitan large script"),
+  ("Kits", "Khitan small script"),
+  ("Knda", "Kannada"),
+  ("Kore", "Korean (alias for Hangul + Han)"),
+  ("Kpel", "Kpelle"),
+  ("Kthi", "Kaithi"),
+  ("Lana", "Tai Tham (Lanna)"),
+  ("Laoo", "Lao"),
+  ("Latf", "Latin (Fraktur variant)"),
+  ("Latg", "Latin (Gaelic variant)"),
+  ("Latn", "Latin"),
+  ("Leke", "Leke"),
+  ("Lepc", "Lepcha (Róng)"),
+  ("Limb", "Limbu"),
+  ("Lina", "Linear A"),
+  ("Linb", "Linear B"),
+  ("Lisu", "Lisu (Fraser)"),
+  ("Loma", "Loma"),
+  ("Lyci", "Lycian"),
+  ("Lydi", "Lydian"),
+  ("Mahj", "Mahajani"),
+  ("Maka", "Makasar"),
+  ("Mand", "Mandaic, Mandaean"),
+  ("Mani", "Manichaean"),
+  ("Marc", "Marchen"),
+  ("Maya", "Mayan hieroglyphs"),
+  ("Medf", "Medefaidrin (Oberi Okaime, Oberi Ɔkaimɛ)"),
+  ("Mend", "Mende Kikakui"),
+  ("Merc", "Meroitic Cursive"),
+  ("Mero", "Meroitic Hieroglyphs"),
+  ("Mlym", "Malayalam"),
+  ("Modi", "Modi, Moḍī"),
+  ("Mong", "Mongolian"),
+  ("Moon", "Moon (Moon code, Moon script, Moon type)"),
+  ("Mroo", "Mro, Mru"),
+  ("Mtei", "Meitei Mayek (Meithei, Meetei)"),
+  ("Mult", "Multani"),
+  ("Mymr", "Myanmar (Burmese)"),
+  ("Nagm", "Nag Mundari"),
+  ("Nand", "Nandinagari"),
+  ("Narb", "Old North Arabian (Ancient North Arabian)"),
+  ("Nbat", "Nabataean"),
+  ("Newa", "Newa, Newar, Newari, Nepāla lipi"),
+  ("Nkdb", "Naxi Dongba (na²¹ɕi³³ to³³ba²¹, Nakhi Tomba)"),
+  ("Nkgb", "Naxi Geba (na²¹ɕi³³ gʌ²¹ba²¹, 'Na-'Khi ²Ggŏ-¹baw, Nakhi Geba)"),
+  ("Nkoo", "N’Ko"),
+  ("Nshu", "Nüshu"),
+  ("Ogam", "Ogham"),
+  ("Olck", "Ol Chiki (Ol Cemet’, Ol, Santali)"),
+  ("Orkh", "Old Turkic, Orkhon Runic"),
+  ("Orya", "Oriya (Odia)"),
+  ("Osge", "Osage"),
+  ("Osma", "Osmanya"),
+  ("Ougr", "Old Uyghur"),
+  ("Palm", "Palmyrene"),
+  ("Pauc", "Pau Cin Hau"),
+  ("Pcun", "Proto-Cuneiform"),
+  ("Pelm", "Proto-Elamite"),
+  ("Perm", "Old Permic"),
+  ("Phag", "Phags-pa"),
+  ("Phli", "Inscriptional Pahlavi"),
+  ("Phlp", "Psalter Pahlavi"),
+  ("Phlv", "Book Pahlavi"),
+  ("Phnx", "Phoenician"),
+  ("Piqd", "Klingon (KLI pIqaD)"),
+  ("Plrd", "Miao (Pollard)"),
+  ("Prti", "Inscriptional Parthian"),
+  ("Psin", "Proto-Sinaitic"),
+  ("Ranj", "Ranjana"),
+  ("Rjng", "Rejang (Redjang, Kaganga)"),
+  ("Rohg", "Hanifi Rohingya"),
+  ("Roro", "Rongorongo"),
+  ("Runr", "Runic"),
+  ("Samr", "Samaritan"),
+  ("Sara", "Sarati"),
+  ("Sarb", "Old South Arabian"),
+  ("Saur", "Saurashtra"),
+  ("Sgnw", "SignWriting"),
+  ("Shaw", "Shavian (Shaw)"),
+  ("Shrd", "Sharada, Śāradā"),
+  ("Shui", "Shuishu"),
+  ("Sidd", "Siddham, Siddhaṃ, Siddhamātṛkā"),
+  ("Sind", "Khudawadi, Sindhi"),
+  ("Sinh", "Sinhala"),
+  ("Sogd", "Sogdian"),
+  ("Sogo", "Old Sogdian"),
+  ("Sora", "Sora Sompeng"),
+  ("Soyo", "Soyombo"),
+  ("Sund", "Sundanese"),
+  ("Sylo", "Syloti Nagri"),
+  ("Syrc", "Syriac"),
+  ("Syre", "Syriac (Estrangelo variant)"),
+  ("Syrj", "Syriac (Western variant)"),
+  ("Syrn", "Syriac (Eastern variant)"),
+  ("Tagb", "Tagbanwa"),
+  ("Takr", "Takri, Ṭākrī, Ṭāṅkrī"),
+  ("Tale", "Tai Le"),
+  ("Talu", "New Tai Lue"),
+  ("Taml", "Tamil"),
+  ("Tang", "Tangut"),
+  ("Tavt", "Tai Viet"),
+  ("Telu", "Telugu"),
+  ("Teng", "Tengwar"),
+  ("Tfng", "Tifinagh (Berber)"),
+  ("Tglg", "Tagalog (Baybayin, Alibata)"),
+  ("Thaa", "Thaana"),
+  ("Thai", "Thai"),
+  ("Tibt", "Tibetan"),
+  ("Tirh", "Tirhuta"),
+  ("Tnsa", "Tangsa"),
+  ("Toto", "Toto"),
+  ("Ugar", "Ugaritic"),
+  ("Vaii", "Vai"),
+  ("Visp", "Visible Speech"),
+  ("Vith", "Vithkuqi"),
+  ("Wara", "Warang Citi (Varang Kshiti)"),
+  ("Wcho", "Wancho"),
+  ("Wole", "Woleai"),
+  ("Xpeo", "Old Persian"),
+  ("Xsux", "Cuneiform, Sumero-Akkadian"),
+  ("Yezi", "Yezidi"),
+  ("Yiii", "Yi"),
+  ("Zanb", "Zanabazar Square (Zanabazarin Dörböljin Useg, Xewtee Dörböljin Bicig, Horizontal Square Script)"),
+  ("Zinh", "Code for inherited script"),
+  ("Zmth", "Mathematical notation"),
+  ("Zsye", "Symbols (Emoji variant)"),
+  ("Zsym", "Symbols"),
+  ("Zxxx", "Code for unwritten documents"),
+  ("Zyyy", "Code for undetermined script"),
+  ("Zzzz", "Code for uncoded script"),
+];
+
+/// The property value aliases of the `Script` property of Unicode, e.g. `Hira` to `Hiragana`.
+pub static PROPERTY_VALUE_ALIASES: &[(&str, &str)] = &[
+  ("Adlm", "Adlam"),
+  ("Aghb", "Caucasian_Albanian"),
+  ("Ahom", "Ahom"),
+  ("Arab", "Arabic"),
+  ("Armi", "Imperial_Aramaic"),
+  ("Armn", "Armenian"),
+  ("Avst", "Avestan"),
+  ("Bali", "Balinese"),
+  ("Bamu", "Bamum"),
+  ("Bass", "Bassa_Vah"),
+  ("Batk", "Batak"),
+  ("Beng", "Bengali"),
+  ("Bhks", "Bhaiksuki"),
+  ("Bopo", "Bopomofo"),
+  ("Brah", "Brahmi"),
+  ("Brai", "Braille"),
+  ("Bugi", "Buginese"),
+  ("Buhd", "Buhid"),
+  ("Cakm", "Chakma"),
+  ("Cans", "Canadian_Aboriginal"),
+  ("Cari", "Carian"),
+  ("Cham", "Cham"),
+  ("Cher", "Cherokee"),
+  ("Chrs", "Chorasmian"),
+  ("Copt", "Coptic"),
+  ("Cpmn", "Cypro_Minoan"),
+  ("Cprt", "Cypriot"),
+  ("Cyrl", "Cyrillic"),
+  ("Deva", "Devanagari"),
+  ("Diak", "Dives_Akuru"),
+  ("Dogr", "Dogra"),
+  ("Dsrt", "Deseret"),
+  ("Dupl", "Duployan"),
+  ("Egyp", "Egyptian_Hieroglyphs"),
+  ("Elba", "Elbasan"),
+  ("Elym", "Elymaic"),
+  ("Ethi", "Ethiopic"),
+  ("Geor", "Georgian"),
+  ("Glag", "Glagolitic"),
+  ("Gong", "Gunjala_Gondi"),
+  ("Gonm", "Masaram_Gondi"),
+  ("Goth", "Gothic"),
+  ("Gran", "Grantha"),
+  ("Grek", "Greek"),
+  ("Gujr", "Gujarati"),
+  ("Guru", "Gurmukhi"),
+  ("Hang", "Hangul"),
+  ("Hani", "Han"),
+  ("Hano", "Hanunoo"),
+  ("Hatr", "Hatran"),
+  ("Hebr", "Hebrew"),
+  ("Hira", "Hiragana"),
+  ("Hluw", "Anatolian_Hieroglyphs"),
+  ("Hmng", "Pahawh_Hmong"),
+  ("Hmnp", "Nyiakeng_Puachue_Hmong"),
+  ("Hrkt", "Katakana_Or_Hiragana"),
+  ("Hung", "Old_Hungarian"),
+  ("Ital", "Old_Italic"),
+  ("Java", "Javanese"),
+  ("Kali", "Kayah_Li"),
+  ("Kana", "Katakana"),
+  ("Kawi", "Kawi"),
+  ("Khar", "Kharoshthi"),
+  ("Khmr", "Khmer"),
+  ("Khoj", "Khojki"),
+  ("Kits", "Khitan_Small_Script"),
+  ("Knda", "Kannada"),
+  ("Kthi", "Kaithi"),
+  ("Lana", "Tai_Tham"),
+  ("Laoo", "Lao"),
+  ("Latn", "Latin"),
+  ("Lepc", "Lepcha"),
+  ("Limb", "Limbu"),
+  ("Lina", "Linear_A"),
+  ("Linb", "Linear_B"),
+  ("Lisu", "Lisu"),
+  ("Lyci", "Lycian"),
+  ("Lydi", "Lydian"),
+  ("Mahj", "Mahajani"),
+  ("Maka", "Makasar"),
+  ("Mand", "Mandaic"),
+  ("Mani", "Manichaean"),
+  ("Marc", "Marchen"),
+  ("Medf", "Medefaidrin"),
+  ("Mend", "Mende_Kikakui"),
+  ("Merc", "Meroitic_Cursive"),
+  ("Mero", "Meroitic_Hieroglyphs"),
+  ("Mlym", "Malayalam"),
+  ("Modi", "Modi"),
+  ("Mong", "Mongolian"),
+  ("Mroo", "Mro"),
+  ("Mtei", "Meetei_Mayek"),
+  ("Mult", "Multani"),
+  ("Mymr", "Myanmar"),
+  ("Nagm", "Nag_Mundari"),
+  ("Nand", "Nandinagari"),
+  ("Narb", "Old_North_Arabian"),
+  ("Nbat", "Nabataean"),
+  ("Newa", "Newa"),
+  ("Nkoo", "Nko"),
+  ("Nshu", "Nushu"),
+  ("Ogam", "Ogham"),
+  ("Olck", "Ol_Chiki"),
+  ("Orkh", "Old_Turkic"),
+  ("Orya", "Oriya"),
+  ("Osge", "Osage"),
+  ("Osma", "Osmanya"),
+  ("Ougr", "Old_Uyghur"),
+  ("Palm", "Palmyrene"),
+  ("Pauc", "Pau_Cin_Hau"),
+  ("Perm", "Old_Permic"),
+  ("Phag", "Phags_Pa"),
+  ("Phli", "Inscriptional_Pahlavi"),
+  ("Phlp", "Psalter_Pahlavi"),
+  ("Phnx", "Phoenician"),
+  ("Plrd", "Miao"),
+  ("Prti", "Inscriptional_Parthian"),
+  ("Rjng", "Rejang"),
+  ("Rohg", "Hanifi_Rohingya"),
+  ("Runr", "Runic"),
+  ("Samr", "Samaritan"),
+  ("Sarb", "Old_South_Arabian"),
+  ("Saur", "Saurashtra"),
+  ("Sgnw", "SignWriting"),
+  ("Shaw", "Shavian"),
+  ("Shrd", "Sharada"),
+  ("Sidd", "Siddham"),
+  ("Sind", "Khudawadi"),
+  ("Sinh", "Sinhala"),
+  ("Sogd", "Sogdian"),
+  ("Sogo", "Old_Sogdian"),
+  ("Sora", "Sora_Sompeng"),
+  ("Soyo", "Soyombo"),
+  ("Sund", "Sundanese"),
+  ("Sylo", "Syloti_Nagri"),
+  ("Syrc", "Syriac"),
+  ("Tagb", "Tagbanwa"),
+  ("Takr", "Takri"),
+  ("Tale", "Tai_Le"),
+  ("Talu", "New_Tai_Lue"),
+  ("Taml", "Tamil"),
+  ("Tang", "Tangut"),
+  ("Tavt", "Tai_Viet"),
+  ("Telu", "Telugu"),
+  ("Tfng", "Tifinagh"),
+  ("Tglg", "Tagalog"),
+  ("Thaa", "Thaana"),
+  ("Thai", "Thai"),
+  ("Tibt", "Tibetan"),
+  ("Tirh", "Tirhuta"),
+  ("Tnsa", "Tangsa"),
+  ("Toto", "Toto"),
+  ("Ugar", "Ugaritic"),
+  ("Vaii", "Vai"),
+  ("Vith", "Vithkuqi"),
+  ("Wara", "Warang_Citi"),
+  ("Wcho", "Wancho"),
+  ("Xpeo", "Old_Persian"),
+  ("Xsux", "Cuneiform"),
+  ("Yezi", "Yezidi"),
+  ("Yiii", "Yi"),
+  ("Zanb", "Zanabazar_Square"),
+  ("Zinh", "Inherited"),
+  ("Zyyy", "Common"),
+  ("Zzzz", "Unknown"),
+];
+
+/// The directions of the scripts that are not written left to right: `rtl`, `ttb` or `neutral`.
+pub static DIRECTIONS: &[(&str, &str)] = &[
+  ("Adlm", "rtl"),
+  ("Arab", "rtl"),
+  ("Aran", "rtl"),
+  ("Armi", "rtl"),
+  ("Avst", "rtl"),
+  ("Chrs", "rtl"),
+  ("Cprt", "rtl"),
+  ("Egyd", "rtl"),
+  ("Egyh", "rtl"),
+  ("Elym", "rtl"),
+  ("Hatr", "rtl"),
+  ("Hebr", "rtl"),
+  ("Hung", "rtl"),
+  ("Inds", "rtl"),
+  ("Khar", "rtl"),
+  ("Lydi", "rtl"),
+  ("Mand", "rtl"),
+  ("Mani", "rtl"),
+  ("Mend", "rtl"),
+  ("Merc", "rtl"),
+  ("Mero", "rtl"),
+  ("Mong", "ttb"),
+  ("Narb", "rtl"),
+  ("Nbat", "rtl"),
+  ("Nkoo", "rtl"),
+  ("Orkh", "rtl"),
+  ("Ougr", "rtl"),
+  ("Palm", "rtl"),
+  ("Phag", "ttb"),
+  ("Phli", "rtl"),
+  ("Phlp", "rtl"),
+  ("Phlv", "rtl"),
+  ("Phnx", "rtl"),
+  ("Prti", "rtl"),
+  ("Psin", "rtl"),
+  ("Rohg", "rtl"),
+  ("Samr", "rtl"),
+  ("Sarb", "rtl"),
+  ("Sgnw", "ttb"),
+  ("Sogd", "rtl"),
+  ("Sogo", "rtl"),
+  ("Syrc", "rtl"),
+  ("Syre", "rtl"),
+  ("Syrj", "rtl"),
+  ("Syrn", "rtl"),
+  ("Thaa", "rtl"),
+  ("Yezi", "rtl"),
+  ("Zinh", "neutral"),
+  ("Zmth", "neutral"),
+  ("Zsye", "neutral"),
+  ("Zsym", "neutral"),
+  ("Zxxx", "neutral"),
+  ("Zyyy", "neutral"),
+  ("Zzzz", "neutral"),
+];
+
+/// The scripts of the unions, separated by a space, e.g. `Jpan` to `Hani Hira Kana`.
+pub static UNIONS: &[(&str, &str)] = &[
+  ("Hanb", "Bopo Hani"),
+  ("Hrkt", "Hira Kana"),
+  ("Jpan", "Hani Hira Kana"),
+  ("Kore", "Hang Hani"),
+];
+
+/// The scripts of the variants, e.g. `Hant` to `Hani`.
+pub static VARIANTS: &[(&str, &str)] = &[
+  ("Aran", "Arab"),
+  ("Cyrs", "Cyrl"),
+  ("Hans", "Hani"),
+  ("Hant", "Hani"),
+  ("Jamo", "Hang"),
+  ("Latf", "Latn"),
+  ("Latg", "Latn"),
+  ("Syre", "Syrc"),
+  ("Syrj", "Syrc"),
+  ("Syrn", "Syrc"),
+  ("Zsye", "Zsym"),
+];
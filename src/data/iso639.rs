@@ -0,0 +1,1060 @@
+//! The code tables of [ISO 639](https://www.loc.gov/standards/iso639-2/), sorted by the code to be binary-searched.
+//!
+//! The tables are keyed by the alpha-3 codes of ISO 639-2/T and ISO 639-3, which are the same for the languages in both.
+//! The members of the macrolanguages are listed for the macrolanguages of ISO 639-1 and Serbo-Croatian, from [the ISO 639-3 macrolanguage mappings](https://iso639-3.sil.org/code_tables/macrolanguage_mappings/data).
+
+/// The alpha-2 codes of ISO 639-1, with their alpha-3 codes.
+pub static ISO639_1: &[(&str, &str)] = &[
+  ("aa", "aar"),
+  ("ab", "abk"),
+  ("ae", "ave"),
+  ("af", "afr"),
+  ("ak", "aka"),
+  ("am", "amh"),
+  ("an", "arg"),
+  ("ar", "ara"),
+  ("as", "asm"),
+  ("av", "ava"),
+  ("ay", "aym"),
+  ("az", "aze"),
+  ("ba", "bak"),
+  ("be", "bel"),
+  ("bg", "bul"),
+  ("bi", "bis"),
+  ("bm", "bam"),
+  ("bn", "ben"),
+  ("bo", "bod"),
+  ("br", "bre"),
+  ("bs", "bos"),
+  ("ca", "cat"),
+  ("ce", "che"),
+  ("ch", "cha"),
+  ("co", "cos"),
+  ("cr", "cre"),
+  ("cs", "ces"),
+  ("cu", "chu"),
+  ("cv", "chv"),
+  ("cy", "cym"),
+  ("da", "dan"),
+  ("de", "deu"),
+  ("dv", "div"),
+  ("dz", "dzo"),
+  ("ee", "ewe"),
+  ("el", "ell"),
+  ("en", "eng"),
+  ("eo", "epo"),
+  ("es", "spa"),
+  ("et", "est"),
+  ("eu", "eus"),
+  ("fa", "fas"),
+  ("ff", "ful"),
+  ("fi", "fin"),
+  ("fj", "fij"),
+  ("fo", "fao"),
+  ("fr", "fra"),
+  ("fy", "fry"),
+  ("ga", "gle"),
+  ("gd", "gla"),
+  ("gl", "glg"),
+  ("gn", "grn"),
+  ("gu", "guj"),
+  ("gv", "glv"),
+  ("ha", "hau"),
+  ("he", "heb"),
+  ("hi", "hin"),
+  ("ho", "hmo"),
+  ("hr", "hrv"),
+  ("ht", "hat"),
+  ("hu", "hun"),
+  ("hy", "hye"),
+  ("hz", "her"),
+  ("ia", "ina"),
+  ("id", "ind"),
+  ("ie", "ile"),
+  ("ig", "ibo"),
+  ("ii", "iii"),
+  ("ik", "ipk"),
+  ("io", "ido"),
+  ("is", "isl"),
+  ("it", "ita"),
+  ("iu", "iku"),
+  ("ja", "jpn"),
+  ("jv", "jav"),
+  ("ka", "kat"),
+  ("kg", "kon"),
+  ("ki", "kik"),
+  ("kj", "kua"),
+  ("kk", "kaz"),
+  ("kl", "kal"),
+  ("km", "khm"),
+  ("kn", "kan"),
+  ("ko", "kor"),
+  ("kr", "kau"),
+  ("ks", "kas"),
+  ("ku", "kur"),
+  ("kv", "kom"),
+  ("kw", "cor"),
+  ("ky", "kir"),
+  ("la", "lat"),
+  ("lb", "ltz"),
+  ("lg", "lug"),
+  ("li", "lim"),
+  ("ln", "lin"),
+  ("lo", "lao"),
+  ("lt", "lit"),
+  ("lu", "lub"),
+  ("lv", "lav"),
+  ("mg", "mlg"),
+  ("mh", "mah"),
+  ("mi", "mri"),
+  ("mk", "mkd"),
+  ("ml", "mal"),
+  ("mn", "mon"),
+  ("mr", "mar"),
+  ("ms", "msa"),
+  ("mt", "mlt"),
+  ("my", "mya"),
+  ("na", "nau"),
+  ("nb", "nob"),
+  ("nd", "nde"),
+  ("ne", "nep"),
+  ("ng", "ndo"),
+  ("nl", "nld"),
+  ("nn", "nno"),
+  ("no", "nor"),
+  ("nr", "nbl"),
+  ("nv", "nav"),
+  ("ny", "nya"),
+  ("oc", "oci"),
+  ("oj", "oji"),
+  ("om", "orm"),
+  ("or", "ori"),
+  ("os", "oss"),
+  ("pa", "pan"),
+  ("pi", "pli"),
+  ("pl", "pol"),
+  ("ps", "pus"),
+  ("pt", "por"),
+  ("qu", "que"),
+  ("rm", "roh"),
+  ("rn", "run"),
+  ("ro", "ron"),
+  ("ru", "rus"),
+  ("rw", "kin"),
+  ("sa", "san"),
+  ("sc", "srd"),
+  ("sd", "snd"),
+  ("se", "sme"),
+  ("sg", "sag"),
+  ("si", "sin"),
+  ("sk", "slk"),
+  ("sl", "slv"),
+  ("sm", "smo"),
+  ("sn", "sna"),
+  ("so", "som"),
+  ("sq", "sqi"),
+  ("sr", "srp"),
+  ("ss", "ssw"),
+  ("st", "sot"),
+  ("su", "sun"),
+  ("sv", "swe"),
+  ("sw", "swa"),
+  ("ta", "tam"),
+  ("te", "tel"),
+  ("tg", "tgk"),
+  ("th", "tha"),
+  ("ti", "tir"),
+  ("tk", "tuk"),
+  ("tl", "tgl"),
+  ("tn", "tsn"),
+  ("to", "ton"),
+  ("tr", "tur"),
+  ("ts", "tso"),
+  ("tt", "tat"),
+  ("tw", "twi"),
+  ("ty", "tah"),
+  ("ug", "uig"),
+  ("uk", "ukr"),
+  ("ur", "urd"),
+  ("uz", "uzb"),
+  ("ve", "ven"),
+  ("vi", "vie"),
+  ("vo", "vol"),
+  ("wa", "wln"),
+  ("wo", "wol"),
+  ("xh", "xho"),
+  ("yi", "yid"),
+  ("yo", "yor"),
+  ("za", "zha"),
+  ("zh", "zho"),
+  ("zu", "zul"),
+];
+
+/// The terminology codes of ISO 639-2, with their bibliographic codes, e.g. `fra` to `fre`.
+pub static ISO639_2: &[(&str, &str)] = &[
+  ("aar", "aar"),
+  ("abk", "abk"),
+  ("ace", "ace"),
+  ("ach", "ach"),
+  ("ada", "ada"),
+  ("ady", "ady"),
+  ("afa", "afa"),
+  ("afr", "afr"),
+  ("ain", "ain"),
+  ("aka", "aka"),
+  ("akk", "akk"),
+  ("ale", "ale"),
+  ("alg", "alg"),
+  ("alt", "alt"),
+  ("amh", "amh"),
+  ("ang", "ang"),
+  ("anp", "anp"),
+  ("apa", "apa"),
+  ("ara", "ara"),
+  ("arc", "arc"),
+  ("arg", "arg"),
+  ("arn", "arn"),
+  ("arp", "arp"),
+  ("art", "art"),
+  ("arw", "arw"),
+  ("asm", "asm"),
+  ("ast", "ast"),
+  ("ath", "ath"),
+  ("aus", "aus"),
+  ("ava", "ava"),
+  ("ave", "ave"),
+  ("awa", "awa"),
+  ("aym", "aym"),
+  ("aze", "aze"),
+  ("bad", "bad"),
+  ("bai", "bai"),
+  ("bak", "bak"),
+  ("bal", "bal"),
+  ("bam", "bam"),
+  ("ban", "ban"),
+  ("bas", "bas"),
+  ("bat", "bat"),
+  ("bej", "bej"),
+  ("bel", "bel"),
+  ("bem", "bem"),
+  ("ben", "ben"),
+  ("ber", "ber"),
+  ("bho", "bho"),
+  ("bih", "bih"),
+  ("bik", "bik"),
+  ("bin", "bin"),
+  ("bis", "bis"),
+  ("bla", "bla"),
+  ("bnt", "bnt"),
+  ("bod", "tib"),
+  ("bos", "bos"),
+  ("bra", "bra"),
+  ("bre", "bre"),
+  ("btk", "btk"),
+  ("bua", "bua"),
+  ("bug", "bug"),
+  ("bul", "bul"),
+  ("byn", "byn"),
+  ("cad", "cad"),
+  ("cai", "cai"),
+  ("car", "car"),
+  ("cat", "cat"),
+  ("cau", "cau"),
+  ("ceb", "ceb"),
+  ("cel", "cel"),
+  ("ces", "cze"),
+  ("cha", "cha"),
+  ("chb", "chb"),
+  ("che", "che"),
+  ("chg", "chg"),
+  ("chk", "chk"),
+  ("chm", "chm"),
+  ("chn", "chn"),
+  ("cho", "cho"),
+  ("chp", "chp"),
+  ("chr", "chr"),
+  ("chu", "chu"),
+  ("chv", "chv"),
+  ("chy", "chy"),
+  ("cmc", "cmc"),
+  ("cnr", "cnr"),
+  ("cop", "cop"),
+  ("cor", "cor"),
+  ("cos", "cos"),
+  ("cpe", "cpe"),
+  ("cpf", "cpf"),
+  ("cpp", "cpp"),
+  ("cre", "cre"),
+  ("crh", "crh"),
+  ("crp", "crp"),
+  ("csb", "csb"),
+  ("cus", "cus"),
+  ("cym", "wel"),
+  ("dak", "dak"),
+  ("dan", "dan"),
+  ("dar", "dar"),
+  ("day", "day"),
+  ("del", "del"),
+  ("den", "den"),
+  ("deu", "ger"),
+  ("dgr", "dgr"),
+  ("din", "din"),
+  ("div", "div"),
+  ("doi", "doi"),
+  ("dra", "dra"),
+  ("dsb", "dsb"),
+  ("dua", "dua"),
+  ("dum", "dum"),
+  ("dyu", "dyu"),
+  ("dzo", "dzo"),
+  ("efi", "efi"),
+  ("egy", "egy"),
+  ("eka", "eka"),
+  ("ell", "gre"),
+  ("elx", "elx"),
+  ("eng", "eng"),
+  ("enm", "enm"),
+  ("epo", "epo"),
+  ("est", "est"),
+  ("eus", "baq"),
+  ("ewe", "ewe"),
+  ("ewo", "ewo"),
+  ("fan", "fan"),
+  ("fao", "fao"),
+  ("fas", "per"),
+  ("fat", "fat"),
+  ("fij", "fij"),
+  ("fil", "fil"),
+  ("fin", "fin"),
+  ("fiu", "fiu"),
+  ("fon", "fon"),
+  ("fra", "fre"),
+  ("frm", "frm"),
+  ("fro", "fro"),
+  ("frr", "frr"),
+  ("frs", "frs"),
+  ("fry", "fry"),
+  ("ful", "ful"),
+  ("fur", "fur"),
+  ("gaa", "gaa"),
+  ("gay", "gay"),
+  ("gba", "gba"),
+  ("gem", "gem"),
+  ("gez", "gez"),
+  ("gil", "gil"),
+  ("gla", "gla"),
+  ("gle", "gle"),
+  ("glg", "glg"),
+  ("glv", "glv"),
+  ("gmh", "gmh"),
+  ("goh", "goh"),
+  ("gon", "gon"),
+  ("gor", "gor"),
+  ("got", "got"),
+  ("grb", "grb"),
+  ("grc", "grc"),
+  ("grn", "grn"),
+  ("gsw", "gsw"),
+  ("guj", "guj"),
+  ("gwi", "gwi"),
+  ("hai", "hai"),
+  ("hat", "hat"),
+  ("hau", "hau"),
+  ("haw", "haw"),
+  ("heb", "heb"),
+  ("her", "her"),
+  ("hil", "hil"),
+  ("him", "him"),
+  ("hin", "hin"),
+  ("hit", "hit"),
+  ("hmn", "hmn"),
+  ("hmo", "hmo"),
+  ("hrv", "hrv"),
+  ("hsb", "hsb"),
+  ("hun", "hun"),
+  ("hup", "hup"),
+  ("hye", "arm"),
+  ("iba", "iba"),
+  ("ibo", "ibo"),
+  ("ido", "ido"),
+  ("iii", "iii"),
+  ("ijo", "ijo"),
+  ("iku", "iku"),
+  ("ile", "ile"),
+  ("ilo", "ilo"),
+  ("ina", "ina"),
+  ("inc", "inc"),
+  ("ind", "ind"),
+  ("ine", "ine"),
+  ("inh", "inh"),
+  ("ipk", "ipk"),
+  ("ira", "ira"),
+  ("iro", "iro"),
+  ("isl", "ice"),
+  ("ita", "ita"),
+  ("jav", "jav"),
+  ("jbo", "jbo"),
+  ("jpn", "jpn"),
+  ("jpr", "jpr"),
+  ("jrb", "jrb"),
+  ("kaa", "kaa"),
+  ("kab", "kab"),
+  ("kac", "kac"),
+  ("kal", "kal"),
+  ("kam", "kam"),
+  ("kan", "kan"),
+  ("kar", "kar"),
+  ("kas", "kas"),
+  ("kat", "geo"),
+  ("kau", "kau"),
+  ("kaw", "kaw"),
+  ("kaz", "kaz"),
+  ("kbd", "kbd"),
+  ("kha", "kha"),
+  ("khi", "khi"),
+  ("khm", "khm"),
+  ("kho", "kho"),
+  ("kik", "kik"),
+  ("kin", "kin"),
+  ("kir", "kir"),
+  ("kmb", "kmb"),
+  ("kok", "kok"),
+  ("kom", "kom"),
+  ("kon", "kon"),
+  ("kor", "kor"),
+  ("kos", "kos"),
+  ("kpe", "kpe"),
+  ("krc", "krc"),
+  ("krl", "krl"),
+  ("kro", "kro"),
+  ("kru", "kru"),
+  ("kua", "kua"),
+  ("kum", "kum"),
+  ("kur", "kur"),
+  ("kut", "kut"),
+  ("lad", "lad"),
+  ("lah", "lah"),
+  ("lam", "lam"),
+  ("lao", "lao"),
+  ("lat", "lat"),
+  ("lav", "lav"),
+  ("lez", "lez"),
+  ("lim", "lim"),
+  ("lin", "lin"),
+  ("lit", "lit"),
+  ("lol", "lol"),
+  ("loz", "loz"),
+  ("ltz", "ltz"),
+  ("lua", "lua"),
+  ("lub", "lub"),
+  ("lug", "lug"),
+  ("lui", "lui"),
+  ("lun", "lun"),
+  ("luo", "luo"),
+  ("lus", "lus"),
+  ("mad", "mad"),
+  ("mag", "mag"),
+  ("mah", "mah"),
+  ("mai", "mai"),
+  ("mak", "mak"),
+  ("mal", "mal"),
+  ("man", "man"),
+  ("map", "map"),
+  ("mar", "mar"),
+  ("mas", "mas"),
+  ("mdf", "mdf"),
+  ("mdr", "mdr"),
+  ("men", "men"),
+  ("mga", "mga"),
+  ("mic", "mic"),
+  ("min", "min"),
+  ("mis", "mis"),
+  ("mkd", "mac"),
+  ("mkh", "mkh"),
+  ("mlg", "mlg"),
+  ("mlt", "mlt"),
+  ("mnc", "mnc"),
+  ("mni", "mni"),
+  ("mno", "mno"),
+  ("moh", "moh"),
+  ("mon", "mon"),
+  ("mos", "mos"),
+  ("mri", "mao"),
+  ("msa", "may"),
+  ("mul", "mul"),
+  ("mun", "mun"),
+  ("mus", "mus"),
+  ("mwl", "mwl"),
+  ("mwr", "mwr"),
+  ("mya", "bur"),
+  ("myn", "myn"),
+  ("myv", "myv"),
+  ("nah", "nah"),
+  ("nai", "nai"),
+  ("nap", "nap"),
+  ("nau", "nau"),
+  ("nav", "nav"),
+  ("nbl", "nbl"),
+  ("nde", "nde"),
+  ("ndo", "ndo"),
+  ("nds", "nds"),
+  ("nep", "nep"),
+  ("new", "new"),
+  ("nia", "nia"),
+  ("nic", "nic"),
+  ("niu", "niu"),
+  ("nld", "dut"),
+  ("nno", "nno"),
+  ("nob", "nob"),
+  ("nog", "nog"),
+  ("non", "non"),
+  ("nor", "nor"),
+  ("nqo", "nqo"),
+  ("nso", "nso"),
+  ("nub", "nub"),
+  ("nwc", "nwc"),
+  ("nya", "nya"),
+  ("nym", "nym"),
+  ("nyn", "nyn"),
+  ("nyo", "nyo"),
+  ("nzi", "nzi"),
+  ("oci", "oci"),
+  ("oji", "oji"),
+  ("ori", "ori"),
+  ("orm", "orm"),
+  ("osa", "osa"),
+  ("oss", "oss"),
+  ("ota", "ota"),
+  ("oto", "oto"),
+  ("paa", "paa"),
+  ("pag", "pag"),
+  ("pal", "pal"),
+  ("pam", "pam"),
+  ("pan", "pan"),
+  ("pap", "pap"),
+  ("pau", "pau"),
+  ("peo", "peo"),
+  ("phi", "phi"),
+  ("phn", "phn"),
+  ("pli", "pli"),
+  ("pol", "pol"),
+  ("pon", "pon"),
+  ("por", "por"),
+  ("pra", "pra"),
+  ("pro", "pro"),
+  ("pus", "pus"),
+  ("que", "que"),
+  ("raj", "raj"),
+  ("rap", "rap"),
+  ("rar", "rar"),
+  ("roa", "roa"),
+  ("roh", "roh"),
+  ("rom", "rom"),
+  ("ron", "rum"),
+  ("run", "run"),
+  ("rup", "rup"),
+  ("rus", "rus"),
+  ("sad", "sad"),
+  ("sag", "sag"),
+  ("sah", "sah"),
+  ("sai", "sai"),
+  ("sal", "sal"),
+  ("sam", "sam"),
+  ("san", "san"),
+  ("sas", "sas"),
+  ("sat", "sat"),
+  ("scn", "scn"),
+  ("sco", "sco"),
+  ("sel", "sel"),
+  ("sem", "sem"),
+  ("sga", "sga"),
+  ("sgn", "sgn"),
+  ("shn", "shn"),
+  ("sid", "sid"),
+  ("sin", "sin"),
+  ("sio", "sio"),
+  ("sit", "sit"),
+  ("sla", "sla"),
+  ("slk", "slo"),
+  ("slv", "slv"),
+  ("sma", "sma"),
+  ("sme", "sme"),
+  ("smi", "smi"),
+  ("smj", "smj"),
+  ("smn", "smn"),
+  ("smo", "smo"),
+  ("sms", "sms"),
+  ("sna", "sna"),
+  ("snd", "snd"),
+  ("snk", "snk"),
+  ("sog", "sog"),
+  ("som", "som"),
+  ("son", "son"),
+  ("sot", "sot"),
+  ("spa", "spa"),
+  ("sqi", "alb"),
+  ("srd", "srd"),
+  ("srn", "srn"),
+  ("srp", "srp"),
+  ("srr", "srr"),
+  ("ssa", "ssa"),
+  ("ssw", "ssw"),
+  ("suk", "suk"),
+  ("sun", "sun"),
+  ("sus", "sus"),
+  ("sux", "sux"),
+  ("swa", "swa"),
+  ("swe", "swe"),
+  ("syc", "syc"),
+  ("syr", "syr"),
+  ("tah", "tah"),
+  ("tai", "tai"),
+  ("tam", "tam"),
+  ("tat", "tat"),
+  ("tel", "tel"),
+  ("tem", "tem"),
+  ("ter", "ter"),
+  ("tet", "tet"),
+  ("tgk", "tgk"),
+  ("tgl", "tgl"),
+  ("tha", "tha"),
+  ("tig", "tig"),
+  ("tir", "tir"),
+  ("tiv", "tiv"),
+  ("tkl", "tkl"),
+  ("tlh", "tlh"),
+  ("tli", "tli"),
+  ("tmh", "tmh"),
+  ("tog", "tog"),
+  ("ton", "ton"),
+  ("tpi", "tpi"),
+  ("tsi", "tsi"),
+  ("tsn", "tsn"),
+  ("tso", "tso"),
+  ("tuk", "tuk"),
+  ("tum", "tum"),
+  ("tup", "tup"),
+  ("tur", "tur"),
+  ("tut", "tut"),
+  ("tvl", "tvl"),
+  ("twi", "twi"),
+  ("tyv", "tyv"),
+  ("udm", "udm"),
+  ("uga", "uga"),
+  ("uig", "uig"),
+  ("ukr", "ukr"),
+  ("umb", "umb"),
+  ("und", "und"),
+  ("urd", "urd"),
+  ("uzb", "uzb"),
+  ("vai", "vai"),
+  ("ven", "ven"),
+  ("vie", "vie"),
+  ("vol", "vol"),
+  ("vot", "vot"),
+  ("wak", "wak"),
+  ("wal", "wal"),
+  ("war", "war"),
+  ("was", "was"),
+  ("wen", "wen"),
+  ("wln", "wln"),
+  ("wol", "wol"),
+  ("xal", "xal"),
+  ("xho", "xho"),
+  ("yao", "yao"),
+  ("yap", "yap"),
+  ("yid", "yid"),
+  ("yor", "yor"),
+  ("ypk", "ypk"),
+  ("zap", "zap"),
+  ("zbl", "zbl"),
+  ("zen", "zen"),
+  ("zgh", "zgh"),
+  ("zha", "zha"),
+  ("zho", "chi"),
+  ("znd", "znd"),
+  ("zul", "zul"),
+  ("zun", "zun"),
+  ("zxx", "zxx"),
+  ("zza", "zza"),
+];
+
+/// The individual languages of ISO 639-3, with their macrolanguages, e.g. `cmn` to `zho`.
+pub static MACROLANGUAGES: &[(&str, &str)] = &[
+  ("aae", "sqi"),
+  ("aao", "ara"),
+  ("aat", "sqi"),
+  ("abh", "ara"),
+  ("abv", "ara"),
+  ("acm", "ara"),
+  ("acq", "ara"),
+  ("acw", "ara"),
+  ("acx", "ara"),
+  ("acy", "ara"),
+  ("adf", "ara"),
+  ("aeb", "ara"),
+  ("aec", "ara"),
+  ("afb", "ara"),
+  ("aln", "sqi"),
+  ("als", "sqi"),
+  ("apc", "ara"),
+  ("apd", "ara"),
+  ("arb", "ara"),
+  ("arq", "ara"),
+  ("ars", "ara"),
+  ("ary", "ara"),
+  ("arz", "ara"),
+  ("auz", "ara"),
+  ("avl", "ara"),
+  ("ayc", "aym"),
+  ("ayh", "ara"),
+  ("ayl", "ara"),
+  ("ayn", "ara"),
+  ("ayp", "ara"),
+  ("ayr", "aym"),
+  ("azb", "aze"),
+  ("azj", "aze"),
+  ("bbz", "ara"),
+  ("bhr", "mlg"),
+  ("bjn", "msa"),
+  ("bmm", "mlg"),
+  ("bos", "hbs"),
+  ("btj", "msa"),
+  ("bve", "msa"),
+  ("bvu", "msa"),
+  ("bzc", "mlg"),
+  ("cdo", "zho"),
+  ("ciw", "oji"),
+  ("cjy", "zho"),
+  ("ckb", "kur"),
+  ("cmn", "zho"),
+  ("cnp", "zho"),
+  ("cnr", "hbs"),
+  ("coa", "msa"),
+  ("cpx", "zho"),
+  ("cqu", "que"),
+  ("crj", "cre"),
+  ("crk", "cre"),
+  ("crl", "cre"),
+  ("crm", "cre"),
+  ("csp", "zho"),
+  ("csw", "cre"),
+  ("cwd", "cre"),
+  ("czh", "zho"),
+  ("czo", "zho"),
+  ("dty", "nep"),
+  ("dup", "msa"),
+  ("ekk", "est"),
+  ("esi", "ipk"),
+  ("esk", "ipk"),
+  ("fat", "aka"),
+  ("ffm", "ful"),
+  ("fub", "ful"),
+  ("fuc", "ful"),
+  ("fue", "ful"),
+  ("fuf", "ful"),
+  ("fuh", "ful"),
+  ("fui", "ful"),
+  ("fuq", "ful"),
+  ("fuv", "ful"),
+  ("gan", "zho"),
+  ("gax", "orm"),
+  ("gaz", "orm"),
+  ("gnw", "grn"),
+  ("gug", "grn"),
+  ("gui", "grn"),
+  ("gun", "grn"),
+  ("hae", "orm"),
+  ("hak", "zho"),
+  ("hji", "msa"),
+  ("hrv", "hbs"),
+  ("hsn", "zho"),
+  ("ike", "iku"),
+  ("ikt", "iku"),
+  ("ind", "msa"),
+  ("jak", "msa"),
+  ("jax", "msa"),
+  ("kby", "kau"),
+  ("khk", "mon"),
+  ("kmr", "kur"),
+  ("knc", "kau"),
+  ("kng", "kon"),
+  ("koi", "kom"),
+  ("kpv", "kom"),
+  ("krt", "kau"),
+  ("kvb", "msa"),
+  ("kvr", "msa"),
+  ("kwy", "kon"),
+  ("kxd", "msa"),
+  ("lce", "msa"),
+  ("lcf", "msa"),
+  ("ldi", "kon"),
+  ("liw", "msa"),
+  ("ltg", "lav"),
+  ("lvs", "lav"),
+  ("lzh", "zho"),
+  ("max", "msa"),
+  ("meo", "msa"),
+  ("mfa", "msa"),
+  ("mfb", "msa"),
+  ("min", "msa"),
+  ("mly", "msa"),
+  ("mnp", "zho"),
+  ("mqg", "msa"),
+  ("msh", "mlg"),
+  ("msi", "msa"),
+  ("mui", "msa"),
+  ("mvf", "mon"),
+  ("nan", "zho"),
+  ("nhd", "grn"),
+  ("nno", "nor"),
+  ("nob", "nor"),
+  ("npi", "nep"),
+  ("ojb", "oji"),
+  ("ojc", "oji"),
+  ("ojg", "oji"),
+  ("ojs", "oji"),
+  ("ojw", "oji"),
+  ("orc", "orm"),
+  ("orn", "msa"),
+  ("ors", "msa"),
+  ("ory", "ori"),
+  ("otw", "oji"),
+  ("pbt", "pus"),
+  ("pbu", "pus"),
+  ("pel", "msa"),
+  ("pes", "fas"),
+  ("pga", "ara"),
+  ("plt", "mlg"),
+  ("prs", "fas"),
+  ("pse", "msa"),
+  ("pst", "pus"),
+  ("qub", "que"),
+  ("qud", "que"),
+  ("quf", "que"),
+  ("qug", "que"),
+  ("quh", "que"),
+  ("quk", "que"),
+  ("qul", "que"),
+  ("qup", "que"),
+  ("qur", "que"),
+  ("qus", "que"),
+  ("quw", "que"),
+  ("qux", "que"),
+  ("quy", "que"),
+  ("quz", "que"),
+  ("qva", "que"),
+  ("qvc", "que"),
+  ("qve", "que"),
+  ("qvh", "que"),
+  ("qvi", "que"),
+  ("qvj", "que"),
+  ("qvl", "que"),
+  ("qvm", "que"),
+  ("qvn", "que"),
+  ("qvo", "que"),
+  ("qvp", "que"),
+  ("qvs", "que"),
+  ("qvw", "que"),
+  ("qvz", "que"),
+  ("qwa", "que"),
+  ("qwc", "que"),
+  ("qwh", "que"),
+  ("qws", "que"),
+  ("qxa", "que"),
+  ("qxc", "que"),
+  ("qxh", "que"),
+  ("qxl", "que"),
+  ("qxn", "que"),
+  ("qxo", "que"),
+  ("qxp", "que"),
+  ("qxr", "que"),
+  ("qxt", "que"),
+  ("qxu", "que"),
+  ("qxw", "que"),
+  ("sdc", "srd"),
+  ("sdh", "kur"),
+  ("sdn", "srd"),
+  ("shu", "ara"),
+  ("skg", "mlg"),
+  ("spv", "ori"),
+  ("src", "srd"),
+  ("sro", "srd"),
+  ("srp", "hbs"),
+  ("ssh", "ara"),
+  ("swc", "swa"),
+  ("swh", "swa"),
+  ("tdx", "mlg"),
+  ("tkg", "mlg"),
+  ("tmw", "msa"),
+  ("twi", "aka"),
+  ("txy", "mlg"),
+  ("urk", "msa"),
+  ("uzn", "uzb"),
+  ("uzs", "uzb"),
+  ("vkk", "msa"),
+  ("vkt", "msa"),
+  ("vro", "est"),
+  ("wuu", "zho"),
+  ("xmm", "msa"),
+  ("xmv", "mlg"),
+  ("xmw", "mlg"),
+  ("ydd", "yid"),
+  ("yih", "yid"),
+  ("yue", "zho"),
+  ("zch", "zha"),
+  ("zeh", "zha"),
+  ("zgb", "zha"),
+  ("zgm", "zha"),
+  ("zgn", "zha"),
+  ("zhd", "zha"),
+  ("zhn", "zha"),
+  ("zlj", "zha"),
+  ("zlm", "msa"),
+  ("zln", "zha"),
+  ("zlq", "zha"),
+  ("zmi", "msa"),
+  ("zqe", "zha"),
+  ("zsm", "msa"),
+  ("zyb", "zha"),
+  ("zyg", "zha"),
+  ("zyj", "zha"),
+  ("zyn", "zha"),
+  ("zzj", "zha"),
+];
+
+/// The scopes of the codes that are not individual languages: `macrolanguage`, `collection` or `special`.
+pub static SCOPES: &[(&str, &str)] = &[
+  ("afa", "collection"),
+  ("aka", "macrolanguage"),
+  ("alg", "collection"),
+  ("apa", "collection"),
+  ("ara", "macrolanguage"),
+  ("art", "collection"),
+  ("ath", "collection"),
+  ("aus", "collection"),
+  ("aym", "macrolanguage"),
+  ("aze", "macrolanguage"),
+  ("bad", "collection"),
+  ("bai", "collection"),
+  ("bal", "macrolanguage"),
+  ("bat", "collection"),
+  ("ber", "collection"),
+  ("bih", "collection"),
+  ("bik", "macrolanguage"),
+  ("bnc", "macrolanguage"),
+  ("bnt", "collection"),
+  ("btk", "collection"),
+  ("bua", "macrolanguage"),
+  ("cai", "collection"),
+  ("cau", "collection"),
+  ("cel", "collection"),
+  ("chm", "macrolanguage"),
+  ("cmc", "collection"),
+  ("cpe", "collection"),
+  ("cpf", "collection"),
+  ("cpp", "collection"),
+  ("cre", "macrolanguage"),
+  ("crp", "collection"),
+  ("cus", "collection"),
+  ("day", "collection"),
+  ("del", "macrolanguage"),
+  ("den", "macrolanguage"),
+  ("din", "macrolanguage"),
+  ("doi", "macrolanguage"),
+  ("dra", "collection"),
+  ("est", "macrolanguage"),
+  ("fas", "macrolanguage"),
+  ("fiu", "collection"),
+  ("ful", "macrolanguage"),
+  ("gba", "macrolanguage"),
+  ("gem", "collection"),
+  ("gon", "macrolanguage"),
+  ("grb", "macrolanguage"),
+  ("grn", "macrolanguage"),
+  ("hai", "macrolanguage"),
+  ("hbs", "macrolanguage"),
+  ("him", "collection"),
+  ("hmn", "macrolanguage"),
+  ("ijo", "collection"),
+  ("iku", "macrolanguage"),
+  ("inc", "collection"),
+  ("ine", "collection"),
+  ("ipk", "macrolanguage"),
+  ("ira", "collection"),
+  ("iro", "collection"),
+  ("jrb", "macrolanguage"),
+  ("kar", "collection"),
+  ("kau", "macrolanguage"),
+  ("khi", "collection"),
+  ("kln", "macrolanguage"),
+  ("kok", "macrolanguage"),
+  ("kom", "macrolanguage"),
+  ("kon", "macrolanguage"),
+  ("kpe", "macrolanguage"),
+  ("kro", "collection"),
+  ("kur", "macrolanguage"),
+  ("lah", "macrolanguage"),
+  ("lav", "macrolanguage"),
+  ("luy", "macrolanguage"),
+  ("man", "macrolanguage"),
+  ("map", "collection"),
+  ("mis", "special"),
+  ("mkh", "collection"),
+  ("mlg", "macrolanguage"),
+  ("mno", "collection"),
+  ("mon", "macrolanguage"),
+  ("msa", "macrolanguage"),
+  ("mul", "special"),
+  ("mun", "collection"),
+  ("mwr", "macrolanguage"),
+  ("myn", "collection"),
+  ("nah", "collection"),
+  ("nai", "collection"),
+  ("nep", "macrolanguage"),
+  ("nic", "collection"),
+  ("nor", "macrolanguage"),
+  ("nub", "collection"),
+  ("oji", "macrolanguage"),
+  ("ori", "macrolanguage"),
+  ("orm", "macrolanguage"),
+  ("oto", "collection"),
+  ("paa", "collection"),
+  ("phi", "collection"),
+  ("pra", "collection"),
+  ("pus", "macrolanguage"),
+  ("que", "macrolanguage"),
+  ("raj", "macrolanguage"),
+  ("roa", "collection"),
+  ("rom", "macrolanguage"),
+  ("sai", "collection"),
+  ("sal", "collection"),
+  ("sem", "collection"),
+  ("sgn", "collection"),
+  ("sio", "collection"),
+  ("sit", "collection"),
+  ("sla", "collection"),
+  ("smi", "collection"),
+  ("son", "collection"),
+  ("sqi", "macrolanguage"),
+  ("srd", "macrolanguage"),
+  ("ssa", "collection"),
+  ("swa", "macrolanguage"),
+  ("syr", "macrolanguage"),
+  ("tai", "collection"),
+  ("tmh", "macrolanguage"),
+  ("tup", "collection"),
+  ("tut", "collection"),
+  ("und", "special"),
+  ("uzb", "macrolanguage"),
+  ("wak", "collection"),
+  ("wen", "collection"),
+  ("yid", "macrolanguage"),
+  ("ypk", "collection"),
+  ("zap", "macrolanguage"),
+  ("zha", "macrolanguage"),
+  ("zho", "macrolanguage"),
+  ("znd", "collection"),
+  ("zxx", "special"),
+  ("zza", "macrolanguage"),
+];
@@ -0,0 +1,16046 @@
+//! The validity data of [CLDR `common/validity`](https://unicode.org/reports/tr35/#Validity_Data) and [`common/bcp47`](https://unicode.org/reports/tr35/#Key_And_Type_Definitions_), sorted by the code to be binary-searched.
+//!
+//! The statuses are the `idStatus` of CLDR: `regular`, `special`, `macroregion`, `deprecated`, `private_use` and `unknown`.
+//! The private use and reserved ranges are not listed, since they are defined by the ranges of the codes, which are private use in BCP 47.
+//!
+//! Generated by `unicode-locale-datagen` from CLDR 43.
+
+/// The statuses of the language subtags.
+pub static LANGUAGES: &[(&str, &str)] = &[
+  ("aa", "regular"),
+  ("aaa", "regular"),
+  ("aab", "regular"),
+  ("aac", "regular"),
+  ("aad", "regular"),
+  ("aae", "regular"),
+  ("aaf", "regular"),
+  ("aag", "regular"),
+  ("aah", "regular"),
+  ("aai", "regular"),
+  ("aak", "regular"),
+  ("aal", "regular"),
+  ("aam", "deprecated"),
+  ("aan", "regular"),
+  ("aao", "regular"),
+  ("aap", "regular"),
+  ("aaq", "regular"),
+  ("aas", "regular"),
+  ("aat", "regular"),
+  ("aau", "regular"),
+  ("aav", "regular"),
+  ("aaw", "regular"),
+  ("aax", "regular"),
+  ("aaz", "regular"),
+  ("ab", "regular"),
+  ("aba", "regular"),
+  ("abb", "regular"),
+  ("abc", "regular"),
+  ("abd", "regular"),
+  ("abe", "regular"),
+  ("abf", "regular"),
+  ("abg", "regular"),
+  ("abh", "regular"),
+  ("abi", "regular"),
+  ("abj", "regular"),
+  ("abl", "regular"),
+  ("abm", "regular"),
+  ("abn", "regular"),
+  ("abo", "regular"),
+  ("abp", "regular"),
+  ("abq", "regular"),
+  ("abr", "regular"),
+  ("abs", "regular"),
+  ("abt", "regular"),
+  ("abu", "regular"),
+  ("abv", "regular"),
+  ("abw", "regular"),
+  ("abx", "regular"),
+  ("aby", "regular"),
+  ("abz", "regular"),
+  ("aca", "regular"),
+  ("acb", "regular"),
+  ("acd", "regular"),
+  ("ace", "regular"),
+  ("acf", "regular"),
+  ("ach", "regular"),
+  ("aci", "regular"),
+  ("ack", "regular"),
+  ("acl", "regular"),
+  ("acm", "regular"),
+  ("acn", "regular"),
+  ("acp", "regular"),
+  ("acq", "regular"),
+  ("acr", "regular"),
+  ("acs", "regular"),
+  ("act", "regular"),
+  ("acu", "regular"),
+  ("acv", "regular"),
+  ("acw", "regular"),
+  ("acx", "regular"),
+  ("acy", "regular"),
+  ("acz", "regular"),
+  ("ada", "regular"),
+  ("adb", "regular"),
+  ("add", "regular"),
+  ("ade", "regular"),
+  ("adf", "regular"),
+  ("adg", "regular"),
+  ("adh", "regular"),
+  ("adi", "regular"),
+  ("adj", "regular"),
+  ("adl", "regular"),
+  ("adn", "regular"),
+  ("ado", "regular"),
+  ("adp", "deprecated"),
+  ("adq", "regular"),
+  ("adr", "regular"),
+  ("ads", "regular"),
+  ("adt", "regular"),
+  ("adu", "regular"),
+  ("adw", "regular"),
+  ("adx", "regular"),
+  ("ady", "regular"),
+  ("adz", "regular"),
+  ("ae", "regular"),
+  ("aea", "regular"),
+  ("aeb", "regular"),
+  ("aec", "regular"),
+  ("aed", "regular"),
+  ("aee", "regular"),
+  ("aek", "regular"),
+  ("ael", "regular"),
+  ("aem", "regular"),
+  ("aen", "regular"),
+  ("aeq", "regular"),
+  ("aer", "regular"),
+  ("aes", "regular"),
+  ("aeu", "regular"),
+  ("aew", "regular"),
+  ("aey", "regular"),
+  ("aez", "regular"),
+  ("af", "regular"),
+  ("afa", "regular"),
+  ("afb", "regular"),
+  ("afd", "regular"),
+  ("afe", "regular"),
+  ("afg", "regular"),
+  ("afh", "regular"),
+  ("afi", "regular"),
+  ("afk", "regular"),
+  ("afn", "regular"),
+  ("afo", "regular"),
+  ("afp", "regular"),
+  ("afs", "regular"),
+  ("aft", "regular"),
+  ("afu", "regular"),
+  ("afz", "regular"),
+  ("aga", "regular"),
+  ("agb", "regular"),
+  ("agc", "regular"),
+  ("agd", "regular"),
+  ("age", "regular"),
+  ("agf", "regular"),
+  ("agg", "regular"),
+  ("agh", "regular"),
+  ("agi", "regular"),
+  ("agj", "regular"),
+  ("agk", "regular"),
+  ("agl", "regular"),
+  ("agm", "regular"),
+  ("agn", "regular"),
+  ("ago", "regular"),
+  ("agp", "deprecated"),
+  ("agq", "regular"),
+  ("agr", "regular"),
+  ("ags", "regular"),
+  ("agt", "regular"),
+  ("agu", "regular"),
+  ("agv", "regular"),
+  ("agw", "regular"),
+  ("agx", "regular"),
+  ("agy", "regular"),
+  ("agz", "regular"),
+  ("aha", "regular"),
+  ("ahb", "regular"),
+  ("ahg", "regular"),
+  ("ahh", "regular"),
+  ("ahi", "regular"),
+  ("ahk", "regular"),
+  ("ahl", "regular"),
+  ("ahm", "regular"),
+  ("ahn", "regular"),
+  ("aho", "regular"),
+  ("ahp", "regular"),
+  ("ahr", "regular"),
+  ("ahs", "regular"),
+  ("aht", "regular"),
+  ("aia", "regular"),
+  ("aib", "regular"),
+  ("aic", "regular"),
+  ("aid", "regular"),
+  ("aie", "regular"),
+  ("aif", "regular"),
+  ("aig", "regular"),
+  ("aih", "regular"),
+  ("aii", "regular"),
+  ("aij", "regular"),
+  ("aik", "regular"),
+  ("ail", "regular"),
+  ("aim", "regular"),
+  ("ain", "regular"),
+  ("aio", "regular"),
+  ("aip", "regular"),
+  ("aiq", "regular"),
+  ("air", "regular"),
+  ("ais", "deprecated"),
+  ("ait", "regular"),
+  ("aiw", "regular"),
+  ("aix", "regular"),
+  ("aiy", "regular"),
+  ("aja", "regular"),
+  ("ajg", "regular"),
+  ("aji", "regular"),
+  ("ajn", "regular"),
+  ("ajp", "regular"),
+  ("ajs", "regular"),
+  ("ajt", "deprecated"),
+  ("aju", "deprecated"),
+  ("ajw", "regular"),
+  ("ajz", "regular"),
+  ("ak", "regular"),
+  ("akb", "regular"),
+  ("akc", "regular"),
+  ("akd", "regular"),
+  ("ake", "regular"),
+  ("akf", "regular"),
+  ("akg", "regular"),
+  ("akh", "regular"),
+  ("aki", "regular"),
+  ("akj", "regular"),
+  ("akk", "regular"),
+  ("akl", "regular"),
+  ("akm", "regular"),
+  ("ako", "regular"),
+  ("akp", "regular"),
+  ("akq", "regular"),
+  ("akr", "regular"),
+  ("aks", "regular"),
+  ("akt", "regular"),
+  ("aku", "regular"),
+  ("akv", "regular"),
+  ("akw", "regular"),
+  ("akx", "regular"),
+  ("aky", "regular"),
+  ("akz", "regular"),
+  ("ala", "regular"),
+  ("alc", "regular"),
+  ("ald", "regular"),
+  ("ale", "regular"),
+  ("alf", "regular"),
+  ("alg", "regular"),
+  ("alh", "regular"),
+  ("ali", "regular"),
+  ("alj", "regular"),
+  ("alk", "regular"),
+  ("all", "regular"),
+  ("alm", "regular"),
+  ("aln", "regular"),
+  ("alo", "regular"),
+  ("alp", "regular"),
+  ("alq", "regular"),
+  ("alr", "regular"),
+  ("als", "deprecated"),
+  ("alt", "regular"),
+  ("alu", "regular"),
+  ("alv", "regular"),
+  ("alw", "regular"),
+  ("alx", "regular"),
+  ("aly", "regular"),
+  ("alz", "regular"),
+  ("am", "regular"),
+  ("ama", "regular"),
+  ("amb", "regular"),
+  ("amc", "regular"),
+  ("ame", "regular"),
+  ("amf", "regular"),
+  ("amg", "regular"),
+  ("ami", "regular"),
+  ("amj", "regular"),
+  ("amk", "regular"),
+  ("aml", "regular"),
+  ("amm", "regular"),
+  ("amn", "regular"),
+  ("amo", "regular"),
+  ("amp", "regular"),
+  ("amq", "regular"),
+  ("amr", "regular"),
+  ("ams", "regular"),
+  ("amt", "regular"),
+  ("amu", "regular"),
+  ("amv", "regular"),
+  ("amw", "regular"),
+  ("amx", "regular"),
+  ("amy", "regular"),
+  ("amz", "regular"),
+  ("an", "regular"),
+  ("ana", "regular"),
+  ("anb", "regular"),
+  ("anc", "regular"),
+  ("and", "regular"),
+  ("ane", "regular"),
+  ("anf", "regular"),
+  ("ang", "regular"),
+  ("anh", "regular"),
+  ("ani", "regular"),
+  ("anj", "regular"),
+  ("ank", "regular"),
+  ("anl", "regular"),
+  ("anm", "regular"),
+  ("ann", "regular"),
+  ("ano", "regular"),
+  ("anp", "regular"),
+  ("anq", "regular"),
+  ("anr", "regular"),
+  ("ans", "regular"),
+  ("ant", "regular"),
+  ("anu", "regular"),
+  ("anv", "regular"),
+  ("anw", "regular"),
+  ("anx", "regular"),
+  ("any", "regular"),
+  ("anz", "regular"),
+  ("aoa", "regular"),
+  ("aob", "regular"),
+  ("aoc", "regular"),
+  ("aod", "regular"),
+  ("aoe", "regular"),
+  ("aof", "regular"),
+  ("aog", "regular"),
+  ("aoh", "deprecated"),
+  ("aoi", "regular"),
+  ("aoj", "regular"),
+  ("aok", "regular"),
+  ("aol", "regular"),
+  ("aom", "regular"),
+  ("aon", "regular"),
+  ("aor", "regular"),
+  ("aos", "regular"),
+  ("aot", "regular"),
+  ("aou", "regular"),
+  ("aox", "regular"),
+  ("aoz", "regular"),
+  ("apa", "regular"),
+  ("apb", "regular"),
+  ("apc", "regular"),
+  ("apd", "regular"),
+  ("ape", "regular"),
+  ("apf", "regular"),
+  ("apg", "regular"),
+  ("aph", "regular"),
+  ("api", "regular"),
+  ("apj", "regular"),
+  ("apk", "regular"),
+  ("apl", "regular"),
+  ("apm", "regular"),
+  ("apn", "regular"),
+  ("apo", "regular"),
+  ("app", "regular"),
+  ("apq", "regular"),
+  ("apr", "regular"),
+  ("aps", "regular"),
+  ("apt", "regular"),
+  ("apu", "regular"),
+  ("apv", "regular"),
+  ("apw", "regular"),
+  ("apx", "regular"),
+  ("apy", "regular"),
+  ("apz", "regular"),
+  ("aqa", "regular"),
+  ("aqc", "regular"),
+  ("aqd", "regular"),
+  ("aqg", "regular"),
+  ("aqk", "regular"),
+  ("aql", "regular"),
+  ("aqm", "regular"),
+  ("aqn", "regular"),
+  ("aqp", "regular"),
+  ("aqr", "regular"),
+  ("aqt", "regular"),
+  ("aqz", "regular"),
+  ("ar", "regular"),
+  ("arb", "deprecated"),
+  ("arc", "regular"),
+  ("ard", "regular"),
+  ("are", "regular"),
+  ("arh", "regular"),
+  ("ari", "regular"),
+  ("arj", "regular"),
+  ("ark", "regular"),
+  ("arl", "regular"),
+  ("arn", "regular"),
+  ("aro", "regular"),
+  ("arp", "regular"),
+  ("arq", "regular"),
+  ("arr", "regular"),
+  ("ars", "regular"),
+  ("art", "regular"),
+  ("aru", "regular"),
+  ("arv", "regular"),
+  ("arw", "regular"),
+  ("arx", "regular"),
+  ("ary", "regular"),
+  ("arz", "regular"),
+  ("as", "regular"),
+  ("asa", "regular"),
+  ("asb", "regular"),
+  ("asc", "regular"),
+  ("asd", "deprecated"),
+  ("ase", "regular"),
+  ("asf", "regular"),
+  ("asg", "regular"),
+  ("ash", "regular"),
+  ("asi", "regular"),
+  ("asj", "regular"),
+  ("ask", "regular"),
+  ("asl", "regular"),
+  ("asn", "regular"),
+  ("aso", "regular"),
+  ("asp", "regular"),
+  ("asq", "regular"),
+  ("asr", "regular"),
+  ("ass", "regular"),
+  ("ast", "regular"),
+  ("asu", "regular"),
+  ("asv", "regular"),
+  ("asw", "regular"),
+  ("asx", "regular"),
+  ("asy", "regular"),
+  ("asz", "regular"),
+  ("ata", "regular"),
+  ("atb", "regular"),
+  ("atc", "regular"),
+  ("atd", "regular"),
+  ("ate", "regular"),
+  ("atg", "regular"),
+  ("ath", "regular"),
+  ("ati", "regular"),
+  ("atj", "regular"),
+  ("atk", "regular"),
+  ("atl", "regular"),
+  ("atm", "regular"),
+  ("atn", "regular"),
+  ("ato", "regular"),
+  ("atp", "regular"),
+  ("atq", "regular"),
+  ("atr", "regular"),
+  ("ats", "regular"),
+  ("att", "regular"),
+  ("atu", "regular"),
+  ("atv", "regular"),
+  ("atw", "regular"),
+  ("atx", "regular"),
+  ("aty", "regular"),
+  ("atz", "regular"),
+  ("aua", "regular"),
+  ("aub", "regular"),
+  ("auc", "regular"),
+  ("aud", "regular"),
+  ("aue", "deprecated"),
+  ("auf", "regular"),
+  ("aug", "regular"),
+  ("auh", "regular"),
+  ("aui", "regular"),
+  ("auj", "regular"),
+  ("auk", "regular"),
+  ("aul", "regular"),
+  ("aum", "regular"),
+  ("aun", "regular"),
+  ("auo", "regular"),
+  ("aup", "regular"),
+  ("auq", "regular"),
+  ("aur", "regular"),
+  ("aus", "regular"),
+  ("aut", "regular"),
+  ("auu", "regular"),
+  ("auw", "regular"),
+  ("aux", "regular"),
+  ("auy", "regular"),
+  ("auz", "regular"),
+  ("av", "regular"),
+  ("avb", "regular"),
+  ("avd", "regular"),
+  ("avi", "regular"),
+  ("avk", "regular"),
+  ("avl", "regular"),
+  ("avm", "regular"),
+  ("avn", "regular"),
+  ("avo", "regular"),
+  ("avs", "regular"),
+  ("avt", "regular"),
+  ("avu", "regular"),
+  ("avv", "regular"),
+  ("awa", "regular"),
+  ("awb", "regular"),
+  ("awc", "regular"),
+  ("awd", "regular"),
+  ("awe", "regular"),
+  ("awg", "regular"),
+  ("awh", "regular"),
+  ("awi", "regular"),
+  ("awk", "regular"),
+  ("awm", "regular"),
+  ("awn", "regular"),
+  ("awo", "regular"),
+  ("awr", "regular"),
+  ("aws", "regular"),
+  ("awt", "regular"),
+  ("awu", "regular"),
+  ("awv", "regular"),
+  ("aww", "regular"),
+  ("awx", "regular"),
+  ("awy", "regular"),
+  ("axb", "regular"),
+  ("axe", "regular"),
+  ("axg", "regular"),
+  ("axk", "regular"),
+  ("axl", "regular"),
+  ("axm", "regular"),
+  ("axx", "regular"),
+  ("ay", "regular"),
+  ("aya", "regular"),
+  ("ayb", "regular"),
+  ("ayc", "regular"),
+  ("ayd", "regular"),
+  ("aye", "regular"),
+  ("ayg", "regular"),
+  ("ayh", "regular"),
+  ("ayi", "regular"),
+  ("ayk", "regular"),
+  ("ayl", "regular"),
+  ("ayn", "regular"),
+  ("ayo", "regular"),
+  ("ayp", "regular"),
+  ("ayq", "regular"),
+  ("ayr", "deprecated"),
+  ("ays", "regular"),
+  ("ayt", "regular"),
+  ("ayu", "regular"),
+  ("ayx", "deprecated"),
+  ("ayy", "deprecated"),
+  ("ayz", "regular"),
+  ("az", "regular"),
+  ("aza", "regular"),
+  ("azb", "regular"),
+  ("azc", "regular"),
+  ("azd", "regular"),
+  ("azg", "regular"),
+  ("azj", "deprecated"),
+  ("azm", "regular"),
+  ("azn", "regular"),
+  ("azo", "regular"),
+  ("azt", "regular"),
+  ("azz", "regular"),
+  ("ba", "regular"),
+  ("baa", "regular"),
+  ("bab", "regular"),
+  ("bac", "regular"),
+  ("bad", "regular"),
+  ("bae", "regular"),
+  ("baf", "regular"),
+  ("bag", "regular"),
+  ("bah", "regular"),
+  ("bai", "regular"),
+  ("baj", "regular"),
+  ("bal", "regular"),
+  ("ban", "regular"),
+  ("bao", "regular"),
+  ("bap", "regular"),
+  ("bar", "regular"),
+  ("bas", "regular"),
+  ("bat", "regular"),
+  ("bau", "regular"),
+  ("bav", "regular"),
+  ("baw", "regular"),
+  ("bax", "regular"),
+  ("bay", "regular"),
+  ("baz", "deprecated"),
+  ("bba", "regular"),
+  ("bbb", "regular"),
+  ("bbc", "regular"),
+  ("bbd", "regular"),
+  ("bbe", "regular"),
+  ("bbf", "regular"),
+  ("bbg", "regular"),
+  ("bbh", "regular"),
+  ("bbi", "regular"),
+  ("bbj", "regular"),
+  ("bbk", "regular"),
+  ("bbl", "regular"),
+  ("bbm", "regular"),
+  ("bbn", "regular"),
+  ("bbo", "regular"),
+  ("bbp", "regular"),
+  ("bbq", "regular"),
+  ("bbr", "regular"),
+  ("bbs", "regular"),
+  ("bbt", "regular"),
+  ("bbu", "regular"),
+  ("bbv", "regular"),
+  ("bbw", "regular"),
+  ("bbx", "regular"),
+  ("bby", "regular"),
+  ("bbz", "deprecated"),
+  ("bca", "regular"),
+  ("bcb", "regular"),
+  ("bcc", "deprecated"),
+  ("bcd", "regular"),
+  ("bce", "regular"),
+  ("bcf", "regular"),
+  ("bcg", "regular"),
+  ("bch", "regular"),
+  ("bci", "regular"),
+  ("bcj", "regular"),
+  ("bck", "regular"),
+  ("bcl", "deprecated"),
+  ("bcm", "regular"),
+  ("bcn", "regular"),
+  ("bco", "regular"),
+  ("bcp", "regular"),
+  ("bcq", "regular"),
+  ("bcr", "regular"),
+  ("bcs", "regular"),
+  ("bct", "regular"),
+  ("bcu", "regular"),
+  ("bcv", "regular"),
+  ("bcw", "regular"),
+  ("bcy", "regular"),
+  ("bcz", "regular"),
+  ("bda", "regular"),
+  ("bdb", "regular"),
+  ("bdc", "regular"),
+  ("bdd", "regular"),
+  ("bde", "regular"),
+  ("bdf", "regular"),
+  ("bdg", "regular"),
+  ("bdh", "regular"),
+  ("bdi", "regular"),
+  ("bdj", "regular"),
+  ("bdk", "regular"),
+  ("bdl", "regular"),
+  ("bdm", "regular"),
+  ("bdn", "regular"),
+  ("bdo", "regular"),
+  ("bdp", "regular"),
+  ("bdq", "regular"),
+  ("bdr", "regular"),
+  ("bds", "regular"),
+  ("bdt", "regular"),
+  ("bdu", "regular"),
+  ("bdv", "regular"),
+  ("bdw", "regular"),
+  ("bdx", "regular"),
+  ("bdy", "regular"),
+  ("bdz", "regular"),
+  ("be", "regular"),
+  ("bea", "regular"),
+  ("beb", "regular"),
+  ("bec", "regular"),
+  ("bed", "regular"),
+  ("bee", "regular"),
+  ("bef", "regular"),
+  ("beg", "regular"),
+  ("beh", "regular"),
+  ("bei", "regular"),
+  ("bej", "regular"),
+  ("bek", "regular"),
+  ("bem", "regular"),
+  ("beo", "regular"),
+  ("bep", "regular"),
+  ("beq", "regular"),
+  ("ber", "regular"),
+  ("bes", "regular"),
+  ("bet", "regular"),
+  ("beu", "regular"),
+  ("bev", "regular"),
+  ("bew", "regular"),
+  ("bex", "regular"),
+  ("bey", "regular"),
+  ("bez", "regular"),
+  ("bfa", "regular"),
+  ("bfb", "regular"),
+  ("bfc", "regular"),
+  ("bfd", "regular"),
+  ("bfe", "regular"),
+  ("bff", "regular"),
+  ("bfg", "regular"),
+  ("bfh", "regular"),
+  ("bfi", "regular"),
+  ("bfj", "regular"),
+  ("bfk", "regular"),
+  ("bfl", "regular"),
+  ("bfm", "regular"),
+  ("bfn", "regular"),
+  ("bfo", "regular"),
+  ("bfp", "regular"),
+  ("bfq", "regular"),
+  ("bfr", "regular"),
+  ("bfs", "regular"),
+  ("bft", "regular"),
+  ("bfu", "regular"),
+  ("bfw", "regular"),
+  ("bfx", "regular"),
+  ("bfy", "regular"),
+  ("bfz", "regular"),
+  ("bg", "regular"),
+  ("bga", "regular"),
+  ("bgb", "regular"),
+  ("bgc", "regular"),
+  ("bgd", "regular"),
+  ("bge", "regular"),
+  ("bgf", "regular"),
+  ("bgg", "regular"),
+  ("bgi", "regular"),
+  ("bgj", "regular"),
+  ("bgk", "regular"),
+  ("bgl", "regular"),
+  ("bgm", "deprecated"),
+  ("bgn", "regular"),
+  ("bgo", "regular"),
+  ("bgp", "regular"),
+  ("bgq", "regular"),
+  ("bgr", "regular"),
+  ("bgs", "regular"),
+  ("bgt", "regular"),
+  ("bgu", "regular"),
+  ("bgv", "regular"),
+  ("bgw", "regular"),
+  ("bgx", "regular"),
+  ("bgy", "regular"),
+  ("bgz", "regular"),
+  ("bh", "deprecated"),
+  ("bha", "regular"),
+  ("bhb", "regular"),
+  ("bhc", "regular"),
+  ("bhd", "regular"),
+  ("bhe", "regular"),
+  ("bhf", "regular"),
+  ("bhg", "regular"),
+  ("bhh", "regular"),
+  ("bhi", "regular"),
+  ("bhj", "regular"),
+  ("bhk", "deprecated"),
+  ("bhl", "regular"),
+  ("bhm", "regular"),
+  ("bhn", "regular"),
+  ("bho", "regular"),
+  ("bhp", "regular"),
+  ("bhq", "regular"),
+  ("bhr", "regular"),
+  ("bhs", "regular"),
+  ("bht", "regular"),
+  ("bhu", "regular"),
+  ("bhv", "regular"),
+  ("bhw", "regular"),
+  ("bhx", "regular"),
+  ("bhy", "regular"),
+  ("bhz", "regular"),
+  ("bi", "regular"),
+  ("bia", "regular"),
+  ("bib", "regular"),
+  ("bic", "deprecated"),
+  ("bid", "regular"),
+  ("bie", "regular"),
+  ("bif", "regular"),
+  ("big", "regular"),
+  ("bij", "deprecated"),
+  ("bik", "regular"),
+  ("bil", "regular"),
+  ("bim", "regular"),
+  ("bin", "regular"),
+  ("bio", "regular"),
+  ("bip", "regular"),
+  ("biq", "regular"),
+  ("bir", "regular"),
+  ("bit", "regular"),
+  ("biu", "regular"),
+  ("biv", "regular"),
+  ("biw", "regular"),
+  ("bix", "regular"),
+  ("biy", "regular"),
+  ("biz", "regular"),
+  ("bja", "regular"),
+  ("bjb", "regular"),
+  ("bjc", "regular"),
+  ("bjd", "deprecated"),
+  ("bje", "regular"),
+  ("bjf", "regular"),
+  ("bjg", "regular"),
+  ("bjh", "regular"),
+  ("bji", "regular"),
+  ("bjj", "regular"),
+  ("bjk", "regular"),
+  ("bjl", "regular"),
+  ("bjm", "regular"),
+  ("bjn", "regular"),
+  ("bjo", "regular"),
+  ("bjp", "regular"),
+  ("bjq", "deprecated"),
+  ("bjr", "regular"),
+  ("bjs", "regular"),
+  ("bjt", "regular"),
+  ("bju", "regular"),
+  ("bjv", "regular"),
+  ("bjw", "regular"),
+  ("bjx", "regular"),
+  ("bjy", "regular"),
+  ("bjz", "regular"),
+  ("bka", "regular"),
+  ("bkb", "deprecated"),
+  ("bkc", "regular"),
+  ("bkd", "regular"),
+  ("bkf", "regular"),
+  ("bkg", "regular"),
+  ("bkh", "regular"),
+  ("bki", "regular"),
+  ("bkj", "regular"),
+  ("bkk", "regular"),
+  ("bkl", "regular"),
+  ("bkm", "regular"),
+  ("bkn", "regular"),
+  ("bko", "regular"),
+  ("bkp", "regular"),
+  ("bkq", "regular"),
+  ("bkr", "regular"),
+  ("bks", "regular"),
+  ("bkt", "regular"),
+  ("bku", "regular"),
+  ("bkv", "regular"),
+  ("bkw", "regular"),
+  ("bkx", "regular"),
+  ("bky", "regular"),
+  ("bkz", "regular"),
+  ("bla", "regular"),
+  ("blb", "regular"),
+  ("blc", "regular"),
+  ("bld", "regular"),
+  ("ble", "regular"),
+  ("blf", "regular"),
+  ("blg", "deprecated"),
+  ("blh", "regular"),
+  ("bli", "regular"),
+  ("blj", "regular"),
+  ("blk", "regular"),
+  ("bll", "regular"),
+  ("blm", "regular"),
+  ("bln", "regular"),
+  ("blo", "regular"),
+  ("blp", "regular"),
+  ("blq", "regular"),
+  ("blr", "regular"),
+  ("bls", "regular"),
+  ("blt", "regular"),
+  ("blv", "regular"),
+  ("blw", "regular"),
+  ("blx", "regular"),
+  ("bly", "regular"),
+  ("blz", "regular"),
+  ("bm", "regular"),
+  ("bma", "regular"),
+  ("bmb", "regular"),
+  ("bmc", "regular"),
+  ("bmd", "regular"),
+  ("bme", "regular"),
+  ("bmf", "regular"),
+  ("bmg", "regular"),
+  ("bmh", "regular"),
+  ("bmi", "regular"),
+  ("bmj", "regular"),
+  ("bmk", "regular"),
+  ("bml", "regular"),
+  ("bmm", "regular"),
+  ("bmn", "regular"),
+  ("bmo", "regular"),
+  ("bmp", "regular"),
+  ("bmq", "regular"),
+  ("bmr", "regular"),
+  ("bms", "regular"),
+  ("bmt", "regular"),
+  ("bmu", "regular"),
+  ("bmv", "regular"),
+  ("bmw", "regular"),
+  ("bmx", "regular"),
+  ("bmy", "deprecated"),
+  ("bmz", "regular"),
+  ("bn", "regular"),
+  ("bna", "regular"),
+  ("bnb", "regular"),
+  ("bnc", "regular"),
+  ("bnd", "regular"),
+  ("bne", "regular"),
+  ("bnf", "regular"),
+  ("bng", "regular"),
+  ("bni", "regular"),
+  ("bnj", "regular"),
+  ("bnk", "regular"),
+  ("bnl", "regular"),
+  ("bnm", "regular"),
+  ("bnn", "regular"),
+  ("bno", "regular"),
+  ("bnp", "regular"),
+  ("bnq", "regular"),
+  ("bnr", "regular"),
+  ("bns", "regular"),
+  ("bnt", "regular"),
+  ("bnu", "regular"),
+  ("bnv", "regular"),
+  ("bnw", "regular"),
+  ("bnx", "regular"),
+  ("bny", "regular"),
+  ("bnz", "regular"),
+  ("bo", "regular"),
+  ("boa", "regular"),
+  ("bob", "regular"),
+  ("boe", "regular"),
+  ("bof", "regular"),
+  ("bog", "regular"),
+  ("boh", "regular"),
+  ("boi", "regular"),
+  ("boj", "regular"),
+  ("bok", "regular"),
+  ("bol", "regular"),
+  ("bom", "regular"),
+  ("bon", "regular"),
+  ("boo", "regular"),
+  ("bop", "regular"),
+  ("boq", "regular"),
+  ("bor", "regular"),
+  ("bot", "regular"),
+  ("bou", "regular"),
+  ("bov", "regular"),
+  ("bow", "regular"),
+  ("box", "regular"),
+  ("boy", "regular"),
+  ("boz", "regular"),
+  ("bpa", "regular"),
+  ("bpb", "deprecated"),
+  ("bpc", "regular"),
+  ("bpd", "regular"),
+  ("bpe", "regular"),
+  ("bpg", "regular"),
+  ("bph", "regular"),
+  ("bpi", "regular"),
+  ("bpj", "regular"),
+  ("bpk", "regular"),
+  ("bpl", "regular"),
+  ("bpm", "regular"),
+  ("bpn", "regular"),
+  ("bpo", "regular"),
+  ("bpp", "regular"),
+  ("bpq", "regular"),
+  ("bpr", "regular"),
+  ("bps", "regular"),
+  ("bpt", "regular"),
+  ("bpu", "regular"),
+  ("bpv", "regular"),
+  ("bpw", "regular"),
+  ("bpx", "regular"),
+  ("bpy", "regular"),
+  ("bpz", "regular"),
+  ("bqa", "regular"),
+  ("bqb", "regular"),
+  ("bqc", "regular"),
+  ("bqd", "regular"),
+  ("bqf", "regular"),
+  ("bqg", "regular"),
+  ("bqh", "regular"),
+  ("bqi", "regular"),
+  ("bqj", "regular"),
+  ("bqk", "regular"),
+  ("bql", "regular"),
+  ("bqm", "regular"),
+  ("bqn", "regular"),
+  ("bqo", "regular"),
+  ("bqp", "regular"),
+  ("bqq", "regular"),
+  ("bqr", "regular"),
+  ("bqs", "regular"),
+  ("bqt", "regular"),
+  ("bqu", "regular"),
+  ("bqv", "regular"),
+  ("bqw", "regular"),
+  ("bqx", "regular"),
+  ("bqy", "regular"),
+  ("bqz", "regular"),
+  ("br", "regular"),
+  ("bra", "regular"),
+  ("brb", "regular"),
+  ("brc", "regular"),
+  ("brd", "regular"),
+  ("brf", "regular"),
+  ("brg", "regular"),
+  ("brh", "regular"),
+  ("bri", "regular"),
+  ("brj", "regular"),
+  ("brk", "regular"),
+  ("brl", "regular"),
+  ("brm", "regular"),
+  ("brn", "regular"),
+  ("bro", "regular"),
+  ("brp", "regular"),
+  ("brq", "regular"),
+  ("brr", "regular"),
+  ("brs", "regular"),
+  ("brt", "regular"),
+  ("bru", "regular"),
+  ("brv", "regular"),
+  ("brw", "regular"),
+  ("brx", "regular"),
+  ("bry", "regular"),
+  ("brz", "regular"),
+  ("bs", "regular"),
+  ("bsa", "regular"),
+  ("bsb", "regular"),
+  ("bsc", "regular"),
+  ("bse", "regular"),
+  ("bsf", "regular"),
+  ("bsg", "regular"),
+  ("bsh", "regular"),
+  ("bsi", "regular"),
+  ("bsj", "regular"),
+  ("bsk", "regular"),
+  ("bsl", "regular"),
+  ("bsm", "regular"),
+  ("bsn", "regular"),
+  ("bso", "regular"),
+  ("bsp", "regular"),
+  ("bsq", "regular"),
+  ("bsr", "regular"),
+  ("bss", "regular"),
+  ("bst", "regular"),
+  ("bsu", "regular"),
+  ("bsv", "regular"),
+  ("bsw", "regular"),
+  ("bsx", "regular"),
+  ("bsy", "regular"),
+  ("bta", "regular"),
+  ("btb", "deprecated"),
+  ("btc", "regular"),
+  ("btd", "regular"),
+  ("bte", "regular"),
+  ("btf", "regular"),
+  ("btg", "regular"),
+  ("bth", "regular"),
+  ("bti", "regular"),
+  ("btj", "regular"),
+  ("btk", "regular"),
+  ("btl", "deprecated"),
+  ("btm", "regular"),
+  ("btn", "regular"),
+  ("bto", "regular"),
+  ("btp", "regular"),
+  ("btq", "regular"),
+  ("btr", "regular"),
+  ("bts", "regular"),
+  ("btt", "regular"),
+  ("btu", "regular"),
+  ("btv", "regular"),
+  ("btw", "regular"),
+  ("btx", "regular"),
+  ("bty", "regular"),
+  ("btz", "regular"),
+  ("bua", "regular"),
+  ("bub", "regular"),
+  ("buc", "regular"),
+  ("bud", "regular"),
+  ("bue", "regular"),
+  ("buf", "regular"),
+  ("bug", "regular"),
+  ("buh", "regular"),
+  ("bui", "regular"),
+  ("buj", "regular"),
+  ("buk", "regular"),
+  ("bum", "regular"),
+  ("bun", "regular"),
+  ("buo", "regular"),
+  ("bup", "regular"),
+  ("buq", "regular"),
+  ("bus", "regular"),
+  ("but", "regular"),
+  ("buu", "regular"),
+  ("buv", "regular"),
+  ("buw", "regular"),
+  ("bux", "regular"),
+  ("buy", "regular"),
+  ("buz", "regular"),
+  ("bva", "regular"),
+  ("bvb", "regular"),
+  ("bvc", "regular"),
+  ("bvd", "regular"),
+  ("bve", "regular"),
+  ("bvf", "regular"),
+  ("bvg", "regular"),
+  ("bvh", "regular"),
+  ("bvi", "regular"),
+  ("bvj", "regular"),
+  ("bvk", "regular"),
+  ("bvl", "regular"),
+  ("bvm", "regular"),
+  ("bvn", "regular"),
+  ("bvo", "regular"),
+  ("bvp", "regular"),
+  ("bvq", "regular"),
+  ("bvr", "regular"),
+  ("bvt", "regular"),
+  ("bvu", "regular"),
+  ("bvv", "regular"),
+  ("bvw", "regular"),
+  ("bvx", "regular"),
+  ("bvy", "regular"),
+  ("bvz", "regular"),
+  ("bwa", "regular"),
+  ("bwb", "regular"),
+  ("bwc", "regular"),
+  ("bwd", "regular"),
+  ("bwe", "regular"),
+  ("bwf", "regular"),
+  ("bwg", "regular"),
+  ("bwh", "regular"),
+  ("bwi", "regular"),
+  ("bwj", "regular"),
+  ("bwk", "regular"),
+  ("bwl", "regular"),
+  ("bwm", "regular"),
+  ("bwn", "regular"),
+  ("bwo", "regular"),
+  ("bwp", "regular"),
+  ("bwq", "regular"),
+  ("bwr", "regular"),
+  ("bws", "regular"),
+  ("bwt", "regular"),
+  ("bwu", "regular"),
+  ("bww", "regular"),
+  ("bwx", "regular"),
+  ("bwy", "regular"),
+  ("bwz", "regular"),
+  ("bxa", "regular"),
+  ("bxb", "regular"),
+  ("bxc", "regular"),
+  ("bxd", "regular"),
+  ("bxe", "regular"),
+  ("bxf", "regular"),
+  ("bxg", "regular"),
+  ("bxh", "regular"),
+  ("bxi", "regular"),
+  ("bxj", "regular"),
+  ("bxk", "deprecated"),
+  ("bxl", "regular"),
+  ("bxm", "regular"),
+  ("bxn", "regular"),
+  ("bxo", "regular"),
+  ("bxp", "regular"),
+  ("bxq", "regular"),
+  ("bxr", "deprecated"),
+  ("bxs", "regular"),
+  ("bxu", "regular"),
+  ("bxv", "regular"),
+  ("bxw", "regular"),
+  ("bxx", "deprecated"),
+  ("bxz", "regular"),
+  ("bya", "regular"),
+  ("byb", "regular"),
+  ("byc", "regular"),
+  ("byd", "regular"),
+  ("bye", "regular"),
+  ("byf", "regular"),
+  ("byg", "regular"),
+  ("byh", "regular"),
+  ("byi", "regular"),
+  ("byj", "regular"),
+  ("byk", "regular"),
+  ("byl", "regular"),
+  ("bym", "regular"),
+  ("byn", "regular"),
+  ("byo", "regular"),
+  ("byp", "regular"),
+  ("byq", "regular"),
+  ("byr", "regular"),
+  ("bys", "regular"),
+  ("byt", "regular"),
+  ("byv", "regular"),
+  ("byw", "regular"),
+  ("byx", "regular"),
+  ("byy", "deprecated"),
+  ("byz", "regular"),
+  ("bza", "regular"),
+  ("bzb", "regular"),
+  ("bzc", "regular"),
+  ("bzd", "regular"),
+  ("bze", "regular"),
+  ("bzf", "regular"),
+  ("bzg", "regular"),
+  ("bzh", "regular"),
+  ("bzi", "regular"),
+  ("bzj", "regular"),
+  ("bzk", "regular"),
+  ("bzl", "regular"),
+  ("bzm", "regular"),
+  ("bzn", "regular"),
+  ("bzo", "regular"),
+  ("bzp", "regular"),
+  ("bzq", "regular"),
+  ("bzr", "regular"),
+  ("bzs", "regular"),
+  ("bzt", "regular"),
+  ("bzu", "regular"),
+  ("bzv", "regular"),
+  ("bzw", "regular"),
+  ("bzx", "regular"),
+  ("bzy", "regular"),
+  ("bzz", "regular"),
+  ("ca", "regular"),
+  ("caa", "regular"),
+  ("cab", "regular"),
+  ("cac", "regular"),
+  ("cad", "regular"),
+  ("cae", "regular"),
+  ("caf", "regular"),
+  ("cag", "regular"),
+  ("cah", "regular"),
+  ("cai", "regular"),
+  ("caj", "regular"),
+  ("cak", "regular"),
+  ("cal", "regular"),
+  ("cam", "regular"),
+  ("can", "regular"),
+  ("cao", "regular"),
+  ("cap", "regular"),
+  ("caq", "regular"),
+  ("car", "regular"),
+  ("cas", "regular"),
+  ("cau", "regular"),
+  ("cav", "regular"),
+  ("caw", "regular"),
+  ("cax", "regular"),
+  ("cay", "regular"),
+  ("caz", "regular"),
+  ("cba", "regular"),
+  ("cbb", "regular"),
+  ("cbc", "regular"),
+  ("cbd", "regular"),
+  ("cbe", "deprecated"),
+  ("cbg", "regular"),
+  ("cbh", "deprecated"),
+  ("cbi", "regular"),
+  ("cbj", "regular"),
+  ("cbk", "regular"),
+  ("cbl", "regular"),
+  ("cbn", "regular"),
+  ("cbo", "regular"),
+  ("cbq", "regular"),
+  ("cbr", "regular"),
+  ("cbs", "regular"),
+  ("cbt", "regular"),
+  ("cbu", "regular"),
+  ("cbv", "regular"),
+  ("cbw", "regular"),
+  ("cby", "regular"),
+  ("cca", "deprecated"),
+  ("ccc", "regular"),
+  ("ccd", "regular"),
+  ("cce", "regular"),
+  ("ccg", "regular"),
+  ("cch", "regular"),
+  ("ccj", "regular"),
+  ("ccl", "regular"),
+  ("ccm", "regular"),
+  ("ccn", "regular"),
+  ("cco", "regular"),
+  ("ccp", "regular"),
+  ("ccq", "deprecated"),
+  ("ccr", "regular"),
+  ("ccs", "regular"),
+  ("cda", "regular"),
+  ("cdc", "regular"),
+  ("cdd", "regular"),
+  ("cde", "regular"),
+  ("cdf", "regular"),
+  ("cdg", "deprecated"),
+  ("cdh", "regular"),
+  ("cdi", "regular"),
+  ("cdj", "regular"),
+  ("cdm", "regular"),
+  ("cdn", "regular"),
+  ("cdo", "regular"),
+  ("cdr", "regular"),
+  ("cds", "regular"),
+  ("cdy", "regular"),
+  ("cdz", "regular"),
+  ("ce", "regular"),
+  ("cea", "regular"),
+  ("ceb", "regular"),
+  ("ceg", "regular"),
+  ("cek", "regular"),
+  ("cel", "regular"),
+  ("cen", "regular"),
+  ("cet", "regular"),
+  ("cey", "regular"),
+  ("cfa", "regular"),
+  ("cfd", "regular"),
+  ("cfg", "regular"),
+  ("cfm", "regular"),
+  ("cga", "regular"),
+  ("cgc", "regular"),
+  ("cgg", "regular"),
+  ("cgk", "regular"),
+  ("ch", "regular"),
+  ("chb", "regular"),
+  ("chc", "regular"),
+  ("chd", "regular"),
+  ("chf", "regular"),
+  ("chg", "regular"),
+  ("chh", "regular"),
+  ("chj", "regular"),
+  ("chk", "regular"),
+  ("chl", "regular"),
+  ("chm", "regular"),
+  ("chn", "regular"),
+  ("cho", "regular"),
+  ("chp", "regular"),
+  ("chq", "regular"),
+  ("chr", "regular"),
+  ("cht", "regular"),
+  ("chw", "regular"),
+  ("chx", "regular"),
+  ("chy", "regular"),
+  ("chz", "regular"),
+  ("cia", "regular"),
+  ("cib", "regular"),
+  ("cic", "regular"),
+  ("cid", "regular"),
+  ("cie", "regular"),
+  ("cih", "regular"),
+  ("cik", "regular"),
+  ("cim", "regular"),
+  ("cin", "regular"),
+  ("cip", "regular"),
+  ("cir", "regular"),
+  ("ciw", "regular"),
+  ("ciy", "regular"),
+  ("cja", "regular"),
+  ("cje", "regular"),
+  ("cjh", "regular"),
+  ("cji", "regular"),
+  ("cjk", "regular"),
+  ("cjm", "regular"),
+  ("cjn", "regular"),
+  ("cjo", "regular"),
+  ("cjp", "regular"),
+  ("cjr", "deprecated"),
+  ("cjs", "regular"),
+  ("cjv", "regular"),
+  ("cjy", "regular"),
+  ("cka", "deprecated"),
+  ("ckb", "regular"),
+  ("ckh", "regular"),
+  ("ckl", "regular"),
+  ("ckm", "regular"),
+  ("ckn", "regular"),
+  ("cko", "regular"),
+  ("ckq", "regular"),
+  ("ckr", "regular"),
+  ("cks", "regular"),
+  ("ckt", "regular"),
+  ("cku", "regular"),
+  ("ckv", "regular"),
+  ("ckx", "regular"),
+  ("cky", "regular"),
+  ("ckz", "regular"),
+  ("cla", "regular"),
+  ("clc", "regular"),
+  ("cld", "deprecated"),
+  ("cle", "regular"),
+  ("clh", "regular"),
+  ("cli", "regular"),
+  ("clj", "regular"),
+  ("clk", "regular"),
+  ("cll", "regular"),
+  ("clm", "regular"),
+  ("clo", "regular"),
+  ("clt", "regular"),
+  ("clu", "regular"),
+  ("clw", "regular"),
+  ("cly", "regular"),
+  ("cma", "regular"),
+  ("cmc", "regular"),
+  ("cme", "regular"),
+  ("cmg", "regular"),
+  ("cmi", "regular"),
+  ("cmk", "deprecated"),
+  ("cml", "regular"),
+  ("cmm", "regular"),
+  ("cmn", "deprecated"),
+  ("cmo", "regular"),
+  ("cmr", "regular"),
+  ("cms", "regular"),
+  ("cmt", "regular"),
+  ("cna", "regular"),
+  ("cnb", "regular"),
+  ("cnc", "regular"),
+  ("cng", "regular"),
+  ("cnh", "regular"),
+  ("cni", "regular"),
+  ("cnk", "regular"),
+  ("cnl", "regular"),
+  ("cno", "regular"),
+  ("cnp", "regular"),
+  ("cnq", "regular"),
+  ("cnr", "deprecated"),
+  ("cns", "regular"),
+  ("cnt", "regular"),
+  ("cnu", "regular"),
+  ("cnw", "regular"),
+  ("cnx", "regular"),
+  ("co", "regular"),
+  ("coa", "regular"),
+  ("cob", "regular"),
+  ("coc", "regular"),
+  ("cod", "regular"),
+  ("coe", "regular"),
+  ("cof", "regular"),
+  ("cog", "regular"),
+  ("coh", "regular"),
+  ("coj", "regular"),
+  ("cok", "regular"),
+  ("col", "regular"),
+  ("com", "regular"),
+  ("con", "regular"),
+  ("coo", "regular"),
+  ("cop", "regular"),
+  ("coq", "regular"),
+  ("cot", "regular"),
+  ("cou", "regular"),
+  ("cov", "regular"),
+  ("cow", "regular"),
+  ("cox", "regular"),
+  ("coy", "deprecated"),
+  ("coz", "regular"),
+  ("cpa", "regular"),
+  ("cpb", "regular"),
+  ("cpc", "regular"),
+  ("cpe", "regular"),
+  ("cpf", "regular"),
+  ("cpg", "regular"),
+  ("cpi", "regular"),
+  ("cpn", "regular"),
+  ("cpo", "regular"),
+  ("cpp", "regular"),
+  ("cps", "regular"),
+  ("cpu", "regular"),
+  ("cpx", "regular"),
+  ("cpy", "regular"),
+  ("cqd", "regular"),
+  ("cqu", "deprecated"),
+  ("cr", "regular"),
+  ("cra", "regular"),
+  ("crb", "regular"),
+  ("crc", "regular"),
+  ("crd", "regular"),
+  ("crf", "regular"),
+  ("crg", "regular"),
+  ("crh", "regular"),
+  ("cri", "regular"),
+  ("crj", "regular"),
+  ("crk", "regular"),
+  ("crl", "regular"),
+  ("crm", "regular"),
+  ("crn", "regular"),
+  ("cro", "regular"),
+  ("crp", "regular"),
+  ("crq", "regular"),
+  ("crr", "regular"),
+  ("crs", "regular"),
+  ("crt", "regular"),
+  ("crv", "regular"),
+  ("crw", "regular"),
+  ("crx", "regular"),
+  ("cry", "regular"),
+  ("crz", "regular"),
+  ("cs", "regular"),
+  ("csa", "regular"),
+  ("csb", "regular"),
+  ("csc", "regular"),
+  ("csd", "regular"),
+  ("cse", "regular"),
+  ("csf", "regular"),
+  ("csg", "regular"),
+  ("csh", "regular"),
+  ("csi", "regular"),
+  ("csj", "regular"),
+  ("csk", "regular"),
+  ("csl", "regular"),
+  ("csm", "regular"),
+  ("csn", "regular"),
+  ("cso", "regular"),
+  ("csp", "regular"),
+  ("csq", "regular"),
+  ("csr", "regular"),
+  ("css", "regular"),
+  ("cst", "regular"),
+  ("csu", "regular"),
+  ("csv", "regular"),
+  ("csw", "regular"),
+  ("csx", "regular"),
+  ("csy", "regular"),
+  ("csz", "regular"),
+  ("cta", "regular"),
+  ("ctc", "regular"),
+  ("ctd", "regular"),
+  ("cte", "regular"),
+  ("ctg", "regular"),
+  ("cth", "regular"),
+  ("ctl", "regular"),
+  ("ctm", "regular"),
+  ("ctn", "regular"),
+  ("cto", "regular"),
+  ("ctp", "regular"),
+  ("cts", "regular"),
+  ("ctt", "regular"),
+  ("ctu", "regular"),
+  ("cty", "regular"),
+  ("ctz", "regular"),
+  ("cu", "regular"),
+  ("cua", "regular"),
+  ("cub", "regular"),
+  ("cuc", "regular"),
+  ("cug", "deprecated"),
+  ("cuh", "regular"),
+  ("cui", "regular"),
+  ("cuj", "regular"),
+  ("cuk", "regular"),
+  ("cul", "regular"),
+  ("cum", "deprecated"),
+  ("cuo", "regular"),
+  ("cup", "regular"),
+  ("cuq", "regular"),
+  ("cur", "regular"),
+  ("cus", "regular"),
+  ("cut", "regular"),
+  ("cuu", "regular"),
+  ("cuv", "regular"),
+  ("cuw", "regular"),
+  ("cux", "regular"),
+  ("cuy", "regular"),
+  ("cv", "regular"),
+  ("cvg", "regular"),
+  ("cvn", "regular"),
+  ("cwa", "regular"),
+  ("cwb", "regular"),
+  ("cwd", "deprecated"),
+  ("cwe", "regular"),
+  ("cwg", "regular"),
+  ("cwt", "regular"),
+  ("cy", "regular"),
+  ("cya", "regular"),
+  ("cyb", "regular"),
+  ("cyo", "regular"),
+  ("czh", "regular"),
+  ("czk", "regular"),
+  ("czn", "regular"),
+  ("czo", "regular"),
+  ("czt", "regular"),
+  ("da", "regular"),
+  ("daa", "regular"),
+  ("dac", "regular"),
+  ("dad", "regular"),
+  ("dae", "regular"),
+  ("daf", "deprecated"),
+  ("dag", "regular"),
+  ("dah", "regular"),
+  ("dai", "regular"),
+  ("daj", "regular"),
+  ("dak", "regular"),
+  ("dal", "regular"),
+  ("dam", "regular"),
+  ("dao", "regular"),
+  ("dap", "deprecated"),
+  ("daq", "regular"),
+  ("dar", "regular"),
+  ("das", "regular"),
+  ("dau", "regular"),
+  ("dav", "regular"),
+  ("daw", "regular"),
+  ("dax", "regular"),
+  ("day", "regular"),
+  ("daz", "regular"),
+  ("dba", "regular"),
+  ("dbb", "regular"),
+  ("dbd", "regular"),
+  ("dbe", "regular"),
+  ("dbf", "regular"),
+  ("dbg", "regular"),
+  ("dbi", "regular"),
+  ("dbj", "regular"),
+  ("dbl", "regular"),
+  ("dbm", "regular"),
+  ("dbn", "regular"),
+  ("dbo", "regular"),
+  ("dbp", "regular"),
+  ("dbq", "regular"),
+  ("dbr", "regular"),
+  ("dbt", "regular"),
+  ("dbu", "regular"),
+  ("dbv", "regular"),
+  ("dbw", "regular"),
+  ("dby", "regular"),
+  ("dcc", "regular"),
+  ("dcr", "regular"),
+  ("dda", "regular"),
+  ("ddd", "regular"),
+  ("dde", "regular"),
+  ("ddg", "regular"),
+  ("ddi", "regular"),
+  ("ddj", "regular"),
+  ("ddn", "regular"),
+  ("ddo", "regular"),
+  ("ddr", "regular"),
+  ("dds", "regular"),
+  ("ddw", "regular"),
+  ("de", "regular"),
+  ("dec", "regular"),
+  ("ded", "regular"),
+  ("dee", "regular"),
+  ("def", "regular"),
+  ("deg", "regular"),
+  ("deh", "regular"),
+  ("dei", "regular"),
+  ("dek", "regular"),
+  ("del", "regular"),
+  ("dem", "regular"),
+  ("den", "regular"),
+  ("dep", "regular"),
+  ("deq", "regular"),
+  ("der", "regular"),
+  ("des", "regular"),
+  ("dev", "regular"),
+  ("dez", "regular"),
+  ("dga", "regular"),
+  ("dgb", "regular"),
+  ("dgc", "regular"),
+  ("dgd", "regular"),
+  ("dge", "regular"),
+  ("dgg", "regular"),
+  ("dgh", "regular"),
+  ("dgi", "regular"),
+  ("dgk", "regular"),
+  ("dgl", "regular"),
+  ("dgn", "regular"),
+  ("dgo", "deprecated"),
+  ("dgr", "regular"),
+  ("dgs", "regular"),
+  ("dgt", "regular"),
+  ("dgu", "deprecated"),
+  ("dgw", "regular"),
+  ("dgx", "regular"),
+  ("dgz", "regular"),
+  ("dha", "deprecated"),
+  ("dhd", "deprecated"),
+  ("dhg", "regular"),
+  ("dhi", "regular"),
+  ("dhl", "regular"),
+  ("dhm", "regular"),
+  ("dhn", "regular"),
+  ("dho", "regular"),
+  ("dhr", "regular"),
+  ("dhs", "regular"),
+  ("dhu", "regular"),
+  ("dhv", "regular"),
+  ("dhw", "regular"),
+  ("dhx", "regular"),
+  ("dia", "regular"),
+  ("dib", "regular"),
+  ("dic", "regular"),
+  ("did", "regular"),
+  ("dif", "regular"),
+  ("dig", "regular"),
+  ("dih", "regular"),
+  ("dii", "regular"),
+  ("dij", "regular"),
+  ("dik", "deprecated"),
+  ("dil", "regular"),
+  ("dim", "regular"),
+  ("din", "regular"),
+  ("dio", "regular"),
+  ("dip", "regular"),
+  ("diq", "deprecated"),
+  ("dir", "regular"),
+  ("dis", "regular"),
+  ("dit", "deprecated"),
+  ("diu", "regular"),
+  ("diw", "regular"),
+  ("dix", "regular"),
+  ("diy", "regular"),
+  ("diz", "regular"),
+  ("dja", "regular"),
+  ("djb", "regular"),
+  ("djc", "regular"),
+  ("djd", "regular"),
+  ("dje", "regular"),
+  ("djf", "regular"),
+  ("dji", "regular"),
+  ("djj", "regular"),
+  ("djk", "regular"),
+  ("djl", "deprecated"),
+  ("djm", "regular"),
+  ("djn", "regular"),
+  ("djo", "regular"),
+  ("djr", "regular"),
+  ("dju", "regular"),
+  ("djw", "regular"),
+  ("dka", "regular"),
+  ("dkg", "regular"),
+  ("dkk", "regular"),
+  ("dkl", "deprecated"),
+  ("dkr", "regular"),
+  ("dks", "regular"),
+  ("dkx", "regular"),
+  ("dlg", "regular"),
+  ("dlk", "regular"),
+  ("dlm", "regular"),
+  ("dln", "regular"),
+  ("dma", "regular"),
+  ("dmb", "regular"),
+  ("dmc", "regular"),
+  ("dmd", "regular"),
+  ("dme", "regular"),
+  ("dmf", "regular"),
+  ("dmg", "regular"),
+  ("dmk", "regular"),
+  ("dml", "regular"),
+  ("dmm", "regular"),
+  ("dmn", "regular"),
+  ("dmo", "regular"),
+  ("dmr", "regular"),
+  ("dms", "regular"),
+  ("dmu", "regular"),
+  ("dmv", "regular"),
+  ("dmw", "regular"),
+  ("dmx", "regular"),
+  ("dmy", "regular"),
+  ("dna", "regular"),
+  ("dnd", "regular"),
+  ("dne", "regular"),
+  ("dng", "regular"),
+  ("dni", "regular"),
+  ("dnj", "regular"),
+  ("dnk", "regular"),
+  ("dnn", "regular"),
+  ("dno", "regular"),
+  ("dnr", "regular"),
+  ("dnt", "regular"),
+  ("dnu", "regular"),
+  ("dnv", "regular"),
+  ("dnw", "regular"),
+  ("dny", "regular"),
+  ("doa", "regular"),
+  ("dob", "regular"),
+  ("doc", "regular"),
+  ("doe", "regular"),
+  ("dof", "regular"),
+  ("doh", "regular"),
+  ("doi", "regular"),
+  ("dok", "regular"),
+  ("dol", "regular"),
+  ("don", "regular"),
+  ("doo", "regular"),
+  ("dop", "regular"),
+  ("doq", "regular"),
+  ("dor", "regular"),
+  ("dos", "regular"),
+  ("dot", "regular"),
+  ("dov", "regular"),
+  ("dow", "regular"),
+  ("dox", "regular"),
+  ("doy", "regular"),
+  ("doz", "regular"),
+  ("dpp", "regular"),
+  ("dra", "regular"),
+  ("drb", "regular"),
+  ("drc", "regular"),
+  ("drd", "regular"),
+  ("dre", "regular"),
+  ("drg", "regular"),
+  ("drh", "deprecated"),
+  ("dri", "regular"),
+  ("drl", "regular"),
+  ("drn", "regular"),
+  ("dro", "regular"),
+  ("drq", "regular"),
+  ("drr", "deprecated"),
+  ("drs", "regular"),
+  ("drt", "regular"),
+  ("dru", "regular"),
+  ("drw", "deprecated"),
+  ("dry", "regular"),
+  ("dsb", "regular"),
+  ("dse", "regular"),
+  ("dsh", "regular"),
+  ("dsi", "regular"),
+  ("dsl", "regular"),
+  ("dsn", "regular"),
+  ("dso", "regular"),
+  ("dsq", "regular"),
+  ("dsz", "regular"),
+  ("dta", "regular"),
+  ("dtb", "regular"),
+  ("dtd", "regular"),
+  ("dth", "regular"),
+  ("dti", "regular"),
+  ("dtk", "regular"),
+  ("dtm", "regular"),
+  ("dtn", "regular"),
+  ("dto", "regular"),
+  ("dtp", "regular"),
+  ("dtr", "regular"),
+  ("dts", "regular"),
+  ("dtt", "regular"),
+  ("dtu", "regular"),
+  ("dty", "regular"),
+  ("dua", "regular"),
+  ("dub", "regular"),
+  ("duc", "regular"),
+  ("dud", "deprecated"),
+  ("due", "regular"),
+  ("duf", "regular"),
+  ("dug", "regular"),
+  ("duh", "regular"),
+  ("dui", "regular"),
+  ("duj", "deprecated"),
+  ("duk", "regular"),
+  ("dul", "regular"),
+  ("dum", "regular"),
+  ("dun", "regular"),
+  ("duo", "regular"),
+  ("dup", "regular"),
+  ("duq", "regular"),
+  ("dur", "regular"),
+  ("dus", "regular"),
+  ("duu", "regular"),
+  ("duv", "regular"),
+  ("duw", "regular"),
+  ("dux", "regular"),
+  ("duy", "regular"),
+  ("duz", "regular"),
+  ("dv", "regular"),
+  ("dva", "regular"),
+  ("dwa", "regular"),
+  ("dwk", "regular"),
+  ("dwl", "deprecated"),
+  ("dwr", "regular"),
+  ("dws", "regular"),
+  ("dwu", "regular"),
+  ("dww", "regular"),
+  ("dwy", "regular"),
+  ("dwz", "regular"),
+  ("dya", "regular"),
+  ("dyb", "regular"),
+  ("dyd", "regular"),
+  ("dyg", "regular"),
+  ("dyi", "regular"),
+  ("dym", "regular"),
+  ("dyn", "regular"),
+  ("dyo", "regular"),
+  ("dyu", "regular"),
+  ("dyy", "regular"),
+  ("dz", "regular"),
+  ("dza", "regular"),
+  ("dzd", "deprecated"),
+  ("dze", "regular"),
+  ("dzg", "regular"),
+  ("dzl", "regular"),
+  ("dzn", "regular"),
+  ("eaa", "regular"),
+  ("ebc", "regular"),
+  ("ebg", "regular"),
+  ("ebk", "regular"),
+  ("ebo", "regular"),
+  ("ebr", "regular"),
+  ("ebu", "regular"),
+  ("ecr", "regular"),
+  ("ecs", "regular"),
+  ("ecy", "regular"),
+  ("ee", "regular"),
+  ("eee", "regular"),
+  ("efa", "regular"),
+  ("efe", "regular"),
+  ("efi", "regular"),
+  ("ega", "regular"),
+  ("egl", "regular"),
+  ("egm", "regular"),
+  ("ego", "regular"),
+  ("egx", "regular"),
+  ("egy", "regular"),
+  ("ehs", "regular"),
+  ("ehu", "regular"),
+  ("eip", "regular"),
+  ("eit", "regular"),
+  ("eiv", "regular"),
+  ("eja", "regular"),
+  ("eka", "regular"),
+  ("ekc", "deprecated"),
+  ("eke", "regular"),
+  ("ekg", "regular"),
+  ("eki", "regular"),
+  ("ekk", "deprecated"),
+  ("ekl", "regular"),
+  ("ekm", "regular"),
+  ("eko", "regular"),
+  ("ekp", "regular"),
+  ("ekr", "regular"),
+  ("eky", "regular"),
+  ("el", "regular"),
+  ("ele", "regular"),
+  ("elh", "regular"),
+  ("eli", "regular"),
+  ("elk", "regular"),
+  ("elm", "regular"),
+  ("elo", "regular"),
+  ("elp", "deprecated"),
+  ("elu", "regular"),
+  ("elx", "regular"),
+  ("ema", "regular"),
+  ("emb", "regular"),
+  ("eme", "regular"),
+  ("emg", "regular"),
+  ("emi", "regular"),
+  ("emk", "deprecated"),
+  ("emm", "regular"),
+  ("emn", "regular"),
+  ("emo", "deprecated"),
+  ("emp", "regular"),
+  ("emq", "regular"),
+  ("ems", "regular"),
+  ("emu", "regular"),
+  ("emw", "regular"),
+  ("emx", "regular"),
+  ("emy", "regular"),
+  ("emz", "regular"),
+  ("en", "regular"),
+  ("ena", "regular"),
+  ("enb", "regular"),
+  ("enc", "regular"),
+  ("end", "regular"),
+  ("enf", "regular"),
+  ("enh", "regular"),
+  ("enl", "regular"),
+  ("enm", "regular"),
+  ("enn", "regular"),
+  ("eno", "regular"),
+  ("enq", "regular"),
+  ("enr", "regular"),
+  ("enu", "regular"),
+  ("env", "regular"),
+  ("enw", "regular"),
+  ("enx", "regular"),
+  ("eo", "regular"),
+  ("eot", "regular"),
+  ("epi", "regular"),
+  ("era", "regular"),
+  ("erg", "regular"),
+  ("erh", "regular"),
+  ("eri", "regular"),
+  ("erk", "regular"),
+  ("ero", "regular"),
+  ("err", "regular"),
+  ("ers", "regular"),
+  ("ert", "regular"),
+  ("erw", "regular"),
+  ("es", "regular"),
+  ("ese", "regular"),
+  ("esg", "regular"),
+  ("esh", "regular"),
+  ("esi", "regular"),
+  ("esk", "deprecated"),
+  ("esl", "regular"),
+  ("esm", "regular"),
+  ("esn", "regular"),
+  ("eso", "regular"),
+  ("esq", "regular"),
+  ("ess", "regular"),
+  ("esu", "regular"),
+  ("esx", "regular"),
+  ("esy", "regular"),
+  ("et", "regular"),
+  ("etb", "regular"),
+  ("etc", "regular"),
+  ("eth", "regular"),
+  ("etn", "regular"),
+  ("eto", "regular"),
+  ("etr", "regular"),
+  ("ets", "regular"),
+  ("ett", "regular"),
+  ("etu", "regular"),
+  ("etx", "regular"),
+  ("etz", "regular"),
+  ("eu", "regular"),
+  ("euq", "regular"),
+  ("eve", "regular"),
+  ("evh", "regular"),
+  ("evn", "regular"),
+  ("ewo", "regular"),
+  ("ext", "regular"),
+  ("eya", "regular"),
+  ("eyo", "regular"),
+  ("eza", "regular"),
+  ("eze", "regular"),
+  ("fa", "regular"),
+  ("faa", "regular"),
+  ("fab", "regular"),
+  ("fad", "regular"),
+  ("faf", "regular"),
+  ("fag", "regular"),
+  ("fah", "regular"),
+  ("fai", "regular"),
+  ("faj", "regular"),
+  ("fak", "regular"),
+  ("fal", "regular"),
+  ("fam", "regular"),
+  ("fan", "regular"),
+  ("fap", "regular"),
+  ("far", "regular"),
+  ("fat", "deprecated"),
+  ("fau", "regular"),
+  ("fax", "regular"),
+  ("fay", "regular"),
+  ("faz", "regular"),
+  ("fbl", "regular"),
+  ("fcs", "regular"),
+  ("fer", "regular"),
+  ("ff", "regular"),
+  ("ffi", "regular"),
+  ("ffm", "regular"),
+  ("fgr", "regular"),
+  ("fi", "regular"),
+  ("fia", "regular"),
+  ("fie", "regular"),
+  ("fif", "regular"),
+  ("fil", "regular"),
+  ("fip", "regular"),
+  ("fir", "regular"),
+  ("fit", "regular"),
+  ("fiu", "regular"),
+  ("fiw", "regular"),
+  ("fj", "regular"),
+  ("fkk", "regular"),
+  ("fkv", "regular"),
+  ("fla", "regular"),
+  ("flh", "regular"),
+  ("fli", "regular"),
+  ("fll", "regular"),
+  ("fln", "regular"),
+  ("flr", "regular"),
+  ("fly", "regular"),
+  ("fmp", "regular"),
+  ("fmu", "regular"),
+  ("fnb", "regular"),
+  ("fng", "regular"),
+  ("fni", "regular"),
+  ("fo", "regular"),
+  ("fod", "regular"),
+  ("foi", "regular"),
+  ("fom", "regular"),
+  ("fon", "regular"),
+  ("for", "regular"),
+  ("fos", "regular"),
+  ("fox", "regular"),
+  ("fpe", "regular"),
+  ("fqs", "regular"),
+  ("fr", "regular"),
+  ("frc", "regular"),
+  ("frd", "regular"),
+  ("frk", "regular"),
+  ("frm", "regular"),
+  ("fro", "regular"),
+  ("frp", "regular"),
+  ("frq", "regular"),
+  ("frr", "regular"),
+  ("frs", "regular"),
+  ("frt", "regular"),
+  ("fse", "regular"),
+  ("fsl", "regular"),
+  ("fss", "regular"),
+  ("fub", "regular"),
+  ("fuc", "deprecated"),
+  ("fud", "regular"),
+  ("fue", "regular"),
+  ("fuf", "regular"),
+  ("fuh", "regular"),
+  ("fui", "regular"),
+  ("fuj", "regular"),
+  ("fum", "regular"),
+  ("fun", "regular"),
+  ("fuq", "regular"),
+  ("fur", "regular"),
+  ("fut", "regular"),
+  ("fuu", "regular"),
+  ("fuv", "regular"),
+  ("fuy", "regular"),
+  ("fvr", "regular"),
+  ("fwa", "regular"),
+  ("fwe", "regular"),
+  ("fy", "regular"),
+  ("ga", "regular"),
+  ("gaa", "regular"),
+  ("gab", "regular"),
+  ("gac", "regular"),
+  ("gad", "regular"),
+  ("gae", "regular"),
+  ("gaf", "regular"),
+  ("gag", "regular"),
+  ("gah", "regular"),
+  ("gai", "regular"),
+  ("gaj", "regular"),
+  ("gak", "regular"),
+  ("gal", "regular"),
+  ("gam", "regular"),
+  ("gan", "regular"),
+  ("gao", "regular"),
+  ("gap", "regular"),
+  ("gaq", "regular"),
+  ("gar", "regular"),
+  ("gas", "regular"),
+  ("gat", "regular"),
+  ("gau", "regular"),
+  ("gav", "deprecated"),
+  ("gaw", "regular"),
+  ("gax", "regular"),
+  ("gay", "regular"),
+  ("gaz", "deprecated"),
+  ("gba", "regular"),
+  ("gbb", "regular"),
+  ("gbc", "deprecated"),
+  ("gbd", "regular"),
+  ("gbe", "regular"),
+  ("gbf", "regular"),
+  ("gbg", "regular"),
+  ("gbh", "regular"),
+  ("gbi", "regular"),
+  ("gbj", "regular"),
+  ("gbk", "regular"),
+  ("gbl", "regular"),
+  ("gbm", "regular"),
+  ("gbn", "regular"),
+  ("gbo", "deprecated"),
+  ("gbp", "regular"),
+  ("gbq", "regular"),
+  ("gbr", "regular"),
+  ("gbs", "regular"),
+  ("gbu", "regular"),
+  ("gbv", "regular"),
+  ("gbw", "regular"),
+  ("gbx", "regular"),
+  ("gby", "regular"),
+  ("gbz", "regular"),
+  ("gcc", "regular"),
+  ("gcd", "regular"),
+  ("gce", "regular"),
+  ("gcf", "regular"),
+  ("gcl", "regular"),
+  ("gcn", "regular"),
+  ("gcr", "regular"),
+  ("gct", "regular"),
+  ("gd", "regular"),
+  ("gda", "regular"),
+  ("gdb", "regular"),
+  ("gdc", "regular"),
+  ("gdd", "regular"),
+  ("gde", "regular"),
+  ("gdf", "regular"),
+  ("gdg", "regular"),
+  ("gdh", "regular"),
+  ("gdi", "regular"),
+  ("gdj", "regular"),
+  ("gdk", "regular"),
+  ("gdl", "regular"),
+  ("gdm", "regular"),
+  ("gdn", "regular"),
+  ("gdo", "regular"),
+  ("gdq", "regular"),
+  ("gdr", "regular"),
+  ("gds", "regular"),
+  ("gdt", "regular"),
+  ("gdu", "regular"),
+  ("gdx", "regular"),
+  ("gea", "regular"),
+  ("geb", "regular"),
+  ("gec", "regular"),
+  ("ged", "regular"),
+  ("gef", "regular"),
+  ("geg", "regular"),
+  ("geh", "regular"),
+  ("gei", "regular"),
+  ("gej", "regular"),
+  ("gek", "regular"),
+  ("gel", "regular"),
+  ("gem", "regular"),
+  ("geq", "regular"),
+  ("ges", "regular"),
+  ("gev", "regular"),
+  ("gew", "regular"),
+  ("gex", "regular"),
+  ("gey", "regular"),
+  ("gez", "regular"),
+  ("gfk", "regular"),
+  ("gft", "regular"),
+  ("gfx", "deprecated"),
+  ("gga", "regular"),
+  ("ggb", "regular"),
+  ("ggd", "regular"),
+  ("gge", "regular"),
+  ("ggg", "regular"),
+  ("ggk", "regular"),
+  ("ggl", "regular"),
+  ("ggn", "deprecated"),
+  ("ggo", "deprecated"),
+  ("ggr", "deprecated"),
+  ("ggt", "regular"),
+  ("ggu", "regular"),
+  ("ggw", "regular"),
+  ("gha", "regular"),
+  ("ghc", "regular"),
+  ("ghe", "regular"),
+  ("ghh", "regular"),
+  ("ghk", "regular"),
+  ("ghl", "regular"),
+  ("ghn", "regular"),
+  ("gho", "regular"),
+  ("ghr", "regular"),
+  ("ghs", "regular"),
+  ("ght", "regular"),
+  ("gia", "regular"),
+  ("gib", "regular"),
+  ("gic", "regular"),
+  ("gid", "regular"),
+  ("gie", "regular"),
+  ("gig", "regular"),
+  ("gih", "regular"),
+  ("gii", "regular"),
+  ("gil", "regular"),
+  ("gim", "regular"),
+  ("gin", "regular"),
+  ("gio", "deprecated"),
+  ("gip", "regular"),
+  ("giq", "regular"),
+  ("gir", "regular"),
+  ("gis", "regular"),
+  ("git", "regular"),
+  ("giu", "regular"),
+  ("giw", "regular"),
+  ("gix", "regular"),
+  ("giy", "regular"),
+  ("giz", "regular"),
+  ("gji", "deprecated"),
+  ("gjk", "regular"),
+  ("gjm", "regular"),
+  ("gjn", "regular"),
+  ("gjr", "regular"),
+  ("gju", "regular"),
+  ("gka", "regular"),
+  ("gkd", "regular"),
+  ("gke", "regular"),
+  ("gkn", "regular"),
+  ("gko", "regular"),
+  ("gkp", "regular"),
+  ("gku", "regular"),
+  ("gl", "regular"),
+  ("glb", "regular"),
+  ("glc", "regular"),
+  ("gld", "regular"),
+  ("glh", "regular"),
+  ("gli", "deprecated"),
+  ("glj", "regular"),
+  ("glk", "regular"),
+  ("gll", "regular"),
+  ("glo", "regular"),
+  ("glr", "regular"),
+  ("glu", "regular"),
+  ("glw", "regular"),
+  ("gly", "regular"),
+  ("gma", "regular"),
+  ("gmb", "regular"),
+  ("gmd", "regular"),
+  ("gme", "regular"),
+  ("gmg", "regular"),
+  ("gmh", "regular"),
+  ("gml", "regular"),
+  ("gmm", "regular"),
+  ("gmn", "regular"),
+  ("gmq", "regular"),
+  ("gmr", "regular"),
+  ("gmu", "regular"),
+  ("gmv", "regular"),
+  ("gmw", "regular"),
+  ("gmx", "regular"),
+  ("gmy", "regular"),
+  ("gmz", "regular"),
+  ("gn", "regular"),
+  ("gna", "regular"),
+  ("gnb", "regular"),
+  ("gnc", "regular"),
+  ("gnd", "regular"),
+  ("gne", "regular"),
+  ("gng", "regular"),
+  ("gnh", "regular"),
+  ("gni", "regular"),
+  ("gnj", "regular"),
+  ("gnk", "regular"),
+  ("gnl", "regular"),
+  ("gnm", "regular"),
+  ("gnn", "regular"),
+  ("gno", "deprecated"),
+  ("gnq", "regular"),
+  ("gnr", "regular"),
+  ("gnt", "regular"),
+  ("gnu", "regular"),
+  ("gnw", "regular"),
+  ("gnz", "regular"),
+  ("goa", "regular"),
+  ("gob", "regular"),
+  ("goc", "regular"),
+  ("god", "regular"),
+  ("goe", "regular"),
+  ("gof", "regular"),
+  ("gog", "regular"),
+  ("goh", "regular"),
+  ("goi", "regular"),
+  ("goj", "regular"),
+  ("gok", "regular"),
+  ("gol", "regular"),
+  ("gom", "regular"),
+  ("gon", "regular"),
+  ("goo", "regular"),
+  ("gop", "regular"),
+  ("goq", "regular"),
+  ("gor", "regular"),
+  ("gos", "regular"),
+  ("got", "regular"),
+  ("gou", "regular"),
+  ("gov", "regular"),
+  ("gow", "regular"),
+  ("gox", "regular"),
+  ("goy", "regular"),
+  ("goz", "regular"),
+  ("gpa", "regular"),
+  ("gpe", "regular"),
+  ("gpn", "regular"),
+  ("gqa", "regular"),
+  ("gqi", "regular"),
+  ("gqn", "regular"),
+  ("gqr", "regular"),
+  ("gqu", "regular"),
+  ("gra", "regular"),
+  ("grb", "regular"),
+  ("grc", "regular"),
+  ("grd", "regular"),
+  ("grg", "regular"),
+  ("grh", "regular"),
+  ("gri", "regular"),
+  ("grj", "regular"),
+  ("grk", "regular"),
+  ("grm", "regular"),
+  ("gro", "regular"),
+  ("grq", "regular"),
+  ("grr", "regular"),
+  ("grs", "regular"),
+  ("grt", "regular"),
+  ("gru", "regular"),
+  ("grv", "regular"),
+  ("grw", "regular"),
+  ("grx", "regular"),
+  ("gry", "regular"),
+  ("grz", "regular"),
+  ("gse", "regular"),
+  ("gsg", "regular"),
+  ("gsl", "regular"),
+  ("gsm", "regular"),
+  ("gsn", "regular"),
+  ("gso", "regular"),
+  ("gsp", "regular"),
+  ("gss", "regular"),
+  ("gsw", "regular"),
+  ("gta", "regular"),
+  ("gti", "deprecated"),
+  ("gtu", "regular"),
+  ("gu", "regular"),
+  ("gua", "regular"),
+  ("gub", "regular"),
+  ("guc", "regular"),
+  ("gud", "regular"),
+  ("gue", "regular"),
+  ("guf", "regular"),
+  ("gug", "deprecated"),
+  ("guh", "regular"),
+  ("gui", "regular"),
+  ("guk", "regular"),
+  ("gul", "regular"),
+  ("gum", "regular"),
+  ("gun", "regular"),
+  ("guo", "regular"),
+  ("gup", "regular"),
+  ("guq", "regular"),
+  ("gur", "regular"),
+  ("gus", "regular"),
+  ("gut", "regular"),
+  ("guu", "regular"),
+  ("guv", "deprecated"),
+  ("guw", "regular"),
+  ("gux", "regular"),
+  ("guz", "regular"),
+  ("gv", "regular"),
+  ("gva", "regular"),
+  ("gvc", "regular"),
+  ("gve", "regular"),
+  ("gvf", "regular"),
+  ("gvj", "regular"),
+  ("gvl", "regular"),
+  ("gvm", "regular"),
+  ("gvn", "regular"),
+  ("gvo", "regular"),
+  ("gvp", "regular"),
+  ("gvr", "regular"),
+  ("gvs", "regular"),
+  ("gvy", "regular"),
+  ("gwa", "regular"),
+  ("gwb", "regular"),
+  ("gwc", "regular"),
+  ("gwd", "regular"),
+  ("gwe", "regular"),
+  ("gwf", "regular"),
+  ("gwg", "regular"),
+  ("gwi", "regular"),
+  ("gwj", "regular"),
+  ("gwm", "regular"),
+  ("gwn", "regular"),
+  ("gwr", "regular"),
+  ("gwt", "regular"),
+  ("gwu", "regular"),
+  ("gww", "regular"),
+  ("gwx", "regular"),
+  ("gxx", "regular"),
+  ("gya", "deprecated"),
+  ("gyb", "regular"),
+  ("gyd", "regular"),
+  ("gye", "regular"),
+  ("gyf", "regular"),
+  ("gyg", "regular"),
+  ("gyi", "regular"),
+  ("gyl", "regular"),
+  ("gym", "regular"),
+  ("gyn", "regular"),
+  ("gyo", "regular"),
+  ("gyr", "regular"),
+  ("gyy", "regular"),
+  ("gyz", "regular"),
+  ("gza", "regular"),
+  ("gzi", "regular"),
+  ("gzn", "regular"),
+  ("ha", "regular"),
+  ("haa", "regular"),
+  ("hab", "regular"),
+  ("hac", "regular"),
+  ("had", "regular"),
+  ("hae", "regular"),
+  ("haf", "regular"),
+  ("hag", "regular"),
+  ("hah", "regular"),
+  ("hai", "regular"),
+  ("haj", "regular"),
+  ("hak", "regular"),
+  ("hal", "regular"),
+  ("ham", "regular"),
+  ("han", "regular"),
+  ("hao", "regular"),
+  ("hap", "regular"),
+  ("haq", "regular"),
+  ("har", "regular"),
+  ("has", "regular"),
+  ("hav", "regular"),
+  ("haw", "regular"),
+  ("hax", "regular"),
+  ("hay", "regular"),
+  ("haz", "regular"),
+  ("hba", "regular"),
+  ("hbb", "regular"),
+  ("hbn", "regular"),
+  ("hbo", "regular"),
+  ("hbu", "regular"),
+  ("hca", "regular"),
+  ("hch", "regular"),
+  ("hdn", "deprecated"),
+  ("hds", "regular"),
+  ("hdy", "regular"),
+  ("he", "regular"),
+  ("hea", "deprecated"),
+  ("hed", "regular"),
+  ("heg", "regular"),
+  ("heh", "regular"),
+  ("hei", "regular"),
+  ("hem", "regular"),
+  ("hgm", "regular"),
+  ("hgw", "regular"),
+  ("hhi", "regular"),
+  ("hhr", "regular"),
+  ("hhy", "regular"),
+  ("hi", "regular"),
+  ("hia", "regular"),
+  ("hib", "regular"),
+  ("hid", "regular"),
+  ("hif", "regular"),
+  ("hig", "regular"),
+  ("hih", "regular"),
+  ("hii", "regular"),
+  ("hij", "regular"),
+  ("hik", "regular"),
+  ("hil", "regular"),
+  ("him", "deprecated"),
+  ("hio", "regular"),
+  ("hir", "regular"),
+  ("hit", "regular"),
+  ("hiw", "regular"),
+  ("hix", "regular"),
+  ("hji", "regular"),
+  ("hka", "regular"),
+  ("hke", "regular"),
+  ("hkh", "regular"),
+  ("hkk", "regular"),
+  ("hkn", "regular"),
+  ("hks", "regular"),
+  ("hla", "regular"),
+  ("hlb", "regular"),
+  ("hld", "regular"),
+  ("hle", "regular"),
+  ("hlt", "regular"),
+  ("hlu", "regular"),
+  ("hma", "regular"),
+  ("hmb", "regular"),
+  ("hmc", "regular"),
+  ("hmd", "regular"),
+  ("hme", "regular"),
+  ("hmf", "regular"),
+  ("hmg", "regular"),
+  ("hmh", "regular"),
+  ("hmi", "regular"),
+  ("hmj", "regular"),
+  ("hmk", "regular"),
+  ("hml", "regular"),
+  ("hmm", "regular"),
+  ("hmn", "regular"),
+  ("hmp", "regular"),
+  ("hmq", "regular"),
+  ("hmr", "regular"),
+  ("hms", "regular"),
+  ("hmt", "regular"),
+  ("hmu", "regular"),
+  ("hmv", "regular"),
+  ("hmw", "regular"),
+  ("hmx", "regular"),
+  ("hmy", "regular"),
+  ("hmz", "regular"),
+  ("hna", "regular"),
+  ("hnd", "regular"),
+  ("hne", "regular"),
+  ("hng", "regular"),
+  ("hnh", "regular"),
+  ("hni", "regular"),
+  ("hnj", "regular"),
+  ("hnn", "regular"),
+  ("hno", "regular"),
+  ("hns", "regular"),
+  ("hnu", "regular"),
+  ("ho", "regular"),
+  ("hoa", "regular"),
+  ("hob", "regular"),
+  ("hoc", "regular"),
+  ("hod", "regular"),
+  ("hoe", "regular"),
+  ("hoh", "regular"),
+  ("hoi", "regular"),
+  ("hoj", "regular"),
+  ("hok", "regular"),
+  ("hol", "regular"),
+  ("hom", "regular"),
+  ("hoo", "regular"),
+  ("hop", "regular"),
+  ("hor", "regular"),
+  ("hos", "regular"),
+  ("hot", "regular"),
+  ("hov", "regular"),
+  ("how", "regular"),
+  ("hoy", "regular"),
+  ("hoz", "regular"),
+  ("hpo", "regular"),
+  ("hps", "regular"),
+  ("hr", "regular"),
+  ("hra", "regular"),
+  ("hrc", "regular"),
+  ("hre", "regular"),
+  ("hrk", "regular"),
+  ("hrm", "regular"),
+  ("hro", "regular"),
+  ("hrp", "regular"),
+  ("hrr", "deprecated"),
+  ("hrt", "regular"),
+  ("hru", "regular"),
+  ("hrw", "regular"),
+  ("hrx", "regular"),
+  ("hrz", "regular"),
+  ("hsb", "regular"),
+  ("hsh", "regular"),
+  ("hsl", "regular"),
+  ("hsn", "regular"),
+  ("hss", "regular"),
+  ("ht", "regular"),
+  ("hti", "regular"),
+  ("hto", "regular"),
+  ("hts", "regular"),
+  ("htu", "regular"),
+  ("htx", "regular"),
+  ("hu", "regular"),
+  ("hub", "regular"),
+  ("huc", "regular"),
+  ("hud", "regular"),
+  ("hue", "regular"),
+  ("huf", "regular"),
+  ("hug", "regular"),
+  ("huh", "regular"),
+  ("hui", "regular"),
+  ("huj", "regular"),
+  ("huk", "regular"),
+  ("hul", "regular"),
+  ("hum", "regular"),
+  ("huo", "regular"),
+  ("hup", "regular"),
+  ("huq", "regular"),
+  ("hur", "regular"),
+  ("hus", "regular"),
+  ("hut", "regular"),
+  ("huu", "regular"),
+  ("huv", "regular"),
+  ("huw", "regular"),
+  ("hux", "regular"),
+  ("huy", "regular"),
+  ("huz", "regular"),
+  ("hvc", "regular"),
+  ("hve", "regular"),
+  ("hvk", "regular"),
+  ("hvn", "regular"),
+  ("hvv", "regular"),
+  ("hwa", "regular"),
+  ("hwc", "regular"),
+  ("hwo", "regular"),
+  ("hy", "regular"),
+  ("hya", "regular"),
+  ("hyw", "regular"),
+  ("hyx", "regular"),
+  ("hz", "regular"),
+  ("ia", "regular"),
+  ("iai", "regular"),
+  ("ian", "regular"),
+  ("iap", "deprecated"),
+  ("iar", "regular"),
+  ("iba", "regular"),
+  ("ibb", "regular"),
+  ("ibd", "regular"),
+  ("ibe", "regular"),
+  ("ibg", "regular"),
+  ("ibh", "regular"),
+  ("ibi", "deprecated"),
+  ("ibl", "regular"),
+  ("ibm", "regular"),
+  ("ibn", "regular"),
+  ("ibr", "regular"),
+  ("ibu", "regular"),
+  ("iby", "regular"),
+  ("ica", "regular"),
+  ("ich", "regular"),
+  ("icl", "regular"),
+  ("icr", "regular"),
+  ("id", "regular"),
+  ("ida", "regular"),
+  ("idb", "regular"),
+  ("idc", "regular"),
+  ("idd", "regular"),
+  ("ide", "regular"),
+  ("idi", "regular"),
+  ("idr", "regular"),
+  ("ids", "regular"),
+  ("idt", "regular"),
+  ("idu", "regular"),
+  ("ie", "regular"),
+  ("ifa", "regular"),
+  ("ifb", "regular"),
+  ("ife", "regular"),
+  ("iff", "regular"),
+  ("ifk", "regular"),
+  ("ifm", "regular"),
+  ("ifu", "regular"),
+  ("ify", "regular"),
+  ("ig", "regular"),
+  ("igb", "regular"),
+  ("ige", "regular"),
+  ("igg", "regular"),
+  ("igl", "regular"),
+  ("igm", "regular"),
+  ("ign", "regular"),
+  ("igo", "regular"),
+  ("igs", "regular"),
+  ("igw", "regular"),
+  ("ihb", "regular"),
+  ("ihi", "regular"),
+  ("ihp", "regular"),
+  ("ihw", "regular"),
+  ("ii", "regular"),
+  ("iin", "regular"),
+  ("iir", "regular"),
+  ("ijc", "regular"),
+  ("ije", "regular"),
+  ("ijj", "regular"),
+  ("ijn", "regular"),
+  ("ijo", "regular"),
+  ("ijs", "regular"),
+  ("ik", "regular"),
+  ("ike", "deprecated"),
+  ("iki", "regular"),
+  ("ikk", "regular"),
+  ("ikl", "regular"),
+  ("iko", "regular"),
+  ("ikp", "regular"),
+  ("ikr", "regular"),
+  ("iks", "regular"),
+  ("ikt", "regular"),
+  ("ikv", "regular"),
+  ("ikw", "regular"),
+  ("ikx", "regular"),
+  ("ikz", "regular"),
+  ("ila", "regular"),
+  ("ilb", "regular"),
+  ("ilg", "regular"),
+  ("ili", "regular"),
+  ("ilk", "regular"),
+  ("ill", "deprecated"),
+  ("ilm", "regular"),
+  ("ilo", "regular"),
+  ("ilp", "regular"),
+  ("ils", "regular"),
+  ("ilu", "regular"),
+  ("ilv", "regular"),
+  ("ilw", "deprecated"),
+  ("ima", "regular"),
+  ("ime", "deprecated"),
+  ("imi", "regular"),
+  ("iml", "regular"),
+  ("imn", "regular"),
+  ("imo", "regular"),
+  ("imr", "regular"),
+  ("ims", "regular"),
+  ("imt", "regular"),
+  ("imy", "regular"),
+  ("in", "deprecated"),
+  ("inb", "regular"),
+  ("inc", "regular"),
+  ("ine", "regular"),
+  ("ing", "regular"),
+  ("inh", "regular"),
+  ("inj", "regular"),
+  ("inl", "regular"),
+  ("inm", "regular"),
+  ("inn", "regular"),
+  ("ino", "regular"),
+  ("inp", "regular"),
+  ("ins", "regular"),
+  ("int", "regular"),
+  ("inz", "regular"),
+  ("io", "regular"),
+  ("ior", "regular"),
+  ("iou", "regular"),
+  ("iow", "regular"),
+  ("ipi", "regular"),
+  ("ipo", "regular"),
+  ("iqu", "regular"),
+  ("iqw", "regular"),
+  ("ira", "regular"),
+  ("ire", "regular"),
+  ("irh", "regular"),
+  ("iri", "regular"),
+  ("irk", "regular"),
+  ("irn", "regular"),
+  ("iro", "regular"),
+  ("irr", "regular"),
+  ("iru", "regular"),
+  ("irx", "regular"),
+  ("iry", "regular"),
+  ("is", "regular"),
+  ("isa", "regular"),
+  ("isc", "regular"),
+  ("isd", "regular"),
+  ("ise", "regular"),
+  ("isg", "regular"),
+  ("ish", "regular"),
+  ("isi", "regular"),
+  ("isk", "regular"),
+  ("ism", "regular"),
+  ("isn", "regular"),
+  ("iso", "regular"),
+  ("isr", "regular"),
+  ("ist", "regular"),
+  ("isu", "regular"),
+  ("it", "regular"),
+  ("itb", "regular"),
+  ("itc", "regular"),
+  ("itd", "regular"),
+  ("ite", "regular"),
+  ("iti", "regular"),
+  ("itk", "regular"),
+  ("itl", "regular"),
+  ("itm", "regular"),
+  ("ito", "regular"),
+  ("itr", "regular"),
+  ("its", "regular"),
+  ("itt", "regular"),
+  ("itv", "regular"),
+  ("itw", "regular"),
+  ("itx", "regular"),
+  ("ity", "regular"),
+  ("itz", "regular"),
+  ("iu", "regular"),
+  ("ium", "regular"),
+  ("ivb", "regular"),
+  ("ivv", "regular"),
+  ("iw", "deprecated"),
+  ("iwk", "regular"),
+  ("iwm", "regular"),
+  ("iwo", "regular"),
+  ("iws", "regular"),
+  ("ixc", "regular"),
+  ("ixl", "regular"),
+  ("iya", "regular"),
+  ("iyo", "regular"),
+  ("iyx", "regular"),
+  ("izh", "regular"),
+  ("izi", "deprecated"),
+  ("izr", "regular"),
+  ("izz", "regular"),
+  ("ja", "regular"),
+  ("jaa", "regular"),
+  ("jab", "regular"),
+  ("jac", "regular"),
+  ("jad", "regular"),
+  ("jae", "regular"),
+  ("jaf", "regular"),
+  ("jah", "regular"),
+  ("jaj", "regular"),
+  ("jak", "regular"),
+  ("jal", "regular"),
+  ("jam", "regular"),
+  ("jan", "regular"),
+  ("jao", "regular"),
+  ("jaq", "regular"),
+  ("jar", "deprecated"),
+  ("jas", "regular"),
+  ("jat", "regular"),
+  ("jau", "regular"),
+  ("jax", "regular"),
+  ("jay", "regular"),
+  ("jaz", "regular"),
+  ("jbe", "regular"),
+  ("jbi", "regular"),
+  ("jbj", "regular"),
+  ("jbk", "regular"),
+  ("jbm", "regular"),
+  ("jbn", "regular"),
+  ("jbo", "regular"),
+  ("jbr", "regular"),
+  ("jbt", "regular"),
+  ("jbu", "regular"),
+  ("jbw", "regular"),
+  ("jcs", "regular"),
+  ("jct", "regular"),
+  ("jda", "regular"),
+  ("jdg", "regular"),
+  ("jdt", "regular"),
+  ("jeb", "regular"),
+  ("jee", "regular"),
+  ("jeg", "deprecated"),
+  ("jeh", "regular"),
+  ("jei", "regular"),
+  ("jek", "regular"),
+  ("jel", "regular"),
+  ("jen", "regular"),
+  ("jer", "regular"),
+  ("jet", "regular"),
+  ("jeu", "regular"),
+  ("jgb", "regular"),
+  ("jge", "regular"),
+  ("jgk", "regular"),
+  ("jgo", "regular"),
+  ("jhi", "regular"),
+  ("jhs", "regular"),
+  ("ji", "deprecated"),
+  ("jia", "regular"),
+  ("jib", "regular"),
+  ("jic", "regular"),
+  ("jid", "regular"),
+  ("jie", "regular"),
+  ("jig", "regular"),
+  ("jih", "regular"),
+  ("jii", "regular"),
+  ("jil", "regular"),
+  ("jim", "regular"),
+  ("jio", "regular"),
+  ("jiq", "regular"),
+  ("jit", "regular"),
+  ("jiu", "regular"),
+  ("jiv", "regular"),
+  ("jiy", "regular"),
+  ("jje", "regular"),
+  ("jjr", "regular"),
+  ("jka", "regular"),
+  ("jkm", "regular"),
+  ("jko", "regular"),
+  ("jkp", "regular"),
+  ("jkr", "regular"),
+  ("jks", "regular"),
+  ("jku", "regular"),
+  ("jle", "regular"),
+  ("jls", "regular"),
+  ("jma", "regular"),
+  ("jmb", "regular"),
+  ("jmc", "regular"),
+  ("jmd", "regular"),
+  ("jmi", "regular"),
+  ("jml", "regular"),
+  ("jmn", "regular"),
+  ("jmr", "regular"),
+  ("jms", "regular"),
+  ("jmw", "regular"),
+  ("jmx", "regular"),
+  ("jna", "regular"),
+  ("jnd", "regular"),
+  ("jng", "regular"),
+  ("jni", "regular"),
+  ("jnj", "regular"),
+  ("jnl", "regular"),
+  ("jns", "regular"),
+  ("job", "regular"),
+  ("jod", "regular"),
+  ("jog", "regular"),
+  ("jor", "regular"),
+  ("jos", "regular"),
+  ("jow", "regular"),
+  ("jpa", "regular"),
+  ("jpr", "regular"),
+  ("jpx", "regular"),
+  ("jqr", "regular"),
+  ("jra", "regular"),
+  ("jrb", "regular"),
+  ("jrr", "regular"),
+  ("jrt", "regular"),
+  ("jru", "regular"),
+  ("jsl", "regular"),
+  ("jua", "regular"),
+  ("jub", "regular"),
+  ("juc", "regular"),
+  ("jud", "regular"),
+  ("juh", "regular"),
+  ("jui", "regular"),
+  ("juk", "regular"),
+  ("jul", "regular"),
+  ("jum", "regular"),
+  ("jun", "regular"),
+  ("juo", "regular"),
+  ("jup", "regular"),
+  ("jur", "regular"),
+  ("jus", "regular"),
+  ("jut", "regular"),
+  ("juu", "regular"),
+  ("juw", "regular"),
+  ("juy", "regular"),
+  ("jv", "regular"),
+  ("jvd", "regular"),
+  ("jvn", "regular"),
+  ("jw", "deprecated"),
+  ("jwi", "regular"),
+  ("jya", "regular"),
+  ("jye", "regular"),
+  ("jyy", "regular"),
+  ("ka", "regular"),
+  ("kaa", "regular"),
+  ("kab", "regular"),
+  ("kac", "regular"),
+  ("kad", "regular"),
+  ("kae", "regular"),
+  ("kaf", "regular"),
+  ("kag", "regular"),
+  ("kah", "regular"),
+  ("kai", "regular"),
+  ("kaj", "regular"),
+  ("kak", "regular"),
+  ("kam", "regular"),
+  ("kao", "regular"),
+  ("kap", "regular"),
+  ("kaq", "regular"),
+  ("kar", "regular"),
+  ("kav", "regular"),
+  ("kaw", "regular"),
+  ("kax", "regular"),
+  ("kay", "regular"),
+  ("kba", "regular"),
+  ("kbb", "regular"),
+  ("kbc", "regular"),
+  ("kbd", "regular"),
+  ("kbe", "regular"),
+  ("kbf", "deprecated"),
+  ("kbg", "regular"),
+  ("kbh", "regular"),
+  ("kbi", "regular"),
+  ("kbj", "regular"),
+  ("kbk", "regular"),
+  ("kbl", "regular"),
+  ("kbm", "regular"),
+  ("kbn", "regular"),
+  ("kbo", "regular"),
+  ("kbp", "regular"),
+  ("kbq", "regular"),
+  ("kbr", "regular"),
+  ("kbs", "regular"),
+  ("kbt", "regular"),
+  ("kbu", "regular"),
+  ("kbv", "regular"),
+  ("kbw", "regular"),
+  ("kbx", "regular"),
+  ("kby", "regular"),
+  ("kbz", "regular"),
+  ("kca", "regular"),
+  ("kcb", "regular"),
+  ("kcc", "regular"),
+  ("kcd", "regular"),
+  ("kce", "regular"),
+  ("kcf", "regular"),
+  ("kcg", "regular"),
+  ("kch", "regular"),
+  ("kci", "regular"),
+  ("kcj", "regular"),
+  ("kck", "regular"),
+  ("kcl", "regular"),
+  ("kcm", "regular"),
+  ("kcn", "regular"),
+  ("kco", "regular"),
+  ("kcp", "regular"),
+  ("kcq", "regular"),
+  ("kcr", "regular"),
+  ("kcs", "regular"),
+  ("kct", "regular"),
+  ("kcu", "regular"),
+  ("kcv", "regular"),
+  ("kcw", "regular"),
+  ("kcx", "regular"),
+  ("kcy", "regular"),
+  ("kcz", "regular"),
+  ("kda", "regular"),
+  ("kdc", "regular"),
+  ("kdd", "regular"),
+  ("kde", "regular"),
+  ("kdf", "regular"),
+  ("kdg", "regular"),
+  ("kdh", "regular"),
+  ("kdi", "regular"),
+  ("kdj", "regular"),
+  ("kdk", "regular"),
+  ("kdl", "regular"),
+  ("kdm", "regular"),
+  ("kdn", "regular"),
+  ("kdo", "regular"),
+  ("kdp", "regular"),
+  ("kdq", "regular"),
+  ("kdr", "regular"),
+  ("kdt", "regular"),
+  ("kdu", "regular"),
+  ("kdv", "deprecated"),
+  ("kdw", "regular"),
+  ("kdx", "regular"),
+  ("kdy", "regular"),
+  ("kdz", "regular"),
+  ("kea", "regular"),
+  ("keb", "regular"),
+  ("kec", "regular"),
+  ("ked", "regular"),
+  ("kee", "regular"),
+  ("kef", "regular"),
+  ("keg", "regular"),
+  ("keh", "regular"),
+  ("kei", "regular"),
+  ("kej", "regular"),
+  ("kek", "regular"),
+  ("kel", "regular"),
+  ("kem", "regular"),
+  ("ken", "regular"),
+  ("keo", "regular"),
+  ("kep", "regular"),
+  ("keq", "regular"),
+  ("ker", "regular"),
+  ("kes", "regular"),
+  ("ket", "regular"),
+  ("keu", "regular"),
+  ("kev", "regular"),
+  ("kew", "regular"),
+  ("kex", "regular"),
+  ("key", "regular"),
+  ("kez", "regular"),
+  ("kfa", "regular"),
+  ("kfb", "regular"),
+  ("kfc", "regular"),
+  ("kfd", "regular"),
+  ("kfe", "regular"),
+  ("kff", "regular"),
+  ("kfg", "regular"),
+  ("kfh", "regular"),
+  ("kfi", "regular"),
+  ("kfj", "regular"),
+  ("kfk", "regular"),
+  ("kfl", "regular"),
+  ("kfm", "regular"),
+  ("kfn", "regular"),
+  ("kfo", "regular"),
+  ("kfp", "regular"),
+  ("kfq", "regular"),
+  ("kfr", "regular"),
+  ("kfs", "regular"),
+  ("kft", "regular"),
+  ("kfu", "regular"),
+  ("kfv", "regular"),
+  ("kfw", "regular"),
+  ("kfx", "regular"),
+  ("kfy", "regular"),
+  ("kfz", "regular"),
+  ("kg", "regular"),
+  ("kga", "regular"),
+  ("kgb", "regular"),
+  ("kgc", "deprecated"),
+  ("kgd", "deprecated"),
+  ("kge", "regular"),
+  ("kgf", "regular"),
+  ("kgg", "regular"),
+  ("kgh", "deprecated"),
+  ("kgi", "regular"),
+  ("kgj", "regular"),
+  ("kgk", "regular"),
+  ("kgl", "regular"),
+  ("kgm", "regular"),
+  ("kgn", "regular"),
+  ("kgo", "regular"),
+  ("kgp", "regular"),
+  ("kgq", "regular"),
+  ("kgr", "regular"),
+  ("kgs", "regular"),
+  ("kgt", "regular"),
+  ("kgu", "regular"),
+  ("kgv", "regular"),
+  ("kgw", "regular"),
+  ("kgx", "regular"),
+  ("kgy", "regular"),
+  ("kha", "regular"),
+  ("khb", "regular"),
+  ("khc", "regular"),
+  ("khd", "regular"),
+  ("khe", "regular"),
+  ("khf", "regular"),
+  ("khg", "regular"),
+  ("khh", "regular"),
+  ("khi", "regular"),
+  ("khj", "regular"),
+  ("khk", "deprecated"),
+  ("khl", "regular"),
+  ("khn", "regular"),
+  ("kho", "regular"),
+  ("khp", "regular"),
+  ("khq", "regular"),
+  ("khr", "regular"),
+  ("khs", "regular"),
+  ("kht", "regular"),
+  ("khu", "regular"),
+  ("khv", "regular"),
+  ("khw", "regular"),
+  ("khx", "regular"),
+  ("khy", "regular"),
+  ("khz", "regular"),
+  ("ki", "regular"),
+  ("kia", "regular"),
+  ("kib", "regular"),
+  ("kic", "regular"),
+  ("kid", "regular"),
+  ("kie", "regular"),
+  ("kif", "regular"),
+  ("kig", "regular"),
+  ("kih", "regular"),
+  ("kii", "regular"),
+  ("kij", "regular"),
+  ("kil", "regular"),
+  ("kim", "regular"),
+  ("kio", "regular"),
+  ("kip", "regular"),
+  ("kiq", "regular"),
+  ("kis", "regular"),
+  ("kit", "regular"),
+  ("kiu", "regular"),
+  ("kiv", "regular"),
+  ("kiw", "regular"),
+  ("kix", "regular"),
+  ("kiy", "regular"),
+  ("kiz", "regular"),
+  ("kj", "regular"),
+  ("kja", "regular"),
+  ("kjb", "regular"),
+  ("kjc", "regular"),
+  ("kjd", "regular"),
+  ("kje", "regular"),
+  ("kjf", "deprecated"),
+  ("kjg", "regular"),
+  ("kjh", "regular"),
+  ("kji", "regular"),
+  ("kjj", "regular"),
+  ("kjk", "regular"),
+  ("kjl", "regular"),
+  ("kjm", "regular"),
+  ("kjn", "regular"),
+  ("kjo", "regular"),
+  ("kjp", "regular"),
+  ("kjq", "regular"),
+  ("kjr", "regular"),
+  ("kjs", "regular"),
+  ("kjt", "regular"),
+  ("kju", "regular"),
+  ("kjv", "regular"),
+  ("kjx", "regular"),
+  ("kjy", "regular"),
+  ("kjz", "regular"),
+  ("kk", "regular"),
+  ("kka", "regular"),
+  ("kkb", "regular"),
+  ("kkc", "regular"),
+  ("kkd", "regular"),
+  ("kke", "regular"),
+  ("kkf", "regular"),
+  ("kkg", "regular"),
+  ("kkh", "regular"),
+  ("kki", "regular"),
+  ("kkj", "regular"),
+  ("kkk", "regular"),
+  ("kkl", "regular"),
+  ("kkm", "regular"),
+  ("kkn", "regular"),
+  ("kko", "regular"),
+  ("kkp", "regular"),
+  ("kkq", "regular"),
+  ("kkr", "regular"),
+  ("kks", "regular"),
+  ("kkt", "regular"),
+  ("kku", "regular"),
+  ("kkv", "regular"),
+  ("kkw", "regular"),
+  ("kkx", "regular"),
+  ("kky", "regular"),
+  ("kkz", "regular"),
+  ("kl", "regular"),
+  ("kla", "regular"),
+  ("klb", "regular"),
+  ("klc", "regular"),
+  ("kld", "regular"),
+  ("kle", "regular"),
+  ("klf", "regular"),
+  ("klg", "regular"),
+  ("klh", "regular"),
+  ("kli", "regular"),
+  ("klj", "regular"),
+  ("klk", "regular"),
+  ("kll", "regular"),
+  ("klm", "regular"),
+  ("kln", "regular"),
+  ("klo", "regular"),
+  ("klp", "regular"),
+  ("klq", "regular"),
+  ("klr", "regular"),
+  ("kls", "regular"),
+  ("klt", "regular"),
+  ("klu", "regular"),
+  ("klv", "regular"),
+  ("klw", "regular"),
+  ("klx", "regular"),
+  ("kly", "regular"),
+  ("klz", "regular"),
+  ("km", "regular"),
+  ("kma", "regular"),
+  ("kmb", "regular"),
+  ("kmc", "regular"),
+  ("kmd", "regular"),
+  ("kme", "regular"),
+  ("kmf", "regular"),
+  ("kmg", "regular"),
+  ("kmh", "regular"),
+  ("kmi", "regular"),
+  ("kmj", "regular"),
+  ("kmk", "regular"),
+  ("kml", "regular"),
+  ("kmm", "regular"),
+  ("kmn", "regular"),
+  ("kmo", "regular"),
+  ("kmp", "regular"),
+  ("kmq", "regular"),
+  ("kmr", "deprecated"),
+  ("kms", "regular"),
+  ("kmt", "regular"),
+  ("kmu", "regular"),
+  ("kmv", "regular"),
+  ("kmw", "regular"),
+  ("kmx", "regular"),
+  ("kmy", "regular"),
+  ("kmz", "regular"),
+  ("kn", "regular"),
+  ("kna", "regular"),
+  ("knb", "regular"),
+  ("knc", "deprecated"),
+  ("knd", "regular"),
+  ("kne", "regular"),
+  ("knf", "regular"),
+  ("kng", "deprecated"),
+  ("kni", "regular"),
+  ("knj", "regular"),
+  ("knk", "regular"),
+  ("knl", "regular"),
+  ("knm", "regular"),
+  ("knn", "deprecated"),
+  ("kno", "regular"),
+  ("knp", "regular"),
+  ("knq", "regular"),
+  ("knr", "regular"),
+  ("kns", "regular"),
+  ("knt", "regular"),
+  ("knu", "regular"),
+  ("knv", "regular"),
+  ("knw", "regular"),
+  ("knx", "regular"),
+  ("kny", "regular"),
+  ("knz", "regular"),
+  ("ko", "regular"),
+  ("koa", "regular"),
+  ("koc", "regular"),
+  ("kod", "regular"),
+  ("koe", "regular"),
+  ("kof", "regular"),
+  ("kog", "regular"),
+  ("koh", "regular"),
+  ("koi", "regular"),
+  ("koj", "deprecated"),
+  ("kok", "regular"),
+  ("kol", "regular"),
+  ("koo", "regular"),
+  ("kop", "regular"),
+  ("koq", "regular"),
+  ("kos", "regular"),
+  ("kot", "regular"),
+  ("kou", "regular"),
+  ("kov", "regular"),
+  ("kow", "regular"),
+  ("kox", "deprecated"),
+  ("koy", "regular"),
+  ("koz", "regular"),
+  ("kpa", "regular"),
+  ("kpb", "regular"),
+  ("kpc", "regular"),
+  ("kpd", "regular"),
+  ("kpe", "regular"),
+  ("kpf", "regular"),
+  ("kpg", "regular"),
+  ("kph", "regular"),
+  ("kpi", "regular"),
+  ("kpj", "regular"),
+  ("kpk", "regular"),
+  ("kpl", "regular"),
+  ("kpm", "regular"),
+  ("kpn", "regular"),
+  ("kpo", "regular"),
+  ("kpp", "deprecated"),
+  ("kpq", "regular"),
+  ("kpr", "regular"),
+  ("kps", "regular"),
+  ("kpt", "regular"),
+  ("kpu", "regular"),
+  ("kpv", "deprecated"),
+  ("kpw", "regular"),
+  ("kpx", "regular"),
+  ("kpy", "regular"),
+  ("kpz", "regular"),
+  ("kqa", "regular"),
+  ("kqb", "regular"),
+  ("kqc", "regular"),
+  ("kqd", "regular"),
+  ("kqe", "regular"),
+  ("kqf", "regular"),
+  ("kqg", "regular"),
+  ("kqh", "regular"),
+  ("kqi", "regular"),
+  ("kqj", "regular"),
+  ("kqk", "regular"),
+  ("kql", "regular"),
+  ("kqm", "regular"),
+  ("kqn", "regular"),
+  ("kqo", "regular"),
+  ("kqp", "regular"),
+  ("kqq", "regular"),
+  ("kqr", "regular"),
+  ("kqs", "regular"),
+  ("kqt", "regular"),
+  ("kqu", "regular"),
+  ("kqv", "regular"),
+  ("kqw", "regular"),
+  ("kqx", "regular"),
+  ("kqy", "regular"),
+  ("kqz", "regular"),
+  ("kr", "regular"),
+  ("kra", "regular"),
+  ("krb", "regular"),
+  ("krc", "regular"),
+  ("krd", "regular"),
+  ("kre", "regular"),
+  ("krf", "regular"),
+  ("krh", "regular"),
+  ("kri", "regular"),
+  ("krj", "regular"),
+  ("krk", "regular"),
+  ("krl", "regular"),
+  ("krm", "deprecated"),
+  ("krn", "regular"),
+  ("kro", "regular"),
+  ("krp", "regular"),
+  ("krr", "regular"),
+  ("krs", "regular"),
+  ("krt", "regular"),
+  ("kru", "regular"),
+  ("krv", "regular"),
+  ("krw", "regular"),
+  ("krx", "regular"),
+  ("kry", "regular"),
+  ("krz", "regular"),
+  ("ks", "regular"),
+  ("ksa", "regular"),
+  ("ksb", "regular"),
+  ("ksc", "regular"),
+  ("ksd", "regular"),
+  ("kse", "regular"),
+  ("ksf", "regular"),
+  ("ksg", "regular"),
+  ("ksh", "regular"),
+  ("ksi", "regular"),
+  ("ksj", "regular"),
+  ("ksk", "regular"),
+  ("ksl", "regular"),
+  ("ksm", "regular"),
+  ("ksn", "regular"),
+  ("kso", "regular"),
+  ("ksp", "regular"),
+  ("ksq", "regular"),
+  ("ksr", "regular"),
+  ("kss", "regular"),
+  ("kst", "regular"),
+  ("ksu", "regular"),
+  ("ksv", "regular"),
+  ("ksw", "regular"),
+  ("ksx", "regular"),
+  ("ksy", "regular"),
+  ("ksz", "regular"),
+  ("kta", "regular"),
+  ("ktb", "regular"),
+  ("ktc", "regular"),
+  ("ktd", "regular"),
+  ("kte", "regular"),
+  ("ktf", "regular"),
+  ("ktg", "regular"),
+  ("kth", "regular"),
+  ("kti", "regular"),
+  ("ktj", "regular"),
+  ("ktk", "regular"),
+  ("ktl", "regular"),
+  ("ktm", "regular"),
+  ("ktn", "regular"),
+  ("kto", "regular"),
+  ("ktp", "regular"),
+  ("ktq", "regular"),
+  ("ktr", "deprecated"),
+  ("kts", "regular"),
+  ("ktt", "regular"),
+  ("ktu", "regular"),
+  ("ktv", "regular"),
+  ("ktw", "regular"),
+  ("ktx", "regular"),
+  ("kty", "regular"),
+  ("ktz", "regular"),
+  ("ku", "regular"),
+  ("kub", "regular"),
+  ("kuc", "regular"),
+  ("kud", "regular"),
+  ("kue", "regular"),
+  ("kuf", "regular"),
+  ("kug", "regular"),
+  ("kuh", "regular"),
+  ("kui", "regular"),
+  ("kuj", "regular"),
+  ("kuk", "regular"),
+  ("kul", "regular"),
+  ("kum", "regular"),
+  ("kun", "regular"),
+  ("kuo", "regular"),
+  ("kup", "regular"),
+  ("kuq", "regular"),
+  ("kus", "regular"),
+  ("kut", "regular"),
+  ("kuu", "regular"),
+  ("kuv", "regular"),
+  ("kuw", "regular"),
+  ("kux", "regular"),
+  ("kuy", "regular"),
+  ("kuz", "regular"),
+  ("kv", "regular"),
+  ("kva", "regular"),
+  ("kvb", "regular"),
+  ("kvc", "regular"),
+  ("kvd", "regular"),
+  ("kve", "regular"),
+  ("kvf", "regular"),
+  ("kvg", "regular"),
+  ("kvh", "regular"),
+  ("kvi", "regular"),
+  ("kvj", "regular"),
+  ("kvk", "regular"),
+  ("kvl", "regular"),
+  ("kvm", "regular"),
+  ("kvn", "regular"),
+  ("kvo", "regular"),
+  ("kvp", "regular"),
+  ("kvq", "regular"),
+  ("kvr", "regular"),
+  ("kvs", "deprecated"),
+  ("kvt", "regular"),
+  ("kvu", "regular"),
+  ("kvv", "regular"),
+  ("kvw", "regular"),
+  ("kvx", "regular"),
+  ("kvy", "regular"),
+  ("kvz", "regular"),
+  ("kw", "regular"),
+  ("kwa", "regular"),
+  ("kwb", "regular"),
+  ("kwc", "regular"),
+  ("kwd", "regular"),
+  ("kwe", "regular"),
+  ("kwf", "regular"),
+  ("kwg", "regular"),
+  ("kwh", "regular"),
+  ("kwi", "regular"),
+  ("kwj", "regular"),
+  ("kwk", "regular"),
+  ("kwl", "regular"),
+  ("kwm", "regular"),
+  ("kwn", "regular"),
+  ("kwo", "regular"),
+  ("kwp", "regular"),
+  ("kwq", "deprecated"),
+  ("kwr", "regular"),
+  ("kws", "regular"),
+  ("kwt", "regular"),
+  ("kwu", "regular"),
+  ("kwv", "regular"),
+  ("kww", "regular"),
+  ("kwx", "regular"),
+  ("kwy", "regular"),
+  ("kwz", "regular"),
+  ("kxa", "regular"),
+  ("kxb", "regular"),
+  ("kxc", "regular"),
+  ("kxd", "regular"),
+  ("kxe", "deprecated"),
+  ("kxf", "regular"),
+  ("kxh", "regular"),
+  ("kxi", "regular"),
+  ("kxj", "regular"),
+  ("kxk", "regular"),
+  ("kxl", "deprecated"),
+  ("kxm", "regular"),
+  ("kxn", "regular"),
+  ("kxo", "regular"),
+  ("kxp", "regular"),
+  ("kxq", "regular"),
+  ("kxr", "regular"),
+  ("kxs", "regular"),
+  ("kxt", "regular"),
+  ("kxu", "deprecated"),
+  ("kxv", "regular"),
+  ("kxw", "regular"),
+  ("kxx", "regular"),
+  ("kxy", "regular"),
+  ("kxz", "regular"),
+  ("ky", "regular"),
+  ("kya", "regular"),
+  ("kyb", "regular"),
+  ("kyc", "regular"),
+  ("kyd", "regular"),
+  ("kye", "regular"),
+  ("kyf", "regular"),
+  ("kyg", "regular"),
+  ("kyh", "regular"),
+  ("kyi", "regular"),
+  ("kyj", "regular"),
+  ("kyk", "regular"),
+  ("kyl", "regular"),
+  ("kym", "regular"),
+  ("kyn", "regular"),
+  ("kyo", "regular"),
+  ("kyp", "regular"),
+  ("kyq", "regular"),
+  ("kyr", "regular"),
+  ("kys", "regular"),
+  ("kyt", "regular"),
+  ("kyu", "regular"),
+  ("kyv", "regular"),
+  ("kyw", "regular"),
+  ("kyx", "regular"),
+  ("kyy", "regular"),
+  ("kyz", "regular"),
+  ("kza", "regular"),
+  ("kzb", "regular"),
+  ("kzc", "regular"),
+  ("kzd", "regular"),
+  ("kze", "regular"),
+  ("kzf", "regular"),
+  ("kzg", "regular"),
+  ("kzh", "deprecated"),
+  ("kzi", "regular"),
+  ("kzj", "deprecated"),
+  ("kzk", "regular"),
+  ("kzl", "regular"),
+  ("kzm", "regular"),
+  ("kzn", "regular"),
+  ("kzo", "regular"),
+  ("kzp", "regular"),
+  ("kzq", "regular"),
+  ("kzr", "regular"),
+  ("kzs", "regular"),
+  ("kzt", "deprecated"),
+  ("kzu", "regular"),
+  ("kzv", "regular"),
+  ("kzw", "regular"),
+  ("kzx", "regular"),
+  ("kzy", "regular"),
+  ("kzz", "regular"),
+  ("la", "regular"),
+  ("laa", "regular"),
+  ("lab", "regular"),
+  ("lac", "regular"),
+  ("lad", "regular"),
+  ("lae", "regular"),
+  ("laf", "regular"),
+  ("lag", "regular"),
+  ("lah", "regular"),
+  ("lai", "regular"),
+  ("laj", "regular"),
+  ("lak", "deprecated"),
+  ("lal", "regular"),
+  ("lam", "regular"),
+  ("lan", "regular"),
+  ("lap", "regular"),
+  ("laq", "regular"),
+  ("lar", "regular"),
+  ("las", "regular"),
+  ("lau", "regular"),
+  ("law", "regular"),
+  ("lax", "regular"),
+  ("lay", "regular"),
+  ("laz", "regular"),
+  ("lb", "regular"),
+  ("lba", "deprecated"),
+  ("lbb", "regular"),
+  ("lbc", "regular"),
+  ("lbe", "regular"),
+  ("lbf", "regular"),
+  ("lbg", "regular"),
+  ("lbi", "regular"),
+  ("lbj", "regular"),
+  ("lbk", "deprecated"),
+  ("lbl", "regular"),
+  ("lbm", "regular"),
+  ("lbn", "regular"),
+  ("lbo", "regular"),
+  ("lbq", "regular"),
+  ("lbr", "regular"),
+  ("lbs", "regular"),
+  ("lbt", "regular"),
+  ("lbu", "regular"),
+  ("lbv", "regular"),
+  ("lbw", "regular"),
+  ("lbx", "regular"),
+  ("lby", "regular"),
+  ("lbz", "regular"),
+  ("lcc", "regular"),
+  ("lcd", "regular"),
+  ("lce", "regular"),
+  ("lcf", "regular"),
+  ("lch", "regular"),
+  ("lcl", "regular"),
+  ("lcm", "regular"),
+  ("lcp", "regular"),
+  ("lcq", "regular"),
+  ("lcs", "regular"),
+  ("lda", "regular"),
+  ("ldb", "regular"),
+  ("ldd", "regular"),
+  ("ldg", "regular"),
+  ("ldh", "regular"),
+  ("ldi", "regular"),
+  ("ldj", "regular"),
+  ("ldk", "regular"),
+  ("ldl", "regular"),
+  ("ldm", "regular"),
+  ("ldn", "regular"),
+  ("ldo", "regular"),
+  ("ldp", "regular"),
+  ("ldq", "regular"),
+  ("lea", "regular"),
+  ("leb", "regular"),
+  ("lec", "regular"),
+  ("led", "regular"),
+  ("lee", "regular"),
+  ("lef", "regular"),
+  ("leg", "deprecated"),
+  ("leh", "regular"),
+  ("lei", "regular"),
+  ("lej", "regular"),
+  ("lek", "regular"),
+  ("lel", "regular"),
+  ("lem", "regular"),
+  ("len", "regular"),
+  ("leo", "regular"),
+  ("lep", "regular"),
+  ("leq", "regular"),
+  ("ler", "regular"),
+  ("les", "regular"),
+  ("let", "regular"),
+  ("leu", "regular"),
+  ("lev", "regular"),
+  ("lew", "regular"),
+  ("lex", "regular"),
+  ("ley", "regular"),
+  ("lez", "regular"),
+  ("lfa", "regular"),
+  ("lfn", "regular"),
+  ("lg", "regular"),
+  ("lga", "regular"),
+  ("lgb", "regular"),
+  ("lgg", "regular"),
+  ("lgh", "regular"),
+  ("lgi", "regular"),
+  ("lgk", "regular"),
+  ("lgl", "regular"),
+  ("lgm", "regular"),
+  ("lgn", "regular"),
+  ("lgo", "regular"),
+  ("lgq", "regular"),
+  ("lgr", "regular"),
+  ("lgt", "regular"),
+  ("lgu", "regular"),
+  ("lgz", "regular"),
+  ("lha", "regular"),
+  ("lhh", "regular"),
+  ("lhi", "regular"),
+  ("lhl", "regular"),
+  ("lhm", "regular"),
+  ("lhn", "regular"),
+  ("lhp", "regular"),
+  ("lhs", "regular"),
+  ("lht", "regular"),
+  ("lhu", "regular"),
+  ("li", "regular"),
+  ("lia", "regular"),
+  ("lib", "regular"),
+  ("lic", "regular"),
+  ("lid", "regular"),
+  ("lie", "regular"),
+  ("lif", "regular"),
+  ("lig", "regular"),
+  ("lih", "regular"),
+  ("lii", "deprecated"),
+  ("lij", "regular"),
+  ("lik", "regular"),
+  ("lil", "regular"),
+  ("lio", "regular"),
+  ("lip", "regular"),
+  ("liq", "regular"),
+  ("lir", "regular"),
+  ("lis", "regular"),
+  ("liu", "regular"),
+  ("liv", "regular"),
+  ("liw", "regular"),
+  ("lix", "regular"),
+  ("liy", "regular"),
+  ("liz", "regular"),
+  ("lja", "regular"),
+  ("lje", "regular"),
+  ("lji", "regular"),
+  ("ljl", "regular"),
+  ("ljp", "regular"),
+  ("ljw", "regular"),
+  ("ljx", "regular"),
+  ("lka", "regular"),
+  ("lkb", "regular"),
+  ("lkc", "regular"),
+  ("lkd", "regular"),
+  ("lke", "regular"),
+  ("lkh", "regular"),
+  ("lki", "regular"),
+  ("lkj", "regular"),
+  ("lkl", "regular"),
+  ("lkm", "regular"),
+  ("lkn", "regular"),
+  ("lko", "regular"),
+  ("lkr", "regular"),
+  ("lks", "regular"),
+  ("lkt", "regular"),
+  ("lku", "regular"),
+  ("lky", "regular"),
+  ("lla", "regular"),
+  ("llb", "regular"),
+  ("llc", "regular"),
+  ("lld", "regular"),
+  ("lle", "regular"),
+  ("llf", "regular"),
+  ("llg", "regular"),
+  ("llh", "regular"),
+  ("lli", "regular"),
+  ("llj", "regular"),
+  ("llk", "regular"),
+  ("lll", "regular"),
+  ("llm", "regular"),
+  ("lln", "regular"),
+  ("llo", "deprecated"),
+  ("llp", "regular"),
+  ("llq", "regular"),
+  ("lls", "regular"),
+  ("llu", "regular"),
+  ("llx", "regular"),
+  ("lma", "regular"),
+  ("lmb", "regular"),
+  ("lmc", "regular"),
+  ("lmd", "regular"),
+  ("lme", "regular"),
+  ("lmf", "regular"),
+  ("lmg", "regular"),
+  ("lmh", "regular"),
+  ("lmi", "regular"),
+  ("lmj", "regular"),
+  ("lmk", "regular"),
+  ("lml", "regular"),
+  ("lmm", "deprecated"),
+  ("lmn", "regular"),
+  ("lmo", "regular"),
+  ("lmp", "regular"),
+  ("lmq", "regular"),
+  ("lmr", "regular"),
+  ("lmu", "regular"),
+  ("lmv", "regular"),
+  ("lmw", "regular"),
+  ("lmx", "regular"),
+  ("lmy", "regular"),
+  ("lmz", "deprecated"),
+  ("ln", "regular"),
+  ("lna", "regular"),
+  ("lnb", "regular"),
+  ("lnd", "regular"),
+  ("lng", "regular"),
+  ("lnh", "regular"),
+  ("lni", "regular"),
+  ("lnj", "regular"),
+  ("lnl", "regular"),
+  ("lnm", "regular"),
+  ("lnn", "regular"),
+  ("lno", "deprecated"),
+  ("lns", "regular"),
+  ("lnu", "regular"),
+  ("lnw", "regular"),
+  ("lnz", "regular"),
+  ("lo", "regular"),
+  ("loa", "regular"),
+  ("lob", "regular"),
+  ("loc", "regular"),
+  ("loe", "regular"),
+  ("lof", "regular"),
+  ("log", "regular"),
+  ("loh", "regular"),
+  ("loi", "regular"),
+  ("loj", "regular"),
+  ("lok", "regular"),
+  ("lol", "regular"),
+  ("lom", "regular"),
+  ("lon", "regular"),
+  ("loo", "regular"),
+  ("lop", "regular"),
+  ("loq", "regular"),
+  ("lor", "regular"),
+  ("los", "regular"),
+  ("lot", "regular"),
+  ("lou", "regular"),
+  ("lov", "regular"),
+  ("low", "regular"),
+  ("lox", "regular"),
+  ("loy", "regular"),
+  ("loz", "regular"),
+  ("lpa", "regular"),
+  ("lpe", "regular"),
+  ("lpn", "regular"),
+  ("lpo", "regular"),
+  ("lpx", "regular"),
+  ("lqr", "regular"),
+  ("lra", "regular"),
+  ("lrc", "regular"),
+  ("lre", "regular"),
+  ("lrg", "regular"),
+  ("lri", "regular"),
+  ("lrk", "regular"),
+  ("lrl", "regular"),
+  ("lrm", "regular"),
+  ("lrn", "regular"),
+  ("lro", "regular"),
+  ("lrr", "regular"),
+  ("lrt", "regular"),
+  ("lrv", "regular"),
+  ("lrz", "regular"),
+  ("lsa", "regular"),
+  ("lsb", "regular"),
+  ("lsc", "regular"),
+  ("lsd", "regular"),
+  ("lse", "regular"),
+  ("lsg", "deprecated"),
+  ("lsh", "regular"),
+  ("lsi", "regular"),
+  ("lsl", "regular"),
+  ("lsm", "regular"),
+  ("lsn", "regular"),
+  ("lso", "regular"),
+  ("lsp", "regular"),
+  ("lsr", "regular"),
+  ("lss", "regular"),
+  ("lst", "regular"),
+  ("lsv", "regular"),
+  ("lsw", "regular"),
+  ("lsy", "regular"),
+  ("lt", "regular"),
+  ("ltc", "regular"),
+  ("ltg", "regular"),
+  ("lth", "regular"),
+  ("lti", "regular"),
+  ("ltn", "regular"),
+  ("lto", "regular"),
+  ("lts", "regular"),
+  ("ltu", "regular"),
+  ("lu", "regular"),
+  ("lua", "regular"),
+  ("luc", "regular"),
+  ("lud", "regular"),
+  ("lue", "regular"),
+  ("luf", "regular"),
+  ("lui", "regular"),
+  ("luj", "regular"),
+  ("luk", "regular"),
+  ("lul", "regular"),
+  ("lum", "regular"),
+  ("lun", "regular"),
+  ("luo", "regular"),
+  ("lup", "regular"),
+  ("luq", "regular"),
+  ("lur", "regular"),
+  ("lus", "regular"),
+  ("lut", "regular"),
+  ("luu", "regular"),
+  ("luv", "regular"),
+  ("luw", "regular"),
+  ("luy", "regular"),
+  ("luz", "regular"),
+  ("lv", "regular"),
+  ("lva", "regular"),
+  ("lvi", "regular"),
+  ("lvk", "regular"),
+  ("lvs", "deprecated"),
+  ("lvu", "regular"),
+  ("lwa", "regular"),
+  ("lwe", "regular"),
+  ("lwg", "regular"),
+  ("lwh", "regular"),
+  ("lwl", "regular"),
+  ("lwm", "regular"),
+  ("lwo", "regular"),
+  ("lws", "regular"),
+  ("lwt", "regular"),
+  ("lwu", "regular"),
+  ("lww", "regular"),
+  ("lxm", "regular"),
+  ("lya", "regular"),
+  ("lyg", "regular"),
+  ("lyn", "regular"),
+  ("lzh", "regular"),
+  ("lzl", "regular"),
+  ("lzn", "regular"),
+  ("lzz", "regular"),
+  ("maa", "regular"),
+  ("mab", "regular"),
+  ("mad", "regular"),
+  ("mae", "regular"),
+  ("maf", "regular"),
+  ("mag", "regular"),
+  ("mai", "regular"),
+  ("maj", "regular"),
+  ("mak", "regular"),
+  ("mam", "regular"),
+  ("man", "regular"),
+  ("map", "regular"),
+  ("maq", "regular"),
+  ("mas", "regular"),
+  ("mat", "regular"),
+  ("mau", "regular"),
+  ("mav", "regular"),
+  ("maw", "regular"),
+  ("max", "regular"),
+  ("maz", "regular"),
+  ("mba", "regular"),
+  ("mbb", "regular"),
+  ("mbc", "regular"),
+  ("mbd", "regular"),
+  ("mbe", "regular"),
+  ("mbf", "regular"),
+  ("mbh", "regular"),
+  ("mbi", "regular"),
+  ("mbj", "regular"),
+  ("mbk", "regular"),
+  ("mbl", "regular"),
+  ("mbm", "regular"),
+  ("mbn", "regular"),
+  ("mbo", "regular"),
+  ("mbp", "regular"),
+  ("mbq", "regular"),
+  ("mbr", "regular"),
+  ("mbs", "regular"),
+  ("mbt", "regular"),
+  ("mbu", "regular"),
+  ("mbv", "regular"),
+  ("mbw", "regular"),
+  ("mbx", "regular"),
+  ("mby", "regular"),
+  ("mbz", "regular"),
+  ("mca", "regular"),
+  ("mcb", "regular"),
+  ("mcc", "regular"),
+  ("mcd", "regular"),
+  ("mce", "regular"),
+  ("mcf", "regular"),
+  ("mcg", "regular"),
+  ("mch", "regular"),
+  ("mci", "regular"),
+  ("mcj", "regular"),
+  ("mck", "regular"),
+  ("mcl", "regular"),
+  ("mcm", "regular"),
+  ("mcn", "regular"),
+  ("mco", "regular"),
+  ("mcp", "regular"),
+  ("mcq", "regular"),
+  ("mcr", "regular"),
+  ("mcs", "regular"),
+  ("mct", "regular"),
+  ("mcu", "regular"),
+  ("mcv", "regular"),
+  ("mcw", "regular"),
+  ("mcx", "regular"),
+  ("mcy", "regular"),
+  ("mcz", "regular"),
+  ("mda", "regular"),
+  ("mdb", "regular"),
+  ("mdc", "regular"),
+  ("mdd", "regular"),
+  ("mde", "regular"),
+  ("mdf", "regular"),
+  ("mdg", "regular"),
+  ("mdh", "regular"),
+  ("mdi", "regular"),
+  ("mdj", "regular"),
+  ("mdk", "regular"),
+  ("mdl", "regular"),
+  ("mdm", "regular"),
+  ("mdn", "regular"),
+  ("mdp", "regular"),
+  ("mdq", "regular"),
+  ("mdr", "regular"),
+  ("mds", "regular"),
+  ("mdt", "regular"),
+  ("mdu", "regular"),
+  ("mdv", "regular"),
+  ("mdw", "regular"),
+  ("mdx", "regular"),
+  ("mdy", "regular"),
+  ("mdz", "regular"),
+  ("mea", "regular"),
+  ("meb", "regular"),
+  ("mec", "regular"),
+  ("med", "regular"),
+  ("mee", "regular"),
+  ("mef", "regular"),
+  ("meg", "deprecated"),
+  ("meh", "regular"),
+  ("mei", "regular"),
+  ("mej", "regular"),
+  ("mek", "regular"),
+  ("mel", "regular"),
+  ("mem", "regular"),
+  ("men", "regular"),
+  ("meo", "regular"),
+  ("mep", "regular"),
+  ("meq", "regular"),
+  ("mer", "regular"),
+  ("mes", "regular"),
+  ("met", "regular"),
+  ("meu", "regular"),
+  ("mev", "regular"),
+  ("mew", "regular"),
+  ("mey", "regular"),
+  ("mez", "regular"),
+  ("mfa", "regular"),
+  ("mfb", "regular"),
+  ("mfc", "regular"),
+  ("mfd", "regular"),
+  ("mfe", "regular"),
+  ("mff", "regular"),
+  ("mfg", "regular"),
+  ("mfh", "regular"),
+  ("mfi", "regular"),
+  ("mfj", "regular"),
+  ("mfk", "regular"),
+  ("mfl", "regular"),
+  ("mfm", "regular"),
+  ("mfn", "regular"),
+  ("mfo", "regular"),
+  ("mfp", "regular"),
+  ("mfq", "regular"),
+  ("mfr", "regular"),
+  ("mfs", "regular"),
+  ("mft", "regular"),
+  ("mfu", "regular"),
+  ("mfv", "regular"),
+  ("mfw", "regular"),
+  ("mfx", "regular"),
+  ("mfy", "regular"),
+  ("mfz", "regular"),
+  ("mg", "regular"),
+  ("mga", "regular"),
+  ("mgb", "regular"),
+  ("mgc", "regular"),
+  ("mgd", "regular"),
+  ("mge", "regular"),
+  ("mgf", "regular"),
+  ("mgg", "regular"),
+  ("mgh", "regular"),
+  ("mgi", "regular"),
+  ("mgj", "regular"),
+  ("mgk", "regular"),
+  ("mgl", "regular"),
+  ("mgm", "regular"),
+  ("mgn", "regular"),
+  ("mgo", "regular"),
+  ("mgp", "regular"),
+  ("mgq", "regular"),
+  ("mgr", "regular"),
+  ("mgs", "regular"),
+  ("mgt", "regular"),
+  ("mgu", "regular"),
+  ("mgv", "regular"),
+  ("mgw", "regular"),
+  ("mgx", "deprecated"),
+  ("mgy", "regular"),
+  ("mgz", "regular"),
+  ("mh", "regular"),
+  ("mha", "regular"),
+  ("mhb", "regular"),
+  ("mhc", "regular"),
+  ("mhd", "regular"),
+  ("mhe", "regular"),
+  ("mhf", "regular"),
+  ("mhg", "regular"),
+  ("mhh", "deprecated"),
+  ("mhi", "regular"),
+  ("mhj", "regular"),
+  ("mhk", "regular"),
+  ("mhl", "regular"),
+  ("mhm", "regular"),
+  ("mhn", "regular"),
+  ("mho", "regular"),
+  ("mhp", "regular"),
+  ("mhq", "regular"),
+  ("mhr", "deprecated"),
+  ("mhs", "regular"),
+  ("mht", "regular"),
+  ("mhu", "regular"),
+  ("mhw", "regular"),
+  ("mhx", "regular"),
+  ("mhy", "regular"),
+  ("mhz", "regular"),
+  ("mi", "regular"),
+  ("mia", "regular"),
+  ("mib", "regular"),
+  ("mic", "regular"),
+  ("mid", "regular"),
+  ("mie", "regular"),
+  ("mif", "regular"),
+  ("mig", "regular"),
+  ("mih", "regular"),
+  ("mii", "regular"),
+  ("mij", "regular"),
+  ("mik", "regular"),
+  ("mil", "regular"),
+  ("mim", "regular"),
+  ("min", "regular"),
+  ("mio", "regular"),
+  ("mip", "regular"),
+  ("miq", "regular"),
+  ("mir", "regular"),
+  ("mis", "special"),
+  ("mit", "regular"),
+  ("miu", "regular"),
+  ("miw", "regular"),
+  ("mix", "regular"),
+  ("miy", "regular"),
+  ("miz", "regular"),
+  ("mja", "deprecated"),
+  ("mjb", "regular"),
+  ("mjc", "regular"),
+  ("mjd", "regular"),
+  ("mje", "regular"),
+  ("mjg", "regular"),
+  ("mjh", "regular"),
+  ("mji", "regular"),
+  ("mjj", "regular"),
+  ("mjk", "regular"),
+  ("mjl", "regular"),
+  ("mjm", "regular"),
+  ("mjn", "regular"),
+  ("mjo", "regular"),
+  ("mjp", "regular"),
+  ("mjq", "regular"),
+  ("mjr", "regular"),
+  ("mjs", "regular"),
+  ("mjt", "regular"),
+  ("mju", "regular"),
+  ("mjv", "regular"),
+  ("mjw", "regular"),
+  ("mjx", "regular"),
+  ("mjy", "regular"),
+  ("mjz", "regular"),
+  ("mk", "regular"),
+  ("mka", "regular"),
+  ("mkb", "regular"),
+  ("mkc", "regular"),
+  ("mke", "regular"),
+  ("mkf", "regular"),
+  ("mkg", "regular"),
+  ("mkh", "regular"),
+  ("mki", "regular"),
+  ("mkj", "regular"),
+  ("mkk", "regular"),
+  ("mkl", "regular"),
+  ("mkm", "regular"),
+  ("mkn", "regular"),
+  ("mko", "regular"),
+  ("mkp", "regular"),
+  ("mkq", "regular"),
+  ("mkr", "regular"),
+  ("mks", "regular"),
+  ("mkt", "regular"),
+  ("mku", "regular"),
+  ("mkv", "regular"),
+  ("mkw", "regular"),
+  ("mkx", "regular"),
+  ("mky", "regular"),
+  ("mkz", "regular"),
+  ("ml", "regular"),
+  ("mla", "regular"),
+  ("mlb", "regular"),
+  ("mlc", "regular"),
+  ("mld", "deprecated"),
+  ("mle", "regular"),
+  ("mlf", "regular"),
+  ("mlh", "regular"),
+  ("mli", "regular"),
+  ("mlj", "regular"),
+  ("mlk", "regular"),
+  ("mll", "regular"),
+  ("mlm", "regular"),
+  ("mln", "regular"),
+  ("mlo", "regular"),
+  ("mlp", "regular"),
+  ("mlq", "regular"),
+  ("mlr", "regular"),
+  ("mls", "regular"),
+  ("mlu", "regular"),
+  ("mlv", "regular"),
+  ("mlw", "regular"),
+  ("mlx", "regular"),
+  ("mlz", "regular"),
+  ("mma", "regular"),
+  ("mmb", "regular"),
+  ("mmc", "regular"),
+  ("mmd", "regular"),
+  ("mme", "regular"),
+  ("mmf", "regular"),
+  ("mmg", "regular"),
+  ("mmh", "regular"),
+  ("mmi", "regular"),
+  ("mmj", "regular"),
+  ("mmk", "regular"),
+  ("mml", "regular"),
+  ("mmm", "regular"),
+  ("mmn", "regular"),
+  ("mmo", "regular"),
+  ("mmp", "regular"),
+  ("mmq", "regular"),
+  ("mmr", "regular"),
+  ("mmt", "regular"),
+  ("mmu", "regular"),
+  ("mmv", "regular"),
+  ("mmw", "regular"),
+  ("mmx", "regular"),
+  ("mmy", "regular"),
+  ("mmz", "regular"),
+  ("mn", "regular"),
+  ("mna", "regular"),
+  ("mnb", "regular"),
+  ("mnc", "regular"),
+  ("mnd", "regular"),
+  ("mne", "regular"),
+  ("mnf", "regular"),
+  ("mng", "regular"),
+  ("mnh", "regular"),
+  ("mni", "regular"),
+  ("mnj", "regular"),
+  ("mnk", "deprecated"),
+  ("mnl", "regular"),
+  ("mnm", "regular"),
+  ("mnn", "regular"),
+  ("mno", "regular"),
+  ("mnp", "regular"),
+  ("mnq", "regular"),
+  ("mnr", "regular"),
+  ("mns", "regular"),
+  ("mnt", "deprecated"),
+  ("mnu", "regular"),
+  ("mnv", "regular"),
+  ("mnw", "regular"),
+  ("mnx", "regular"),
+  ("mny", "regular"),
+  ("mnz", "regular"),
+  ("mo", "deprecated"),
+  ("moa", "regular"),
+  ("moc", "regular"),
+  ("mod", "regular"),
+  ("moe", "regular"),
+  ("mof", "deprecated"),
+  ("mog", "regular"),
+  ("moh", "regular"),
+  ("moi", "regular"),
+  ("moj", "regular"),
+  ("mok", "regular"),
+  ("mom", "regular"),
+  ("moo", "regular"),
+  ("mop", "regular"),
+  ("moq", "regular"),
+  ("mor", "regular"),
+  ("mos", "regular"),
+  ("mot", "regular"),
+  ("mou", "regular"),
+  ("mov", "regular"),
+  ("mow", "regular"),
+  ("mox", "regular"),
+  ("moy", "regular"),
+  ("moz", "regular"),
+  ("mpa", "regular"),
+  ("mpb", "regular"),
+  ("mpc", "regular"),
+  ("mpd", "regular"),
+  ("mpe", "regular"),
+  ("mpg", "regular"),
+  ("mph", "regular"),
+  ("mpi", "regular"),
+  ("mpj", "regular"),
+  ("mpk", "regular"),
+  ("mpl", "regular"),
+  ("mpm", "regular"),
+  ("mpn", "regular"),
+  ("mpo", "regular"),
+  ("mpp", "regular"),
+  ("mpq", "regular"),
+  ("mpr", "regular"),
+  ("mps", "regular"),
+  ("mpt", "regular"),
+  ("mpu", "regular"),
+  ("mpv", "regular"),
+  ("mpw", "regular"),
+  ("mpx", "regular"),
+  ("mpy", "regular"),
+  ("mpz", "regular"),
+  ("mqa", "regular"),
+  ("mqb", "regular"),
+  ("mqc", "regular"),
+  ("mqe", "regular"),
+  ("mqf", "regular"),
+  ("mqg", "regular"),
+  ("mqh", "regular"),
+  ("mqi", "regular"),
+  ("mqj", "regular"),
+  ("mqk", "regular"),
+  ("mql", "regular"),
+  ("mqm", "regular"),
+  ("mqn", "regular"),
+  ("mqo", "regular"),
+  ("mqp", "regular"),
+  ("mqq", "regular"),
+  ("mqr", "regular"),
+  ("mqs", "regular"),
+  ("mqt", "regular"),
+  ("mqu", "regular"),
+  ("mqv", "regular"),
+  ("mqw", "regular"),
+  ("mqx", "regular"),
+  ("mqy", "regular"),
+  ("mqz", "regular"),
+  ("mr", "regular"),
+  ("mra", "regular"),
+  ("mrb", "regular"),
+  ("mrc", "regular"),
+  ("mrd", "regular"),
+  ("mre", "regular"),
+  ("mrf", "regular"),
+  ("mrg", "regular"),
+  ("mrh", "regular"),
+  ("mrj", "regular"),
+  ("mrk", "regular"),
+  ("mrl", "regular"),
+  ("mrm", "regular"),
+  ("mrn", "regular"),
+  ("mro", "regular"),
+  ("mrp", "regular"),
+  ("mrq", "regular"),
+  ("mrr", "regular"),
+  ("mrs", "regular"),
+  ("mrt", "regular"),
+  ("mru", "regular"),
+  ("mrv", "regular"),
+  ("mrw", "regular"),
+  ("mrx", "regular"),
+  ("mry", "regular"),
+  ("mrz", "regular"),
+  ("ms", "regular"),
+  ("msb", "regular"),
+  ("msc", "regular"),
+  ("msd", "regular"),
+  ("mse", "regular"),
+  ("msf", "regular"),
+  ("msg", "regular"),
+  ("msh", "regular"),
+  ("msi", "regular"),
+  ("msj", "regular"),
+  ("msk", "regular"),
+  ("msl", "regular"),
+  ("msm", "regular"),
+  ("msn", "regular"),
+  ("mso", "regular"),
+  ("msp", "regular"),
+  ("msq", "regular"),
+  ("msr", "regular"),
+  ("mss", "regular"),
+  ("mst", "deprecated"),
+  ("msu", "regular"),
+  ("msv", "regular"),
+  ("msw", "regular"),
+  ("msx", "regular"),
+  ("msy", "regular"),
+  ("msz", "regular"),
+  ("mt", "regular"),
+  ("mta", "regular"),
+  ("mtb", "regular"),
+  ("mtc", "regular"),
+  ("mtd", "regular"),
+  ("mte", "regular"),
+  ("mtf", "regular"),
+  ("mtg", "regular"),
+  ("mth", "regular"),
+  ("mti", "regular"),
+  ("mtj", "regular"),
+  ("mtk", "regular"),
+  ("mtl", "regular"),
+  ("mtm", "regular"),
+  ("mtn", "regular"),
+  ("mto", "regular"),
+  ("mtp", "regular"),
+  ("mtq", "regular"),
+  ("mtr", "regular"),
+  ("mts", "regular"),
+  ("mtt", "regular"),
+  ("mtu", "regular"),
+  ("mtv", "regular"),
+  ("mtw", "regular"),
+  ("mtx", "regular"),
+  ("mty", "regular"),
+  ("mua", "regular"),
+  ("mub", "regular"),
+  ("muc", "regular"),
+  ("mud", "regular"),
+  ("mue", "regular"),
+  ("mug", "regular"),
+  ("muh", "regular"),
+  ("mui", "regular"),
+  ("muj", "regular"),
+  ("muk", "regular"),
+  ("mul", "special"),
+  ("mum", "regular"),
+  ("mun", "regular"),
+  ("muo", "regular"),
+  ("mup", "deprecated"),
+  ("muq", "regular"),
+  ("mur", "regular"),
+  ("mus", "regular"),
+  ("mut", "regular"),
+  ("muu", "regular"),
+  ("muv", "regular"),
+  ("mux", "regular"),
+  ("muy", "regular"),
+  ("muz", "regular"),
+  ("mva", "regular"),
+  ("mvb", "regular"),
+  ("mvd", "regular"),
+  ("mve", "regular"),
+  ("mvf", "regular"),
+  ("mvg", "regular"),
+  ("mvh", "regular"),
+  ("mvi", "regular"),
+  ("mvk", "regular"),
+  ("mvl", "regular"),
+  ("mvm", "deprecated"),
+  ("mvn", "regular"),
+  ("mvo", "regular"),
+  ("mvp", "regular"),
+  ("mvq", "regular"),
+  ("mvr", "regular"),
+  ("mvs", "regular"),
+  ("mvt", "regular"),
+  ("mvu", "regular"),
+  ("mvv", "regular"),
+  ("mvw", "regular"),
+  ("mvx", "regular"),
+  ("mvy", "regular"),
+  ("mvz", "regular"),
+  ("mwa", "regular"),
+  ("mwb", "regular"),
+  ("mwc", "regular"),
+  ("mwd", "deprecated"),
+  ("mwe", "regular"),
+  ("mwf", "regular"),
+  ("mwg", "regular"),
+  ("mwh", "regular"),
+  ("mwi", "regular"),
+  ("mwj", "deprecated"),
+  ("mwk", "regular"),
+  ("mwl", "regular"),
+  ("mwm", "regular"),
+  ("mwn", "regular"),
+  ("mwo", "regular"),
+  ("mwp", "regular"),
+  ("mwq", "regular"),
+  ("mwr", "regular"),
+  ("mws", "regular"),
+  ("mwt", "regular"),
+  ("mwu", "regular"),
+  ("mwv", "regular"),
+  ("mww", "regular"),
+  ("mwx", "deprecated"),
+  ("mwy", "deprecated"),
+  ("mwz", "regular"),
+  ("mxa", "regular"),
+  ("mxb", "regular"),
+  ("mxc", "regular"),
+  ("mxd", "regular"),
+  ("mxe", "regular"),
+  ("mxf", "regular"),
+  ("mxg", "regular"),
+  ("mxh", "regular"),
+  ("mxi", "regular"),
+  ("mxj", "regular"),
+  ("mxk", "regular"),
+  ("mxl", "regular"),
+  ("mxm", "regular"),
+  ("mxn", "regular"),
+  ("mxo", "regular"),
+  ("mxp", "regular"),
+  ("mxq", "regular"),
+  ("mxr", "regular"),
+  ("mxs", "regular"),
+  ("mxt", "regular"),
+  ("mxu", "regular"),
+  ("mxv", "regular"),
+  ("mxw", "regular"),
+  ("mxx", "regular"),
+  ("mxy", "regular"),
+  ("mxz", "regular"),
+  ("my", "regular"),
+  ("myb", "regular"),
+  ("myc", "regular"),
+  ("myd", "deprecated"),
+  ("mye", "regular"),
+  ("myf", "regular"),
+  ("myg", "regular"),
+  ("myh", "regular"),
+  ("myi", "deprecated"),
+  ("myj", "regular"),
+  ("myk", "regular"),
+  ("myl", "regular"),
+  ("mym", "regular"),
+  ("myn", "regular"),
+  ("myo", "regular"),
+  ("myp", "regular"),
+  ("myq", "deprecated"),
+  ("myr", "regular"),
+  ("mys", "regular"),
+  ("myt", "deprecated"),
+  ("myu", "regular"),
+  ("myv", "regular"),
+  ("myw", "regular"),
+  ("myx", "regular"),
+  ("myy", "regular"),
+  ("myz", "regular"),
+  ("mza", "regular"),
+  ("mzb", "regular"),
+  ("mzc", "regular"),
+  ("mzd", "regular"),
+  ("mze", "regular"),
+  ("mzg", "regular"),
+  ("mzh", "regular"),
+  ("mzi", "regular"),
+  ("mzj", "regular"),
+  ("mzk", "regular"),
+  ("mzl", "regular"),
+  ("mzm", "regular"),
+  ("mzn", "regular"),
+  ("mzo", "regular"),
+  ("mzp", "regular"),
+  ("mzq", "regular"),
+  ("mzr", "regular"),
+  ("mzs", "regular"),
+  ("mzt", "regular"),
+  ("mzu", "regular"),
+  ("mzv", "regular"),
+  ("mzw", "regular"),
+  ("mzx", "regular"),
+  ("mzy", "regular"),
+  ("mzz", "regular"),
+  ("na", "regular"),
+  ("naa", "regular"),
+  ("nab", "regular"),
+  ("nac", "regular"),
+  ("nad", "deprecated"),
+  ("nae", "regular"),
+  ("naf", "regular"),
+  ("nag", "regular"),
+  ("nah", "regular"),
+  ("nai", "regular"),
+  ("naj", "regular"),
+  ("nak", "regular"),
+  ("nal", "regular"),
+  ("nam", "regular"),
+  ("nan", "regular"),
+  ("nao", "regular"),
+  ("nap", "regular"),
+  ("naq", "regular"),
+  ("nar", "regular"),
+  ("nas", "regular"),
+  ("nat", "regular"),
+  ("naw", "regular"),
+  ("nax", "regular"),
+  ("nay", "regular"),
+  ("naz", "regular"),
+  ("nb", "regular"),
+  ("nba", "regular"),
+  ("nbb", "regular"),
+  ("nbc", "regular"),
+  ("nbd", "regular"),
+  ("nbe", "regular"),
+  ("nbf", "deprecated"),
+  ("nbg", "regular"),
+  ("nbh", "regular"),
+  ("nbi", "regular"),
+  ("nbj", "regular"),
+  ("nbk", "regular"),
+  ("nbm", "regular"),
+  ("nbn", "regular"),
+  ("nbo", "regular"),
+  ("nbp", "regular"),
+  ("nbq", "regular"),
+  ("nbr", "regular"),
+  ("nbs", "regular"),
+  ("nbt", "regular"),
+  ("nbu", "regular"),
+  ("nbv", "regular"),
+  ("nbw", "regular"),
+  ("nbx", "deprecated"),
+  ("nby", "regular"),
+  ("nca", "regular"),
+  ("ncb", "regular"),
+  ("ncc", "regular"),
+  ("ncd", "regular"),
+  ("nce", "regular"),
+  ("ncf", "regular"),
+  ("ncg", "regular"),
+  ("nch", "regular"),
+  ("nci", "regular"),
+  ("ncj", "regular"),
+  ("nck", "regular"),
+  ("ncl", "regular"),
+  ("ncm", "regular"),
+  ("ncn", "regular"),
+  ("nco", "regular"),
+  ("ncp", "deprecated"),
+  ("ncq", "regular"),
+  ("ncr", "regular"),
+  ("ncs", "regular"),
+  ("nct", "regular"),
+  ("ncu", "regular"),
+  ("ncx", "regular"),
+  ("ncz", "regular"),
+  ("nd", "regular"),
+  ("nda", "regular"),
+  ("ndb", "regular"),
+  ("ndc", "regular"),
+  ("ndd", "regular"),
+  ("ndf", "regular"),
+  ("ndg", "regular"),
+  ("ndh", "regular"),
+  ("ndi", "regular"),
+  ("ndj", "regular"),
+  ("ndk", "regular"),
+  ("ndl", "regular"),
+  ("ndm", "regular"),
+  ("ndn", "regular"),
+  ("ndp", "regular"),
+  ("ndq", "regular"),
+  ("ndr", "regular"),
+  ("nds", "regular"),
+  ("ndt", "regular"),
+  ("ndu", "regular"),
+  ("ndv", "regular"),
+  ("ndw", "regular"),
+  ("ndx", "regular"),
+  ("ndy", "regular"),
+  ("ndz", "regular"),
+  ("ne", "regular"),
+  ("nea", "regular"),
+  ("neb", "regular"),
+  ("nec", "regular"),
+  ("ned", "regular"),
+  ("nee", "regular"),
+  ("nef", "regular"),
+  ("neg", "regular"),
+  ("neh", "regular"),
+  ("nei", "regular"),
+  ("nej", "regular"),
+  ("nek", "regular"),
+  ("nem", "regular"),
+  ("nen", "regular"),
+  ("neo", "regular"),
+  ("neq", "regular"),
+  ("ner", "regular"),
+  ("nes", "regular"),
+  ("net", "regular"),
+  ("neu", "regular"),
+  ("nev", "regular"),
+  ("new", "regular"),
+  ("nex", "regular"),
+  ("ney", "regular"),
+  ("nez", "regular"),
+  ("nfa", "regular"),
+  ("nfd", "regular"),
+  ("nfl", "regular"),
+  ("nfr", "regular"),
+  ("nfu", "regular"),
+  ("ng", "regular"),
+  ("nga", "regular"),
+  ("ngb", "regular"),
+  ("ngc", "regular"),
+  ("ngd", "regular"),
+  ("nge", "regular"),
+  ("ngf", "regular"),
+  ("ngg", "regular"),
+  ("ngh", "regular"),
+  ("ngi", "regular"),
+  ("ngj", "regular"),
+  ("ngk", "regular"),
+  ("ngl", "regular"),
+  ("ngm", "regular"),
+  ("ngn", "regular"),
+  ("ngo", "deprecated"),
+  ("ngp", "regular"),
+  ("ngq", "regular"),
+  ("ngr", "regular"),
+  ("ngs", "regular"),
+  ("ngt", "regular"),
+  ("ngu", "regular"),
+  ("ngv", "regular"),
+  ("ngw", "regular"),
+  ("ngx", "regular"),
+  ("ngy", "regular"),
+  ("ngz", "regular"),
+  ("nha", "regular"),
+  ("nhb", "regular"),
+  ("nhc", "regular"),
+  ("nhd", "regular"),
+  ("nhe", "regular"),
+  ("nhf", "regular"),
+  ("nhg", "regular"),
+  ("nhh", "regular"),
+  ("nhi", "regular"),
+  ("nhk", "regular"),
+  ("nhm", "regular"),
+  ("nhn", "regular"),
+  ("nho", "regular"),
+  ("nhp", "regular"),
+  ("nhq", "regular"),
+  ("nhr", "regular"),
+  ("nht", "regular"),
+  ("nhu", "regular"),
+  ("nhv", "regular"),
+  ("nhw", "regular"),
+  ("nhx", "regular"),
+  ("nhy", "regular"),
+  ("nhz", "regular"),
+  ("nia", "regular"),
+  ("nib", "regular"),
+  ("nic", "regular"),
+  ("nid", "regular"),
+  ("nie", "regular"),
+  ("nif", "regular"),
+  ("nig", "regular"),
+  ("nih", "regular"),
+  ("nii", "regular"),
+  ("nij", "regular"),
+  ("nik", "regular"),
+  ("nil", "regular"),
+  ("nim", "regular"),
+  ("nin", "regular"),
+  ("nio", "regular"),
+  ("niq", "regular"),
+  ("nir", "regular"),
+  ("nis", "regular"),
+  ("nit", "regular"),
+  ("niu", "regular"),
+  ("niv", "regular"),
+  ("niw", "regular"),
+  ("nix", "regular"),
+  ("niy", "regular"),
+  ("niz", "regular"),
+  ("nja", "regular"),
+  ("njb", "regular"),
+  ("njd", "regular"),
+  ("njh", "regular"),
+  ("nji", "regular"),
+  ("njj", "regular"),
+  ("njl", "regular"),
+  ("njm", "regular"),
+  ("njn", "regular"),
+  ("njo", "regular"),
+  ("njr", "regular"),
+  ("njs", "regular"),
+  ("njt", "regular"),
+  ("nju", "regular"),
+  ("njx", "regular"),
+  ("njy", "regular"),
+  ("njz", "regular"),
+  ("nka", "regular"),
+  ("nkb", "regular"),
+  ("nkc", "regular"),
+  ("nkd", "regular"),
+  ("nke", "regular"),
+  ("nkf", "regular"),
+  ("nkg", "regular"),
+  ("nkh", "regular"),
+  ("nki", "regular"),
+  ("nkj", "regular"),
+  ("nkk", "regular"),
+  ("nkm", "regular"),
+  ("nkn", "regular"),
+  ("nko", "regular"),
+  ("nkp", "regular"),
+  ("nkq", "regular"),
+  ("nkr", "regular"),
+  ("nks", "regular"),
+  ("nkt", "regular"),
+  ("nku", "regular"),
+  ("nkv", "regular"),
+  ("nkw", "regular"),
+  ("nkx", "regular"),
+  ("nkz", "regular"),
+  ("nl", "regular"),
+  ("nla", "regular"),
+  ("nlc", "regular"),
+  ("nle", "regular"),
+  ("nlg", "regular"),
+  ("nli", "regular"),
+  ("nlj", "regular"),
+  ("nlk", "regular"),
+  ("nll", "regular"),
+  ("nlm", "regular"),
+  ("nln", "deprecated"),
+  ("nlo", "regular"),
+  ("nlq", "regular"),
+  ("nlr", "deprecated"),
+  ("nlu", "regular"),
+  ("nlv", "regular"),
+  ("nlw", "regular"),
+  ("nlx", "regular"),
+  ("nly", "regular"),
+  ("nlz", "regular"),
+  ("nma", "regular"),
+  ("nmb", "regular"),
+  ("nmc", "regular"),
+  ("nmd", "regular"),
+  ("nme", "regular"),
+  ("nmf", "regular"),
+  ("nmg", "regular"),
+  ("nmh", "regular"),
+  ("nmi", "regular"),
+  ("nmj", "regular"),
+  ("nmk", "regular"),
+  ("nml", "regular"),
+  ("nmm", "regular"),
+  ("nmn", "regular"),
+  ("nmo", "regular"),
+  ("nmp", "regular"),
+  ("nmq", "regular"),
+  ("nmr", "regular"),
+  ("nms", "regular"),
+  ("nmt", "regular"),
+  ("nmu", "regular"),
+  ("nmv", "regular"),
+  ("nmw", "regular"),
+  ("nmx", "regular"),
+  ("nmy", "regular"),
+  ("nmz", "regular"),
+  ("nn", "regular"),
+  ("nna", "regular"),
+  ("nnb", "regular"),
+  ("nnc", "regular"),
+  ("nnd", "regular"),
+  ("nne", "regular"),
+  ("nnf", "regular"),
+  ("nng", "regular"),
+  ("nnh", "regular"),
+  ("nni", "regular"),
+  ("nnj", "regular"),
+  ("nnk", "regular"),
+  ("nnl", "regular"),
+  ("nnm", "regular"),
+  ("nnn", "regular"),
+  ("nnp", "regular"),
+  ("nnq", "regular"),
+  ("nnr", "regular"),
+  ("nns", "deprecated"),
+  ("nnt", "regular"),
+  ("nnu", "regular"),
+  ("nnv", "regular"),
+  ("nnw", "regular"),
+  ("nnx", "deprecated"),
+  ("nny", "regular"),
+  ("nnz", "regular"),
+  ("no", "regular"),
+  ("noa", "regular"),
+  ("noc", "regular"),
+  ("nod", "regular"),
+  ("noe", "regular"),
+  ("nof", "regular"),
+  ("nog", "regular"),
+  ("noh", "regular"),
+  ("noi", "regular"),
+  ("noj", "regular"),
+  ("nok", "regular"),
+  ("nol", "regular"),
+  ("nom", "regular"),
+  ("non", "regular"),
+  ("noo", "deprecated"),
+  ("nop", "regular"),
+  ("noq", "regular"),
+  ("nos", "regular"),
+  ("not", "regular"),
+  ("nou", "regular"),
+  ("nov", "regular"),
+  ("now", "regular"),
+  ("noy", "regular"),
+  ("noz", "regular"),
+  ("npa", "regular"),
+  ("npb", "regular"),
+  ("npg", "regular"),
+  ("nph", "regular"),
+  ("npi", "deprecated"),
+  ("npl", "regular"),
+  ("npn", "regular"),
+  ("npo", "regular"),
+  ("nps", "regular"),
+  ("npu", "regular"),
+  ("npx", "regular"),
+  ("npy", "regular"),
+  ("nqg", "regular"),
+  ("nqk", "regular"),
+  ("nql", "regular"),
+  ("nqm", "regular"),
+  ("nqn", "regular"),
+  ("nqo", "regular"),
+  ("nqq", "regular"),
+  ("nqt", "regular"),
+  ("nqy", "regular"),
+  ("nr", "regular"),
+  ("nra", "regular"),
+  ("nrb", "regular"),
+  ("nrc", "regular"),
+  ("nre", "regular"),
+  ("nrf", "regular"),
+  ("nrg", "regular"),
+  ("nri", "regular"),
+  ("nrk", "regular"),
+  ("nrl", "regular"),
+  ("nrm", "regular"),
+  ("nrn", "regular"),
+  ("nrp", "regular"),
+  ("nrr", "regular"),
+  ("nrt", "regular"),
+  ("nru", "regular"),
+  ("nrx", "regular"),
+  ("nrz", "regular"),
+  ("nsa", "regular"),
+  ("nsb", "regular"),
+  ("nsc", "regular"),
+  ("nsd", "regular"),
+  ("nse", "regular"),
+  ("nsf", "regular"),
+  ("nsg", "regular"),
+  ("nsh", "regular"),
+  ("nsi", "regular"),
+  ("nsk", "regular"),
+  ("nsl", "regular"),
+  ("nsm", "regular"),
+  ("nsn", "regular"),
+  ("nso", "regular"),
+  ("nsp", "regular"),
+  ("nsq", "regular"),
+  ("nsr", "regular"),
+  ("nss", "regular"),
+  ("nst", "regular"),
+  ("nsu", "regular"),
+  ("nsv", "regular"),
+  ("nsw", "regular"),
+  ("nsx", "regular"),
+  ("nsy", "regular"),
+  ("nsz", "regular"),
+  ("ntd", "regular"),
+  ("nte", "regular"),
+  ("ntg", "regular"),
+  ("nti", "regular"),
+  ("ntj", "regular"),
+  ("ntk", "regular"),
+  ("ntm", "regular"),
+  ("nto", "regular"),
+  ("ntp", "regular"),
+  ("ntr", "regular"),
+  ("nts", "deprecated"),
+  ("ntu", "regular"),
+  ("ntw", "regular"),
+  ("ntx", "regular"),
+  ("nty", "regular"),
+  ("ntz", "regular"),
+  ("nua", "regular"),
+  ("nub", "regular"),
+  ("nuc", "regular"),
+  ("nud", "regular"),
+  ("nue", "regular"),
+  ("nuf", "regular"),
+  ("nug", "regular"),
+  ("nuh", "regular"),
+  ("nui", "regular"),
+  ("nuj", "regular"),
+  ("nuk", "regular"),
+  ("nul", "regular"),
+  ("num", "regular"),
+  ("nun", "regular"),
+  ("nuo", "regular"),
+  ("nup", "regular"),
+  ("nuq", "regular"),
+  ("nur", "regular"),
+  ("nus", "regular"),
+  ("nut", "regular"),
+  ("nuu", "regular"),
+  ("nuv", "regular"),
+  ("nuw", "regular"),
+  ("nux", "regular"),
+  ("nuy", "regular"),
+  ("nuz", "regular"),
+  ("nv", "regular"),
+  ("nvh", "regular"),
+  ("nvm", "regular"),
+  ("nvo", "regular"),
+  ("nwa", "regular"),
+  ("nwb", "regular"),
+  ("nwc", "regular"),
+  ("nwe", "regular"),
+  ("nwg", "regular"),
+  ("nwi", "regular"),
+  ("nwm", "regular"),
+  ("nwo", "regular"),
+  ("nwr", "regular"),
+  ("nww", "regular"),
+  ("nwx", "regular"),
+  ("nwy", "regular"),
+  ("nxa", "regular"),
+  ("nxd", "regular"),
+  ("nxe", "regular"),
+  ("nxg", "regular"),
+  ("nxi", "regular"),
+  ("nxk", "regular"),
+  ("nxl", "regular"),
+  ("nxm", "regular"),
+  ("nxn", "regular"),
+  ("nxo", "regular"),
+  ("nxq", "regular"),
+  ("nxr", "regular"),
+  ("nxu", "deprecated"),
+  ("nxx", "regular"),
+  ("ny", "regular"),
+  ("nyb", "regular"),
+  ("nyc", "regular"),
+  ("nyd", "regular"),
+  ("nye", "regular"),
+  ("nyf", "regular"),
+  ("nyg", "regular"),
+  ("nyh", "regular"),
+  ("nyi", "regular"),
+  ("nyj", "regular"),
+  ("nyk", "regular"),
+  ("nyl", "regular"),
+  ("nym", "regular"),
+  ("nyn", "regular"),
+  ("nyo", "regular"),
+  ("nyp", "regular"),
+  ("nyq", "regular"),
+  ("nyr", "regular"),
+  ("nys", "regular"),
+  ("nyt", "regular"),
+  ("nyu", "regular"),
+  ("nyv", "regular"),
+  ("nyw", "regular"),
+  ("nyx", "regular"),
+  ("nyy", "regular"),
+  ("nza", "regular"),
+  ("nzb", "regular"),
+  ("nzd", "regular"),
+  ("nzi", "regular"),
+  ("nzk", "regular"),
+  ("nzm", "regular"),
+  ("nzs", "regular"),
+  ("nzu", "regular"),
+  ("nzy", "regular"),
+  ("nzz", "regular"),
+  ("oaa", "regular"),
+  ("oac", "regular"),
+  ("oar", "regular"),
+  ("oav", "regular"),
+  ("obi", "regular"),
+  ("obk", "regular"),
+  ("obl", "regular"),
+  ("obm", "regular"),
+  ("obo", "regular"),
+  ("obr", "regular"),
+  ("obt", "regular"),
+  ("obu", "regular"),
+  ("oc", "regular"),
+  ("oca", "regular"),
+  ("och", "regular"),
+  ("ocm", "regular"),
+  ("oco", "regular"),
+  ("ocu", "regular"),
+  ("oda", "regular"),
+  ("odk", "regular"),
+  ("odt", "regular"),
+  ("odu", "regular"),
+  ("ofo", "regular"),
+  ("ofs", "regular"),
+  ("ofu", "regular"),
+  ("ogb", "regular"),
+  ("ogc", "regular"),
+  ("oge", "regular"),
+  ("ogg", "regular"),
+  ("ogo", "regular"),
+  ("ogu", "regular"),
+  ("oht", "regular"),
+  ("ohu", "regular"),
+  ("oia", "regular"),
+  ("oie", "regular"),
+  ("oin", "regular"),
+  ("oj", "regular"),
+  ("ojb", "regular"),
+  ("ojc", "regular"),
+  ("ojg", "deprecated"),
+  ("ojp", "regular"),
+  ("ojs", "regular"),
+  ("ojv", "regular"),
+  ("ojw", "regular"),
+  ("oka", "regular"),
+  ("okb", "regular"),
+  ("okc", "regular"),
+  ("okd", "regular"),
+  ("oke", "regular"),
+  ("okg", "regular"),
+  ("okh", "regular"),
+  ("oki", "regular"),
+  ("okj", "regular"),
+  ("okk", "regular"),
+  ("okl", "regular"),
+  ("okm", "regular"),
+  ("okn", "regular"),
+  ("oko", "regular"),
+  ("okr", "regular"),
+  ("oks", "regular"),
+  ("oku", "regular"),
+  ("okv", "regular"),
+  ("okx", "regular"),
+  ("okz", "regular"),
+  ("ola", "regular"),
+  ("old", "regular"),
+  ("ole", "regular"),
+  ("olk", "regular"),
+  ("olm", "regular"),
+  ("olo", "regular"),
+  ("olr", "regular"),
+  ("olt", "regular"),
+  ("olu", "regular"),
+  ("om", "regular"),
+  ("oma", "regular"),
+  ("omb", "regular"),
+  ("omc", "regular"),
+  ("ome", "deprecated"),
+  ("omg", "regular"),
+  ("omi", "regular"),
+  ("omk", "regular"),
+  ("oml", "regular"),
+  ("omn", "regular"),
+  ("omo", "regular"),
+  ("omp", "regular"),
+  ("omq", "regular"),
+  ("omr", "regular"),
+  ("omt", "regular"),
+  ("omu", "regular"),
+  ("omv", "regular"),
+  ("omw", "regular"),
+  ("omx", "regular"),
+  ("omy", "regular"),
+  ("ona", "regular"),
+  ("onb", "regular"),
+  ("one", "regular"),
+  ("ong", "regular"),
+  ("oni", "regular"),
+  ("onj", "regular"),
+  ("onk", "regular"),
+  ("onn", "regular"),
+  ("ono", "regular"),
+  ("onp", "regular"),
+  ("onr", "regular"),
+  ("ons", "regular"),
+  ("ont", "regular"),
+  ("onu", "regular"),
+  ("onw", "regular"),
+  ("onx", "regular"),
+  ("ood", "regular"),
+  ("oog", "regular"),
+  ("oon", "regular"),
+  ("oor", "regular"),
+  ("oos", "regular"),
+  ("opa", "regular"),
+  ("opk", "regular"),
+  ("opm", "regular"),
+  ("opo", "regular"),
+  ("opt", "regular"),
+  ("opy", "regular"),
+  ("or", "regular"),
+  ("ora", "regular"),
+  ("orc", "regular"),
+  ("ore", "regular"),
+  ("org", "regular"),
+  ("orh", "regular"),
+  ("orn", "regular"),
+  ("oro", "regular"),
+  ("orr", "regular"),
+  ("ors", "regular"),
+  ("ort", "regular"),
+  ("oru", "regular"),
+  ("orv", "regular"),
+  ("orw", "regular"),
+  ("orx", "regular"),
+  ("ory", "deprecated"),
+  ("orz", "regular"),
+  ("os", "regular"),
+  ("osa", "regular"),
+  ("osc", "regular"),
+  ("osi", "regular"),
+  ("osn", "regular"),
+  ("oso", "regular"),
+  ("osp", "regular"),
+  ("ost", "regular"),
+  ("osu", "regular"),
+  ("osx", "regular"),
+  ("ota", "regular"),
+  ("otb", "regular"),
+  ("otd", "regular"),
+  ("ote", "regular"),
+  ("oti", "regular"),
+  ("otk", "regular"),
+  ("otl", "regular"),
+  ("otm", "regular"),
+  ("otn", "regular"),
+  ("oto", "regular"),
+  ("otq", "regular"),
+  ("otr", "regular"),
+  ("ots", "regular"),
+  ("ott", "regular"),
+  ("otu", "regular"),
+  ("otw", "regular"),
+  ("otx", "regular"),
+  ("oty", "regular"),
+  ("otz", "regular"),
+  ("oua", "regular"),
+  ("oub", "regular"),
+  ("oue", "regular"),
+  ("oui", "regular"),
+  ("oum", "regular"),
+  ("oun", "deprecated"),
+  ("ovd", "regular"),
+  ("owi", "regular"),
+  ("owl", "regular"),
+  ("oyb", "regular"),
+  ("oyd", "regular"),
+  ("oym", "regular"),
+  ("oyy", "regular"),
+  ("ozm", "regular"),
+  ("pa", "regular"),
+  ("paa", "regular"),
+  ("pab", "regular"),
+  ("pac", "regular"),
+  ("pad", "regular"),
+  ("pae", "regular"),
+  ("paf", "regular"),
+  ("pag", "regular"),
+  ("pah", "regular"),
+  ("pai", "regular"),
+  ("pak", "regular"),
+  ("pal", "regular"),
+  ("pam", "regular"),
+  ("pao", "regular"),
+  ("pap", "regular"),
+  ("paq", "regular"),
+  ("par", "regular"),
+  ("pas", "regular"),
+  ("pat", "deprecated"),
+  ("pau", "regular"),
+  ("pav", "regular"),
+  ("paw", "regular"),
+  ("pax", "regular"),
+  ("pay", "regular"),
+  ("paz", "regular"),
+  ("pbb", "regular"),
+  ("pbc", "regular"),
+  ("pbe", "regular"),
+  ("pbf", "regular"),
+  ("pbg", "regular"),
+  ("pbh", "regular"),
+  ("pbi", "regular"),
+  ("pbl", "regular"),
+  ("pbm", "regular"),
+  ("pbn", "regular"),
+  ("pbo", "regular"),
+  ("pbp", "regular"),
+  ("pbr", "regular"),
+  ("pbs", "regular"),
+  ("pbt", "regular"),
+  ("pbu", "deprecated"),
+  ("pbv", "regular"),
+  ("pby", "regular"),
+  ("pbz", "deprecated"),
+  ("pca", "regular"),
+  ("pcb", "regular"),
+  ("pcc", "regular"),
+  ("pcd", "regular"),
+  ("pce", "regular"),
+  ("pcf", "regular"),
+  ("pcg", "regular"),
+  ("pch", "regular"),
+  ("pci", "regular"),
+  ("pcj", "regular"),
+  ("pck", "regular"),
+  ("pcl", "regular"),
+  ("pcm", "regular"),
+  ("pcn", "regular"),
+  ("pcp", "regular"),
+  ("pcr", "deprecated"),
+  ("pcw", "regular"),
+  ("pda", "regular"),
+  ("pdc", "regular"),
+  ("pdi", "regular"),
+  ("pdn", "regular"),
+  ("pdo", "regular"),
+  ("pdt", "regular"),
+  ("pdu", "regular"),
+  ("pea", "regular"),
+  ("peb", "regular"),
+  ("ped", "regular"),
+  ("pee", "regular"),
+  ("pef", "regular"),
+  ("peg", "regular"),
+  ("peh", "regular"),
+  ("pei", "regular"),
+  ("pej", "regular"),
+  ("pek", "regular"),
+  ("pel", "regular"),
+  ("pem", "regular"),
+  ("peo", "regular"),
+  ("pep", "regular"),
+  ("peq", "regular"),
+  ("pes", "deprecated"),
+  ("pev", "regular"),
+  ("pex", "regular"),
+  ("pey", "regular"),
+  ("pez", "regular"),
+  ("pfa", "regular"),
+  ("pfe", "regular"),
+  ("pfl", "regular"),
+  ("pga", "regular"),
+  ("pgd", "regular"),
+  ("pgg", "regular"),
+  ("pgi", "regular"),
+  ("pgk", "regular"),
+  ("pgl", "regular"),
+  ("pgn", "regular"),
+  ("pgs", "regular"),
+  ("pgu", "regular"),
+  ("pgy", "deprecated"),
+  ("pgz", "regular"),
+  ("pha", "regular"),
+  ("phd", "regular"),
+  ("phg", "regular"),
+  ("phh", "regular"),
+  ("phi", "regular"),
+  ("phj", "regular"),
+  ("phk", "regular"),
+  ("phl", "regular"),
+  ("phm", "regular"),
+  ("phn", "regular"),
+  ("pho", "regular"),
+  ("phq", "regular"),
+  ("phr", "regular"),
+  ("pht", "regular"),
+  ("phu", "regular"),
+  ("phv", "regular"),
+  ("phw", "regular"),
+  ("pi", "regular"),
+  ("pia", "regular"),
+  ("pib", "regular"),
+  ("pic", "regular"),
+  ("pid", "regular"),
+  ("pie", "regular"),
+  ("pif", "regular"),
+  ("pig", "regular"),
+  ("pih", "regular"),
+  ("pii", "deprecated"),
+  ("pij", "regular"),
+  ("pil", "regular"),
+  ("pim", "regular"),
+  ("pin", "regular"),
+  ("pio", "regular"),
+  ("pip", "regular"),
+  ("pir", "regular"),
+  ("pis", "regular"),
+  ("pit", "regular"),
+  ("piu", "regular"),
+  ("piv", "regular"),
+  ("piw", "regular"),
+  ("pix", "regular"),
+  ("piy", "regular"),
+  ("piz", "regular"),
+  ("pjt", "regular"),
+  ("pka", "regular"),
+  ("pkb", "regular"),
+  ("pkc", "regular"),
+  ("pkg", "regular"),
+  ("pkh", "regular"),
+  ("pkn", "regular"),
+  ("pko", "regular"),
+  ("pkp", "regular"),
+  ("pkr", "regular"),
+  ("pks", "regular"),
+  ("pkt", "regular"),
+  ("pku", "regular"),
+  ("pl", "regular"),
+  ("pla", "regular"),
+  ("plb", "regular"),
+  ("plc", "regular"),
+  ("pld", "regular"),
+  ("ple", "regular"),
+  ("plf", "regular"),
+  ("plg", "regular"),
+  ("plh", "regular"),
+  ("plj", "regular"),
+  ("plk", "regular"),
+  ("pll", "regular"),
+  ("pln", "regular"),
+  ("plo", "regular"),
+  ("plp", "deprecated"),
+  ("plq", "regular"),
+  ("plr", "regular"),
+  ("pls", "regular"),
+  ("plt", "deprecated"),
+  ("plu", "regular"),
+  ("plv", "regular"),
+  ("plw", "regular"),
+  ("ply", "regular"),
+  ("plz", "regular"),
+  ("pma", "regular"),
+  ("pmb", "regular"),
+  ("pmc", "deprecated"),
+  ("pmd", "regular"),
+  ("pme", "regular"),
+  ("pmf", "regular"),
+  ("pmh", "regular"),
+  ("pmi", "regular"),
+  ("pmj", "regular"),
+  ("pmk", "regular"),
+  ("pml", "regular"),
+  ("pmm", "regular"),
+  ("pmn", "regular"),
+  ("pmo", "regular"),
+  ("pmq", "regular"),
+  ("pmr", "regular"),
+  ("pms", "regular"),
+  ("pmt", "regular"),
+  ("pmu", "deprecated"),
+  ("pmw", "regular"),
+  ("pmx", "regular"),
+  ("pmy", "regular"),
+  ("pmz", "regular"),
+  ("pna", "regular"),
+  ("pnb", "deprecated"),
+  ("pnc", "regular"),
+  ("pnd", "regular"),
+  ("pne", "regular"),
+  ("png", "regular"),
+  ("pnh", "regular"),
+  ("pni", "regular"),
+  ("pnj", "regular"),
+  ("pnk", "regular"),
+  ("pnl", "regular"),
+  ("pnm", "regular"),
+  ("pnn", "regular"),
+  ("pno", "regular"),
+  ("pnp", "regular"),
+  ("pnq", "regular"),
+  ("pnr", "regular"),
+  ("pns", "regular"),
+  ("pnt", "regular"),
+  ("pnu", "regular"),
+  ("pnv", "regular"),
+  ("pnw", "regular"),
+  ("pnx", "regular"),
+  ("pny", "regular"),
+  ("pnz", "regular"),
+  ("poc", "regular"),
+  ("pod", "deprecated"),
+  ("poe", "regular"),
+  ("pof", "regular"),
+  ("pog", "regular"),
+  ("poh", "regular"),
+  ("poi", "regular"),
+  ("pok", "regular"),
+  ("pom", "regular"),
+  ("pon", "regular"),
+  ("poo", "regular"),
+  ("pop", "regular"),
+  ("poq", "regular"),
+  ("pos", "regular"),
+  ("pot", "regular"),
+  ("pov", "regular"),
+  ("pow", "regular"),
+  ("pox", "regular"),
+  ("poy", "regular"),
+  ("poz", "regular"),
+  ("ppa", "deprecated"),
+  ("ppe", "regular"),
+  ("ppi", "regular"),
+  ("ppk", "regular"),
+  ("ppl", "regular"),
+  ("ppm", "regular"),
+  ("ppn", "regular"),
+  ("ppo", "regular"),
+  ("ppp", "regular"),
+  ("ppq", "regular"),
+  ("ppr", "deprecated"),
+  ("pps", "regular"),
+  ("ppt", "regular"),
+  ("ppu", "regular"),
+  ("pqa", "regular"),
+  ("pqe", "regular"),
+  ("pqm", "regular"),
+  ("pqw", "regular"),
+  ("pra", "regular"),
+  ("prb", "deprecated"),
+  ("prc", "regular"),
+  ("prd", "regular"),
+  ("pre", "regular"),
+  ("prf", "regular"),
+  ("prg", "regular"),
+  ("prh", "regular"),
+  ("pri", "regular"),
+  ("prk", "regular"),
+  ("prl", "regular"),
+  ("prm", "regular"),
+  ("prn", "regular"),
+  ("pro", "regular"),
+  ("prp", "regular"),
+  ("prq", "regular"),
+  ("prr", "regular"),
+  ("prs", "deprecated"),
+  ("prt", "regular"),
+  ("pru", "regular"),
+  ("prw", "regular"),
+  ("prx", "regular"),
+  ("pry", "deprecated"),
+  ("prz", "regular"),
+  ("ps", "regular"),
+  ("psa", "regular"),
+  ("psc", "regular"),
+  ("psd", "regular"),
+  ("pse", "regular"),
+  ("psg", "regular"),
+  ("psh", "regular"),
+  ("psi", "regular"),
+  ("psl", "regular"),
+  ("psm", "regular"),
+  ("psn", "regular"),
+  ("pso", "regular"),
+  ("psp", "regular"),
+  ("psq", "regular"),
+  ("psr", "regular"),
+  ("pss", "regular"),
+  ("pst", "regular"),
+  ("psu", "regular"),
+  ("psw", "regular"),
+  ("psy", "regular"),
+  ("pt", "regular"),
+  ("pta", "regular"),
+  ("pth", "regular"),
+  ("pti", "regular"),
+  ("ptn", "regular"),
+  ("pto", "regular"),
+  ("ptp", "regular"),
+  ("ptq", "regular"),
+  ("ptr", "regular"),
+  ("ptt", "regular"),
+  ("ptu", "regular"),
+  ("ptv", "regular"),
+  ("ptw", "regular"),
+  ("pty", "regular"),
+  ("pua", "regular"),
+  ("pub", "regular"),
+  ("puc", "regular"),
+  ("pud", "regular"),
+  ("pue", "regular"),
+  ("puf", "regular"),
+  ("pug", "regular"),
+  ("pui", "regular"),
+  ("puj", "regular"),
+  ("puk", "deprecated"),
+  ("pum", "regular"),
+  ("puo", "regular"),
+  ("pup", "regular"),
+  ("puq", "regular"),
+  ("pur", "regular"),
+  ("put", "regular"),
+  ("puu", "regular"),
+  ("puw", "regular"),
+  ("pux", "regular"),
+  ("puy", "regular"),
+  ("puz", "deprecated"),
+  ("pwa", "regular"),
+  ("pwb", "regular"),
+  ("pwg", "regular"),
+  ("pwi", "regular"),
+  ("pwm", "regular"),
+  ("pwn", "regular"),
+  ("pwo", "regular"),
+  ("pwr", "regular"),
+  ("pww", "regular"),
+  ("pxm", "regular"),
+  ("pye", "regular"),
+  ("pym", "regular"),
+  ("pyn", "regular"),
+  ("pys", "regular"),
+  ("pyu", "regular"),
+  ("pyx", "regular"),
+  ("pyy", "regular"),
+  ("pzh", "regular"),
+  ("pzn", "regular"),
+  ("qu", "regular"),
+  ("qua", "regular"),
+  ("qub", "regular"),
+  ("quc", "regular"),
+  ("qud", "regular"),
+  ("quf", "regular"),
+  ("qug", "regular"),
+  ("quh", "regular"),
+  ("qui", "regular"),
+  ("quk", "regular"),
+  ("qul", "regular"),
+  ("qum", "regular"),
+  ("qun", "regular"),
+  ("qup", "regular"),
+  ("quq", "regular"),
+  ("qur", "regular"),
+  ("qus", "regular"),
+  ("quv", "regular"),
+  ("quw", "regular"),
+  ("qux", "regular"),
+  ("quy", "regular"),
+  ("quz", "deprecated"),
+  ("qva", "regular"),
+  ("qvc", "regular"),
+  ("qve", "regular"),
+  ("qvh", "regular"),
+  ("qvi", "regular"),
+  ("qvj", "regular"),
+  ("qvl", "regular"),
+  ("qvm", "regular"),
+  ("qvn", "regular"),
+  ("qvo", "regular"),
+  ("qvp", "regular"),
+  ("qvs", "regular"),
+  ("qvw", "regular"),
+  ("qvy", "regular"),
+  ("qvz", "regular"),
+  ("qwa", "regular"),
+  ("qwc", "regular"),
+  ("qwe", "regular"),
+  ("qwh", "regular"),
+  ("qwm", "regular"),
+  ("qws", "regular"),
+  ("qwt", "regular"),
+  ("qxa", "regular"),
+  ("qxc", "regular"),
+  ("qxh", "regular"),
+  ("qxl", "regular"),
+  ("qxn", "regular"),
+  ("qxo", "regular"),
+  ("qxp", "regular"),
+  ("qxq", "regular"),
+  ("qxr", "regular"),
+  ("qxs", "regular"),
+  ("qxt", "regular"),
+  ("qxu", "regular"),
+  ("qxw", "regular"),
+  ("qya", "regular"),
+  ("qyp", "regular"),
+  ("raa", "regular"),
+  ("rab", "regular"),
+  ("rac", "regular"),
+  ("rad", "regular"),
+  ("raf", "regular"),
+  ("rag", "regular"),
+  ("rah", "regular"),
+  ("rai", "regular"),
+  ("raj", "regular"),
+  ("rak", "regular"),
+  ("ral", "regular"),
+  ("ram", "regular"),
+  ("ran", "regular"),
+  ("rao", "regular"),
+  ("rap", "regular"),
+  ("raq", "regular"),
+  ("rar", "regular"),
+  ("ras", "regular"),
+  ("rat", "regular"),
+  ("rau", "regular"),
+  ("rav", "regular"),
+  ("raw", "regular"),
+  ("rax", "regular"),
+  ("ray", "regular"),
+  ("raz", "regular"),
+  ("rbb", "regular"),
+  ("rbk", "regular"),
+  ("rbl", "regular"),
+  ("rbp", "regular"),
+  ("rcf", "regular"),
+  ("rdb", "regular"),
+  ("rea", "regular"),
+  ("reb", "regular"),
+  ("ree", "regular"),
+  ("reg", "regular"),
+  ("rei", "regular"),
+  ("rej", "regular"),
+  ("rel", "regular"),
+  ("rem", "regular"),
+  ("ren", "regular"),
+  ("rer", "regular"),
+  ("res", "regular"),
+  ("ret", "regular"),
+  ("rey", "regular"),
+  ("rga", "regular"),
+  ("rge", "regular"),
+  ("rgk", "regular"),
+  ("rgn", "regular"),
+  ("rgr", "regular"),
+  ("rgs", "regular"),
+  ("rgu", "regular"),
+  ("rhg", "regular"),
+  ("rhp", "regular"),
+  ("ria", "regular"),
+  ("rib", "regular"),
+  ("rie", "deprecated"),
+  ("rif", "regular"),
+  ("ril", "regular"),
+  ("rim", "regular"),
+  ("rin", "regular"),
+  ("rir", "regular"),
+  ("rit", "regular"),
+  ("riu", "regular"),
+  ("rjg", "regular"),
+  ("rji", "regular"),
+  ("rjs", "regular"),
+  ("rka", "regular"),
+  ("rkb", "regular"),
+  ("rkh", "regular"),
+  ("rki", "regular"),
+  ("rkm", "regular"),
+  ("rkt", "regular"),
+  ("rkw", "regular"),
+  ("rm", "regular"),
+  ("rma", "regular"),
+  ("rmb", "regular"),
+  ("rmc", "regular"),
+  ("rmd", "regular"),
+  ("rme", "regular"),
+  ("rmf", "regular"),
+  ("rmg", "regular"),
+  ("rmh", "regular"),
+  ("rmi", "regular"),
+  ("rmk", "regular"),
+  ("rml", "regular"),
+  ("rmm", "regular"),
+  ("rmn", "regular"),
+  ("rmo", "regular"),
+  ("rmp", "regular"),
+  ("rmq", "regular"),
+  ("rmr", "deprecated"),
+  ("rms", "regular"),
+  ("rmt", "regular"),
+  ("rmu", "regular"),
+  ("rmv", "regular"),
+  ("rmw", "regular"),
+  ("rmx", "regular"),
+  ("rmy", "deprecated"),
+  ("rmz", "regular"),
+  ("rn", "regular"),
+  ("rna", "deprecated"),
+  ("rnb", "regular"),
+  ("rnd", "regular"),
+  ("rng", "regular"),
+  ("rnl", "regular"),
+  ("rnn", "regular"),
+  ("rnp", "regular"),
+  ("rnr", "regular"),
+  ("rnw", "regular"),
+  ("ro", "regular"),
+  ("roa", "regular"),
+  ("rob", "regular"),
+  ("roc", "regular"),
+  ("rod", "regular"),
+  ("roe", "regular"),
+  ("rof", "regular"),
+  ("rog", "regular"),
+  ("rol", "regular"),
+  ("rom", "regular"),
+  ("roo", "regular"),
+  ("rop", "regular"),
+  ("ror", "regular"),
+  ("rou", "regular"),
+  ("row", "regular"),
+  ("rpn", "regular"),
+  ("rpt", "regular"),
+  ("rri", "regular"),
+  ("rro", "regular"),
+  ("rrt", "regular"),
+  ("rsb", "regular"),
+  ("rsi", "deprecated"),
+  ("rsk", "regular"),
+  ("rsl", "regular"),
+  ("rsm", "regular"),
+  ("rsn", "regular"),
+  ("rtc", "regular"),
+  ("rth", "regular"),
+  ("rtm", "regular"),
+  ("rts", "regular"),
+  ("rtw", "regular"),
+  ("ru", "regular"),
+  ("rub", "regular"),
+  ("ruc", "regular"),
+  ("rue", "regular"),
+  ("ruf", "regular"),
+  ("rug", "regular"),
+  ("ruh", "regular"),
+  ("rui", "regular"),
+  ("ruk", "regular"),
+  ("ruo", "regular"),
+  ("rup", "regular"),
+  ("ruq", "regular"),
+  ("rut", "regular"),
+  ("ruu", "regular"),
+  ("ruy", "regular"),
+  ("ruz", "regular"),
+  ("rw", "regular"),
+  ("rwa", "regular"),
+  ("rwk", "regular"),
+  ("rwl", "regular"),
+  ("rwm", "regular"),
+  ("rwo", "regular"),
+  ("rwr", "regular"),
+  ("rxd", "regular"),
+  ("rxw", "regular"),
+  ("ryn", "regular"),
+  ("rys", "regular"),
+  ("ryu", "regular"),
+  ("rzh", "regular"),
+  ("sa", "regular"),
+  ("saa", "regular"),
+  ("sab", "regular"),
+  ("sac", "regular"),
+  ("sad", "regular"),
+  ("sae", "regular"),
+  ("saf", "regular"),
+  ("sah", "regular"),
+  ("sai", "regular"),
+  ("saj", "regular"),
+  ("sak", "regular"),
+  ("sal", "regular"),
+  ("sam", "regular"),
+  ("sao", "regular"),
+  ("sap", "deprecated"),
+  ("saq", "regular"),
+  ("sar", "regular"),
+  ("sas", "regular"),
+  ("sat", "regular"),
+  ("sau", "regular"),
+  ("sav", "regular"),
+  ("saw", "regular"),
+  ("sax", "regular"),
+  ("say", "regular"),
+  ("saz", "regular"),
+  ("sba", "regular"),
+  ("sbb", "regular"),
+  ("sbc", "regular"),
+  ("sbd", "regular"),
+  ("sbe", "regular"),
+  ("sbf", "regular"),
+  ("sbg", "regular"),
+  ("sbh", "regular"),
+  ("sbi", "regular"),
+  ("sbj", "regular"),
+  ("sbk", "regular"),
+  ("sbl", "regular"),
+  ("sbm", "regular"),
+  ("sbn", "regular"),
+  ("sbo", "regular"),
+  ("sbp", "regular"),
+  ("sbq", "regular"),
+  ("sbr", "regular"),
+  ("sbs", "regular"),
+  ("sbt", "regular"),
+  ("sbu", "regular"),
+  ("sbv", "regular"),
+  ("sbw", "regular"),
+  ("sbx", "regular"),
+  ("sby", "regular"),
+  ("sbz", "regular"),
+  ("sc", "regular"),
+  ("sca", "deprecated"),
+  ("scb", "regular"),
+  ("sce", "regular"),
+  ("scf", "regular"),
+  ("scg", "regular"),
+  ("sch", "regular"),
+  ("sci", "regular"),
+  ("sck", "regular"),
+  ("scl", "regular"),
+  ("scn", "regular"),
+  ("sco", "regular"),
+  ("scp", "regular"),
+  ("scq", "regular"),
+  ("scs", "regular"),
+  ("sct", "regular"),
+  ("scu", "regular"),
+  ("scv", "regular"),
+  ("scw", "regular"),
+  ("scx", "regular"),
+  ("sd", "regular"),
+  ("sda", "regular"),
+  ("sdb", "regular"),
+  ("sdc", "regular"),
+  ("sde", "regular"),
+  ("sdf", "regular"),
+  ("sdg", "regular"),
+  ("sdh", "regular"),
+  ("sdj", "regular"),
+  ("sdk", "regular"),
+  ("sdl", "regular"),
+  ("sdm", "deprecated"),
+  ("sdn", "regular"),
+  ("sdo", "regular"),
+  ("sdp", "regular"),
+  ("sdq", "regular"),
+  ("sdr", "regular"),
+  ("sds", "regular"),
+  ("sdt", "regular"),
+  ("sdu", "regular"),
+  ("sdv", "regular"),
+  ("sdx", "regular"),
+  ("sdz", "regular"),
+  ("se", "regular"),
+  ("sea", "regular"),
+  ("seb", "regular"),
+  ("sec", "regular"),
+  ("sed", "regular"),
+  ("see", "regular"),
+  ("sef", "regular"),
+  ("seg", "regular"),
+  ("seh", "regular"),
+  ("sei", "regular"),
+  ("sej", "regular"),
+  ("sek", "regular"),
+  ("sel", "regular"),
+  ("sem", "regular"),
+  ("sen", "regular"),
+  ("seo", "regular"),
+  ("sep", "regular"),
+  ("seq", "regular"),
+  ("ser", "regular"),
+  ("ses", "regular"),
+  ("set", "regular"),
+  ("seu", "regular"),
+  ("sev", "regular"),
+  ("sew", "regular"),
+  ("sey", "regular"),
+  ("sez", "regular"),
+  ("sfb", "regular"),
+  ("sfe", "regular"),
+  ("sfm", "regular"),
+  ("sfs", "regular"),
+  ("sfw", "regular"),
+  ("sg", "regular"),
+  ("sga", "regular"),
+  ("sgb", "regular"),
+  ("sgc", "regular"),
+  ("sgd", "regular"),
+  ("sge", "regular"),
+  ("sgg", "regular"),
+  ("sgh", "regular"),
+  ("sgi", "regular"),
+  ("sgj", "regular"),
+  ("sgk", "regular"),
+  ("sgl", "deprecated"),
+  ("sgm", "regular"),
+  ("sgn", "regular"),
+  ("sgo", "deprecated"),
+  ("sgp", "regular"),
+  ("sgr", "regular"),
+  ("sgs", "regular"),
+  ("sgt", "regular"),
+  ("sgu", "regular"),
+  ("sgw", "regular"),
+  ("sgx", "regular"),
+  ("sgy", "regular"),
+  ("sgz", "regular"),
+  ("sh", "deprecated"),
+  ("sha", "regular"),
+  ("shb", "regular"),
+  ("shc", "regular"),
+  ("shd", "regular"),
+  ("she", "regular"),
+  ("shg", "regular"),
+  ("shh", "regular"),
+  ("shi", "regular"),
+  ("shj", "regular"),
+  ("shk", "regular"),
+  ("shl", "regular"),
+  ("shm", "regular"),
+  ("shn", "regular"),
+  ("sho", "regular"),
+  ("shp", "regular"),
+  ("shq", "regular"),
+  ("shr", "regular"),
+  ("shs", "regular"),
+  ("sht", "regular"),
+  ("shu", "regular"),
+  ("shv", "regular"),
+  ("shw", "regular"),
+  ("shx", "regular"),
+  ("shy", "regular"),
+  ("shz", "regular"),
+  ("si", "regular"),
+  ("sia", "regular"),
+  ("sib", "regular"),
+  ("sid", "regular"),
+  ("sie", "regular"),
+  ("sif", "regular"),
+  ("sig", "regular"),
+  ("sih", "regular"),
+  ("sii", "regular"),
+  ("sij", "regular"),
+  ("sik", "regular"),
+  ("sil", "regular"),
+  ("sim", "regular"),
+  ("sio", "regular"),
+  ("sip", "regular"),
+  ("siq", "regular"),
+  ("sir", "regular"),
+  ("sis", "regular"),
+  ("sit", "regular"),
+  ("siu", "regular"),
+  ("siv", "regular"),
+  ("siw", "regular"),
+  ("six", "regular"),
+  ("siy", "regular"),
+  ("siz", "regular"),
+  ("sja", "regular"),
+  ("sjb", "regular"),
+  ("sjd", "regular"),
+  ("sje", "regular"),
+  ("sjg", "regular"),
+  ("sjk", "regular"),
+  ("sjl", "regular"),
+  ("sjm", "regular"),
+  ("sjn", "regular"),
+  ("sjo", "regular"),
+  ("sjp", "regular"),
+  ("sjr", "regular"),
+  ("sjs", "regular"),
+  ("sjt", "regular"),
+  ("sju", "regular"),
+  ("sjw", "regular"),
+  ("sk", "regular"),
+  ("ska", "regular"),
+  ("skb", "regular"),
+  ("skc", "regular"),
+  ("skd", "regular"),
+  ("ske", "regular"),
+  ("skf", "regular"),
+  ("skg", "regular"),
+  ("skh", "regular"),
+  ("ski", "regular"),
+  ("skj", "regular"),
+  ("skk", "deprecated"),
+  ("skm", "regular"),
+  ("skn", "regular"),
+  ("sko", "regular"),
+  ("skp", "regular"),
+  ("skq", "regular"),
+  ("skr", "regular"),
+  ("sks", "regular"),
+  ("skt", "regular"),
+  ("sku", "regular"),
+  ("skv", "regular"),
+  ("skw", "regular"),
+  ("skx", "regular"),
+  ("sky", "regular"),
+  ("skz", "regular"),
+  ("sl", "regular"),
+  ("sla", "regular"),
+  ("slc", "regular"),
+  ("sld", "regular"),
+  ("sle", "regular"),
+  ("slf", "regular"),
+  ("slg", "regular"),
+  ("slh", "regular"),
+  ("sli", "regular"),
+  ("slj", "regular"),
+  ("sll", "regular"),
+  ("slm", "regular"),
+  ("sln", "regular"),
+  ("slp", "regular"),
+  ("slq", "regular"),
+  ("slr", "regular"),
+  ("sls", "regular"),
+  ("slt", "regular"),
+  ("slu", "regular"),
+  ("slw", "regular"),
+  ("slx", "regular"),
+  ("sly", "regular"),
+  ("slz", "regular"),
+  ("sm", "regular"),
+  ("sma", "regular"),
+  ("smb", "regular"),
+  ("smc", "regular"),
+  ("smd", "deprecated"),
+  ("smf", "regular"),
+  ("smg", "regular"),
+  ("smh", "regular"),
+  ("smi", "regular"),
+  ("smj", "regular"),
+  ("smk", "regular"),
+  ("sml", "regular"),
+  ("smm", "regular"),
+  ("smn", "regular"),
+  ("smp", "regular"),
+  ("smq", "regular"),
+  ("smr", "regular"),
+  ("sms", "regular"),
+  ("smt", "regular"),
+  ("smu", "regular"),
+  ("smv", "regular"),
+  ("smw", "regular"),
+  ("smx", "regular"),
+  ("smy", "regular"),
+  ("smz", "regular"),
+  ("sn", "regular"),
+  ("snb", "deprecated"),
+  ("snc", "regular"),
+  ("sne", "regular"),
+  ("snf", "regular"),
+  ("sng", "regular"),
+  ("snh", "deprecated"),
+  ("sni", "regular"),
+  ("snj", "regular"),
+  ("snk", "regular"),
+  ("snl", "regular"),
+  ("snm", "regular"),
+  ("snn", "regular"),
+  ("sno", "regular"),
+  ("snp", "regular"),
+  ("snq", "regular"),
+  ("snr", "regular"),
+  ("sns", "regular"),
+  ("snu", "regular"),
+  ("snv", "regular"),
+  ("snw", "regular"),
+  ("snx", "regular"),
+  ("sny", "regular"),
+  ("snz", "regular"),
+  ("so", "regular"),
+  ("soa", "regular"),
+  ("sob", "regular"),
+  ("soc", "regular"),
+  ("sod", "regular"),
+  ("soe", "regular"),
+  ("sog", "regular"),
+  ("soh", "regular"),
+  ("soi", "regular"),
+  ("soj", "regular"),
+  ("sok", "regular"),
+  ("sol", "regular"),
+  ("son", "regular"),
+  ("soo", "regular"),
+  ("sop", "regular"),
+  ("soq", "regular"),
+  ("sor", "regular"),
+  ("sos", "regular"),
+  ("sou", "regular"),
+  ("sov", "regular"),
+  ("sow", "regular"),
+  ("sox", "regular"),
+  ("soy", "regular"),
+  ("soz", "regular"),
+  ("spb", "regular"),
+  ("spc", "regular"),
+  ("spd", "regular"),
+  ("spe", "regular"),
+  ("spg", "regular"),
+  ("spi", "regular"),
+  ("spk", "regular"),
+  ("spl", "regular"),
+  ("spm", "regular"),
+  ("spn", "regular"),
+  ("spo", "regular"),
+  ("spp", "regular"),
+  ("spq", "regular"),
+  ("spr", "regular"),
+  ("sps", "regular"),
+  ("spt", "regular"),
+  ("spu", "regular"),
+  ("spv", "regular"),
+  ("spx", "regular"),
+  ("spy", "deprecated"),
+  ("sq", "regular"),
+  ("sqa", "regular"),
+  ("sqh", "regular"),
+  ("sqj", "regular"),
+  ("sqk", "regular"),
+  ("sqm", "regular"),
+  ("sqn", "regular"),
+  ("sqo", "regular"),
+  ("sqq", "regular"),
+  ("sqr", "regular"),
+  ("sqs", "regular"),
+  ("sqt", "regular"),
+  ("squ", "regular"),
+  ("sqx", "regular"),
+  ("sr", "regular"),
+  ("sra", "regular"),
+  ("srb", "regular"),
+  ("src", "deprecated"),
+  ("sre", "regular"),
+  ("srf", "regular"),
+  ("srg", "regular"),
+  ("srh", "regular"),
+  ("sri", "regular"),
+  ("srk", "regular"),
+  ("srl", "regular"),
+  ("srm", "regular"),
+  ("srn", "regular"),
+  ("sro", "regular"),
+  ("srq", "regular"),
+  ("srr", "regular"),
+  ("srs", "regular"),
+  ("srt", "regular"),
+  ("sru", "regular"),
+  ("srv", "regular"),
+  ("srw", "regular"),
+  ("srx", "regular"),
+  ("sry", "regular"),
+  ("srz", "regular"),
+  ("ss", "regular"),
+  ("ssa", "regular"),
+  ("ssb", "regular"),
+  ("ssc", "regular"),
+  ("ssd", "regular"),
+  ("sse", "regular"),
+  ("ssf", "regular"),
+  ("ssg", "regular"),
+  ("ssh", "regular"),
+  ("ssi", "regular"),
+  ("ssj", "regular"),
+  ("ssk", "regular"),
+  ("ssl", "regular"),
+  ("ssm", "regular"),
+  ("ssn", "regular"),
+  ("sso", "regular"),
+  ("ssp", "regular"),
+  ("ssq", "regular"),
+  ("ssr", "regular"),
+  ("sss", "regular"),
+  ("sst", "regular"),
+  ("ssu", "regular"),
+  ("ssv", "regular"),
+  ("ssx", "regular"),
+  ("ssy", "regular"),
+  ("ssz", "regular"),
+  ("st", "regular"),
+  ("sta", "regular"),
+  ("stb", "regular"),
+  ("std", "regular"),
+  ("ste", "regular"),
+  ("stf", "regular"),
+  ("stg", "regular"),
+  ("sth", "regular"),
+  ("sti", "regular"),
+  ("stj", "regular"),
+  ("stk", "regular"),
+  ("stl", "regular"),
+  ("stm", "regular"),
+  ("stn", "regular"),
+  ("sto", "regular"),
+  ("stp", "regular"),
+  ("stq", "regular"),
+  ("str", "regular"),
+  ("sts", "regular"),
+  ("stt", "regular"),
+  ("stu", "regular"),
+  ("stv", "regular"),
+  ("stw", "regular"),
+  ("sty", "regular"),
+  ("su", "regular"),
+  ("sua", "regular"),
+  ("sub", "regular"),
+  ("suc", "regular"),
+  ("sue", "regular"),
+  ("sug", "regular"),
+  ("sui", "regular"),
+  ("suj", "regular"),
+  ("suk", "regular"),
+  ("sul", "deprecated"),
+  ("sum", "deprecated"),
+  ("suo", "regular"),
+  ("suq", "regular"),
+  ("sur", "regular"),
+  ("sus", "regular"),
+  ("sut", "regular"),
+  ("suv", "regular"),
+  ("suw", "regular"),
+  ("sux", "regular"),
+  ("suy", "regular"),
+  ("suz", "regular"),
+  ("sv", "regular"),
+  ("sva", "regular"),
+  ("svb", "regular"),
+  ("svc", "regular"),
+  ("sve", "regular"),
+  ("svk", "regular"),
+  ("svm", "regular"),
+  ("svr", "deprecated"),
+  ("svs", "regular"),
+  ("svx", "regular"),
+  ("sw", "regular"),
+  ("swb", "regular"),
+  ("swc", "deprecated"),
+  ("swf", "regular"),
+  ("swg", "regular"),
+  ("swh", "deprecated"),
+  ("swi", "regular"),
+  ("swj", "regular"),
+  ("swk", "regular"),
+  ("swl", "regular"),
+  ("swm", "regular"),
+  ("swn", "regular"),
+  ("swo", "regular"),
+  ("swp", "regular"),
+  ("swq", "regular"),
+  ("swr", "regular"),
+  ("sws", "regular"),
+  ("swt", "regular"),
+  ("swu", "regular"),
+  ("swv", "regular"),
+  ("sww", "regular"),
+  ("swx", "regular"),
+  ("swy", "regular"),
+  ("sxb", "regular"),
+  ("sxc", "regular"),
+  ("sxe", "regular"),
+  ("sxg", "regular"),
+  ("sxk", "regular"),
+  ("sxl", "regular"),
+  ("sxm", "regular"),
+  ("sxn", "regular"),
+  ("sxo", "regular"),
+  ("sxr", "regular"),
+  ("sxs", "regular"),
+  ("sxu", "regular"),
+  ("sxw", "regular"),
+  ("sya", "regular"),
+  ("syb", "regular"),
+  ("syc", "regular"),
+  ("syd", "regular"),
+  ("syi", "regular"),
+  ("syk", "regular"),
+  ("syl", "regular"),
+  ("sym", "regular"),
+  ("syn", "regular"),
+  ("syo", "regular"),
+  ("syr", "regular"),
+  ("sys", "regular"),
+  ("syw", "regular"),
+  ("syx", "regular"),
+  ("syy", "regular"),
+  ("sza", "regular"),
+  ("szb", "regular"),
+  ("szc", "regular"),
+  ("szd", "regular"),
+  ("sze", "regular"),
+  ("szg", "regular"),
+  ("szl", "regular"),
+  ("szn", "regular"),
+  ("szp", "regular"),
+  ("szs", "regular"),
+  ("szv", "regular"),
+  ("szw", "regular"),
+  ("szy", "regular"),
+  ("ta", "regular"),
+  ("taa", "regular"),
+  ("tab", "regular"),
+  ("tac", "regular"),
+  ("tad", "regular"),
+  ("tae", "regular"),
+  ("taf", "regular"),
+  ("tag", "regular"),
+  ("tai", "regular"),
+  ("taj", "regular"),
+  ("tak", "regular"),
+  ("tal", "regular"),
+  ("tan", "regular"),
+  ("tao", "regular"),
+  ("tap", "regular"),
+  ("taq", "regular"),
+  ("tar", "regular"),
+  ("tas", "regular"),
+  ("tau", "regular"),
+  ("tav", "regular"),
+  ("taw", "regular"),
+  ("tax", "regular"),
+  ("tay", "regular"),
+  ("taz", "regular"),
+  ("tba", "regular"),
+  ("tbb", "deprecated"),
+  ("tbc", "regular"),
+  ("tbd", "regular"),
+  ("tbe", "regular"),
+  ("tbf", "regular"),
+  ("tbg", "regular"),
+  ("tbh", "regular"),
+  ("tbi", "regular"),
+  ("tbj", "regular"),
+  ("tbk", "regular"),
+  ("tbl", "regular"),
+  ("tbm", "regular"),
+  ("tbn", "regular"),
+  ("tbo", "regular"),
+  ("tbp", "regular"),
+  ("tbq", "regular"),
+  ("tbr", "regular"),
+  ("tbs", "regular"),
+  ("tbt", "regular"),
+  ("tbu", "regular"),
+  ("tbv", "regular"),
+  ("tbw", "regular"),
+  ("tbx", "regular"),
+  ("tby", "regular"),
+  ("tbz", "regular"),
+  ("tca", "regular"),
+  ("tcb", "regular"),
+  ("tcc", "regular"),
+  ("tcd", "regular"),
+  ("tce", "regular"),
+  ("tcf", "regular"),
+  ("tcg", "regular"),
+  ("tch", "regular"),
+  ("tci", "regular"),
+  ("tck", "regular"),
+  ("tcl", "regular"),
+  ("tcm", "regular"),
+  ("tcn", "regular"),
+  ("tco", "regular"),
+  ("tcp", "regular"),
+  ("tcq", "regular"),
+  ("tcs", "regular"),
+  ("tct", "regular"),
+  ("tcu", "regular"),
+  ("tcw", "regular"),
+  ("tcx", "regular"),
+  ("tcy", "regular"),
+  ("tcz", "regular"),
+  ("tda", "regular"),
+  ("tdb", "regular"),
+  ("tdc", "regular"),
+  ("tdd", "regular"),
+  ("tde", "regular"),
+  ("tdf", "regular"),
+  ("tdg", "regular"),
+  ("tdh", "regular"),
+  ("tdi", "regular"),
+  ("tdj", "regular"),
+  ("tdk", "regular"),
+  ("tdl", "regular"),
+  ("tdm", "regular"),
+  ("tdn", "regular"),
+  ("tdo", "regular"),
+  ("tdq", "regular"),
+  ("tdr", "regular"),
+  ("tds", "regular"),
+  ("tdt", "regular"),
+  ("tdu", "deprecated"),
+  ("tdv", "regular"),
+  ("tdx", "regular"),
+  ("tdy", "regular"),
+  ("te", "regular"),
+  ("tea", "regular"),
+  ("teb", "regular"),
+  ("tec", "regular"),
+  ("ted", "regular"),
+  ("tee", "regular"),
+  ("tef", "regular"),
+  ("teg", "regular"),
+  ("teh", "regular"),
+  ("tei", "regular"),
+  ("tek", "regular"),
+  ("tem", "regular"),
+  ("ten", "regular"),
+  ("teo", "regular"),
+  ("tep", "regular"),
+  ("teq", "regular"),
+  ("ter", "regular"),
+  ("tes", "regular"),
+  ("tet", "regular"),
+  ("teu", "regular"),
+  ("tev", "regular"),
+  ("tew", "regular"),
+  ("tex", "regular"),
+  ("tey", "regular"),
+  ("tez", "regular"),
+  ("tfi", "regular"),
+  ("tfn", "regular"),
+  ("tfo", "regular"),
+  ("tfr", "regular"),
+  ("tft", "regular"),
+  ("tg", "regular"),
+  ("tga", "regular"),
+  ("tgb", "regular"),
+  ("tgc", "regular"),
+  ("tgd", "regular"),
+  ("tge", "regular"),
+  ("tgf", "regular"),
+  ("tgg", "deprecated"),
+  ("tgh", "regular"),
+  ("tgi", "regular"),
+  ("tgj", "regular"),
+  ("tgn", "regular"),
+  ("tgo", "regular"),
+  ("tgp", "regular"),
+  ("tgq", "regular"),
+  ("tgr", "regular"),
+  ("tgs", "regular"),
+  ("tgt", "regular"),
+  ("tgu", "regular"),
+  ("tgv", "regular"),
+  ("tgw", "regular"),
+  ("tgx", "regular"),
+  ("tgy", "regular"),
+  ("tgz", "regular"),
+  ("th", "regular"),
+  ("thc", "deprecated"),
+  ("thd", "regular"),
+  ("the", "regular"),
+  ("thf", "regular"),
+  ("thh", "regular"),
+  ("thi", "regular"),
+  ("thk", "regular"),
+  ("thl", "regular"),
+  ("thm", "regular"),
+  ("thn", "regular"),
+  ("thp", "regular"),
+  ("thq", "regular"),
+  ("thr", "regular"),
+  ("ths", "regular"),
+  ("tht", "regular"),
+  ("thu", "regular"),
+  ("thv", "regular"),
+  ("thw", "deprecated"),
+  ("thx", "deprecated"),
+  ("thy", "regular"),
+  ("thz", "regular"),
+  ("ti", "regular"),
+  ("tia", "regular"),
+  ("tic", "regular"),
+  ("tid", "deprecated"),
+  ("tie", "deprecated"),
+  ("tif", "regular"),
+  ("tig", "regular"),
+  ("tih", "regular"),
+  ("tii", "regular"),
+  ("tij", "regular"),
+  ("tik", "regular"),
+  ("til", "regular"),
+  ("tim", "regular"),
+  ("tin", "regular"),
+  ("tio", "regular"),
+  ("tip", "regular"),
+  ("tiq", "regular"),
+  ("tis", "regular"),
+  ("tit", "regular"),
+  ("tiu", "regular"),
+  ("tiv", "regular"),
+  ("tiw", "regular"),
+  ("tix", "regular"),
+  ("tiy", "regular"),
+  ("tiz", "regular"),
+  ("tja", "regular"),
+  ("tjg", "regular"),
+  ("tji", "regular"),
+  ("tjj", "regular"),
+  ("tjl", "regular"),
+  ("tjm", "regular"),
+  ("tjn", "regular"),
+  ("tjo", "regular"),
+  ("tjp", "regular"),
+  ("tjs", "regular"),
+  ("tju", "regular"),
+  ("tjw", "regular"),
+  ("tk", "regular"),
+  ("tka", "regular"),
+  ("tkb", "regular"),
+  ("tkd", "regular"),
+  ("tke", "regular"),
+  ("tkf", "regular"),
+  ("tkg", "regular"),
+  ("tkk", "deprecated"),
+  ("tkl", "regular"),
+  ("tkm", "regular"),
+  ("tkn", "regular"),
+  ("tkp", "regular"),
+  ("tkq", "regular"),
+  ("tkr", "regular"),
+  ("tks", "regular"),
+  ("tkt", "regular"),
+  ("tku", "regular"),
+  ("tkv", "regular"),
+  ("tkw", "regular"),
+  ("tkx", "regular"),
+  ("tkz", "regular"),
+  ("tl", "deprecated"),
+  ("tla", "regular"),
+  ("tlb", "regular"),
+  ("tlc", "regular"),
+  ("tld", "regular"),
+  ("tlf", "regular"),
+  ("tlg", "regular"),
+  ("tlh", "regular"),
+  ("tli", "regular"),
+  ("tlj", "regular"),
+  ("tlk", "regular"),
+  ("tll", "regular"),
+  ("tlm", "regular"),
+  ("tln", "regular"),
+  ("tlo", "regular"),
+  ("tlp", "regular"),
+  ("tlq", "regular"),
+  ("tlr", "regular"),
+  ("tls", "regular"),
+  ("tlt", "regular"),
+  ("tlu", "regular"),
+  ("tlv", "regular"),
+  ("tlw", "deprecated"),
+  ("tlx", "regular"),
+  ("tly", "regular"),
+  ("tma", "regular"),
+  ("tmb", "regular"),
+  ("tmc", "regular"),
+  ("tmd", "regular"),
+  ("tme", "regular"),
+  ("tmf", "regular"),
+  ("tmg", "regular"),
+  ("tmh", "regular"),
+  ("tmi", "regular"),
+  ("tmj", "regular"),
+  ("tmk", "regular"),
+  ("tml", "regular"),
+  ("tmm", "regular"),
+  ("tmn", "regular"),
+  ("tmo", "regular"),
+  ("tmp", "deprecated"),
+  ("tmq", "regular"),
+  ("tmr", "regular"),
+  ("tms", "regular"),
+  ("tmt", "regular"),
+  ("tmu", "regular"),
+  ("tmv", "regular"),
+  ("tmw", "regular"),
+  ("tmy", "regular"),
+  ("tmz", "regular"),
+  ("tn", "regular"),
+  ("tna", "regular"),
+  ("tnb", "regular"),
+  ("tnc", "regular"),
+  ("tnd", "regular"),
+  ("tne", "deprecated"),
+  ("tnf", "deprecated"),
+  ("tng", "regular"),
+  ("tnh", "regular"),
+  ("tni", "regular"),
+  ("tnk", "regular"),
+  ("tnl", "regular"),
+  ("tnm", "regular"),
+  ("tnn", "regular"),
+  ("tno", "regular"),
+  ("tnp", "regular"),
+  ("tnq", "regular"),
+  ("tnr", "regular"),
+  ("tns", "regular"),
+  ("tnt", "regular"),
+  ("tnu", "regular"),
+  ("tnv", "regular"),
+  ("tnw", "regular"),
+  ("tnx", "regular"),
+  ("tny", "regular"),
+  ("tnz", "regular"),
+  ("to", "regular"),
+  ("tob", "regular"),
+  ("toc", "regular"),
+  ("tod", "regular"),
+  ("toe", "deprecated"),
+  ("tof", "regular"),
+  ("tog", "regular"),
+  ("toh", "regular"),
+  ("toi", "regular"),
+  ("toj", "regular"),
+  ("tok", "regular"),
+  ("tol", "regular"),
+  ("tom", "regular"),
+  ("too", "regular"),
+  ("top", "regular"),
+  ("toq", "regular"),
+  ("tor", "regular"),
+  ("tos", "regular"),
+  ("tou", "regular"),
+  ("tov", "regular"),
+  ("tow", "regular"),
+  ("tox", "regular"),
+  ("toy", "regular"),
+  ("toz", "regular"),
+  ("tpa", "regular"),
+  ("tpc", "regular"),
+  ("tpe", "regular"),
+  ("tpf", "regular"),
+  ("tpg", "regular"),
+  ("tpi", "regular"),
+  ("tpj", "regular"),
+  ("tpk", "regular"),
+  ("tpl", "regular"),
+  ("tpm", "regular"),
+  ("tpn", "regular"),
+  ("tpo", "regular"),
+  ("tpp", "regular"),
+  ("tpq", "regular"),
+  ("tpr", "regular"),
+  ("tpt", "regular"),
+  ("tpu", "regular"),
+  ("tpv", "regular"),
+  ("tpw", "regular"),
+  ("tpx", "regular"),
+  ("tpy", "regular"),
+  ("tpz", "regular"),
+  ("tqb", "regular"),
+  ("tql", "regular"),
+  ("tqm", "regular"),
+  ("tqn", "regular"),
+  ("tqo", "regular"),
+  ("tqp", "regular"),
+  ("tqq", "regular"),
+  ("tqr", "regular"),
+  ("tqt", "regular"),
+  ("tqu", "regular"),
+  ("tqw", "regular"),
+  ("tr", "regular"),
+  ("tra", "regular"),
+  ("trb", "regular"),
+  ("trc", "regular"),
+  ("trd", "regular"),
+  ("tre", "regular"),
+  ("trf", "regular"),
+  ("trg", "regular"),
+  ("trh", "regular"),
+  ("tri", "regular"),
+  ("trj", "regular"),
+  ("trk", "regular"),
+  ("trl", "regular"),
+  ("trm", "regular"),
+  ("trn", "regular"),
+  ("tro", "regular"),
+  ("trp", "regular"),
+  ("trq", "regular"),
+  ("trr", "regular"),
+  ("trs", "regular"),
+  ("trt", "regular"),
+  ("tru", "regular"),
+  ("trv", "regular"),
+  ("trw", "regular"),
+  ("trx", "regular"),
+  ("try", "regular"),
+  ("trz", "regular"),
+  ("ts", "regular"),
+  ("tsa", "regular"),
+  ("tsb", "regular"),
+  ("tsc", "regular"),
+  ("tsd", "regular"),
+  ("tse", "regular"),
+  ("tsf", "deprecated"),
+  ("tsg", "regular"),
+  ("tsh", "regular"),
+  ("tsi", "regular"),
+  ("tsj", "regular"),
+  ("tsk", "regular"),
+  ("tsl", "regular"),
+  ("tsm", "regular"),
+  ("tsp", "regular"),
+  ("tsq", "regular"),
+  ("tsr", "regular"),
+  ("tss", "regular"),
+  ("tst", "regular"),
+  ("tsu", "regular"),
+  ("tsv", "regular"),
+  ("tsw", "regular"),
+  ("tsx", "regular"),
+  ("tsy", "regular"),
+  ("tsz", "regular"),
+  ("tt", "regular"),
+  ("tta", "regular"),
+  ("ttb", "regular"),
+  ("ttc", "regular"),
+  ("ttd", "regular"),
+  ("tte", "regular"),
+  ("ttf", "regular"),
+  ("ttg", "regular"),
+  ("tth", "regular"),
+  ("tti", "regular"),
+  ("ttj", "regular"),
+  ("ttk", "regular"),
+  ("ttl", "regular"),
+  ("ttm", "regular"),
+  ("ttn", "regular"),
+  ("tto", "regular"),
+  ("ttp", "regular"),
+  ("ttq", "deprecated"),
+  ("ttr", "regular"),
+  ("tts", "regular"),
+  ("ttt", "regular"),
+  ("ttu", "regular"),
+  ("ttv", "regular"),
+  ("ttw", "regular"),
+  ("tty", "regular"),
+  ("ttz", "regular"),
+  ("tua", "regular"),
+  ("tub", "regular"),
+  ("tuc", "regular"),
+  ("tud", "regular"),
+  ("tue", "regular"),
+  ("tuf", "regular"),
+  ("tug", "regular"),
+  ("tuh", "regular"),
+  ("tui", "regular"),
+  ("tuj", "regular"),
+  ("tul", "regular"),
+  ("tum", "regular"),
+  ("tun", "regular"),
+  ("tuo", "regular"),
+  ("tup", "regular"),
+  ("tuq", "regular"),
+  ("tus", "regular"),
+  ("tut", "regular"),
+  ("tuu", "regular"),
+  ("tuv", "regular"),
+  ("tuw", "regular"),
+  ("tux", "regular"),
+  ("tuy", "regular"),
+  ("tuz", "regular"),
+  ("tva", "regular"),
+  ("tvd", "regular"),
+  ("tve", "regular"),
+  ("tvk", "regular"),
+  ("tvl", "regular"),
+  ("tvm", "regular"),
+  ("tvn", "regular"),
+  ("tvo", "regular"),
+  ("tvs", "regular"),
+  ("tvt", "regular"),
+  ("tvu", "regular"),
+  ("tvw", "regular"),
+  ("tvx", "regular"),
+  ("tvy", "regular"),
+  ("tw", "deprecated"),
+  ("twa", "regular"),
+  ("twb", "regular"),
+  ("twc", "regular"),
+  ("twd", "regular"),
+  ("twe", "regular"),
+  ("twf", "regular"),
+  ("twg", "regular"),
+  ("twh", "regular"),
+  ("twl", "regular"),
+  ("twm", "regular"),
+  ("twn", "regular"),
+  ("two", "regular"),
+  ("twp", "regular"),
+  ("twq", "regular"),
+  ("twr", "regular"),
+  ("twt", "regular"),
+  ("twu", "regular"),
+  ("tww", "regular"),
+  ("twx", "regular"),
+  ("twy", "regular"),
+  ("txa", "regular"),
+  ("txb", "regular"),
+  ("txc", "regular"),
+  ("txe", "regular"),
+  ("txg", "regular"),
+  ("txh", "regular"),
+  ("txi", "regular"),
+  ("txj", "regular"),
+  ("txm", "regular"),
+  ("txn", "regular"),
+  ("txo", "regular"),
+  ("txq", "regular"),
+  ("txr", "regular"),
+  ("txs", "regular"),
+  ("txt", "regular"),
+  ("txu", "regular"),
+  ("txx", "regular"),
+  ("txy", "regular"),
+  ("ty", "regular"),
+  ("tya", "regular"),
+  ("tye", "regular"),
+  ("tyh", "regular"),
+  ("tyi", "regular"),
+  ("tyj", "regular"),
+  ("tyl", "regular"),
+  ("tyn", "regular"),
+  ("typ", "regular"),
+  ("tyr", "regular"),
+  ("tys", "regular"),
+  ("tyt", "regular"),
+  ("tyu", "regular"),
+  ("tyv", "regular"),
+  ("tyx", "regular"),
+  ("tyy", "regular"),
+  ("tyz", "regular"),
+  ("tza", "regular"),
+  ("tzh", "regular"),
+  ("tzj", "regular"),
+  ("tzl", "regular"),
+  ("tzm", "regular"),
+  ("tzn", "regular"),
+  ("tzo", "regular"),
+  ("tzx", "regular"),
+  ("uam", "regular"),
+  ("uan", "regular"),
+  ("uar", "regular"),
+  ("uba", "regular"),
+  ("ubi", "regular"),
+  ("ubl", "regular"),
+  ("ubr", "regular"),
+  ("ubu", "regular"),
+  ("uby", "regular"),
+  ("uda", "regular"),
+  ("ude", "regular"),
+  ("udg", "regular"),
+  ("udi", "regular"),
+  ("udj", "regular"),
+  ("udl", "regular"),
+  ("udm", "regular"),
+  ("udu", "regular"),
+  ("ues", "regular"),
+  ("ufi", "regular"),
+  ("ug", "regular"),
+  ("uga", "regular"),
+  ("ugb", "regular"),
+  ("uge", "regular"),
+  ("ugh", "regular"),
+  ("ugn", "regular"),
+  ("ugo", "regular"),
+  ("ugy", "regular"),
+  ("uha", "regular"),
+  ("uhn", "regular"),
+  ("uis", "regular"),
+  ("uiv", "regular"),
+  ("uji", "regular"),
+  ("uk", "regular"),
+  ("uka", "regular"),
+  ("ukg", "regular"),
+  ("ukh", "regular"),
+  ("uki", "regular"),
+  ("ukk", "regular"),
+  ("ukl", "regular"),
+  ("ukp", "regular"),
+  ("ukq", "regular"),
+  ("uks", "regular"),
+  ("uku", "regular"),
+  ("ukv", "regular"),
+  ("ukw", "regular"),
+  ("uky", "regular"),
+  ("ula", "regular"),
+  ("ulb", "regular"),
+  ("ulc", "regular"),
+  ("ule", "regular"),
+  ("ulf", "regular"),
+  ("uli", "regular"),
+  ("ulk", "regular"),
+  ("ull", "regular"),
+  ("ulm", "regular"),
+  ("uln", "regular"),
+  ("ulu", "regular"),
+  ("ulw", "regular"),
+  ("uma", "regular"),
+  ("umb", "regular"),
+  ("umc", "regular"),
+  ("umd", "regular"),
+  ("umg", "regular"),
+  ("umi", "regular"),
+  ("umm", "regular"),
+  ("umn", "regular"),
+  ("umo", "regular"),
+  ("ump", "regular"),
+  ("umr", "regular"),
+  ("ums", "regular"),
+  ("umu", "deprecated"),
+  ("una", "regular"),
+  ("und", "unknown"),
+  ("une", "regular"),
+  ("ung", "regular"),
+  ("uni", "regular"),
+  ("unk", "regular"),
+  ("unm", "regular"),
+  ("unn", "regular"),
+  ("unp", "deprecated"),
+  ("unr", "regular"),
+  ("unu", "regular"),
+  ("unx", "regular"),
+  ("unz", "regular"),
+  ("uok", "deprecated"),
+  ("uon", "regular"),
+  ("upi", "regular"),
+  ("upv", "regular"),
+  ("ur", "regular"),
+  ("ura", "regular"),
+  ("urb", "regular"),
+  ("urc", "regular"),
+  ("ure", "regular"),
+  ("urf", "regular"),
+  ("urg", "regular"),
+  ("urh", "regular"),
+  ("uri", "regular"),
+  ("urj", "regular"),
+  ("urk", "regular"),
+  ("url", "regular"),
+  ("urm", "regular"),
+  ("urn", "regular"),
+  ("uro", "regular"),
+  ("urp", "regular"),
+  ("urr", "regular"),
+  ("urt", "regular"),
+  ("uru", "regular"),
+  ("urv", "regular"),
+  ("urw", "regular"),
+  ("urx", "regular"),
+  ("ury", "regular"),
+  ("urz", "regular"),
+  ("usa", "regular"),
+  ("ush", "regular"),
+  ("usi", "regular"),
+  ("usk", "regular"),
+  ("usp", "regular"),
+  ("uss", "regular"),
+  ("usu", "regular"),
+  ("uta", "regular"),
+  ("ute", "regular"),
+  ("uth", "regular"),
+  ("utp", "regular"),
+  ("utr", "regular"),
+  ("utu", "regular"),
+  ("uum", "regular"),
+  ("uun", "deprecated"),
+  ("uur", "regular"),
+  ("uuu", "regular"),
+  ("uve", "regular"),
+  ("uvh", "regular"),
+  ("uvl", "regular"),
+  ("uwa", "regular"),
+  ("uya", "regular"),
+  ("uz", "regular"),
+  ("uzn", "deprecated"),
+  ("uzs", "regular"),
+  ("vaa", "regular"),
+  ("vae", "regular"),
+  ("vaf", "regular"),
+  ("vag", "regular"),
+  ("vah", "regular"),
+  ("vai", "regular"),
+  ("vaj", "regular"),
+  ("val", "regular"),
+  ("vam", "regular"),
+  ("van", "regular"),
+  ("vao", "regular"),
+  ("vap", "regular"),
+  ("var", "regular"),
+  ("vas", "regular"),
+  ("vau", "regular"),
+  ("vav", "regular"),
+  ("vay", "regular"),
+  ("vbb", "regular"),
+  ("vbk", "regular"),
+  ("ve", "regular"),
+  ("vec", "regular"),
+  ("ved", "regular"),
+  ("vel", "regular"),
+  ("vem", "regular"),
+  ("veo", "regular"),
+  ("vep", "regular"),
+  ("ver", "regular"),
+  ("vgr", "regular"),
+  ("vgt", "regular"),
+  ("vi", "regular"),
+  ("vic", "regular"),
+  ("vid", "regular"),
+  ("vif", "regular"),
+  ("vig", "regular"),
+  ("vil", "regular"),
+  ("vin", "regular"),
+  ("vis", "regular"),
+  ("vit", "regular"),
+  ("viv", "regular"),
+  ("vka", "regular"),
+  ("vki", "deprecated"),
+  ("vkj", "regular"),
+  ("vkk", "regular"),
+  ("vkl", "regular"),
+  ("vkm", "regular"),
+  ("vkn", "regular"),
+  ("vko", "regular"),
+  ("vkp", "regular"),
+  ("vkt", "regular"),
+  ("vku", "regular"),
+  ("vkz", "regular"),
+  ("vlp", "regular"),
+  ("vls", "regular"),
+  ("vma", "regular"),
+  ("vmb", "regular"),
+  ("vmc", "regular"),
+  ("vmd", "regular"),
+  ("vme", "regular"),
+  ("vmf", "regular"),
+  ("vmg", "regular"),
+  ("vmh", "regular"),
+  ("vmi", "regular"),
+  ("vmj", "regular"),
+  ("vmk", "regular"),
+  ("vml", "regular"),
+  ("vmm", "regular"),
+  ("vmp", "regular"),
+  ("vmq", "regular"),
+  ("vmr", "regular"),
+  ("vms", "regular"),
+  ("vmu", "regular"),
+  ("vmv", "regular"),
+  ("vmw", "regular"),
+  ("vmx", "regular"),
+  ("vmy", "regular"),
+  ("vmz", "regular"),
+  ("vnk", "regular"),
+  ("vnm", "regular"),
+  ("vnp", "regular"),
+  ("vo", "regular"),
+  ("vor", "regular"),
+  ("vot", "regular"),
+  ("vra", "regular"),
+  ("vro", "regular"),
+  ("vrs", "regular"),
+  ("vrt", "regular"),
+  ("vsi", "regular"),
+  ("vsl", "regular"),
+  ("vsv", "regular"),
+  ("vto", "regular"),
+  ("vum", "regular"),
+  ("vun", "regular"),
+  ("vut", "regular"),
+  ("vwa", "regular"),
+  ("wa", "regular"),
+  ("waa", "regular"),
+  ("wab", "regular"),
+  ("wac", "regular"),
+  ("wad", "regular"),
+  ("wae", "regular"),
+  ("waf", "regular"),
+  ("wag", "regular"),
+  ("wah", "regular"),
+  ("wai", "regular"),
+  ("waj", "regular"),
+  ("wak", "regular"),
+  ("wal", "regular"),
+  ("wam", "regular"),
+  ("wan", "regular"),
+  ("wao", "regular"),
+  ("wap", "regular"),
+  ("waq", "regular"),
+  ("war", "regular"),
+  ("was", "regular"),
+  ("wat", "regular"),
+  ("wau", "regular"),
+  ("wav", "regular"),
+  ("waw", "regular"),
+  ("wax", "regular"),
+  ("way", "regular"),
+  ("waz", "regular"),
+  ("wba", "regular"),
+  ("wbb", "regular"),
+  ("wbe", "regular"),
+  ("wbf", "regular"),
+  ("wbh", "regular"),
+  ("wbi", "regular"),
+  ("wbj", "regular"),
+  ("wbk", "regular"),
+  ("wbl", "regular"),
+  ("wbm", "regular"),
+  ("wbp", "regular"),
+  ("wbq", "regular"),
+  ("wbr", "regular"),
+  ("wbs", "regular"),
+  ("wbt", "regular"),
+  ("wbv", "regular"),
+  ("wbw", "regular"),
+  ("wca", "regular"),
+  ("wci", "regular"),
+  ("wdd", "regular"),
+  ("wdg", "regular"),
+  ("wdj", "regular"),
+  ("wdk", "regular"),
+  ("wdt", "regular"),
+  ("wdu", "regular"),
+  ("wdy", "regular"),
+  ("wea", "regular"),
+  ("wec", "regular"),
+  ("wed", "regular"),
+  ("weg", "regular"),
+  ("weh", "regular"),
+  ("wei", "regular"),
+  ("wem", "regular"),
+  ("wen", "regular"),
+  ("weo", "regular"),
+  ("wep", "regular"),
+  ("wer", "regular"),
+  ("wes", "regular"),
+  ("wet", "regular"),
+  ("weu", "regular"),
+  ("wew", "regular"),
+  ("wfg", "regular"),
+  ("wga", "regular"),
+  ("wgb", "regular"),
+  ("wgg", "regular"),
+  ("wgi", "regular"),
+  ("wgo", "regular"),
+  ("wgu", "regular"),
+  ("wgw", "deprecated"),
+  ("wgy", "regular"),
+  ("wha", "regular"),
+  ("whg", "regular"),
+  ("whk", "regular"),
+  ("whu", "regular"),
+  ("wib", "regular"),
+  ("wic", "regular"),
+  ("wie", "regular"),
+  ("wif", "regular"),
+  ("wig", "regular"),
+  ("wih", "regular"),
+  ("wii", "regular"),
+  ("wij", "regular"),
+  ("wik", "regular"),
+  ("wil", "regular"),
+  ("wim", "regular"),
+  ("win", "regular"),
+  ("wir", "regular"),
+  ("wit", "deprecated"),
+  ("wiu", "regular"),
+  ("wiv", "regular"),
+  ("wiw", "deprecated"),
+  ("wiy", "regular"),
+  ("wja", "regular"),
+  ("wji", "regular"),
+  ("wka", "regular"),
+  ("wkb", "regular"),
+  ("wkd", "regular"),
+  ("wkl", "regular"),
+  ("wkr", "regular"),
+  ("wku", "regular"),
+  ("wkw", "regular"),
+  ("wky", "regular"),
+  ("wla", "regular"),
+  ("wlc", "regular"),
+  ("wle", "regular"),
+  ("wlg", "regular"),
+  ("wlh", "regular"),
+  ("wli", "regular"),
+  ("wlk", "regular"),
+  ("wll", "regular"),
+  ("wlm", "regular"),
+  ("wlo", "regular"),
+  ("wlr", "regular"),
+  ("wls", "regular"),
+  ("wlu", "regular"),
+  ("wlv", "regular"),
+  ("wlw", "regular"),
+  ("wlx", "regular"),
+  ("wly", "regular"),
+  ("wma", "regular"),
+  ("wmb", "regular"),
+  ("wmc", "regular"),
+  ("wmd", "regular"),
+  ("wme", "regular"),
+  ("wmg", "regular"),
+  ("wmh", "regular"),
+  ("wmi", "regular"),
+  ("wmm", "regular"),
+  ("wmn", "regular"),
+  ("wmo", "regular"),
+  ("wms", "regular"),
+  ("wmt", "regular"),
+  ("wmw", "regular"),
+  ("wmx", "regular"),
+  ("wnb", "regular"),
+  ("wnc", "regular"),
+  ("wnd", "regular"),
+  ("wne", "regular"),
+  ("wng", "regular"),
+  ("wni", "regular"),
+  ("wnk", "regular"),
+  ("wnm", "regular"),
+  ("wnn", "regular"),
+  ("wno", "regular"),
+  ("wnp", "regular"),
+  ("wnu", "regular"),
+  ("wnw", "regular"),
+  ("wny", "regular"),
+  ("wo", "regular"),
+  ("woa", "regular"),
+  ("wob", "regular"),
+  ("woc", "regular"),
+  ("wod", "regular"),
+  ("woe", "regular"),
+  ("wof", "regular"),
+  ("wog", "regular"),
+  ("woi", "regular"),
+  ("wok", "regular"),
+  ("wom", "regular"),
+  ("won", "regular"),
+  ("woo", "regular"),
+  ("wor", "regular"),
+  ("wos", "regular"),
+  ("wow", "regular"),
+  ("woy", "regular"),
+  ("wpc", "regular"),
+  ("wra", "deprecated"),
+  ("wrb", "regular"),
+  ("wrd", "deprecated"),
+  ("wrg", "regular"),
+  ("wrh", "regular"),
+  ("wri", "regular"),
+  ("wrk", "regular"),
+  ("wrl", "regular"),
+  ("wrm", "regular"),
+  ("wrn", "regular"),
+  ("wro", "regular"),
+  ("wrp", "regular"),
+  ("wrr", "regular"),
+  ("wrs", "regular"),
+  ("wru", "regular"),
+  ("wrv", "regular"),
+  ("wrw", "regular"),
+  ("wrx", "regular"),
+  ("wry", "regular"),
+  ("wrz", "regular"),
+  ("wsa", "regular"),
+  ("wsg", "regular"),
+  ("wsi", "regular"),
+  ("wsk", "regular"),
+  ("wsr", "regular"),
+  ("wss", "regular"),
+  ("wsu", "regular"),
+  ("wsv", "regular"),
+  ("wtf", "regular"),
+  ("wth", "regular"),
+  ("wti", "regular"),
+  ("wtk", "regular"),
+  ("wtm", "regular"),
+  ("wtw", "regular"),
+  ("wua", "regular"),
+  ("wub", "regular"),
+  ("wud", "regular"),
+  ("wuh", "regular"),
+  ("wul", "regular"),
+  ("wum", "regular"),
+  ("wun", "regular"),
+  ("wur", "regular"),
+  ("wut", "regular"),
+  ("wuu", "regular"),
+  ("wuv", "regular"),
+  ("wux", "regular"),
+  ("wuy", "regular"),
+  ("wwa", "regular"),
+  ("wwb", "regular"),
+  ("wwo", "regular"),
+  ("wwr", "regular"),
+  ("www", "regular"),
+  ("wxa", "regular"),
+  ("wxw", "regular"),
+  ("wya", "deprecated"),
+  ("wyb", "regular"),
+  ("wyi", "regular"),
+  ("wym", "regular"),
+  ("wyn", "regular"),
+  ("wyr", "regular"),
+  ("wyy", "regular"),
+  ("xaa", "regular"),
+  ("xab", "regular"),
+  ("xac", "regular"),
+  ("xad", "regular"),
+  ("xae", "regular"),
+  ("xag", "regular"),
+  ("xai", "regular"),
+  ("xaj", "regular"),
+  ("xak", "regular"),
+  ("xal", "regular"),
+  ("xam", "regular"),
+  ("xan", "regular"),
+  ("xao", "regular"),
+  ("xap", "regular"),
+  ("xaq", "regular"),
+  ("xar", "regular"),
+  ("xas", "regular"),
+  ("xat", "regular"),
+  ("xau", "regular"),
+  ("xav", "regular"),
+  ("xaw", "regular"),
+  ("xay", "regular"),
+  ("xba", "deprecated"),
+  ("xbb", "regular"),
+  ("xbc", "regular"),
+  ("xbd", "regular"),
+  ("xbe", "regular"),
+  ("xbg", "regular"),
+  ("xbi", "regular"),
+  ("xbj", "regular"),
+  ("xbm", "regular"),
+  ("xbn", "regular"),
+  ("xbo", "regular"),
+  ("xbp", "regular"),
+  ("xbr", "regular"),
+  ("xbw", "regular"),
+  ("xbx", "deprecated"),
+  ("xby", "regular"),
+  ("xcb", "regular"),
+  ("xcc", "regular"),
+  ("xce", "regular"),
+  ("xcg", "regular"),
+  ("xch", "regular"),
+  ("xcl", "regular"),
+  ("xcm", "regular"),
+  ("xcn", "regular"),
+  ("xco", "regular"),
+  ("xcr", "regular"),
+  ("xct", "regular"),
+  ("xcu", "regular"),
+  ("xcv", "regular"),
+  ("xcw", "regular"),
+  ("xcy", "regular"),
+  ("xda", "regular"),
+  ("xdc", "regular"),
+  ("xdk", "regular"),
+  ("xdm", "regular"),
+  ("xdo", "regular"),
+  ("xdq", "regular"),
+  ("xdy", "regular"),
+  ("xeb", "regular"),
+  ("xed", "regular"),
+  ("xeg", "regular"),
+  ("xel", "regular"),
+  ("xem", "regular"),
+  ("xep", "regular"),
+  ("xer", "regular"),
+  ("xes", "regular"),
+  ("xet", "regular"),
+  ("xeu", "regular"),
+  ("xfa", "regular"),
+  ("xga", "regular"),
+  ("xgb", "regular"),
+  ("xgd", "regular"),
+  ("xgf", "regular"),
+  ("xgg", "regular"),
+  ("xgi", "regular"),
+  ("xgl", "regular"),
+  ("xgm", "regular"),
+  ("xgn", "regular"),
+  ("xgr", "regular"),
+  ("xgu", "regular"),
+  ("xgw", "regular"),
+  ("xh", "regular"),
+  ("xha", "regular"),
+  ("xhc", "regular"),
+  ("xhd", "regular"),
+  ("xhe", "regular"),
+  ("xhm", "regular"),
+  ("xhr", "regular"),
+  ("xht", "regular"),
+  ("xhu", "regular"),
+  ("xhv", "regular"),
+  ("xia", "deprecated"),
+  ("xib", "regular"),
+  ("xii", "regular"),
+  ("xil", "regular"),
+  ("xin", "regular"),
+  ("xip", "deprecated"),
+  ("xir", "regular"),
+  ("xis", "regular"),
+  ("xiv", "regular"),
+  ("xiy", "regular"),
+  ("xjb", "regular"),
+  ("xjt", "regular"),
+  ("xka", "regular"),
+  ("xkb", "regular"),
+  ("xkc", "regular"),
+  ("xkd", "regular"),
+  ("xke", "regular"),
+  ("xkf", "regular"),
+  ("xkg", "regular"),
+  ("xkh", "deprecated"),
+  ("xki", "regular"),
+  ("xkj", "regular"),
+  ("xkk", "regular"),
+  ("xkl", "regular"),
+  ("xkn", "regular"),
+  ("xko", "regular"),
+  ("xkp", "regular"),
+  ("xkq", "regular"),
+  ("xkr", "regular"),
+  ("xks", "regular"),
+  ("xkt", "regular"),
+  ("xku", "regular"),
+  ("xkv", "regular"),
+  ("xkw", "regular"),
+  ("xkx", "regular"),
+  ("xky", "regular"),
+  ("xkz", "regular"),
+  ("xla", "regular"),
+  ("xlb", "regular"),
+  ("xlc", "regular"),
+  ("xld", "regular"),
+  ("xle", "regular"),
+  ("xlg", "regular"),
+  ("xli", "regular"),
+  ("xln", "regular"),
+  ("xlo", "regular"),
+  ("xlp", "regular"),
+  ("xls", "regular"),
+  ("xlu", "regular"),
+  ("xly", "regular"),
+  ("xma", "regular"),
+  ("xmb", "regular"),
+  ("xmc", "regular"),
+  ("xmd", "regular"),
+  ("xme", "regular"),
+  ("xmf", "regular"),
+  ("xmg", "regular"),
+  ("xmh", "regular"),
+  ("xmj", "regular"),
+  ("xmk", "regular"),
+  ("xml", "regular"),
+  ("xmm", "regular"),
+  ("xmn", "regular"),
+  ("xmo", "regular"),
+  ("xmp", "regular"),
+  ("xmq", "regular"),
+  ("xmr", "regular"),
+  ("xms", "regular"),
+  ("xmt", "regular"),
+  ("xmu", "regular"),
+  ("xmv", "regular"),
+  ("xmw", "regular"),
+  ("xmx", "regular"),
+  ("xmy", "regular"),
+  ("xmz", "regular"),
+  ("xna", "regular"),
+  ("xnb", "regular"),
+  ("xnd", "regular"),
+  ("xng", "regular"),
+  ("xnh", "regular"),
+  ("xni", "regular"),
+  ("xnj", "regular"),
+  ("xnk", "regular"),
+  ("xnm", "regular"),
+  ("xnn", "regular"),
+  ("xno", "regular"),
+  ("xnq", "regular"),
+  ("xnr", "regular"),
+  ("xns", "regular"),
+  ("xnt", "regular"),
+  ("xnu", "regular"),
+  ("xny", "regular"),
+  ("xnz", "regular"),
+  ("xoc", "regular"),
+  ("xod", "regular"),
+  ("xog", "regular"),
+  ("xoi", "regular"),
+  ("xok", "regular"),
+  ("xom", "regular"),
+  ("xon", "regular"),
+  ("xoo", "regular"),
+  ("xop", "regular"),
+  ("xor", "regular"),
+  ("xow", "regular"),
+  ("xpa", "regular"),
+  ("xpb", "regular"),
+  ("xpc", "regular"),
+  ("xpd", "regular"),
+  ("xpe", "deprecated"),
+  ("xpf", "regular"),
+  ("xpg", "regular"),
+  ("xph", "regular"),
+  ("xpi", "regular"),
+  ("xpj", "regular"),
+  ("xpk", "regular"),
+  ("xpl", "regular"),
+  ("xpm", "regular"),
+  ("xpn", "regular"),
+  ("xpo", "regular"),
+  ("xpp", "regular"),
+  ("xpq", "regular"),
+  ("xpr", "regular"),
+  ("xps", "regular"),
+  ("xpt", "regular"),
+  ("xpu", "regular"),
+  ("xpv", "regular"),
+  ("xpw", "regular"),
+  ("xpx", "regular"),
+  ("xpy", "regular"),
+  ("xpz", "regular"),
+  ("xqa", "regular"),
+  ("xqt", "regular"),
+  ("xra", "regular"),
+  ("xrb", "regular"),
+  ("xrd", "regular"),
+  ("xre", "regular"),
+  ("xrg", "regular"),
+  ("xri", "regular"),
+  ("xrm", "regular"),
+  ("xrn", "regular"),
+  ("xrq", "deprecated"),
+  ("xrr", "regular"),
+  ("xrt", "regular"),
+  ("xru", "regular"),
+  ("xrw", "regular"),
+  ("xsa", "regular"),
+  ("xsb", "regular"),
+  ("xsc", "regular"),
+  ("xsd", "regular"),
+  ("xse", "regular"),
+  ("xsh", "regular"),
+  ("xsi", "regular"),
+  ("xsj", "deprecated"),
+  ("xsl", "deprecated"),
+  ("xsm", "regular"),
+  ("xsn", "regular"),
+  ("xso", "regular"),
+  ("xsp", "regular"),
+  ("xsq", "regular"),
+  ("xsr", "regular"),
+  ("xss", "regular"),
+  ("xsu", "regular"),
+  ("xsv", "regular"),
+  ("xsy", "regular"),
+  ("xta", "regular"),
+  ("xtb", "regular"),
+  ("xtc", "regular"),
+  ("xtd", "regular"),
+  ("xte", "regular"),
+  ("xtg", "regular"),
+  ("xth", "regular"),
+  ("xti", "regular"),
+  ("xtj", "regular"),
+  ("xtl", "regular"),
+  ("xtm", "regular"),
+  ("xtn", "regular"),
+  ("xto", "regular"),
+  ("xtp", "regular"),
+  ("xtq", "regular"),
+  ("xtr", "regular"),
+  ("xts", "regular"),
+  ("xtt", "regular"),
+  ("xtu", "regular"),
+  ("xtv", "regular"),
+  ("xtw", "regular"),
+  ("xty", "regular"),
+  ("xtz", "deprecated"),
+  ("xua", "regular"),
+  ("xub", "regular"),
+  ("xud", "regular"),
+  ("xug", "regular"),
+  ("xuj", "regular"),
+  ("xul", "regular"),
+  ("xum", "regular"),
+  ("xun", "regular"),
+  ("xuo", "regular"),
+  ("xup", "regular"),
+  ("xur", "regular"),
+  ("xut", "regular"),
+  ("xuu", "regular"),
+  ("xve", "regular"),
+  ("xvi", "regular"),
+  ("xvn", "regular"),
+  ("xvo", "regular"),
+  ("xvs", "regular"),
+  ("xwa", "regular"),
+  ("xwc", "regular"),
+  ("xwd", "regular"),
+  ("xwe", "regular"),
+  ("xwg", "regular"),
+  ("xwj", "regular"),
+  ("xwk", "regular"),
+  ("xwl", "regular"),
+  ("xwo", "regular"),
+  ("xwr", "regular"),
+  ("xwt", "regular"),
+  ("xww", "regular"),
+  ("xxb", "regular"),
+  ("xxk", "regular"),
+  ("xxm", "regular"),
+  ("xxr", "regular"),
+  ("xxt", "regular"),
+  ("xya", "regular"),
+  ("xyb", "regular"),
+  ("xyj", "regular"),
+  ("xyk", "regular"),
+  ("xyl", "regular"),
+  ("xyt", "regular"),
+  ("xyy", "regular"),
+  ("xzh", "regular"),
+  ("xzm", "regular"),
+  ("xzp", "regular"),
+  ("yaa", "regular"),
+  ("yab", "regular"),
+  ("yac", "regular"),
+  ("yad", "regular"),
+  ("yae", "regular"),
+  ("yaf", "regular"),
+  ("yag", "regular"),
+  ("yah", "regular"),
+  ("yai", "regular"),
+  ("yaj", "regular"),
+  ("yak", "regular"),
+  ("yal", "regular"),
+  ("yam", "regular"),
+  ("yan", "regular"),
+  ("yao", "regular"),
+  ("yap", "regular"),
+  ("yaq", "regular"),
+  ("yar", "regular"),
+  ("yas", "regular"),
+  ("yat", "regular"),
+  ("yau", "regular"),
+  ("yav", "regular"),
+  ("yaw", "regular"),
+  ("yax", "regular"),
+  ("yay", "regular"),
+  ("yaz", "regular"),
+  ("yba", "regular"),
+  ("ybb", "regular"),
+  ("ybd", "deprecated"),
+  ("ybe", "regular"),
+  ("ybh", "regular"),
+  ("ybi", "regular"),
+  ("ybj", "regular"),
+  ("ybk", "regular"),
+  ("ybl", "regular"),
+  ("ybm", "regular"),
+  ("ybn", "regular"),
+  ("ybo", "regular"),
+  ("ybx", "regular"),
+  ("yby", "regular"),
+  ("ych", "regular"),
+  ("ycl", "regular"),
+  ("ycn", "regular"),
+  ("ycp", "regular"),
+  ("yda", "regular"),
+  ("ydd", "deprecated"),
+  ("yde", "regular"),
+  ("ydg", "regular"),
+  ("ydk", "regular"),
+  ("yds", "deprecated"),
+  ("yea", "regular"),
+  ("yec", "regular"),
+  ("yee", "regular"),
+  ("yei", "regular"),
+  ("yej", "regular"),
+  ("yel", "regular"),
+  ("yen", "deprecated"),
+  ("yer", "regular"),
+  ("yes", "regular"),
+  ("yet", "regular"),
+  ("yeu", "regular"),
+  ("yev", "regular"),
+  ("yey", "regular"),
+  ("yga", "regular"),
+  ("ygi", "regular"),
+  ("ygl", "regular"),
+  ("ygm", "regular"),
+  ("ygp", "regular"),
+  ("ygr", "regular"),
+  ("ygs", "regular"),
+  ("ygu", "regular"),
+  ("ygw", "regular"),
+  ("yha", "regular"),
+  ("yhd", "regular"),
+  ("yhl", "regular"),
+  ("yhs", "regular"),
+  ("yi", "regular"),
+  ("yia", "regular"),
+  ("yif", "regular"),
+  ("yig", "regular"),
+  ("yih", "regular"),
+  ("yii", "regular"),
+  ("yij", "regular"),
+  ("yik", "regular"),
+  ("yil", "regular"),
+  ("yim", "regular"),
+  ("yin", "regular"),
+  ("yip", "regular"),
+  ("yiq", "regular"),
+  ("yir", "regular"),
+  ("yis", "regular"),
+  ("yit", "regular"),
+  ("yiu", "regular"),
+  ("yiv", "regular"),
+  ("yix", "regular"),
+  ("yiy", "deprecated"),
+  ("yiz", "regular"),
+  ("yka", "regular"),
+  ("ykg", "regular"),
+  ("yki", "regular"),
+  ("ykk", "regular"),
+  ("ykl", "regular"),
+  ("ykm", "regular"),
+  ("ykn", "regular"),
+  ("yko", "regular"),
+  ("ykr", "regular"),
+  ("ykt", "regular"),
+  ("yku", "regular"),
+  ("yky", "regular"),
+  ("yla", "regular"),
+  ("ylb", "regular"),
+  ("yle", "regular"),
+  ("ylg", "regular"),
+  ("yli", "regular"),
+  ("yll", "regular"),
+  ("ylm", "regular"),
+  ("yln", "regular"),
+  ("ylo", "regular"),
+  ("ylr", "regular"),
+  ("ylu", "regular"),
+  ("yly", "regular"),
+  ("yma", "deprecated"),
+  ("ymb", "regular"),
+  ("ymc", "regular"),
+  ("ymd", "regular"),
+  ("yme", "regular"),
+  ("ymg", "regular"),
+  ("ymh", "regular"),
+  ("ymi", "regular"),
+  ("ymk", "regular"),
+  ("yml", "regular"),
+  ("ymm", "regular"),
+  ("ymn", "regular"),
+  ("ymo", "regular"),
+  ("ymp", "regular"),
+  ("ymq", "regular"),
+  ("ymr", "regular"),
+  ("yms", "regular"),
+  ("ymt", "deprecated"),
+  ("ymx", "regular"),
+  ("ymz", "regular"),
+  ("yna", "regular"),
+  ("ynd", "regular"),
+  ("yne", "regular"),
+  ("yng", "regular"),
+  ("ynh", "deprecated"),
+  ("ynk", "regular"),
+  ("ynl", "regular"),
+  ("ynn", "regular"),
+  ("yno", "regular"),
+  ("ynq", "regular"),
+  ("yns", "regular"),
+  ("ynu", "regular"),
+  ("yo", "regular"),
+  ("yob", "regular"),
+  ("yog", "regular"),
+  ("yoi", "regular"),
+  ("yok", "regular"),
+  ("yol", "regular"),
+  ("yom", "regular"),
+  ("yon", "regular"),
+  ("yos", "deprecated"),
+  ("yot", "regular"),
+  ("yox", "regular"),
+  ("yoy", "regular"),
+  ("ypa", "regular"),
+  ("ypb", "regular"),
+  ("ypg", "regular"),
+  ("yph", "regular"),
+  ("ypk", "regular"),
+  ("ypm", "regular"),
+  ("ypn", "regular"),
+  ("ypo", "regular"),
+  ("ypp", "regular"),
+  ("ypz", "regular"),
+  ("yra", "regular"),
+  ("yrb", "regular"),
+  ("yre", "regular"),
+  ("yri", "deprecated"),
+  ("yrk", "regular"),
+  ("yrl", "regular"),
+  ("yrm", "regular"),
+  ("yrn", "regular"),
+  ("yro", "regular"),
+  ("yrs", "regular"),
+  ("yrw", "regular"),
+  ("yry", "regular"),
+  ("ysc", "regular"),
+  ("ysd", "regular"),
+  ("ysg", "regular"),
+  ("ysl", "regular"),
+  ("ysm", "regular"),
+  ("ysn", "regular"),
+  ("yso", "regular"),
+  ("ysp", "regular"),
+  ("ysr", "regular"),
+  ("yss", "regular"),
+  ("ysy", "regular"),
+  ("yta", "regular"),
+  ("ytl", "regular"),
+  ("ytp", "regular"),
+  ("ytw", "regular"),
+  ("yty", "regular"),
+  ("yua", "regular"),
+  ("yub", "regular"),
+  ("yuc", "regular"),
+  ("yud", "regular"),
+  ("yue", "regular"),
+  ("yuf", "regular"),
+  ("yug", "regular"),
+  ("yui", "regular"),
+  ("yuj", "regular"),
+  ("yuk", "regular"),
+  ("yul", "regular"),
+  ("yum", "regular"),
+  ("yun", "regular"),
+  ("yup", "regular"),
+  ("yuq", "regular"),
+  ("yur", "regular"),
+  ("yut", "regular"),
+  ("yuu", "deprecated"),
+  ("yuw", "regular"),
+  ("yux", "regular"),
+  ("yuy", "regular"),
+  ("yuz", "regular"),
+  ("yva", "regular"),
+  ("yvt", "regular"),
+  ("ywa", "regular"),
+  ("ywg", "regular"),
+  ("ywl", "regular"),
+  ("ywn", "regular"),
+  ("ywq", "regular"),
+  ("ywr", "regular"),
+  ("ywt", "regular"),
+  ("ywu", "regular"),
+  ("yww", "regular"),
+  ("yxa", "regular"),
+  ("yxg", "regular"),
+  ("yxl", "regular"),
+  ("yxm", "regular"),
+  ("yxu", "regular"),
+  ("yxy", "regular"),
+  ("yyr", "regular"),
+  ("yyu", "regular"),
+  ("yyz", "regular"),
+  ("yzg", "regular"),
+  ("yzk", "regular"),
+  ("za", "regular"),
+  ("zaa", "regular"),
+  ("zab", "regular"),
+  ("zac", "regular"),
+  ("zad", "regular"),
+  ("zae", "regular"),
+  ("zaf", "regular"),
+  ("zag", "regular"),
+  ("zah", "regular"),
+  ("zai", "deprecated"),
+  ("zaj", "regular"),
+  ("zak", "regular"),
+  ("zal", "regular"),
+  ("zam", "regular"),
+  ("zao", "regular"),
+  ("zap", "regular"),
+  ("zaq", "regular"),
+  ("zar", "regular"),
+  ("zas", "regular"),
+  ("zat", "regular"),
+  ("zau", "regular"),
+  ("zav", "regular"),
+  ("zaw", "regular"),
+  ("zax", "regular"),
+  ("zay", "regular"),
+  ("zaz", "regular"),
+  ("zba", "regular"),
+  ("zbc", "regular"),
+  ("zbe", "regular"),
+  ("zbl", "regular"),
+  ("zbt", "regular"),
+  ("zbu", "regular"),
+  ("zbw", "regular"),
+  ("zca", "regular"),
+  ("zcd", "regular"),
+  ("zch", "regular"),
+  ("zdj", "regular"),
+  ("zea", "regular"),
+  ("zeg", "regular"),
+  ("zeh", "regular"),
+  ("zen", "regular"),
+  ("zga", "regular"),
+  ("zgb", "regular"),
+  ("zgh", "regular"),
+  ("zgm", "regular"),
+  ("zgn", "regular"),
+  ("zgr", "regular"),
+  ("zh", "regular"),
+  ("zhb", "regular"),
+  ("zhd", "regular"),
+  ("zhi", "regular"),
+  ("zhn", "regular"),
+  ("zhw", "regular"),
+  ("zhx", "regular"),
+  ("zia", "regular"),
+  ("zib", "regular"),
+  ("zik", "regular"),
+  ("zil", "regular"),
+  ("zim", "regular"),
+  ("zin", "regular"),
+  ("zir", "deprecated"),
+  ("ziw", "regular"),
+  ("ziz", "regular"),
+  ("zka", "regular"),
+  ("zkb", "regular"),
+  ("zkd", "regular"),
+  ("zkg", "regular"),
+  ("zkh", "regular"),
+  ("zkk", "regular"),
+  ("zkn", "regular"),
+  ("zko", "regular"),
+  ("zkp", "regular"),
+  ("zkr", "regular"),
+  ("zkt", "regular"),
+  ("zku", "regular"),
+  ("zkv", "regular"),
+  ("zkz", "regular"),
+  ("zla", "regular"),
+  ("zle", "regular"),
+  ("zlj", "regular"),
+  ("zlm", "regular"),
+  ("zln", "regular"),
+  ("zlq", "regular"),
+  ("zls", "regular"),
+  ("zlw", "regular"),
+  ("zma", "regular"),
+  ("zmb", "regular"),
+  ("zmc", "regular"),
+  ("zmd", "regular"),
+  ("zme", "regular"),
+  ("zmf", "regular"),
+  ("zmg", "regular"),
+  ("zmh", "regular"),
+  ("zmi", "regular"),
+  ("zmj", "regular"),
+  ("zmk", "regular"),
+  ("zml", "regular"),
+  ("zmm", "regular"),
+  ("zmn", "regular"),
+  ("zmo", "regular"),
+  ("zmp", "regular"),
+  ("zmq", "regular"),
+  ("zmr", "regular"),
+  ("zms", "regular"),
+  ("zmt", "regular"),
+  ("zmu", "regular"),
+  ("zmv", "regular"),
+  ("zmw", "regular"),
+  ("zmx", "regular"),
+  ("zmy", "regular"),
+  ("zmz", "regular"),
+  ("zna", "regular"),
+  ("znd", "regular"),
+  ("zne", "regular"),
+  ("zng", "regular"),
+  ("znk", "regular"),
+  ("zns", "regular"),
+  ("zoc", "regular"),
+  ("zoh", "regular"),
+  ("zom", "regular"),
+  ("zoo", "regular"),
+  ("zoq", "regular"),
+  ("zor", "regular"),
+  ("zos", "regular"),
+  ("zpa", "regular"),
+  ("zpb", "regular"),
+  ("zpc", "regular"),
+  ("zpd", "regular"),
+  ("zpe", "regular"),
+  ("zpf", "regular"),
+  ("zpg", "regular"),
+  ("zph", "regular"),
+  ("zpi", "regular"),
+  ("zpj", "regular"),
+  ("zpk", "regular"),
+  ("zpl", "regular"),
+  ("zpm", "regular"),
+  ("zpn", "regular"),
+  ("zpo", "regular"),
+  ("zpp", "regular"),
+  ("zpq", "regular"),
+  ("zpr", "regular"),
+  ("zps", "regular"),
+  ("zpt", "regular"),
+  ("zpu", "regular"),
+  ("zpv", "regular"),
+  ("zpw", "regular"),
+  ("zpx", "regular"),
+  ("zpy", "regular"),
+  ("zpz", "regular"),
+  ("zqe", "regular"),
+  ("zra", "regular"),
+  ("zrg", "regular"),
+  ("zrn", "regular"),
+  ("zro", "regular"),
+  ("zrp", "regular"),
+  ("zrs", "regular"),
+  ("zsa", "regular"),
+  ("zsk", "regular"),
+  ("zsl", "regular"),
+  ("zsm", "deprecated"),
+  ("zsr", "regular"),
+  ("zsu", "regular"),
+  ("zte", "regular"),
+  ("ztg", "regular"),
+  ("ztl", "regular"),
+  ("ztm", "regular"),
+  ("ztn", "regular"),
+  ("ztp", "regular"),
+  ("ztq", "regular"),
+  ("zts", "regular"),
+  ("ztt", "regular"),
+  ("ztu", "regular"),
+  ("ztx", "regular"),
+  ("zty", "regular"),
+  ("zu", "regular"),
+  ("zua", "regular"),
+  ("zuh", "regular"),
+  ("zum", "regular"),
+  ("zun", "regular"),
+  ("zuy", "regular"),
+  ("zwa", "regular"),
+  ("zxx", "special"),
+  ("zyb", "deprecated"),
+  ("zyg", "regular"),
+  ("zyj", "regular"),
+  ("zyn", "regular"),
+  ("zyp", "regular"),
+  ("zza", "regular"),
+  ("zzj", "regular"),
+];
+
+/// The statuses of the script subtags.
+pub static SCRIPTS: &[(&str, &str)] = &[
+  ("Adlm", "regular"),
+  ("Aghb", "regular"),
+  ("Ahom", "regular"),
+  ("Arab", "regular"),
+  ("Aran", "special"),
+  ("Armi", "regular"),
+  ("Armn", "regular"),
+  ("Avst", "regular"),
+  ("Bali", "regular"),
+  ("Bamu", "regular"),
+  ("Bass", "regular"),
+  ("Batk", "regular"),
+  ("Beng", "regular"),
+  ("Bhks", "regular"),
+  ("Bopo", "regular"),
+  ("Brah", "regular"),
+  ("Brai", "regular"),
+  ("Bugi", "regular"),
+  ("Buhd", "regular"),
+  ("Cakm", "regular"),
+  ("Cans", "regular"),
+  ("Cari", "regular"),
+  ("Cham", "regular"),
+  ("Cher", "regular"),
+  ("Chrs", "regular"),
+  ("Copt", "regular"),
+  ("Cpmn", "regular"),
+  ("Cprt", "regular"),
+  ("Cyrl", "regular"),
+  ("Deva", "regular"),
+  ("Diak", "regular"),
+  ("Dogr", "regular"),
+  ("Dsrt", "regular"),
+  ("Dupl", "regular"),
+  ("Egyp", "regular"),
+  ("Elba", "regular"),
+  ("Elym", "regular"),
+  ("Ethi", "regular"),
+  ("Geor", "regular"),
+  ("Glag", "regular"),
+  ("Gong", "regular"),
+  ("Gonm", "regular"),
+  ("Goth", "regular"),
+  ("Gran", "regular"),
+  ("Grek", "regular"),
+  ("Gujr", "regular"),
+  ("Guru", "regular"),
+  ("Hanb", "regular"),
+  ("Hang", "regular"),
+  ("Hani", "regular"),
+  ("Hano", "regular"),
+  ("Hans", "regular"),
+  ("Hant", "regular"),
+  ("Hatr", "regular"),
+  ("Hebr", "regular"),
+  ("Hira", "regular"),
+  ("Hluw", "regular"),
+  ("Hmng", "regular"),
+  ("Hmnp", "regular"),
+  ("Hrkt", "regular"),
+  ("Hung", "regular"),
+  ("Ital", "regular"),
+  ("Jamo", "regular"),
+  ("Java", "regular"),
+  ("Jpan", "regular"),
+  ("Kali", "regular"),
+  ("Kana", "regular"),
+  ("Kawi", "regular"),
+  ("Khar", "regular"),
+  ("Khmr", "regular"),
+  ("Khoj", "regular"),
+  ("Kits", "regular"),
+  ("Knda", "regular"),
+  ("Kore", "regular"),
+  ("Kthi", "regular"),
+  ("Lana", "regular"),
+  ("Laoo", "regular"),
+  ("Latn", "regular"),
+  ("Lepc", "regular"),
+  ("Limb", "regular"),
+  ("Lina", "regular"),
+  ("Linb", "regular"),
+  ("Lisu", "regular"),
+  ("Lyci", "regular"),
+  ("Lydi", "regular"),
+  ("Mahj", "regular"),
+  ("Maka", "regular"),
+  ("Mand", "regular"),
+  ("Mani", "regular"),
+  ("Marc", "regular"),
+  ("Medf", "regular"),
+  ("Mend", "regular"),
+  ("Merc", "regular"),
+  ("Mero", "regular"),
+  ("Mlym", "regular"),
+  ("Modi", "regular"),
+  ("Mong", "regular"),
+  ("Mroo", "regular"),
+  ("Mtei", "regular"),
+  ("Mult", "regular"),
+  ("Mymr", "regular"),
+  ("Nagm", "regular"),
+  ("Nand", "regular"),
+  ("Narb", "regular"),
+  ("Nbat", "regular"),
+  ("Newa", "regular"),
+  ("Nkoo", "regular"),
+  ("Nshu", "regular"),
+  ("Ogam", "regular"),
+  ("Olck", "regular"),
+  ("Orkh", "regular"),
+  ("Orya", "regular"),
+  ("Osge", "regular"),
+  ("Osma", "regular"),
+  ("Ougr", "regular"),
+  ("Palm", "regular"),
+  ("Pauc", "regular"),
+  ("Perm", "regular"),
+  ("Phag", "regular"),
+  ("Phli", "regular"),
+  ("Phlp", "regular"),
+  ("Phnx", "regular"),
+  ("Plrd", "regular"),
+  ("Prti", "regular"),
+  ("Qaag", "special"),
+  ("Qaai", "deprecated"),
+  ("Rjng", "regular"),
+  ("Rohg", "regular"),
+  ("Runr", "regular"),
+  ("Samr", "regular"),
+  ("Sarb", "regular"),
+  ("Saur", "regular"),
+  ("Sgnw", "regular"),
+  ("Shaw", "regular"),
+  ("Shrd", "regular"),
+  ("Sidd", "regular"),
+  ("Sind", "regular"),
+  ("Sinh", "regular"),
+  ("Sogd", "regular"),
+  ("Sogo", "regular"),
+  ("Sora", "regular"),
+  ("Soyo", "regular"),
+  ("Sund", "regular"),
+  ("Sylo", "regular"),
+  ("Syrc", "regular"),
+  ("Tagb", "regular"),
+  ("Takr", "regular"),
+  ("Tale", "regular"),
+  ("Talu", "regular"),
+  ("Taml", "regular"),
+  ("Tang", "regular"),
+  ("Tavt", "regular"),
+  ("Telu", "regular"),
+  ("Tfng", "regular"),
+  ("Tglg", "regular"),
+  ("Thaa", "regular"),
+  ("Thai", "regular"),
+  ("Tibt", "regular"),
+  ("Tirh", "regular"),
+  ("Tnsa", "regular"),
+  ("Toto", "regular"),
+  ("Ugar", "regular"),
+  ("Vaii", "regular"),
+  ("Vith", "regular"),
+  ("Wara", "regular"),
+  ("Wcho", "regular"),
+  ("Xpeo", "regular"),
+  ("Xsux", "regular"),
+  ("Yezi", "regular"),
+  ("Yiii", "regular"),
+  ("Zanb", "special"),
+  ("Zinh", "special"),
+  ("Zmth", "special"),
+  ("Zsye", "special"),
+  ("Zsym", "special"),
+  ("Zxxx", "special"),
+  ("Zyyy", "special"),
+  ("Zzzz", "unknown"),
+];
+
+/// The statuses of the region subtags.
+pub static REGIONS: &[(&str, &str)] = &[
+  ("001", "macroregion"),
+  ("002", "macroregion"),
+  ("003", "macroregion"),
+  ("005", "macroregion"),
+  ("009", "macroregion"),
+  ("011", "macroregion"),
+  ("013", "macroregion"),
+  ("014", "macroregion"),
+  ("015", "macroregion"),
+  ("017", "macroregion"),
+  ("018", "macroregion"),
+  ("019", "macroregion"),
+  ("021", "macroregion"),
+  ("029", "macroregion"),
+  ("030", "macroregion"),
+  ("034", "macroregion"),
+  ("035", "macroregion"),
+  ("039", "macroregion"),
+  ("053", "macroregion"),
+  ("054", "macroregion"),
+  ("057", "macroregion"),
+  ("061", "macroregion"),
+  ("142", "macroregion"),
+  ("143", "macroregion"),
+  ("145", "macroregion"),
+  ("150", "macroregion"),
+  ("151", "macroregion"),
+  ("154", "macroregion"),
+  ("155", "macroregion"),
+  ("202", "macroregion"),
+  ("419", "macroregion"),
+  ("AC", "regular"),
+  ("AD", "regular"),
+  ("AE", "regular"),
+  ("AF", "regular"),
+  ("AG", "regular"),
+  ("AI", "regular"),
+  ("AL", "regular"),
+  ("AM", "regular"),
+  ("AN", "deprecated"),
+  ("AO", "regular"),
+  ("AQ", "regular"),
+  ("AR", "regular"),
+  ("AS", "regular"),
+  ("AT", "regular"),
+  ("AU", "regular"),
+  ("AW", "regular"),
+  ("AX", "regular"),
+  ("AZ", "regular"),
+  ("BA", "regular"),
+  ("BB", "regular"),
+  ("BD", "regular"),
+  ("BE", "regular"),
+  ("BF", "regular"),
+  ("BG", "regular"),
+  ("BH", "regular"),
+  ("BI", "regular"),
+  ("BJ", "regular"),
+  ("BL", "regular"),
+  ("BM", "regular"),
+  ("BN", "regular"),
+  ("BO", "regular"),
+  ("BQ", "regular"),
+  ("BR", "regular"),
+  ("BS", "regular"),
+  ("BT", "regular"),
+  ("BU", "deprecated"),
+  ("BV", "regular"),
+  ("BW", "regular"),
+  ("BY", "regular"),
+  ("BZ", "regular"),
+  ("CA", "regular"),
+  ("CC", "regular"),
+  ("CD", "regular"),
+  ("CF", "regular"),
+  ("CG", "regular"),
+  ("CH", "regular"),
+  ("CI", "regular"),
+  ("CK", "regular"),
+  ("CL", "regular"),
+  ("CM", "regular"),
+  ("CN", "regular"),
+  ("CO", "regular"),
+  ("CP", "regular"),
+  ("CQ", "regular"),
+  ("CR", "regular"),
+  ("CS", "deprecated"),
+  ("CU", "regular"),
+  ("CV", "regular"),
+  ("CW", "regular"),
+  ("CX", "regular"),
+  ("CY", "regular"),
+  ("CZ", "regular"),
+  ("DD", "deprecated"),
+  ("DE", "regular"),
+  ("DG", "regular"),
+  ("DJ", "regular"),
+  ("DK", "regular"),
+  ("DM", "regular"),
+  ("DO", "regular"),
+  ("DZ", "regular"),
+  ("EA", "regular"),
+  ("EC", "regular"),
+  ("EE", "regular"),
+  ("EG", "regular"),
+  ("EH", "regular"),
+  ("ER", "regular"),
+  ("ES", "regular"),
+  ("ET", "regular"),
+  ("EU", "macroregion"),
+  ("EZ", "macroregion"),
+  ("FI", "regular"),
+  ("FJ", "regular"),
+  ("FK", "regular"),
+  ("FM", "regular"),
+  ("FO", "regular"),
+  ("FR", "regular"),
+  ("FX", "deprecated"),
+  ("GA", "regular"),
+  ("GB", "regular"),
+  ("GD", "regular"),
+  ("GE", "regular"),
+  ("GF", "regular"),
+  ("GG", "regular"),
+  ("GH", "regular"),
+  ("GI", "regular"),
+  ("GL", "regular"),
+  ("GM", "regular"),
+  ("GN", "regular"),
+  ("GP", "regular"),
+  ("GQ", "regular"),
+  ("GR", "regular"),
+  ("GS", "regular"),
+  ("GT", "regular"),
+  ("GU", "regular"),
+  ("GW", "regular"),
+  ("GY", "regular"),
+  ("HK", "regular"),
+  ("HM", "regular"),
+  ("HN", "regular"),
+  ("HR", "regular"),
+  ("HT", "regular"),
+  ("HU", "regular"),
+  ("IC", "regular"),
+  ("ID", "regular"),
+  ("IE", "regular"),
+  ("IL", "regular"),
+  ("IM", "regular"),
+  ("IN", "regular"),
+  ("IO", "regular"),
+  ("IQ", "regular"),
+  ("IR", "regular"),
+  ("IS", "regular"),
+  ("IT", "regular"),
+  ("JE", "regular"),
+  ("JM", "regular"),
+  ("JO", "regular"),
+  ("JP", "regular"),
+  ("KE", "regular"),
+  ("KG", "regular"),
+  ("KH", "regular"),
+  ("KI", "regular"),
+  ("KM", "regular"),
+  ("KN", "regular"),
+  ("KP", "regular"),
+  ("KR", "regular"),
+  ("KW", "regular"),
+  ("KY", "regular"),
+  ("KZ", "regular"),
+  ("LA", "regular"),
+  ("LB", "regular"),
+  ("LC", "regular"),
+  ("LI", "regular"),
+  ("LK", "regular"),
+  ("LR", "regular"),
+  ("LS", "regular"),
+  ("LT", "regular"),
+  ("LU", "regular"),
+  ("LV", "regular"),
+  ("LY", "regular"),
+  ("MA", "regular"),
+  ("MC", "regular"),
+  ("MD", "regular"),
+  ("ME", "regular"),
+  ("MF", "regular"),
+  ("MG", "regular"),
+  ("MH", "regular"),
+  ("MK", "regular"),
+  ("ML", "regular"),
+  ("MM", "regular"),
+  ("MN", "regular"),
+  ("MO", "regular"),
+  ("MP", "regular"),
+  ("MQ", "regular"),
+  ("MR", "regular"),
+  ("MS", "regular"),
+  ("MT", "regular"),
+  ("MU", "regular"),
+  ("MV", "regular"),
+  ("MW", "regular"),
+  ("MX", "regular"),
+  ("MY", "regular"),
+  ("MZ", "regular"),
+  ("NA", "regular"),
+  ("NC", "regular"),
+  ("NE", "regular"),
+  ("NF", "regular"),
+  ("NG", "regular"),
+  ("NI", "regular"),
+  ("NL", "regular"),
+  ("NO", "regular"),
+  ("NP", "regular"),
+  ("NR", "regular"),
+  ("NT", "deprecated"),
+  ("NU", "regular"),
+  ("NZ", "regular"),
+  ("OM", "regular"),
+  ("PA", "regular"),
+  ("PE", "regular"),
+  ("PF", "regular"),
+  ("PG", "regular"),
+  ("PH", "regular"),
+  ("PK", "regular"),
+  ("PL", "regular"),
+  ("PM", "regular"),
+  ("PN", "regular"),
+  ("PR", "regular"),
+  ("PS", "regular"),
+  ("PT", "regular"),
+  ("PW", "regular"),
+  ("PY", "regular"),
+  ("QA", "regular"),
+  ("QO", "macroregion"),
+  ("QU", "deprecated"),
+  ("RE", "regular"),
+  ("RO", "regular"),
+  ("RS", "regular"),
+  ("RU", "regular"),
+  ("RW", "regular"),
+  ("SA", "regular"),
+  ("SB", "regular"),
+  ("SC", "regular"),
+  ("SD", "regular"),
+  ("SE", "regular"),
+  ("SG", "regular"),
+  ("SH", "regular"),
+  ("SI", "regular"),
+  ("SJ", "regular"),
+  ("SK", "regular"),
+  ("SL", "regular"),
+  ("SM", "regular"),
+  ("SN", "regular"),
+  ("SO", "regular"),
+  ("SR", "regular"),
+  ("SS", "regular"),
+  ("ST", "regular"),
+  ("SU", "deprecated"),
+  ("SV", "regular"),
+  ("SX", "regular"),
+  ("SY", "regular"),
+  ("SZ", "regular"),
+  ("TA", "regular"),
+  ("TC", "regular"),
+  ("TD", "regular"),
+  ("TF", "regular"),
+  ("TG", "regular"),
+  ("TH", "regular"),
+  ("TJ", "regular"),
+  ("TK", "regular"),
+  ("TL", "regular"),
+  ("TM", "regular"),
+  ("TN", "regular"),
+  ("TO", "regular"),
+  ("TP", "deprecated"),
+  ("TR", "regular"),
+  ("TT", "regular"),
+  ("TV", "regular"),
+  ("TW", "regular"),
+  ("TZ", "regular"),
+  ("UA", "regular"),
+  ("UG", "regular"),
+  ("UM", "regular"),
+  ("UN", "macroregion"),
+  ("US", "regular"),
+  ("UY", "regular"),
+  ("UZ", "regular"),
+  ("VA", "regular"),
+  ("VC", "regular"),
+  ("VE", "regular"),
+  ("VG", "regular"),
+  ("VI", "regular"),
+  ("VN", "regular"),
+  ("VU", "regular"),
+  ("WF", "regular"),
+  ("WS", "regular"),
+  ("XA", "special"),
+  ("XB", "special"),
+  ("XK", "regular"),
+  ("YD", "deprecated"),
+  ("YE", "regular"),
+  ("YT", "regular"),
+  ("YU", "deprecated"),
+  ("ZA", "regular"),
+  ("ZM", "regular"),
+  ("ZR", "deprecated"),
+  ("ZW", "regular"),
+  ("ZZ", "unknown"),
+];
+
+/// The statuses of the variant subtags.
+pub static VARIANTS: &[(&str, &str)] = &[
+  ("1606nict", "regular"),
+  ("1694acad", "regular"),
+  ("1901", "regular"),
+  ("1959acad", "regular"),
+  ("1994", "regular"),
+  ("1996", "regular"),
+  ("abl1943", "regular"),
+  ("akuapem", "regular"),
+  ("alalc97", "regular"),
+  ("aluku", "regular"),
+  ("ao1990", "regular"),
+  ("aranes", "regular"),
+  ("arevela", "deprecated"),
+  ("arevmda", "deprecated"),
+  ("arkaika", "regular"),
+  ("asante", "regular"),
+  ("auvern", "regular"),
+  ("baku1926", "regular"),
+  ("balanka", "regular"),
+  ("barla", "regular"),
+  ("basiceng", "regular"),
+  ("bauddha", "regular"),
+  ("biscayan", "regular"),
+  ("biske", "regular"),
+  ("bohoric", "regular"),
+  ("boont", "regular"),
+  ("bornholm", "regular"),
+  ("cisaup", "regular"),
+  ("colb1945", "regular"),
+  ("cornu", "regular"),
+  ("creiss", "regular"),
+  ("dajnko", "regular"),
+  ("ekavsk", "regular"),
+  ("emodeng", "regular"),
+  ("fonipa", "regular"),
+  ("fonkirsh", "regular"),
+  ("fonnapa", "regular"),
+  ("fonupa", "regular"),
+  ("fonxsamp", "regular"),
+  ("gallo", "regular"),
+  ("gascon", "regular"),
+  ("grclass", "regular"),
+  ("grital", "regular"),
+  ("grmistr", "regular"),
+  ("hepburn", "regular"),
+  ("heploc", "deprecated"),
+  ("hognorsk", "regular"),
+  ("hsistemo", "regular"),
+  ("ijekavsk", "regular"),
+  ("itihasa", "regular"),
+  ("ivanchov", "regular"),
+  ("jauer", "regular"),
+  ("jyutping", "regular"),
+  ("kkcor", "regular"),
+  ("kociewie", "regular"),
+  ("kscor", "regular"),
+  ("laukika", "regular"),
+  ("lemosin", "regular"),
+  ("lengadoc", "regular"),
+  ("lipaw", "regular"),
+  ("ltg1929", "regular"),
+  ("ltg2007", "regular"),
+  ("luna1918", "regular"),
+  ("metelko", "regular"),
+  ("monoton", "regular"),
+  ("ndyuka", "regular"),
+  ("nedis", "regular"),
+  ("newfound", "regular"),
+  ("nicard", "regular"),
+  ("njiva", "regular"),
+  ("nulik", "regular"),
+  ("osojs", "regular"),
+  ("oxendict", "regular"),
+  ("pahawh2", "regular"),
+  ("pahawh3", "regular"),
+  ("pahawh4", "regular"),
+  ("pamaka", "regular"),
+  ("peano", "regular"),
+  ("petr1708", "regular"),
+  ("pinyin", "regular"),
+  ("polyton", "regular"),
+  ("provenc", "regular"),
+  ("puter", "regular"),
+  ("rigik", "regular"),
+  ("rozaj", "regular"),
+  ("rumgr", "regular"),
+  ("scotland", "regular"),
+  ("scouse", "regular"),
+  ("simple", "regular"),
+  ("solba", "regular"),
+  ("sotav", "regular"),
+  ("spanglis", "regular"),
+  ("surmiran", "regular"),
+  ("sursilv", "regular"),
+  ("sutsilv", "regular"),
+  ("synnejyl", "regular"),
+  ("tarask", "regular"),
+  ("tongyong", "regular"),
+  ("tunumiit", "regular"),
+  ("uccor", "regular"),
+  ("ucrcor", "regular"),
+  ("ulster", "regular"),
+  ("unifon", "regular"),
+  ("vaidika", "regular"),
+  ("valencia", "regular"),
+  ("vallader", "regular"),
+  ("vecdruka", "regular"),
+  ("vivaraup", "regular"),
+  ("wadegile", "regular"),
+  ("xsistemo", "regular"),
+];
+
+/// The statuses of the subdivisions.
+pub static SUBDIVISIONS: &[(&str, &str)] = &[
+  ("aczzzz", "unknown"),
+  ("ad02", "regular"),
+  ("ad03", "regular"),
+  ("ad04", "regular"),
+  ("ad05", "regular"),
+  ("ad06", "regular"),
+  ("ad07", "regular"),
+  ("ad08", "regular"),
+  ("adzzzz", "unknown"),
+  ("aeaj", "regular"),
+  ("aeaz", "regular"),
+  ("aedu", "regular"),
+  ("aefu", "regular"),
+  ("aerk", "regular"),
+  ("aesh", "regular"),
+  ("aeuq", "regular"),
+  ("aezzzz", "unknown"),
+  ("afbal", "regular"),
+  ("afbam", "regular"),
+  ("afbdg", "regular"),
+  ("afbds", "regular"),
+  ("afbgl", "regular"),
+  ("afday", "regular"),
+  ("affra", "regular"),
+  ("affyb", "regular"),
+  ("afgha", "regular"),
+  ("afgho", "regular"),
+  ("afhel", "regular"),
+  ("afher", "regular"),
+  ("afjow", "regular"),
+  ("afkab", "regular"),
+  ("afkan", "regular"),
+  ("afkap", "regular"),
+  ("afkdz", "regular"),
+  ("afkho", "regular"),
+  ("afknr", "regular"),
+  ("aflag", "regular"),
+  ("aflog", "regular"),
+  ("afnan", "regular"),
+  ("afnim", "regular"),
+  ("afnur", "regular"),
+  ("afpan", "regular"),
+  ("afpar", "regular"),
+  ("afpia", "regular"),
+  ("afpka", "regular"),
+  ("afsam", "regular"),
+  ("afsar", "regular"),
+  ("aftak", "regular"),
+  ("afuru", "regular"),
+  ("afwar", "regular"),
+  ("afzab", "regular"),
+  ("afzzzz", "unknown"),
+  ("ag03", "regular"),
+  ("ag04", "regular"),
+  ("ag05", "regular"),
+  ("ag06", "regular"),
+  ("ag07", "regular"),
+  ("ag08", "regular"),
+  ("ag10", "regular"),
+  ("ag11", "regular"),
+  ("agzzzz", "unknown"),
+  ("aizzzz", "unknown"),
+  ("al01", "regular"),
+  ("al02", "regular"),
+  ("al03", "regular"),
+  ("al04", "regular"),
+  ("al05", "regular"),
+  ("al06", "regular"),
+  ("al07", "regular"),
+  ("al08", "regular"),
+  ("al09", "regular"),
+  ("al10", "regular"),
+  ("al11", "regular"),
+  ("al12", "regular"),
+  ("albr", "deprecated"),
+  ("albu", "deprecated"),
+  ("aldi", "deprecated"),
+  ("aldl", "deprecated"),
+  ("aldr", "deprecated"),
+  ("aldv", "deprecated"),
+  ("alel", "deprecated"),
+  ("aler", "deprecated"),
+  ("alfr", "deprecated"),
+  ("algj", "deprecated"),
+  ("algr", "deprecated"),
+  ("alha", "deprecated"),
+  ("alka", "deprecated"),
+  ("alkb", "deprecated"),
+  ("alkc", "deprecated"),
+  ("alko", "deprecated"),
+  ("alkr", "deprecated"),
+  ("alku", "deprecated"),
+  ("allb", "deprecated"),
+  ("alle", "deprecated"),
+  ("allu", "deprecated"),
+  ("almk", "deprecated"),
+  ("almm", "deprecated"),
+  ("almr", "deprecated"),
+  ("almt", "deprecated"),
+  ("alpg", "deprecated"),
+  ("alpq", "deprecated"),
+  ("alpr", "deprecated"),
+  ("alpu", "deprecated"),
+  ("alsh", "deprecated"),
+  ("alsk", "deprecated"),
+  ("alsr", "deprecated"),
+  ("alte", "deprecated"),
+  ("altp", "deprecated"),
+  ("altr", "deprecated"),
+  ("alvl", "deprecated"),
+  ("alzzzz", "unknown"),
+  ("amag", "regular"),
+  ("amar", "regular"),
+  ("amav", "regular"),
+  ("amer", "regular"),
+  ("amgr", "regular"),
+  ("amkt", "regular"),
+  ("amlo", "regular"),
+  ("amsh", "regular"),
+  ("amsu", "regular"),
+  ("amtv", "regular"),
+  ("amvd", "regular"),
+  ("amzzzz", "unknown"),
+  ("aobgo", "regular"),
+  ("aobgu", "regular"),
+  ("aobie", "regular"),
+  ("aocab", "regular"),
+  ("aoccu", "regular"),
+  ("aocnn", "regular"),
+  ("aocno", "regular"),
+  ("aocus", "regular"),
+  ("aohua", "regular"),
+  ("aohui", "regular"),
+  ("aolno", "regular"),
+  ("aolsu", "regular"),
+  ("aolua", "regular"),
+  ("aomal", "regular"),
+  ("aomox", "regular"),
+  ("aonam", "regular"),
+  ("aouig", "regular"),
+  ("aozai", "regular"),
+  ("aozzzz", "unknown"),
+  ("aqzzzz", "unknown"),
+  ("ara", "regular"),
+  ("arb", "regular"),
+  ("arc", "regular"),
+  ("ard", "regular"),
+  ("are", "regular"),
+  ("arf", "regular"),
+  ("arg", "regular"),
+  ("arh", "regular"),
+  ("arj", "regular"),
+  ("ark", "regular"),
+  ("arl", "regular"),
+  ("arm", "regular"),
+  ("arn", "regular"),
+  ("arp", "regular"),
+  ("arq", "regular"),
+  ("arr", "regular"),
+  ("ars", "regular"),
+  ("art", "regular"),
+  ("aru", "regular"),
+  ("arv", "regular"),
+  ("arw", "regular"),
+  ("arx", "regular"),
+  ("ary", "regular"),
+  ("arz", "regular"),
+  ("arzzzz", "unknown"),
+  ("aszzzz", "unknown"),
+  ("at1", "regular"),
+  ("at2", "regular"),
+  ("at3", "regular"),
+  ("at4", "regular"),
+  ("at5", "regular"),
+  ("at6", "regular"),
+  ("at7", "regular"),
+  ("at8", "regular"),
+  ("at9", "regular"),
+  ("atzzzz", "unknown"),
+  ("auact", "regular"),
+  ("aunsw", "regular"),
+  ("aunt", "regular"),
+  ("auqld", "regular"),
+  ("ausa", "regular"),
+  ("autas", "regular"),
+  ("auvic", "regular"),
+  ("auwa", "regular"),
+  ("auzzzz", "unknown"),
+  ("awzzzz", "unknown"),
+  ("axzzzz", "unknown"),
+  ("azabs", "regular"),
+  ("azaga", "regular"),
+  ("azagc", "regular"),
+  ("azagm", "regular"),
+  ("azags", "regular"),
+  ("azagu", "regular"),
+  ("azast", "regular"),
+  ("azba", "regular"),
+  ("azbab", "regular"),
+  ("azbal", "regular"),
+  ("azbar", "regular"),
+  ("azbey", "regular"),
+  ("azbil", "regular"),
+  ("azcab", "regular"),
+  ("azcal", "regular"),
+  ("azcul", "regular"),
+  ("azdas", "regular"),
+  ("azfuz", "regular"),
+  ("azga", "regular"),
+  ("azgad", "regular"),
+  ("azgor", "regular"),
+  ("azgoy", "regular"),
+  ("azgyg", "regular"),
+  ("azhac", "regular"),
+  ("azimi", "regular"),
+  ("azism", "regular"),
+  ("azkal", "regular"),
+  ("azkan", "regular"),
+  ("azkur", "regular"),
+  ("azla", "regular"),
+  ("azlac", "regular"),
+  ("azlan", "regular"),
+  ("azler", "regular"),
+  ("azmas", "regular"),
+  ("azmi", "regular"),
+  ("azna", "regular"),
+  ("aznef", "regular"),
+  ("aznv", "regular"),
+  ("aznx", "regular"),
+  ("azogu", "regular"),
+  ("azord", "regular"),
+  ("azqab", "regular"),
+  ("azqax", "regular"),
+  ("azqaz", "regular"),
+  ("azqba", "regular"),
+  ("azqbi", "regular"),
+  ("azqob", "regular"),
+  ("azqus", "regular"),
+  ("azsa", "regular"),
+  ("azsab", "regular"),
+  ("azsad", "regular"),
+  ("azsah", "regular"),
+  ("azsak", "regular"),
+  ("azsal", "regular"),
+  ("azsar", "regular"),
+  ("azsat", "regular"),
+  ("azsbn", "regular"),
+  ("azsiy", "regular"),
+  ("azskr", "regular"),
+  ("azsm", "regular"),
+  ("azsmi", "regular"),
+  ("azsmx", "regular"),
+  ("azsr", "regular"),
+  ("azsus", "regular"),
+  ("aztar", "regular"),
+  ("aztov", "regular"),
+  ("azuca", "regular"),
+  ("azxa", "regular"),
+  ("azxac", "regular"),
+  ("azxci", "regular"),
+  ("azxiz", "regular"),
+  ("azxvd", "regular"),
+  ("azyar", "regular"),
+  ("azye", "regular"),
+  ("azyev", "regular"),
+  ("azzan", "regular"),
+  ("azzaq", "regular"),
+  ("azzar", "regular"),
+  ("azzzzz", "unknown"),
+  ("ba01", "deprecated"),
+  ("ba02", "deprecated"),
+  ("ba03", "deprecated"),
+  ("ba04", "deprecated"),
+  ("ba05", "deprecated"),
+  ("ba06", "deprecated"),
+  ("ba07", "deprecated"),
+  ("ba08", "deprecated"),
+  ("ba09", "deprecated"),
+  ("ba10", "deprecated"),
+  ("babih", "regular"),
+  ("babrc", "regular"),
+  ("basrp", "regular"),
+  ("bazzzz", "unknown"),
+  ("bb01", "regular"),
+  ("bb02", "regular"),
+  ("bb03", "regular"),
+  ("bb04", "regular"),
+  ("bb05", "regular"),
+  ("bb06", "regular"),
+  ("bb07", "regular"),
+  ("bb08", "regular"),
+  ("bb09", "regular"),
+  ("bb10", "regular"),
+  ("bb11", "regular"),
+  ("bbzzzz", "unknown"),
+  ("bd01", "regular"),
+  ("bd02", "regular"),
+  ("bd03", "regular"),
+  ("bd04", "regular"),
+  ("bd05", "regular"),
+  ("bd06", "regular"),
+  ("bd07", "regular"),
+  ("bd08", "regular"),
+  ("bd09", "regular"),
+  ("bd10", "regular"),
+  ("bd11", "regular"),
+  ("bd12", "regular"),
+  ("bd13", "regular"),
+  ("bd14", "regular"),
+  ("bd15", "regular"),
+  ("bd16", "regular"),
+  ("bd17", "regular"),
+  ("bd18", "regular"),
+  ("bd19", "regular"),
+  ("bd20", "regular"),
+  ("bd21", "regular"),
+  ("bd22", "regular"),
+  ("bd23", "regular"),
+  ("bd24", "regular"),
+  ("bd25", "regular"),
+  ("bd26", "regular"),
+  ("bd27", "regular"),
+  ("bd28", "regular"),
+  ("bd29", "regular"),
+  ("bd30", "regular"),
+  ("bd31", "regular"),
+  ("bd32", "regular"),
+  ("bd33", "regular"),
+  ("bd34", "regular"),
+  ("bd35", "regular"),
+  ("bd36", "regular"),
+  ("bd37", "regular"),
+  ("bd38", "regular"),
+  ("bd39", "regular"),
+  ("bd40", "regular"),
+  ("bd41", "regular"),
+  ("bd42", "regular"),
+  ("bd43", "regular"),
+  ("bd44", "regular"),
+  ("bd45", "regular"),
+  ("bd46", "regular"),
+  ("bd47", "regular"),
+  ("bd48", "regular"),
+  ("bd49", "regular"),
+  ("bd50", "regular"),
+  ("bd51", "regular"),
+  ("bd52", "regular"),
+  ("bd53", "regular"),
+  ("bd54", "regular"),
+  ("bd55", "regular"),
+  ("bd56", "regular"),
+  ("bd57", "regular"),
+  ("bd58", "regular"),
+  ("bd59", "regular"),
+  ("bd60", "regular"),
+  ("bd61", "regular"),
+  ("bd62", "regular"),
+  ("bd63", "regular"),
+  ("bd64", "regular"),
+  ("bda", "regular"),
+  ("bdb", "regular"),
+  ("bdc", "regular"),
+  ("bdd", "regular"),
+  ("bde", "regular"),
+  ("bdf", "regular"),
+  ("bdg", "regular"),
+  ("bdh", "regular"),
+  ("bdzzzz", "unknown"),
+  ("bebru", "regular"),
+  ("bevan", "regular"),
+  ("bevbr", "regular"),
+  ("bevlg", "regular"),
+  ("bevli", "regular"),
+  ("bevov", "regular"),
+  ("bevwv", "regular"),
+  ("bewal", "regular"),
+  ("bewbr", "regular"),
+  ("bewht", "regular"),
+  ("bewlg", "regular"),
+  ("bewlx", "regular"),
+  ("bewna", "regular"),
+  ("bezzzz", "unknown"),
+  ("bf01", "regular"),
+  ("bf02", "regular"),
+  ("bf03", "regular"),
+  ("bf04", "regular"),
+  ("bf05", "regular"),
+  ("bf06", "regular"),
+  ("bf07", "regular"),
+  ("bf08", "regular"),
+  ("bf09", "regular"),
+  ("bf10", "regular"),
+  ("bf11", "regular"),
+  ("bf12", "regular"),
+  ("bf13", "regular"),
+  ("bfbal", "regular"),
+  ("bfbam", "regular"),
+  ("bfban", "regular"),
+  ("bfbaz", "regular"),
+  ("bfbgr", "regular"),
+  ("bfblg", "regular"),
+  ("bfblk", "regular"),
+  ("bfcom", "regular"),
+  ("bfgan", "regular"),
+  ("bfgna", "regular"),
+  ("bfgou", "regular"),
+  ("bfhou", "regular"),
+  ("bfiob", "regular"),
+  ("bfkad", "regular"),
+  ("bfken", "regular"),
+  ("bfkmd", "regular"),
+  ("bfkmp", "regular"),
+  ("bfkop", "regular"),
+  ("bfkos", "regular"),
+  ("bfkot", "regular"),
+  ("bfkow", "regular"),
+  ("bfler", "regular"),
+  ("bflor", "regular"),
+  ("bfmou", "regular"),
+  ("bfnam", "regular"),
+  ("bfnao", "regular"),
+  ("bfnay", "regular"),
+  ("bfnou", "regular"),
+  ("bfoub", "regular"),
+  ("bfoud", "regular"),
+  ("bfpas", "regular"),
+  ("bfpon", "regular"),
+  ("bfsen", "regular"),
+  ("bfsis", "regular"),
+  ("bfsmt", "regular"),
+  ("bfsng", "regular"),
+  ("bfsom", "regular"),
+  ("bfsor", "regular"),
+  ("bftap", "regular"),
+  ("bftui", "regular"),
+  ("bfyag", "regular"),
+  ("bfyat", "regular"),
+  ("bfzir", "regular"),
+  ("bfzon", "regular"),
+  ("bfzou", "regular"),
+  ("bfzzzz", "unknown"),
+  ("bg01", "regular"),
+  ("bg02", "regular"),
+  ("bg03", "regular"),
+  ("bg04", "regular"),
+  ("bg05", "regular"),
+  ("bg06", "regular"),
+  ("bg07", "regular"),
+  ("bg08", "regular"),
+  ("bg09", "regular"),
+  ("bg10", "regular"),
+  ("bg11", "regular"),
+  ("bg12", "regular"),
+  ("bg13", "regular"),
+  ("bg14", "regular"),
+  ("bg15", "regular"),
+  ("bg16", "regular"),
+  ("bg17", "regular"),
+  ("bg18", "regular"),
+  ("bg19", "regular"),
+  ("bg20", "regular"),
+  ("bg21", "regular"),
+  ("bg22", "regular"),
+  ("bg23", "regular"),
+  ("bg24", "regular"),
+  ("bg25", "regular"),
+  ("bg26", "regular"),
+  ("bg27", "regular"),
+  ("bg28", "regular"),
+  ("bgzzzz", "unknown"),
+  ("bh13", "regular"),
+  ("bh14", "regular"),
+  ("bh15", "regular"),
+  ("bh16", "deprecated"),
+  ("bh17", "regular"),
+  ("bhzzzz", "unknown"),
+  ("bibb", "regular"),
+  ("bibl", "regular"),
+  ("bibm", "regular"),
+  ("bibr", "regular"),
+  ("bica", "regular"),
+  ("bici", "regular"),
+  ("bigi", "regular"),
+  ("biki", "regular"),
+  ("bikr", "regular"),
+  ("biky", "regular"),
+  ("bima", "regular"),
+  ("bimu", "regular"),
+  ("bimw", "regular"),
+  ("bimy", "regular"),
+  ("bing", "regular"),
+  ("birm", "regular"),
+  ("birt", "regular"),
+  ("biry", "regular"),
+  ("bizzzz", "unknown"),
+  ("bjak", "regular"),
+  ("bjal", "regular"),
+  ("bjaq", "regular"),
+  ("bjbo", "regular"),
+  ("bjco", "regular"),
+  ("bjdo", "regular"),
+  ("bjko", "regular"),
+  ("bjli", "regular"),
+  ("bjmo", "regular"),
+  ("bjou", "regular"),
+  ("bjpl", "regular"),
+  ("bjzo", "regular"),
+  ("bjzzzz", "unknown"),
+  ("blzzzz", "unknown"),
+  ("bmzzzz", "unknown"),
+  ("bnbe", "regular"),
+  ("bnbm", "regular"),
+  ("bnte", "regular"),
+  ("bntu", "regular"),
+  ("bnzzzz", "unknown"),
+  ("bob", "regular"),
+  ("boc", "regular"),
+  ("boh", "regular"),
+  ("bol", "regular"),
+  ("bon", "regular"),
+  ("boo", "regular"),
+  ("bop", "regular"),
+  ("bos", "regular"),
+  ("bot", "regular"),
+  ("bozzzz", "unknown"),
+  ("bqbo", "regular"),
+  ("bqsa", "regular"),
+  ("bqse", "regular"),
+  ("bqzzzz", "unknown"),
+  ("brac", "regular"),
+  ("bral", "regular"),
+  ("bram", "regular"),
+  ("brap", "regular"),
+  ("brba", "regular"),
+  ("brce", "regular"),
+  ("brdf", "regular"),
+  ("bres", "regular"),
+  ("brgo", "regular"),
+  ("brma", "regular"),
+  ("brmg", "regular"),
+  ("brms", "regular"),
+  ("brmt", "regular"),
+  ("brpa", "regular"),
+  ("brpb", "regular"),
+  ("brpe", "regular"),
+  ("brpi", "regular"),
+  ("brpr", "regular"),
+  ("brrj", "regular"),
+  ("brrn", "regular"),
+  ("brro", "regular"),
+  ("brrr", "regular"),
+  ("brrs", "regular"),
+  ("brsc", "regular"),
+  ("brse", "regular"),
+  ("brsp", "regular"),
+  ("brto", "regular"),
+  ("brzzzz", "unknown"),
+  ("bsak", "regular"),
+  ("bsbi", "regular"),
+  ("bsbp", "regular"),
+  ("bsby", "regular"),
+  ("bsce", "regular"),
+  ("bsci", "regular"),
+  ("bsck", "regular"),
+  ("bsco", "regular"),
+  ("bscs", "regular"),
+  ("bseg", "regular"),
+  ("bsex", "regular"),
+  ("bsfp", "regular"),
+  ("bsgc", "regular"),
+  ("bshi", "regular"),
+  ("bsht", "regular"),
+  ("bsin", "regular"),
+  ("bsli", "regular"),
+  ("bsmc", "regular"),
+  ("bsmg", "regular"),
+  ("bsmi", "regular"),
+  ("bsne", "regular"),
+  ("bsno", "regular"),
+  ("bsnp", "regular"),
+  ("bsns", "regular"),
+  ("bsrc", "regular"),
+  ("bsri", "regular"),
+  ("bssa", "regular"),
+  ("bsse", "regular"),
+  ("bsso", "regular"),
+  ("bsss", "regular"),
+  ("bssw", "regular"),
+  ("bswg", "regular"),
+  ("bszzzz", "unknown"),
+  ("bt11", "regular"),
+  ("bt12", "regular"),
+  ("bt13", "regular"),
+  ("bt14", "regular"),
+  ("bt15", "regular"),
+  ("bt21", "regular"),
+  ("bt22", "regular"),
+  ("bt23", "regular"),
+  ("bt24", "regular"),
+  ("bt31", "regular"),
+  ("bt32", "regular"),
+  ("bt33", "regular"),
+  ("bt34", "regular"),
+  ("bt41", "regular"),
+  ("bt42", "regular"),
+  ("bt43", "regular"),
+  ("bt44", "regular"),
+  ("bt45", "regular"),
+  ("btga", "regular"),
+  ("btty", "regular"),
+  ("btzzzz", "unknown"),
+  ("bvzzzz", "unknown"),
+  ("bwce", "regular"),
+  ("bwch", "regular"),
+  ("bwfr", "regular"),
+  ("bwga", "regular"),
+  ("bwgh", "regular"),
+  ("bwjw", "regular"),
+  ("bwkg", "regular"),
+  ("bwkl", "regular"),
+  ("bwkw", "regular"),
+  ("bwlo", "regular"),
+  ("bwne", "regular"),
+  ("bwnw", "regular"),
+  ("bwse", "regular"),
+  ("bwso", "regular"),
+  ("bwsp", "regular"),
+  ("bwst", "regular"),
+  ("bwzzzz", "unknown"),
+  ("bybr", "regular"),
+  ("byhm", "regular"),
+  ("byho", "regular"),
+  ("byhr", "regular"),
+  ("byma", "regular"),
+  ("bymi", "regular"),
+  ("byvi", "regular"),
+  ("byzzzz", "unknown"),
+  ("bzbz", "regular"),
+  ("bzcy", "regular"),
+  ("bzczl", "regular"),
+  ("bzow", "regular"),
+  ("bzsc", "regular"),
+  ("bztol", "regular"),
+  ("bzzzzz", "unknown"),
+  ("caab", "regular"),
+  ("cabc", "regular"),
+  ("camb", "regular"),
+  ("canb", "regular"),
+  ("canl", "regular"),
+  ("cans", "regular"),
+  ("cant", "regular"),
+  ("canu", "regular"),
+  ("caon", "regular"),
+  ("cape", "regular"),
+  ("caqc", "regular"),
+  ("cask", "regular"),
+  ("cayt", "regular"),
+  ("cazzzz", "unknown"),
+  ("cczzzz", "unknown"),
+  ("cdbc", "regular"),
+  ("cdbn", "deprecated"),
+  ("cdbu", "regular"),
+  ("cdeq", "regular"),
+  ("cdhk", "regular"),
+  ("cdhl", "regular"),
+  ("cdhu", "regular"),
+  ("cdit", "regular"),
+  ("cdka", "deprecated"),
+  ("cdkc", "regular"),
+  ("cdke", "regular"),
+  ("cdkg", "regular"),
+  ("cdkl", "regular"),
+  ("cdkn", "regular"),
+  ("cdks", "regular"),
+  ("cdkw", "deprecated"),
+  ("cdlo", "regular"),
+  ("cdlu", "regular"),
+  ("cdma", "regular"),
+  ("cdmn", "regular"),
+  ("cdmo", "regular"),
+  ("cdnk", "regular"),
+  ("cdnu", "regular"),
+  ("cdor", "deprecated"),
+  ("cdsa", "regular"),
+  ("cdsk", "regular"),
+  ("cdsu", "regular"),
+  ("cdta", "regular"),
+  ("cdto", "regular"),
+  ("cdtu", "regular"),
+  ("cdzzzz", "unknown"),
+  ("cfac", "regular"),
+  ("cfbb", "regular"),
+  ("cfbgf", "regular"),
+  ("cfbk", "regular"),
+  ("cfhk", "regular"),
+  ("cfhm", "regular"),
+  ("cfhs", "regular"),
+  ("cfkb", "regular"),
+  ("cfkg", "regular"),
+  ("cflb", "regular"),
+  ("cfmb", "regular"),
+  ("cfmp", "regular"),
+  ("cfnm", "regular"),
+  ("cfop", "regular"),
+  ("cfse", "regular"),
+  ("cfuk", "regular"),
+  ("cfvk", "regular"),
+  ("cfzzzz", "unknown"),
+  ("cg11", "regular"),
+  ("cg12", "regular"),
+  ("cg13", "regular"),
+  ("cg14", "regular"),
+  ("cg15", "regular"),
+  ("cg16", "regular"),
+  ("cg2", "regular"),
+  ("cg5", "regular"),
+  ("cg7", "regular"),
+  ("cg8", "regular"),
+  ("cg9", "regular"),
+  ("cgbzv", "regular"),
+  ("cgzzzz", "unknown"),
+  ("chag", "regular"),
+  ("chai", "regular"),
+  ("char", "regular"),
+  ("chbe", "regular"),
+  ("chbl", "regular"),
+  ("chbs", "regular"),
+  ("chfr", "regular"),
+  ("chge", "regular"),
+  ("chgl", "regular"),
+  ("chgr", "regular"),
+  ("chju", "regular"),
+  ("chlu", "regular"),
+  ("chne", "regular"),
+  ("chnw", "regular"),
+  ("chow", "regular"),
+  ("chsg", "regular"),
+  ("chsh", "regular"),
+  ("chso", "regular"),
+  ("chsz", "regular"),
+  ("chtg", "regular"),
+  ("chti", "regular"),
+  ("chur", "regular"),
+  ("chvd", "regular"),
+  ("chvs", "regular"),
+  ("chzg", "regular"),
+  ("chzh", "regular"),
+  ("chzzzz", "unknown"),
+  ("ci01", "deprecated"),
+  ("ci02", "deprecated"),
+  ("ci03", "deprecated"),
+  ("ci04", "deprecated"),
+  ("ci05", "deprecated"),
+  ("ci06", "deprecated"),
+  ("ci07", "deprecated"),
+  ("ci08", "deprecated"),
+  ("ci09", "deprecated"),
+  ("ci10", "deprecated"),
+  ("ci11", "deprecated"),
+  ("ci12", "deprecated"),
+  ("ci13", "deprecated"),
+  ("ci14", "deprecated"),
+  ("ci15", "deprecated"),
+  ("ci16", "deprecated"),
+  ("ci17", "deprecated"),
+  ("ci18", "deprecated"),
+  ("ci19", "deprecated"),
+  ("ciab", "regular"),
+  ("cibs", "regular"),
+  ("cicm", "regular"),
+  ("cidn", "regular"),
+  ("cigd", "regular"),
+  ("cilc", "regular"),
+  ("cilg", "regular"),
+  ("cimg", "regular"),
+  ("cism", "regular"),
+  ("cisv", "regular"),
+  ("civb", "regular"),
+  ("ciwr", "regular"),
+  ("ciym", "regular"),
+  ("cizz", "regular"),
+  ("cizzzz", "unknown"),
+  ("ckzzzz", "unknown"),
+  ("clai", "regular"),
+  ("clan", "regular"),
+  ("clap", "regular"),
+  ("clar", "regular"),
+  ("clat", "regular"),
+  ("clbi", "regular"),
+  ("clco", "regular"),
+  ("clli", "regular"),
+  ("clll", "regular"),
+  ("cllr", "regular"),
+  ("clma", "regular"),
+  ("clml", "regular"),
+  ("clnb", "regular"),
+  ("clrm", "regular"),
+  ("clta", "regular"),
+  ("clvs", "regular"),
+  ("clzzzz", "unknown"),
+  ("cmad", "regular"),
+  ("cmce", "regular"),
+  ("cmen", "regular"),
+  ("cmes", "regular"),
+  ("cmlt", "regular"),
+  ("cmno", "regular"),
+  ("cmnw", "regular"),
+  ("cmou", "regular"),
+  ("cmsu", "regular"),
+  ("cmsw", "regular"),
+  ("cmzzzz", "unknown"),
+  ("cn11", "deprecated"),
+  ("cn12", "deprecated"),
+  ("cn13", "deprecated"),
+  ("cn14", "deprecated"),
+  ("cn15", "deprecated"),
+  ("cn21", "deprecated"),
+  ("cn22", "deprecated"),
+  ("cn23", "deprecated"),
+  ("cn31", "deprecated"),
+  ("cn32", "deprecated"),
+  ("cn33", "deprecated"),
+  ("cn34", "deprecated"),
+  ("cn35", "deprecated"),
+  ("cn36", "deprecated"),
+  ("cn37", "deprecated"),
+  ("cn41", "deprecated"),
+  ("cn42", "deprecated"),
+  ("cn43", "deprecated"),
+  ("cn44", "deprecated"),
+  ("cn45", "deprecated"),
+  ("cn46", "deprecated"),
+  ("cn50", "deprecated"),
+  ("cn51", "deprecated"),
+  ("cn52", "deprecated"),
+  ("cn53", "deprecated"),
+  ("cn54", "deprecated"),
+  ("cn61", "deprecated"),
+  ("cn62", "deprecated"),
+  ("cn63", "deprecated"),
+  ("cn64", "deprecated"),
+  ("cn65", "deprecated"),
+  ("cn71", "deprecated"),
+  ("cn91", "deprecated"),
+  ("cn92", "deprecated"),
+  ("cnah", "regular"),
+  ("cnbj", "regular"),
+  ("cncq", "regular"),
+  ("cnfj", "regular"),
+  ("cngd", "regular"),
+  ("cngs", "regular"),
+  ("cngx", "regular"),
+  ("cngz", "regular"),
+  ("cnha", "regular"),
+  ("cnhb", "regular"),
+  ("cnhe", "regular"),
+  ("cnhi", "regular"),
+  ("cnhk", "regular"),
+  ("cnhl", "regular"),
+  ("cnhn", "regular"),
+  ("cnjl", "regular"),
+  ("cnjs", "regular"),
+  ("cnjx", "regular"),
+  ("cnln", "regular"),
+  ("cnmo", "regular"),
+  ("cnnm", "regular"),
+  ("cnnx", "regular"),
+  ("cnqh", "regular"),
+  ("cnsc", "regular"),
+  ("cnsd", "regular"),
+  ("cnsh", "regular"),
+  ("cnsn", "regular"),
+  ("cnsx", "regular"),
+  ("cntj", "regular"),
+  ("cntw", "regular"),
+  ("cnxj", "regular"),
+  ("cnxz", "regular"),
+  ("cnyn", "regular"),
+  ("cnzj", "regular"),
+  ("cnzzzz", "unknown"),
+  ("coama", "regular"),
+  ("coant", "regular"),
+  ("coara", "regular"),
+  ("coatl", "regular"),
+  ("cobol", "regular"),
+  ("coboy", "regular"),
+  ("cocal", "regular"),
+  ("cocaq", "regular"),
+  ("cocas", "regular"),
+  ("cocau", "regular"),
+  ("coces", "regular"),
+  ("cocho", "regular"),
+  ("cocor", "regular"),
+  ("cocun", "regular"),
+  ("codc", "regular"),
+  ("cogua", "regular"),
+  ("coguv", "regular"),
+  ("cohui", "regular"),
+  ("colag", "regular"),
+  ("comag", "regular"),
+  ("comet", "regular"),
+  ("conar", "regular"),
+  ("consa", "regular"),
+  ("coput", "regular"),
+  ("coqui", "regular"),
+  ("coris", "regular"),
+  ("cosan", "regular"),
+  ("cosap", "regular"),
+  ("cosuc", "regular"),
+  ("cotol", "regular"),
+  ("covac", "regular"),
+  ("covau", "regular"),
+  ("covid", "regular"),
+  ("cozzzz", "unknown"),
+  ("cpzzzz", "unknown"),
+  ("cqzzzz", "unknown"),
+  ("cra", "regular"),
+  ("crc", "regular"),
+  ("crg", "regular"),
+  ("crh", "regular"),
+  ("crl", "regular"),
+  ("crp", "regular"),
+  ("crsj", "regular"),
+  ("crzzzz", "unknown"),
+  ("cu01", "regular"),
+  ("cu03", "regular"),
+  ("cu04", "regular"),
+  ("cu05", "regular"),
+  ("cu06", "regular"),
+  ("cu07", "regular"),
+  ("cu08", "regular"),
+  ("cu09", "regular"),
+  ("cu10", "regular"),
+  ("cu11", "regular"),
+  ("cu12", "regular"),
+  ("cu13", "regular"),
+  ("cu14", "regular"),
+  ("cu15", "regular"),
+  ("cu16", "regular"),
+  ("cu99", "regular"),
+  ("cuzzzz", "unknown"),
+  ("cvb", "regular"),
+  ("cvbr", "regular"),
+  ("cvbv", "regular"),
+  ("cvca", "regular"),
+  ("cvcf", "regular"),
+  ("cvcr", "regular"),
+  ("cvma", "regular"),
+  ("cvmo", "regular"),
+  ("cvpa", "regular"),
+  ("cvpn", "regular"),
+  ("cvpr", "regular"),
+  ("cvrb", "regular"),
+  ("cvrg", "regular"),
+  ("cvrs", "regular"),
+  ("cvs", "regular"),
+  ("cvsd", "regular"),
+  ("cvsf", "regular"),
+  ("cvsl", "regular"),
+  ("cvsm", "regular"),
+  ("cvso", "regular"),
+  ("cvss", "regular"),
+  ("cvsv", "regular"),
+  ("cvta", "regular"),
+  ("cvts", "regular"),
+  ("cvzzzz", "unknown"),
+  ("cwzzzz", "unknown"),
+  ("cxzzzz", "unknown"),
+  ("cy01", "regular"),
+  ("cy02", "regular"),
+  ("cy03", "regular"),
+  ("cy04", "regular"),
+  ("cy05", "regular"),
+  ("cy06", "regular"),
+  ("cyzzzz", "unknown"),
+  ("cz10", "regular"),
+  ("cz101", "deprecated"),
+  ("cz102", "deprecated"),
+  ("cz103", "deprecated"),
+  ("cz104", "deprecated"),
+  ("cz105", "deprecated"),
+  ("cz106", "deprecated"),
+  ("cz107", "deprecated"),
+  ("cz108", "deprecated"),
+  ("cz109", "deprecated"),
+  ("cz10a", "deprecated"),
+  ("cz10b", "deprecated"),
+  ("cz10c", "deprecated"),
+  ("cz10d", "deprecated"),
+  ("cz10e", "deprecated"),
+  ("cz10f", "deprecated"),
+  ("cz110", "deprecated"),
+  ("cz111", "deprecated"),
+  ("cz112", "deprecated"),
+  ("cz113", "deprecated"),
+  ("cz114", "deprecated"),
+  ("cz115", "deprecated"),
+  ("cz116", "deprecated"),
+  ("cz117", "deprecated"),
+  ("cz118", "deprecated"),
+  ("cz119", "deprecated"),
+  ("cz120", "deprecated"),
+  ("cz121", "deprecated"),
+  ("cz122", "deprecated"),
+  ("cz20", "regular"),
+  ("cz201", "regular"),
+  ("cz202", "regular"),
+  ("cz203", "regular"),
+  ("cz204", "regular"),
+  ("cz205", "regular"),
+  ("cz206", "regular"),
+  ("cz207", "regular"),
+  ("cz208", "regular"),
+  ("cz209", "regular"),
+  ("cz20a", "regular"),
+  ("cz20b", "regular"),
+  ("cz20c", "regular"),
+  ("cz31", "regular"),
+  ("cz311", "regular"),
+  ("cz312", "regular"),
+  ("cz313", "regular"),
+  ("cz314", "regular"),
+  ("cz315", "regular"),
+  ("cz316", "regular"),
+  ("cz317", "regular"),
+  ("cz32", "regular"),
+  ("cz321", "regular"),
+  ("cz322", "regular"),
+  ("cz323", "regular"),
+  ("cz324", "regular"),
+  ("cz325", "regular"),
+  ("cz326", "regular"),
+  ("cz327", "regular"),
+  ("cz41", "regular"),
+  ("cz411", "regular"),
+  ("cz412", "regular"),
+  ("cz413", "regular"),
+  ("cz42", "regular"),
+  ("cz421", "regular"),
+  ("cz422", "regular"),
+  ("cz423", "regular"),
+  ("cz424", "regular"),
+  ("cz425", "regular"),
+  ("cz426", "regular"),
+  ("cz427", "regular"),
+  ("cz51", "regular"),
+  ("cz511", "regular"),
+  ("cz512", "regular"),
+  ("cz513", "regular"),
+  ("cz514", "regular"),
+  ("cz52", "regular"),
+  ("cz521", "regular"),
+  ("cz522", "regular"),
+  ("cz523", "regular"),
+  ("cz524", "regular"),
+  ("cz525", "regular"),
+  ("cz53", "regular"),
+  ("cz531", "regular"),
+  ("cz532", "regular"),
+  ("cz533", "regular"),
+  ("cz534", "regular"),
+  ("cz611", "deprecated"),
+  ("cz612", "deprecated"),
+  ("cz613", "deprecated"),
+  ("cz614", "deprecated"),
+  ("cz615", "deprecated"),
+  ("cz621", "deprecated"),
+  ("cz622", "deprecated"),
+  ("cz623", "deprecated"),
+  ("cz624", "deprecated"),
+  ("cz625", "deprecated"),
+  ("cz626", "deprecated"),
+  ("cz627", "deprecated"),
+  ("cz63", "regular"),
+  ("cz631", "regular"),
+  ("cz632", "regular"),
+  ("cz633", "regular"),
+  ("cz634", "regular"),
+  ("cz635", "regular"),
+  ("cz64", "regular"),
+  ("cz641", "regular"),
+  ("cz642", "regular"),
+  ("cz643", "regular"),
+  ("cz644", "regular"),
+  ("cz645", "regular"),
+  ("cz646", "regular"),
+  ("cz647", "regular"),
+  ("cz71", "regular"),
+  ("cz711", "regular"),
+  ("cz712", "regular"),
+  ("cz713", "regular"),
+  ("cz714", "regular"),
+  ("cz715", "regular"),
+  ("cz72", "regular"),
+  ("cz721", "regular"),
+  ("cz722", "regular"),
+  ("cz723", "regular"),
+  ("cz724", "regular"),
+  ("cz80", "regular"),
+  ("cz801", "regular"),
+  ("cz802", "regular"),
+  ("cz803", "regular"),
+  ("cz804", "regular"),
+  ("cz805", "regular"),
+  ("cz806", "regular"),
+  ("czjc", "deprecated"),
+  ("czjm", "deprecated"),
+  ("czka", "deprecated"),
+  ("czkr", "deprecated"),
+  ("czli", "deprecated"),
+  ("czmo", "deprecated"),
+  ("czol", "deprecated"),
+  ("czpa", "deprecated"),
+  ("czpl", "deprecated"),
+  ("czpr", "deprecated"),
+  ("czst", "deprecated"),
+  ("czus", "deprecated"),
+  ("czvy", "deprecated"),
+  ("czzl", "deprecated"),
+  ("czzzzz", "unknown"),
+  ("debb", "regular"),
+  ("debe", "regular"),
+  ("debw", "regular"),
+  ("deby", "regular"),
+  ("dehb", "regular"),
+  ("dehe", "regular"),
+  ("dehh", "regular"),
+  ("demv", "regular"),
+  ("deni", "regular"),
+  ("denw", "regular"),
+  ("derp", "regular"),
+  ("desh", "regular"),
+  ("desl", "regular"),
+  ("desn", "regular"),
+  ("dest", "regular"),
+  ("deth", "regular"),
+  ("dezzzz", "unknown"),
+  ("dgzzzz", "unknown"),
+  ("djar", "regular"),
+  ("djas", "regular"),
+  ("djdi", "regular"),
+  ("djdj", "regular"),
+  ("djob", "regular"),
+  ("djta", "regular"),
+  ("djzzzz", "unknown"),
+  ("dk81", "regular"),
+  ("dk82", "regular"),
+  ("dk83", "regular"),
+  ("dk84", "regular"),
+  ("dk85", "regular"),
+  ("dkzzzz", "unknown"),
+  ("dm02", "regular"),
+  ("dm03", "regular"),
+  ("dm04", "regular"),
+  ("dm05", "regular"),
+  ("dm06", "regular"),
+  ("dm07", "regular"),
+  ("dm08", "regular"),
+  ("dm09", "regular"),
+  ("dm10", "regular"),
+  ("dm11", "regular"),
+  ("dmzzzz", "unknown"),
+  ("do01", "regular"),
+  ("do02", "regular"),
+  ("do03", "regular"),
+  ("do04", "regular"),
+  ("do05", "regular"),
+  ("do06", "regular"),
+  ("do07", "regular"),
+  ("do08", "regular"),
+  ("do09", "regular"),
+  ("do10", "regular"),
+  ("do11", "regular"),
+  ("do12", "regular"),
+  ("do13", "regular"),
+  ("do14", "regular"),
+  ("do15", "regular"),
+  ("do16", "regular"),
+  ("do17", "regular"),
+  ("do18", "regular"),
+  ("do19", "regular"),
+  ("do20", "regular"),
+  ("do21", "regular"),
+  ("do22", "regular"),
+  ("do23", "regular"),
+  ("do24", "regular"),
+  ("do25", "regular"),
+  ("do26", "regular"),
+  ("do27", "regular"),
+  ("do28", "regular"),
+  ("do29", "regular"),
+  ("do30", "regular"),
+  ("do31", "regular"),
+  ("do32", "regular"),
+  ("do33", "regular"),
+  ("do34", "regular"),
+  ("do35", "regular"),
+  ("do36", "regular"),
+  ("do37", "regular"),
+  ("do38", "regular"),
+  ("do39", "regular"),
+  ("do40", "regular"),
+  ("do41", "regular"),
+  ("do42", "regular"),
+  ("dozzzz", "unknown"),
+  ("dz01", "regular"),
+  ("dz02", "regular"),
+  ("dz03", "regular"),
+  ("dz04", "regular"),
+  ("dz05", "regular"),
+  ("dz06", "regular"),
+  ("dz07", "regular"),
+  ("dz08", "regular"),
+  ("dz09", "regular"),
+  ("dz10", "regular"),
+  ("dz11", "regular"),
+  ("dz12", "regular"),
+  ("dz13", "regular"),
+  ("dz14", "regular"),
+  ("dz15", "regular"),
+  ("dz16", "regular"),
+  ("dz17", "regular"),
+  ("dz18", "regular"),
+  ("dz19", "regular"),
+  ("dz20", "regular"),
+  ("dz21", "regular"),
+  ("dz22", "regular"),
+  ("dz23", "regular"),
+  ("dz24", "regular"),
+  ("dz25", "regular"),
+  ("dz26", "regular"),
+  ("dz27", "regular"),
+  ("dz28", "regular"),
+  ("dz29", "regular"),
+  ("dz30", "regular"),
+  ("dz31", "regular"),
+  ("dz32", "regular"),
+  ("dz33", "regular"),
+  ("dz34", "regular"),
+  ("dz35", "regular"),
+  ("dz36", "regular"),
+  ("dz37", "regular"),
+  ("dz38", "regular"),
+  ("dz39", "regular"),
+  ("dz40", "regular"),
+  ("dz41", "regular"),
+  ("dz42", "regular"),
+  ("dz43", "regular"),
+  ("dz44", "regular"),
+  ("dz45", "regular"),
+  ("dz46", "regular"),
+  ("dz47", "regular"),
+  ("dz48", "regular"),
+  ("dzzzzz", "unknown"),
+  ("eazzzz", "unknown"),
+  ("eca", "regular"),
+  ("ecb", "regular"),
+  ("ecc", "regular"),
+  ("ecd", "regular"),
+  ("ece", "regular"),
+  ("ecf", "regular"),
+  ("ecg", "regular"),
+  ("ech", "regular"),
+  ("eci", "regular"),
+  ("ecl", "regular"),
+  ("ecm", "regular"),
+  ("ecn", "regular"),
+  ("eco", "regular"),
+  ("ecp", "regular"),
+  ("ecr", "regular"),
+  ("ecs", "regular"),
+  ("ecsd", "regular"),
+  ("ecse", "regular"),
+  ("ect", "regular"),
+  ("ecu", "regular"),
+  ("ecw", "regular"),
+  ("ecx", "regular"),
+  ("ecy", "regular"),
+  ("ecz", "regular"),
+  ("eczzzz", "unknown"),
+  ("ee130", "regular"),
+  ("ee141", "regular"),
+  ("ee142", "regular"),
+  ("ee171", "regular"),
+  ("ee184", "regular"),
+  ("ee191", "regular"),
+  ("ee198", "regular"),
+  ("ee205", "regular"),
+  ("ee214", "regular"),
+  ("ee245", "regular"),
+  ("ee247", "regular"),
+  ("ee251", "regular"),
+  ("ee255", "regular"),
+  ("ee272", "regular"),
+  ("ee283", "regular"),
+  ("ee284", "regular"),
+  ("ee291", "regular"),
+  ("ee293", "regular"),
+  ("ee296", "regular"),
+  ("ee303", "regular"),
+  ("ee305", "regular"),
+  ("ee317", "regular"),
+  ("ee321", "regular"),
+  ("ee338", "regular"),
+  ("ee353", "regular"),
+  ("ee37", "regular"),
+  ("ee39", "regular"),
+  ("ee424", "regular"),
+  ("ee430", "regular"),
+  ("ee431", "regular"),
+  ("ee432", "regular"),
+  ("ee44", "deprecated"),
+  ("ee441", "regular"),
+  ("ee442", "regular"),
+  ("ee446", "regular"),
+  ("ee45", "regular"),
+  ("ee478", "regular"),
+  ("ee480", "regular"),
+  ("ee486", "regular"),
+  ("ee49", "deprecated"),
+  ("ee50", "regular"),
+  ("ee503", "regular"),
+  ("ee51", "deprecated"),
+  ("ee511", "regular"),
+  ("ee514", "regular"),
+  ("ee52", "regular"),
+  ("ee528", "regular"),
+  ("ee557", "regular"),
+  ("ee56", "regular"),
+  ("ee567", "regular"),
+  ("ee57", "deprecated"),
+  ("ee586", "regular"),
+  ("ee59", "deprecated"),
+  ("ee60", "regular"),
+  ("ee615", "regular"),
+  ("ee618", "regular"),
+  ("ee622", "regular"),
+  ("ee624", "regular"),
+  ("ee638", "regular"),
+  ("ee64", "regular"),
+  ("ee65", "deprecated"),
+  ("ee651", "regular"),
+  ("ee653", "regular"),
+  ("ee661", "regular"),
+  ("ee663", "regular"),
+  ("ee668", "regular"),
+  ("ee67", "deprecated"),
+  ("ee68", "regular"),
+  ("ee689", "regular"),
+  ("ee698", "regular"),
+  ("ee70", "deprecated"),
+  ("ee708", "regular"),
+  ("ee71", "regular"),
+  ("ee712", "regular"),
+  ("ee714", "regular"),
+  ("ee719", "regular"),
+  ("ee726", "regular"),
+  ("ee732", "regular"),
+  ("ee735", "regular"),
+  ("ee74", "regular"),
+  ("ee78", "deprecated"),
+  ("ee784", "regular"),
+  ("ee79", "regular"),
+  ("ee792", "regular"),
+  ("ee793", "regular"),
+  ("ee796", "regular"),
+  ("ee803", "regular"),
+  ("ee809", "regular"),
+  ("ee81", "regular"),
+  ("ee82", "deprecated"),
+  ("ee824", "regular"),
+  ("ee834", "regular"),
+  ("ee84", "regular"),
+  ("ee855", "regular"),
+  ("ee86", "deprecated"),
+  ("ee87", "regular"),
+  ("ee890", "regular"),
+  ("ee897", "regular"),
+  ("ee899", "regular"),
+  ("ee901", "regular"),
+  ("ee903", "regular"),
+  ("ee907", "regular"),
+  ("ee917", "regular"),
+  ("ee919", "regular"),
+  ("ee928", "regular"),
+  ("eezzzz", "unknown"),
+  ("egalx", "regular"),
+  ("egasn", "regular"),
+  ("egast", "regular"),
+  ("egba", "regular"),
+  ("egbh", "regular"),
+  ("egbns", "regular"),
+  ("egc", "regular"),
+  ("egdk", "regular"),
+  ("egdt", "regular"),
+  ("egfym", "regular"),
+  ("eggh", "regular"),
+  ("eggz", "regular"),
+  ("egis", "regular"),
+  ("egjs", "regular"),
+  ("egkb", "regular"),
+  ("egkfs", "regular"),
+  ("egkn", "regular"),
+  ("eglx", "regular"),
+  ("egmn", "regular"),
+  ("egmnf", "regular"),
+  ("egmt", "regular"),
+  ("egpts", "regular"),
+  ("egshg", "regular"),
+  ("egshr", "regular"),
+  ("egsin", "regular"),
+  ("egsuz", "regular"),
+  ("egwad", "regular"),
+  ("egzzzz", "unknown"),
+  ("ehzzzz", "unknown"),
+  ("eran", "regular"),
+  ("erdk", "regular"),
+  ("erdu", "regular"),
+  ("ergb", "regular"),
+  ("erma", "regular"),
+  ("ersk", "regular"),
+  ("erzzzz", "unknown"),
+  ("esa", "regular"),
+  ("esab", "regular"),
+  ("esal", "regular"),
+  ("esan", "regular"),
+  ("esar", "regular"),
+  ("esas", "regular"),
+  ("esav", "regular"),
+  ("esb", "regular"),
+  ("esba", "regular"),
+  ("esbi", "regular"),
+  ("esbu", "regular"),
+  ("esc", "regular"),
+  ("esca", "regular"),
+  ("escb", "regular"),
+  ("escc", "regular"),
+  ("esce", "regular"),
+  ("escl", "regular"),
+  ("escm", "regular"),
+  ("escn", "regular"),
+  ("esco", "regular"),
+  ("escr", "regular"),
+  ("escs", "regular"),
+  ("esct", "regular"),
+  ("escu", "regular"),
+  ("esex", "regular"),
+  ("esga", "regular"),
+  ("esgc", "regular"),
+  ("esgi", "regular"),
+  ("esgr", "regular"),
+  ("esgu", "regular"),
+  ("esh", "regular"),
+  ("eshu", "regular"),
+  ("esib", "regular"),
+  ("esj", "regular"),
+  ("esl", "regular"),
+  ("esle", "regular"),
+  ("eslo", "regular"),
+  ("eslu", "regular"),
+  ("esm", "regular"),
+  ("esma", "regular"),
+  ("esmc", "regular"),
+  ("esmd", "regular"),
+  ("esml", "regular"),
+  ("esmu", "regular"),
+  ("esna", "regular"),
+  ("esnc", "regular"),
+  ("eso", "regular"),
+  ("esor", "regular"),
+  ("esp", "regular"),
+  ("espm", "regular"),
+  ("espo", "regular"),
+  ("espv", "regular"),
+  ("esri", "regular"),
+  ("ess", "regular"),
+  ("essa", "regular"),
+  ("esse", "regular"),
+  ("essg", "regular"),
+  ("esso", "regular"),
+  ("esss", "regular"),
+  ("est", "regular"),
+  ("este", "regular"),
+  ("estf", "regular"),
+  ("esto", "regular"),
+  ("esv", "regular"),
+  ("esva", "regular"),
+  ("esvc", "regular"),
+  ("esvi", "regular"),
+  ("esz", "regular"),
+  ("esza", "regular"),
+  ("eszzzz", "unknown"),
+  ("etaa", "regular"),
+  ("etaf", "regular"),
+  ("etam", "regular"),
+  ("etbe", "regular"),
+  ("etdd", "regular"),
+  ("etga", "regular"),
+  ("etha", "regular"),
+  ("etor", "regular"),
+  ("etsi", "regular"),
+  ("etsn", "regular"),
+  ("etso", "regular"),
+  ("etti", "regular"),
+  ("etzzzz", "unknown"),
+  ("fi01", "deprecated"),
+  ("fi02", "regular"),
+  ("fi03", "regular"),
+  ("fi04", "regular"),
+  ("fi05", "regular"),
+  ("fi06", "regular"),
+  ("fi07", "regular"),
+  ("fi08", "regular"),
+  ("fi09", "regular"),
+  ("fi10", "regular"),
+  ("fi11", "regular"),
+  ("fi12", "regular"),
+  ("fi13", "regular"),
+  ("fi14", "regular"),
+  ("fi15", "regular"),
+  ("fi16", "regular"),
+  ("fi17", "regular"),
+  ("fi18", "regular"),
+  ("fi19", "regular"),
+  ("fizzzz", "unknown"),
+  ("fj01", "regular"),
+  ("fj02", "regular"),
+  ("fj03", "regular"),
+  ("fj04", "regular"),
+  ("fj05", "regular"),
+  ("fj06", "regular"),
+  ("fj07", "regular"),
+  ("fj08", "regular"),
+  ("fj09", "regular"),
+  ("fj10", "regular"),
+  ("fj11", "regular"),
+  ("fj12", "regular"),
+  ("fj13", "regular"),
+  ("fj14", "regular"),
+  ("fjc", "regular"),
+  ("fje", "regular"),
+  ("fjn", "regular"),
+  ("fjr", "regular"),
+  ("fjw", "regular"),
+  ("fjzzzz", "unknown"),
+  ("fkzzzz", "unknown"),
+  ("fmksa", "regular"),
+  ("fmpni", "regular"),
+  ("fmtrk", "regular"),
+  ("fmyap", "regular"),
+  ("fmzzzz", "unknown"),
+  ("fozzzz", "unknown"),
+  ("fr01", "regular"),
+  ("fr02", "regular"),
+  ("fr03", "regular"),
+  ("fr04", "regular"),
+  ("fr05", "regular"),
+  ("fr06", "regular"),
+  ("fr07", "regular"),
+  ("fr08", "regular"),
+  ("fr09", "regular"),
+  ("fr10", "regular"),
+  ("fr11", "regular"),
+  ("fr12", "regular"),
+  ("fr13", "regular"),
+  ("fr14", "regular"),
+  ("fr15", "regular"),
+  ("fr16", "regular"),
+  ("fr17", "regular"),
+  ("fr18", "regular"),
+  ("fr19", "regular"),
+  ("fr20r", "regular"),
+  ("fr21", "regular"),
+  ("fr22", "regular"),
+  ("fr23", "regular"),
+  ("fr24", "regular"),
+  ("fr25", "regular"),
+  ("fr26", "regular"),
+  ("fr27", "regular"),
+  ("fr28", "regular"),
+  ("fr29", "regular"),
+  ("fr2a", "regular"),
+  ("fr2b", "regular"),
+  ("fr30", "regular"),
+  ("fr31", "regular"),
+  ("fr32", "regular"),
+  ("fr33", "regular"),
+  ("fr34", "regular"),
+  ("fr35", "regular"),
+  ("fr36", "regular"),
+  ("fr37", "regular"),
+  ("fr38", "regular"),
+  ("fr39", "regular"),
+  ("fr40", "regular"),
+  ("fr41", "regular"),
+  ("fr42", "regular"),
+  ("fr43", "regular"),
+  ("fr44", "regular"),
+  ("fr45", "regular"),
+  ("fr46", "regular"),
+  ("fr47", "regular"),
+  ("fr48", "regular"),
+  ("fr49", "regular"),
+  ("fr50", "regular"),
+  ("fr51", "regular"),
+  ("fr52", "regular"),
+  ("fr53", "regular"),
+  ("fr54", "regular"),
+  ("fr55", "regular"),
+  ("fr56", "regular"),
+  ("fr57", "regular"),
+  ("fr58", "regular"),
+  ("fr59", "regular"),
+  ("fr60", "regular"),
+  ("fr61", "regular"),
+  ("fr62", "regular"),
+  ("fr63", "regular"),
+  ("fr64", "regular"),
+  ("fr65", "regular"),
+  ("fr66", "regular"),
+  ("fr67", "regular"),
+  ("fr68", "regular"),
+  ("fr69", "regular"),
+  ("fr69m", "regular"),
+  ("fr6ae", "regular"),
+  ("fr70", "regular"),
+  ("fr71", "regular"),
+  ("fr72", "regular"),
+  ("fr73", "regular"),
+  ("fr74", "regular"),
+  ("fr75", "deprecated"),
+  ("fr75c", "regular"),
+  ("fr76", "regular"),
+  ("fr77", "regular"),
+  ("fr78", "regular"),
+  ("fr79", "regular"),
+  ("fr80", "regular"),
+  ("fr81", "regular"),
+  ("fr82", "regular"),
+  ("fr83", "regular"),
+  ("fr84", "regular"),
+  ("fr85", "regular"),
+  ("fr86", "regular"),
+  ("fr87", "regular"),
+  ("fr88", "regular"),
+  ("fr89", "regular"),
+  ("fr90", "regular"),
+  ("fr91", "regular"),
+  ("fr92", "regular"),
+  ("fr93", "regular"),
+  ("fr94", "regular"),
+  ("fr95", "regular"),
+  ("fr971", "regular"),
+  ("fr972", "regular"),
+  ("fr973", "regular"),
+  ("fr974", "regular"),
+  ("fr976", "regular"),
+  ("fra", "deprecated"),
+  ("frara", "regular"),
+  ("frb", "deprecated"),
+  ("frbfc", "regular"),
+  ("frbl", "deprecated"),
+  ("frbre", "regular"),
+  ("frc", "deprecated"),
+  ("frcor", "deprecated"),
+  ("frcp", "deprecated"),
+  ("frcvl", "regular"),
+  ("frd", "deprecated"),
+  ("fre", "deprecated"),
+  ("frf", "deprecated"),
+  ("frg", "deprecated"),
+  ("frges", "regular"),
+  ("frgf", "deprecated"),
+  ("frgp", "deprecated"),
+  ("frgua", "deprecated"),
+  ("frh", "deprecated"),
+  ("frhdf", "regular"),
+  ("fri", "deprecated"),
+  ("fridf", "regular"),
+  ("frj", "deprecated"),
+  ("frk", "deprecated"),
+  ("frl", "deprecated"),
+  ("frlre", "deprecated"),
+  ("frm", "deprecated"),
+  ("frmay", "deprecated"),
+  ("frmf", "deprecated"),
+  ("frmq", "deprecated"),
+  ("frn", "deprecated"),
+  ("frnaq", "regular"),
+  ("frnc", "deprecated"),
+  ("frnor", "regular"),
+  ("fro", "deprecated"),
+  ("frocc", "regular"),
+  ("frp", "deprecated"),
+  ("frpac", "regular"),
+  ("frpdl", "regular"),
+  ("frpf", "deprecated"),
+  ("frpm", "deprecated"),
+  ("frq", "deprecated"),
+  ("frr", "deprecated"),
+  ("frre", "deprecated"),
+  ("frs", "deprecated"),
+  ("frt", "deprecated"),
+  ("frtf", "deprecated"),
+  ("fru", "deprecated"),
+  ("frv", "deprecated"),
+  ("frwf", "deprecated"),
+  ("fryt", "deprecated"),
+  ("frzzzz", "unknown"),
+  ("ga1", "regular"),
+  ("ga2", "regular"),
+  ("ga3", "regular"),
+  ("ga4", "regular"),
+  ("ga5", "regular"),
+  ("ga6", "regular"),
+  ("ga7", "regular"),
+  ("ga8", "regular"),
+  ("ga9", "regular"),
+  ("gazzzz", "unknown"),
+  ("gbabc", "regular"),
+  ("gbabd", "regular"),
+  ("gbabe", "regular"),
+  ("gbagb", "regular"),
+  ("gbagy", "regular"),
+  ("gband", "regular"),
+  ("gbann", "regular"),
+  ("gbans", "regular"),
+  ("gbant", "deprecated"),
+  ("gbard", "deprecated"),
+  ("gbarm", "deprecated"),
+  ("gbbas", "regular"),
+  ("gbbbd", "regular"),
+  ("gbbcp", "regular"),
+  ("gbbdf", "regular"),
+  ("gbbdg", "regular"),
+  ("gbben", "regular"),
+  ("gbbex", "regular"),
+  ("gbbfs", "regular"),
+  ("gbbge", "regular"),
+  ("gbbgw", "regular"),
+  ("gbbir", "regular"),
+  ("gbbkm", "regular"),
+  ("gbbla", "deprecated"),
+  ("gbbly", "deprecated"),
+  ("gbbmh", "deprecated"),
+  ("gbbnb", "deprecated"),
+  ("gbbne", "regular"),
+  ("gbbnh", "regular"),
+  ("gbbns", "regular"),
+  ("gbbol", "regular"),
+  ("gbbpl", "regular"),
+  ("gbbrc", "regular"),
+  ("gbbrd", "regular"),
+  ("gbbry", "regular"),
+  ("gbbst", "regular"),
+  ("gbbur", "regular"),
+  ("gbcam", "regular"),
+  ("gbcay", "regular"),
+  ("gbcbf", "regular"),
+  ("gbccg", "regular"),
+  ("gbcgn", "regular"),
+  ("gbcgv", "deprecated"),
+  ("gbche", "regular"),
+  ("gbchw", "regular"),
+  ("gbckf", "deprecated"),
+  ("gbckt", "deprecated"),
+  ("gbcld", "regular"),
+  ("gbclk", "regular"),
+  ("gbclr", "deprecated"),
+  ("gbcma", "regular"),
+  ("gbcmd", "regular"),
+  ("gbcmn", "regular"),
+  ("gbcon", "regular"),
+  ("gbcov", "regular"),
+  ("gbcrf", "regular"),
+  ("gbcry", "regular"),
+  ("gbcsr", "deprecated"),
+  ("gbcwy", "regular"),
+  ("gbdal", "regular"),
+  ("gbdby", "regular"),
+  ("gbden", "regular"),
+  ("gbder", "regular"),
+  ("gbdev", "regular"),
+  ("gbdgn", "deprecated"),
+  ("gbdgy", "regular"),
+  ("gbdnc", "regular"),
+  ("gbdnd", "regular"),
+  ("gbdor", "regular"),
+  ("gbdow", "deprecated"),
+  ("gbdrs", "regular"),
+  ("gbdry", "deprecated"),
+  ("gbdud", "regular"),
+  ("gbdur", "regular"),
+  ("gbeal", "regular"),
+  ("gbeaw", "deprecated"),
+  ("gbeay", "regular"),
+  ("gbedh", "regular"),
+  ("gbedu", "regular"),
+  ("gbeln", "regular"),
+  ("gbels", "regular"),
+  ("gbenf", "regular"),
+  ("gbeng", "regular"),
+  ("gberw", "regular"),
+  ("gbery", "regular"),
+  ("gbess", "regular"),
+  ("gbesx", "regular"),
+  ("gbfal", "regular"),
+  ("gbfer", "deprecated"),
+  ("gbfif", "regular"),
+  ("gbfln", "regular"),
+  ("gbfmo", "regular"),
+  ("gbgat", "regular"),
+  ("gbgbn", "deprecated"),
+  ("gbglg", "regular"),
+  ("gbgls", "regular"),
+  ("gbgre", "regular"),
+  ("gbgwn", "regular"),
+  ("gbhal", "regular"),
+  ("gbham", "regular"),
+  ("gbhav", "regular"),
+  ("gbhck", "regular"),
+  ("gbhef", "regular"),
+  ("gbhil", "regular"),
+  ("gbhld", "regular"),
+  ("gbhmf", "regular"),
+  ("gbhns", "regular"),
+  ("gbhpl", "regular"),
+  ("gbhrt", "regular"),
+  ("gbhrw", "regular"),
+  ("gbhry", "regular"),
+  ("gbios", "regular"),
+  ("gbiow", "regular"),
+  ("gbisl", "regular"),
+  ("gbivc", "regular"),
+  ("gbkec", "regular"),
+  ("gbken", "regular"),
+  ("gbkhl", "regular"),
+  ("gbkir", "regular"),
+  ("gbktt", "regular"),
+  ("gbkwl", "regular"),
+  ("gblan", "regular"),
+  ("gblbc", "regular"),
+  ("gblbh", "regular"),
+  ("gblce", "regular"),
+  ("gblds", "regular"),
+  ("gblec", "regular"),
+  ("gblew", "regular"),
+  ("gblin", "regular"),
+  ("gbliv", "regular"),
+  ("gblmv", "deprecated"),
+  ("gblnd", "regular"),
+  ("gblrn", "deprecated"),
+  ("gblsb", "deprecated"),
+  ("gblut", "regular"),
+  ("gbman", "regular"),
+  ("gbmdb", "regular"),
+  ("gbmdw", "regular"),
+  ("gbmea", "regular"),
+  ("gbmft", "deprecated"),
+  ("gbmik", "regular"),
+  ("gbmln", "regular"),
+  ("gbmon", "regular"),
+  ("gbmrt", "regular"),
+  ("gbmry", "regular"),
+  ("gbmty", "regular"),
+  ("gbmul", "regular"),
+  ("gbmyl", "deprecated"),
+  ("gbnay", "regular"),
+  ("gbnbl", "regular"),
+  ("gbndn", "deprecated"),
+  ("gbnel", "regular"),
+  ("gbnet", "regular"),
+  ("gbnfk", "regular"),
+  ("gbngm", "regular"),
+  ("gbnir", "regular"),
+  ("gbnlk", "regular"),
+  ("gbnln", "regular"),
+  ("gbnmd", "regular"),
+  ("gbnsm", "regular"),
+  ("gbnta", "deprecated"),
+  ("gbnth", "regular"),
+  ("gbntl", "regular"),
+  ("gbntt", "regular"),
+  ("gbnty", "regular"),
+  ("gbnwm", "regular"),
+  ("gbnwp", "regular"),
+  ("gbnyk", "regular"),
+  ("gbnym", "deprecated"),
+  ("gbold", "regular"),
+  ("gbomh", "deprecated"),
+  ("gbork", "regular"),
+  ("gboxf", "regular"),
+  ("gbpem", "regular"),
+  ("gbpkn", "regular"),
+  ("gbply", "regular"),
+  ("gbpol", "deprecated"),
+  ("gbpor", "regular"),
+  ("gbpow", "regular"),
+  ("gbpte", "regular"),
+  ("gbrcc", "regular"),
+  ("gbrch", "regular"),
+  ("gbrct", "regular"),
+  ("gbrdb", "regular"),
+  ("gbrdg", "regular"),
+  ("gbrfw", "regular"),
+  ("gbric", "regular"),
+  ("gbrot", "regular"),
+  ("gbrut", "regular"),
+  ("gbsaw", "regular"),
+  ("gbsay", "regular"),
+  ("gbscb", "regular"),
+  ("gbsct", "regular"),
+  ("gbsfk", "regular"),
+  ("gbsft", "regular"),
+  ("gbsgc", "regular"),
+  ("gbshf", "regular"),
+  ("gbshn", "regular"),
+  ("gbshr", "regular"),
+  ("gbskp", "regular"),
+  ("gbslf", "regular"),
+  ("gbslg", "regular"),
+  ("gbslk", "regular"),
+  ("gbsnd", "regular"),
+  ("gbsol", "regular"),
+  ("gbsom", "regular"),
+  ("gbsos", "regular"),
+  ("gbsry", "regular"),
+  ("gbstb", "deprecated"),
+  ("gbste", "regular"),
+  ("gbstg", "regular"),
+  ("gbsth", "regular"),
+  ("gbstn", "regular"),
+  ("gbsts", "regular"),
+  ("gbstt", "regular"),
+  ("gbsty", "regular"),
+  ("gbswa", "regular"),
+  ("gbswd", "regular"),
+  ("gbswk", "regular"),
+  ("gbtam", "regular"),
+  ("gbtfw", "regular"),
+  ("gbthr", "regular"),
+  ("gbtob", "regular"),
+  ("gbtof", "regular"),
+  ("gbtrf", "regular"),
+  ("gbtwh", "regular"),
+  ("gbukm", "deprecated"),
+  ("gbvgl", "regular"),
+  ("gbwar", "regular"),
+  ("gbwbk", "regular"),
+  ("gbwdu", "regular"),
+  ("gbwft", "regular"),
+  ("gbwgn", "regular"),
+  ("gbwil", "regular"),
+  ("gbwkf", "regular"),
+  ("gbwll", "regular"),
+  ("gbwln", "regular"),
+  ("gbwls", "regular"),
+  ("gbwlv", "regular"),
+  ("gbwnd", "regular"),
+  ("gbwnm", "regular"),
+  ("gbwok", "regular"),
+  ("gbwor", "regular"),
+  ("gbwrl", "regular"),
+  ("gbwrt", "regular"),
+  ("gbwrx", "regular"),
+  ("gbwsm", "regular"),
+  ("gbwsx", "regular"),
+  ("gbyor", "regular"),
+  ("gbzet", "regular"),
+  ("gbzzzz", "unknown"),
+  ("gd01", "regular"),
+  ("gd02", "regular"),
+  ("gd03", "regular"),
+  ("gd04", "regular"),
+  ("gd05", "regular"),
+  ("gd06", "regular"),
+  ("gd10", "regular"),
+  ("gdzzzz", "unknown"),
+  ("geab", "regular"),
+  ("geaj", "regular"),
+  ("gegu", "regular"),
+  ("geim", "regular"),
+  ("geka", "regular"),
+  ("gekk", "regular"),
+  ("gemm", "regular"),
+  ("gerl", "regular"),
+  ("gesj", "regular"),
+  ("gesk", "regular"),
+  ("gesz", "regular"),
+  ("getb", "regular"),
+  ("gezzzz", "unknown"),
+  ("gfzzzz", "unknown"),
+  ("ggzzzz", "unknown"),
+  ("ghaa", "regular"),
+  ("ghaf", "regular"),
+  ("ghah", "regular"),
+  ("ghba", "deprecated"),
+  ("ghbe", "regular"),
+  ("ghbo", "regular"),
+  ("ghcp", "regular"),
+  ("ghep", "regular"),
+  ("ghne", "regular"),
+  ("ghnp", "regular"),
+  ("ghot", "regular"),
+  ("ghsv", "regular"),
+  ("ghtv", "regular"),
+  ("ghue", "regular"),
+  ("ghuw", "regular"),
+  ("ghwn", "regular"),
+  ("ghwp", "regular"),
+  ("ghzzzz", "unknown"),
+  ("gizzzz", "unknown"),
+  ("glav", "regular"),
+  ("glku", "regular"),
+  ("glqa", "deprecated"),
+  ("glqe", "regular"),
+  ("glqt", "regular"),
+  ("glsm", "regular"),
+  ("glzzzz", "unknown"),
+  ("gmb", "regular"),
+  ("gml", "regular"),
+  ("gmm", "regular"),
+  ("gmn", "regular"),
+  ("gmu", "regular"),
+  ("gmw", "regular"),
+  ("gmzzzz", "unknown"),
+  ("gnb", "regular"),
+  ("gnbe", "regular"),
+  ("gnbf", "regular"),
+  ("gnbk", "regular"),
+  ("gnc", "regular"),
+  ("gnco", "regular"),
+  ("gnd", "regular"),
+  ("gndb", "regular"),
+  ("gndi", "regular"),
+  ("gndl", "regular"),
+  ("gndu", "regular"),
+  ("gnf", "regular"),
+  ("gnfa", "regular"),
+  ("gnfo", "regular"),
+  ("gnfr", "regular"),
+  ("gnga", "regular"),
+  ("gngu", "regular"),
+  ("gnk", "regular"),
+  ("gnka", "regular"),
+  ("gnkb", "regular"),
+  ("gnkd", "regular"),
+  ("gnke", "regular"),
+  ("gnkn", "regular"),
+  ("gnko", "regular"),
+  ("gnks", "regular"),
+  ("gnl", "regular"),
+  ("gnla", "regular"),
+  ("gnle", "regular"),
+  ("gnlo", "regular"),
+  ("gnm", "regular"),
+  ("gnmc", "regular"),
+  ("gnmd", "regular"),
+  ("gnml", "regular"),
+  ("gnmm", "regular"),
+  ("gnn", "regular"),
+  ("gnnz", "regular"),
+  ("gnpi", "regular"),
+  ("gnsi", "regular"),
+  ("gnte", "regular"),
+  ("gnto", "regular"),
+  ("gnyo", "regular"),
+  ("gnzzzz", "unknown"),
+  ("gpzzzz", "unknown"),
+  ("gqan", "regular"),
+  ("gqbn", "regular"),
+  ("gqbs", "regular"),
+  ("gqc", "regular"),
+  ("gqcs", "regular"),
+  ("gqdj", "regular"),
+  ("gqi", "regular"),
+  ("gqkn", "regular"),
+  ("gqli", "regular"),
+  ("gqwn", "regular"),
+  ("gqzzzz", "unknown"),
+  ("gr01", "deprecated"),
+  ("gr03", "deprecated"),
+  ("gr04", "deprecated"),
+  ("gr05", "deprecated"),
+  ("gr06", "deprecated"),
+  ("gr07", "deprecated"),
+  ("gr11", "deprecated"),
+  ("gr12", "deprecated"),
+  ("gr13", "deprecated"),
+  ("gr14", "deprecated"),
+  ("gr15", "deprecated"),
+  ("gr16", "deprecated"),
+  ("gr17", "deprecated"),
+  ("gr21", "deprecated"),
+  ("gr22", "deprecated"),
+  ("gr23", "deprecated"),
+  ("gr24", "deprecated"),
+  ("gr31", "deprecated"),
+  ("gr32", "deprecated"),
+  ("gr33", "deprecated"),
+  ("gr34", "deprecated"),
+  ("gr41", "deprecated"),
+  ("gr42", "deprecated"),
+  ("gr43", "deprecated"),
+  ("gr44", "deprecated"),
+  ("gr51", "deprecated"),
+  ("gr52", "deprecated"),
+  ("gr53", "deprecated"),
+  ("gr54", "deprecated"),
+  ("gr55", "deprecated"),
+  ("gr56", "deprecated"),
+  ("gr57", "deprecated"),
+  ("gr58", "deprecated"),
+  ("gr59", "deprecated"),
+  ("gr61", "deprecated"),
+  ("gr62", "deprecated"),
+  ("gr63", "deprecated"),
+  ("gr64", "deprecated"),
+  ("gr69", "regular"),
+  ("gr71", "deprecated"),
+  ("gr72", "deprecated"),
+  ("gr73", "deprecated"),
+  ("gr81", "deprecated"),
+  ("gr82", "deprecated"),
+  ("gr83", "deprecated"),
+  ("gr84", "deprecated"),
+  ("gr85", "deprecated"),
+  ("gr91", "deprecated"),
+  ("gr92", "deprecated"),
+  ("gr93", "deprecated"),
+  ("gr94", "deprecated"),
+  ("gra", "regular"),
+  ("gra1", "deprecated"),
+  ("grb", "regular"),
+  ("grc", "regular"),
+  ("grd", "regular"),
+  ("gre", "regular"),
+  ("grf", "regular"),
+  ("grg", "regular"),
+  ("grh", "regular"),
+  ("gri", "regular"),
+  ("grj", "regular"),
+  ("grk", "regular"),
+  ("grl", "regular"),
+  ("grm", "regular"),
+  ("grzzzz", "unknown"),
+  ("gszzzz", "unknown"),
+  ("gt01", "regular"),
+  ("gt02", "regular"),
+  ("gt03", "regular"),
+  ("gt04", "regular"),
+  ("gt05", "regular"),
+  ("gt06", "regular"),
+  ("gt07", "regular"),
+  ("gt08", "regular"),
+  ("gt09", "regular"),
+  ("gt10", "regular"),
+  ("gt11", "regular"),
+  ("gt12", "regular"),
+  ("gt13", "regular"),
+  ("gt14", "regular"),
+  ("gt15", "regular"),
+  ("gt16", "regular"),
+  ("gt17", "regular"),
+  ("gt18", "regular"),
+  ("gt19", "regular"),
+  ("gt20", "regular"),
+  ("gt21", "regular"),
+  ("gt22", "regular"),
+  ("gtav", "deprecated"),
+  ("gtbv", "deprecated"),
+  ("gtcm", "deprecated"),
+  ("gtcq", "deprecated"),
+  ("gtes", "deprecated"),
+  ("gtgu", "deprecated"),
+  ("gthu", "deprecated"),
+  ("gtiz", "deprecated"),
+  ("gtja", "deprecated"),
+  ("gtju", "deprecated"),
+  ("gtpe", "deprecated"),
+  ("gtpr", "deprecated"),
+  ("gtqc", "deprecated"),
+  ("gtqz", "deprecated"),
+  ("gtre", "deprecated"),
+  ("gtsa", "deprecated"),
+  ("gtsm", "deprecated"),
+  ("gtso", "deprecated"),
+  ("gtsr", "deprecated"),
+  ("gtsu", "deprecated"),
+  ("gtto", "deprecated"),
+  ("gtza", "deprecated"),
+  ("gtzzzz", "unknown"),
+  ("guzzzz", "unknown"),
+  ("gwba", "regular"),
+  ("gwbl", "regular"),
+  ("gwbm", "regular"),
+  ("gwbs", "regular"),
+  ("gwca", "regular"),
+  ("gwga", "regular"),
+  ("gwl", "regular"),
+  ("gwn", "regular"),
+  ("gwoi", "regular"),
+  ("gwqu", "regular"),
+  ("gws", "regular"),
+  ("gwto", "regular"),
+  ("gwzzzz", "unknown"),
+  ("gyba", "regular"),
+  ("gycu", "regular"),
+  ("gyde", "regular"),
+  ("gyeb", "regular"),
+  ("gyes", "regular"),
+  ("gyma", "regular"),
+  ("gypm", "regular"),
+  ("gypt", "regular"),
+  ("gyud", "regular"),
+  ("gyut", "regular"),
+  ("gyzzzz", "unknown"),
+  ("hkzzzz", "unknown"),
+  ("hmzzzz", "unknown"),
+  ("hnat", "regular"),
+  ("hnch", "regular"),
+  ("hncl", "regular"),
+  ("hncm", "regular"),
+  ("hncp", "regular"),
+  ("hncr", "regular"),
+  ("hnep", "regular"),
+  ("hnfm", "regular"),
+  ("hngd", "regular"),
+  ("hnib", "regular"),
+  ("hnin", "regular"),
+  ("hnle", "regular"),
+  ("hnlp", "regular"),
+  ("hnoc", "regular"),
+  ("hnol", "regular"),
+  ("hnsb", "regular"),
+  ("hnva", "regular"),
+  ("hnyo", "regular"),
+  ("hnzzzz", "unknown"),
+  ("hr01", "regular"),
+  ("hr02", "regular"),
+  ("hr03", "regular"),
+  ("hr04", "regular"),
+  ("hr05", "regular"),
+  ("hr06", "regular"),
+  ("hr07", "regular"),
+  ("hr08", "regular"),
+  ("hr09", "regular"),
+  ("hr10", "regular"),
+  ("hr11", "regular"),
+  ("hr12", "regular"),
+  ("hr13", "regular"),
+  ("hr14", "regular"),
+  ("hr15", "regular"),
+  ("hr16", "regular"),
+  ("hr17", "regular"),
+  ("hr18", "regular"),
+  ("hr19", "regular"),
+  ("hr20", "regular"),
+  ("hr21", "regular"),
+  ("hrzzzz", "unknown"),
+  ("htar", "regular"),
+  ("htce", "regular"),
+  ("htga", "regular"),
+  ("htnd", "regular"),
+  ("htne", "regular"),
+  ("htni", "regular"),
+  ("htno", "regular"),
+  ("htou", "regular"),
+  ("htsd", "regular"),
+  ("htse", "regular"),
+  ("htzzzz", "unknown"),
+  ("huba", "regular"),
+  ("hubc", "regular"),
+  ("hube", "regular"),
+  ("hubk", "regular"),
+  ("hubu", "regular"),
+  ("hubz", "regular"),
+  ("hucs", "regular"),
+  ("hude", "regular"),
+  ("hudu", "regular"),
+  ("hueg", "regular"),
+  ("huer", "regular"),
+  ("hufe", "regular"),
+  ("hugs", "regular"),
+  ("hugy", "regular"),
+  ("huhb", "regular"),
+  ("huhe", "regular"),
+  ("huhv", "regular"),
+  ("hujn", "regular"),
+  ("huke", "regular"),
+  ("hukm", "regular"),
+  ("hukv", "regular"),
+  ("humi", "regular"),
+  ("hunk", "regular"),
+  ("huno", "regular"),
+  ("huny", "regular"),
+  ("hupe", "regular"),
+  ("hups", "regular"),
+  ("husd", "regular"),
+  ("husf", "regular"),
+  ("hush", "regular"),
+  ("husk", "regular"),
+  ("husn", "regular"),
+  ("huso", "regular"),
+  ("huss", "regular"),
+  ("hust", "regular"),
+  ("husz", "regular"),
+  ("hutb", "regular"),
+  ("huto", "regular"),
+  ("huva", "regular"),
+  ("huve", "regular"),
+  ("huvm", "regular"),
+  ("huza", "regular"),
+  ("huze", "regular"),
+  ("huzzzz", "unknown"),
+  ("iczzzz", "unknown"),
+  ("idac", "regular"),
+  ("idba", "regular"),
+  ("idbb", "regular"),
+  ("idbe", "regular"),
+  ("idbt", "regular"),
+  ("idgo", "regular"),
+  ("idja", "regular"),
+  ("idjb", "regular"),
+  ("idji", "regular"),
+  ("idjk", "regular"),
+  ("idjt", "regular"),
+  ("idjw", "regular"),
+  ("idka", "regular"),
+  ("idkb", "regular"),
+  ("idki", "regular"),
+  ("idkr", "regular"),
+  ("idks", "regular"),
+  ("idkt", "regular"),
+  ("idku", "regular"),
+  ("idla", "regular"),
+  ("idma", "regular"),
+  ("idml", "regular"),
+  ("idmu", "regular"),
+  ("idnb", "regular"),
+  ("idnt", "regular"),
+  ("idnu", "regular"),
+  ("idpa", "regular"),
+  ("idpb", "regular"),
+  ("idpp", "regular"),
+  ("idri", "regular"),
+  ("idsa", "regular"),
+  ("idsb", "regular"),
+  ("idsg", "regular"),
+  ("idsl", "regular"),
+  ("idsm", "regular"),
+  ("idsn", "regular"),
+  ("idsr", "regular"),
+  ("idss", "regular"),
+  ("idst", "regular"),
+  ("idsu", "regular"),
+  ("idyo", "regular"),
+  ("idzzzz", "unknown"),
+  ("iec", "regular"),
+  ("iece", "regular"),
+  ("iecn", "regular"),
+  ("ieco", "regular"),
+  ("iecw", "regular"),
+  ("ied", "regular"),
+  ("iedl", "regular"),
+  ("ieg", "regular"),
+  ("ieke", "regular"),
+  ("iekk", "regular"),
+  ("ieky", "regular"),
+  ("iel", "regular"),
+  ("ield", "regular"),
+  ("ielh", "regular"),
+  ("ielk", "regular"),
+  ("ielm", "regular"),
+  ("iels", "regular"),
+  ("iem", "regular"),
+  ("iemh", "regular"),
+  ("iemn", "regular"),
+  ("iemo", "regular"),
+  ("ieoy", "regular"),
+  ("iern", "regular"),
+  ("ieso", "regular"),
+  ("ieta", "regular"),
+  ("ieu", "regular"),
+  ("iewd", "regular"),
+  ("iewh", "regular"),
+  ("ieww", "regular"),
+  ("iewx", "regular"),
+  ("iezzzz", "unknown"),
+  ("ild", "regular"),
+  ("ilha", "regular"),
+  ("iljm", "regular"),
+  ("ilm", "regular"),
+  ("ilta", "regular"),
+  ("ilz", "regular"),
+  ("ilzzzz", "unknown"),
+  ("imzzzz", "unknown"),
+  ("inan", "regular"),
+  ("inap", "regular"),
+  ("inar", "regular"),
+  ("inas", "regular"),
+  ("inbr", "regular"),
+  ("inch", "regular"),
+  ("inct", "regular"),
+  ("indd", "deprecated"),
+  ("indh", "regular"),
+  ("indl", "regular"),
+  ("indn", "deprecated"),
+  ("inga", "regular"),
+  ("ingj", "regular"),
+  ("inhp", "regular"),
+  ("inhr", "regular"),
+  ("injh", "regular"),
+  ("injk", "regular"),
+  ("inka", "regular"),
+  ("inkl", "regular"),
+  ("inla", "regular"),
+  ("inld", "regular"),
+  ("inmh", "regular"),
+  ("inml", "regular"),
+  ("inmn", "regular"),
+  ("inmp", "regular"),
+  ("inmz", "regular"),
+  ("innl", "regular"),
+  ("inor", "regular"),
+  ("inpb", "regular"),
+  ("inpy", "regular"),
+  ("inrj", "regular"),
+  ("insk", "regular"),
+  ("intg", "regular"),
+  ("intn", "regular"),
+  ("intr", "regular"),
+  ("inup", "regular"),
+  ("inut", "regular"),
+  ("inwb", "regular"),
+  ("inzzzz", "unknown"),
+  ("iozzzz", "unknown"),
+  ("iqan", "regular"),
+  ("iqar", "regular"),
+  ("iqba", "regular"),
+  ("iqbb", "regular"),
+  ("iqbg", "regular"),
+  ("iqda", "regular"),
+  ("iqdi", "regular"),
+  ("iqdq", "regular"),
+  ("iqka", "regular"),
+  ("iqki", "regular"),
+  ("iqkr", "regular"),
+  ("iqma", "regular"),
+  ("iqmu", "regular"),
+  ("iqna", "regular"),
+  ("iqni", "regular"),
+  ("iqqa", "regular"),
+  ("iqsd", "regular"),
+  ("iqsu", "regular"),
+  ("iqwa", "regular"),
+  ("iqzzzz", "unknown"),
+  ("ir00", "regular"),
+  ("ir01", "regular"),
+  ("ir02", "regular"),
+  ("ir03", "regular"),
+  ("ir04", "regular"),
+  ("ir05", "regular"),
+  ("ir06", "regular"),
+  ("ir07", "regular"),
+  ("ir08", "regular"),
+  ("ir09", "regular"),
+  ("ir10", "regular"),
+  ("ir11", "regular"),
+  ("ir12", "regular"),
+  ("ir13", "regular"),
+  ("ir14", "regular"),
+  ("ir15", "regular"),
+  ("ir16", "regular"),
+  ("ir17", "regular"),
+  ("ir18", "regular"),
+  ("ir19", "regular"),
+  ("ir20", "regular"),
+  ("ir21", "regular"),
+  ("ir22", "regular"),
+  ("ir23", "regular"),
+  ("ir24", "regular"),
+  ("ir25", "regular"),
+  ("ir26", "regular"),
+  ("ir27", "regular"),
+  ("ir28", "regular"),
+  ("ir29", "regular"),
+  ("ir30", "regular"),
+  ("ir31", "deprecated"),
+  ("ir32", "deprecated"),
+  ("irzzzz", "unknown"),
+  ("is0", "deprecated"),
+  ("is1", "regular"),
+  ("is2", "regular"),
+  ("is3", "regular"),
+  ("is4", "regular"),
+  ("is5", "regular"),
+  ("is6", "regular"),
+  ("is7", "regular"),
+  ("is8", "regular"),
+  ("isakh", "regular"),
+  ("isakn", "regular"),
+  ("isaku", "regular"),
+  ("isarn", "regular"),
+  ("isasa", "regular"),
+  ("isbfj", "deprecated"),
+  ("isbla", "regular"),
+  ("isblo", "regular"),
+  ("isbog", "regular"),
+  ("isbol", "regular"),
+  ("isdab", "regular"),
+  ("isdav", "regular"),
+  ("isdju", "deprecated"),
+  ("iseom", "regular"),
+  ("iseyf", "regular"),
+  ("isfjd", "regular"),
+  ("isfjl", "regular"),
+  ("isfla", "regular"),
+  ("isfld", "deprecated"),
+  ("isflr", "regular"),
+  ("isgar", "regular"),
+  ("isgog", "regular"),
+  ("isgrn", "regular"),
+  ("isgru", "regular"),
+  ("isgry", "regular"),
+  ("ishaf", "regular"),
+  ("ishel", "regular"),
+  ("ishrg", "regular"),
+  ("ishru", "regular"),
+  ("ishut", "regular"),
+  ("ishuv", "regular"),
+  ("ishva", "regular"),
+  ("ishve", "regular"),
+  ("isisa", "regular"),
+  ("iskal", "regular"),
+  ("iskjo", "regular"),
+  ("iskop", "regular"),
+  ("islan", "regular"),
+  ("ismos", "regular"),
+  ("ismul", "regular"),
+  ("ismyr", "regular"),
+  ("isnor", "regular"),
+  ("isrge", "regular"),
+  ("isrgy", "regular"),
+  ("isrhh", "regular"),
+  ("isrkn", "regular"),
+  ("isrkv", "regular"),
+  ("issbh", "regular"),
+  ("issbt", "regular"),
+  ("issdn", "regular"),
+  ("issdv", "regular"),
+  ("issel", "regular"),
+  ("issey", "deprecated"),
+  ("issfa", "regular"),
+  ("isshf", "regular"),
+  ("isskf", "regular"),
+  ("isskg", "regular"),
+  ("issko", "regular"),
+  ("issku", "regular"),
+  ("issnf", "regular"),
+  ("issog", "regular"),
+  ("issol", "regular"),
+  ("isssf", "regular"),
+  ("issss", "regular"),
+  ("isstr", "regular"),
+  ("issty", "regular"),
+  ("issvg", "regular"),
+  ("istal", "regular"),
+  ("isthg", "regular"),
+  ("istjo", "regular"),
+  ("isvem", "regular"),
+  ("isver", "regular"),
+  ("isvop", "regular"),
+  ("iszzzz", "unknown"),
+  ("it21", "regular"),
+  ("it23", "regular"),
+  ("it25", "regular"),
+  ("it32", "regular"),
+  ("it34", "regular"),
+  ("it36", "regular"),
+  ("it42", "regular"),
+  ("it45", "regular"),
+  ("it52", "regular"),
+  ("it55", "regular"),
+  ("it57", "regular"),
+  ("it62", "regular"),
+  ("it65", "regular"),
+  ("it67", "regular"),
+  ("it72", "regular"),
+  ("it75", "regular"),
+  ("it77", "regular"),
+  ("it78", "regular"),
+  ("it82", "regular"),
+  ("it88", "regular"),
+  ("itag", "regular"),
+  ("ital", "regular"),
+  ("itan", "regular"),
+  ("itao", "deprecated"),
+  ("itap", "regular"),
+  ("itaq", "regular"),
+  ("itar", "regular"),
+  ("itat", "regular"),
+  ("itav", "regular"),
+  ("itba", "regular"),
+  ("itbg", "regular"),
+  ("itbi", "regular"),
+  ("itbl", "regular"),
+  ("itbn", "regular"),
+  ("itbo", "regular"),
+  ("itbr", "regular"),
+  ("itbs", "regular"),
+  ("itbt", "regular"),
+  ("itbz", "regular"),
+  ("itca", "regular"),
+  ("itcb", "regular"),
+  ("itce", "regular"),
+  ("itch", "regular"),
+  ("itci", "deprecated"),
+  ("itcl", "regular"),
+  ("itcn", "regular"),
+  ("itco", "regular"),
+  ("itcr", "regular"),
+  ("itcs", "regular"),
+  ("itct", "regular"),
+  ("itcz", "regular"),
+  ("iten", "regular"),
+  ("itfc", "regular"),
+  ("itfe", "regular"),
+  ("itfg", "regular"),
+  ("itfi", "regular"),
+  ("itfm", "regular"),
+  ("itfr", "regular"),
+  ("itge", "regular"),
+  ("itgo", "regular"),
+  ("itgr", "regular"),
+  ("itim", "regular"),
+  ("itis", "regular"),
+  ("itkr", "regular"),
+  ("itlc", "regular"),
+  ("itle", "regular"),
+  ("itli", "regular"),
+  ("itlo", "regular"),
+  ("itlt", "regular"),
+  ("itlu", "regular"),
+  ("itmb", "regular"),
+  ("itmc", "regular"),
+  ("itme", "regular"),
+  ("itmi", "regular"),
+  ("itmn", "regular"),
+  ("itmo", "regular"),
+  ("itms", "regular"),
+  ("itmt", "regular"),
+  ("itna", "regular"),
+  ("itno", "regular"),
+  ("itnu", "regular"),
+  ("itog", "deprecated"),
+  ("itor", "regular"),
+  ("itot", "deprecated"),
+  ("itpa", "regular"),
+  ("itpc", "regular"),
+  ("itpd", "regular"),
+  ("itpe", "regular"),
+  ("itpg", "regular"),
+  ("itpi", "regular"),
+  ("itpn", "regular"),
+  ("itpo", "regular"),
+  ("itpr", "regular"),
+  ("itpt", "regular"),
+  ("itpu", "regular"),
+  ("itpv", "regular"),
+  ("itpz", "regular"),
+  ("itra", "regular"),
+  ("itrc", "regular"),
+  ("itre", "regular"),
+  ("itrg", "regular"),
+  ("itri", "regular"),
+  ("itrm", "regular"),
+  ("itrn", "regular"),
+  ("itro", "regular"),
+  ("itsa", "regular"),
+  ("itsd", "deprecated"),
+  ("itsi", "regular"),
+  ("itso", "regular"),
+  ("itsp", "regular"),
+  ("itsr", "regular"),
+  ("itss", "regular"),
+  ("itsu", "regular"),
+  ("itsv", "regular"),
+  ("itta", "regular"),
+  ("itte", "regular"),
+  ("ittn", "regular"),
+  ("itto", "regular"),
+  ("ittp", "regular"),
+  ("ittr", "regular"),
+  ("itts", "regular"),
+  ("ittv", "regular"),
+  ("itud", "regular"),
+  ("itva", "regular"),
+  ("itvb", "regular"),
+  ("itvc", "regular"),
+  ("itve", "regular"),
+  ("itvi", "regular"),
+  ("itvr", "regular"),
+  ("itvs", "deprecated"),
+  ("itvt", "regular"),
+  ("itvv", "regular"),
+  ("itzzzz", "unknown"),
+  ("jezzzz", "unknown"),
+  ("jm01", "regular"),
+  ("jm02", "regular"),
+  ("jm03", "regular"),
+  ("jm04", "regular"),
+  ("jm05", "regular"),
+  ("jm06", "regular"),
+  ("jm07", "regular"),
+  ("jm08", "regular"),
+  ("jm09", "regular"),
+  ("jm10", "regular"),
+  ("jm11", "regular"),
+  ("jm12", "regular"),
+  ("jm13", "regular"),
+  ("jm14", "regular"),
+  ("jmzzzz", "unknown"),
+  ("joaj", "regular"),
+  ("joam", "regular"),
+  ("joaq", "regular"),
+  ("joat", "regular"),
+  ("joaz", "regular"),
+  ("joba", "regular"),
+  ("joir", "regular"),
+  ("joja", "regular"),
+  ("joka", "regular"),
+  ("joma", "regular"),
+  ("jomd", "regular"),
+  ("jomn", "regular"),
+  ("jozzzz", "unknown"),
+  ("jp01", "regular"),
+  ("jp02", "regular"),
+  ("jp03", "regular"),
+  ("jp04", "regular"),
+  ("jp05", "regular"),
+  ("jp06", "regular"),
+  ("jp07", "regular"),
+  ("jp08", "regular"),
+  ("jp09", "regular"),
+  ("jp10", "regular"),
+  ("jp11", "regular"),
+  ("jp12", "regular"),
+  ("jp13", "regular"),
+  ("jp14", "regular"),
+  ("jp15", "regular"),
+  ("jp16", "regular"),
+  ("jp17", "regular"),
+  ("jp18", "regular"),
+  ("jp19", "regular"),
+  ("jp20", "regular"),
+  ("jp21", "regular"),
+  ("jp22", "regular"),
+  ("jp23", "regular"),
+  ("jp24", "regular"),
+  ("jp25", "regular"),
+  ("jp26", "regular"),
+  ("jp27", "regular"),
+  ("jp28", "regular"),
+  ("jp29", "regular"),
+  ("jp30", "regular"),
+  ("jp31", "regular"),
+  ("jp32", "regular"),
+  ("jp33", "regular"),
+  ("jp34", "regular"),
+  ("jp35", "regular"),
+  ("jp36", "regular"),
+  ("jp37", "regular"),
+  ("jp38", "regular"),
+  ("jp39", "regular"),
+  ("jp40", "regular"),
+  ("jp41", "regular"),
+  ("jp42", "regular"),
+  ("jp43", "regular"),
+  ("jp44", "regular"),
+  ("jp45", "regular"),
+  ("jp46", "regular"),
+  ("jp47", "regular"),
+  ("jpzzzz", "unknown"),
+  ("ke01", "regular"),
+  ("ke02", "regular"),
+  ("ke03", "regular"),
+  ("ke04", "regular"),
+  ("ke05", "regular"),
+  ("ke06", "regular"),
+  ("ke07", "regular"),
+  ("ke08", "regular"),
+  ("ke09", "regular"),
+  ("ke10", "regular"),
+  ("ke11", "regular"),
+  ("ke12", "regular"),
+  ("ke13", "regular"),
+  ("ke14", "regular"),
+  ("ke15", "regular"),
+  ("ke16", "regular"),
+  ("ke17", "regular"),
+  ("ke18", "regular"),
+  ("ke19", "regular"),
+  ("ke20", "regular"),
+  ("ke21", "regular"),
+  ("ke22", "regular"),
+  ("ke23", "regular"),
+  ("ke24", "regular"),
+  ("ke25", "regular"),
+  ("ke26", "regular"),
+  ("ke27", "regular"),
+  ("ke28", "regular"),
+  ("ke29", "regular"),
+  ("ke30", "regular"),
+  ("ke31", "regular"),
+  ("ke32", "regular"),
+  ("ke33", "regular"),
+  ("ke34", "regular"),
+  ("ke35", "regular"),
+  ("ke36", "regular"),
+  ("ke37", "regular"),
+  ("ke38", "regular"),
+  ("ke39", "regular"),
+  ("ke40", "regular"),
+  ("ke41", "regular"),
+  ("ke42", "regular"),
+  ("ke43", "regular"),
+  ("ke44", "regular"),
+  ("ke45", "regular"),
+  ("ke46", "regular"),
+  ("ke47", "regular"),
+  ("kezzzz", "unknown"),
+  ("kgb", "regular"),
+  ("kgc", "regular"),
+  ("kggb", "regular"),
+  ("kggo", "regular"),
+  ("kgj", "regular"),
+  ("kgn", "regular"),
+  ("kgo", "regular"),
+  ("kgt", "regular"),
+  ("kgy", "regular"),
+  ("kgzzzz", "unknown"),
+  ("kh1", "regular"),
+  ("kh10", "regular"),
+  ("kh11", "regular"),
+  ("kh12", "regular"),
+  ("kh13", "regular"),
+  ("kh14", "regular"),
+  ("kh15", "regular"),
+  ("kh16", "regular"),
+  ("kh17", "regular"),
+  ("kh18", "regular"),
+  ("kh19", "regular"),
+  ("kh2", "regular"),
+  ("kh20", "regular"),
+  ("kh21", "regular"),
+  ("kh22", "regular"),
+  ("kh23", "regular"),
+  ("kh24", "regular"),
+  ("kh25", "regular"),
+  ("kh3", "regular"),
+  ("kh4", "regular"),
+  ("kh5", "regular"),
+  ("kh6", "regular"),
+  ("kh7", "regular"),
+  ("kh8", "regular"),
+  ("kh9", "regular"),
+  ("khzzzz", "unknown"),
+  ("kig", "regular"),
+  ("kil", "regular"),
+  ("kip", "regular"),
+  ("kizzzz", "unknown"),
+  ("kma", "regular"),
+  ("kmg", "regular"),
+  ("kmm", "regular"),
+  ("kmzzzz", "unknown"),
+  ("kn01", "regular"),
+  ("kn02", "regular"),
+  ("kn03", "regular"),
+  ("kn04", "regular"),
+  ("kn05", "regular"),
+  ("kn06", "regular"),
+  ("kn07", "regular"),
+  ("kn08", "regular"),
+  ("kn09", "regular"),
+  ("kn10", "regular"),
+  ("kn11", "regular"),
+  ("kn12", "regular"),
+  ("kn13", "regular"),
+  ("kn15", "regular"),
+  ("knk", "regular"),
+  ("knn", "regular"),
+  ("knzzzz", "unknown"),
+  ("kp01", "regular"),
+  ("kp02", "regular"),
+  ("kp03", "regular"),
+  ("kp04", "regular"),
+  ("kp05", "regular"),
+  ("kp06", "regular"),
+  ("kp07", "regular"),
+  ("kp08", "regular"),
+  ("kp09", "regular"),
+  ("kp10", "regular"),
+  ("kp13", "regular"),
+  ("kp14", "regular"),
+  ("kpzzzz", "unknown"),
+  ("kr11", "regular"),
+  ("kr26", "regular"),
+  ("kr27", "regular"),
+  ("kr28", "regular"),
+  ("kr29", "regular"),
+  ("kr30", "regular"),
+  ("kr31", "regular"),
+  ("kr41", "regular"),
+  ("kr42", "regular"),
+  ("kr43", "regular"),
+  ("kr44", "regular"),
+  ("kr45", "regular"),
+  ("kr46", "regular"),
+  ("kr47", "regular"),
+  ("kr48", "regular"),
+  ("kr49", "regular"),
+  ("kr50", "regular"),
+  ("krzzzz", "unknown"),
+  ("kwah", "regular"),
+  ("kwfa", "regular"),
+  ("kwha", "regular"),
+  ("kwja", "regular"),
+  ("kwku", "regular"),
+  ("kwmu", "regular"),
+  ("kwzzzz", "unknown"),
+  ("kyzzzz", "unknown"),
+  ("kzakm", "regular"),
+  ("kzakt", "regular"),
+  ("kzala", "regular"),
+  ("kzalm", "regular"),
+  ("kzast", "regular"),
+  ("kzaty", "regular"),
+  ("kzbay", "deprecated"),
+  ("kzkar", "regular"),
+  ("kzkus", "regular"),
+  ("kzkzy", "regular"),
+  ("kzman", "regular"),
+  ("kzpav", "regular"),
+  ("kzsev", "regular"),
+  ("kzshy", "regular"),
+  ("kzvos", "regular"),
+  ("kzyuz", "regular"),
+  ("kzzap", "regular"),
+  ("kzzha", "regular"),
+  ("kzzzzz", "unknown"),
+  ("laat", "regular"),
+  ("labk", "regular"),
+  ("labl", "regular"),
+  ("lach", "regular"),
+  ("laho", "regular"),
+  ("lakh", "regular"),
+  ("lalm", "regular"),
+  ("lalp", "regular"),
+  ("laou", "regular"),
+  ("laph", "regular"),
+  ("lasl", "regular"),
+  ("lasv", "regular"),
+  ("lavi", "regular"),
+  ("lavt", "regular"),
+  ("laxa", "regular"),
+  ("laxe", "regular"),
+  ("laxi", "regular"),
+  ("laxn", "deprecated"),
+  ("laxs", "regular"),
+  ("lazzzz", "unknown"),
+  ("lbak", "regular"),
+  ("lbas", "regular"),
+  ("lbba", "regular"),
+  ("lbbh", "regular"),
+  ("lbbi", "regular"),
+  ("lbja", "regular"),
+  ("lbjl", "regular"),
+  ("lbna", "regular"),
+  ("lbzzzz", "unknown"),
+  ("lc01", "regular"),
+  ("lc02", "regular"),
+  ("lc03", "regular"),
+  ("lc05", "regular"),
+  ("lc06", "regular"),
+  ("lc07", "regular"),
+  ("lc08", "regular"),
+  ("lc10", "regular"),
+  ("lc11", "regular"),
+  ("lc12", "regular"),
+  ("lczzzz", "unknown"),
+  ("li01", "regular"),
+  ("li02", "regular"),
+  ("li03", "regular"),
+  ("li04", "regular"),
+  ("li05", "regular"),
+  ("li06", "regular"),
+  ("li07", "regular"),
+  ("li08", "regular"),
+  ("li09", "regular"),
+  ("li10", "regular"),
+  ("li11", "regular"),
+  ("lizzzz", "unknown"),
+  ("lk1", "regular"),
+  ("lk11", "regular"),
+  ("lk12", "regular"),
+  ("lk13", "regular"),
+  ("lk2", "regular"),
+  ("lk21", "regular"),
+  ("lk22", "regular"),
+  ("lk23", "regular"),
+  ("lk3", "regular"),
+  ("lk31", "regular"),
+  ("lk32", "regular"),
+  ("lk33", "regular"),
+  ("lk4", "regular"),
+  ("lk41", "regular"),
+  ("lk42", "regular"),
+  ("lk43", "regular"),
+  ("lk44", "regular"),
+  ("lk45", "regular"),
+  ("lk5", "regular"),
+  ("lk51", "regular"),
+  ("lk52", "regular"),
+  ("lk53", "regular"),
+  ("lk6", "regular"),
+  ("lk61", "regular"),
+  ("lk62", "regular"),
+  ("lk7", "regular"),
+  ("lk71", "regular"),
+  ("lk72", "regular"),
+  ("lk8", "regular"),
+  ("lk81", "regular"),
+  ("lk82", "regular"),
+  ("lk9", "regular"),
+  ("lk91", "regular"),
+  ("lk92", "regular"),
+  ("lkzzzz", "unknown"),
+  ("lrbg", "regular"),
+  ("lrbm", "regular"),
+  ("lrcm", "regular"),
+  ("lrgb", "regular"),
+  ("lrgg", "regular"),
+  ("lrgk", "regular"),
+  ("lrgp", "regular"),
+  ("lrlo", "regular"),
+  ("lrmg", "regular"),
+  ("lrmo", "regular"),
+  ("lrmy", "regular"),
+  ("lrni", "regular"),
+  ("lrrg", "regular"),
+  ("lrri", "regular"),
+  ("lrsi", "regular"),
+  ("lrzzzz", "unknown"),
+  ("lsa", "regular"),
+  ("lsb", "regular"),
+  ("lsc", "regular"),
+  ("lsd", "regular"),
+  ("lse", "regular"),
+  ("lsf", "regular"),
+  ("lsg", "regular"),
+  ("lsh", "regular"),
+  ("lsj", "regular"),
+  ("lsk", "regular"),
+  ("lszzzz", "unknown"),
+  ("lt01", "regular"),
+  ("lt02", "regular"),
+  ("lt03", "regular"),
+  ("lt04", "regular"),
+  ("lt05", "regular"),
+  ("lt06", "regular"),
+  ("lt07", "regular"),
+  ("lt08", "regular"),
+  ("lt09", "regular"),
+  ("lt10", "regular"),
+  ("lt11", "regular"),
+  ("lt12", "regular"),
+  ("lt13", "regular"),
+  ("lt14", "regular"),
+  ("lt15", "regular"),
+  ("lt16", "regular"),
+  ("lt17", "regular"),
+  ("lt18", "regular"),
+  ("lt19", "regular"),
+  ("lt20", "regular"),
+  ("lt21", "regular"),
+  ("lt22", "regular"),
+  ("lt23", "regular"),
+  ("lt24", "regular"),
+  ("lt25", "regular"),
+  ("lt26", "regular"),
+  ("lt27", "regular"),
+  ("lt28", "regular"),
+  ("lt29", "regular"),
+  ("lt30", "regular"),
+  ("lt31", "regular"),
+  ("lt32", "regular"),
+  ("lt33", "regular"),
+  ("lt34", "regular"),
+  ("lt35", "regular"),
+  ("lt36", "regular"),
+  ("lt37", "regular"),
+  ("lt38", "regular"),
+  ("lt39", "regular"),
+  ("lt40", "regular"),
+  ("lt41", "regular"),
+  ("lt42", "regular"),
+  ("lt43", "regular"),
+  ("lt44", "regular"),
+  ("lt45", "regular"),
+  ("lt46", "regular"),
+  ("lt47", "regular"),
+  ("lt48", "regular"),
+  ("lt49", "regular"),
+  ("lt50", "regular"),
+  ("lt51", "regular"),
+  ("lt52", "regular"),
+  ("lt53", "regular"),
+  ("lt54", "regular"),
+  ("lt55", "regular"),
+  ("lt56", "regular"),
+  ("lt57", "regular"),
+  ("lt58", "regular"),
+  ("lt59", "regular"),
+  ("lt60", "regular"),
+  ("ltal", "regular"),
+  ("ltkl", "regular"),
+  ("ltku", "regular"),
+  ("ltmr", "regular"),
+  ("ltpn", "regular"),
+  ("ltsa", "regular"),
+  ("ltta", "regular"),
+  ("ltte", "regular"),
+  ("ltut", "regular"),
+  ("ltvl", "regular"),
+  ("ltzzzz", "unknown"),
+  ("luca", "regular"),
+  ("lucl", "regular"),
+  ("lud", "deprecated"),
+  ("ludi", "regular"),
+  ("luec", "regular"),
+  ("lues", "regular"),
+  ("lug", "deprecated"),
+  ("lugr", "regular"),
+  ("lul", "deprecated"),
+  ("lulu", "regular"),
+  ("lume", "regular"),
+  ("lurd", "regular"),
+  ("lurm", "regular"),
+  ("luvd", "regular"),
+  ("luwi", "regular"),
+  ("luzzzz", "unknown"),
+  ("lv001", "deprecated"),
+  ("lv002", "regular"),
+  ("lv003", "deprecated"),
+  ("lv004", "deprecated"),
+  ("lv005", "deprecated"),
+  ("lv006", "deprecated"),
+  ("lv007", "regular"),
+  ("lv008", "deprecated"),
+  ("lv009", "deprecated"),
+  ("lv010", "deprecated"),
+  ("lv011", "regular"),
+  ("lv012", "deprecated"),
+  ("lv013", "deprecated"),
+  ("lv014", "deprecated"),
+  ("lv015", "regular"),
+  ("lv016", "regular"),
+  ("lv017", "deprecated"),
+  ("lv018", "deprecated"),
+  ("lv019", "deprecated"),
+  ("lv020", "deprecated"),
+  ("lv021", "deprecated"),
+  ("lv022", "regular"),
+  ("lv023", "deprecated"),
+  ("lv024", "deprecated"),
+  ("lv025", "deprecated"),
+  ("lv026", "regular"),
+  ("lv027", "deprecated"),
+  ("lv028", "deprecated"),
+  ("lv029", "deprecated"),
+  ("lv030", "deprecated"),
+  ("lv031", "deprecated"),
+  ("lv032", "deprecated"),
+  ("lv033", "regular"),
+  ("lv034", "deprecated"),
+  ("lv035", "deprecated"),
+  ("lv036", "deprecated"),
+  ("lv037", "deprecated"),
+  ("lv038", "deprecated"),
+  ("lv039", "deprecated"),
+  ("lv040", "deprecated"),
+  ("lv041", "regular"),
+  ("lv042", "regular"),
+  ("lv043", "deprecated"),
+  ("lv044", "deprecated"),
+  ("lv045", "deprecated"),
+  ("lv046", "deprecated"),
+  ("lv047", "regular"),
+  ("lv048", "deprecated"),
+  ("lv049", "deprecated"),
+  ("lv050", "regular"),
+  ("lv051", "deprecated"),
+  ("lv052", "regular"),
+  ("lv053", "deprecated"),
+  ("lv054", "regular"),
+  ("lv055", "deprecated"),
+  ("lv056", "regular"),
+  ("lv057", "deprecated"),
+  ("lv058", "regular"),
+  ("lv059", "regular"),
+  ("lv060", "deprecated"),
+  ("lv061", "deprecated"),
+  ("lv062", "regular"),
+  ("lv063", "deprecated"),
+  ("lv064", "deprecated"),
+  ("lv065", "deprecated"),
+  ("lv066", "deprecated"),
+  ("lv067", "regular"),
+  ("lv068", "regular"),
+  ("lv069", "deprecated"),
+  ("lv070", "deprecated"),
+  ("lv071", "deprecated"),
+  ("lv072", "deprecated"),
+  ("lv073", "regular"),
+  ("lv074", "deprecated"),
+  ("lv075", "deprecated"),
+  ("lv076", "deprecated"),
+  ("lv077", "regular"),
+  ("lv078", "deprecated"),
+  ("lv079", "deprecated"),
+  ("lv080", "regular"),
+  ("lv081", "deprecated"),
+  ("lv082", "deprecated"),
+  ("lv083", "deprecated"),
+  ("lv084", "deprecated"),
+  ("lv085", "deprecated"),
+  ("lv086", "deprecated"),
+  ("lv087", "regular"),
+  ("lv088", "regular"),
+  ("lv089", "regular"),
+  ("lv090", "deprecated"),
+  ("lv091", "regular"),
+  ("lv092", "deprecated"),
+  ("lv093", "deprecated"),
+  ("lv094", "regular"),
+  ("lv095", "deprecated"),
+  ("lv096", "deprecated"),
+  ("lv097", "regular"),
+  ("lv098", "deprecated"),
+  ("lv099", "regular"),
+  ("lv100", "deprecated"),
+  ("lv101", "regular"),
+  ("lv102", "regular"),
+  ("lv103", "deprecated"),
+  ("lv104", "deprecated"),
+  ("lv105", "deprecated"),
+  ("lv106", "regular"),
+  ("lv107", "deprecated"),
+  ("lv108", "deprecated"),
+  ("lv109", "deprecated"),
+  ("lv110", "deprecated"),
+  ("lv111", "regular"),
+  ("lv112", "regular"),
+  ("lv113", "regular"),
+  ("lvdgv", "regular"),
+  ("lvjel", "regular"),
+  ("lvjkb", "deprecated"),
+  ("lvjur", "regular"),
+  ("lvlpx", "regular"),
+  ("lvrez", "regular"),
+  ("lvrix", "regular"),
+  ("lvven", "regular"),
+  ("lvvmr", "deprecated"),
+  ("lvzzzz", "unknown"),
+  ("lyba", "regular"),
+  ("lybu", "regular"),
+  ("lydr", "regular"),
+  ("lygt", "regular"),
+  ("lyja", "regular"),
+  ("lyjg", "regular"),
+  ("lyji", "regular"),
+  ("lyju", "regular"),
+  ("lykf", "regular"),
+  ("lymb", "regular"),
+  ("lymi", "regular"),
+  ("lymj", "regular"),
+  ("lymq", "regular"),
+  ("lynl", "regular"),
+  ("lynq", "regular"),
+  ("lysb", "regular"),
+  ("lysr", "regular"),
+  ("lytb", "regular"),
+  ("lywa", "regular"),
+  ("lywd", "regular"),
+  ("lyws", "regular"),
+  ("lyza", "regular"),
+  ("lyzzzz", "unknown"),
+  ("ma01", "regular"),
+  ("ma02", "regular"),
+  ("ma03", "regular"),
+  ("ma04", "regular"),
+  ("ma05", "regular"),
+  ("ma06", "regular"),
+  ("ma07", "regular"),
+  ("ma08", "regular"),
+  ("ma09", "regular"),
+  ("ma10", "regular"),
+  ("ma11", "regular"),
+  ("ma12", "regular"),
+  ("ma13", "deprecated"),
+  ("ma14", "deprecated"),
+  ("ma15", "deprecated"),
+  ("ma16", "deprecated"),
+  ("maagd", "regular"),
+  ("maaou", "regular"),
+  ("maasz", "regular"),
+  ("maazi", "regular"),
+  ("mabem", "regular"),
+  ("maber", "regular"),
+  ("mabes", "regular"),
+  ("mabod", "regular"),
+  ("mabom", "regular"),
+  ("mabrr", "regular"),
+  ("macas", "regular"),
+  ("mache", "regular"),
+  ("machi", "regular"),
+  ("macht", "regular"),
+  ("madri", "regular"),
+  ("maerr", "regular"),
+  ("maesi", "regular"),
+  ("maesm", "regular"),
+  ("mafah", "regular"),
+  ("mafes", "regular"),
+  ("mafig", "regular"),
+  ("mafqh", "regular"),
+  ("mague", "regular"),
+  ("maguf", "regular"),
+  ("mahaj", "regular"),
+  ("mahao", "regular"),
+  ("mahoc", "regular"),
+  ("maifr", "regular"),
+  ("maine", "regular"),
+  ("majdi", "regular"),
+  ("majra", "regular"),
+  ("maken", "regular"),
+  ("makes", "regular"),
+  ("makhe", "regular"),
+  ("makhn", "regular"),
+  ("makho", "regular"),
+  ("malaa", "regular"),
+  ("malar", "regular"),
+  ("mamar", "regular"),
+  ("mamdf", "regular"),
+  ("mamed", "regular"),
+  ("mamek", "regular"),
+  ("mamid", "regular"),
+  ("mammd", "deprecated"),
+  ("mammn", "deprecated"),
+  ("mamoh", "regular"),
+  ("mamou", "regular"),
+  ("manad", "regular"),
+  ("manou", "regular"),
+  ("maoua", "regular"),
+  ("maoud", "regular"),
+  ("maouj", "regular"),
+  ("maouz", "regular"),
+  ("marab", "regular"),
+  ("mareh", "regular"),
+  ("masaf", "regular"),
+  ("masal", "regular"),
+  ("masef", "regular"),
+  ("maset", "regular"),
+  ("masib", "regular"),
+  ("masif", "regular"),
+  ("masik", "regular"),
+  ("masil", "regular"),
+  ("maskh", "regular"),
+  ("masyb", "deprecated"),
+  ("mataf", "regular"),
+  ("matai", "regular"),
+  ("matao", "regular"),
+  ("matar", "regular"),
+  ("matat", "regular"),
+  ("mataz", "regular"),
+  ("matet", "regular"),
+  ("matin", "regular"),
+  ("matiz", "regular"),
+  ("matng", "regular"),
+  ("matnt", "regular"),
+  ("mayus", "regular"),
+  ("mazag", "regular"),
+  ("mazzzz", "unknown"),
+  ("mccl", "regular"),
+  ("mcco", "regular"),
+  ("mcfo", "regular"),
+  ("mcga", "regular"),
+  ("mcje", "regular"),
+  ("mcla", "regular"),
+  ("mcma", "regular"),
+  ("mcmc", "regular"),
+  ("mcmg", "regular"),
+  ("mcmo", "regular"),
+  ("mcmu", "regular"),
+  ("mcph", "regular"),
+  ("mcsd", "regular"),
+  ("mcso", "regular"),
+  ("mcsp", "regular"),
+  ("mcsr", "regular"),
+  ("mcvr", "regular"),
+  ("mczzzz", "unknown"),
+  ("mdan", "regular"),
+  ("mdba", "regular"),
+  ("mdbd", "regular"),
+  ("mdbr", "regular"),
+  ("mdbs", "regular"),
+  ("mdca", "regular"),
+  ("mdcl", "regular"),
+  ("mdcm", "regular"),
+  ("mdcr", "regular"),
+  ("mdcs", "regular"),
+  ("mdct", "regular"),
+  ("mdcu", "regular"),
+  ("mddo", "regular"),
+  ("mddr", "regular"),
+  ("mddu", "regular"),
+  ("mded", "regular"),
+  ("mdfa", "regular"),
+  ("mdfl", "regular"),
+  ("mdga", "regular"),
+  ("mdgl", "regular"),
+  ("mdhi", "regular"),
+  ("mdia", "regular"),
+  ("mdle", "regular"),
+  ("mdni", "regular"),
+  ("mdoc", "regular"),
+  ("mdor", "regular"),
+  ("mdre", "regular"),
+  ("mdri", "regular"),
+  ("mdsd", "regular"),
+  ("mdsi", "regular"),
+  ("mdsn", "regular"),
+  ("mdso", "regular"),
+  ("mdst", "regular"),
+  ("mdsv", "regular"),
+  ("mdta", "regular"),
+  ("mdte", "regular"),
+  ("mdun", "regular"),
+  ("mdzzzz", "unknown"),
+  ("me01", "regular"),
+  ("me02", "regular"),
+  ("me03", "regular"),
+  ("me04", "regular"),
+  ("me05", "regular"),
+  ("me06", "regular"),
+  ("me07", "regular"),
+  ("me08", "regular"),
+  ("me09", "regular"),
+  ("me10", "regular"),
+  ("me11", "regular"),
+  ("me12", "regular"),
+  ("me13", "regular"),
+  ("me14", "regular"),
+  ("me15", "regular"),
+  ("me16", "regular"),
+  ("me17", "regular"),
+  ("me18", "regular"),
+  ("me19", "regular"),
+  ("me20", "regular"),
+  ("me21", "regular"),
+  ("me22", "regular"),
+  ("me23", "regular"),
+  ("me24", "regular"),
+  ("mezzzz", "unknown"),
+  ("mfzzzz", "unknown"),
+  ("mga", "regular"),
+  ("mgd", "regular"),
+  ("mgf", "regular"),
+  ("mgm", "regular"),
+  ("mgt", "regular"),
+  ("mgu", "regular"),
+  ("mgzzzz", "unknown"),
+  ("mhalk", "regular"),
+  ("mhall", "regular"),
+  ("mharn", "regular"),
+  ("mhaur", "regular"),
+  ("mhebo", "regular"),
+  ("mheni", "regular"),
+  ("mhjab", "regular"),
+  ("mhjal", "regular"),
+  ("mhkil", "regular"),
+  ("mhkwa", "regular"),
+  ("mhl", "regular"),
+  ("mhlae", "regular"),
+  ("mhlib", "regular"),
+  ("mhlik", "regular"),
+  ("mhmaj", "regular"),
+  ("mhmal", "regular"),
+  ("mhmej", "regular"),
+  ("mhmil", "regular"),
+  ("mhnmk", "regular"),
+  ("mhnmu", "regular"),
+  ("mhron", "regular"),
+  ("mht", "regular"),
+  ("mhuja", "regular"),
+  ("mhuti", "regular"),
+  ("mhwth", "regular"),
+  ("mhwtj", "regular"),
+  ("mhzzzz", "unknown"),
+  ("mk01", "deprecated"),
+  ("mk02", "deprecated"),
+  ("mk03", "deprecated"),
+  ("mk04", "deprecated"),
+  ("mk05", "deprecated"),
+  ("mk06", "deprecated"),
+  ("mk07", "deprecated"),
+  ("mk08", "deprecated"),
+  ("mk09", "deprecated"),
+  ("mk10", "deprecated"),
+  ("mk101", "regular"),
+  ("mk102", "regular"),
+  ("mk103", "regular"),
+  ("mk104", "regular"),
+  ("mk105", "regular"),
+  ("mk106", "regular"),
+  ("mk107", "regular"),
+  ("mk108", "regular"),
+  ("mk109", "regular"),
+  ("mk11", "deprecated"),
+  ("mk12", "deprecated"),
+  ("mk13", "deprecated"),
+  ("mk14", "deprecated"),
+  ("mk15", "deprecated"),
+  ("mk16", "deprecated"),
+  ("mk17", "deprecated"),
+  ("mk18", "deprecated"),
+  ("mk19", "deprecated"),
+  ("mk20", "deprecated"),
+  ("mk201", "regular"),
+  ("mk202", "regular"),
+  ("mk203", "regular"),
+  ("mk204", "regular"),
+  ("mk205", "regular"),
+  ("mk206", "regular"),
+  ("mk207", "regular"),
+  ("mk208", "regular"),
+  ("mk209", "regular"),
+  ("mk21", "deprecated"),
+  ("mk210", "regular"),
+  ("mk211", "regular"),
+  ("mk22", "deprecated"),
+  ("mk23", "deprecated"),
+  ("mk24", "deprecated"),
+  ("mk25", "deprecated"),
+  ("mk26", "deprecated"),
+  ("mk27", "deprecated"),
+  ("mk28", "deprecated"),
+  ("mk29", "deprecated"),
+  ("mk30", "deprecated"),
+  ("mk301", "regular"),
+  ("mk303", "regular"),
+  ("mk304", "regular"),
+  ("mk307", "regular"),
+  ("mk308", "regular"),
+  ("mk31", "deprecated"),
+  ("mk310", "regular"),
+  ("mk311", "regular"),
+  ("mk312", "regular"),
+  ("mk313", "regular"),
+  ("mk32", "deprecated"),
+  ("mk33", "deprecated"),
+  ("mk34", "deprecated"),
+  ("mk35", "deprecated"),
+  ("mk36", "deprecated"),
+  ("mk37", "deprecated"),
+  ("mk38", "deprecated"),
+  ("mk39", "deprecated"),
+  ("mk40", "deprecated"),
+  ("mk401", "regular"),
+  ("mk402", "regular"),
+  ("mk403", "regular"),
+  ("mk404", "regular"),
+  ("mk405", "regular"),
+  ("mk406", "regular"),
+  ("mk407", "regular"),
+  ("mk408", "regular"),
+  ("mk409", "regular"),
+  ("mk41", "deprecated"),
+  ("mk410", "regular"),
+  ("mk42", "deprecated"),
+  ("mk43", "deprecated"),
+  ("mk44", "deprecated"),
+  ("mk45", "deprecated"),
+  ("mk46", "deprecated"),
+  ("mk47", "deprecated"),
+  ("mk48", "deprecated"),
+  ("mk49", "deprecated"),
+  ("mk50", "deprecated"),
+  ("mk501", "regular"),
+  ("mk502", "regular"),
+  ("mk503", "regular"),
+  ("mk504", "regular"),
+  ("mk505", "regular"),
+  ("mk506", "regular"),
+  ("mk507", "regular"),
+  ("mk508", "regular"),
+  ("mk509", "regular"),
+  ("mk51", "deprecated"),
+  ("mk52", "deprecated"),
+  ("mk53", "deprecated"),
+  ("mk54", "deprecated"),
+  ("mk55", "deprecated"),
+  ("mk56", "deprecated"),
+  ("mk57", "deprecated"),
+  ("mk58", "deprecated"),
+  ("mk59", "deprecated"),
+  ("mk60", "deprecated"),
+  ("mk601", "regular"),
+  ("mk602", "regular"),
+  ("mk603", "regular"),
+  ("mk604", "regular"),
+  ("mk605", "regular"),
+  ("mk606", "regular"),
+  ("mk607", "regular"),
+  ("mk608", "regular"),
+  ("mk609", "regular"),
+  ("mk61", "deprecated"),
+  ("mk62", "deprecated"),
+  ("mk63", "deprecated"),
+  ("mk64", "deprecated"),
+  ("mk65", "deprecated"),
+  ("mk66", "deprecated"),
+  ("mk67", "deprecated"),
+  ("mk68", "deprecated"),
+  ("mk69", "deprecated"),
+  ("mk70", "deprecated"),
+  ("mk701", "regular"),
+  ("mk702", "regular"),
+  ("mk703", "regular"),
+  ("mk704", "regular"),
+  ("mk705", "regular"),
+  ("mk706", "regular"),
+  ("mk71", "deprecated"),
+  ("mk72", "deprecated"),
+  ("mk73", "deprecated"),
+  ("mk74", "deprecated"),
+  ("mk75", "deprecated"),
+  ("mk76", "deprecated"),
+  ("mk77", "deprecated"),
+  ("mk78", "deprecated"),
+  ("mk79", "deprecated"),
+  ("mk80", "deprecated"),
+  ("mk801", "regular"),
+  ("mk802", "regular"),
+  ("mk803", "regular"),
+  ("mk804", "regular"),
+  ("mk805", "regular"),
+  ("mk806", "regular"),
+  ("mk807", "regular"),
+  ("mk808", "regular"),
+  ("mk809", "regular"),
+  ("mk81", "deprecated"),
+  ("mk810", "regular"),
+  ("mk811", "regular"),
+  ("mk812", "regular"),
+  ("mk813", "regular"),
+  ("mk814", "regular"),
+  ("mk815", "regular"),
+  ("mk816", "regular"),
+  ("mk817", "regular"),
+  ("mk82", "deprecated"),
+  ("mk83", "deprecated"),
+  ("mk84", "deprecated"),
+  ("mk85", "deprecated"),
+  ("mkzzzz", "unknown"),
+  ("ml1", "regular"),
+  ("ml10", "regular"),
+  ("ml2", "regular"),
+  ("ml3", "regular"),
+  ("ml4", "regular"),
+  ("ml5", "regular"),
+  ("ml6", "regular"),
+  ("ml7", "regular"),
+  ("ml8", "regular"),
+  ("ml9", "regular"),
+  ("mlbko", "regular"),
+  ("mlzzzz", "unknown"),
+  ("mm01", "regular"),
+  ("mm02", "regular"),
+  ("mm03", "regular"),
+  ("mm04", "regular"),
+  ("mm05", "regular"),
+  ("mm06", "regular"),
+  ("mm07", "regular"),
+  ("mm11", "regular"),
+  ("mm12", "regular"),
+  ("mm13", "regular"),
+  ("mm14", "regular"),
+  ("mm15", "regular"),
+  ("mm16", "regular"),
+  ("mm17", "regular"),
+  ("mm18", "regular"),
+  ("mmzzzz", "unknown"),
+  ("mn035", "regular"),
+  ("mn037", "regular"),
+  ("mn039", "regular"),
+  ("mn041", "regular"),
+  ("mn043", "regular"),
+  ("mn046", "regular"),
+  ("mn047", "regular"),
+  ("mn049", "regular"),
+  ("mn051", "regular"),
+  ("mn053", "regular"),
+  ("mn055", "regular"),
+  ("mn057", "regular"),
+  ("mn059", "regular"),
+  ("mn061", "regular"),
+  ("mn063", "regular"),
+  ("mn064", "regular"),
+  ("mn065", "regular"),
+  ("mn067", "regular"),
+  ("mn069", "regular"),
+  ("mn071", "regular"),
+  ("mn073", "regular"),
+  ("mn1", "regular"),
+  ("mnzzzz", "unknown"),
+  ("mozzzz", "unknown"),
+  ("mpzzzz", "unknown"),
+  ("mqzzzz", "unknown"),
+  ("mr01", "regular"),
+  ("mr02", "regular"),
+  ("mr03", "regular"),
+  ("mr04", "regular"),
+  ("mr05", "regular"),
+  ("mr06", "regular"),
+  ("mr07", "regular"),
+  ("mr08", "regular"),
+  ("mr09", "regular"),
+  ("mr10", "regular"),
+  ("mr11", "regular"),
+  ("mr12", "regular"),
+  ("mr13", "regular"),
+  ("mr14", "regular"),
+  ("mr15", "regular"),
+  ("mrnkc", "deprecated"),
+  ("mrzzzz", "unknown"),
+  ("mszzzz", "unknown"),
+  ("mt01", "regular"),
+  ("mt02", "regular"),
+  ("mt03", "regular"),
+  ("mt04", "regular"),
+  ("mt05", "regular"),
+  ("mt06", "regular"),
+  ("mt07", "regular"),
+  ("mt08", "regular"),
+  ("mt09", "regular"),
+  ("mt10", "regular"),
+  ("mt11", "regular"),
+  ("mt12", "regular"),
+  ("mt13", "regular"),
+  ("mt14", "regular"),
+  ("mt15", "regular"),
+  ("mt16", "regular"),
+  ("mt17", "regular"),
+  ("mt18", "regular"),
+  ("mt19", "regular"),
+  ("mt20", "regular"),
+  ("mt21", "regular"),
+  ("mt22", "regular"),
+  ("mt23", "regular"),
+  ("mt24", "regular"),
+  ("mt25", "regular"),
+  ("mt26", "regular"),
+  ("mt27", "regular"),
+  ("mt28", "regular"),
+  ("mt29", "regular"),
+  ("mt30", "regular"),
+  ("mt31", "regular"),
+  ("mt32", "regular"),
+  ("mt33", "regular"),
+  ("mt34", "regular"),
+  ("mt35", "regular"),
+  ("mt36", "regular"),
+  ("mt37", "regular"),
+  ("mt38", "regular"),
+  ("mt39", "regular"),
+  ("mt40", "regular"),
+  ("mt41", "regular"),
+  ("mt42", "regular"),
+  ("mt43", "regular"),
+  ("mt44", "regular"),
+  ("mt45", "regular"),
+  ("mt46", "regular"),
+  ("mt47", "regular"),
+  ("mt48", "regular"),
+  ("mt49", "regular"),
+  ("mt50", "regular"),
+  ("mt51", "regular"),
+  ("mt52", "regular"),
+  ("mt53", "regular"),
+  ("mt54", "regular"),
+  ("mt55", "regular"),
+  ("mt56", "regular"),
+  ("mt57", "regular"),
+  ("mt58", "regular"),
+  ("mt59", "regular"),
+  ("mt60", "regular"),
+  ("mt61", "regular"),
+  ("mt62", "regular"),
+  ("mt63", "regular"),
+  ("mt64", "regular"),
+  ("mt65", "regular"),
+  ("mt66", "regular"),
+  ("mt67", "regular"),
+  ("mt68", "regular"),
+  ("mtzzzz", "unknown"),
+  ("muag", "regular"),
+  ("mubl", "regular"),
+  ("mubr", "deprecated"),
+  ("mucc", "regular"),
+  ("mucu", "deprecated"),
+  ("mufl", "regular"),
+  ("mugp", "regular"),
+  ("mumo", "regular"),
+  ("mupa", "regular"),
+  ("mupl", "regular"),
+  ("mupu", "deprecated"),
+  ("mupw", "regular"),
+  ("muqb", "deprecated"),
+  ("muro", "regular"),
+  ("murr", "regular"),
+  ("musa", "regular"),
+  ("muvp", "deprecated"),
+  ("muzzzz", "unknown"),
+  ("mv00", "regular"),
+  ("mv01", "regular"),
+  ("mv02", "regular"),
+  ("mv03", "regular"),
+  ("mv04", "regular"),
+  ("mv05", "regular"),
+  ("mv07", "regular"),
+  ("mv08", "regular"),
+  ("mv12", "regular"),
+  ("mv13", "regular"),
+  ("mv14", "regular"),
+  ("mv17", "regular"),
+  ("mv20", "regular"),
+  ("mv23", "regular"),
+  ("mv24", "regular"),
+  ("mv25", "regular"),
+  ("mv26", "regular"),
+  ("mv27", "regular"),
+  ("mv28", "regular"),
+  ("mv29", "regular"),
+  ("mvce", "deprecated"),
+  ("mvmle", "regular"),
+  ("mvnc", "deprecated"),
+  ("mvno", "deprecated"),
+  ("mvsc", "deprecated"),
+  ("mvsu", "deprecated"),
+  ("mvun", "deprecated"),
+  ("mvus", "deprecated"),
+  ("mvzzzz", "unknown"),
+  ("mwba", "regular"),
+  ("mwbl", "regular"),
+  ("mwc", "regular"),
+  ("mwck", "regular"),
+  ("mwcr", "regular"),
+  ("mwct", "regular"),
+  ("mwde", "regular"),
+  ("mwdo", "regular"),
+  ("mwkr", "regular"),
+  ("mwks", "regular"),
+  ("mwli", "regular"),
+  ("mwlk", "regular"),
+  ("mwmc", "regular"),
+  ("mwmg", "regular"),
+  ("mwmh", "regular"),
+  ("mwmu", "regular"),
+  ("mwmw", "regular"),
+  ("mwmz", "regular"),
+  ("mwn", "regular"),
+  ("mwnb", "regular"),
+  ("mwne", "regular"),
+  ("mwni", "regular"),
+  ("mwnk", "regular"),
+  ("mwns", "regular"),
+  ("mwnu", "regular"),
+  ("mwph", "regular"),
+  ("mwru", "regular"),
+  ("mws", "regular"),
+  ("mwsa", "regular"),
+  ("mwth", "regular"),
+  ("mwzo", "regular"),
+  ("mwzzzz", "unknown"),
+  ("mxagu", "regular"),
+  ("mxbcn", "regular"),
+  ("mxbcs", "regular"),
+  ("mxcam", "regular"),
+  ("mxchh", "regular"),
+  ("mxchp", "regular"),
+  ("mxcmx", "regular"),
+  ("mxcoa", "regular"),
+  ("mxcol", "regular"),
+  ("mxdif", "deprecated"),
+  ("mxdur", "regular"),
+  ("mxgro", "regular"),
+  ("mxgua", "regular"),
+  ("mxhid", "regular"),
+  ("mxjal", "regular"),
+  ("mxmex", "regular"),
+  ("mxmic", "regular"),
+  ("mxmor", "regular"),
+  ("mxnay", "regular"),
+  ("mxnle", "regular"),
+  ("mxoax", "regular"),
+  ("mxpue", "regular"),
+  ("mxque", "regular"),
+  ("mxroo", "regular"),
+  ("mxsin", "regular"),
+  ("mxslp", "regular"),
+  ("mxson", "regular"),
+  ("mxtab", "regular"),
+  ("mxtam", "regular"),
+  ("mxtla", "regular"),
+  ("mxver", "regular"),
+  ("mxyuc", "regular"),
+  ("mxzac", "regular"),
+  ("mxzzzz", "unknown"),
+  ("my01", "regular"),
+  ("my02", "regular"),
+  ("my03", "regular"),
+  ("my04", "regular"),
+  ("my05", "regular"),
+  ("my06", "regular"),
+  ("my07", "regular"),
+  ("my08", "regular"),
+  ("my09", "regular"),
+  ("my10", "regular"),
+  ("my11", "regular"),
+  ("my12", "regular"),
+  ("my13", "regular"),
+  ("my14", "regular"),
+  ("my15", "regular"),
+  ("my16", "regular"),
+  ("myzzzz", "unknown"),
+  ("mza", "regular"),
+  ("mzb", "regular"),
+  ("mzg", "regular"),
+  ("mzi", "regular"),
+  ("mzl", "regular"),
+  ("mzmpm", "regular"),
+  ("mzn", "regular"),
+  ("mzp", "regular"),
+  ("mzq", "regular"),
+  ("mzs", "regular"),
+  ("mzt", "regular"),
+  ("mzzzzz", "unknown"),
+  ("naca", "regular"),
+  ("naer", "regular"),
+  ("naha", "regular"),
+  ("naka", "regular"),
+  ("nake", "regular"),
+  ("nakh", "regular"),
+  ("naku", "regular"),
+  ("nakw", "regular"),
+  ("naod", "regular"),
+  ("naoh", "regular"),
+  ("naon", "regular"),
+  ("naos", "regular"),
+  ("naot", "regular"),
+  ("naow", "regular"),
+  ("nazzzz", "unknown"),
+  ("nczzzz", "unknown"),
+  ("ne1", "regular"),
+  ("ne2", "regular"),
+  ("ne3", "regular"),
+  ("ne4", "regular"),
+  ("ne5", "regular"),
+  ("ne6", "regular"),
+  ("ne7", "regular"),
+  ("ne8", "regular"),
+  ("nezzzz", "unknown"),
+  ("nfzzzz", "unknown"),
+  ("ngab", "regular"),
+  ("ngad", "regular"),
+  ("ngak", "regular"),
+  ("ngan", "regular"),
+  ("ngba", "regular"),
+  ("ngbe", "regular"),
+  ("ngbo", "regular"),
+  ("ngby", "regular"),
+  ("ngcr", "regular"),
+  ("ngde", "regular"),
+  ("ngeb", "regular"),
+  ("nged", "regular"),
+  ("ngek", "regular"),
+  ("ngen", "regular"),
+  ("ngfc", "regular"),
+  ("nggo", "regular"),
+  ("ngim", "regular"),
+  ("ngji", "regular"),
+  ("ngkd", "regular"),
+  ("ngke", "regular"),
+  ("ngkn", "regular"),
+  ("ngko", "regular"),
+  ("ngkt", "regular"),
+  ("ngkw", "regular"),
+  ("ngla", "regular"),
+  ("ngna", "regular"),
+  ("ngni", "regular"),
+  ("ngog", "regular"),
+  ("ngon", "regular"),
+  ("ngos", "regular"),
+  ("ngoy", "regular"),
+  ("ngpl", "regular"),
+  ("ngri", "regular"),
+  ("ngso", "regular"),
+  ("ngta", "regular"),
+  ("ngyo", "regular"),
+  ("ngza", "regular"),
+  ("ngzzzz", "unknown"),
+  ("nian", "regular"),
+  ("nias", "regular"),
+  ("nibo", "regular"),
+  ("nica", "regular"),
+  ("nici", "regular"),
+  ("nico", "regular"),
+  ("nies", "regular"),
+  ("nigr", "regular"),
+  ("niji", "regular"),
+  ("nile", "regular"),
+  ("nimd", "regular"),
+  ("nimn", "regular"),
+  ("nims", "regular"),
+  ("nimt", "regular"),
+  ("nins", "regular"),
+  ("niri", "regular"),
+  ("nisj", "regular"),
+  ("nizzzz", "unknown"),
+  ("nlaw", "deprecated"),
+  ("nlbq1", "regular"),
+  ("nlbq2", "regular"),
+  ("nlbq3", "regular"),
+  ("nlcw", "deprecated"),
+  ("nldr", "regular"),
+  ("nlfl", "regular"),
+  ("nlfr", "regular"),
+  ("nlge", "regular"),
+  ("nlgr", "regular"),
+  ("nlli", "regular"),
+  ("nlnb", "regular"),
+  ("nlnh", "regular"),
+  ("nlov", "regular"),
+  ("nlsx", "deprecated"),
+  ("nlut", "regular"),
+  ("nlze", "regular"),
+  ("nlzh", "regular"),
+  ("nlzzzz", "unknown"),
+  ("no01", "deprecated"),
+  ("no02", "deprecated"),
+  ("no03", "regular"),
+  ("no04", "deprecated"),
+  ("no05", "deprecated"),
+  ("no06", "deprecated"),
+  ("no07", "deprecated"),
+  ("no08", "deprecated"),
+  ("no09", "deprecated"),
+  ("no10", "deprecated"),
+  ("no11", "regular"),
+  ("no12", "deprecated"),
+  ("no14", "deprecated"),
+  ("no15", "regular"),
+  ("no16", "deprecated"),
+  ("no17", "deprecated"),
+  ("no18", "regular"),
+  ("no19", "deprecated"),
+  ("no20", "deprecated"),
+  ("no21", "regular"),
+  ("no22", "regular"),
+  ("no23", "deprecated"),
+  ("no30", "regular"),
+  ("no34", "regular"),
+  ("no38", "regular"),
+  ("no42", "regular"),
+  ("no46", "regular"),
+  ("no50", "regular"),
+  ("no54", "regular"),
+  ("nozzzz", "unknown"),
+  ("np1", "regular"),
+  ("np2", "regular"),
+  ("np3", "regular"),
+  ("np4", "regular"),
+  ("np5", "regular"),
+  ("npba", "regular"),
+  ("npbh", "regular"),
+  ("npdh", "regular"),
+  ("npga", "regular"),
+  ("npja", "regular"),
+  ("npka", "regular"),
+  ("npko", "regular"),
+  ("nplu", "regular"),
+  ("npma", "regular"),
+  ("npme", "regular"),
+  ("npna", "regular"),
+  ("npp1", "regular"),
+  ("npp2", "regular"),
+  ("npp3", "regular"),
+  ("npp4", "regular"),
+  ("npp5", "regular"),
+  ("npp6", "regular"),
+  ("npp7", "regular"),
+  ("npra", "regular"),
+  ("npsa", "regular"),
+  ("npse", "regular"),
+  ("npzzzz", "unknown"),
+  ("nr01", "regular"),
+  ("nr02", "regular"),
+  ("nr03", "regular"),
+  ("nr04", "regular"),
+  ("nr05", "regular"),
+  ("nr06", "regular"),
+  ("nr07", "regular"),
+  ("nr08", "regular"),
+  ("nr09", "regular"),
+  ("nr10", "regular"),
+  ("nr11", "regular"),
+  ("nr12", "regular"),
+  ("nr13", "regular"),
+  ("nr14", "regular"),
+  ("nrzzzz", "unknown"),
+  ("nuzzzz", "unknown"),
+  ("nzauk", "regular"),
+  ("nzbop", "regular"),
+  ("nzcan", "regular"),
+  ("nzcit", "regular"),
+  ("nzgis", "regular"),
+  ("nzhkb", "regular"),
+  ("nzmbh", "regular"),
+  ("nzmwt", "regular"),
+  ("nzn", "deprecated"),
+  ("nznsn", "regular"),
+  ("nzntl", "regular"),
+  ("nzota", "regular"),
+  ("nzs", "deprecated"),
+  ("nzstl", "regular"),
+  ("nztas", "regular"),
+  ("nztki", "regular"),
+  ("nzwgn", "regular"),
+  ("nzwko", "regular"),
+  ("nzwtc", "regular"),
+  ("nzzzzz", "unknown"),
+  ("omba", "deprecated"),
+  ("ombj", "regular"),
+  ("ombs", "regular"),
+  ("ombu", "regular"),
+  ("omda", "regular"),
+  ("omma", "regular"),
+  ("ommu", "regular"),
+  ("omsh", "deprecated"),
+  ("omsj", "regular"),
+  ("omss", "regular"),
+  ("omwu", "regular"),
+  ("omza", "regular"),
+  ("omzu", "regular"),
+  ("omzzzz", "unknown"),
+  ("pa1", "regular"),
+  ("pa10", "regular"),
+  ("pa2", "regular"),
+  ("pa3", "regular"),
+  ("pa4", "regular"),
+  ("pa5", "regular"),
+  ("pa6", "regular"),
+  ("pa7", "regular"),
+  ("pa8", "regular"),
+  ("pa9", "regular"),
+  ("paem", "regular"),
+  ("paky", "regular"),
+  ("panb", "regular"),
+  ("pant", "regular"),
+  ("pazzzz", "unknown"),
+  ("peama", "regular"),
+  ("peanc", "regular"),
+  ("peapu", "regular"),
+  ("peare", "regular"),
+  ("peaya", "regular"),
+  ("pecaj", "regular"),
+  ("pecal", "regular"),
+  ("pecus", "regular"),
+  ("pehuc", "regular"),
+  ("pehuv", "regular"),
+  ("peica", "regular"),
+  ("pejun", "regular"),
+  ("pelal", "regular"),
+  ("pelam", "regular"),
+  ("pelim", "regular"),
+  ("pelma", "regular"),
+  ("pelor", "regular"),
+  ("pemdd", "regular"),
+  ("pemoq", "regular"),
+  ("pepas", "regular"),
+  ("pepiu", "regular"),
+  ("pepun", "regular"),
+  ("pesam", "regular"),
+  ("petac", "regular"),
+  ("petum", "regular"),
+  ("peuca", "regular"),
+  ("pezzzz", "unknown"),
+  ("pfzzzz", "unknown"),
+  ("pgcpk", "regular"),
+  ("pgcpm", "regular"),
+  ("pgebr", "regular"),
+  ("pgehg", "regular"),
+  ("pgepw", "regular"),
+  ("pgesw", "regular"),
+  ("pggpk", "regular"),
+  ("pghla", "regular"),
+  ("pgjwk", "regular"),
+  ("pgmba", "regular"),
+  ("pgmpl", "regular"),
+  ("pgmpm", "regular"),
+  ("pgmrl", "regular"),
+  ("pgncd", "regular"),
+  ("pgnik", "regular"),
+  ("pgnpp", "regular"),
+  ("pgnsb", "regular"),
+  ("pgsan", "regular"),
+  ("pgshm", "regular"),
+  ("pgwbk", "regular"),
+  ("pgwhm", "regular"),
+  ("pgwpd", "regular"),
+  ("pgzzzz", "unknown"),
+  ("ph00", "regular"),
+  ("ph01", "regular"),
+  ("ph02", "regular"),
+  ("ph03", "regular"),
+  ("ph05", "regular"),
+  ("ph06", "regular"),
+  ("ph07", "regular"),
+  ("ph08", "regular"),
+  ("ph09", "regular"),
+  ("ph10", "regular"),
+  ("ph11", "regular"),
+  ("ph12", "regular"),
+  ("ph13", "regular"),
+  ("ph14", "regular"),
+  ("ph15", "regular"),
+  ("ph40", "regular"),
+  ("ph41", "regular"),
+  ("phabr", "regular"),
+  ("phagn", "regular"),
+  ("phags", "regular"),
+  ("phakl", "regular"),
+  ("phalb", "regular"),
+  ("phant", "regular"),
+  ("phapa", "regular"),
+  ("phaur", "regular"),
+  ("phban", "regular"),
+  ("phbas", "regular"),
+  ("phben", "regular"),
+  ("phbil", "regular"),
+  ("phboh", "regular"),
+  ("phbtg", "regular"),
+  ("phbtn", "regular"),
+  ("phbuk", "regular"),
+  ("phbul", "regular"),
+  ("phcag", "regular"),
+  ("phcam", "regular"),
+  ("phcan", "regular"),
+  ("phcap", "regular"),
+  ("phcas", "regular"),
+  ("phcat", "regular"),
+  ("phcav", "regular"),
+  ("phceb", "regular"),
+  ("phcom", "regular"),
+  ("phdao", "regular"),
+  ("phdas", "regular"),
+  ("phdav", "regular"),
+  ("phdin", "regular"),
+  ("phdvo", "regular"),
+  ("pheas", "regular"),
+  ("phgui", "regular"),
+  ("phifu", "regular"),
+  ("phili", "regular"),
+  ("philn", "regular"),
+  ("phils", "regular"),
+  ("phisa", "regular"),
+  ("phkal", "regular"),
+  ("phlag", "regular"),
+  ("phlan", "regular"),
+  ("phlas", "regular"),
+  ("phley", "regular"),
+  ("phlun", "regular"),
+  ("phmad", "regular"),
+  ("phmag", "regular"),
+  ("phmas", "regular"),
+  ("phmdc", "regular"),
+  ("phmdr", "regular"),
+  ("phmou", "regular"),
+  ("phmsc", "regular"),
+  ("phmsr", "regular"),
+  ("phnco", "regular"),
+  ("phnec", "regular"),
+  ("phner", "regular"),
+  ("phnsa", "regular"),
+  ("phnue", "regular"),
+  ("phnuv", "regular"),
+  ("phpam", "regular"),
+  ("phpan", "regular"),
+  ("phplw", "regular"),
+  ("phque", "regular"),
+  ("phqui", "regular"),
+  ("phriz", "regular"),
+  ("phrom", "regular"),
+  ("phsar", "regular"),
+  ("phsco", "regular"),
+  ("phsig", "regular"),
+  ("phsle", "regular"),
+  ("phslu", "regular"),
+  ("phsor", "regular"),
+  ("phsuk", "regular"),
+  ("phsun", "regular"),
+  ("phsur", "regular"),
+  ("phtar", "regular"),
+  ("phtaw", "regular"),
+  ("phwsa", "regular"),
+  ("phzan", "regular"),
+  ("phzas", "regular"),
+  ("phzmb", "regular"),
+  ("phzsi", "regular"),
+  ("phzzzz", "unknown"),
+  ("pkba", "regular"),
+  ("pkgb", "regular"),
+  ("pkis", "regular"),
+  ("pkjk", "regular"),
+  ("pkkp", "regular"),
+  ("pkpb", "regular"),
+  ("pksd", "regular"),
+  ("pkta", "deprecated"),
+  ("pkzzzz", "unknown"),
+  ("pl02", "regular"),
+  ("pl04", "regular"),
+  ("pl06", "regular"),
+  ("pl08", "regular"),
+  ("pl10", "regular"),
+  ("pl12", "regular"),
+  ("pl14", "regular"),
+  ("pl16", "regular"),
+  ("pl18", "regular"),
+  ("pl20", "regular"),
+  ("pl22", "regular"),
+  ("pl24", "regular"),
+  ("pl26", "regular"),
+  ("pl28", "regular"),
+  ("pl30", "regular"),
+  ("pl32", "regular"),
+  ("plds", "deprecated"),
+  ("plkp", "deprecated"),
+  ("pllb", "deprecated"),
+  ("plld", "deprecated"),
+  ("pllu", "deprecated"),
+  ("plma", "deprecated"),
+  ("plmz", "deprecated"),
+  ("plop", "deprecated"),
+  ("plpd", "deprecated"),
+  ("plpk", "deprecated"),
+  ("plpm", "deprecated"),
+  ("plsk", "deprecated"),
+  ("plsl", "deprecated"),
+  ("plwn", "deprecated"),
+  ("plwp", "deprecated"),
+  ("plzp", "deprecated"),
+  ("plzzzz", "unknown"),
+  ("pmzzzz", "unknown"),
+  ("pnzzzz", "unknown"),
+  ("przzzz", "unknown"),
+  ("psbth", "regular"),
+  ("psdeb", "regular"),
+  ("psgza", "regular"),
+  ("pshbn", "regular"),
+  ("psjem", "regular"),
+  ("psjen", "regular"),
+  ("psjrh", "regular"),
+  ("pskys", "regular"),
+  ("psnbs", "regular"),
+  ("psngz", "regular"),
+  ("psqqa", "regular"),
+  ("psrbh", "regular"),
+  ("psrfh", "regular"),
+  ("psslt", "regular"),
+  ("pstbs", "regular"),
+  ("pstkm", "regular"),
+  ("pszzzz", "unknown"),
+  ("pt01", "regular"),
+  ("pt02", "regular"),
+  ("pt03", "regular"),
+  ("pt04", "regular"),
+  ("pt05", "regular"),
+  ("pt06", "regular"),
+  ("pt07", "regular"),
+  ("pt08", "regular"),
+  ("pt09", "regular"),
+  ("pt10", "regular"),
+  ("pt11", "regular"),
+  ("pt12", "regular"),
+  ("pt13", "regular"),
+  ("pt14", "regular"),
+  ("pt15", "regular"),
+  ("pt16", "regular"),
+  ("pt17", "regular"),
+  ("pt18", "regular"),
+  ("pt20", "regular"),
+  ("pt30", "regular"),
+  ("ptzzzz", "unknown"),
+  ("pw002", "regular"),
+  ("pw004", "regular"),
+  ("pw010", "regular"),
+  ("pw050", "regular"),
+  ("pw100", "regular"),
+  ("pw150", "regular"),
+  ("pw212", "regular"),
+  ("pw214", "regular"),
+  ("pw218", "regular"),
+  ("pw222", "regular"),
+  ("pw224", "regular"),
+  ("pw226", "regular"),
+  ("pw227", "regular"),
+  ("pw228", "regular"),
+  ("pw350", "regular"),
+  ("pw370", "regular"),
+  ("pwzzzz", "unknown"),
+  ("py1", "regular"),
+  ("py10", "regular"),
+  ("py11", "regular"),
+  ("py12", "regular"),
+  ("py13", "regular"),
+  ("py14", "regular"),
+  ("py15", "regular"),
+  ("py16", "regular"),
+  ("py19", "regular"),
+  ("py2", "regular"),
+  ("py3", "regular"),
+  ("py4", "regular"),
+  ("py5", "regular"),
+  ("py6", "regular"),
+  ("py7", "regular"),
+  ("py8", "regular"),
+  ("py9", "regular"),
+  ("pyasu", "regular"),
+  ("pyzzzz", "unknown"),
+  ("qada", "regular"),
+  ("qakh", "regular"),
+  ("qams", "regular"),
+  ("qara", "regular"),
+  ("qash", "regular"),
+  ("qaus", "regular"),
+  ("qawa", "regular"),
+  ("qaza", "regular"),
+  ("qazzzz", "unknown"),
+  ("rezzzz", "unknown"),
+  ("roab", "regular"),
+  ("roag", "regular"),
+  ("roar", "regular"),
+  ("rob", "regular"),
+  ("robc", "regular"),
+  ("robh", "regular"),
+  ("robn", "regular"),
+  ("robr", "regular"),
+  ("robt", "regular"),
+  ("robv", "regular"),
+  ("robz", "regular"),
+  ("rocj", "regular"),
+  ("rocl", "regular"),
+  ("rocs", "regular"),
+  ("roct", "regular"),
+  ("rocv", "regular"),
+  ("rodb", "regular"),
+  ("rodj", "regular"),
+  ("rogj", "regular"),
+  ("rogl", "regular"),
+  ("rogr", "regular"),
+  ("rohd", "regular"),
+  ("rohr", "regular"),
+  ("roif", "regular"),
+  ("roil", "regular"),
+  ("rois", "regular"),
+  ("romh", "regular"),
+  ("romm", "regular"),
+  ("roms", "regular"),
+  ("ront", "regular"),
+  ("root", "regular"),
+  ("roph", "regular"),
+  ("rosb", "regular"),
+  ("rosj", "regular"),
+  ("rosm", "regular"),
+  ("rosv", "regular"),
+  ("rotl", "regular"),
+  ("rotm", "regular"),
+  ("rotr", "regular"),
+  ("rovl", "regular"),
+  ("rovn", "regular"),
+  ("rovs", "regular"),
+  ("rozzzz", "unknown"),
+  ("rs00", "regular"),
+  ("rs01", "regular"),
+  ("rs02", "regular"),
+  ("rs03", "regular"),
+  ("rs04", "regular"),
+  ("rs05", "regular"),
+  ("rs06", "regular"),
+  ("rs07", "regular"),
+  ("rs08", "regular"),
+  ("rs09", "regular"),
+  ("rs10", "regular"),
+  ("rs11", "regular"),
+  ("rs12", "regular"),
+  ("rs13", "regular"),
+  ("rs14", "regular"),
+  ("rs15", "regular"),
+  ("rs16", "regular"),
+  ("rs17", "regular"),
+  ("rs18", "regular"),
+  ("rs19", "regular"),
+  ("rs20", "regular"),
+  ("rs21", "regular"),
+  ("rs22", "regular"),
+  ("rs23", "regular"),
+  ("rs24", "regular"),
+  ("rs25", "regular"),
+  ("rs26", "regular"),
+  ("rs27", "regular"),
+  ("rs28", "regular"),
+  ("rs29", "regular"),
+  ("rskm", "regular"),
+  ("rsvo", "regular"),
+  ("rszzzz", "unknown"),
+  ("ruad", "regular"),
+  ("rual", "regular"),
+  ("rualt", "regular"),
+  ("ruamu", "regular"),
+  ("ruark", "regular"),
+  ("ruast", "regular"),
+  ("ruba", "regular"),
+  ("rubel", "regular"),
+  ("rubry", "regular"),
+  ("rubu", "regular"),
+  ("ruce", "regular"),
+  ("ruche", "regular"),
+  ("ruchu", "regular"),
+  ("rucu", "regular"),
+  ("ruda", "regular"),
+  ("ruin", "regular"),
+  ("ruirk", "regular"),
+  ("ruiva", "regular"),
+  ("rukam", "regular"),
+  ("rukb", "regular"),
+  ("rukc", "regular"),
+  ("rukda", "regular"),
+  ("rukem", "regular"),
+  ("rukgd", "regular"),
+  ("rukgn", "regular"),
+  ("rukha", "regular"),
+  ("rukhm", "regular"),
+  ("rukir", "regular"),
+  ("rukk", "regular"),
+  ("rukl", "regular"),
+  ("ruklu", "regular"),
+  ("ruko", "regular"),
+  ("rukos", "regular"),
+  ("rukr", "regular"),
+  ("rukrs", "regular"),
+  ("rukya", "regular"),
+  ("rulen", "regular"),
+  ("rulip", "regular"),
+  ("rumag", "regular"),
+  ("rume", "regular"),
+  ("rumo", "regular"),
+  ("rumos", "regular"),
+  ("rumow", "regular"),
+  ("rumur", "regular"),
+  ("runen", "regular"),
+  ("rungr", "regular"),
+  ("runiz", "regular"),
+  ("runvs", "regular"),
+  ("ruoms", "regular"),
+  ("ruore", "regular"),
+  ("ruorl", "regular"),
+  ("ruper", "regular"),
+  ("rupnz", "regular"),
+  ("rupri", "regular"),
+  ("rupsk", "regular"),
+  ("ruros", "regular"),
+  ("rurya", "regular"),
+  ("rusa", "regular"),
+  ("rusak", "regular"),
+  ("rusam", "regular"),
+  ("rusar", "regular"),
+  ("ruse", "regular"),
+  ("rusmo", "regular"),
+  ("ruspe", "regular"),
+  ("rusta", "regular"),
+  ("rusve", "regular"),
+  ("ruta", "regular"),
+  ("rutam", "regular"),
+  ("rutom", "regular"),
+  ("rutul", "regular"),
+  ("rutve", "regular"),
+  ("ruty", "regular"),
+  ("rutyu", "regular"),
+  ("ruud", "regular"),
+  ("ruuly", "regular"),
+  ("ruvgg", "regular"),
+  ("ruvla", "regular"),
+  ("ruvlg", "regular"),
+  ("ruvor", "regular"),
+  ("ruyan", "regular"),
+  ("ruyar", "regular"),
+  ("ruyev", "regular"),
+  ("ruzab", "regular"),
+  ("ruzzzz", "unknown"),
+  ("rw01", "regular"),
+  ("rw02", "regular"),
+  ("rw03", "regular"),
+  ("rw04", "regular"),
+  ("rw05", "regular"),
+  ("rwzzzz", "unknown"),
+  ("sa01", "regular"),
+  ("sa02", "regular"),
+  ("sa03", "regular"),
+  ("sa04", "regular"),
+  ("sa05", "regular"),
+  ("sa06", "regular"),
+  ("sa07", "regular"),
+  ("sa08", "regular"),
+  ("sa09", "regular"),
+  ("sa10", "regular"),
+  ("sa11", "regular"),
+  ("sa12", "regular"),
+  ("sa14", "regular"),
+  ("sazzzz", "unknown"),
+  ("sbce", "regular"),
+  ("sbch", "regular"),
+  ("sbct", "regular"),
+  ("sbgu", "regular"),
+  ("sbis", "regular"),
+  ("sbmk", "regular"),
+  ("sbml", "regular"),
+  ("sbrb", "regular"),
+  ("sbte", "regular"),
+  ("sbwe", "regular"),
+  ("sbzzzz", "unknown"),
+  ("sc01", "regular"),
+  ("sc02", "regular"),
+  ("sc03", "regular"),
+  ("sc04", "regular"),
+  ("sc05", "regular"),
+  ("sc06", "regular"),
+  ("sc07", "regular"),
+  ("sc08", "regular"),
+  ("sc09", "regular"),
+  ("sc10", "regular"),
+  ("sc11", "regular"),
+  ("sc12", "regular"),
+  ("sc13", "regular"),
+  ("sc14", "regular"),
+  ("sc15", "regular"),
+  ("sc16", "regular"),
+  ("sc17", "regular"),
+  ("sc18", "regular"),
+  ("sc19", "regular"),
+  ("sc20", "regular"),
+  ("sc21", "regular"),
+  ("sc22", "regular"),
+  ("sc23", "regular"),
+  ("sc24", "regular"),
+  ("sc25", "regular"),
+  ("sc26", "regular"),
+  ("sc27", "regular"),
+  ("sczzzz", "unknown"),
+  ("sddc", "regular"),
+  ("sdde", "regular"),
+  ("sddn", "regular"),
+  ("sdds", "regular"),
+  ("sddw", "regular"),
+  ("sdgd", "regular"),
+  ("sdgk", "regular"),
+  ("sdgz", "regular"),
+  ("sdka", "regular"),
+  ("sdkh", "regular"),
+  ("sdkn", "regular"),
+  ("sdks", "regular"),
+  ("sdnb", "regular"),
+  ("sdno", "regular"),
+  ("sdnr", "regular"),
+  ("sdnw", "regular"),
+  ("sdrs", "regular"),
+  ("sdsi", "regular"),
+  ("sdzzzz", "unknown"),
+  ("seab", "regular"),
+  ("seac", "regular"),
+  ("sebd", "regular"),
+  ("sec", "regular"),
+  ("sed", "regular"),
+  ("see", "regular"),
+  ("sef", "regular"),
+  ("seg", "regular"),
+  ("seh", "regular"),
+  ("sei", "regular"),
+  ("sek", "regular"),
+  ("sem", "regular"),
+  ("sen", "regular"),
+  ("seo", "regular"),
+  ("ses", "regular"),
+  ("set", "regular"),
+  ("seu", "regular"),
+  ("sew", "regular"),
+  ("sex", "regular"),
+  ("sey", "regular"),
+  ("sez", "regular"),
+  ("sezzzz", "unknown"),
+  ("sg01", "regular"),
+  ("sg02", "regular"),
+  ("sg03", "regular"),
+  ("sg04", "regular"),
+  ("sg05", "regular"),
+  ("sgzzzz", "unknown"),
+  ("shac", "regular"),
+  ("shhl", "regular"),
+  ("shta", "deprecated"),
+  ("shzzzz", "unknown"),
+  ("si001", "regular"),
+  ("si002", "regular"),
+  ("si003", "regular"),
+  ("si004", "regular"),
+  ("si005", "regular"),
+  ("si006", "regular"),
+  ("si007", "regular"),
+  ("si008", "regular"),
+  ("si009", "regular"),
+  ("si010", "regular"),
+  ("si011", "regular"),
+  ("si012", "regular"),
+  ("si013", "regular"),
+  ("si014", "regular"),
+  ("si015", "regular"),
+  ("si016", "regular"),
+  ("si017", "regular"),
+  ("si018", "regular"),
+  ("si019", "regular"),
+  ("si020", "regular"),
+  ("si021", "regular"),
+  ("si022", "regular"),
+  ("si023", "regular"),
+  ("si024", "regular"),
+  ("si025", "regular"),
+  ("si026", "regular"),
+  ("si027", "regular"),
+  ("si028", "regular"),
+  ("si029", "regular"),
+  ("si030", "regular"),
+  ("si031", "regular"),
+  ("si032", "regular"),
+  ("si033", "regular"),
+  ("si034", "regular"),
+  ("si035", "regular"),
+  ("si036", "regular"),
+  ("si037", "regular"),
+  ("si038", "regular"),
+  ("si039", "regular"),
+  ("si040", "regular"),
+  ("si041", "regular"),
+  ("si042", "regular"),
+  ("si043", "regular"),
+  ("si044", "regular"),
+  ("si045", "regular"),
+  ("si046", "regular"),
+  ("si047", "regular"),
+  ("si048", "regular"),
+  ("si049", "regular"),
+  ("si050", "regular"),
+  ("si051", "regular"),
+  ("si052", "regular"),
+  ("si053", "regular"),
+  ("si054", "regular"),
+  ("si055", "regular"),
+  ("si056", "regular"),
+  ("si057", "regular"),
+  ("si058", "regular"),
+  ("si059", "regular"),
+  ("si060", "regular"),
+  ("si061", "regular"),
+  ("si062", "regular"),
+  ("si063", "regular"),
+  ("si064", "regular"),
+  ("si065", "regular"),
+  ("si066", "regular"),
+  ("si067", "regular"),
+  ("si068", "regular"),
+  ("si069", "regular"),
+  ("si070", "regular"),
+  ("si071", "regular"),
+  ("si072", "regular"),
+  ("si073", "regular"),
+  ("si074", "regular"),
+  ("si075", "regular"),
+  ("si076", "regular"),
+  ("si077", "regular"),
+  ("si078", "regular"),
+  ("si079", "regular"),
+  ("si080", "regular"),
+  ("si081", "regular"),
+  ("si082", "regular"),
+  ("si083", "regular"),
+  ("si084", "regular"),
+  ("si085", "regular"),
+  ("si086", "regular"),
+  ("si087", "regular"),
+  ("si088", "regular"),
+  ("si089", "regular"),
+  ("si090", "regular"),
+  ("si091", "regular"),
+  ("si092", "regular"),
+  ("si093", "regular"),
+  ("si094", "regular"),
+  ("si095", "regular"),
+  ("si096", "regular"),
+  ("si097", "regular"),
+  ("si098", "regular"),
+  ("si099", "regular"),
+  ("si100", "regular"),
+  ("si101", "regular"),
+  ("si102", "regular"),
+  ("si103", "regular"),
+  ("si104", "regular"),
+  ("si105", "regular"),
+  ("si106", "regular"),
+  ("si107", "regular"),
+  ("si108", "regular"),
+  ("si109", "regular"),
+  ("si110", "regular"),
+  ("si111", "regular"),
+  ("si112", "regular"),
+  ("si113", "regular"),
+  ("si114", "regular"),
+  ("si115", "regular"),
+  ("si116", "regular"),
+  ("si117", "regular"),
+  ("si118", "regular"),
+  ("si119", "regular"),
+  ("si120", "regular"),
+  ("si121", "regular"),
+  ("si122", "regular"),
+  ("si123", "regular"),
+  ("si124", "regular"),
+  ("si125", "regular"),
+  ("si126", "regular"),
+  ("si127", "regular"),
+  ("si128", "regular"),
+  ("si129", "regular"),
+  ("si130", "regular"),
+  ("si131", "regular"),
+  ("si132", "regular"),
+  ("si133", "regular"),
+  ("si134", "regular"),
+  ("si135", "regular"),
+  ("si136", "regular"),
+  ("si137", "regular"),
+  ("si138", "regular"),
+  ("si139", "regular"),
+  ("si140", "regular"),
+  ("si141", "regular"),
+  ("si142", "regular"),
+  ("si143", "regular"),
+  ("si144", "regular"),
+  ("si146", "regular"),
+  ("si147", "regular"),
+  ("si148", "regular"),
+  ("si149", "regular"),
+  ("si150", "regular"),
+  ("si151", "regular"),
+  ("si152", "regular"),
+  ("si153", "regular"),
+  ("si154", "regular"),
+  ("si155", "regular"),
+  ("si156", "regular"),
+  ("si157", "regular"),
+  ("si158", "regular"),
+  ("si159", "regular"),
+  ("si160", "regular"),
+  ("si161", "regular"),
+  ("si162", "regular"),
+  ("si163", "regular"),
+  ("si164", "regular"),
+  ("si165", "regular"),
+  ("si166", "regular"),
+  ("si167", "regular"),
+  ("si168", "regular"),
+  ("si169", "regular"),
+  ("si170", "regular"),
+  ("si171", "regular"),
+  ("si172", "regular"),
+  ("si173", "regular"),
+  ("si174", "regular"),
+  ("si175", "regular"),
+  ("si176", "regular"),
+  ("si177", "regular"),
+  ("si178", "regular"),
+  ("si179", "regular"),
+  ("si180", "regular"),
+  ("si181", "regular"),
+  ("si182", "regular"),
+  ("si183", "regular"),
+  ("si184", "regular"),
+  ("si185", "regular"),
+  ("si186", "regular"),
+  ("si187", "regular"),
+  ("si188", "regular"),
+  ("si189", "regular"),
+  ("si190", "regular"),
+  ("si191", "regular"),
+  ("si192", "regular"),
+  ("si193", "regular"),
+  ("si194", "regular"),
+  ("si195", "regular"),
+  ("si196", "regular"),
+  ("si197", "regular"),
+  ("si198", "regular"),
+  ("si199", "regular"),
+  ("si200", "regular"),
+  ("si201", "regular"),
+  ("si202", "regular"),
+  ("si203", "regular"),
+  ("si204", "regular"),
+  ("si205", "regular"),
+  ("si206", "regular"),
+  ("si207", "regular"),
+  ("si208", "regular"),
+  ("si209", "regular"),
+  ("si210", "regular"),
+  ("si211", "regular"),
+  ("si212", "regular"),
+  ("si213", "regular"),
+  ("sizzzz", "unknown"),
+  ("sjzzzz", "unknown"),
+  ("skbc", "regular"),
+  ("skbl", "regular"),
+  ("skki", "regular"),
+  ("skni", "regular"),
+  ("skpv", "regular"),
+  ("skta", "regular"),
+  ("sktc", "regular"),
+  ("skzi", "regular"),
+  ("skzzzz", "unknown"),
+  ("sle", "regular"),
+  ("sln", "regular"),
+  ("slnw", "regular"),
+  ("sls", "regular"),
+  ("slw", "regular"),
+  ("slzzzz", "unknown"),
+  ("sm01", "regular"),
+  ("sm02", "regular"),
+  ("sm03", "regular"),
+  ("sm04", "regular"),
+  ("sm05", "regular"),
+  ("sm06", "regular"),
+  ("sm07", "regular"),
+  ("sm08", "regular"),
+  ("sm09", "regular"),
+  ("smzzzz", "unknown"),
+  ("sndb", "regular"),
+  ("sndk", "regular"),
+  ("snfk", "regular"),
+  ("snka", "regular"),
+  ("snkd", "regular"),
+  ("snke", "regular"),
+  ("snkl", "regular"),
+  ("snlg", "regular"),
+  ("snmt", "regular"),
+  ("snse", "regular"),
+  ("snsl", "regular"),
+  ("sntc", "regular"),
+  ("snth", "regular"),
+  ("snzg", "regular"),
+  ("snzzzz", "unknown"),
+  ("soaw", "regular"),
+  ("sobk", "regular"),
+  ("sobn", "regular"),
+  ("sobr", "regular"),
+  ("soby", "regular"),
+  ("soga", "regular"),
+  ("soge", "regular"),
+  ("sohi", "regular"),
+  ("sojd", "regular"),
+  ("sojh", "regular"),
+  ("somu", "regular"),
+  ("sonu", "regular"),
+  ("sosa", "regular"),
+  ("sosd", "regular"),
+  ("sosh", "regular"),
+  ("soso", "regular"),
+  ("soto", "regular"),
+  ("sowo", "regular"),
+  ("sozzzz", "unknown"),
+  ("srbr", "regular"),
+  ("srcm", "regular"),
+  ("srcr", "regular"),
+  ("srma", "regular"),
+  ("srni", "regular"),
+  ("srpm", "regular"),
+  ("srpr", "regular"),
+  ("srsa", "regular"),
+  ("srsi", "regular"),
+  ("srwa", "regular"),
+  ("srzzzz", "unknown"),
+  ("ssbn", "regular"),
+  ("ssbw", "regular"),
+  ("ssec", "regular"),
+  ("ssee", "regular"),
+  ("ssew", "regular"),
+  ("ssjg", "regular"),
+  ("sslk", "regular"),
+  ("ssnu", "regular"),
+  ("ssuy", "regular"),
+  ("sswr", "regular"),
+  ("sszzzz", "unknown"),
+  ("st01", "regular"),
+  ("st02", "regular"),
+  ("st03", "regular"),
+  ("st04", "regular"),
+  ("st05", "regular"),
+  ("st06", "regular"),
+  ("stp", "regular"),
+  ("sts", "deprecated"),
+  ("stzzzz", "unknown"),
+  ("svah", "regular"),
+  ("svca", "regular"),
+  ("svch", "regular"),
+  ("svcu", "regular"),
+  ("svli", "regular"),
+  ("svmo", "regular"),
+  ("svpa", "regular"),
+  ("svsa", "regular"),
+  ("svsm", "regular"),
+  ("svso", "regular"),
+  ("svss", "regular"),
+  ("svsv", "regular"),
+  ("svun", "regular"),
+  ("svus", "regular"),
+  ("svzzzz", "unknown"),
+  ("sxzzzz", "unknown"),
+  ("sydi", "regular"),
+  ("sydr", "regular"),
+  ("sydy", "regular"),
+  ("syha", "regular"),
+  ("syhi", "regular"),
+  ("syhl", "regular"),
+  ("syhm", "regular"),
+  ("syid", "regular"),
+  ("syla", "regular"),
+  ("syqu", "regular"),
+  ("syra", "regular"),
+  ("syrd", "regular"),
+  ("sysu", "regular"),
+  ("syta", "regular"),
+  ("syzzzz", "unknown"),
+  ("szhh", "regular"),
+  ("szlu", "regular"),
+  ("szma", "regular"),
+  ("szsh", "regular"),
+  ("szzzzz", "unknown"),
+  ("tazzzz", "unknown"),
+  ("tczzzz", "unknown"),
+  ("tdba", "regular"),
+  ("tdbg", "regular"),
+  ("tdbo", "regular"),
+  ("tdcb", "regular"),
+  ("tdee", "regular"),
+  ("tdeo", "regular"),
+  ("tdgr", "regular"),
+  ("tdhl", "regular"),
+  ("tdka", "regular"),
+  ("tdlc", "regular"),
+  ("tdlo", "regular"),
+  ("tdlr", "regular"),
+  ("tdma", "regular"),
+  ("tdmc", "regular"),
+  ("tdme", "regular"),
+  ("tdmo", "regular"),
+  ("tdnd", "regular"),
+  ("tdod", "regular"),
+  ("tdsa", "regular"),
+  ("tdsi", "regular"),
+  ("tdta", "regular"),
+  ("tdti", "regular"),
+  ("tdwf", "regular"),
+  ("tdzzzz", "unknown"),
+  ("tfzzzz", "unknown"),
+  ("tgc", "regular"),
+  ("tgk", "regular"),
+  ("tgm", "regular"),
+  ("tgp", "regular"),
+  ("tgs", "regular"),
+  ("tgzzzz", "unknown"),
+  ("th10", "regular"),
+  ("th11", "regular"),
+  ("th12", "regular"),
+  ("th13", "regular"),
+  ("th14", "regular"),
+  ("th15", "regular"),
+  ("th16", "regular"),
+  ("th17", "regular"),
+  ("th18", "regular"),
+  ("th19", "regular"),
+  ("th20", "regular"),
+  ("th21", "regular"),
+  ("th22", "regular"),
+  ("th23", "regular"),
+  ("th24", "regular"),
+  ("th25", "regular"),
+  ("th26", "regular"),
+  ("th27", "regular"),
+  ("th30", "regular"),
+  ("th31", "regular"),
+  ("th32", "regular"),
+  ("th33", "regular"),
+  ("th34", "regular"),
+  ("th35", "regular"),
+  ("th36", "regular"),
+  ("th37", "regular"),
+  ("th38", "regular"),
+  ("th39", "regular"),
+  ("th40", "regular"),
+  ("th41", "regular"),
+  ("th42", "regular"),
+  ("th43", "regular"),
+  ("th44", "regular"),
+  ("th45", "regular"),
+  ("th46", "regular"),
+  ("th47", "regular"),
+  ("th48", "regular"),
+  ("th49", "regular"),
+  ("th50", "regular"),
+  ("th51", "regular"),
+  ("th52", "regular"),
+  ("th53", "regular"),
+  ("th54", "regular"),
+  ("th55", "regular"),
+  ("th56", "regular"),
+  ("th57", "regular"),
+  ("th58", "regular"),
+  ("th60", "regular"),
+  ("th61", "regular"),
+  ("th62", "regular"),
+  ("th63", "regular"),
+  ("th64", "regular"),
+  ("th65", "regular"),
+  ("th66", "regular"),
+  ("th67", "regular"),
+  ("th70", "regular"),
+  ("th71", "regular"),
+  ("th72", "regular"),
+  ("th73", "regular"),
+  ("th74", "regular"),
+  ("th75", "regular"),
+  ("th76", "regular"),
+  ("th77", "regular"),
+  ("th80", "regular"),
+  ("th81", "regular"),
+  ("th82", "regular"),
+  ("th83", "regular"),
+  ("th84", "regular"),
+  ("th85", "regular"),
+  ("th86", "regular"),
+  ("th90", "regular"),
+  ("th91", "regular"),
+  ("th92", "regular"),
+  ("th93", "regular"),
+  ("th94", "regular"),
+  ("th95", "regular"),
+  ("th96", "regular"),
+  ("ths", "regular"),
+  ("thzzzz", "unknown"),
+  ("tjdu", "regular"),
+  ("tjgb", "regular"),
+  ("tjkt", "regular"),
+  ("tjra", "regular"),
+  ("tjsu", "regular"),
+  ("tjzzzz", "unknown"),
+  ("tkzzzz", "unknown"),
+  ("tlal", "regular"),
+  ("tlan", "regular"),
+  ("tlba", "regular"),
+  ("tlbo", "regular"),
+  ("tlco", "regular"),
+  ("tldi", "regular"),
+  ("tler", "regular"),
+  ("tlla", "regular"),
+  ("tlli", "regular"),
+  ("tlmf", "regular"),
+  ("tlmt", "regular"),
+  ("tloe", "regular"),
+  ("tlvi", "regular"),
+  ("tlzzzz", "unknown"),
+  ("tma", "regular"),
+  ("tmb", "regular"),
+  ("tmd", "regular"),
+  ("tml", "regular"),
+  ("tmm", "regular"),
+  ("tms", "regular"),
+  ("tmzzzz", "unknown"),
+  ("tn11", "regular"),
+  ("tn12", "regular"),
+  ("tn13", "regular"),
+  ("tn14", "regular"),
+  ("tn21", "regular"),
+  ("tn22", "regular"),
+  ("tn23", "regular"),
+  ("tn31", "regular"),
+  ("tn32", "regular"),
+  ("tn33", "regular"),
+  ("tn34", "regular"),
+  ("tn41", "regular"),
+  ("tn42", "regular"),
+  ("tn43", "regular"),
+  ("tn51", "regular"),
+  ("tn52", "regular"),
+  ("tn53", "regular"),
+  ("tn61", "regular"),
+  ("tn71", "regular"),
+  ("tn72", "regular"),
+  ("tn73", "regular"),
+  ("tn81", "regular"),
+  ("tn82", "regular"),
+  ("tn83", "regular"),
+  ("tnzzzz", "unknown"),
+  ("to01", "regular"),
+  ("to02", "regular"),
+  ("to03", "regular"),
+  ("to04", "regular"),
+  ("to05", "regular"),
+  ("tozzzz", "unknown"),
+  ("tr01", "regular"),
+  ("tr02", "regular"),
+  ("tr03", "regular"),
+  ("tr04", "regular"),
+  ("tr05", "regular"),
+  ("tr06", "regular"),
+  ("tr07", "regular"),
+  ("tr08", "regular"),
+  ("tr09", "regular"),
+  ("tr10", "regular"),
+  ("tr11", "regular"),
+  ("tr12", "regular"),
+  ("tr13", "regular"),
+  ("tr14", "regular"),
+  ("tr15", "regular"),
+  ("tr16", "regular"),
+  ("tr17", "regular"),
+  ("tr18", "regular"),
+  ("tr19", "regular"),
+  ("tr20", "regular"),
+  ("tr21", "regular"),
+  ("tr22", "regular"),
+  ("tr23", "regular"),
+  ("tr24", "regular"),
+  ("tr25", "regular"),
+  ("tr26", "regular"),
+  ("tr27", "regular"),
+  ("tr28", "regular"),
+  ("tr29", "regular"),
+  ("tr30", "regular"),
+  ("tr31", "regular"),
+  ("tr32", "regular"),
+  ("tr33", "regular"),
+  ("tr34", "regular"),
+  ("tr35", "regular"),
+  ("tr36", "regular"),
+  ("tr37", "regular"),
+  ("tr38", "regular"),
+  ("tr39", "regular"),
+  ("tr40", "regular"),
+  ("tr41", "regular"),
+  ("tr42", "regular"),
+  ("tr43", "regular"),
+  ("tr44", "regular"),
+  ("tr45", "regular"),
+  ("tr46", "regular"),
+  ("tr47", "regular"),
+  ("tr48", "regular"),
+  ("tr49", "regular"),
+  ("tr50", "regular"),
+  ("tr51", "regular"),
+  ("tr52", "regular"),
+  ("tr53", "regular"),
+  ("tr54", "regular"),
+  ("tr55", "regular"),
+  ("tr56", "regular"),
+  ("tr57", "regular"),
+  ("tr58", "regular"),
+  ("tr59", "regular"),
+  ("tr60", "regular"),
+  ("tr61", "regular"),
+  ("tr62", "regular"),
+  ("tr63", "regular"),
+  ("tr64", "regular"),
+  ("tr65", "regular"),
+  ("tr66", "regular"),
+  ("tr67", "regular"),
+  ("tr68", "regular"),
+  ("tr69", "regular"),
+  ("tr70", "regular"),
+  ("tr71", "regular"),
+  ("tr72", "regular"),
+  ("tr73", "regular"),
+  ("tr74", "regular"),
+  ("tr75", "regular"),
+  ("tr76", "regular"),
+  ("tr77", "regular"),
+  ("tr78", "regular"),
+  ("tr79", "regular"),
+  ("tr80", "regular"),
+  ("tr81", "regular"),
+  ("trzzzz", "unknown"),
+  ("ttari", "regular"),
+  ("ttcha", "regular"),
+  ("ttctt", "regular"),
+  ("ttdmn", "regular"),
+  ("tteto", "deprecated"),
+  ("ttmrc", "regular"),
+  ("ttped", "regular"),
+  ("ttpos", "regular"),
+  ("ttprt", "regular"),
+  ("ttptf", "regular"),
+  ("ttrcm", "deprecated"),
+  ("ttsfo", "regular"),
+  ("ttsge", "regular"),
+  ("ttsip", "regular"),
+  ("ttsjl", "regular"),
+  ("tttob", "regular"),
+  ("tttup", "regular"),
+  ("ttwto", "deprecated"),
+  ("ttzzzz", "unknown"),
+  ("tvfun", "regular"),
+  ("tvnit", "regular"),
+  ("tvnkf", "regular"),
+  ("tvnkl", "regular"),
+  ("tvnma", "regular"),
+  ("tvnmg", "regular"),
+  ("tvnui", "regular"),
+  ("tvvai", "regular"),
+  ("tvzzzz", "unknown"),
+  ("twcha", "regular"),
+  ("twcyi", "regular"),
+  ("twcyq", "regular"),
+  ("twhsq", "regular"),
+  ("twhsz", "regular"),
+  ("twhua", "regular"),
+  ("twila", "regular"),
+  ("twkee", "regular"),
+  ("twkhh", "regular"),
+  ("twkhq", "deprecated"),
+  ("twkin", "regular"),
+  ("twlie", "regular"),
+  ("twmia", "regular"),
+  ("twnan", "regular"),
+  ("twnwt", "regular"),
+  ("twpen", "regular"),
+  ("twpif", "regular"),
+  ("twtao", "regular"),
+  ("twtnn", "regular"),
+  ("twtnq", "deprecated"),
+  ("twtpe", "regular"),
+  ("twtpq", "deprecated"),
+  ("twttt", "regular"),
+  ("twtxg", "regular"),
+  ("twtxq", "deprecated"),
+  ("twyun", "regular"),
+  ("twzzzz", "unknown"),
+  ("tz01", "regular"),
+  ("tz02", "regular"),
+  ("tz03", "regular"),
+  ("tz04", "regular"),
+  ("tz05", "regular"),
+  ("tz06", "regular"),
+  ("tz07", "regular"),
+  ("tz08", "regular"),
+  ("tz09", "regular"),
+  ("tz10", "regular"),
+  ("tz11", "regular"),
+  ("tz12", "regular"),
+  ("tz13", "regular"),
+  ("tz14", "regular"),
+  ("tz15", "regular"),
+  ("tz16", "regular"),
+  ("tz17", "regular"),
+  ("tz18", "regular"),
+  ("tz19", "regular"),
+  ("tz20", "regular"),
+  ("tz21", "regular"),
+  ("tz22", "regular"),
+  ("tz23", "regular"),
+  ("tz24", "regular"),
+  ("tz25", "regular"),
+  ("tz26", "regular"),
+  ("tz27", "regular"),
+  ("tz28", "regular"),
+  ("tz29", "regular"),
+  ("tz30", "regular"),
+  ("tz31", "regular"),
+  ("tzzzzz", "unknown"),
+  ("ua05", "regular"),
+  ("ua07", "regular"),
+  ("ua09", "regular"),
+  ("ua12", "regular"),
+  ("ua14", "regular"),
+  ("ua18", "regular"),
+  ("ua21", "regular"),
+  ("ua23", "regular"),
+  ("ua26", "regular"),
+  ("ua30", "regular"),
+  ("ua32", "regular"),
+  ("ua35", "regular"),
+  ("ua40", "regular"),
+  ("ua43", "regular"),
+  ("ua46", "regular"),
+  ("ua48", "regular"),
+  ("ua51", "regular"),
+  ("ua53", "regular"),
+  ("ua56", "regular"),
+  ("ua59", "regular"),
+  ("ua61", "regular"),
+  ("ua63", "regular"),
+  ("ua65", "regular"),
+  ("ua68", "regular"),
+  ("ua71", "regular"),
+  ("ua74", "regular"),
+  ("ua77", "regular"),
+  ("uazzzz", "unknown"),
+  ("ug101", "regular"),
+  ("ug102", "regular"),
+  ("ug103", "regular"),
+  ("ug104", "regular"),
+  ("ug105", "regular"),
+  ("ug106", "regular"),
+  ("ug107", "regular"),
+  ("ug108", "regular"),
+  ("ug109", "regular"),
+  ("ug110", "regular"),
+  ("ug111", "regular"),
+  ("ug112", "regular"),
+  ("ug113", "regular"),
+  ("ug114", "regular"),
+  ("ug115", "regular"),
+  ("ug116", "regular"),
+  ("ug117", "regular"),
+  ("ug118", "regular"),
+  ("ug119", "regular"),
+  ("ug120", "regular"),
+  ("ug121", "regular"),
+  ("ug122", "regular"),
+  ("ug123", "regular"),
+  ("ug124", "regular"),
+  ("ug125", "regular"),
+  ("ug126", "regular"),
+  ("ug201", "regular"),
+  ("ug202", "regular"),
+  ("ug203", "regular"),
+  ("ug204", "regular"),
+  ("ug205", "regular"),
+  ("ug206", "regular"),
+  ("ug207", "regular"),
+  ("ug208", "regular"),
+  ("ug209", "regular"),
+  ("ug210", "regular"),
+  ("ug211", "regular"),
+  ("ug212", "regular"),
+  ("ug213", "regular"),
+  ("ug214", "regular"),
+  ("ug215", "regular"),
+  ("ug216", "regular"),
+  ("ug217", "regular"),
+  ("ug218", "regular"),
+  ("ug219", "regular"),
+  ("ug220", "regular"),
+  ("ug221", "regular"),
+  ("ug222", "regular"),
+  ("ug223", "regular"),
+  ("ug224", "regular"),
+  ("ug225", "regular"),
+  ("ug226", "regular"),
+  ("ug227", "regular"),
+  ("ug228", "regular"),
+  ("ug229", "regular"),
+  ("ug230", "regular"),
+  ("ug231", "regular"),
+  ("ug232", "regular"),
+  ("ug233", "regular"),
+  ("ug234", "regular"),
+  ("ug235", "regular"),
+  ("ug236", "regular"),
+  ("ug237", "regular"),
+  ("ug301", "regular"),
+  ("ug302", "regular"),
+  ("ug303", "regular"),
+  ("ug304", "regular"),
+  ("ug305", "regular"),
+  ("ug306", "regular"),
+  ("ug307", "regular"),
+  ("ug308", "regular"),
+  ("ug309", "regular"),
+  ("ug310", "regular"),
+  ("ug311", "regular"),
+  ("ug312", "regular"),
+  ("ug313", "regular"),
+  ("ug314", "regular"),
+  ("ug315", "regular"),
+  ("ug316", "regular"),
+  ("ug317", "regular"),
+  ("ug318", "regular"),
+  ("ug319", "regular"),
+  ("ug320", "regular"),
+  ("ug321", "regular"),
+  ("ug322", "regular"),
+  ("ug323", "regular"),
+  ("ug324", "regular"),
+  ("ug325", "regular"),
+  ("ug326", "regular"),
+  ("ug327", "regular"),
+  ("ug328", "regular"),
+  ("ug329", "regular"),
+  ("ug330", "regular"),
+  ("ug331", "regular"),
+  ("ug332", "regular"),
+  ("ug333", "regular"),
+  ("ug334", "regular"),
+  ("ug335", "regular"),
+  ("ug336", "regular"),
+  ("ug337", "regular"),
+  ("ug401", "regular"),
+  ("ug402", "regular"),
+  ("ug403", "regular"),
+  ("ug404", "regular"),
+  ("ug405", "regular"),
+  ("ug406", "regular"),
+  ("ug407", "regular"),
+  ("ug408", "regular"),
+  ("ug409", "regular"),
+  ("ug410", "regular"),
+  ("ug411", "regular"),
+  ("ug412", "regular"),
+  ("ug413", "regular"),
+  ("ug414", "regular"),
+  ("ug415", "regular"),
+  ("ug416", "regular"),
+  ("ug417", "regular"),
+  ("ug418", "regular"),
+  ("ug419", "regular"),
+  ("ug420", "regular"),
+  ("ug421", "regular"),
+  ("ug422", "regular"),
+  ("ug423", "regular"),
+  ("ug424", "regular"),
+  ("ug425", "regular"),
+  ("ug426", "regular"),
+  ("ug427", "regular"),
+  ("ug428", "regular"),
+  ("ug429", "regular"),
+  ("ug430", "regular"),
+  ("ug431", "regular"),
+  ("ug432", "regular"),
+  ("ug433", "regular"),
+  ("ug434", "regular"),
+  ("ug435", "regular"),
+  ("ugc", "regular"),
+  ("uge", "regular"),
+  ("ugn", "regular"),
+  ("ugw", "regular"),
+  ("ugzzzz", "unknown"),
+  ("um67", "regular"),
+  ("um71", "regular"),
+  ("um76", "regular"),
+  ("um79", "regular"),
+  ("um81", "regular"),
+  ("um84", "regular"),
+  ("um86", "regular"),
+  ("um89", "regular"),
+  ("um95", "regular"),
+  ("umzzzz", "unknown"),
+  ("usak", "regular"),
+  ("usal", "regular"),
+  ("usar", "regular"),
+  ("usas", "deprecated"),
+  ("usaz", "regular"),
+  ("usca", "regular"),
+  ("usco", "regular"),
+  ("usct", "regular"),
+  ("usdc", "regular"),
+  ("usde", "regular"),
+  ("usfl", "regular"),
+  ("usga", "regular"),
+  ("usgu", "deprecated"),
+  ("ushi", "regular"),
+  ("usia", "regular"),
+  ("usid", "regular"),
+  ("usil", "regular"),
+  ("usin", "regular"),
+  ("usks", "regular"),
+  ("usky", "regular"),
+  ("usla", "regular"),
+  ("usma", "regular"),
+  ("usmd", "regular"),
+  ("usme", "regular"),
+  ("usmi", "regular"),
+  ("usmn", "regular"),
+  ("usmo", "regular"),
+  ("usmp", "deprecated"),
+  ("usms", "regular"),
+  ("usmt", "regular"),
+  ("usnc", "regular"),
+  ("usnd", "regular"),
+  ("usne", "regular"),
+  ("usnh", "regular"),
+  ("usnj", "regular"),
+  ("usnm", "regular"),
+  ("usnv", "regular"),
+  ("usny", "regular"),
+  ("usoh", "regular"),
+  ("usok", "regular"),
+  ("usor", "regular"),
+  ("uspa", "regular"),
+  ("uspr", "deprecated"),
+  ("usri", "regular"),
+  ("ussc", "regular"),
+  ("ussd", "regular"),
+  ("ustn", "regular"),
+  ("ustx", "regular"),
+  ("usum", "deprecated"),
+  ("usut", "regular"),
+  ("usva", "regular"),
+  ("usvi", "deprecated"),
+  ("usvt", "regular"),
+  ("uswa", "regular"),
+  ("uswi", "regular"),
+  ("uswv", "regular"),
+  ("uswy", "regular"),
+  ("uszzzz", "unknown"),
+  ("uyar", "regular"),
+  ("uyca", "regular"),
+  ("uycl", "regular"),
+  ("uyco", "regular"),
+  ("uydu", "regular"),
+  ("uyfd", "regular"),
+  ("uyfs", "regular"),
+  ("uyla", "regular"),
+  ("uyma", "regular"),
+  ("uymo", "regular"),
+  ("uypa", "regular"),
+  ("uyrn", "regular"),
+  ("uyro", "regular"),
+  ("uyrv", "regular"),
+  ("uysa", "regular"),
+  ("uysj", "regular"),
+  ("uyso", "regular"),
+  ("uyta", "regular"),
+  ("uytt", "regular"),
+  ("uyzzzz", "unknown"),
+  ("uzan", "regular"),
+  ("uzbu", "regular"),
+  ("uzfa", "regular"),
+  ("uzji", "regular"),
+  ("uzng", "regular"),
+  ("uznw", "regular"),
+  ("uzqa", "regular"),
+  ("uzqr", "regular"),
+  ("uzsa", "regular"),
+  ("uzsi", "regular"),
+  ("uzsu", "regular"),
+  ("uztk", "regular"),
+  ("uzto", "regular"),
+  ("uzxo", "regular"),
+  ("uzzzzz", "unknown"),
+  ("vazzzz", "unknown"),
+  ("vc01", "regular"),
+  ("vc02", "regular"),
+  ("vc03", "regular"),
+  ("vc04", "regular"),
+  ("vc05", "regular"),
+  ("vc06", "regular"),
+  ("vczzzz", "unknown"),
+  ("vea", "regular"),
+  ("veb", "regular"),
+  ("vec", "regular"),
+  ("ved", "regular"),
+  ("vee", "regular"),
+  ("vef", "regular"),
+  ("veg", "regular"),
+  ("veh", "regular"),
+  ("vei", "regular"),
+  ("vej", "regular"),
+  ("vek", "regular"),
+  ("vel", "regular"),
+  ("vem", "regular"),
+  ("ven", "regular"),
+  ("veo", "regular"),
+  ("vep", "regular"),
+  ("ver", "regular"),
+  ("ves", "regular"),
+  ("vet", "regular"),
+  ("veu", "regular"),
+  ("vev", "regular"),
+  ("vew", "regular"),
+  ("vex", "regular"),
+  ("vey", "regular"),
+  ("vez", "regular"),
+  ("vezzzz", "unknown"),
+  ("vgzzzz", "unknown"),
+  ("vizzzz", "unknown"),
+  ("vn01", "regular"),
+  ("vn02", "regular"),
+  ("vn03", "regular"),
+  ("vn04", "regular"),
+  ("vn05", "regular"),
+  ("vn06", "regular"),
+  ("vn07", "regular"),
+  ("vn09", "regular"),
+  ("vn13", "regular"),
+  ("vn14", "regular"),
+  ("vn18", "regular"),
+  ("vn20", "regular"),
+  ("vn21", "regular"),
+  ("vn22", "regular"),
+  ("vn23", "regular"),
+  ("vn24", "regular"),
+  ("vn25", "regular"),
+  ("vn26", "regular"),
+  ("vn27", "regular"),
+  ("vn28", "regular"),
+  ("vn29", "regular"),
+  ("vn30", "regular"),
+  ("vn31", "regular"),
+  ("vn32", "regular"),
+  ("vn33", "regular"),
+  ("vn34", "regular"),
+  ("vn35", "regular"),
+  ("vn36", "regular"),
+  ("vn37", "regular"),
+  ("vn39", "regular"),
+  ("vn40", "regular"),
+  ("vn41", "regular"),
+  ("vn43", "regular"),
+  ("vn44", "regular"),
+  ("vn45", "regular"),
+  ("vn46", "regular"),
+  ("vn47", "regular"),
+  ("vn49", "regular"),
+  ("vn50", "regular"),
+  ("vn51", "regular"),
+  ("vn52", "regular"),
+  ("vn53", "regular"),
+  ("vn54", "regular"),
+  ("vn55", "regular"),
+  ("vn56", "regular"),
+  ("vn57", "regular"),
+  ("vn58", "regular"),
+  ("vn59", "regular"),
+  ("vn61", "regular"),
+  ("vn63", "regular"),
+  ("vn66", "regular"),
+  ("vn67", "regular"),
+  ("vn68", "regular"),
+  ("vn69", "regular"),
+  ("vn70", "regular"),
+  ("vn71", "regular"),
+  ("vn72", "regular"),
+  ("vn73", "regular"),
+  ("vnct", "regular"),
+  ("vndn", "regular"),
+  ("vnhn", "regular"),
+  ("vnhp", "regular"),
+  ("vnsg", "regular"),
+  ("vnzzzz", "unknown"),
+  ("vumap", "regular"),
+  ("vupam", "regular"),
+  ("vusam", "regular"),
+  ("vusee", "regular"),
+  ("vutae", "regular"),
+  ("vutob", "regular"),
+  ("vuzzzz", "unknown"),
+  ("wfal", "regular"),
+  ("wfsg", "regular"),
+  ("wfuv", "regular"),
+  ("wfzzzz", "unknown"),
+  ("wsaa", "regular"),
+  ("wsal", "regular"),
+  ("wsat", "regular"),
+  ("wsfa", "regular"),
+  ("wsge", "regular"),
+  ("wsgi", "regular"),
+  ("wspa", "regular"),
+  ("wssa", "regular"),
+  ("wstu", "regular"),
+  ("wsvf", "regular"),
+  ("wsvs", "regular"),
+  ("wszzzz", "unknown"),
+  ("xkzzzz", "unknown"),
+  ("yeab", "regular"),
+  ("yead", "regular"),
+  ("yeam", "regular"),
+  ("yeba", "regular"),
+  ("yeda", "regular"),
+  ("yedh", "regular"),
+  ("yehd", "regular"),
+  ("yehj", "regular"),
+  ("yehu", "regular"),
+  ("yeib", "regular"),
+  ("yeja", "regular"),
+  ("yela", "regular"),
+  ("yema", "regular"),
+  ("yemr", "regular"),
+  ("yemw", "regular"),
+  ("yera", "regular"),
+  ("yesa", "regular"),
+  ("yesd", "regular"),
+  ("yesh", "regular"),
+  ("yesn", "regular"),
+  ("yesu", "regular"),
+  ("yeta", "regular"),
+  ("yezzzz", "unknown"),
+  ("ytzzzz", "unknown"),
+  ("zaec", "regular"),
+  ("zafs", "regular"),
+  ("zagp", "regular"),
+  ("zagt", "deprecated"),
+  ("zakzn", "regular"),
+  ("zalp", "regular"),
+  ("zamp", "regular"),
+  ("zanc", "regular"),
+  ("zanl", "deprecated"),
+  ("zanw", "regular"),
+  ("zawc", "regular"),
+  ("zazzzz", "unknown"),
+  ("zm01", "regular"),
+  ("zm02", "regular"),
+  ("zm03", "regular"),
+  ("zm04", "regular"),
+  ("zm05", "regular"),
+  ("zm06", "regular"),
+  ("zm07", "regular"),
+  ("zm08", "regular"),
+  ("zm09", "regular"),
+  ("zm10", "regular"),
+  ("zmzzzz", "unknown"),
+  ("zwbu", "regular"),
+  ("zwha", "regular"),
+  ("zwma", "regular"),
+  ("zwmc", "regular"),
+  ("zwme", "regular"),
+  ("zwmi", "regular"),
+  ("zwmn", "regular"),
+  ("zwms", "regular"),
+  ("zwmv", "regular"),
+  ("zwmw", "regular"),
+  ("zwzzzz", "unknown"),
+];
+
+/// The statuses of the measure units.
+pub static UNITS: &[(&str, &str)] = &[
+  ("acceleration-g-force", "regular"),
+  ("acceleration-meter-per-second-squared", "deprecated"),
+  ("acceleration-meter-per-square-second", "regular"),
+  ("angle-arc-minute", "regular"),
+  ("angle-arc-second", "regular"),
+  ("angle-degree", "regular"),
+  ("angle-radian", "regular"),
+  ("angle-revolution", "regular"),
+  ("area-acre", "regular"),
+  ("area-dunam", "regular"),
+  ("area-hectare", "regular"),
+  ("area-square-centimeter", "regular"),
+  ("area-square-foot", "regular"),
+  ("area-square-inch", "regular"),
+  ("area-square-kilometer", "regular"),
+  ("area-square-meter", "regular"),
+  ("area-square-mile", "regular"),
+  ("area-square-yard", "regular"),
+  ("concentr-item", "regular"),
+  ("concentr-karat", "regular"),
+  ("concentr-milligram-ofglucose-per-deciliter", "regular"),
+  ("concentr-milligram-per-deciliter", "deprecated"),
+  ("concentr-millimole-per-liter", "regular"),
+  ("concentr-mole", "regular"),
+  ("concentr-ofglucose", "regular"),
+  ("concentr-part-per-million", "deprecated"),
+  ("concentr-percent", "regular"),
+  ("concentr-permille", "regular"),
+  ("concentr-permillion", "regular"),
+  ("concentr-permyriad", "regular"),
+  ("concentr-portion", "regular"),
+  ("consumption-liter-per-100-kilometer", "regular"),
+  ("consumption-liter-per-100kilometers", "deprecated"),
+  ("consumption-liter-per-kilometer", "regular"),
+  ("consumption-mile-per-gallon", "regular"),
+  ("consumption-mile-per-gallon-imperial", "regular"),
+  ("digital-bit", "regular"),
+  ("digital-byte", "regular"),
+  ("digital-gigabit", "regular"),
+  ("digital-gigabyte", "regular"),
+  ("digital-kilobit", "regular"),
+  ("digital-kilobyte", "regular"),
+  ("digital-megabit", "regular"),
+  ("digital-megabyte", "regular"),
+  ("digital-petabyte", "regular"),
+  ("digital-terabit", "regular"),
+  ("digital-terabyte", "regular"),
+  ("duration-century", "regular"),
+  ("duration-day", "regular"),
+  ("duration-day-person", "regular"),
+  ("duration-decade", "regular"),
+  ("duration-hour", "regular"),
+  ("duration-microsecond", "regular"),
+  ("duration-millisecond", "regular"),
+  ("duration-minute", "regular"),
+  ("duration-month", "regular"),
+  ("duration-month-person", "regular"),
+  ("duration-nanosecond", "regular"),
+  ("duration-quarter", "regular"),
+  ("duration-second", "regular"),
+  ("duration-week", "regular"),
+  ("duration-week-person", "regular"),
+  ("duration-year", "regular"),
+  ("duration-year-person", "regular"),
+  ("electric-ampere", "regular"),
+  ("electric-milliampere", "regular"),
+  ("electric-ohm", "regular"),
+  ("electric-volt", "regular"),
+  ("energy-british-thermal-unit", "regular"),
+  ("energy-calorie", "regular"),
+  ("energy-electronvolt", "regular"),
+  ("energy-foodcalorie", "regular"),
+  ("energy-joule", "regular"),
+  ("energy-kilocalorie", "regular"),
+  ("energy-kilojoule", "regular"),
+  ("energy-kilowatt-hour", "regular"),
+  ("energy-therm-us", "regular"),
+  ("force-kilowatt-hour-per-100-kilometer", "regular"),
+  ("force-newton", "regular"),
+  ("force-pound-force", "regular"),
+  ("frequency-gigahertz", "regular"),
+  ("frequency-hertz", "regular"),
+  ("frequency-kilohertz", "regular"),
+  ("frequency-megahertz", "regular"),
+  ("graphics-dot", "regular"),
+  ("graphics-dot-per-centimeter", "regular"),
+  ("graphics-dot-per-inch", "regular"),
+  ("graphics-em", "regular"),
+  ("graphics-megapixel", "regular"),
+  ("graphics-pixel", "regular"),
+  ("graphics-pixel-per-centimeter", "regular"),
+  ("graphics-pixel-per-inch", "regular"),
+  ("length-100-kilometer", "regular"),
+  ("length-astronomical-unit", "regular"),
+  ("length-centimeter", "regular"),
+  ("length-decimeter", "regular"),
+  ("length-earth-radius", "regular"),
+  ("length-fathom", "regular"),
+  ("length-foot", "regular"),
+  ("length-furlong", "regular"),
+  ("length-inch", "regular"),
+  ("length-kilometer", "regular"),
+  ("length-light-year", "regular"),
+  ("length-meter", "regular"),
+  ("length-micrometer", "regular"),
+  ("length-mile", "regular"),
+  ("length-mile-scandinavian", "regular"),
+  ("length-millimeter", "regular"),
+  ("length-nanometer", "regular"),
+  ("length-nautical-mile", "regular"),
+  ("length-parsec", "regular"),
+  ("length-picometer", "regular"),
+  ("length-point", "regular"),
+  ("length-solar-radius", "regular"),
+  ("length-yard", "regular"),
+  ("light-candela", "regular"),
+  ("light-lumen", "regular"),
+  ("light-lux", "regular"),
+  ("light-solar-luminosity", "regular"),
+  ("mass-carat", "regular"),
+  ("mass-dalton", "regular"),
+  ("mass-earth-mass", "regular"),
+  ("mass-grain", "regular"),
+  ("mass-gram", "regular"),
+  ("mass-kilogram", "regular"),
+  ("mass-metric-ton", "deprecated"),
+  ("mass-microgram", "regular"),
+  ("mass-milligram", "regular"),
+  ("mass-ounce", "regular"),
+  ("mass-ounce-troy", "regular"),
+  ("mass-pound", "regular"),
+  ("mass-solar-mass", "regular"),
+  ("mass-stone", "regular"),
+  ("mass-ton", "regular"),
+  ("mass-tonne", "regular"),
+  ("power-gigawatt", "regular"),
+  ("power-horsepower", "regular"),
+  ("power-kilowatt", "regular"),
+  ("power-megawatt", "regular"),
+  ("power-milliwatt", "regular"),
+  ("power-watt", "regular"),
+  ("pressure-atmosphere", "regular"),
+  ("pressure-bar", "regular"),
+  ("pressure-hectopascal", "regular"),
+  ("pressure-inch-hg", "deprecated"),
+  ("pressure-inch-ofhg", "regular"),
+  ("pressure-kilopascal", "regular"),
+  ("pressure-megapascal", "regular"),
+  ("pressure-millibar", "regular"),
+  ("pressure-millimeter-of-mercury", "deprecated"),
+  ("pressure-millimeter-ofhg", "regular"),
+  ("pressure-ofhg", "regular"),
+  ("pressure-pascal", "regular"),
+  ("pressure-pound-force-per-square-inch", "regular"),
+  ("pressure-pound-per-square-inch", "deprecated"),
+  ("proportion-karat", "deprecated"),
+  ("speed-beaufort", "regular"),
+  ("speed-kilometer-per-hour", "regular"),
+  ("speed-knot", "regular"),
+  ("speed-meter-per-second", "regular"),
+  ("speed-mile-per-hour", "regular"),
+  ("temperature-celsius", "regular"),
+  ("temperature-fahrenheit", "regular"),
+  ("temperature-generic", "regular"),
+  ("temperature-kelvin", "regular"),
+  ("torque-newton-meter", "regular"),
+  ("torque-pound-foot", "deprecated"),
+  ("torque-pound-force-foot", "regular"),
+  ("volume-acre-foot", "regular"),
+  ("volume-barrel", "regular"),
+  ("volume-bushel", "regular"),
+  ("volume-centiliter", "regular"),
+  ("volume-cubic-centimeter", "regular"),
+  ("volume-cubic-foot", "regular"),
+  ("volume-cubic-inch", "regular"),
+  ("volume-cubic-kilometer", "regular"),
+  ("volume-cubic-meter", "regular"),
+  ("volume-cubic-mile", "regular"),
+  ("volume-cubic-yard", "regular"),
+  ("volume-cup", "regular"),
+  ("volume-cup-metric", "regular"),
+  ("volume-deciliter", "regular"),
+  ("volume-dessert-spoon", "regular"),
+  ("volume-dessert-spoon-imperial", "regular"),
+  ("volume-dram", "regular"),
+  ("volume-drop", "regular"),
+  ("volume-fluid-ounce", "regular"),
+  ("volume-fluid-ounce-imperial", "regular"),
+  ("volume-gallon", "regular"),
+  ("volume-gallon-imperial", "regular"),
+  ("volume-hectoliter", "regular"),
+  ("volume-jigger", "regular"),
+  ("volume-liter", "regular"),
+  ("volume-megaliter", "regular"),
+  ("volume-milliliter", "regular"),
+  ("volume-pinch", "regular"),
+  ("volume-pint", "regular"),
+  ("volume-pint-metric", "regular"),
+  ("volume-quart", "regular"),
+  ("volume-quart-imperial", "regular"),
+  ("volume-tablespoon", "regular"),
+  ("volume-teaspoon", "regular"),
+];
+
+/// The statuses of the currencies, in lowercase as the `cu` key.
+pub static CURRENCIES: &[(&str, &str)] = &[
+  ("adp", "regular"),
+  ("aed", "regular"),
+  ("afa", "regular"),
+  ("afn", "regular"),
+  ("alk", "regular"),
+  ("all", "regular"),
+  ("amd", "regular"),
+  ("ang", "regular"),
+  ("aoa", "regular"),
+  ("aok", "regular"),
+  ("aon", "regular"),
+  ("aor", "regular"),
+  ("ara", "regular"),
+  ("arl", "regular"),
+  ("arm", "regular"),
+  ("arp", "regular"),
+  ("ars", "regular"),
+  ("ats", "regular"),
+  ("aud", "regular"),
+  ("awg", "regular"),
+  ("azm", "regular"),
+  ("azn", "regular"),
+  ("bad", "regular"),
+  ("bam", "regular"),
+  ("ban", "regular"),
+  ("bbd", "regular"),
+  ("bdt", "regular"),
+  ("bec", "regular"),
+  ("bef", "regular"),
+  ("bel", "regular"),
+  ("bgl", "regular"),
+  ("bgm", "regular"),
+  ("bgn", "regular"),
+  ("bgo", "regular"),
+  ("bhd", "regular"),
+  ("bif", "regular"),
+  ("bmd", "regular"),
+  ("bnd", "regular"),
+  ("bob", "regular"),
+  ("bol", "regular"),
+  ("bop", "regular"),
+  ("bov", "regular"),
+  ("brb", "regular"),
+  ("brc", "regular"),
+  ("bre", "regular"),
+  ("brl", "regular"),
+  ("brn", "regular"),
+  ("brr", "regular"),
+  ("brz", "regular"),
+  ("bsd", "regular"),
+  ("btn", "regular"),
+  ("buk", "regular"),
+  ("bwp", "regular"),
+  ("byb", "regular"),
+  ("byn", "regular"),
+  ("byr", "regular"),
+  ("bzd", "regular"),
+  ("cad", "regular"),
+  ("cdf", "regular"),
+  ("che", "regular"),
+  ("chf", "regular"),
+  ("chw", "regular"),
+  ("cle", "regular"),
+  ("clf", "regular"),
+  ("clp", "regular"),
+  ("cnh", "regular"),
+  ("cnx", "regular"),
+  ("cny", "regular"),
+  ("cop", "regular"),
+  ("cou", "regular"),
+  ("crc", "regular"),
+  ("csd", "regular"),
+  ("csk", "regular"),
+  ("cuc", "regular"),
+  ("cup", "regular"),
+  ("cve", "regular"),
+  ("cyp", "regular"),
+  ("czk", "regular"),
+  ("ddm", "regular"),
+  ("dem", "regular"),
+  ("djf", "regular"),
+  ("dkk", "regular"),
+  ("dop", "regular"),
+  ("dzd", "regular"),
+  ("ecs", "regular"),
+  ("ecv", "regular"),
+  ("eek", "regular"),
+  ("egp", "regular"),
+  ("ern", "regular"),
+  ("esa", "regular"),
+  ("esb", "regular"),
+  ("esp", "regular"),
+  ("etb", "regular"),
+  ("eur", "regular"),
+  ("fim", "regular"),
+  ("fjd", "regular"),
+  ("fkp", "regular"),
+  ("frf", "regular"),
+  ("gbp", "regular"),
+  ("gek", "regular"),
+  ("gel", "regular"),
+  ("ghc", "regular"),
+  ("ghs", "regular"),
+  ("gip", "regular"),
+  ("gmd", "regular"),
+  ("gnf", "regular"),
+  ("gns", "regular"),
+  ("gqe", "regular"),
+  ("grd", "regular"),
+  ("gtq", "regular"),
+  ("gwe", "regular"),
+  ("gwp", "regular"),
+  ("gyd", "regular"),
+  ("hkd", "regular"),
+  ("hnl", "regular"),
+  ("hrd", "regular"),
+  ("hrk", "regular"),
+  ("htg", "regular"),
+  ("huf", "regular"),
+  ("idr", "regular"),
+  ("iep", "regular"),
+  ("ilp", "regular"),
+  ("ilr", "regular"),
+  ("ils", "regular"),
+  ("inr", "regular"),
+  ("iqd", "regular"),
+  ("irr", "regular"),
+  ("isj", "regular"),
+  ("isk", "regular"),
+  ("itl", "regular"),
+  ("jmd", "regular"),
+  ("jod", "regular"),
+  ("jpy", "regular"),
+  ("kes", "regular"),
+  ("kgs", "regular"),
+  ("khr", "regular"),
+  ("kmf", "regular"),
+  ("kpw", "regular"),
+  ("krh", "regular"),
+  ("kro", "regular"),
+  ("krw", "regular"),
+  ("kwd", "regular"),
+  ("kyd", "regular"),
+  ("kzt", "regular"),
+  ("lak", "regular"),
+  ("lbp", "regular"),
+  ("lkr", "regular"),
+  ("lrd", "regular"),
+  ("lsl", "regular"),
+  ("ltl", "regular"),
+  ("ltt", "regular"),
+  ("luc", "regular"),
+  ("luf", "regular"),
+  ("lul", "regular"),
+  ("lvl", "regular"),
+  ("lvr", "regular"),
+  ("lyd", "regular"),
+  ("mad", "regular"),
+  ("maf", "regular"),
+  ("mcf", "regular"),
+  ("mdc", "regular"),
+  ("mdl", "regular"),
+  ("mga", "regular"),
+  ("mgf", "regular"),
+  ("mkd", "regular"),
+  ("mkn", "regular"),
+  ("mlf", "regular"),
+  ("mmk", "regular"),
+  ("mnt", "regular"),
+  ("mop", "regular"),
+  ("mro", "regular"),
+  ("mru", "regular"),
+  ("mtl", "regular"),
+  ("mtp", "regular"),
+  ("mur", "regular"),
+  ("mvp", "regular"),
+  ("mvr", "regular"),
+  ("mwk", "regular"),
+  ("mxn", "regular"),
+  ("mxp", "regular"),
+  ("mxv", "regular"),
+  ("myr", "regular"),
+  ("mze", "regular"),
+  ("mzm", "regular"),
+  ("mzn", "regular"),
+  ("nad", "regular"),
+  ("ngn", "regular"),
+  ("nic", "regular"),
+  ("nio", "regular"),
+  ("nlg", "regular"),
+  ("nok", "regular"),
+  ("npr", "regular"),
+  ("nzd", "regular"),
+  ("omr", "regular"),
+  ("pab", "regular"),
+  ("pei", "regular"),
+  ("pen", "regular"),
+  ("pes", "regular"),
+  ("pgk", "regular"),
+  ("php", "regular"),
+  ("pkr", "regular"),
+  ("pln", "regular"),
+  ("plz", "regular"),
+  ("pte", "regular"),
+  ("pyg", "regular"),
+  ("qar", "regular"),
+  ("rhd", "regular"),
+  ("rol", "regular"),
+  ("ron", "regular"),
+  ("rsd", "regular"),
+  ("rub", "regular"),
+  ("rur", "regular"),
+  ("rwf", "regular"),
+  ("sar", "regular"),
+  ("sbd", "regular"),
+  ("scr", "regular"),
+  ("sdd", "regular"),
+  ("sdg", "regular"),
+  ("sdp", "regular"),
+  ("sek", "regular"),
+  ("sgd", "regular"),
+  ("shp", "regular"),
+  ("sit", "regular"),
+  ("skk", "regular"),
+  ("sle", "regular"),
+  ("sll", "regular"),
+  ("sos", "regular"),
+  ("srd", "regular"),
+  ("srg", "regular"),
+  ("ssp", "regular"),
+  ("std", "regular"),
+  ("stn", "regular"),
+  ("sur", "regular"),
+  ("svc", "regular"),
+  ("syp", "regular"),
+  ("szl", "regular"),
+  ("thb", "regular"),
+  ("tjr", "regular"),
+  ("tjs", "regular"),
+  ("tmm", "regular"),
+  ("tmt", "regular"),
+  ("tnd", "regular"),
+  ("top", "regular"),
+  ("tpe", "regular"),
+  ("trl", "regular"),
+  ("try", "regular"),
+  ("ttd", "regular"),
+  ("twd", "regular"),
+  ("tzs", "regular"),
+  ("uah", "regular"),
+  ("uak", "regular"),
+  ("ugs", "regular"),
+  ("ugx", "regular"),
+  ("usd", "regular"),
+  ("usn", "regular"),
+  ("uss", "regular"),
+  ("uyi", "regular"),
+  ("uyp", "regular"),
+  ("uyu", "regular"),
+  ("uyw", "regular"),
+  ("uzs", "regular"),
+  ("veb", "regular"),
+  ("ved", "regular"),
+  ("vef", "regular"),
+  ("ves", "regular"),
+  ("vnd", "regular"),
+  ("vnn", "regular"),
+  ("vuv", "regular"),
+  ("wst", "regular"),
+  ("xaf", "regular"),
+  ("xag", "regular"),
+  ("xau", "regular"),
+  ("xba", "regular"),
+  ("xbb", "regular"),
+  ("xbc", "regular"),
+  ("xbd", "regular"),
+  ("xcd", "regular"),
+  ("xdr", "regular"),
+  ("xeu", "regular"),
+  ("xfo", "regular"),
+  ("xfu", "regular"),
+  ("xof", "regular"),
+  ("xpd", "regular"),
+  ("xpf", "regular"),
+  ("xpt", "regular"),
+  ("xre", "regular"),
+  ("xsu", "regular"),
+  ("xts", "regular"),
+  ("xua", "regular"),
+  ("xxx", "regular"),
+  ("ydd", "regular"),
+  ("yer", "regular"),
+  ("yud", "regular"),
+  ("yum", "regular"),
+  ("yun", "regular"),
+  ("yur", "regular"),
+  ("zal", "regular"),
+  ("zar", "regular"),
+  ("zmk", "regular"),
+  ("zmw", "regular"),
+  ("zrn", "regular"),
+  ("zrz", "regular"),
+  ("zwd", "regular"),
+  ("zwl", "regular"),
+  ("zwr", "regular"),
+];
+
+/// The ukeys, with the kind of their values, followed by `deprecated` for the deprecated keys.
+pub static UNICODE_KEYS: &[(&str, &str)] = &[
+  ("ca", "type"),
+  ("cf", "type"),
+  ("co", "type"),
+  ("cu", "currency"),
+  ("dx", "script"),
+  ("em", "type"),
+  ("fw", "type"),
+  ("hc", "type"),
+  ("ka", "type"),
+  ("kb", "type"),
+  ("kc", "type"),
+  ("kf", "type"),
+  ("kh", "type deprecated"),
+  ("kk", "type"),
+  ("kn", "type"),
+  ("kr", "reorder"),
+  ("ks", "type"),
+  ("kv", "type"),
+  ("lb", "type"),
+  ("lw", "type"),
+  ("ms", "type"),
+  ("mu", "type"),
+  ("nu", "type"),
+  ("rg", "region"),
+  ("sd", "subdivision"),
+  ("ss", "type"),
+  ("tz", "type"),
+  ("va", "type"),
+  ("vt", "codepoints deprecated"),
+];
+
+/// The statuses of the types of the ukeys whose values are `type` or `reorder`, by the key and the type.
+pub static UNICODE_TYPES: &[(&str, &str)] = &[
+  ("ca-buddhist", "regular"),
+  ("ca-chinese", "regular"),
+  ("ca-coptic", "regular"),
+  ("ca-dangi", "regular"),
+  ("ca-ethioaa", "regular"),
+  ("ca-ethiopic", "regular"),
+  ("ca-gregory", "regular"),
+  ("ca-hebrew", "regular"),
+  ("ca-indian", "regular"),
+  ("ca-islamic", "regular"),
+  ("ca-islamic-civil", "regular"),
+  ("ca-islamic-rgsa", "regular"),
+  ("ca-islamic-tbla", "regular"),
+  ("ca-islamic-umalqura", "regular"),
+  ("ca-islamicc", "deprecated"),
+  ("ca-iso8601", "regular"),
+  ("ca-japanese", "regular"),
+  ("ca-persian", "regular"),
+  ("ca-roc", "regular"),
+  ("cf-account", "regular"),
+  ("cf-standard", "regular"),
+  ("co-big5han", "regular"),
+  ("co-compat", "regular"),
+  ("co-dict", "regular"),
+  ("co-direct", "deprecated"),
+  ("co-ducet", "regular"),
+  ("co-emoji", "regular"),
+  ("co-eor", "regular"),
+  ("co-gb2312", "regular"),
+  ("co-phonebk", "regular"),
+  ("co-phonetic", "regular"),
+  ("co-pinyin", "regular"),
+  ("co-reformed", "regular"),
+  ("co-search", "regular"),
+  ("co-searchjl", "regular"),
+  ("co-standard", "regular"),
+  ("co-stroke", "regular"),
+  ("co-trad", "regular"),
+  ("co-unihan", "regular"),
+  ("co-zhuyin", "regular"),
+  ("em-default", "regular"),
+  ("em-emoji", "regular"),
+  ("em-text", "regular"),
+  ("fw-fri", "regular"),
+  ("fw-mon", "regular"),
+  ("fw-sat", "regular"),
+  ("fw-sun", "regular"),
+  ("fw-thu", "regular"),
+  ("fw-tue", "regular"),
+  ("fw-wed", "regular"),
+  ("hc-h11", "regular"),
+  ("hc-h12", "regular"),
+  ("hc-h23", "regular"),
+  ("hc-h24", "regular"),
+  ("ka-noignore", "regular"),
+  ("ka-shifted", "regular"),
+  ("kb-false", "regular"),
+  ("kb-true", "regular"),
+  ("kc-false", "regular"),
+  ("kc-true", "regular"),
+  ("kf-false", "regular"),
+  ("kf-lower", "regular"),
+  ("kf-upper", "regular"),
+  ("kh-false", "regular"),
+  ("kh-true", "regular"),
+  ("kk-false", "regular"),
+  ("kk-true", "regular"),
+  ("kn-false", "regular"),
+  ("kn-true", "regular"),
+  ("kr-currency", "regular"),
+  ("kr-digit", "regular"),
+  ("kr-punct", "regular"),
+  ("kr-space", "regular"),
+  ("kr-symbol", "regular"),
+  ("ks-identic", "regular"),
+  ("ks-level1", "regular"),
+  ("ks-level2", "regular"),
+  ("ks-level3", "regular"),
+  ("ks-level4", "regular"),
+  ("kv-currency", "regular"),
+  ("kv-punct", "regular"),
+  ("kv-space", "regular"),
+  ("kv-symbol", "regular"),
+  ("lb-loose", "regular"),
+  ("lb-normal", "regular"),
+  ("lb-strict", "regular"),
+  ("lw-breakall", "regular"),
+  ("lw-keepall", "regular"),
+  ("lw-normal", "regular"),
+  ("lw-phrase", "regular"),
+  ("ms-metric", "regular"),
+  ("ms-uksystem", "regular"),
+  ("ms-ussystem", "regular"),
+  ("mu-celsius", "regular"),
+  ("mu-fahrenhe", "regular"),
+  ("mu-kelvin", "regular"),
+  ("nu-adlm", "regular"),
+  ("nu-ahom", "regular"),
+  ("nu-arab", "regular"),
+  ("nu-arabext", "regular"),
+  ("nu-armn", "regular"),
+  ("nu-armnlow", "regular"),
+  ("nu-bali", "regular"),
+  ("nu-beng", "regular"),
+  ("nu-bhks", "regular"),
+  ("nu-brah", "regular"),
+  ("nu-cakm", "regular"),
+  ("nu-cham", "regular"),
+  ("nu-cyrl", "regular"),
+  ("nu-deva", "regular"),
+  ("nu-diak", "regular"),
+  ("nu-ethi", "regular"),
+  ("nu-finance", "regular"),
+  ("nu-fullwide", "regular"),
+  ("nu-geor", "regular"),
+  ("nu-gong", "regular"),
+  ("nu-gonm", "regular"),
+  ("nu-grek", "regular"),
+  ("nu-greklow", "regular"),
+  ("nu-gujr", "regular"),
+  ("nu-guru", "regular"),
+  ("nu-hanidays", "regular"),
+  ("nu-hanidec", "regular"),
+  ("nu-hans", "regular"),
+  ("nu-hansfin", "regular"),
+  ("nu-hant", "regular"),
+  ("nu-hantfin", "regular"),
+  ("nu-hebr", "regular"),
+  ("nu-hmng", "regular"),
+  ("nu-hmnp", "regular"),
+  ("nu-java", "regular"),
+  ("nu-jpan", "regular"),
+  ("nu-jpanfin", "regular"),
+  ("nu-jpanyear", "regular"),
+  ("nu-kali", "regular"),
+  ("nu-kawi", "regular"),
+  ("nu-khmr", "regular"),
+  ("nu-knda", "regular"),
+  ("nu-lana", "regular"),
+  ("nu-lanatham", "regular"),
+  ("nu-laoo", "regular"),
+  ("nu-latn", "regular"),
+  ("nu-lepc", "regular"),
+  ("nu-limb", "regular"),
+  ("nu-mathbold", "regular"),
+  ("nu-mathdbl", "regular"),
+  ("nu-mathmono", "regular"),
+  ("nu-mathsanb", "regular"),
+  ("nu-mathsans", "regular"),
+  ("nu-mlym", "regular"),
+  ("nu-modi", "regular"),
+  ("nu-mong", "regular"),
+  ("nu-mroo", "regular"),
+  ("nu-mtei", "regular"),
+  ("nu-mymr", "regular"),
+  ("nu-mymrshan", "regular"),
+  ("nu-mymrtlng", "regular"),
+  ("nu-nagm", "regular"),
+  ("nu-native", "regular"),
+  ("nu-newa", "regular"),
+  ("nu-nkoo", "regular"),
+  ("nu-olck", "regular"),
+  ("nu-orya", "regular"),
+  ("nu-osma", "regular"),
+  ("nu-rohg", "regular"),
+  ("nu-roman", "regular"),
+  ("nu-romanlow", "regular"),
+  ("nu-saur", "regular"),
+  ("nu-segment", "regular"),
+  ("nu-shrd", "regular"),
+  ("nu-sind", "regular"),
+  ("nu-sinh", "regular"),
+  ("nu-sora", "regular"),
+  ("nu-sund", "regular"),
+  ("nu-takr", "regular"),
+  ("nu-talu", "regular"),
+  ("nu-taml", "regular"),
+  ("nu-tamldec", "regular"),
+  ("nu-telu", "regular"),
+  ("nu-thai", "regular"),
+  ("nu-tibt", "regular"),
+  ("nu-tirh", "regular"),
+  ("nu-tnsa", "regular"),
+  ("nu-traditio", "regular"),
+  ("nu-vaii", "regular"),
+  ("nu-wara", "regular"),
+  ("nu-wcho", "regular"),
+  ("ss-none", "regular"),
+  ("ss-standard", "regular"),
+  ("tz-adalv", "regular"),
+  ("tz-aedxb", "regular"),
+  ("tz-afkbl", "regular"),
+  ("tz-aganu", "regular"),
+  ("tz-aiaxa", "regular"),
+  ("tz-altia", "regular"),
+  ("tz-amevn", "regular"),
+  ("tz-ancur", "regular"),
+  ("tz-aolad", "regular"),
+  ("tz-aqams", "deprecated"),
+  ("tz-aqcas", "regular"),
+  ("tz-aqdav", "regular"),
+  ("tz-aqddu", "regular"),
+  ("tz-aqmaw", "regular"),
+  ("tz-aqmcm", "regular"),
+  ("tz-aqplm", "regular"),
+  ("tz-aqrot", "regular"),
+  ("tz-aqsyw", "regular"),
+  ("tz-aqtrl", "regular"),
+  ("tz-aqvos", "regular"),
+  ("tz-arbue", "regular"),
+  ("tz-arcor", "regular"),
+  ("tz-arctc", "regular"),
+  ("tz-arirj", "regular"),
+  ("tz-arjuj", "regular"),
+  ("tz-arluq", "regular"),
+  ("tz-armdz", "regular"),
+  ("tz-arrgl", "regular"),
+  ("tz-arsla", "regular"),
+  ("tz-artuc", "regular"),
+  ("tz-aruaq", "regular"),
+  ("tz-arush", "regular"),
+  ("tz-asppg", "regular"),
+  ("tz-atvie", "regular"),
+  ("tz-auadl", "regular"),
+  ("tz-aubhq", "regular"),
+  ("tz-aubne", "regular"),
+  ("tz-audrw", "regular"),
+  ("tz-aueuc", "regular"),
+  ("tz-auhba", "regular"),
+  ("tz-aukns", "regular"),
+  ("tz-auldc", "regular"),
+  ("tz-auldh", "regular"),
+  ("tz-aumel", "regular"),
+  ("tz-aumqi", "regular"),
+  ("tz-auper", "regular"),
+  ("tz-ausyd", "regular"),
+  ("tz-awaua", "regular"),
+  ("tz-azbak", "regular"),
+  ("tz-basjj", "regular"),
+  ("tz-bbbgi", "regular"),
+  ("tz-bddac", "regular"),
+  ("tz-bebru", "regular"),
+  ("tz-bfoua", "regular"),
+  ("tz-bgsof", "regular"),
+  ("tz-bhbah", "regular"),
+  ("tz-bibjm", "regular"),
+  ("tz-bjptn", "regular"),
+  ("tz-bmbda", "regular"),
+  ("tz-bnbwn", "regular"),
+  ("tz-bolpb", "regular"),
+  ("tz-bqkra", "regular"),
+  ("tz-braux", "regular"),
+  ("tz-brbel", "regular"),
+  ("tz-brbvb", "regular"),
+  ("tz-brcgb", "regular"),
+  ("tz-brcgr", "regular"),
+  ("tz-brern", "regular"),
+  ("tz-brfen", "regular"),
+  ("tz-brfor", "regular"),
+  ("tz-brmao", "regular"),
+  ("tz-brmcz", "regular"),
+  ("tz-brpvh", "regular"),
+  ("tz-brrbr", "regular"),
+  ("tz-brrec", "regular"),
+  ("tz-brsao", "regular"),
+  ("tz-brssa", "regular"),
+  ("tz-brstm", "regular"),
+  ("tz-bsnas", "regular"),
+  ("tz-btthi", "regular"),
+  ("tz-bwgbe", "regular"),
+  ("tz-bymsq", "regular"),
+  ("tz-bzbze", "regular"),
+  ("tz-cacfq", "regular"),
+  ("tz-caedm", "regular"),
+  ("tz-caffs", "regular"),
+  ("tz-cafne", "regular"),
+  ("tz-caglb", "regular"),
+  ("tz-cagoo", "regular"),
+  ("tz-cahal", "regular"),
+  ("tz-caiql", "regular"),
+  ("tz-camon", "regular"),
+  ("tz-camtr", "deprecated"),
+  ("tz-canpg", "regular"),
+  ("tz-capnt", "regular"),
+  ("tz-careb", "regular"),
+  ("tz-careg", "regular"),
+  ("tz-casjf", "regular"),
+  ("tz-cathu", "regular"),
+  ("tz-cator", "regular"),
+  ("tz-cavan", "regular"),
+  ("tz-cawnp", "regular"),
+  ("tz-caybx", "regular"),
+  ("tz-caycb", "regular"),
+  ("tz-cayda", "regular"),
+  ("tz-caydq", "regular"),
+  ("tz-cayek", "regular"),
+  ("tz-cayev", "regular"),
+  ("tz-cayxy", "regular"),
+  ("tz-cayyn", "regular"),
+  ("tz-cayzf", "regular"),
+  ("tz-cayzs", "regular"),
+  ("tz-cccck", "regular"),
+  ("tz-cdfbm", "regular"),
+  ("tz-cdfih", "regular"),
+  ("tz-cfbgf", "regular"),
+  ("tz-cgbzv", "regular"),
+  ("tz-chzrh", "regular"),
+  ("tz-ciabj", "regular"),
+  ("tz-ckrar", "regular"),
+  ("tz-clipc", "regular"),
+  ("tz-clpuq", "regular"),
+  ("tz-clscl", "regular"),
+  ("tz-cmdla", "regular"),
+  ("tz-cnckg", "deprecated"),
+  ("tz-cnhrb", "deprecated"),
+  ("tz-cnkhg", "deprecated"),
+  ("tz-cnsha", "regular"),
+  ("tz-cnurc", "regular"),
+  ("tz-cobog", "regular"),
+  ("tz-crsjo", "regular"),
+  ("tz-cst6cdt", "regular"),
+  ("tz-cuhav", "regular"),
+  ("tz-cvrai", "regular"),
+  ("tz-cxxch", "regular"),
+  ("tz-cyfmg", "regular"),
+  ("tz-cynic", "regular"),
+  ("tz-czprg", "regular"),
+  ("tz-deber", "regular"),
+  ("tz-debsngn", "regular"),
+  ("tz-djjib", "regular"),
+  ("tz-dkcph", "regular"),
+  ("tz-dmdom", "regular"),
+  ("tz-dosdq", "regular"),
+  ("tz-dzalg", "regular"),
+  ("tz-ecgps", "regular"),
+  ("tz-ecgye", "regular"),
+  ("tz-eetll", "regular"),
+  ("tz-egcai", "regular"),
+  ("tz-eheai", "regular"),
+  ("tz-erasm", "regular"),
+  ("tz-esceu", "regular"),
+  ("tz-eslpa", "regular"),
+  ("tz-esmad", "regular"),
+  ("tz-est5edt", "regular"),
+  ("tz-etadd", "regular"),
+  ("tz-fihel", "regular"),
+  ("tz-fimhq", "regular"),
+  ("tz-fjsuv", "regular"),
+  ("tz-fkpsy", "regular"),
+  ("tz-fmksa", "regular"),
+  ("tz-fmpni", "regular"),
+  ("tz-fmtkk", "regular"),
+  ("tz-fotho", "regular"),
+  ("tz-frpar", "regular"),
+  ("tz-galbv", "regular"),
+  ("tz-gaza", "deprecated"),
+  ("tz-gazastrp", "regular"),
+  ("tz-gblon", "regular"),
+  ("tz-gdgnd", "regular"),
+  ("tz-getbs", "regular"),
+  ("tz-gfcay", "regular"),
+  ("tz-gggci", "regular"),
+  ("tz-ghacc", "regular"),
+  ("tz-gigib", "regular"),
+  ("tz-gldkshvn", "regular"),
+  ("tz-glgoh", "regular"),
+  ("tz-globy", "regular"),
+  ("tz-glthu", "regular"),
+  ("tz-gmbjl", "regular"),
+  ("tz-gmt", "regular"),
+  ("tz-gncky", "regular"),
+  ("tz-gpbbr", "regular"),
+  ("tz-gpmsb", "regular"),
+  ("tz-gpsbh", "regular"),
+  ("tz-gqssg", "regular"),
+  ("tz-grath", "regular"),
+  ("tz-gsgrv", "regular"),
+  ("tz-gtgua", "regular"),
+  ("tz-gugum", "regular"),
+  ("tz-gwoxb", "regular"),
+  ("tz-gygeo", "regular"),
+  ("tz-hebron", "regular"),
+  ("tz-hkhkg", "regular"),
+  ("tz-hntgu", "regular"),
+  ("tz-hrzag", "regular"),
+  ("tz-htpap", "regular"),
+  ("tz-hubud", "regular"),
+  ("tz-iddjj", "regular"),
+  ("tz-idjkt", "regular"),
+  ("tz-idmak", "regular"),
+  ("tz-idpnk", "regular"),
+  ("tz-iedub", "regular"),
+  ("tz-imdgs", "regular"),
+  ("tz-inccu", "regular"),
+  ("tz-iodga", "regular"),
+  ("tz-iqbgw", "regular"),
+  ("tz-irthr", "regular"),
+  ("tz-isrey", "regular"),
+  ("tz-itrom", "regular"),
+  ("tz-jeruslm", "regular"),
+  ("tz-jesth", "regular"),
+  ("tz-jmkin", "regular"),
+  ("tz-joamm", "regular"),
+  ("tz-jptyo", "regular"),
+  ("tz-kenbo", "regular"),
+  ("tz-kgfru", "regular"),
+  ("tz-khpnh", "regular"),
+  ("tz-kicxi", "regular"),
+  ("tz-kipho", "regular"),
+  ("tz-kitrw", "regular"),
+  ("tz-kmyva", "regular"),
+  ("tz-knbas", "regular"),
+  ("tz-kpfnj", "regular"),
+  ("tz-krsel", "regular"),
+  ("tz-kwkwi", "regular"),
+  ("tz-kygec", "regular"),
+  ("tz-kzaau", "regular"),
+  ("tz-kzakx", "regular"),
+  ("tz-kzala", "regular"),
+  ("tz-kzguw", "regular"),
+  ("tz-kzksn", "regular"),
+  ("tz-kzkzo", "regular"),
+  ("tz-kzura", "regular"),
+  ("tz-lavte", "regular"),
+  ("tz-lbbey", "regular"),
+  ("tz-lccas", "regular"),
+  ("tz-livdz", "regular"),
+  ("tz-lkcmb", "regular"),
+  ("tz-lrmlw", "regular"),
+  ("tz-lsmsu", "regular"),
+  ("tz-ltvno", "regular"),
+  ("tz-lulux", "regular"),
+  ("tz-lvrix", "regular"),
+  ("tz-lytip", "regular"),
+  ("tz-macas", "regular"),
+  ("tz-mcmon", "regular"),
+  ("tz-mdkiv", "regular"),
+  ("tz-metgd", "regular"),
+  ("tz-mgtnr", "regular"),
+  ("tz-mhkwa", "regular"),
+  ("tz-mhmaj", "regular"),
+  ("tz-mkskp", "regular"),
+  ("tz-mlbko", "regular"),
+  ("tz-mmrgn", "regular"),
+  ("tz-mncoq", "regular"),
+  ("tz-mnhvd", "regular"),
+  ("tz-mnuln", "regular"),
+  ("tz-momfm", "regular"),
+  ("tz-mpspn", "regular"),
+  ("tz-mqfdf", "regular"),
+  ("tz-mrnkc", "regular"),
+  ("tz-msmni", "regular"),
+  ("tz-mst7mdt", "regular"),
+  ("tz-mtmla", "regular"),
+  ("tz-muplu", "regular"),
+  ("tz-mvmle", "regular"),
+  ("tz-mwblz", "regular"),
+  ("tz-mxchi", "regular"),
+  ("tz-mxcjs", "regular"),
+  ("tz-mxcun", "regular"),
+  ("tz-mxhmo", "regular"),
+  ("tz-mxmam", "regular"),
+  ("tz-mxmex", "regular"),
+  ("tz-mxmid", "regular"),
+  ("tz-mxmty", "regular"),
+  ("tz-mxmzt", "regular"),
+  ("tz-mxoji", "regular"),
+  ("tz-mxpvr", "regular"),
+  ("tz-mxstis", "regular"),
+  ("tz-mxtij", "regular"),
+  ("tz-mykch", "regular"),
+  ("tz-mykul", "regular"),
+  ("tz-mzmpm", "regular"),
+  ("tz-nawdh", "regular"),
+  ("tz-ncnou", "regular"),
+  ("tz-nenim", "regular"),
+  ("tz-nfnlk", "regular"),
+  ("tz-nglos", "regular"),
+  ("tz-nimga", "regular"),
+  ("tz-nlams", "regular"),
+  ("tz-noosl", "regular"),
+  ("tz-npktm", "regular"),
+  ("tz-nrinu", "regular"),
+  ("tz-nuiue", "regular"),
+  ("tz-nzakl", "regular"),
+  ("tz-nzcht", "regular"),
+  ("tz-ommct", "regular"),
+  ("tz-papty", "regular"),
+  ("tz-pelim", "regular"),
+  ("tz-pfgmr", "regular"),
+  ("tz-pfnhv", "regular"),
+  ("tz-pfppt", "regular"),
+  ("tz-pgpom", "regular"),
+  ("tz-pgraw", "regular"),
+  ("tz-phmnl", "regular"),
+  ("tz-pkkhi", "regular"),
+  ("tz-plwaw", "regular"),
+  ("tz-pmmqc", "regular"),
+  ("tz-pnpcn", "regular"),
+  ("tz-prsju", "regular"),
+  ("tz-pst8pdt", "regular"),
+  ("tz-ptfnc", "regular"),
+  ("tz-ptlis", "regular"),
+  ("tz-ptpdl", "regular"),
+  ("tz-pwror", "regular"),
+  ("tz-pyasu", "regular"),
+  ("tz-qadoh", "regular"),
+  ("tz-rereu", "regular"),
+  ("tz-robuh", "regular"),
+  ("tz-rsbeg", "regular"),
+  ("tz-ruasf", "regular"),
+  ("tz-rubax", "regular"),
+  ("tz-ruchita", "regular"),
+  ("tz-rudyr", "regular"),
+  ("tz-rugdx", "regular"),
+  ("tz-ruikt", "regular"),
+  ("tz-rukgd", "regular"),
+  ("tz-rukhndg", "regular"),
+  ("tz-rukra", "regular"),
+  ("tz-rukuf", "regular"),
+  ("tz-rukvx", "regular"),
+  ("tz-rumow", "regular"),
+  ("tz-runoz", "regular"),
+  ("tz-ruoms", "regular"),
+  ("tz-ruovb", "regular"),
+  ("tz-rupkc", "regular"),
+  ("tz-rurtw", "regular"),
+  ("tz-rusred", "regular"),
+  ("tz-rutof", "regular"),
+  ("tz-ruuly", "regular"),
+  ("tz-ruunera", "regular"),
+  ("tz-ruuus", "regular"),
+  ("tz-ruvog", "regular"),
+  ("tz-ruvvo", "regular"),
+  ("tz-ruyek", "regular"),
+  ("tz-ruyks", "regular"),
+  ("tz-rwkgl", "regular"),
+  ("tz-saruh", "regular"),
+  ("tz-sbhir", "regular"),
+  ("tz-scmaw", "regular"),
+  ("tz-sdkrt", "regular"),
+  ("tz-sesto", "regular"),
+  ("tz-sgsin", "regular"),
+  ("tz-shshn", "regular"),
+  ("tz-silju", "regular"),
+  ("tz-sjlyr", "regular"),
+  ("tz-skbts", "regular"),
+  ("tz-slfna", "regular"),
+  ("tz-smsai", "regular"),
+  ("tz-sndkr", "regular"),
+  ("tz-somgq", "regular"),
+  ("tz-srpbm", "regular"),
+  ("tz-ssjub", "regular"),
+  ("tz-sttms", "regular"),
+  ("tz-svsal", "regular"),
+  ("tz-sxphi", "regular"),
+  ("tz-sydam", "regular"),
+  ("tz-szqmn", "regular"),
+  ("tz-tcgdt", "regular"),
+  ("tz-tdndj", "regular"),
+  ("tz-tfpfr", "regular"),
+  ("tz-tglfw", "regular"),
+  ("tz-thbkk", "regular"),
+  ("tz-tjdyu", "regular"),
+  ("tz-tkfko", "regular"),
+  ("tz-tldil", "regular"),
+  ("tz-tmasb", "regular"),
+  ("tz-tntun", "regular"),
+  ("tz-totbu", "regular"),
+  ("tz-trist", "regular"),
+  ("tz-ttpos", "regular"),
+  ("tz-tvfun", "regular"),
+  ("tz-twtpe", "regular"),
+  ("tz-tzdar", "regular"),
+  ("tz-uaiev", "regular"),
+  ("tz-uaozh", "regular"),
+  ("tz-uasip", "regular"),
+  ("tz-uauzh", "regular"),
+  ("tz-ugkla", "regular"),
+  ("tz-umawk", "regular"),
+  ("tz-umjon", "regular"),
+  ("tz-ummdy", "regular"),
+  ("tz-unk", "regular"),
+  ("tz-usadk", "regular"),
+  ("tz-usaeg", "regular"),
+  ("tz-usanc", "regular"),
+  ("tz-usboi", "regular"),
+  ("tz-uschi", "regular"),
+  ("tz-usden", "regular"),
+  ("tz-usdet", "regular"),
+  ("tz-ushnl", "regular"),
+  ("tz-usind", "regular"),
+  ("tz-usinvev", "regular"),
+  ("tz-usjnu", "regular"),
+  ("tz-usknx", "regular"),
+  ("tz-uslax", "regular"),
+  ("tz-uslui", "regular"),
+  ("tz-usmnm", "regular"),
+  ("tz-usmoc", "regular"),
+  ("tz-usmtm", "regular"),
+  ("tz-usnavajo", "deprecated"),
+  ("tz-usndcnt", "regular"),
+  ("tz-usndnsl", "regular"),
+  ("tz-usnyc", "regular"),
+  ("tz-usoea", "regular"),
+  ("tz-usome", "regular"),
+  ("tz-usphx", "regular"),
+  ("tz-ussit", "regular"),
+  ("tz-ustel", "regular"),
+  ("tz-uswlz", "regular"),
+  ("tz-uswsq", "regular"),
+  ("tz-usxul", "regular"),
+  ("tz-usyak", "regular"),
+  ("tz-utc", "regular"),
+  ("tz-utce01", "regular"),
+  ("tz-utce02", "regular"),
+  ("tz-utce03", "regular"),
+  ("tz-utce04", "regular"),
+  ("tz-utce05", "regular"),
+  ("tz-utce06", "regular"),
+  ("tz-utce07", "regular"),
+  ("tz-utce08", "regular"),
+  ("tz-utce09", "regular"),
+  ("tz-utce10", "regular"),
+  ("tz-utce11", "regular"),
+  ("tz-utce12", "regular"),
+  ("tz-utce13", "regular"),
+  ("tz-utce14", "regular"),
+  ("tz-utcw01", "regular"),
+  ("tz-utcw02", "regular"),
+  ("tz-utcw03", "regular"),
+  ("tz-utcw04", "regular"),
+  ("tz-utcw05", "regular"),
+  ("tz-utcw06", "regular"),
+  ("tz-utcw07", "regular"),
+  ("tz-utcw08", "regular"),
+  ("tz-utcw09", "regular"),
+  ("tz-utcw10", "regular"),
+  ("tz-utcw11", "regular"),
+  ("tz-utcw12", "regular"),
+  ("tz-uymvd", "regular"),
+  ("tz-uzskd", "regular"),
+  ("tz-uztas", "regular"),
+  ("tz-vavat", "regular"),
+  ("tz-vcsvd", "regular"),
+  ("tz-veccs", "regular"),
+  ("tz-vgtov", "regular"),
+  ("tz-vistt", "regular"),
+  ("tz-vnsgn", "regular"),
+  ("tz-vuvli", "regular"),
+  ("tz-wfmau", "regular"),
+  ("tz-wsapw", "regular"),
+  ("tz-yeade", "regular"),
+  ("tz-ytmam", "regular"),
+  ("tz-zajnb", "regular"),
+  ("tz-zmlun", "regular"),
+  ("tz-zwhre", "regular"),
+  ("va-posix", "regular"),
+];
+
+/// The tkeys, with the kind of their values, followed by `deprecated` for the deprecated keys.
+pub static TRANSFORMED_KEYS: &[(&str, &str)] = &[
+  ("d0", "type"),
+  ("h0", "type"),
+  ("i0", "type"),
+  ("k0", "type"),
+  ("m0", "type"),
+  ("s0", "type"),
+  ("t0", "type"),
+  ("x0", "private_use"),
+];
+
+/// The statuses of the types of the tkeys whose values are `type`, by the key and the type.
+pub static TRANSFORMED_TYPES: &[(&str, &str)] = &[
+  ("d0-accents", "regular"),
+  ("d0-ascii", "regular"),
+  ("d0-casefold", "regular"),
+  ("d0-charname", "regular"),
+  ("d0-digit", "regular"),
+  ("d0-fcc", "regular"),
+  ("d0-fcd", "regular"),
+  ("d0-fwidth", "regular"),
+  ("d0-hex", "regular"),
+  ("d0-hwidth", "regular"),
+  ("d0-lower", "regular"),
+  ("d0-morse", "regular"),
+  ("d0-nfc", "regular"),
+  ("d0-nfd", "regular"),
+  ("d0-nfkc", "regular"),
+  ("d0-nfkd", "regular"),
+  ("d0-npinyin", "regular"),
+  ("d0-null", "regular"),
+  ("d0-publish", "regular"),
+  ("d0-remove", "regular"),
+  ("d0-title", "regular"),
+  ("d0-upper", "regular"),
+  ("d0-zawgyi", "regular"),
+  ("h0-hybrid", "regular"),
+  ("i0-handwrit", "regular"),
+  ("i0-pinyin", "regular"),
+  ("i0-und", "regular"),
+  ("i0-wubi", "regular"),
+  ("k0-101key", "regular"),
+  ("k0-102key", "regular"),
+  ("k0-600dpi", "regular"),
+  ("k0-768dpi", "regular"),
+  ("k0-android", "regular"),
+  ("k0-azerty", "regular"),
+  ("k0-chromeos", "regular"),
+  ("k0-colemak", "regular"),
+  ("k0-dvorak", "regular"),
+  ("k0-dvorakl", "regular"),
+  ("k0-dvorakr", "regular"),
+  ("k0-el220", "regular"),
+  ("k0-el319", "regular"),
+  ("k0-extended", "regular"),
+  ("k0-googlevk", "regular"),
+  ("k0-isiri", "regular"),
+  ("k0-legacy", "regular"),
+  ("k0-lt1205", "regular"),
+  ("k0-lt1582", "regular"),
+  ("k0-nutaaq", "regular"),
+  ("k0-osx", "regular"),
+  ("k0-patta", "regular"),
+  ("k0-qwerty", "regular"),
+  ("k0-qwertz", "regular"),
+  ("k0-ta99", "regular"),
+  ("k0-und", "regular"),
+  ("k0-var", "regular"),
+  ("k0-viqr", "regular"),
+  ("k0-windows", "regular"),
+  ("m0-aethiopi", "regular"),
+  ("m0-alaloc", "regular"),
+  ("m0-betamets", "regular"),
+  ("m0-bgn", "regular"),
+  ("m0-buckwalt", "regular"),
+  ("m0-c11", "regular"),
+  ("m0-css", "regular"),
+  ("m0-din", "regular"),
+  ("m0-es3842", "regular"),
+  ("m0-ewts", "regular"),
+  ("m0-gost", "regular"),
+  ("m0-gurage", "regular"),
+  ("m0-gutgarts", "regular"),
+  ("m0-iast", "regular"),
+  ("m0-iesjes", "regular"),
+  ("m0-iso", "regular"),
+  ("m0-java", "regular"),
+  ("m0-lambdin", "regular"),
+  ("m0-mcst", "regular"),
+  ("m0-mns", "regular"),
+  ("m0-percent", "regular"),
+  ("m0-perl", "regular"),
+  ("m0-plain", "regular"),
+  ("m0-prprname", "regular"),
+  ("m0-satts", "regular"),
+  ("m0-sera", "regular"),
+  ("m0-tekieali", "regular"),
+  ("m0-ungegn", "regular"),
+  ("m0-unicode", "regular"),
+  ("m0-xaleget", "regular"),
+  ("m0-xml", "regular"),
+  ("m0-xml10", "regular"),
+  ("s0-accents", "regular"),
+  ("s0-ascii", "regular"),
+  ("s0-hex", "regular"),
+  ("s0-morse", "regular"),
+  ("s0-npinyin", "regular"),
+  ("s0-publish", "regular"),
+  ("s0-zawgyi", "regular"),
+  ("t0-und", "regular"),
+];
@@ -0,0 +1,445 @@
+use crate::constants::LANG_UND;
+use crate::extensions::other::OtherExtensions;
+use crate::extensions::pu::PuExtensions;
+use crate::extensions::transformed::TransformedExtensions;
+use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
+use crate::extensions::Extensions;
+use crate::lang::UnicodeLanguageIdentifier;
+use crate::locale::UnicodeLocaleIdentifier;
+use crate::options::Casing;
+use crate::subtags::{CanonicalSubtag, Key, Value};
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::slice;
+
+/*
+ * The identifiers and the extensions are equal, hashed and ordered by the subtags of their canonical syntax.
+ *
+ * Two values are canonically equivalent if they are the same after `canonicalize_syntax`,
+ * i.e. they differ only in the casing, the separators, the order of the variants, attributes, keys and extensions,
+ * or the `true` uvalues. The aliases are not replaced, so `iw` and `he` are different.
+ *
+ * The subtags are collected into one buffer, and each of the variants, attributes, keys and extensions are sorted once,
+ * so the comparisons and the hashing are O(n log n) in the number of the subtags, even for the untrusted input.
+ * The ordering is the byte order of the canonical syntax strings, which is a total order consistent with the equality.
+ */
+
+type Subtags = Vec<CanonicalSubtag>;
+
+/// Sort the subtags pushed since `start`, and remove the duplicates.
+fn sort_distinct(subtags: &mut Subtags, start: usize) {
+  let mut sorted = subtags.split_off(start);
+  sorted.sort_unstable();
+  sorted.dedup();
+  subtags.append(&mut sorted);
+}
+
+fn lower(subtag: &str) -> CanonicalSubtag {
+  CanonicalSubtag::new(subtag, Casing::Lower)
+}
+
+fn singleton(extension: char) -> CanonicalSubtag {
+  lower(extension.encode_utf8(&mut [0; 4]))
+}
+
+impl UnicodeLanguageIdentifier {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    self.push_subtags(&mut subtags, Casing::Title, Casing::Upper);
+    subtags
+  }
+
+  fn push_subtags(&self, subtags: &mut Subtags, script: Casing, region: Casing) {
+    let language = if self.language.is_empty() {
+      LANG_UND
+    } else {
+      &self.language
+    };
+    subtags.push(lower(language));
+    subtags.extend(self.script.map(|s| CanonicalSubtag::new(&s, script)));
+    subtags.extend(self.region.map(|r| CanonicalSubtag::new(&r, region)));
+    let start = subtags.len();
+    subtags.extend(self.variants.iter().flatten().map(|v| lower(v)));
+    sort_distinct(subtags, start);
+  }
+}
+
+/// Push the keys and values of the fields merged into one, as `canonicalize_syntax` merges the extensions and the keys in any casing.
+///
+/// The uvalue of the single subtag `true` is removed, but not the tvalue, since a tkey always needs a tvalue.
+fn push_field_subtags<'a>(
+  subtags: &mut Subtags,
+  fields: impl Iterator<Item = &'a BTreeMap<Key, Vec<Value>>>,
+  uvalue: bool,
+) {
+  // the values of the same key are in the order of the keys as parsed, and then of the fields
+  let mut entries = fields
+    .enumerate()
+    .flat_map(|(index, field)| {
+      field
+        .iter()
+        .map(move |(key, values)| (lower(key), *key, index, values))
+    })
+    .collect::<Vec<_>>();
+  entries.sort_unstable_by_key(|&(lowered, key, index, _)| (lowered, key, index));
+
+  let true_value = [lower("true")];
+  let mut entries = entries.into_iter().peekable();
+  while let Some((key, ..)) = entries.peek().copied() {
+    subtags.push(key);
+    let start = subtags.len();
+    while let Some((_, _, _, values)) = entries.next_if(|entry| entry.0 == key) {
+      subtags.extend(values.iter().map(|v| lower(v)));
+    }
+    if uvalue && subtags[start..] == true_value {
+      subtags.truncate(start);
+    }
+  }
+}
+
+fn push_unicode_locale_subtags(subtags: &mut Subtags, extensions: &[UnicodeLocaleExtensions]) {
+  if extensions.is_empty() {
+    return;
+  }
+  subtags.push(singleton('u'));
+  let start = subtags.len();
+  subtags.extend(
+    extensions
+      .iter()
+      .flat_map(|u| &u.attribute)
+      .map(|a| lower(a)),
+  );
+  sort_distinct(subtags, start);
+  push_field_subtags(subtags, extensions.iter().map(|u| &u.ufield), true);
+}
+
+fn push_transformed_subtags(subtags: &mut Subtags, extensions: &[TransformedExtensions]) {
+  if extensions.is_empty() {
+    return;
+  }
+  subtags.push(singleton('t'));
+  // the tlang is lowercased, and the first one is kept
+  if let Some(tlang) = extensions.iter().find_map(|t| t.tlang.as_ref()) {
+    tlang.push_subtags(subtags, Casing::Lower, Casing::Lower);
+  }
+  push_field_subtags(subtags, extensions.iter().map(|t| &t.tfield), false);
+}
+
+fn push_other_subtags(
+  subtags: &mut Subtags,
+  extensions: &[OtherExtensions],
+  filter: fn(&char) -> bool,
+) {
+  // the stable sort keeps the values of the same singleton in order
+  let mut extensions = extensions
+    .iter()
+    .map(|o| (o.extension.to_ascii_lowercase(), o))
+    .filter(|(extension, _)| filter(extension))
+    .collect::<Vec<_>>();
+  extensions.sort_by_key(|(extension, _)| *extension);
+
+  let mut prev = None;
+  for (extension, o) in extensions {
+    if prev != Some(extension) {
+      subtags.push(singleton(extension));
+      prev = Some(extension);
+    }
+    subtags.extend(o.values.iter().map(|v| lower(v)));
+  }
+}
+
+fn push_pu_subtags(subtags: &mut Subtags, pu: Option<&PuExtensions>) {
+  if let Some(pu) = pu {
+    subtags.push(singleton('x'));
+    subtags.extend(pu.values.iter().map(|v| lower(v)));
+  }
+}
+
+impl UnicodeLocaleExtensions {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    push_unicode_locale_subtags(&mut subtags, slice::from_ref(self));
+    subtags
+  }
+}
+
+impl TransformedExtensions {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    push_transformed_subtags(&mut subtags, slice::from_ref(self));
+    subtags
+  }
+}
+
+impl OtherExtensions {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    push_other_subtags(&mut subtags, slice::from_ref(self), |_| true);
+    subtags
+  }
+}
+
+impl PuExtensions {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    push_pu_subtags(&mut subtags, Some(self));
+    subtags
+  }
+}
+
+impl Extensions {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    self.push_subtags(&mut subtags);
+    subtags
+  }
+
+  /// The singletons are in alphabetical order, except the private use extensions, which are always the last.
+  fn push_subtags(&self, subtags: &mut Subtags) {
+    let other = self.other.as_deref().unwrap_or_default();
+    push_other_subtags(subtags, other, |e| *e < 't');
+    push_transformed_subtags(subtags, self.transformed.as_deref().unwrap_or_default());
+    push_unicode_locale_subtags(subtags, self.unicode_locale.as_deref().unwrap_or_default());
+    push_other_subtags(subtags, other, |e| *e > 'u');
+    push_pu_subtags(subtags, self.pu.as_ref());
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  fn canonical_subtags(&self) -> Subtags {
+    let mut subtags = vec![];
+    self
+      .language
+      .push_subtags(&mut subtags, Casing::Title, Casing::Upper);
+    self.extensions.push_subtags(&mut subtags);
+    subtags
+  }
+}
+
+macro_rules! canonical_equivalence {
+  ($($name:ty),*) => {
+    $(
+      impl PartialEq for $name {
+        fn eq(&self, other: &Self) -> bool {
+          self.canonical_subtags() == other.canonical_subtags()
+        }
+      }
+
+      impl Eq for $name {}
+
+      impl Hash for $name {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+          for subtag in self.canonical_subtags() {
+            subtag.hash(state);
+          }
+        }
+      }
+
+      impl PartialOrd for $name {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+          Some(self.cmp(other))
+        }
+      }
+
+      impl Ord for $name {
+        fn cmp(&self, other: &Self) -> Ordering {
+          self.canonical_subtags().cmp(&other.canonical_subtags())
+        }
+      }
+    )*
+  };
+}
+
+canonical_equivalence!(
+  UnicodeLanguageIdentifier,
+  UnicodeLocaleIdentifier,
+  Extensions,
+  UnicodeLocaleExtensions,
+  TransformedExtensions,
+  OtherExtensions,
+  PuExtensions
+);
+
+/*
+ * Unit tests
+ */
+
+#[allow(unused_imports)] // for unit tests
+use crate::lang::parse_unicode_language_id;
+#[allow(unused_imports)] // for unit tests
+use crate::locale::parse_unicode_locale_id;
+#[allow(unused_imports)] // for unit tests
+use crate::options::{CasePolicy, ParseOptions};
+#[allow(unused_imports)] // for unit tests
+use std::collections::HashMap;
+
+#[test]
+fn success_canonical_equivalence() {
+  let lang = |source: &str| parse_unicode_language_id(source).unwrap();
+  assert_eq!(lang("en-US"), lang("EN-us"));
+  assert_eq!(lang("de-1996-fonipa"), lang("de-fonipa-1996"));
+  assert_eq!(lang("und-Latn"), lang("UND-latn"));
+  assert_ne!(lang("iw"), lang("he"));
+  assert_ne!(lang("en-US"), lang("en-GB"));
+
+  let locale = |source: &str| parse_unicode_locale_id(source).unwrap();
+  assert_eq!(
+    locale("en-US-u-ca-gregory-nu-latn"),
+    locale("EN-us-u-NU-latn-CA-gregory")
+  );
+  assert_eq!(locale("en-u-kn-true"), locale("en-u-kn"));
+  assert_eq!(
+    locale("en-u-ca-gregory-t-ja-x-foo"),
+    locale("en-t-ja-u-ca-gregory-x-foo")
+  );
+  assert_ne!(locale("en-u-ca-gregory"), locale("en-u-ca-buddhist"));
+  assert_ne!(locale("en-x-foo"), locale("en-x-bar"));
+  assert_eq!(
+    locale("en-u-ca-gregory-t-ja").extensions,
+    locale("de-T-JA-U-CA-gregory").extensions
+  );
+
+  // the normalized locale is equal to the one preserving the casing
+  let normalized = crate::locale::parse_unicode_locale_id_with(
+    "EN_us_U_CA_Gregory_T_JA_Latn",
+    &ParseOptions {
+      case: CasePolicy::Normalize,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  assert_eq!("en-US-u-ca-gregory-t-ja-Latn", normalized.to_string());
+  assert_eq!(locale("EN_us_U_CA_Gregory_T_JA_Latn"), normalized);
+
+  // the extensions
+  let extensions = |source: &str| locale(source).extensions;
+  let u = |source: &str| extensions(source).unicode_locale.unwrap().remove(0);
+  assert_eq!(u("en-u-FOO-bar-CA-Gregory"), u("en-u-bar-foo-ca-gregory"));
+  assert_eq!(u("en-u-kn-true"), u("en-u-KN"));
+  assert_eq!(u("en-u-xx-foo-TRUE"), u("en-u-xx-foo-true"));
+  assert_ne!(u("en-u-xx-foo-true"), u("en-u-xx-foo"));
+  assert_ne!(u("en-u-ca-gregory"), u("en-u-ca-buddhist"));
+  let t = |source: &str| extensions(source).transformed.unwrap().remove(0);
+  assert_eq!(t("en-t-JA-Latn-H0-hybrid"), t("en-t-ja-latn-h0-HYBRID"));
+  assert_ne!(t("en-t-ja"), t("en-t-ko"));
+  assert_eq!(t("en-t-m0-TRUE"), t("en-t-M0-true"));
+  assert_ne!(t("en-t-m0-true-ungegn"), t("en-t-m0-ungegn"));
+  let other = |source: &str| extensions(source).other.unwrap().remove(0);
+  assert_eq!(other("en-A-FOO"), other("en-a-foo"));
+  assert_ne!(other("en-a-foo"), other("en-b-foo"));
+  let pu = |source: &str| extensions(source).pu.unwrap();
+  assert_eq!(pu("en-x-FOO-bar"), pu("en-x-foo-BAR"));
+  assert_ne!(pu("en-x-foo-bar"), pu("en-x-bar-foo"));
+}
+
+#[test]
+fn success_canonical_order_of_strings() {
+  // the ordering is the byte order of the canonical syntax strings
+  let mut locales: Vec<UnicodeLocaleIdentifier> = [
+    "en-abcde",
+    "en-US",
+    "en-Latn",
+    "en-1996",
+    "EN",
+    "enx",
+    "en-t-ja-u-ca-buddhist",
+    "en-a-bar-x-foo",
+    "und-x-foo",
+    "en-u-ca-gregory-kn",
+    "en-u-kn-ca-gregory",
+    "en-u-attr-ca-gregory",
+  ]
+  .into_iter()
+  .map(|source| parse_unicode_locale_id(source).unwrap())
+  .collect();
+  let mut strings: Vec<String> = locales
+    .iter()
+    .map(|locale| locale.canonical_display().to_string())
+    .collect();
+  locales.sort();
+  strings.sort();
+  assert_eq!(
+    strings,
+    locales
+      .iter()
+      .map(|locale| locale.canonical_display().to_string())
+      .collect::<Vec<_>>()
+  );
+}
+
+#[test]
+fn success_canonical_hash_and_ord() {
+  let locale = |source: &str| parse_unicode_locale_id(source).unwrap();
+
+  let mut map = HashMap::new();
+  map.insert(locale("en-US-u-ca-gregory"), 1);
+  map.insert(locale("en-us-u-CA-gregory"), 2);
+  map.insert(locale("ja-JP"), 3);
+  assert_eq!(2, map.len());
+  assert_eq!(Some(&2), map.get(&locale("EN-US-U-CA-GREGORY")));
+
+  let mut map = BTreeMap::new();
+  for source in [
+    "zh-Hant",
+    "en-US",
+    "EN",
+    "en-u-ca-gregory",
+    "de-1996-fonipa",
+  ] {
+    map.insert(locale(source), source);
+  }
+  assert_eq!(
+    vec![
+      "de-fonipa-1996",
+      "en",
+      "en-US",
+      "en-u-ca-gregory",
+      "zh-Hant"
+    ]
+    .into_iter()
+    .map(locale)
+    .collect::<Vec<_>>(),
+    map.into_keys().collect::<Vec<_>>()
+  );
+  assert!(locale("en") < locale("en-US"));
+  assert!(locale("en-Latn") < locale("en-US"));
+
+  // the default is `und`
+  assert_eq!(locale("und"), UnicodeLocaleIdentifier::default());
+  assert_eq!("und", UnicodeLocaleIdentifier::default().to_string());
+}
+
+#[test]
+fn success_canonical_equivalence_of_large_input() {
+  use std::collections::hash_map::DefaultHasher;
+  use std::time::{Duration, Instant};
+
+  // the variants, attributes and keys in reverse order
+  let variants = (0..8000)
+    .rev()
+    .map(|n| format!("v{:04}", n))
+    .collect::<Vec<_>>()
+    .join("-");
+  let attributes = (0..8000)
+    .rev()
+    .map(|n| format!("a{:04}", n))
+    .collect::<Vec<_>>()
+    .join("-");
+  let keys = (b'a'..=b'z')
+    .rev()
+    .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}-foo", a as char, b as char)))
+    .collect::<Vec<_>>()
+    .join("-");
+  let source = format!("en-{}-u-{}-{}", variants, attributes, keys);
+  let locale = parse_unicode_locale_id(&source).unwrap();
+  let other = parse_unicode_locale_id(&source.to_uppercase()).unwrap();
+
+  let start = Instant::now();
+  let mut hasher = DefaultHasher::new();
+  locale.hash(&mut hasher);
+  assert_eq!(locale, other);
+  assert_eq!(Ordering::Equal, locale.cmp(&other));
+  assert!(start.elapsed() < Duration::from_secs(1));
+}
@@ -1,8 +1,11 @@
+use crate::shared::{is_sep, Fragment};
+
 use std::error::Error;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{self, Display, Formatter, Result};
+use std::ops::Range;
 
 /// Enum representing the possible errors that can occur when parsing [Unicode UTS #35 Language and Locale Identifiers](https://unicode.org/reports/tr35/#Identifiers).
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParserError {
   /// A missing identifier error.
   Missing,
@@ -14,6 +17,28 @@ pub enum ParserError {
   InvalidExtension,
   /// An invalid unicode subdivision error.
   InvalidSubdivision,
+  /// A duplicate variant subtag error.
+  DuplicateVariant,
+  /// A duplicate extension singleton error.
+  DuplicateExtension,
+  /// A duplicate ukey or tkey in an extension error.
+  DuplicateKey,
+  /// An empty subtag error, e.g. `en--US`.
+  EmptySubtag,
+  /// A leading separator error, e.g. `-en`.
+  LeadingSeparator,
+  /// A trailing separator error, e.g. `en-`.
+  TrailingSeparator,
+  /// A non-ASCII character error.
+  NonAsciiCharacter,
+  /// A legacy `_` separator error, when it is not allowed.
+  LegacySeparator,
+  /// A subtag not in the canonical casing error, when it is not allowed.
+  NonCanonicalCase,
+  /// An input longer than the maximum length error.
+  TooLong,
+  /// An input with more subtags than the maximum error.
+  TooManySubtags,
   /// An unexpected error.
   Unexpected,
 }
@@ -28,8 +53,250 @@ impl Display for ParserError {
       ParserError::InvalidSubtag => "Invalid subtag",
       ParserError::InvalidExtension => "Invalid extension",
       ParserError::InvalidSubdivision => "Invalid subdivision",
+      ParserError::DuplicateVariant => "Duplicate variant",
+      ParserError::DuplicateExtension => "Duplicate extension",
+      ParserError::DuplicateKey => "Duplicate key",
+      ParserError::EmptySubtag => "Empty subtag",
+      ParserError::LeadingSeparator => "Leading separator",
+      ParserError::TrailingSeparator => "Trailing separator",
+      ParserError::NonAsciiCharacter => "Non-ASCII character",
+      ParserError::LegacySeparator => "Legacy separator",
+      ParserError::NonCanonicalCase => "Non-canonical case",
+      ParserError::TooLong => "Too long",
+      ParserError::TooManySubtags => "Too many subtags",
       ParserError::Unexpected => "Unexpected error",
     };
     f.write_str(value)
   }
 }
+
+/// Enum representing the grammar productions of [UTS #35](https://unicode.org/reports/tr35/#Unicode_locale_identifier) that the parser can expect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Production {
+  /// [`unicode_language_subtag`](https://unicode.org/reports/tr35/#unicode_language_subtag)
+  Language,
+  /// An [`extlang`](https://www.rfc-editor.org/rfc/rfc5646#section-2.2.2) subtag of BCP 47 language tags
+  Extlang,
+  /// [`unicode_script_subtag`](https://unicode.org/reports/tr35/#unicode_script_subtag)
+  Script,
+  /// [`unicode_region_subtag`](https://unicode.org/reports/tr35/#unicode_region_subtag)
+  Region,
+  /// [`unicode_variant_subtag`](https://unicode.org/reports/tr35/#unicode_variant_subtag)
+  Variant,
+  /// An extension singleton, e.g. `u`, `t` or `x`.
+  Singleton,
+  /// An `attribute` of unicode locale extensions.
+  Attribute,
+  /// A [`ukey`](https://unicode.org/reports/tr35/#ukey)
+  UKey,
+  /// An [`uvalue`](https://unicode.org/reports/tr35/#uvalue)
+  UValue,
+  /// A [`tkey`](https://unicode.org/reports/tr35/#tkey)
+  TKey,
+  /// A [`tvalue`](https://unicode.org/reports/tr35/#tvalue)
+  TValue,
+  /// A value of [`other_extensions`](https://unicode.org/reports/tr35/#other_extensions)
+  OtherValue,
+  /// A value of [`pu_extensions`](https://unicode.org/reports/tr35/#pu_extensions)
+  PuValue,
+  /// [`unicode_subdivision_id`](https://unicode.org/reports/tr35/#unicode_subdivision_id)
+  Subdivision,
+  /// A subtag of [`unicode_measure_unit`](https://unicode.org/reports/tr35/#unicode_measure_unit)
+  MeasureUnit,
+}
+
+impl Display for Production {
+  fn fmt(&self, f: &mut Formatter) -> Result {
+    let value = match self {
+      Production::Language => "language",
+      Production::Extlang => "extlang",
+      Production::Script => "script",
+      Production::Region => "region",
+      Production::Variant => "variant",
+      Production::Singleton => "extension singleton",
+      Production::Attribute => "attribute",
+      Production::UKey => "ukey",
+      Production::UValue => "uvalue",
+      Production::TKey => "tkey",
+      Production::TValue => "tvalue",
+      Production::OtherValue => "other extension value",
+      Production::PuValue => "pu value",
+      Production::Subdivision => "subdivision",
+      Production::MeasureUnit => "measure unit",
+    };
+    f.write_str(value)
+  }
+}
+
+/// An error with the position in the source where the parsing failed.
+///
+/// The [`ParserError`] of the error is available with [`SyntaxError::kind`], and `SyntaxError` can be compared with `ParserError` directly.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_locale_id, ParserError, Production};
+///
+/// let err = parse_locale_id("zh-Hant-TW-u-ca-chinese-k1").unwrap_err();
+/// assert_eq!(ParserError::InvalidSubtag, err.kind());
+/// assert_eq!(24..26, err.span());
+/// assert_eq!(6, err.index());
+/// assert_eq!(Some(Production::UKey), err.expected());
+/// assert_eq!("k1", err.fragment());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+  kind: ParserError,
+  span: Range<usize>,
+  index: usize,
+  expected: Option<Production>,
+  fragment: String,
+}
+
+impl SyntaxError {
+  /// Make an error at the start of the source, e.g. for the empty source.
+  pub(crate) fn new(kind: ParserError) -> Self {
+    SyntaxError {
+      kind,
+      span: 0..0,
+      index: 0,
+      expected: None,
+      fragment: String::new(),
+    }
+  }
+
+  /// Make an error for `fragment`, with its span in the source.
+  pub(crate) fn at(kind: ParserError, fragment: Fragment, expected: Option<Production>) -> Self {
+    SyntaxError {
+      kind,
+      span: fragment.span(),
+      index: 0,
+      expected,
+      fragment: fragment.text.to_string(),
+    }
+  }
+
+  pub(crate) fn expecting(mut self, expected: Production) -> Self {
+    self.expected = Some(expected);
+    self
+  }
+
+  /// Count the index of the subtag at the span in `source`.
+  ///
+  /// An empty span right after a subtag is the missing subtag following it, e.g. a tvalue after the last tkey.
+  pub(crate) fn locate(mut self, source: &str) -> Self {
+    let before = source.as_bytes().get(..self.span.start).unwrap_or_default();
+    let seps = before.iter().filter(|b| is_sep(b)).count();
+    let following = self.span.is_empty() && before.last().is_some_and(|b| !is_sep(b));
+    self.index = if following { seps + 1 } else { seps };
+    self
+  }
+
+  /// Returns the kind of the error.
+  pub fn kind(&self) -> ParserError {
+    self.kind
+  }
+
+  /// Returns the byte range of the offending subtag in the source.
+  pub fn span(&self) -> Range<usize> {
+    self.span.clone()
+  }
+
+  /// Returns the index of the offending subtag in the source, counting from 0.
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Returns the grammar production that was expected at the offending subtag, if any.
+  pub fn expected(&self) -> Option<Production> {
+    self.expected
+  }
+
+  /// Returns the offending subtag.
+  pub fn fragment(&self) -> &str {
+    &self.fragment
+  }
+}
+
+impl Error for SyntaxError {}
+
+impl Display for SyntaxError {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.kind)?;
+    if !self.fragment.is_empty() {
+      write!(f, " `{}`", self.fragment)?;
+    }
+    write!(
+      f,
+      " at {}..{} (subtag {})",
+      self.span.start, self.span.end, self.index
+    )?;
+    if let Some(expected) = self.expected {
+      write!(f, ", expected {}", expected)?;
+    }
+    Ok(())
+  }
+}
+
+impl From<ParserError> for SyntaxError {
+  fn from(kind: ParserError) -> Self {
+    SyntaxError::new(kind)
+  }
+}
+
+impl From<SyntaxError> for ParserError {
+  fn from(err: SyntaxError) -> Self {
+    err.kind
+  }
+}
+
+impl PartialEq<ParserError> for SyntaxError {
+  fn eq(&self, other: &ParserError) -> bool {
+    self.kind == *other
+  }
+}
+
+impl PartialEq<SyntaxError> for ParserError {
+  fn eq(&self, other: &SyntaxError) -> bool {
+    *self == other.kind
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn syntax_error_locate() {
+  let source = "en-US-u-k1";
+  let err = SyntaxError::at(
+    ParserError::InvalidSubtag,
+    Fragment::new(8, &source[8..]),
+    Some(Production::UKey),
+  )
+  .locate(source);
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(8..10, err.span());
+  assert_eq!(3, err.index());
+  assert_eq!("k1", err.fragment());
+  assert_eq!(
+    "Invalid subtag `k1` at 8..10 (subtag 3), expected ukey",
+    format!("{}", err)
+  );
+
+  // the missing subtag after the last one
+  let err = SyntaxError::at(ParserError::InvalidSubtag, Fragment::new(10, ""), None).locate(source);
+  assert_eq!(10..10, err.span());
+  assert_eq!(4, err.index());
+  assert_eq!("Invalid subtag at 10..10 (subtag 4)", format!("{}", err));
+
+  // the empty subtag
+  let err = SyntaxError::at(ParserError::EmptySubtag, Fragment::new(3, ""), None).locate("en--US");
+  assert_eq!(3..3, err.span());
+  assert_eq!(1, err.index());
+
+  // no fragment
+  let err = SyntaxError::new(ParserError::Missing).locate("");
+  assert_eq!(0..0, err.span());
+  assert_eq!(0, err.index());
+}
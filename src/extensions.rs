@@ -1,3 +1,4 @@
+pub mod icu;
 pub mod other;
 pub mod pu;
 pub mod transformed;
@@ -9,8 +10,10 @@ use transformed::{parse_transformed_extensions, TransformedExtensions};
 use unicode_locale::{parse_unicode_locale_extensions, UnicodeLocaleExtensions};
 
 use crate::constants::SEP;
-use crate::errors::ParserError;
-use crate::shared::split_str;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::options::Casing;
+use crate::shared::{check_subtags, split_str, Fragment, ParserContext};
+use crate::tokenizer::TokenKind;
 
 use std::fmt::{self, Write};
 use std::iter::Peekable;
@@ -24,6 +27,15 @@ enum ExtensionKind {
 }
 
 impl ExtensionKind {
+  fn singleton(&self) -> char {
+    match self {
+      ExtensionKind::UnicodeLocale => 'u',
+      ExtensionKind::Transformed => 't',
+      ExtensionKind::Pu => 'x',
+      ExtensionKind::Other(c) => *c,
+    }
+  }
+
   fn from_byte(key: u8) -> Result<Self, ParserError> {
     let key = key.to_ascii_lowercase();
     match key {
@@ -38,17 +50,14 @@ impl ExtensionKind {
 
 impl fmt::Display for ExtensionKind {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let c = match self {
-      ExtensionKind::UnicodeLocale => 'u',
-      ExtensionKind::Transformed => 't',
-      ExtensionKind::Pu => 'x',
-      ExtensionKind::Other(c) => *c,
-    };
-    f.write_char(c)
+    f.write_char(self.singleton())
   }
 }
 
-#[derive(Debug)]
+/// The extensions of an Unicode Locale Identifier.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence, as [`UnicodeLocaleIdentifier`](crate::locale::UnicodeLocaleIdentifier).
+#[derive(Debug, Clone, Default)]
 pub struct Extensions {
   pub unicode_locale: Option<Vec<UnicodeLocaleExtensions>>,
   pub transformed: Option<Vec<TransformedExtensions>>,
@@ -85,51 +94,192 @@ impl fmt::Display for Extensions {
   }
 }
 
+impl Extensions {
+  /// Returns whether there is no extension.
+  pub fn is_empty(&self) -> bool {
+    self.unicode_locale.is_none()
+      && self.transformed.is_none()
+      && self.other.is_none()
+      && self.pu.is_none()
+  }
+
+  /// Normalize to the canonical syntax of UTS #35.
+  ///
+  /// Each extension is normalized with its own `canonicalize_syntax`, and the extensions with the same singleton are merged into one.
+  pub fn canonicalize_syntax(&mut self) {
+    self.unicode_locale = self.unicode_locale.take().and_then(|unicode_locale| {
+      let mut merged = unicode_locale.into_iter().reduce(|mut merged, u| {
+        merged.attribute.extend(u.attribute);
+        for (key, values) in u.ufield {
+          merged.ufield.entry(key).or_default().extend(values);
+        }
+        merged
+      })?;
+      merged.canonicalize_syntax();
+      Some(vec![merged])
+    });
+    self.transformed = self.transformed.take().and_then(|transformed| {
+      let mut merged = transformed.into_iter().reduce(|mut merged, t| {
+        merged.tlang = merged.tlang.or(t.tlang);
+        for (key, values) in t.tfield {
+          merged.tfield.entry(key).or_default().extend(values);
+        }
+        merged
+      })?;
+      merged.canonicalize_syntax();
+      Some(vec![merged])
+    });
+    if let Some(other) = &mut self.other {
+      for o in other.iter_mut() {
+        o.canonicalize_syntax();
+      }
+      other.sort_by_key(|o| o.extension);
+      other.dedup_by(|o, merged| {
+        let same = o.extension == merged.extension;
+        if same {
+          merged.values.append(&mut o.values);
+        }
+        same
+      });
+    }
+    if let Some(pu) = &mut self.pu {
+      pu.canonicalize_syntax();
+    }
+  }
+
+  /// Write the extensions with the singletons in alphabetical order, except the private use extensions, which are always the last.
+  pub(crate) fn write_canonical(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let other = self.other.iter().flatten();
+    let unicode_locale = self
+      .unicode_locale
+      .iter()
+      .flatten()
+      .map(|u| u as &dyn fmt::Display);
+    let transformed = self
+      .transformed
+      .iter()
+      .flatten()
+      .map(|t| t as &dyn fmt::Display);
+    let extensions = other
+      .clone()
+      .filter(|o| o.extension < 't')
+      .map(|o| o as &dyn fmt::Display)
+      .chain(transformed)
+      .chain(unicode_locale)
+      .chain(
+        other
+          .filter(|o| o.extension > 'u')
+          .map(|o| o as &dyn fmt::Display),
+      )
+      .chain(self.pu.iter().map(|pu| pu as &dyn fmt::Display));
+    for (i, extension) in extensions.enumerate() {
+      if i > 0 {
+        f.write_char(SEP)?;
+      }
+      extension.fmt(f)?;
+    }
+    Ok(())
+  }
+}
+
 #[allow(dead_code)]
-pub fn parse_extensions(chunk: &str) -> Result<Extensions, ParserError> {
+pub fn parse_extensions(chunk: &str) -> Result<Extensions, SyntaxError> {
   // check empty
   if chunk.is_empty() {
-    return Err(ParserError::Missing);
+    return Err(ParserError::Missing.into());
   }
+  let mut ctx = ParserContext::new(false);
+  check_subtags(chunk, &mut ctx)?;
 
-  parse_extensions_from_iter(&mut split_str(chunk).peekable())
+  parse_extensions_from_iter(&mut split_str(chunk).peekable(), &mut ctx)
 }
 
 pub fn parse_extensions_from_iter<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<Extensions, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  ctx: &mut ParserContext,
+) -> Result<Extensions, SyntaxError> {
   let mut unicode_locale = vec![];
   let mut transformed = vec![];
   let mut other = vec![];
   let mut pu = None;
 
-  let mut chunk = iter.next();
-  while let Some(subtag) = chunk {
-    match subtag
-      .as_bytes()
-      .first()
-      .map(|c| ExtensionKind::from_byte(*c))
-    {
-      Some(Ok(ExtensionKind::UnicodeLocale)) => {
-        unicode_locale.push(parse_unicode_locale_extensions(iter)?);
+  while let Some(subtag) = iter.next() {
+    let kind = match subtag.as_bytes() {
+      [key] => ExtensionKind::from_byte(*key)
+        .map_err(|e| SyntaxError::at(e, subtag, Some(Production::Singleton))),
+      [] => Err(SyntaxError::at(ParserError::EmptySubtag, subtag, None)),
+      _ => Err(SyntaxError::at(
+        ParserError::InvalidExtension,
+        subtag,
+        Some(Production::Singleton),
+      )),
+    };
+    let kind = match kind {
+      Ok(kind) => {
+        ctx.cased(subtag, Casing::Lower)?;
+        ctx.token(TokenKind::Singleton(kind.singleton()), subtag);
+        kind
       }
-      Some(Ok(ExtensionKind::Transformed)) => {
-        transformed.push(parse_transformed_extensions(iter)?);
+      Err(err) => {
+        ctx.recover(err)?;
+        // skip the subtags up to the next singleton
+        while iter.peek().is_some_and(|subtag| subtag.len() != 1) {
+          iter.next();
+        }
+        continue;
       }
-      Some(Ok(ExtensionKind::Pu)) => {
+    };
+    let duplicate = || SyntaxError::at(ParserError::DuplicateExtension, subtag, None);
+
+    // an extension needs at least one subtag after the singleton, so the empty one is dropped in the lenient mode
+    let empty = |expected| SyntaxError::at(ParserError::InvalidExtension, subtag, Some(expected));
+
+    match kind {
+      ExtensionKind::UnicodeLocale => {
+        if ctx.strict && !unicode_locale.is_empty() {
+          ctx.recover(duplicate())?;
+        }
+        let u = parse_unicode_locale_extensions(iter, ctx)?;
+        if u.attribute.is_empty() && u.ufield.is_empty() {
+          ctx.recover(empty(Production::UKey))?;
+        } else {
+          unicode_locale.push(u);
+        }
+      }
+      ExtensionKind::Transformed => {
+        if ctx.strict && !transformed.is_empty() {
+          ctx.recover(duplicate())?;
+        }
+        let t = parse_transformed_extensions(iter, ctx)?;
+        if t.tlang.is_none() && t.tfield.is_empty() {
+          ctx.recover(empty(Production::TKey))?;
+        } else {
+          transformed.push(t);
+        }
+      }
+      ExtensionKind::Pu => {
         if pu.is_some() {
-          return Err(ParserError::Unexpected);
+          return Err(SyntaxError::at(ParserError::Unexpected, subtag, None));
+        }
+        let x = parse_pu_extensions(iter, ctx)?;
+        if x.values.is_empty() {
+          ctx.recover(empty(Production::PuValue))?;
+        } else {
+          pu = Some(x);
         }
-        pu = Some(parse_pu_extensions(iter)?);
       }
-      Some(Ok(ExtensionKind::Other(c))) => {
-        other.push(parse_other_extensions(iter, c)?);
+      ExtensionKind::Other(c) => {
+        if ctx.strict && other.iter().any(|o: &OtherExtensions| o.extension == c) {
+          ctx.recover(duplicate())?;
+        }
+        let o = parse_other_extensions(iter, c, ctx)?;
+        if o.values.is_empty() {
+          ctx.recover(empty(Production::OtherValue))?;
+        } else {
+          other.push(o);
+        }
       }
-      None => {}
-      _ => unreachable!(),
     }
-
-    chunk = iter.next();
   }
 
   // normalize unicode locale extensions
@@ -218,6 +368,34 @@ fn success_parse_extensions() {
 fn fail_parse_unicode_extensions() {
   // missing locale
   assert_eq!(ParserError::Missing, parse_extensions("").unwrap_err());
+
+  // invalid singleton
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_extensions("!-foo").unwrap_err()
+  );
+
+  // not a singleton
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_extensions("ca-buddhist").unwrap_err()
+  );
+
+  // empty subtag
+  assert_eq!(
+    ParserError::EmptySubtag,
+    parse_extensions("u--ca").unwrap_err()
+  );
+
+  // singleton without subtags
+  for chunk in ["u", "t", "x", "a", "u-ca-x", "u-c", "a-b", "t-x-foo"] {
+    assert_eq!(
+      ParserError::InvalidExtension,
+      parse_extensions(chunk).unwrap_err(),
+      "{}",
+      chunk
+    );
+  }
 }
 
 #[test]
@@ -0,0 +1,161 @@
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
+use crate::shared::{Fragment, ParserContext};
+use crate::subtags::{is_ukey, Key, Value};
+
+use std::collections::BTreeMap;
+
+// ICU keyword names and their ukey
+// https://unicode-org.github.io/icu/userguide/locale/#keywords
+const KEYS: [(&str, &str); 20] = [
+  ("calendar", "ca"),
+  ("colalternate", "ka"),
+  ("colbackwards", "kb"),
+  ("colcasefirst", "kf"),
+  ("colcaselevel", "kc"),
+  ("colhiraganaquaternary", "kh"),
+  ("collation", "co"),
+  ("colnormalization", "kk"),
+  ("colnumeric", "kn"),
+  ("colreorder", "kr"),
+  ("colstrength", "ks"),
+  ("currency", "cu"),
+  ("hours", "hc"),
+  ("measure", "ms"),
+  ("numbers", "nu"),
+  ("timezone", "tz"),
+  ("variabletop", "vt"),
+  ("lb", "lb"),
+  ("lw", "lw"),
+  ("em", "em"),
+];
+
+// ICU keyword values that differ from their uvalue
+const VALUES: [(&str, &str); 15] = [
+  ("dictionary", "dict"),
+  ("ethiopic-amete-alem", "ethioaa"),
+  ("gb2312han", "gb2312"),
+  ("gregorian", "gregory"),
+  ("identical", "identic"),
+  ("imperial", "uksystem"),
+  ("lowercase", "lower"),
+  ("no", "false"),
+  ("non-ignorable", "noignore"),
+  ("phonebook", "phonebk"),
+  ("primary", "level1"),
+  ("secondary", "level2"),
+  ("tertiary", "level3"),
+  ("traditional", "trad"),
+  ("yes", "true"),
+];
+
+fn is_uvalue(value: &[u8]) -> bool {
+  (3..=8).contains(&value.len()) && value.iter().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// Parse the keywords of a legacy ICU locale ID, e.g. `collation=phonebook;calendar=gregorian`, as unicode locale extensions.
+pub fn parse_icu_keywords(
+  keywords: Fragment,
+  ctx: &mut ParserContext,
+) -> Result<UnicodeLocaleExtensions, SyntaxError> {
+  let mut ufield = BTreeMap::new();
+
+  let mut start = keywords.start;
+  for text in keywords.text.split(';') {
+    let keyword = Fragment::new(start, text);
+    start += text.len() + 1;
+    if keyword.is_empty() {
+      continue;
+    }
+    let (name, value) = text.split_once('=').unwrap_or((text, ""));
+
+    let name = name.trim().to_ascii_lowercase();
+    let key = match KEYS.iter().find(|(n, _)| *n == name) {
+      Some((_, key)) => Key::from_str_unchecked(key),
+      None if is_ukey(name.as_bytes()) => Key::from_str_unchecked(&name),
+      None => {
+        ctx.recover(SyntaxError::at(
+          ParserError::InvalidExtension,
+          keyword,
+          Some(Production::UKey),
+        ))?;
+        continue;
+      }
+    };
+
+    let value = value.trim().to_ascii_lowercase();
+    let value = match VALUES.iter().find(|(v, _)| *v == value) {
+      Some((_, uvalue)) => uvalue.to_string(),
+      None => value,
+    };
+    let mut uvalue = vec![];
+    for subtag in value.split('-').filter(|v| !v.is_empty()) {
+      if is_uvalue(subtag.as_bytes()) {
+        uvalue.push(Value::from_str_unchecked(subtag));
+      } else {
+        ctx.recover(SyntaxError::at(
+          ParserError::InvalidSubtag,
+          keyword,
+          Some(Production::UValue),
+        ))?;
+        uvalue.clear();
+        break;
+      }
+    }
+    ufield.entry(key).or_insert(uvalue);
+  }
+
+  Ok(UnicodeLocaleExtensions {
+    attribute: vec![],
+    ufield,
+  })
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse_icu_keywords() {
+  let mut ctx = ParserContext::default();
+  assert_eq!(
+    "u-ca-gregory-co-phonebk",
+    format!(
+      "{}",
+      parse_icu_keywords(
+        Fragment::new(0, "collation=phonebook;calendar=gregorian"),
+        &mut ctx
+      )
+      .unwrap()
+    )
+  );
+  assert_eq!(
+    "u-ca-ethioaa-kn-true-nu-thai",
+    format!(
+      "{}",
+      parse_icu_keywords(
+        Fragment::new(0, "CALENDAR=ethiopic-amete-alem;colnumeric=yes;nu=thai;"),
+        &mut ctx
+      )
+      .unwrap()
+    )
+  );
+}
+
+#[test]
+fn fail_parse_icu_keywords() {
+  let mut ctx = ParserContext::default();
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_icu_keywords(Fragment::new(0, "unknownkey=value"), &mut ctx).unwrap_err()
+  );
+
+  // the span of the keyword, with the offset of the keywords
+  let err = parse_icu_keywords(
+    Fragment::new(6, "ca=gregorian;timezone=America/Los_Angeles"),
+    &mut ctx,
+  )
+  .unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err);
+  assert_eq!(19..47, err.span());
+}
@@ -1,12 +1,19 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::options::Casing;
+use crate::shared::{Fragment, ParserContext};
+use crate::subtags::{Subtag, Value};
+use crate::tokenizer::TokenKind;
 
 use std::fmt::{self, Write};
 use std::iter::Peekable;
 
-#[derive(Debug, PartialEq)]
+/// The [other extensions](https://unicode.org/reports/tr35/#other_extensions), with a singleton other than `u`, `t` and `x`.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence, as [`Extensions`](crate::Extensions).
+#[derive(Debug, Clone)]
 pub struct OtherExtensions {
-  pub values: Vec<String>,
+  pub values: Vec<Value>,
   pub extension: char,
 }
 
@@ -21,19 +28,36 @@ impl fmt::Display for OtherExtensions {
   }
 }
 
+impl OtherExtensions {
+  /// Normalize to the canonical syntax of UTS #35, which lowercases the values.
+  pub fn canonicalize_syntax(&mut self) {
+    self.extension = self.extension.to_ascii_lowercase();
+    for value in self.values.iter_mut() {
+      *value = value.with_casing(Casing::Lower);
+    }
+  }
+}
+
 pub fn parse_other_extensions<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
   extension: char,
-) -> Result<OtherExtensions, ParserError> {
+  ctx: &mut ParserContext,
+) -> Result<OtherExtensions, SyntaxError> {
   // other_extensions
   // https://www.unicode.org/reports/tr35/tr35-71/tr35.html#other_extensions
   let mut values = vec![];
 
-  while let Some(subtag) = iter.peek() {
+  while let Some(&subtag) = iter.peek() {
     if subtag.len() == 1 {
       break;
     } else {
-      values.push(String::from(parse_value(subtag)?));
+      match parse_value(subtag) {
+        Ok(value) => {
+          values.push(ctx.subtag(value, Casing::Lower)?);
+          ctx.token(TokenKind::OtherValue, value);
+        }
+        Err(err) => ctx.recover(err)?,
+      }
       iter.next();
     }
   }
@@ -45,9 +69,13 @@ fn is_other_value_subtag(subtag: &[u8]) -> bool {
   (2..=8).contains(&subtag.len()) && subtag.iter().all(|c| c.is_ascii_alphanumeric())
 }
 
-fn parse_value(subtag: &str) -> Result<&str, ParserError> {
+fn parse_value(subtag: Fragment) -> Result<Fragment, SyntaxError> {
   if !is_other_value_subtag(subtag.as_bytes()) {
-    Err(ParserError::InvalidSubtag)
+    Err(SyntaxError::at(
+      ParserError::InvalidSubtag,
+      subtag,
+      Some(Production::OtherValue),
+    ))
   } else {
     Ok(subtag)
   }
@@ -66,14 +94,19 @@ fn success_other_extensions() {
   let mut iter = split_str("abc-123").peekable();
   assert_eq!(
     vec!["abc", "123"],
-    parse_other_extensions(&mut iter, 'a').unwrap().values
+    parse_other_extensions(&mut iter, 'a', &mut ParserContext::default())
+      .unwrap()
+      .values
   );
 
   // Display trait implementation
   let mut iter = split_str("abc-123").peekable();
   assert_eq!(
     "b-abc-123",
-    format!("{}", parse_other_extensions(&mut iter, 'b').unwrap())
+    format!(
+      "{}",
+      parse_other_extensions(&mut iter, 'b', &mut ParserContext::default()).unwrap()
+    )
   );
 }
 
@@ -83,6 +116,6 @@ fn fail_pu_extensions() {
   let mut iter = split_str("abc-123456789").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_other_extensions(&mut iter, '1').unwrap_err()
+    parse_other_extensions(&mut iter, '1', &mut ParserContext::default()).unwrap_err()
   );
 }
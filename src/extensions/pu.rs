@@ -1,13 +1,20 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
 use crate::extensions::ExtensionKind;
+use crate::options::Casing;
+use crate::shared::{Fragment, ParserContext};
+use crate::subtags::{Subtag, Value};
+use crate::tokenizer::TokenKind;
 
 use std::fmt::{self, Write};
 use std::iter::Peekable;
 
-#[derive(Debug)]
+/// The [private use extensions](https://unicode.org/reports/tr35/#pu_extensions), i.e. `x`.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence, as [`Extensions`](crate::Extensions).
+#[derive(Debug, Clone)]
 pub struct PuExtensions {
-  pub values: Vec<String>,
+  pub values: Vec<Value>,
 }
 
 impl fmt::Display for PuExtensions {
@@ -21,15 +28,31 @@ impl fmt::Display for PuExtensions {
   }
 }
 
+impl PuExtensions {
+  /// Normalize to the canonical syntax of UTS #35, which lowercases the values.
+  pub fn canonicalize_syntax(&mut self) {
+    for value in self.values.iter_mut() {
+      *value = value.with_casing(Casing::Lower);
+    }
+  }
+}
+
 pub fn parse_pu_extensions<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<PuExtensions, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  ctx: &mut ParserContext,
+) -> Result<PuExtensions, SyntaxError> {
   // pu_extensions
   // https://www.unicode.org/reports/tr35/tr35-71/tr35.html#pu_extensions
   let mut values = vec![];
 
   for subtag in iter {
-    values.push(String::from(parse_value(subtag)?));
+    match parse_value(subtag) {
+      Ok(value) => {
+        values.push(ctx.subtag(value, Casing::Lower)?);
+        ctx.token(TokenKind::PrivateUse, value);
+      }
+      Err(err) => ctx.recover(err)?,
+    }
   }
 
   Ok(PuExtensions { values })
@@ -39,9 +62,13 @@ fn is_pu_value_subtag(subtag: &[u8]) -> bool {
   (1..=8).contains(&subtag.len()) && subtag.iter().all(|c| c.is_ascii_alphanumeric())
 }
 
-fn parse_value(subtag: &str) -> Result<&str, ParserError> {
+fn parse_value(subtag: Fragment) -> Result<Fragment, SyntaxError> {
   if !is_pu_value_subtag(subtag.as_bytes()) {
-    Err(ParserError::InvalidSubtag)
+    Err(SyntaxError::at(
+      ParserError::InvalidSubtag,
+      subtag,
+      Some(Production::PuValue),
+    ))
   } else {
     Ok(subtag)
   }
@@ -60,14 +87,19 @@ fn success_pu_extensions() {
   let mut iter = split_str("abc-123").peekable();
   assert_eq!(
     vec!["abc", "123"],
-    parse_pu_extensions(&mut iter).unwrap().values
+    parse_pu_extensions(&mut iter, &mut ParserContext::default())
+      .unwrap()
+      .values
   );
 
   // Display trait implementation
   let mut iter = split_str("abc-123").peekable();
   assert_eq!(
     "x-abc-123",
-    format!("{}", parse_pu_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_pu_extensions(&mut iter, &mut ParserContext::default()).unwrap()
+    )
   );
 }
 
@@ -77,6 +109,6 @@ fn fail_pu_extensions() {
   let mut iter = split_str("abc-123456789").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_pu_extensions(&mut iter).unwrap_err()
+    parse_pu_extensions(&mut iter, &mut ParserContext::default()).unwrap_err()
   );
 }
@@ -1,17 +1,24 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::extensions::unicode_locale::{canonicalize_fields, is_duplicate_key};
 use crate::extensions::ExtensionKind;
 use crate::lang::{parse_unicode_language_id_from_iter, UnicodeLanguageIdentifier};
-use crate::subtags::is_language_subtag;
+use crate::options::Casing;
+use crate::shared::{Fragment, ParserContext};
+use crate::subtags::{is_language_subtag, is_tkey, Key, Subtag, Value};
+use crate::tokenizer::TokenKind;
 
 use std::collections::BTreeMap;
 use std::fmt::{self, Debug, Write};
 use std::iter::Peekable;
 
-#[derive(Debug)]
+/// The [transformed extensions](https://unicode.org/reports/tr35/#transformed_extensions), i.e. `t`.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence, as [`Extensions`](crate::Extensions).
+#[derive(Debug, Clone)]
 pub struct TransformedExtensions {
   pub tlang: Option<UnicodeLanguageIdentifier>,
-  pub tfield: BTreeMap<String, Vec<String>>,
+  pub tfield: BTreeMap<Key, Vec<Value>>,
 }
 
 impl fmt::Display for TransformedExtensions {
@@ -33,60 +40,116 @@ impl fmt::Display for TransformedExtensions {
   }
 }
 
+impl TransformedExtensions {
+  /// Normalize to the canonical syntax of UTS #35.
+  ///
+  /// All subtags are lowercased, including the script and the region of the `tlang`, whose variants are sorted and deduplicated.
+  /// Unlike the uvalues, the `true` tvalues are kept, since a tkey always needs a tvalue.
+  pub fn canonicalize_syntax(&mut self) {
+    if let Some(tlang) = &mut self.tlang {
+      tlang.canonicalize_syntax();
+      tlang.script = tlang.script.map(|script| script.with_casing(Casing::Lower));
+      tlang.region = tlang.region.map(|region| region.with_casing(Casing::Lower));
+    }
+    canonicalize_fields(&mut self.tfield);
+  }
+}
+
 pub fn parse_transformed_extensions<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<TransformedExtensions, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  ctx: &mut ParserContext,
+) -> Result<TransformedExtensions, SyntaxError> {
   // transformed_extensions
   // https://unicode.org/reports/tr35/#transformed_extensions
   let mut tlang = None;
   let mut tfield = BTreeMap::new();
-  let mut tkey: Option<String> = None;
-  let mut tvalue: Vec<String> = vec![];
+  let mut tkey: Option<Key> = None;
+  let mut tvalue: Vec<Value> = vec![];
+  // the end of the last tkey, where a missing tvalue is reported
+  let mut tkey_end = 0;
 
-  while let Some(subtag) = iter.peek() {
+  while let Some(&subtag) = iter.peek() {
     let subtag_bytes = subtag.as_bytes();
     let len = subtag_bytes.len();
     if len == 1 {
       break;
-    } else if len == 2 && subtag_bytes[0].is_ascii_alphabetic() && subtag_bytes[1].is_ascii_digit()
-    {
+    } else if is_tkey(subtag_bytes) {
       // for tkey
+      if ctx.strict && is_duplicate_key(&tfield, &tkey, &subtag) {
+        ctx.recover(SyntaxError::at(ParserError::DuplicateKey, subtag, None))?;
+      }
       if let Some(tkey) = tkey {
-        if !tfield.contains_key(&tkey) {
-          tfield.insert(tkey.clone(), vec![]);
+        if tvalue.is_empty() {
+          // the previous tkey has no tvalue, so it is dropped in the lenient mode
+          ctx.recover(SyntaxError::at(
+            ParserError::InvalidSubtag,
+            subtag,
+            Some(Production::TValue),
+          ))?;
+        } else {
+          tfield
+            .entry(tkey)
+            .or_insert_with(Vec::new)
+            .append(&mut tvalue);
         }
-        let values = tfield.get_mut(&tkey).unwrap();
-        for value in tvalue {
-          values.push(value);
-        }
-        tvalue = vec![];
       }
-      tkey = Some(subtag.to_string());
+      tkey = Some(ctx.subtag(subtag, Casing::Lower)?);
+      ctx.token(TokenKind::TKey, subtag);
+      tkey_end = subtag.end();
       iter.next();
+    } else if tlang.is_none() && tkey.is_none() && is_language_subtag(subtag_bytes) {
+      // for tlang, which is checked before tvalue, since a 3 letters language subtag is also a tvalue
+      tlang = Some(parse_unicode_language_id_from_iter(iter, ctx)?);
     } else if (3..=8).contains(&len) && subtag_bytes.iter().all(|c| c.is_ascii_alphanumeric()) {
       // for tvalue
       if tkey.is_none() {
-        return Err(ParserError::InvalidSubtag);
+        ctx.recover(SyntaxError::at(
+          ParserError::InvalidSubtag,
+          subtag,
+          Some(Production::TKey),
+        ))?;
+      } else {
+        tvalue.push(ctx.subtag(subtag, Casing::Lower)?);
+        ctx.token(TokenKind::TValue, subtag);
       }
-      tvalue.push(subtag.to_string());
       iter.next();
     } else if is_language_subtag(subtag_bytes) {
-      tlang = Some(parse_unicode_language_id_from_iter(iter)?);
+      // tlang is allowed only at the beginning
+      ctx.recover(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(Production::TKey),
+      ))?;
+      iter.next();
     } else {
-      return Err(ParserError::InvalidSubtag);
+      let expected = if tkey.is_some() && len != 2 {
+        Production::TValue
+      } else {
+        Production::TKey
+      };
+      ctx.recover(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(expected),
+      ))?;
+      iter.next();
     }
   }
 
   if let Some(tkey) = tkey {
     if tvalue.is_empty() {
-      return Err(ParserError::InvalidSubtag);
-    }
-    if !tfield.contains_key(&tkey) {
-      tfield.insert(tkey.clone(), vec![]);
-    }
-    let values = tfield.get_mut(&tkey).unwrap();
-    for value in tvalue {
-      values.push(value);
+      let subtag = iter.peek().copied();
+      let err = SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag.unwrap_or(Fragment::new(tkey_end, "")),
+        None,
+      );
+      ctx.recover(err.expecting(Production::TValue))?;
+    } else {
+      tfield
+        .entry(tkey)
+        .or_insert_with(Vec::new)
+        .append(&mut tvalue);
     }
   }
 
@@ -106,29 +169,74 @@ fn success_transformed_extensions() {
   let mut iter = split_str("en-US-a1-foo").peekable();
   assert_eq!(
     "t-en-US-a1-foo",
-    format!("{}", parse_transformed_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
   // no tlang
   let mut iter = split_str("a1-foo").peekable();
   assert_eq!(
     "t-a1-foo",
-    format!("{}", parse_transformed_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
   // tvalue multiple
   let mut iter = split_str("en-a1-foo-b1-bar").peekable();
   assert_eq!(
     "t-en-a1-foo-b1-bar",
-    format!("{}", parse_transformed_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
+  // 3 letters tlang
+  for (source, expected) in [
+    ("yue", "t-yue"),
+    ("fil-PH", "t-fil-PH"),
+    ("und-h0-hybrid", "t-und-h0-hybrid"),
+  ] {
+    let mut iter = split_str(source).peekable();
+    assert_eq!(
+      expected,
+      format!(
+        "{}",
+        parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+      )
+    );
+  }
+
   // tlang only
   let mut iter = split_str("en-Latn-US-macos").peekable();
   assert_eq!(
     "t-en-Latn-US-macos",
-    format!("{}", parse_transformed_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
+  );
+}
+
+#[test]
+fn success_transformed_extensions_canonicalize_syntax() {
+  let mut iter = split_str("EN-Latn-US-Macos-1996-M0-Ungegn-h0-HYBRID").peekable();
+  let mut result = parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap();
+  result.canonicalize_syntax();
+  assert_eq!(
+    "t-en-latn-us-1996-macos-h0-hybrid-m0-ungegn",
+    format!("{}", result)
   );
+
+  // `true` values are kept, since a tkey needs a tvalue
+  let mut iter = split_str("k0-TRUE-m0-true-ungegn").peekable();
+  let mut result = parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap();
+  result.canonicalize_syntax();
+  assert_eq!("t-k0-true-m0-true-ungegn", format!("{}", result));
 }
 
 #[test]
@@ -137,20 +245,59 @@ fn fail_transformed_extensions() {
   let mut iter = split_str("1a-foo").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_transformed_extensions(&mut iter).unwrap_err()
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
   );
 
   // missing tkey
-  let mut iter = split_str("foo").peekable();
+  let mut iter = split_str("en-foo").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_transformed_extensions(&mut iter).unwrap_err()
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
   );
 
   // missing tvalue
   let mut iter = split_str("a1-foo-b1").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_transformed_extensions(&mut iter).unwrap_err()
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
+  );
+
+  // missing tvalue before tkey
+  let mut iter = split_str("a1-b1-foo").peekable();
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
+  );
+}
+
+#[test]
+fn fail_transformed_extensions_strict() {
+  // duplicate tkey
+  let mut iter = split_str("h0-hybrid-m0-ungegn-h0-hybrid").peekable();
+  assert_eq!(
+    ParserError::DuplicateKey,
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(true)).unwrap_err()
+  );
+
+  // duplicate variants in tlang
+  let mut iter = split_str("de-1901-1901").peekable();
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_transformed_extensions(&mut iter, &mut ParserContext::new(true)).unwrap_err()
+  );
+}
+
+#[test]
+fn lenient_transformed_extensions() {
+  // orphan tvalue, tkey without tvalue and late tlang are dropped
+  let mut ctx = ParserContext::lenient();
+  let mut iter = split_str("en-foo-a1-b1-bar-fr-c1").peekable();
+  assert_eq!(
+    "t-en-b1-bar",
+    format!(
+      "{}",
+      parse_transformed_extensions(&mut iter, &mut ctx).unwrap()
+    )
   );
+  assert_eq!(4, ctx.diagnostics.len());
 }
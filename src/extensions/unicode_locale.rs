@@ -1,15 +1,22 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
 use crate::extensions::ExtensionKind;
+use crate::options::Casing;
+use crate::shared::{Fragment, ParserContext};
+use crate::subtags::{is_ukey, Key, Subtag, Value};
+use crate::tokenizer::TokenKind;
 
 use std::collections::BTreeMap;
 use std::fmt::{self, Debug, Write};
 use std::iter::Peekable;
 
-#[derive(Debug)]
+/// The [unicode locale extensions](https://unicode.org/reports/tr35/#unicode_locale_extensions), i.e. `u`.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence, as [`Extensions`](crate::Extensions).
+#[derive(Debug, Clone)]
 pub struct UnicodeLocaleExtensions {
-  pub attribute: Vec<String>,
-  pub ufield: BTreeMap<String, Vec<String>>,
+  pub attribute: Vec<Value>,
+  pub ufield: BTreeMap<Key, Vec<Value>>,
 }
 
 impl fmt::Display for UnicodeLocaleExtensions {
@@ -31,66 +38,117 @@ impl fmt::Display for UnicodeLocaleExtensions {
   }
 }
 
+impl UnicodeLocaleExtensions {
+  /// Normalize to the canonical syntax of UTS #35.
+  ///
+  /// The attributes are lowercased, sorted and deduplicated, the keys and the values are lowercased, and the uvalues of the single subtag `true` are removed.
+  pub fn canonicalize_syntax(&mut self) {
+    for attribute in self.attribute.iter_mut() {
+      *attribute = attribute.with_casing(Casing::Lower);
+    }
+    self.attribute.sort();
+    self.attribute.dedup();
+    canonicalize_fields(&mut self.ufield);
+    for values in self.ufield.values_mut() {
+      if values.len() == 1 && values[0] == "true" {
+        values.clear();
+      }
+    }
+  }
+}
+
 pub fn parse_unicode_locale_extensions<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<UnicodeLocaleExtensions, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  ctx: &mut ParserContext,
+) -> Result<UnicodeLocaleExtensions, SyntaxError> {
   // unicode_locale_extensions
   // https://unicode.org/reports/tr35/#unicode_locale_extensions
 
   let mut attribute = vec![];
   let mut ufield = BTreeMap::new();
-  let mut ukey: Option<String> = None;
-  let mut uvalue: Vec<String> = vec![];
+  let mut ukey: Option<Key> = None;
+  let mut uvalue: Vec<Value> = vec![];
 
-  while let Some(subtag) = iter.peek() {
+  while let Some(&subtag) = iter.peek() {
     let subtag_bytes = subtag.as_bytes();
     let len = subtag_bytes.len();
     if len == 1 {
       break;
-    } else if len == 2
-      && subtag_bytes[0].is_ascii_alphanumeric()
-      && subtag_bytes[1].is_ascii_alphabetic()
-    {
+    } else if is_ukey(subtag_bytes) {
       // for ukey
+      if ctx.strict && is_duplicate_key(&ufield, &ukey, &subtag) {
+        ctx.recover(SyntaxError::at(ParserError::DuplicateKey, subtag, None))?;
+      }
       if let Some(ukey) = ukey {
-        if !ufield.contains_key(&ukey) {
-          ufield.insert(ukey.clone(), vec![]);
-        }
-        let values = ufield.get_mut(&ukey).unwrap();
-        for value in uvalue {
-          values.push(value);
-        }
-        uvalue = vec![];
+        ufield
+          .entry(ukey)
+          .or_insert_with(Vec::new)
+          .append(&mut uvalue);
       }
-      ukey = Some(subtag.to_string());
+      ukey = Some(ctx.subtag(subtag, Casing::Lower)?);
+      ctx.token(TokenKind::UKey, subtag);
       iter.next();
     } else if (3..=8).contains(&len) && subtag_bytes.iter().all(|c| c.is_ascii_alphanumeric()) {
       if ukey.is_some() {
         // for uvalue
-        uvalue.push(subtag.to_string());
+        uvalue.push(ctx.subtag(subtag, Casing::Lower)?);
+        ctx.token(TokenKind::UValue, subtag);
       } else {
         // for attribute
-        attribute.push(subtag.to_string());
+        attribute.push(ctx.subtag(subtag, Casing::Lower)?);
+        ctx.token(TokenKind::Attribute, subtag);
       }
       iter.next();
     } else {
-      return Err(ParserError::InvalidSubtag);
+      let expected = if len == 2 {
+        Production::UKey
+      } else if ukey.is_some() {
+        Production::UValue
+      } else {
+        Production::Attribute
+      };
+      ctx.recover(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(expected),
+      ))?;
+      iter.next();
     }
   }
 
   if let Some(ukey) = ukey {
-    if !ufield.contains_key(&ukey) {
-      ufield.insert(ukey.clone(), vec![]);
-    }
-    let values = ufield.get_mut(&ukey).unwrap();
-    for value in uvalue {
-      values.push(value);
-    }
+    ufield
+      .entry(ukey)
+      .or_insert_with(Vec::new)
+      .append(&mut uvalue);
   }
 
   Ok(UnicodeLocaleExtensions { attribute, ufield })
 }
 
+pub fn is_duplicate_key(
+  fields: &BTreeMap<Key, Vec<Value>>,
+  current: &Option<Key>,
+  key: &str,
+) -> bool {
+  current
+    .iter()
+    .chain(fields.keys())
+    .any(|k| k.eq_ignore_ascii_case(key))
+}
+
+pub fn canonicalize_fields(fields: &mut BTreeMap<Key, Vec<Value>>) {
+  // the keys with different cases are merged, as the parser does for the duplicate keys
+  let mut canonical: BTreeMap<Key, Vec<Value>> = BTreeMap::new();
+  for (key, values) in std::mem::take(fields) {
+    canonical
+      .entry(key.with_casing(Casing::Lower))
+      .or_default()
+      .extend(values.into_iter().map(|v| v.with_casing(Casing::Lower)));
+  }
+  *fields = canonical;
+}
+
 /*
  * Unit tests
  */
@@ -102,58 +160,113 @@ use crate::shared::split_str;
 fn success_unicode_locale_extensions() {
   // basic case
   let mut iter = split_str("attr1-ky-value1").peekable();
-  let result = parse_unicode_locale_extensions(&mut iter).unwrap();
+  let result = parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap();
   assert_eq!("u-attr1-ky-value1", format!("{}", result));
 
   // no attribute
   let mut iter = split_str("ky-value1").peekable();
   assert_eq!(
     "u-ky-value1",
-    format!("{}", parse_unicode_locale_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
   // attribute multiple
   let mut iter = split_str("attr1-attr2-ky-value1").peekable();
   assert_eq!(
     "u-attr1-attr2-ky-value1",
-    format!("{}", parse_unicode_locale_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
   // uvalue multiple
   let mut iter = split_str("ky-value1-value2").peekable();
   assert_eq!(
     "u-ky-value1-value2",
-    format!("{}", parse_unicode_locale_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 
   // no uvalue
   let mut iter = split_str("ky").peekable();
   assert_eq!(
     "u-ky",
-    format!("{}", parse_unicode_locale_extensions(&mut iter).unwrap())
+    format!(
+      "{}",
+      parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 }
 
+#[test]
+fn success_unicode_locale_extensions_canonicalize_syntax() {
+  let mut iter = split_str("Foo-bar-foo-KB-true-Ca-Buddhist-ca-Gregory-kn").peekable();
+  let mut result =
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap();
+  result.canonicalize_syntax();
+  assert_eq!("u-bar-foo-ca-buddhist-gregory-kb-kn", format!("{}", result));
+
+  // `true` is removed only if it is the whole uvalue
+  let mut iter = split_str("xx-foo-TRUE-yy-true-foo-kn-true").peekable();
+  let mut result =
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap();
+  result.canonicalize_syntax();
+  assert_eq!("u-kn-xx-foo-true-yy-true-foo", format!("{}", result));
+}
+
 #[test]
 fn fail_unicode_locale_extensions() {
   // invalid ukey
   let mut iter = split_str("k1").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_unicode_locale_extensions(&mut iter).unwrap_err()
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
   );
 
   // invalid uvalue
   let mut iter = split_str("ky-{}").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_unicode_locale_extensions(&mut iter).unwrap_err()
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
   );
 
   // invalid attribute
   let mut iter = split_str("ky-value1-{?}").peekable();
   assert_eq!(
     ParserError::InvalidSubtag,
-    parse_unicode_locale_extensions(&mut iter).unwrap_err()
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap_err()
+  );
+}
+
+#[test]
+fn fail_unicode_locale_extensions_strict() {
+  // duplicate ukey
+  let mut iter = split_str("ca-buddhist-ca-gregory").peekable();
+  assert_eq!(
+    ParserError::DuplicateKey,
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(true)).unwrap_err()
+  );
+
+  // duplicate ukey with different case
+  let mut iter = split_str("ca-hc-h12-CA").peekable();
+  assert_eq!(
+    ParserError::DuplicateKey,
+    parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(true)).unwrap_err()
+  );
+
+  // non-strict mode merges the values
+  let mut iter = split_str("ca-buddhist-ca-gregory").peekable();
+  assert_eq!(
+    "u-ca-buddhist-gregory",
+    format!(
+      "{}",
+      parse_unicode_locale_extensions(&mut iter, &mut ParserContext::new(false)).unwrap()
+    )
   );
 }
@@ -0,0 +1,278 @@
+use crate::data::iso15924::{
+  DIRECTIONS, NAMES, NUMERIC_CODES, PROPERTY_VALUE_ALIASES, UNIONS, VARIANTS,
+};
+use crate::data::lookup;
+use crate::subtags::Script;
+
+// the private use codes `Qaaa..Qabx`, whose numeric codes are `900..949`
+const PRIVATE_USE_PREFIX: &str = "Qa";
+const PRIVATE_USE_NUMERIC: u16 = 900;
+const PRIVATE_USE_COUNT: u16 = 50;
+
+/// Enum representing the writing direction of a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptDirection {
+  LeftToRight,
+  RightToLeft,
+  /// Written vertically in columns, e.g. `Mong`.
+  TopToBottom,
+}
+
+impl Script {
+  /// Make the script subtag from the numeric code of ISO 15924, e.g. `215` to `Latn`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::Script;
+  ///
+  /// assert_eq!(Some("Latn"), Script::from_iso15924_numeric(215).as_deref());
+  /// assert_eq!(Some("Qaab"), Script::from_iso15924_numeric(901).as_deref());
+  /// assert_eq!(None, Script::from_iso15924_numeric(1000));
+  /// ```
+  pub fn from_iso15924_numeric(numeric: u16) -> Option<Script> {
+    if (PRIVATE_USE_NUMERIC..PRIVATE_USE_NUMERIC + PRIVATE_USE_COUNT).contains(&numeric) {
+      let index = (numeric - PRIVATE_USE_NUMERIC) as u8;
+      let code = [b'Q', b'a', b'a' + index / 26, b'a' + index % 26];
+      return Script::try_from_str(std::str::from_utf8(&code).ok()?).ok();
+    }
+    NUMERIC_CODES
+      .iter()
+      .find(|(_, code)| code.parse() == Ok(numeric))
+      .and_then(|(script, _)| Script::try_from_str(script).ok())
+  }
+
+  /// Returns the numeric code of ISO 15924, e.g. `215` for `Latn`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("sr-Latn").unwrap();
+  /// assert_eq!(Some(215), lang.script.unwrap().iso15924_numeric());
+  /// ```
+  pub fn iso15924_numeric(&self) -> Option<u16> {
+    let code = self.iso15924_code();
+    if let Some(index) = private_use_index(&code) {
+      return Some(PRIVATE_USE_NUMERIC + index);
+    }
+    lookup(NUMERIC_CODES, &code).and_then(|numeric| numeric.parse().ok())
+  }
+
+  /// Returns the English name of ISO 15924, e.g. `Han (Traditional variant)` for `Hant`.
+  pub fn english_name(&self) -> Option<&'static str> {
+    lookup(NAMES, &self.iso15924_code())
+  }
+
+  /// Returns the property value alias of the Unicode `Script` property, e.g. `Hiragana` for `Hira`.
+  ///
+  /// Returns `None` for the scripts not encoded as a value of the property, e.g. the unions and the variants.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::Script;
+  ///
+  /// let script = Script::try_from_str("Hira").unwrap();
+  /// assert_eq!(Some("Hiragana"), script.property_value_alias());
+  /// assert_eq!(Some("Hiragana"), script.english_name());
+  /// let script = Script::try_from_str("Jpan").unwrap();
+  /// assert_eq!(None, script.property_value_alias());
+  /// ```
+  pub fn property_value_alias(&self) -> Option<&'static str> {
+    lookup(PROPERTY_VALUE_ALIASES, &self.iso15924_code())
+  }
+
+  /// Returns the writing direction of the script.
+  ///
+  /// Returns `None` for the codes without a direction of their own, e.g. `Zyyy` and `Zinh`, and for the unknown and the private use codes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{Script, ScriptDirection};
+  ///
+  /// let script = Script::try_from_str("Arab").unwrap();
+  /// assert_eq!(Some(ScriptDirection::RightToLeft), script.direction());
+  /// let script = Script::try_from_str("Latn").unwrap();
+  /// assert_eq!(Some(ScriptDirection::LeftToRight), script.direction());
+  /// ```
+  pub fn direction(&self) -> Option<ScriptDirection> {
+    let code = self.iso15924_code();
+    match lookup(DIRECTIONS, &code) {
+      Some("rtl") => Some(ScriptDirection::RightToLeft),
+      Some("ttb") => Some(ScriptDirection::TopToBottom),
+      Some(_) => None,
+      None => lookup(NAMES, &code).map(|_| ScriptDirection::LeftToRight),
+    }
+  }
+
+  /// Returns the scripts that the union consists of, e.g. `Hani`, `Hira` and `Kana` for `Jpan`.
+  ///
+  /// Returns an empty `Vec` if the script is not a union.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::Script;
+  ///
+  /// let script = Script::try_from_str("Kore").unwrap();
+  /// assert_eq!(vec!["Hang", "Hani"], script.union_of());
+  /// ```
+  pub fn union_of(&self) -> Vec<Script> {
+    lookup(UNIONS, &self.iso15924_code())
+      .into_iter()
+      .flat_map(|scripts| scripts.split(' '))
+      .filter_map(|script| Script::try_from_str(script).ok())
+      .collect()
+  }
+
+  /// Returns the script that the script is a variant of, e.g. `Hani` for `Hans` and `Hant`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("zh-Hant-TW").unwrap();
+  /// assert_eq!(Some("Hani"), lang.script.unwrap().variant_of().as_deref());
+  /// ```
+  pub fn variant_of(&self) -> Option<Script> {
+    lookup(VARIANTS, &self.iso15924_code()).and_then(|script| Script::try_from_str(script).ok())
+  }
+
+  /// Returns the alpha-4 code in title case, e.g. `Latn` for `LATN`.
+  fn iso15924_code(&self) -> String {
+    self
+      .char_indices()
+      .map(|(index, c)| match index {
+        0 => c.to_ascii_uppercase(),
+        _ => c.to_ascii_lowercase(),
+      })
+      .collect()
+  }
+}
+
+/// The index of the private use code from `Qaaa`, e.g. `1` for `Qaab`.
+fn private_use_index(code: &str) -> Option<u16> {
+  let rest = code.strip_prefix(PRIVATE_USE_PREFIX)?.as_bytes();
+  let index = (rest[0].checked_sub(b'a')? as u16) * 26 + rest[1].checked_sub(b'a')? as u16;
+  (index < PRIVATE_USE_COUNT).then_some(index)
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn script(code: &str) -> Script {
+  Script::try_from_str(code).unwrap()
+}
+
+#[test]
+fn success_iso15924_numeric() {
+  for (code, numeric) in [
+    ("Latn", 215),
+    ("Hira", 410),
+    ("Egyp", 50),
+    ("Zzzz", 999),
+    ("Qaaa", 900),
+    ("Qaaz", 925),
+    ("Qaba", 926),
+    ("Qabx", 949),
+  ] {
+    assert_eq!(Some(numeric), script(code).iso15924_numeric(), "{}", code);
+    assert_eq!(
+      Some(script(code)),
+      Script::from_iso15924_numeric(numeric),
+      "{}",
+      code
+    );
+  }
+  // case insensitive
+  assert_eq!(Some(215), script("lATN").iso15924_numeric());
+
+  assert_eq!(None, script("Qaby").iso15924_numeric());
+  assert_eq!(None, script("Abcd").iso15924_numeric());
+  assert_eq!(None, Script::from_iso15924_numeric(950));
+  assert_eq!(None, Script::from_iso15924_numeric(0));
+}
+
+#[test]
+fn success_script_names() {
+  assert_eq!(Some("Latin"), script("Latn").english_name());
+  assert_eq!(Some("Latin"), script("Latn").property_value_alias());
+  assert_eq!(
+    Some("Han (Hanzi, Kanji, Hanja)"),
+    script("Hani").english_name()
+  );
+  assert_eq!(Some("Han"), script("Hani").property_value_alias());
+  assert_eq!(
+    Some("Katakana_Or_Hiragana"),
+    script("Hrkt").property_value_alias()
+  );
+  assert_eq!(Some("Common"), script("Zyyy").property_value_alias());
+  assert_eq!(
+    Some("Han (Simplified variant)"),
+    script("Hans").english_name()
+  );
+  assert_eq!(None, script("Hans").property_value_alias());
+  assert_eq!(None, script("Abcd").english_name());
+  assert_eq!(None, script("Qaaa").english_name());
+}
+
+#[test]
+fn success_script_direction() {
+  assert_eq!(
+    Some(ScriptDirection::LeftToRight),
+    script("Latn").direction()
+  );
+  assert_eq!(
+    Some(ScriptDirection::LeftToRight),
+    script("Jpan").direction()
+  );
+  assert_eq!(
+    Some(ScriptDirection::RightToLeft),
+    script("Hebr").direction()
+  );
+  assert_eq!(
+    Some(ScriptDirection::RightToLeft),
+    script("Aran").direction()
+  );
+  assert_eq!(
+    Some(ScriptDirection::RightToLeft),
+    script("Adlm").direction()
+  );
+  assert_eq!(
+    Some(ScriptDirection::TopToBottom),
+    script("Mong").direction()
+  );
+  assert_eq!(None, script("Zyyy").direction());
+  assert_eq!(None, script("Zinh").direction());
+  assert_eq!(None, script("Qaaa").direction());
+  assert_eq!(None, script("Abcd").direction());
+}
+
+#[test]
+fn success_script_union_and_variant() {
+  assert_eq!(
+    vec![script("Hani"), script("Hira"), script("Kana")],
+    script("Jpan").union_of()
+  );
+  assert_eq!(
+    vec![script("Bopo"), script("Hani")],
+    script("Hanb").union_of()
+  );
+  assert_eq!(
+    vec![script("Hang"), script("Hani")],
+    script("Kore").union_of()
+  );
+  assert!(script("Hani").union_of().is_empty());
+
+  assert_eq!(Some(script("Hani")), script("Hans").variant_of());
+  assert_eq!(Some(script("Hani")), script("Hant").variant_of());
+  assert_eq!(Some(script("Latn")), script("Latf").variant_of());
+  assert_eq!(None, script("Latn").variant_of());
+  assert_eq!(None, script("Jpan").variant_of());
+}
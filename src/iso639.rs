@@ -0,0 +1,283 @@
+use crate::constants::LANG_UND;
+use crate::data::iso639::{ISO639_1, ISO639_2, MACROLANGUAGES, SCOPES};
+use crate::data::lookup;
+use crate::registry::RegistryScope;
+use crate::subtags::Language;
+
+// the private use codes of ISO 639-2 and ISO 639-3
+const PRIVATE_USE_START: &str = "qaa";
+const PRIVATE_USE_END: &str = "qtz";
+
+impl Language {
+  /// Convert to the alpha-2 code of ISO 639-1, e.g. `fre`, `fra` and `fr` to `fr`.
+  ///
+  /// Returns `None` if the language has no ISO 639-1 code, e.g. `cmn`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("ger-DE").unwrap();
+  /// assert_eq!(Some("de"), lang.language.to_iso639_1().as_deref());
+  /// ```
+  pub fn to_iso639_1(&self) -> Option<Language> {
+    let code = self.iso639_code()?;
+    ISO639_1
+      .iter()
+      .find(|(_, alpha3)| *alpha3 == code)
+      .and_then(|(alpha2, _)| Language::try_from_str(alpha2).ok())
+  }
+
+  /// Convert to the bibliographic code of ISO 639-2, e.g. `fr` and `fra` to `fre`.
+  ///
+  /// The bibliographic code is the same as the terminology code except for 20 languages.
+  /// Returns `None` if the language is not in ISO 639-2, e.g. `cmn`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("zh-TW").unwrap();
+  /// assert_eq!(Some("chi"), lang.language.to_iso639_2b().as_deref());
+  /// ```
+  pub fn to_iso639_2b(&self) -> Option<Language> {
+    let code = self.iso639_code()?;
+    let code = match is_private_use(&code) {
+      true => code.as_str(),
+      false => lookup(ISO639_2, &code)?,
+    };
+    Language::try_from_str(code).ok()
+  }
+
+  /// Convert to the terminology code of ISO 639-2, e.g. `fr` and `fre` to `fra`.
+  ///
+  /// Returns `None` if the language is not in ISO 639-2, e.g. `cmn`.
+  pub fn to_iso639_2t(&self) -> Option<Language> {
+    let code = self.iso639_code()?;
+    if !is_private_use(&code) && lookup(ISO639_2, &code).is_none() {
+      return None;
+    }
+    Language::try_from_str(&code).ok()
+  }
+
+  /// Convert to the code of ISO 639-3, e.g. `fr` and `fre` to `fra`.
+  ///
+  /// The alpha-3 codes not in the embedded tables are returned as is, since ISO 639-3 covers all the individual languages.
+  /// Returns `None` for the collections of ISO 639-2 and ISO 639-5, e.g. `sla`, which are not in ISO 639-3.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("de").unwrap();
+  /// assert_eq!(Some("deu"), lang.language.to_iso639_3().as_deref());
+  /// let lang = parse_language_id("cmn").unwrap();
+  /// assert_eq!(Some("cmn"), lang.language.to_iso639_3().as_deref());
+  /// ```
+  pub fn to_iso639_3(&self) -> Option<Language> {
+    let code = self.iso639_code()?;
+    if lookup(SCOPES, &code) == Some("collection") {
+      return None;
+    }
+    Language::try_from_str(&code).ok()
+  }
+
+  /// Find the macrolanguage that the language is a member of, e.g. `cmn` and `yue` to `zh`, and `nb` to `no`.
+  ///
+  /// The macrolanguage is in the form of the language subtag, i.e. the ISO 639-1 code if any.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("yue-HK").unwrap();
+  /// assert_eq!(Some("zh"), lang.language.macrolanguage().as_deref());
+  /// let lang = parse_language_id("nob").unwrap();
+  /// assert_eq!(Some("no"), lang.language.macrolanguage().as_deref());
+  /// ```
+  pub fn macrolanguage(&self) -> Option<Language> {
+    let code = self.iso639_code()?;
+    lookup(MACROLANGUAGES, &code).and_then(preferred)
+  }
+
+  /// Returns the members of the macrolanguage, in the form of the language subtags, e.g. `nb` and `nn` for `no`.
+  ///
+  /// Returns an empty `Vec` if the language is not a macrolanguage, or the members are not in the embedded tables.
+  pub fn macrolanguage_members(&self) -> Vec<Language> {
+    let Some(code) = self.iso639_code() else {
+      return vec![];
+    };
+    MACROLANGUAGES
+      .iter()
+      .filter(|(_, macrolanguage)| *macrolanguage == code)
+      .filter_map(|(member, _)| preferred(member))
+      .collect()
+  }
+
+  /// Returns the scope of the language in ISO 639: a macrolanguage, a collection, a special code (e.g. `und` and `mul`) or a private use code.
+  ///
+  /// Returns `None` for the individual languages, as the `Scope` field of the IANA Language Subtag Registry.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{parse_language_id, RegistryScope};
+  ///
+  /// let lang = parse_language_id("zh").unwrap();
+  /// assert_eq!(Some(RegistryScope::Macrolanguage), lang.language.scope());
+  /// let lang = parse_language_id("sla").unwrap();
+  /// assert_eq!(Some(RegistryScope::Collection), lang.language.scope());
+  /// let lang = parse_language_id("cmn").unwrap();
+  /// assert_eq!(None, lang.language.scope());
+  /// ```
+  pub fn scope(&self) -> Option<RegistryScope> {
+    let code = self.iso639_code()?;
+    if is_private_use(&code) {
+      return Some(RegistryScope::PrivateUse);
+    }
+    match lookup(SCOPES, &code)? {
+      "macrolanguage" => Some(RegistryScope::Macrolanguage),
+      "collection" => Some(RegistryScope::Collection),
+      _ => Some(RegistryScope::Special),
+    }
+  }
+
+  /// Returns the alpha-3 code of ISO 639-2/T and ISO 639-3 in lowercase, from an alpha-2 code or a bibliographic code.
+  fn iso639_code(&self) -> Option<String> {
+    let code = match self.as_str() {
+      "" => LANG_UND.to_string(),
+      code => code.to_ascii_lowercase(),
+    };
+    match code.len() {
+      2 => lookup(ISO639_1, &code).map(str::to_string),
+      3 => Some(
+        ISO639_2
+          .iter()
+          .find(|(terminology, bibliographic)| {
+            terminology != bibliographic && *bibliographic == code
+          })
+          .map_or(code, |(terminology, _)| terminology.to_string()),
+      ),
+      _ => None,
+    }
+  }
+}
+
+fn is_private_use(code: &str) -> bool {
+  (PRIVATE_USE_START..=PRIVATE_USE_END).contains(&code)
+}
+
+/// The language subtag of the alpha-3 code, which is the ISO 639-1 code if any.
+fn preferred(code: &str) -> Option<Language> {
+  let code = ISO639_1
+    .iter()
+    .find(|(_, alpha3)| *alpha3 == code)
+    .map_or(code, |(alpha2, _)| alpha2);
+  Language::try_from_str(code).ok()
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn language(code: &str) -> Language {
+  Language::try_from_str(code).unwrap()
+}
+
+#[test]
+fn success_iso639_conversions() {
+  for (alpha2, terminology, bibliographic) in [
+    ("fr", "fra", "fre"),
+    ("de", "deu", "ger"),
+    ("zh", "zho", "chi"),
+    ("en", "eng", "eng"),
+  ] {
+    for code in [alpha2, terminology, bibliographic] {
+      let lang = language(code);
+      assert_eq!(Some(language(alpha2)), lang.to_iso639_1(), "{}", code);
+      assert_eq!(Some(language(terminology)), lang.to_iso639_2t(), "{}", code);
+      assert_eq!(
+        Some(language(bibliographic)),
+        lang.to_iso639_2b(),
+        "{}",
+        code
+      );
+      assert_eq!(Some(language(terminology)), lang.to_iso639_3(), "{}", code);
+    }
+  }
+
+  // ISO 639-3 only
+  let lang = language("cmn");
+  assert_eq!(None, lang.to_iso639_1());
+  assert_eq!(None, lang.to_iso639_2t());
+  assert_eq!(None, lang.to_iso639_2b());
+  assert_eq!(Some(lang), lang.to_iso639_3());
+
+  // ISO 639-2 only
+  let lang = language("ast");
+  assert_eq!(None, lang.to_iso639_1());
+  assert_eq!(Some(lang), lang.to_iso639_2b());
+  assert_eq!(Some(lang), lang.to_iso639_3());
+  let lang = language("sla");
+  assert_eq!(Some(lang), lang.to_iso639_2t());
+  assert_eq!(None, lang.to_iso639_3());
+
+  // special and private use codes
+  assert_eq!(Some(language("und")), Language::default().to_iso639_3());
+  assert_eq!(Some(language("qab")), language("qab").to_iso639_2b());
+  // not in ISO 639
+  assert_eq!(None, language("xx").to_iso639_3());
+  assert_eq!(None, language("abcde").to_iso639_3());
+  // case insensitive
+  assert_eq!(Some(language("fr")), language("FRE").to_iso639_1());
+}
+
+#[test]
+fn success_macrolanguage() {
+  assert_eq!(Some(language("zh")), language("cmn").macrolanguage());
+  assert_eq!(Some(language("zh")), language("yue").macrolanguage());
+  assert_eq!(Some(language("no")), language("nb").macrolanguage());
+  assert_eq!(Some(language("no")), language("nno").macrolanguage());
+  assert_eq!(Some(language("ms")), language("id").macrolanguage());
+  assert_eq!(None, language("zh").macrolanguage());
+  assert_eq!(None, language("en").macrolanguage());
+
+  assert_eq!(
+    vec![language("nn"), language("nb")],
+    language("nor").macrolanguage_members()
+  );
+  assert_eq!(
+    vec![
+      language("bs"),
+      language("cnr"),
+      language("hr"),
+      language("sr")
+    ],
+    language("hbs").macrolanguage_members()
+  );
+  let members = language("zh").macrolanguage_members();
+  assert!(members.contains(&language("cmn")));
+  assert!(members.contains(&language("yue")));
+  assert!(language("en").macrolanguage_members().is_empty());
+  // macrolanguages without the members in the embedded tables
+  assert!(language("zap").macrolanguage_members().is_empty());
+}
+
+#[test]
+fn success_scope() {
+  assert_eq!(Some(RegistryScope::Macrolanguage), language("zh").scope());
+  assert_eq!(Some(RegistryScope::Macrolanguage), language("chi").scope());
+  assert_eq!(Some(RegistryScope::Macrolanguage), language("zap").scope());
+  assert_eq!(Some(RegistryScope::Collection), language("sla").scope());
+  assert_eq!(Some(RegistryScope::Special), language("mul").scope());
+  assert_eq!(Some(RegistryScope::Special), Language::default().scope());
+  assert_eq!(Some(RegistryScope::PrivateUse), language("qtz").scope());
+  assert_eq!(None, language("cmn").scope());
+  assert_eq!(None, language("fr").scope());
+  assert_eq!(None, language("xx").scope());
+}
@@ -1,18 +1,29 @@
-use crate::constants::{LANG_UND, SEP};
-use crate::errors::ParserError;
-use crate::shared::split_str;
-use crate::subtags::{language_subtag, region_subtag, script_subtag, variant_subtag};
+use crate::constants::{LANG_EMPTY, LANG_ROOT, LANG_UND, SEP};
+use crate::errors::{ParserError, Production, SyntaxError};
+#[allow(unused_imports)] // for unit tests
+use crate::options::CasePolicy;
+use crate::options::{Casing, GrammarProfile, ParseOptions};
+use crate::shared::{check_subtags, split_subtags, Fragment, ParserContext};
+use crate::subtags::{
+  language_subtag, region_subtag, script_subtag, variant_subtag, Language, Region, Script, Subtag,
+  Variant,
+};
+use crate::tokenizer::TokenKind;
 
 use std::fmt::{self, Write};
 use std::iter::Peekable;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+/// An Unicode Language Identifier.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence: the identifiers that differ only in the casing and the order of the variants are equal.
+/// The ordering is the byte order of the strings in the canonical syntax.
+#[derive(Debug, Clone, Default)]
 pub struct UnicodeLanguageIdentifier {
-  pub language: String,
-  pub script: Option<String>,
-  pub region: Option<String>,
-  pub variants: Option<Vec<String>>,
+  pub language: Language,
+  pub script: Option<Script>,
+  pub region: Option<Region>,
+  pub variants: Option<Vec<Variant>>,
 }
 
 /// Parse the given string as an Unicode Language Identifier.
@@ -27,72 +38,182 @@ pub struct UnicodeLanguageIdentifier {
 /// let res = parse_language_id("en-US").unwrap();
 /// assert_eq!("en", res.language);
 /// assert_eq!(None, res.script);
-/// assert_eq!(Some("US".to_string()), res.region);
+/// assert_eq!(Some("US"), res.region.as_deref());
 /// assert_eq!(None, res.variants);
 /// ```
 ///
 /// # Errors
 ///
-/// This function returns an error in the following cases:
+/// This function returns a [`SyntaxError`] with the position of the offending subtag, whose [`SyntaxError::kind`] is:
 ///
 /// - [`ParserError::Missing`] if the given language id is empty.
 /// - [`ParserError::InvalidLanguage`] if the given language id is not a valid language identifier.
-/// - [`ParserError::InvalidSubtag`] if the given language id is not a valid subtag.
+/// - [`ParserError::InvalidSubtag`] if the given language id has a subtag that is not valid, or not in the order of the grammar, e.g. `en-Latn-Latn` and `en-US-u-ca`.
+/// - [`ParserError::EmptySubtag`], [`ParserError::LeadingSeparator`] or [`ParserError::TrailingSeparator`] if the given language id has an empty subtag.
+/// - [`ParserError::NonAsciiCharacter`] if the given language id has a non-ASCII character.
+///
+pub fn parse_unicode_language_id(lang_id: &str) -> Result<UnicodeLanguageIdentifier, SyntaxError> {
+  parse_unicode_language_id_from_str(lang_id, &mut ParserContext::new(false))
+    .map_err(|e| e.locate(lang_id))
+}
+
+/// Parse the given string as an Unicode Language Identifier, with the strict well-formedness rules.
+///
+/// In addition to [`parse_unicode_language_id`], this function rejects duplicate variants, as required by [UTS #35](https://unicode.org/reports/tr35/#Unicode_language_identifier) and [BCP 47](https://www.rfc-editor.org/rfc/rfc5646#section-2.2.5).
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_language_id_strict, ParserError};
+///
+/// assert!(parse_language_id_strict("de-DE-1901-1996").is_ok());
+/// assert_eq!(
+///   ParserError::DuplicateVariant,
+///   parse_language_id_strict("de-DE-1901-1996-1901").unwrap_err()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// This function returns an error in the same cases as [`parse_unicode_language_id`], and also:
+///
+/// - [`ParserError::DuplicateVariant`] if the given language id has the same variant more than once.
+///
+pub fn parse_unicode_language_id_strict(
+  lang_id: &str,
+) -> Result<UnicodeLanguageIdentifier, SyntaxError> {
+  parse_unicode_language_id_from_str(lang_id, &mut ParserContext::new(true))
+    .map_err(|e| e.locate(lang_id))
+}
+
+/// Parse the given string as an Unicode Language Identifier, with the given options.
+///
+/// See [`ParseOptions`] for the options.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_language_id_with, CasePolicy, ParseOptions, ParserError};
+///
+/// let options = ParseOptions {
+///   case: CasePolicy::Reject,
+///   ..Default::default()
+/// };
+/// assert!(parse_language_id_with("sr-Latn-RS", &options).is_ok());
+/// assert_eq!(
+///   ParserError::NonCanonicalCase,
+///   parse_language_id_with("sr-latn-RS", &options).unwrap_err()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// This function returns an error in the same cases as [`parse_unicode_language_id`], and also:
 ///
-pub fn parse_unicode_language_id(lang_id: &str) -> Result<UnicodeLanguageIdentifier, ParserError> {
+/// - [`ParserError::LegacySeparator`] if `_` is not allowed, and the given language id has it.
+/// - [`ParserError::NonCanonicalCase`] if the case policy is [`CasePolicy::Reject`](crate::CasePolicy::Reject), and the given language id has a subtag not in the canonical casing.
+/// - [`ParserError::TooLong`] if the given language id is longer than the maximum length.
+/// - [`ParserError::TooManySubtags`] if the given language id has more subtags than the maximum.
+/// - [`ParserError::DuplicateVariant`] if the profile is [`GrammarProfile::Bcp47`](crate::GrammarProfile::Bcp47), and the given language id has the same variant more than once.
+/// - [`ParserError::InvalidLanguage`] if the profile is [`GrammarProfile::Bcp47`](crate::GrammarProfile::Bcp47), and the given language id is `root`.
+pub fn parse_unicode_language_id_with(
+  lang_id: &str,
+  options: &ParseOptions,
+) -> Result<UnicodeLanguageIdentifier, SyntaxError> {
+  parse_unicode_language_id_from_str(lang_id, &mut ParserContext::with_options(options))
+    .map_err(|e| e.locate(lang_id))
+}
+
+fn parse_unicode_language_id_from_str(
+  lang_id: &str,
+  ctx: &mut ParserContext,
+) -> Result<UnicodeLanguageIdentifier, SyntaxError> {
   // check empty
   if lang_id.is_empty() {
-    return Err(ParserError::Missing);
+    return Err(ParserError::Missing.into());
   }
+  check_subtags(lang_id, ctx)?;
 
-  parse_unicode_language_id_from_iter(&mut split_str(lang_id).peekable())
+  let mut iter = split_subtags(lang_id).peekable();
+  let lang = parse_unicode_language_id_from_iter(&mut iter, ctx)?;
+
+  // the subtags left are not in the order of the grammar, e.g. a second script, or an extension
+  if let Some(subtag) = iter.next() {
+    let expected =
+      (lang.variants.is_some() || lang.region.is_some()).then_some(Production::Variant);
+    return Err(SyntaxError::at(
+      ParserError::InvalidSubtag,
+      subtag,
+      expected,
+    ));
+  }
+  Ok(lang)
 }
 
 pub fn parse_unicode_language_id_from_iter<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<UnicodeLanguageIdentifier, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  ctx: &mut ParserContext,
+) -> Result<UnicodeLanguageIdentifier, SyntaxError> {
   // language subtag
-  let language = if let Some(lang) = iter.next() {
-    language_subtag(lang)?
-  } else {
-    return Err(ParserError::Unexpected);
+  let language = match iter.peek() {
+    Some(&lang) => match language_subtag(lang.text) {
+      Ok(_)
+        if ctx.options.profile == GrammarProfile::Bcp47 && lang.eq_ignore_ascii_case(LANG_ROOT) =>
+      {
+        // 'root' is not a BCP 47 language subtag
+        ctx.recover(SyntaxError::at(
+          ParserError::InvalidLanguage,
+          lang,
+          Some(Production::Language),
+        ))?;
+        iter.next();
+        Fragment::new(lang.start, LANG_EMPTY)
+      }
+      Ok(language) => {
+        ctx.token(TokenKind::Language, lang);
+        iter.next();
+        // 'und' and 'root' are the empty language
+        Fragment::new(lang.start, language)
+      }
+      Err(e) => {
+        ctx.recover(SyntaxError::at(e, lang, Some(Production::Language)))?;
+        // recover as 'und', and keep the subtag if it can follow the language subtag,
+        // but never as an extension singleton, e.g. `q-US` is not `und-q-US`
+        if script_subtag(&lang).is_err()
+          && region_subtag(&lang).is_err()
+          && variant_subtag(&lang).is_err()
+        {
+          iter.next();
+        }
+        Fragment::new(lang.start, LANG_EMPTY)
+      }
+    },
+    None => return Err(ParserError::Unexpected.into()),
   };
-  let language = String::from(language);
+  let language = ctx.subtag(language, Casing::Lower)?;
 
   // other subtags
   let mut script = None;
   let mut region = None;
-  let mut variants = vec![];
+  let mut variants: Vec<Fragment> = vec![];
   let mut current = 1;
-  while let Some(subtag) = iter.peek() {
-    if current == 1 {
-      if let Ok(script_subtag) = script_subtag(subtag) {
-        script = Some(String::from(script_subtag));
-        current = 2;
-      } else if let Ok(region_subtag) = region_subtag(subtag) {
-        region = Some(String::from(region_subtag));
-        current = 3;
-      } else if let Ok(variant_subtag) = variant_subtag(subtag) {
-        variants.push(String::from(variant_subtag));
-        current = 3;
-      } else {
-        break;
-      }
-    } else if current == 2 {
-      if let Ok(region_subtag) = region_subtag(subtag) {
-        region = Some(String::from(region_subtag));
-        current = 3;
-      } else if let Ok(variant_subtag) = variant_subtag(subtag) {
-        variants.push(String::from(variant_subtag));
-        current = 3;
-      } else {
-        break;
-      }
-    } else if let Ok(variant_subtag) = variant_subtag(subtag) {
-      variants.push(String::from(variant_subtag));
+  while let Some(&subtag) = iter.peek() {
+    let kind = if current == 1 && script_subtag(&subtag).is_ok() {
+      script = Some(ctx.subtag(subtag, Casing::Title)?);
+      current = 2;
+      TokenKind::Script
+    } else if current <= 2 && region_subtag(&subtag).is_ok() {
+      region = Some(ctx.subtag(subtag, Casing::Upper)?);
+      current = 3;
+      TokenKind::Region
+    } else if variant_subtag(&subtag).is_ok() {
+      variants.push(subtag);
+      current = 3;
+      TokenKind::Variant
     } else {
       break;
-    }
+    };
+    ctx.token(kind, subtag);
     iter.next();
   }
 
@@ -100,7 +221,20 @@ pub fn parse_unicode_language_id_from_iter<'a>(
   let variants = if variants.is_empty() {
     None
   } else {
-    variants.dedup();
+    if ctx.strict {
+      if let Some(duplicate) = find_duplicate_variant(&variants) {
+        ctx.recover(SyntaxError::at(
+          ParserError::DuplicateVariant,
+          duplicate,
+          None,
+        ))?;
+      }
+    }
+    variants.dedup_by(|a, b| a.text == b.text);
+    let variants = variants
+      .into_iter()
+      .map(|variant| ctx.subtag(variant, Casing::Lower))
+      .collect::<Result<Vec<Variant>, SyntaxError>>()?;
     Some(variants)
   };
 
@@ -112,6 +246,43 @@ pub fn parse_unicode_language_id_from_iter<'a>(
   })
 }
 
+fn find_duplicate_variant<'a>(variants: &[Fragment<'a>]) -> Option<Fragment<'a>> {
+  variants.iter().enumerate().find_map(|(i, variant)| {
+    variants[..i]
+      .iter()
+      .any(|other| other.eq_ignore_ascii_case(variant))
+      .then_some(*variant)
+  })
+}
+
+impl UnicodeLanguageIdentifier {
+  /// Normalize to the [canonical syntax of UTS #35](https://unicode.org/reports/tr35/#Canonical_Unicode_Locale_Identifiers).
+  ///
+  /// The language is lowercased, the script is title-cased, the region is uppercased, and the variants are lowercased, sorted and deduplicated.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let mut lang = parse_language_id("JA-latn-jp-MACOS-1996-macos").unwrap();
+  /// lang.canonicalize_syntax();
+  /// assert_eq!("ja-Latn-JP-1996-macos", format!("{}", lang));
+  /// ```
+  pub fn canonicalize_syntax(&mut self) {
+    self.language = self.language.with_casing(Casing::Lower);
+    self.script = self.script.map(|script| script.with_casing(Casing::Title));
+    self.region = self.region.map(|region| region.with_casing(Casing::Upper));
+    if let Some(variants) = &mut self.variants {
+      for variant in variants.iter_mut() {
+        *variant = variant.with_casing(Casing::Lower);
+      }
+      variants.sort();
+      variants.dedup();
+    }
+  }
+}
+
 impl fmt::Display for UnicodeLanguageIdentifier {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     if self.language.is_empty() {
@@ -138,7 +309,7 @@ impl fmt::Display for UnicodeLanguageIdentifier {
 }
 
 impl FromStr for UnicodeLanguageIdentifier {
-  type Err = ParserError;
+  type Err = SyntaxError;
 
   fn from_str(source: &str) -> Result<Self, Self::Err> {
     parse_unicode_language_id(source)
@@ -154,22 +325,15 @@ fn success_parse_unicode_language_id() {
   // full case
   let result = parse_unicode_language_id("en-Latn-US-macos-windows-linux").unwrap();
   assert_eq!(result.language, "en");
-  assert_eq!(result.script, Some("Latn".to_string()));
-  assert_eq!(result.region, Some("US".to_string()));
-  assert_eq!(
-    result.variants,
-    Some(vec![
-      "macos".to_string(),
-      "windows".to_string(),
-      "linux".to_string()
-    ])
-  );
+  assert_eq!(result.script.as_deref(), Some("Latn"));
+  assert_eq!(result.region.as_deref(), Some("US"));
+  assert_eq!(result.variants.unwrap(), vec!["macos", "windows", "linux"]);
 
   // use sep with underscore
   let result = parse_unicode_language_id("en_Latn_US").unwrap();
   assert_eq!(result.language, "en");
-  assert_eq!(result.script, Some("Latn".to_string()));
-  assert_eq!(result.region, Some("US".to_string()));
+  assert_eq!(result.script.as_deref(), Some("Latn"));
+  assert_eq!(result.region.as_deref(), Some("US"));
 
   // language subtag only
   let result = parse_unicode_language_id("en").unwrap();
@@ -182,13 +346,13 @@ fn success_parse_unicode_language_id() {
   let result = parse_unicode_language_id("en-US").unwrap();
   assert_eq!(result.language, "en");
   assert_eq!(result.script, None);
-  assert_eq!(result.region, Some("US".to_string()));
+  assert_eq!(result.region.as_deref(), Some("US"));
   assert_eq!(result.variants, None);
 
   // language subtag and script subtag
   let result = parse_unicode_language_id("en-Latn").unwrap();
   assert_eq!(result.language, "en");
-  assert_eq!(result.script, Some("Latn".to_string()));
+  assert_eq!(result.script.as_deref(), Some("Latn"));
   assert_eq!(result.region, None);
   assert_eq!(result.variants, None);
 
@@ -197,13 +361,13 @@ fn success_parse_unicode_language_id() {
   assert_eq!(result.language, "en");
   assert_eq!(result.script, None);
   assert_eq!(result.region, None);
-  assert_eq!(result.variants, Some(vec!["macos".to_string()]));
+  assert_eq!(result.variants.unwrap(), vec!["macos"]);
 
   // language subtag, script subtag and region subtag
   let result = parse_unicode_language_id("en-Latn-US").unwrap();
   assert_eq!(result.language, "en");
-  assert_eq!(result.script, Some("Latn".to_string()));
-  assert_eq!(result.region, Some("US".to_string()));
+  assert_eq!(result.script.as_deref(), Some("Latn"));
+  assert_eq!(result.region.as_deref(), Some("US"));
   assert_eq!(result.variants, None);
 
   // language subtag: 'root'
@@ -216,9 +380,9 @@ fn success_parse_unicode_language_id() {
   // include language subtag: 'und'
   let result = parse_unicode_language_id("und-Latn-AT-macos").unwrap();
   assert_eq!(result.language, "");
-  assert_eq!(result.script, Some("Latn".to_string()));
-  assert_eq!(result.region, Some("AT".to_string()));
-  assert_eq!(result.variants, Some(vec!["macos".to_string()]));
+  assert_eq!(result.script.as_deref(), Some("Latn"));
+  assert_eq!(result.region.as_deref(), Some("AT"));
+  assert_eq!(result.variants.unwrap(), vec!["macos"]);
 
   // Display trait implementation
   assert_eq!(
@@ -242,9 +406,9 @@ fn success_parse_unicode_language_id() {
   // FromStr trait implementation
   let result: UnicodeLanguageIdentifier = "en-Latn-US-macos".parse().unwrap();
   assert_eq!("en", result.language);
-  assert_eq!(Some("Latn".to_string()), result.script);
-  assert_eq!(Some("US".to_string()), result.region);
-  assert_eq!(Some(vec!["macos".to_string()]), result.variants);
+  assert_eq!(Some("Latn"), result.script.as_deref());
+  assert_eq!(Some("US"), result.region.as_deref());
+  assert_eq!(vec!["macos"], result.variants.unwrap());
   let result: UnicodeLanguageIdentifier = "en-Latn-US".parse().unwrap();
   assert_eq!("en-Latn-US", format!("{}", result));
 }
@@ -256,4 +420,167 @@ fn fail_parse_unicode_language_id() {
     ParserError::Missing,
     parse_unicode_language_id("").unwrap_err()
   );
+
+  // subtags left after the language id
+  let err = parse_unicode_language_id("en-Latn-Latn").unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(8..12, err.span());
+  assert_eq!(None, err.expected());
+  let err = parse_unicode_language_id_strict("en-US-u-ca-x").unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(6..7, err.span());
+  assert_eq!(Some(Production::Variant), err.expected());
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    parse_unicode_language_id("en-US-US").unwrap_err()
+  );
+}
+
+#[test]
+fn success_parse_unicode_language_id_strict() {
+  let result = parse_unicode_language_id_strict("de-DE-1901-1996").unwrap();
+  assert_eq!(result.variants.unwrap(), vec!["1901", "1996"]);
+}
+
+#[test]
+fn fail_parse_unicode_language_id_strict() {
+  // missing language
+  assert_eq!(
+    ParserError::Missing,
+    parse_unicode_language_id_strict("").unwrap_err()
+  );
+
+  // adjacent duplicate variants
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_unicode_language_id_strict("de-DE-1901-1901").unwrap_err()
+  );
+
+  // duplicate variants with different case
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_unicode_language_id_strict("sl-rozaj-biske-ROZAJ").unwrap_err()
+  );
+}
+
+#[test]
+fn fail_parse_unicode_language_id_malformed() {
+  // empty subtag
+  assert_eq!(
+    ParserError::EmptySubtag,
+    parse_unicode_language_id("en--US").unwrap_err()
+  );
+
+  // leading separator
+  assert_eq!(
+    ParserError::LeadingSeparator,
+    parse_unicode_language_id("_en").unwrap_err()
+  );
+
+  // trailing separator
+  assert_eq!(
+    ParserError::TrailingSeparator,
+    parse_unicode_language_id("en-US-").unwrap_err()
+  );
+
+  // non-ASCII character
+  assert_eq!(
+    ParserError::NonAsciiCharacter,
+    parse_unicode_language_id("en-ÜS").unwrap_err()
+  );
+}
+
+#[test]
+fn fail_parse_unicode_language_id_position() {
+  // invalid language
+  let err = parse_unicode_language_id("e-US").unwrap_err();
+  assert_eq!(ParserError::InvalidLanguage, err.kind());
+  assert_eq!(0..1, err.span());
+  assert_eq!(0, err.index());
+  assert_eq!(Some(Production::Language), err.expected());
+  assert_eq!("e", err.fragment());
+
+  // duplicate variant
+  let err = parse_unicode_language_id_strict("de_DE_1901_1996_1901").unwrap_err();
+  assert_eq!(ParserError::DuplicateVariant, err.kind());
+  assert_eq!(16..20, err.span());
+  assert_eq!(4, err.index());
+  assert_eq!(None, err.expected());
+  assert_eq!("1901", err.fragment());
+
+  // empty subtag
+  let err = parse_unicode_language_id("en--US").unwrap_err();
+  assert_eq!(ParserError::EmptySubtag, err.kind());
+  assert_eq!(3..3, err.span());
+  assert_eq!(1, err.index());
+}
+
+#[test]
+fn success_canonicalize_syntax() {
+  let canonicalize = |source: &str| {
+    let mut lang = parse_unicode_language_id(source).unwrap();
+    lang.canonicalize_syntax();
+    format!("{}", lang)
+  };
+  assert_eq!("en-US", canonicalize("EN_us"));
+  assert_eq!("sr-Cyrl-RS", canonicalize("sr-CYRL-rs"));
+  assert_eq!("de-DE-1901-1996", canonicalize("de-de-1996-1901-1996"));
+  assert_eq!("und-Latn", canonicalize("UND-latn"));
+  assert_eq!("und-419", canonicalize("root-419"));
+}
+
+#[test]
+fn success_parse_unicode_language_id_with() {
+  // normalize case
+  let options = ParseOptions {
+    case: CasePolicy::Normalize,
+    ..Default::default()
+  };
+  let result = parse_unicode_language_id_with("EN-latn-us-MACOS", &options).unwrap();
+  assert_eq!("en-Latn-US-macos", format!("{}", result));
+  let result = parse_unicode_language_id_with("UND-419", &options).unwrap();
+  assert_eq!("", result.language);
+  assert_eq!(Some("419"), result.region.as_deref());
+
+  // preserve case
+  let result = parse_unicode_language_id_with("EN-latn", &ParseOptions::default()).unwrap();
+  assert_eq!("EN-latn", format!("{}", result));
+
+  // ICU profile
+  let options = ParseOptions {
+    profile: GrammarProfile::Icu,
+    ..Default::default()
+  };
+  let result = parse_unicode_language_id_with("en__POSIX", &options).unwrap();
+  assert_eq!("en-POSIX", format!("{}", result));
+}
+
+#[test]
+fn fail_parse_unicode_language_id_with() {
+  // reject non-canonical case
+  let options = ParseOptions {
+    case: CasePolicy::Reject,
+    ..Default::default()
+  };
+  let err = parse_unicode_language_id_with("en-Latn-us", &options).unwrap_err();
+  assert_eq!(ParserError::NonCanonicalCase, err.kind());
+  assert_eq!(8..10, err.span());
+
+  // BCP 47 profile
+  let options = ParseOptions {
+    profile: GrammarProfile::Bcp47,
+    ..Default::default()
+  };
+  assert_eq!(
+    ParserError::InvalidLanguage,
+    parse_unicode_language_id_with("root", &options).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::LegacySeparator,
+    parse_unicode_language_id_with("en_US", &options).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_unicode_language_id_with("de-1901-1901", &options).unwrap_err()
+  );
 }
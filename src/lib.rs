@@ -19,26 +19,80 @@
 //! println!("{:#?}", locale);
 //! # }
 //! ```
+mod borrowed;
+mod canonicalize;
 mod constants;
+#[cfg(feature = "embedded-data")]
+mod data;
+mod equivalence;
 mod extensions;
 mod shared;
 mod subtags;
 
+mod bcp47;
+mod containment;
 mod errors;
+#[cfg(feature = "embedded-data")]
+mod iso15924;
+#[cfg(feature = "embedded-data")]
+mod iso639;
 mod lang;
+mod likely_subtags;
+mod lint;
 mod locale;
 mod measure;
+mod options;
+mod parent_locales;
+mod provider;
+mod registry;
 mod subdivision;
+mod syntax;
+mod tokenizer;
+mod validity;
 
-pub use crate::errors::ParserError;
+pub use crate::bcp47::{parse_bcp47_language_tag as parse_bcp47_tag, Langtag, LanguageTag};
+pub use crate::borrowed::{
+  parse_unicode_locale_id_ref as parse_locale_id_ref, ExtensionsRef, Fields, OtherExtensionsRef,
+  PuExtensionsRef, Subtags, TransformedExtensionsRef, UnicodeLanguageIdentifierRef,
+  UnicodeLocaleExtensionsRef, UnicodeLocaleIdentifierRef,
+};
+pub use crate::errors::{ParserError, Production, SyntaxError};
 pub use crate::extensions::other::OtherExtensions;
 pub use crate::extensions::pu::PuExtensions;
 pub use crate::extensions::transformed::TransformedExtensions;
 pub use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
 pub use crate::extensions::Extensions;
-pub use crate::lang::{parse_unicode_language_id as parse_language_id, UnicodeLanguageIdentifier};
-pub use crate::locale::{parse_unicode_locale_id as parse_locale_id, UnicodeLocaleIdentifier};
+#[cfg(feature = "embedded-data")]
+pub use crate::iso15924::ScriptDirection;
+pub use crate::lang::{
+  parse_unicode_language_id as parse_language_id,
+  parse_unicode_language_id_strict as parse_language_id_strict,
+  parse_unicode_language_id_with as parse_language_id_with, UnicodeLanguageIdentifier,
+};
+pub use crate::lint::{LintCode, LintWarning};
+pub use crate::locale::{
+  parse_unicode_locale_id as parse_locale_id,
+  parse_unicode_locale_id_lenient as parse_locale_id_lenient,
+  parse_unicode_locale_id_strict as parse_locale_id_strict,
+  parse_unicode_locale_id_with as parse_locale_id_with, CanonicalDisplay, UnicodeLocaleIdentifier,
+};
 pub use crate::measure::{parse_unicode_measure_unit as parse_measure_unit, UnicodeMeasureUnit};
+pub use crate::options::{CasePolicy, GrammarProfile, ParseOptions};
+#[cfg(feature = "json-data")]
+pub use crate::provider::json::{JsonDataError, JsonDataProvider};
+#[cfg(feature = "embedded-data")]
+pub use crate::provider::EmbeddedDataProvider;
+pub use crate::provider::{DataTable, LocaleDataProvider};
+pub use crate::registry::{
+  parse_language_subtag_registry, LanguageSubtagRegistry, RegistryError, RegistryRecord,
+  RegistryRecordType, RegistryScope,
+};
 pub use crate::subdivision::{
   parse_unicode_subdivision_id as parse_subdivision_id, UnicodeSubdivisionIdentifier,
 };
+pub use crate::subtags::{Key, Language, Region, Script, Value, Variant};
+pub use crate::syntax::{parse_syntax_tree, SyntaxKind, SyntaxNode, SyntaxTree};
+pub use crate::tokenizer::{
+  is_well_formed, normalize_in_place, tokenize, Token, TokenKind, Tokenizer,
+};
+pub use crate::validity::{SubtagValidity, ValidityReport, ValidityStatus};
@@ -0,0 +1,328 @@
+use crate::constants::{LANG_UND, SEP};
+use crate::lang::{parse_unicode_language_id, UnicodeLanguageIdentifier};
+use crate::locale::UnicodeLocaleIdentifier;
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+
+const SCRIPT_UNKNOWN: &str = "Zzzz";
+const REGION_UNKNOWN: &str = "ZZ";
+
+impl UnicodeLanguageIdentifier {
+  /// Add the likely subtags with the likely subtags data of CLDR, following [the "Add Likely Subtags" algorithm of UTS #35](https://unicode.org/reports/tr35/#Likely_Subtags).
+  ///
+  /// The unknown script `Zzzz` and region `ZZ` are removed, and the missing language, script and region are filled from the first match of the language with the script and the region, the language with the region, the language with the script, the language and `und` with the script.
+  /// The variants are left untouched.
+  /// The identifier is expected to be canonicalized, e.g. with [`UnicodeLanguageIdentifier::canonicalize`].
+  ///
+  /// Returns whether the identifier is modified.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let mut lang = parse_language_id("zh-TW").unwrap();
+  /// assert!(lang.maximize());
+  /// assert_eq!("zh-Hant-TW", format!("{}", lang));
+  ///
+  /// let mut lang = parse_language_id("und-Arab").unwrap();
+  /// assert!(lang.maximize());
+  /// assert_eq!("ar-Arab-EG", format!("{}", lang));
+  ///
+  /// let mut lang = parse_language_id("en-Latn-US").unwrap();
+  /// assert!(!lang.maximize());
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn maximize(&mut self) -> bool {
+    self.maximize_with(&EmbeddedDataProvider)
+  }
+
+  /// Add the likely subtags with the likely subtags data of the given provider, as [`UnicodeLanguageIdentifier::maximize`].
+  pub fn maximize_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    let mut modified = false;
+    if self.script.is_some_and(|script| script == SCRIPT_UNKNOWN) {
+      self.script = None;
+      modified = true;
+    }
+    if self.region.is_some_and(|region| region == REGION_UNKNOWN) {
+      self.region = None;
+      modified = true;
+    }
+
+    let Some(likely) = self.likely_subtags(provider) else {
+      return modified;
+    };
+    if self.language.is_empty() && !likely.language.is_empty() {
+      self.language = likely.language;
+      modified = true;
+    }
+    if self.script.is_none() && likely.script.is_some() {
+      self.script = likely.script;
+      modified = true;
+    }
+    if self.region.is_none() && likely.region.is_some() {
+      self.region = likely.region;
+      modified = true;
+    }
+    modified
+  }
+
+  /// Remove the likely subtags, favoring the region, following [the "Remove Likely Subtags" algorithm of UTS #35](https://unicode.org/reports/tr35/#Likely_Subtags).
+  ///
+  /// The identifier is maximized, and the first one of the language, the language with the region and the language with the script that is maximized to the same is chosen.
+  /// The variants are left untouched.
+  ///
+  /// Returns whether the identifier is modified.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let mut lang = parse_language_id("en-Latn-US").unwrap();
+  /// assert!(lang.minimize());
+  /// assert_eq!("en", format!("{}", lang));
+  ///
+  /// let mut lang = parse_language_id("zh-Hant-TW").unwrap();
+  /// assert!(lang.minimize());
+  /// assert_eq!("zh-TW", format!("{}", lang));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn minimize(&mut self) -> bool {
+    self.minimize_with(&EmbeddedDataProvider)
+  }
+
+  /// Remove the likely subtags with the likely subtags data of the given provider, as [`UnicodeLanguageIdentifier::minimize`].
+  pub fn minimize_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    self.minimize_by(false, provider)
+  }
+
+  /// Remove the likely subtags, favoring the script.
+  ///
+  /// Same as [`UnicodeLanguageIdentifier::minimize`], except that the language with the script is tried before the language with the region.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let mut lang = parse_language_id("zh-Hant-TW").unwrap();
+  /// assert!(lang.minimize_favor_script());
+  /// assert_eq!("zh-Hant", format!("{}", lang));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn minimize_favor_script(&mut self) -> bool {
+    self.minimize_favor_script_with(&EmbeddedDataProvider)
+  }
+
+  /// Remove the likely subtags, favoring the script, with the likely subtags data of the given provider, as [`UnicodeLanguageIdentifier::minimize_favor_script`].
+  pub fn minimize_favor_script_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    self.minimize_by(true, provider)
+  }
+
+  fn minimize_by(&mut self, favor_script: bool, provider: &dyn LocaleDataProvider) -> bool {
+    let mut max = UnicodeLanguageIdentifier {
+      language: self.language,
+      script: self.script,
+      region: self.region,
+      variants: None,
+    };
+    max.maximize_with(provider);
+
+    let with_script = (max.script, None);
+    let with_region = (None, max.region);
+    let trials = if favor_script {
+      [(None, None), with_script, with_region]
+    } else {
+      [(None, None), with_region, with_script]
+    };
+    let (script, region) = trials
+      .into_iter()
+      .find(|&(script, region)| {
+        let mut trial = UnicodeLanguageIdentifier {
+          language: max.language,
+          script,
+          region,
+          variants: None,
+        };
+        trial.maximize_with(provider);
+        trial == max
+      })
+      .unwrap_or((max.script, max.region));
+
+    let modified = self.language != max.language || self.script != script || self.region != region;
+    self.language = max.language;
+    self.script = script;
+    self.region = region;
+    modified
+  }
+
+  /// Find the likely subtags of the language, the script and the region.
+  pub(crate) fn likely_subtags(
+    &self,
+    provider: &dyn LocaleDataProvider,
+  ) -> Option<UnicodeLanguageIdentifier> {
+    let language = if self.language.is_empty() {
+      LANG_UND
+    } else {
+      &self.language
+    };
+
+    let mut sources = vec![];
+    if let (Some(script), Some(region)) = (self.script, self.region) {
+      sources.push(format!("{}{}{}{}{}", language, SEP, script, SEP, region));
+    }
+    if let Some(region) = self.region {
+      sources.push(format!("{}{}{}", language, SEP, region));
+    }
+    if let Some(script) = self.script {
+      sources.push(format!("{}{}{}", language, SEP, script));
+    }
+    sources.push(language.to_string());
+    if let Some(script) = self.script {
+      sources.push(format!("{}{}{}", LANG_UND, SEP, script));
+    }
+
+    let likely = sources
+      .iter()
+      .find_map(|source| provider.lookup(DataTable::LikelySubtags, source))?;
+    parse_unicode_language_id(likely).ok()
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  /// Add the likely subtags to the language, as [`UnicodeLanguageIdentifier::maximize`].
+  ///
+  /// The extensions are left untouched. Returns whether the identifier is modified.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_locale_id;
+  ///
+  /// let mut locale = parse_locale_id("sr-ME-u-ca-gregory").unwrap();
+  /// locale.maximize();
+  /// assert_eq!("sr-Latn-ME-u-ca-gregory", format!("{}", locale));
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn maximize(&mut self) -> bool {
+    self.language.maximize()
+  }
+
+  /// Add the likely subtags to the language, as [`UnicodeLanguageIdentifier::maximize_with`].
+  pub fn maximize_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    self.language.maximize_with(provider)
+  }
+
+  /// Remove the likely subtags from the language, as [`UnicodeLanguageIdentifier::minimize`].
+  ///
+  /// The extensions are left untouched. Returns whether the identifier is modified.
+  #[cfg(feature = "embedded-data")]
+  pub fn minimize(&mut self) -> bool {
+    self.language.minimize()
+  }
+
+  /// Remove the likely subtags from the language, as [`UnicodeLanguageIdentifier::minimize_with`].
+  pub fn minimize_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    self.language.minimize_with(provider)
+  }
+
+  /// Remove the likely subtags from the language, as [`UnicodeLanguageIdentifier::minimize_favor_script`].
+  ///
+  /// The extensions are left untouched. Returns whether the identifier is modified.
+  #[cfg(feature = "embedded-data")]
+  pub fn minimize_favor_script(&mut self) -> bool {
+    self.language.minimize_favor_script()
+  }
+
+  /// Remove the likely subtags from the language, as [`UnicodeLanguageIdentifier::minimize_favor_script_with`].
+  pub fn minimize_favor_script_with(&mut self, provider: &dyn LocaleDataProvider) -> bool {
+    self.language.minimize_favor_script_with(provider)
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[cfg(feature = "embedded-data")]
+#[allow(dead_code)] // for unit tests
+fn maximize(source: &str) -> String {
+  let mut lang = parse_unicode_language_id(source).unwrap();
+  lang.maximize();
+  format!("{}", lang)
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_maximize() {
+  // language
+  assert_eq!("en-Latn-US", maximize("en"));
+  assert_eq!("ja-Jpan-JP", maximize("ja"));
+
+  // language with region or script
+  assert_eq!("zh-Hant-TW", maximize("zh-TW"));
+  assert_eq!("sr-Latn-ME", maximize("sr-ME"));
+  assert_eq!("sr-Latn-RS", maximize("sr-Latn"));
+  assert_eq!("en-Latn-GB", maximize("en-GB"));
+  assert_eq!("zh-Hant-CN", maximize("zh-Hant-CN"));
+  assert_eq!("haw-Latn-US", maximize("haw"));
+  assert_eq!("ast-Latn-ES", maximize("ast"));
+  assert_eq!("chr-Cher-US", maximize("chr"));
+
+  // und
+  assert_eq!("en-Latn-US", maximize("und"));
+  assert_eq!("ar-Arab-EG", maximize("und-Arab"));
+  assert_eq!("zh-Hant-TW", maximize("und-TW"));
+  assert_eq!("ru-Cyrl-KZ", maximize("und-KZ"));
+  assert_eq!("bg-Cyrl-RO", maximize("und-Cyrl-RO"));
+
+  // unknown script and region
+  assert_eq!("en-Latn-US", maximize("en-Zzzz-ZZ"));
+
+  // variants are left
+  assert_eq!("de-Latn-DE-1996", maximize("de-1996"));
+
+  // no match
+  let mut lang = parse_unicode_language_id("xx-Abcd").unwrap();
+  assert!(!lang.maximize());
+  assert_eq!("xx-Abcd", format!("{}", lang));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_minimize() {
+  let minimize = |source: &str, favor_script: bool| {
+    let mut lang = parse_unicode_language_id(source).unwrap();
+    if favor_script {
+      lang.minimize_favor_script();
+    } else {
+      lang.minimize();
+    }
+    format!("{}", lang)
+  };
+
+  // favor region
+  assert_eq!("en", minimize("en-Latn-US", false));
+  assert_eq!("en", minimize("en-US", false));
+  assert_eq!("en-GB", minimize("en-Latn-GB", false));
+  assert_eq!("zh-TW", minimize("zh-Hant-TW", false));
+  assert_eq!("zh-TW", minimize("zh-Hant", false));
+  assert_eq!("sr-ME", minimize("sr-Latn-ME", false));
+  assert_eq!("ar", minimize("und-Arab", false));
+
+  // favor script
+  assert_eq!("en", minimize("en-Latn-US", true));
+  assert_eq!("zh-Hant", minimize("zh-Hant-TW", true));
+  assert_eq!("zh-Hant", minimize("zh-TW", true));
+  assert_eq!("sr-ME", minimize("sr-Latn-ME", true));
+
+  // variants are left
+  assert_eq!("de-1996", minimize("de-Latn-DE-1996", false));
+
+  // no match
+  assert_eq!("xx-Abcd", minimize("xx-Abcd", false));
+  let mut lang = parse_unicode_language_id("en").unwrap();
+  assert!(!lang.minimize());
+}
@@ -0,0 +1,553 @@
+use crate::constants::SEP;
+use crate::errors::Production;
+use crate::extensions::unicode_locale::UnicodeLocaleExtensions;
+use crate::lang::UnicodeLanguageIdentifier;
+use crate::locale::UnicodeLocaleIdentifier;
+use crate::options::Casing;
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+use crate::subtags::{Key, Region, Script, Subtag, Value};
+use crate::validity::{
+  region_status, script_status, validate_ukey, ValidityReport, ValidityStatus,
+};
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+const KEY_REGION_OVERRIDE: &str = "rg";
+const KEY_SUBDIVISION: &str = "sd";
+const SUBDIVISION_UNKNOWN: &str = "zzzz";
+
+/// Enum representing the category of a [`LintWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintCode {
+  /// A deprecated code, e.g. `iw`, `BU` or `ca-islamicc`.
+  DeprecatedCode,
+  /// A code not in the validity data of CLDR, e.g. `xx` or `Abcd`.
+  InvalidCode,
+  /// An unknown ukey or tkey, e.g. `zz` in `en-u-zz-abc`.
+  UnknownKey,
+  /// A script that is the likely script of the language, e.g. `Latn` in `en-Latn`.
+  RedundantScript,
+  /// A `sd` subdivision not in the region, e.g. `en-US-u-sd-gbsct`.
+  SubdivisionMismatch,
+  /// A `rg` region override equal to the region, e.g. `en-US-u-rg-uszzzz`.
+  RedundantRegionOverride,
+  /// An attribute of unicode locale extensions, which is deprecated.
+  DeprecatedAttribute,
+  /// A `tlang` of transformed extensions equal to the language, e.g. `en-t-en`.
+  RedundantTransformedLanguage,
+  /// A private use subtag that has a meaning in the other parts: a keyword of a ukey with the types, e.g. `x-ca-buddhist`,
+  /// or a script or a region missing in the language identifier, e.g. `en-x-US`.
+  ///
+  /// The languages are not detected, since any private use subtag of 2 or 3 letters looks like one.
+  PrivateUseData,
+}
+
+impl LintCode {
+  /// Returns the stable identifier of the code, e.g. `deprecated-code`, to configure or filter the warnings.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      LintCode::DeprecatedCode => "deprecated-code",
+      LintCode::InvalidCode => "invalid-code",
+      LintCode::UnknownKey => "unknown-key",
+      LintCode::RedundantScript => "redundant-script",
+      LintCode::SubdivisionMismatch => "subdivision-mismatch",
+      LintCode::RedundantRegionOverride => "redundant-region-override",
+      LintCode::DeprecatedAttribute => "deprecated-attribute",
+      LintCode::RedundantTransformedLanguage => "redundant-tlang",
+      LintCode::PrivateUseData => "private-use-data",
+    }
+  }
+
+  fn message(&self) -> &'static str {
+    match self {
+      LintCode::DeprecatedCode => "Deprecated code",
+      LintCode::InvalidCode => "Invalid code",
+      LintCode::UnknownKey => "Unknown key",
+      LintCode::RedundantScript => "Redundant script",
+      LintCode::SubdivisionMismatch => "Subdivision not in the region",
+      LintCode::RedundantRegionOverride => "Region override equal to the region",
+      LintCode::DeprecatedAttribute => "Deprecated attribute",
+      LintCode::RedundantTransformedLanguage => "Transformed language equal to the language",
+      LintCode::PrivateUseData => "Private use hiding data",
+    }
+  }
+}
+
+impl Display for LintCode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// A warning for a suspicious but well-formed subtag, made by [`UnicodeLocaleIdentifier::lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+  pub code: LintCode,
+  /// The kind of the subtag
+  pub production: Production,
+  /// The subtag. The values of the keys are joined with their key, e.g. `sd-gbsct`.
+  pub subtag: String,
+  /// The locale with the warning fixed, if it can be fixed.
+  pub suggestion: Option<String>,
+}
+
+impl Display for LintWarning {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}: {} `{}` [{}]",
+      self.production,
+      self.code.message(),
+      self.subtag,
+      self.code
+    )?;
+    if let Some(suggestion) = &self.suggestion {
+      write!(f, ", suggested `{}`", suggestion)?;
+    }
+    Ok(())
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  /// Lint the suspicious but well-formed subtags, and return the warnings with the suggested fixes.
+  ///
+  /// The deprecated, invalid and unknown codes are found with [`UnicodeLocaleIdentifier::validate`],
+  /// and the redundant script with the likely subtags data.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{parse_locale_id, LintCode};
+  ///
+  /// let warnings = parse_locale_id("iw-Hebr-IL-u-rg-ilzzzz").unwrap().lint();
+  /// assert_eq!(LintCode::DeprecatedCode, warnings[0].code);
+  /// assert_eq!("iw", warnings[0].subtag);
+  /// assert_eq!(Some("he-Hebr-IL-u-rg-ilzzzz".to_string()), warnings[0].suggestion);
+  /// assert_eq!(LintCode::RedundantScript, warnings[1].code);
+  /// assert_eq!(Some("iw-IL-u-rg-ilzzzz".to_string()), warnings[1].suggestion);
+  /// assert_eq!(LintCode::RedundantRegionOverride, warnings[2].code);
+  /// assert_eq!(Some("iw-Hebr-IL".to_string()), warnings[2].suggestion);
+  /// assert_eq!(
+  ///   "uvalue: Region override equal to the region `rg-ilzzzz` [redundant-region-override], suggested `iw-Hebr-IL`",
+  ///   warnings[2].to_string()
+  /// );
+  ///
+  /// assert!(parse_locale_id("en-US-u-ca-buddhist").unwrap().lint().is_empty());
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn lint(&self) -> Vec<LintWarning> {
+    self.lint_with(&EmbeddedDataProvider)
+  }
+
+  /// Lint with the data of the given provider, as [`UnicodeLocaleIdentifier::lint`].
+  pub fn lint_with(&self, provider: &dyn LocaleDataProvider) -> Vec<LintWarning> {
+    let mut warnings = vec![];
+    self.lint_validity(&mut warnings, provider);
+    self.lint_script(&mut warnings, provider);
+    for u in self.extensions.unicode_locale.iter().flatten() {
+      self.lint_unicode_locale(u, &mut warnings);
+    }
+    self.lint_transformed(&mut warnings);
+    self.lint_pu(&mut warnings, provider);
+    warnings
+  }
+
+  fn lint_validity(&self, warnings: &mut Vec<LintWarning>, provider: &dyn LocaleDataProvider) {
+    for subtag in self.validate_with(provider).subtags {
+      let (code, suggestion) = match (subtag.status, subtag.production) {
+        (ValidityStatus::Deprecated, _) => {
+          let mut locale = self.clone();
+          locale.canonicalize_with(provider);
+          (LintCode::DeprecatedCode, Some(locale))
+        }
+        (ValidityStatus::Invalid, Production::UKey | Production::TKey) => {
+          let mut locale = self.clone();
+          for u in locale.extensions.unicode_locale.iter_mut().flatten() {
+            remove_key(&mut u.ufield, &subtag.subtag);
+          }
+          for t in locale.extensions.transformed.iter_mut().flatten() {
+            remove_key(&mut t.tfield, &subtag.subtag);
+          }
+          locale.remove_empty_extensions();
+          (LintCode::UnknownKey, Some(locale))
+        }
+        (ValidityStatus::Invalid, _) => (LintCode::InvalidCode, None),
+        _ => continue,
+      };
+      warnings.push(LintWarning {
+        code,
+        production: subtag.production,
+        subtag: subtag.subtag,
+        suggestion: suggestion.map(|locale| locale.to_string()),
+      });
+    }
+  }
+
+  fn lint_script(&self, warnings: &mut Vec<LintWarning>, provider: &dyn LocaleDataProvider) {
+    let lang = &self.language;
+    let Some(script) = lang.script.filter(|_| !lang.language.is_empty()) else {
+      return;
+    };
+    let mut likely = UnicodeLanguageIdentifier {
+      language: lang.language,
+      script: None,
+      region: lang.region,
+      variants: None,
+    };
+    likely.maximize_with(provider);
+    if likely
+      .script
+      .is_some_and(|likely| likely.eq_ignore_ascii_case(&script))
+    {
+      let mut locale = self.clone();
+      locale.language.script = None;
+      warnings.push(LintWarning {
+        code: LintCode::RedundantScript,
+        production: Production::Script,
+        subtag: script.to_string(),
+        suggestion: Some(locale.to_string()),
+      });
+    }
+  }
+
+  fn lint_unicode_locale(&self, u: &UnicodeLocaleExtensions, warnings: &mut Vec<LintWarning>) {
+    for attribute in &u.attribute {
+      let mut locale = self.clone();
+      for u in locale.extensions.unicode_locale.iter_mut().flatten() {
+        u.attribute.clear();
+      }
+      locale.remove_empty_extensions();
+      warnings.push(LintWarning {
+        code: LintCode::DeprecatedAttribute,
+        production: Production::Attribute,
+        subtag: attribute.to_string(),
+        suggestion: Some(locale.to_string()),
+      });
+    }
+
+    let Some(region) = self.language.region else {
+      return;
+    };
+    let region = region.with_casing(Casing::Lower);
+    for (key, values) in &u.ufield {
+      let [value] = values.as_slice() else {
+        continue;
+      };
+      let value = value.with_casing(Casing::Lower);
+      let code = if key.eq_ignore_ascii_case(KEY_SUBDIVISION) && !value.starts_with(&*region) {
+        LintCode::SubdivisionMismatch
+      } else if key.eq_ignore_ascii_case(KEY_REGION_OVERRIDE)
+        && value.strip_suffix(SUBDIVISION_UNKNOWN) == Some(&region)
+      {
+        LintCode::RedundantRegionOverride
+      } else {
+        continue;
+      };
+      let mut locale = self.clone();
+      for u in locale.extensions.unicode_locale.iter_mut().flatten() {
+        remove_key(&mut u.ufield, key);
+      }
+      locale.remove_empty_extensions();
+      warnings.push(LintWarning {
+        code,
+        production: Production::UValue,
+        subtag: format!("{}{}{}", key.with_casing(Casing::Lower), SEP, value),
+        suggestion: Some(locale.to_string()),
+      });
+    }
+  }
+
+  fn lint_transformed(&self, warnings: &mut Vec<LintWarning>) {
+    for (index, t) in self.extensions.transformed.iter().flatten().enumerate() {
+      let Some(tlang) = t.tlang.as_ref().filter(|tlang| **tlang == self.language) else {
+        continue;
+      };
+      let mut locale = self.clone();
+      if let Some(transformed) = &mut locale.extensions.transformed {
+        transformed[index].tlang = None;
+      }
+      locale.remove_empty_extensions();
+      warnings.push(LintWarning {
+        code: LintCode::RedundantTransformedLanguage,
+        production: Production::Language,
+        subtag: tlang.to_string(),
+        suggestion: Some(locale.to_string()),
+      });
+    }
+  }
+
+  fn lint_pu(&self, warnings: &mut Vec<LintWarning>, provider: &dyn LocaleDataProvider) {
+    let Some(pu) = &self.extensions.pu else {
+      return;
+    };
+    let mut index = 0;
+    while index < pu.values.len() {
+      let value = pu.values[index];
+      if let Some((key, keyword)) = pu_keyword(provider, &pu.values[index..]) {
+        // move the keyword into the unicode locale extensions
+        let mut locale = self.clone();
+        locale.remove_pu(index, 2);
+        let u = locale
+          .extensions
+          .unicode_locale
+          .get_or_insert_with(Vec::new);
+        if u.is_empty() {
+          u.push(UnicodeLocaleExtensions {
+            attribute: vec![],
+            ufield: BTreeMap::new(),
+          });
+        }
+        u[0].ufield.insert(key, vec![keyword]);
+        warnings.push(LintWarning {
+          code: LintCode::PrivateUseData,
+          production: Production::PuValue,
+          subtag: format!("{}{}{}", value, SEP, keyword),
+          suggestion: Some(locale.to_string()),
+        });
+        index += 2;
+        continue;
+      }
+      // move the script or the region into the language
+      let script = pu_script(provider, &value).filter(|_| self.language.script.is_none());
+      // a key followed by a value reads as a keyword, e.g. `ca` of `x-ca-abcde`, not as a region
+      let keyword = index + 1 < pu.values.len()
+        && provider
+          .lookup(DataTable::UnicodeKeys, &value.with_casing(Casing::Lower))
+          .is_some();
+      let region =
+        pu_region(provider, &value).filter(|_| !keyword && self.language.region.is_none());
+      if script.is_some() || region.is_some() {
+        let mut locale = self.clone();
+        locale.remove_pu(index, 1);
+        locale.language.script = locale.language.script.or(script);
+        locale.language.region = locale.language.region.or(region);
+        warnings.push(LintWarning {
+          code: LintCode::PrivateUseData,
+          production: Production::PuValue,
+          subtag: value.to_string(),
+          suggestion: Some(locale.to_string()),
+        });
+      }
+      index += 1;
+    }
+  }
+
+  fn remove_empty_extensions(&mut self) {
+    let extensions = &mut self.extensions;
+    if let Some(unicode_locale) = &mut extensions.unicode_locale {
+      unicode_locale.retain(|u| !u.attribute.is_empty() || !u.ufield.is_empty());
+      if unicode_locale.is_empty() {
+        extensions.unicode_locale = None;
+      }
+    }
+    if let Some(transformed) = &mut extensions.transformed {
+      transformed.retain(|t| t.tlang.is_some() || !t.tfield.is_empty());
+      if transformed.is_empty() {
+        extensions.transformed = None;
+      }
+    }
+  }
+
+  fn remove_pu(&mut self, index: usize, len: usize) {
+    if let Some(pu) = &mut self.extensions.pu {
+      pu.values.drain(index..index + len);
+      if pu.values.is_empty() {
+        self.extensions.pu = None;
+      }
+    }
+  }
+}
+
+fn remove_key(fields: &mut BTreeMap<Key, Vec<Value>>, key: &str) {
+  fields.retain(|k, _| !k.eq_ignore_ascii_case(key));
+}
+
+/// The keyword of unicode locale extensions at the start of the private use values, e.g. `ca-buddhist`.
+fn pu_keyword(provider: &dyn LocaleDataProvider, values: &[Value]) -> Option<(Key, Value)> {
+  let [key, value, ..] = values else {
+    return None;
+  };
+  let key = Key::try_from_str(key).ok()?.with_casing(Casing::Lower);
+  // only the keys with the types, since any region or currency code looks like a value
+  if provider.lookup(DataTable::UnicodeKeys, &key) != Some("type") {
+    return None;
+  }
+  let mut report = ValidityReport::default();
+  validate_ukey(&mut report, provider, &key, &[*value]);
+  report.is_valid().then_some((key, *value))
+}
+
+/// The script in the private use values, e.g. `Cyrl`.
+fn pu_script(provider: &dyn LocaleDataProvider, value: &str) -> Option<Script> {
+  let script = Script::try_from_str(value).ok()?.with_casing(Casing::Title);
+  (script_status(provider, &script) == ValidityStatus::Regular).then_some(script)
+}
+
+/// The region in the private use values, e.g. `US`.
+fn pu_region(provider: &dyn LocaleDataProvider, value: &str) -> Option<Region> {
+  let region = Region::try_from_str(value).ok()?.with_casing(Casing::Upper);
+  (region_status(provider, &region) == ValidityStatus::Regular).then_some(region)
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(unused_imports)] // for unit tests
+use crate::locale::parse_unicode_locale_id;
+
+#[cfg(feature = "embedded-data")]
+#[allow(dead_code)] // for unit tests
+fn lint(source: &str) -> Vec<(LintCode, String, Option<String>)> {
+  parse_unicode_locale_id(source)
+    .unwrap()
+    .lint()
+    .into_iter()
+    .map(|warning| (warning.code, warning.subtag, warning.suggestion))
+    .collect()
+}
+
+#[allow(dead_code)] // for unit tests
+fn warning(
+  code: LintCode,
+  subtag: &str,
+  suggestion: Option<&str>,
+) -> (LintCode, String, Option<String>) {
+  (code, subtag.to_string(), suggestion.map(str::to_string))
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_lint() {
+  use LintCode::*;
+
+  // no warnings
+  for source in [
+    "en",
+    "en-US",
+    "sr-Latn",
+    "zh-Hans-TW",
+    "de-DE-u-co-phonebk-sd-deby",
+    "en-US-u-rg-gbzzzz",
+    "ja-t-en-m0-ungegn",
+    "en-x-private",
+  ] {
+    assert_eq!(
+      Vec::<(LintCode, String, Option<String>)>::new(),
+      lint(source),
+      "{}",
+      source
+    );
+  }
+
+  // deprecated and invalid codes
+  assert_eq!(
+    vec![
+      warning(DeprecatedCode, "iw", Some("he-MM")),
+      warning(DeprecatedCode, "BU", Some("he-MM")),
+    ],
+    lint("iw-BU")
+  );
+  assert_eq!(vec![warning(InvalidCode, "xx", None)], lint("xx"));
+  assert_eq!(
+    vec![warning(
+      DeprecatedCode,
+      "ca-islamicc",
+      Some("en-u-ca-islamic-civil")
+    )],
+    lint("en-u-ca-islamicc")
+  );
+
+  // unknown keys
+  assert_eq!(
+    vec![warning(UnknownKey, "zz", Some("en-u-ca-gregory"))],
+    lint("en-u-ca-gregory-zz-abc")
+  );
+  assert!(lint("en-u-kr-space-digit").is_empty());
+  assert_eq!(
+    vec![warning(DeprecatedCode, "vt", Some("en-u-vt-0020"))],
+    lint("en-u-vt-0020")
+  );
+
+  // redundant script
+  assert_eq!(
+    vec![warning(RedundantScript, "Latn", Some("en"))],
+    lint("en-Latn")
+  );
+  assert_eq!(
+    vec![warning(RedundantScript, "Hant", Some("zh-TW"))],
+    lint("zh-Hant-TW")
+  );
+
+  // subdivisions and region overrides
+  assert_eq!(
+    vec![warning(SubdivisionMismatch, "sd-gbsct", Some("en-US"))],
+    lint("en-US-u-sd-gbsct")
+  );
+  assert_eq!(
+    vec![warning(
+      RedundantRegionOverride,
+      "rg-uszzzz",
+      Some("en-US-u-ca-gregory")
+    )],
+    lint("en-US-u-ca-gregory-rg-uszzzz")
+  );
+
+  // attributes
+  assert_eq!(
+    vec![warning(DeprecatedAttribute, "foo", Some("en-u-ca-gregory"))],
+    lint("en-u-foo-ca-gregory")
+  );
+
+  // transformed language
+  assert_eq!(
+    vec![warning(RedundantTransformedLanguage, "ja", Some("ja"))],
+    lint("ja-t-ja")
+  );
+  assert_eq!(
+    vec![warning(
+      RedundantTransformedLanguage,
+      "ja",
+      Some("ja-t-m0-ungegn")
+    )],
+    lint("ja-t-ja-m0-ungegn")
+  );
+
+  // private use
+  assert_eq!(
+    vec![warning(
+      PrivateUseData,
+      "ca-buddhist",
+      Some("th-u-ca-buddhist-x-foo")
+    )],
+    lint("th-x-ca-buddhist-foo")
+  );
+  assert_eq!(
+    vec![warning(PrivateUseData, "latn", Some("sr-Latn"))],
+    lint("sr-x-latn")
+  );
+  assert_eq!(
+    vec![warning(PrivateUseData, "US", Some("en-US"))],
+    lint("en-x-US")
+  );
+  assert_eq!(
+    vec![warning(PrivateUseData, "kr", Some("ko-KR"))],
+    lint("ko-x-kr")
+  );
+  assert_eq!(
+    vec![warning(PrivateUseData, "cyrl", Some("sr-Cyrl-RS-x-foo"))],
+    lint("sr-RS-x-cyrl-foo")
+  );
+
+  // not the private use data
+  assert!(lint("en-US-x-lang-fr").is_empty());
+  assert!(lint("en-US-x-GB").is_empty());
+  assert!(lint("sr-Latn-x-cyrl").is_empty());
+  assert!(lint("en-x-zz-foo").is_empty());
+  assert!(lint("th-x-ca-abcde").is_empty());
+  assert!(lint("en-x-kr-foo").is_empty());
+  assert!(lint("en-x-cu-jpy").is_empty());
+}
@@ -1,14 +1,23 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::extensions::icu::parse_icu_keywords;
 use crate::extensions::{parse_extensions_from_iter, Extensions};
 use crate::lang::{parse_unicode_language_id_from_iter, UnicodeLanguageIdentifier};
-use crate::shared::split_str;
+use crate::options::{Casing, GrammarProfile, ParseOptions};
+use crate::shared::{check_subtags, split_subtags, Fragment, ParserContext};
+use crate::subtags::{region_subtag, script_subtag, variant_subtag, Variant};
+use crate::tokenizer::TokenKind;
 
-use std::fmt::{self};
+use std::fmt::{self, Write};
+use std::iter::Peekable;
 use std::str;
 use std::str::FromStr;
 
-#[derive(Debug)]
+/// An Unicode Locale Identifier.
+///
+/// The equality, the hash and the ordering follow the canonical equivalence: the identifiers that are displayed as the same string with [`UnicodeLocaleIdentifier::canonical_display`] are equal,
+/// e.g. `en-US-u-ca-gregory` and `EN_us_U_CA_Gregory`. The ordering is the byte order of those strings.
+#[derive(Debug, Clone, Default)]
 pub struct UnicodeLocaleIdentifier {
   pub language: UnicodeLanguageIdentifier,
   pub extensions: Extensions,
@@ -27,8 +36,56 @@ impl fmt::Display for UnicodeLocaleIdentifier {
   }
 }
 
+impl UnicodeLocaleIdentifier {
+  /// Normalize to the [canonical syntax of UTS #35](https://unicode.org/reports/tr35/#Canonical_Unicode_Locale_Identifiers).
+  ///
+  /// See [`UnicodeLanguageIdentifier::canonicalize_syntax`] and [`Extensions::canonicalize_syntax`].
+  /// The order of the singletons is not held in the fields, so use [`UnicodeLocaleIdentifier::canonical_display`] to serialize in the canonical syntax.
+  pub fn canonicalize_syntax(&mut self) {
+    self.language.canonicalize_syntax();
+    self.extensions.canonicalize_syntax();
+  }
+
+  /// Returns a wrapper to display in the canonical syntax, without changing the locale.
+  ///
+  /// In addition to [`UnicodeLocaleIdentifier::canonicalize_syntax`], the extensions are written with the singletons in alphabetical order, and the private use extensions are the last.
+  /// The locales that are identical in the canonical syntax are displayed as the same string.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_locale_id;
+  ///
+  /// let locale = parse_locale_id("EN-latn-us-u-KB-true-CA-gregory-a-foo-x-Private").unwrap();
+  /// assert_eq!(
+  ///   "en-Latn-US-a-foo-u-ca-gregory-kb-x-private",
+  ///   format!("{}", locale.canonical_display())
+  /// );
+  /// ```
+  pub fn canonical_display(&self) -> CanonicalDisplay<'_> {
+    CanonicalDisplay(self)
+  }
+}
+
+/// A wrapper to display an [`UnicodeLocaleIdentifier`] in the canonical syntax, made by [`UnicodeLocaleIdentifier::canonical_display`].
+#[derive(Debug, Clone, Copy)]
+pub struct CanonicalDisplay<'a>(&'a UnicodeLocaleIdentifier);
+
+impl fmt::Display for CanonicalDisplay<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut locale = self.0.clone();
+    locale.canonicalize_syntax();
+    locale.language.fmt(f)?;
+    if !locale.extensions.is_empty() {
+      f.write_char(SEP)?;
+      locale.extensions.write_canonical(f)?;
+    }
+    Ok(())
+  }
+}
+
 impl FromStr for UnicodeLocaleIdentifier {
-  type Err = ParserError;
+  type Err = SyntaxError;
 
   fn from_str(source: &str) -> Result<Self, Self::Err> {
     parse_unicode_locale_id(source)
@@ -47,32 +104,193 @@ impl FromStr for UnicodeLocaleIdentifier {
 /// let locale = parse_locale_id("en-US-u-hc-h12").unwrap();
 /// assert_eq!("en", locale.language.language);
 /// assert_eq!(None, locale.language.script);
-/// assert_eq!(Some("US".to_string()), locale.language.region);
+/// assert_eq!(Some("US"), locale.language.region.as_deref());
 /// assert_eq!(None, locale.language.variants);
 /// let u = locale.extensions.unicode_locale.unwrap();
 /// assert_eq!(
-///     &vec!["h12".to_string()],
-///     u.get(0).unwrap().ufield.get("hc").unwrap()
+///     &vec!["h12"],
+///     u.first().unwrap().ufield.get("hc").unwrap()
 /// );
 /// ```
 ///
 /// # Errors
 ///
-/// This function returns an error in the following cases:
+/// This function returns a [`SyntaxError`] with the position of the offending subtag, whose [`SyntaxError::kind`] is:
 ///
 /// - [`ParserError::Missing`] if the given locale id is empty.
 /// - [`ParserError::InvalidLanguage`] if the given locale id is not a valid language identifier.
 /// - [`ParserError::InvalidSubtag`] if the given locale id is not a valid subtag.
-/// - [`ParserError::InvalidExtension`] if the given locale id is not a valid unicode extensions
-pub fn parse_unicode_locale_id(locale_id: &str) -> Result<UnicodeLocaleIdentifier, ParserError> {
+/// - [`ParserError::InvalidExtension`] if the given locale id is not a valid unicode extensions, or has an extension singleton without subtags, e.g. `en-u` and `en-u-ca-x`.
+/// - [`ParserError::EmptySubtag`], [`ParserError::LeadingSeparator`] or [`ParserError::TrailingSeparator`] if the given locale id has an empty subtag.
+/// - [`ParserError::NonAsciiCharacter`] if the given locale id has a non-ASCII character.
+pub fn parse_unicode_locale_id(locale_id: &str) -> Result<UnicodeLocaleIdentifier, SyntaxError> {
+  parse_unicode_locale_id_from_str(locale_id, &mut ParserContext::new(false))
+    .map_err(|e| e.locate(locale_id))
+}
+
+/// Parse the given string as an Unicode Locale Identifier, with the strict well-formedness rules.
+///
+/// In addition to [`parse_unicode_locale_id`], this function rejects the identifiers that [UTS #35](https://unicode.org/reports/tr35/#Unicode_locale_identifier) and [BCP 47](https://www.rfc-editor.org/rfc/rfc5646#section-2.2.9) do not allow:
+/// duplicate variants (also in the `tlang` of transformed extensions), a repeated extension singleton, and a repeated key in one extension.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_locale_id_strict, ParserError};
+///
+/// assert!(parse_locale_id_strict("en-US-u-ca-buddhist-t-ja").is_ok());
+/// assert_eq!(
+///   ParserError::DuplicateExtension,
+///   parse_locale_id_strict("en-US-u-ca-buddhist-u-hc-h12").unwrap_err()
+/// );
+/// assert_eq!(
+///   ParserError::DuplicateKey,
+///   parse_locale_id_strict("en-US-u-ca-buddhist-ca-gregory").unwrap_err()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// This function returns an error in the same cases as [`parse_unicode_locale_id`], and also:
+///
+/// - [`ParserError::DuplicateVariant`] if the given locale id has the same variant more than once.
+/// - [`ParserError::DuplicateExtension`] if the given locale id has the same extension singleton more than once.
+/// - [`ParserError::DuplicateKey`] if the given locale id has the same ukey or tkey more than once in one extension.
+pub fn parse_unicode_locale_id_strict(
+  locale_id: &str,
+) -> Result<UnicodeLocaleIdentifier, SyntaxError> {
+  parse_unicode_locale_id_from_str(locale_id, &mut ParserContext::new(true))
+    .map_err(|e| e.locate(locale_id))
+}
+
+/// Parse the given string as an Unicode Locale Identifier, with the given options.
+///
+/// See [`ParseOptions`] for the options.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_locale_id_with, GrammarProfile, ParseOptions, ParserError};
+///
+/// // legacy ICU locale ID
+/// let options = ParseOptions {
+///   profile: GrammarProfile::Icu,
+///   ..Default::default()
+/// };
+/// let locale = parse_locale_id_with("de_DE@collation=phonebook", &options).unwrap();
+/// assert_eq!("de-DE-u-co-phonebk", format!("{}", locale));
+///
+/// // limits for untrusted input
+/// let options = ParseOptions {
+///   max_length: Some(35),
+///   ..Default::default()
+/// };
+/// assert_eq!(
+///   ParserError::TooLong,
+///   parse_locale_id_with("en-US-u-ca-buddhist-co-phonebk-nu-thai", &options).unwrap_err()
+/// );
+/// ```
+///
+/// # Errors
+///
+/// This function returns an error in the same cases as [`parse_unicode_locale_id`], and also:
+///
+/// - [`ParserError::LegacySeparator`] if `_` is not allowed, and the given locale id has it.
+/// - [`ParserError::NonCanonicalCase`] if the case policy is [`CasePolicy::Reject`](crate::CasePolicy::Reject), and the given locale id has a subtag not in the canonical casing.
+/// - [`ParserError::TooLong`] if the given locale id is longer than the maximum length.
+/// - [`ParserError::TooManySubtags`] if the given locale id has more subtags than the maximum.
+/// - the errors of [`parse_unicode_locale_id_strict`] if the profile is [`GrammarProfile::Bcp47`](crate::GrammarProfile::Bcp47).
+pub fn parse_unicode_locale_id_with(
+  locale_id: &str,
+  options: &ParseOptions,
+) -> Result<UnicodeLocaleIdentifier, SyntaxError> {
+  parse_unicode_locale_id_from_str(locale_id, &mut ParserContext::with_options(options))
+    .map_err(|e| e.locate(locale_id))
+}
+
+/// Parse the given string as an Unicode Locale Identifier, recovering from the errors.
+///
+/// Instead of failing on the first error like [`parse_unicode_locale_id`], this function skips or repairs the invalid subtags,
+/// and returns the locale it could build together with all the errors found. For example:
+///
+/// - an invalid language subtag is replaced with `und`, and is never read as an extension singleton, e.g. `q-US` is `und-US`.
+/// - an invalid subtag of the language identifier is dropped, and the parsing resumes at the production that failed, e.g. `en-!!-US` is `en-US`.
+/// - an invalid ukey, uvalue, tkey or private use value is dropped.
+/// - an orphan tvalue, and a tkey without tvalue are dropped.
+/// - the subtags of an invalid extension singleton are dropped up to the next singleton.
+/// - an extension left without subtags is dropped, so that the locale is always well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_locale_id_lenient, ParserError};
+///
+/// let (locale, diagnostics) = parse_locale_id_lenient("en-US-foo-u-ca-buddhist-t-ja-bar-a-xyz");
+/// assert_eq!("en-US-u-ca-buddhist-t-ja-a-xyz", format!("{}", locale));
+/// assert_eq!(2, diagnostics.len());
+/// assert_eq!("foo", diagnostics[0].fragment());
+/// assert_eq!(ParserError::InvalidSubtag, diagnostics[1].kind());
+/// assert_eq!(29..32, diagnostics[1].span());
+/// ```
+pub fn parse_unicode_locale_id_lenient(
+  locale_id: &str,
+) -> (UnicodeLocaleIdentifier, Vec<SyntaxError>) {
+  let mut ctx = ParserContext::lenient();
+  let locale = match parse_unicode_locale_id_from_str(locale_id, &mut ctx) {
+    Ok(locale) => locale,
+    Err(err) => {
+      // nothing to recover, e.g. the empty string
+      ctx.diagnostics.push(err);
+      UnicodeLocaleIdentifier::default()
+    }
+  };
+  let diagnostics = ctx
+    .diagnostics
+    .into_iter()
+    .map(|e| e.locate(locale_id))
+    .collect();
+  (locale, diagnostics)
+}
+
+pub fn parse_unicode_locale_id_from_str(
+  locale_id: &str,
+  ctx: &mut ParserContext,
+) -> Result<UnicodeLocaleIdentifier, SyntaxError> {
   // check empty
   if locale_id.is_empty() {
-    return Err(ParserError::Missing);
+    return Err(ParserError::Missing.into());
   }
+  check_subtags(locale_id, ctx)?;
 
-  let mut iter = split_str(locale_id).peekable();
-  let language = parse_unicode_language_id_from_iter(&mut iter)?;
-  let extensions = parse_extensions_from_iter(&mut iter)?;
+  // legacy ICU keywords, e.g. `de_DE@collation=phonebook`
+  let (locale_id, keywords) = match locale_id.split_once('@') {
+    Some((base, keywords)) if ctx.options.profile == GrammarProfile::Icu => {
+      (base, Some(Fragment::new(base.len() + 1, keywords)))
+    }
+    _ => (locale_id, None),
+  };
+
+  let mut iter = split_subtags(locale_id).peekable();
+  let mut language = parse_unicode_language_id_from_iter(&mut iter, ctx)?;
+  if ctx.lenient {
+    recover_language(&mut iter, &mut language, ctx)?;
+  }
+  let mut extensions = parse_extensions_from_iter(&mut iter, ctx)?;
+  if let Some(keywords) = keywords {
+    let keywords = parse_icu_keywords(keywords, ctx)?;
+    match extensions
+      .unicode_locale
+      .as_mut()
+      .and_then(|u| u.first_mut())
+    {
+      Some(u) => {
+        for (key, value) in keywords.ufield {
+          u.ufield.entry(key).or_insert(value);
+        }
+      }
+      None => extensions.unicode_locale = Some(vec![keywords]),
+    }
+  }
 
   Ok(UnicodeLocaleIdentifier {
     language,
@@ -80,23 +298,66 @@ pub fn parse_unicode_locale_id(locale_id: &str) -> Result<UnicodeLocaleIdentifie
   })
 }
 
+fn recover_language<'a>(
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+  language: &mut UnicodeLanguageIdentifier,
+  ctx: &mut ParserContext,
+) -> Result<(), SyntaxError> {
+  // the subtags up to the first singleton belong to the language identifier,
+  // so resume at the production that failed, i.e. the script, the region or the variants
+  while let Some(&subtag) = iter.peek() {
+    if subtag.len() == 1 {
+      break;
+    }
+    let expected = if language.variants.is_some() || language.region.is_some() {
+      Production::Variant
+    } else if language.script.is_some() {
+      Production::Region
+    } else {
+      Production::Script
+    };
+    if expected == Production::Script && script_subtag(&subtag).is_ok() {
+      language.script = Some(ctx.subtag(subtag, Casing::Title)?);
+      ctx.token(TokenKind::Script, subtag);
+    } else if expected != Production::Variant && region_subtag(&subtag).is_ok() {
+      language.region = Some(ctx.subtag(subtag, Casing::Upper)?);
+      ctx.token(TokenKind::Region, subtag);
+    } else if variant_subtag(&subtag).is_ok() {
+      let variant: Variant = ctx.subtag(subtag, Casing::Lower)?;
+      ctx.token(TokenKind::Variant, subtag);
+      let variants = language.variants.get_or_insert_with(Vec::new);
+      if !variants.iter().any(|v| v.eq_ignore_ascii_case(&variant)) {
+        variants.push(variant);
+      }
+    } else {
+      ctx.recover(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(expected),
+      ))?;
+    }
+    iter.next();
+  }
+  Ok(())
+}
+
 /*
  * Unit tests
  */
 
+#[allow(unused_imports)] // for unit tests
+use crate::options::CasePolicy;
+
 #[test]
 fn success_parse_unicode_locale_id() {
   // basic
   let locale = parse_unicode_locale_id("en-US-u-hc-h12").unwrap();
   assert_eq!("en", locale.language.language);
   assert_eq!(None, locale.language.script);
-  assert_eq!(Some("US".to_string()), locale.language.region);
+  assert_eq!(Some("US"), locale.language.region.as_deref());
   assert_eq!(None, locale.language.variants);
   let u = locale.extensions.unicode_locale.unwrap();
-  assert_eq!(
-    &vec!["h12".to_string()],
-    u.get(0).unwrap().ufield.get("hc").unwrap()
-  );
+  assert_eq!(&vec!["h12"], u.first().unwrap().ufield.get("hc").unwrap());
 
   // full case
   let locale = parse_unicode_locale_id(
@@ -121,6 +382,47 @@ fn success_parse_unicode_locale_id() {
   assert_eq!("ja-Latn-JP", format!("{}", result));
 }
 
+#[test]
+fn success_canonical_display() {
+  let canonical = |source: &str| {
+    format!(
+      "{}",
+      parse_unicode_locale_id(source).unwrap().canonical_display()
+    )
+  };
+
+  // casing and variants
+  assert_eq!("sr-Latn-RS-1994-rozaj", canonical("SR-latn-rs-ROZAJ-1994"));
+
+  // singletons in alphabetical order, and private use at last
+  assert_eq!(
+    "ja-a-vue-rust-t-en-latn-us-m0-ungegn-u-ca-japanese-z-foo-x-bar",
+    canonical("ja-u-ca-japanese-z-foo-t-en-Latn-US-m0-ungegn-a-vue-rust-x-bar")
+  );
+
+  // merged extensions, sorted attributes and keys, and removed "true"
+  assert_eq!(
+    "en-u-attr1-attr2-ca-buddhist-kn-nu-thai",
+    canonical("en-u-attr2-NU-thai-u-attr1-kn-true-Ca-buddhist")
+  );
+  assert_eq!("en-t-k0-true", canonical("en-t-k0-true"));
+  assert_eq!("en-u-xx-foo-true", canonical("en-u-xx-foo-true"));
+  assert_eq!("en-t-m0-true", canonical("EN-T-M0-TRUE"));
+  for source in ["en-t-m0-true", "en-t-k0-true-m0-true-ungegn"] {
+    assert!(
+      parse_unicode_locale_id(&canonical(source)).is_ok(),
+      "{}",
+      source
+    );
+  }
+
+  // identical locales are displayed as the same string
+  assert_eq!(
+    canonical("de-DE-u-co-phonebk-ka-shifted"),
+    canonical("DE_de_U_KA_Shifted_co_PHONEBK")
+  );
+}
+
 #[test]
 fn fail_parse_unicode_locale_id() {
   // missing locale
@@ -129,3 +431,378 @@ fn fail_parse_unicode_locale_id() {
     parse_unicode_locale_id("").unwrap_err()
   );
 }
+
+#[test]
+fn success_parse_unicode_locale_id_strict() {
+  let locale =
+    parse_unicode_locale_id_strict("de-DE-1901-u-co-phonebk-t-en-1996-h0-hybrid-x-1901-1901")
+      .unwrap();
+  assert_eq!(
+    "de-DE-1901-u-co-phonebk-t-en-1996-h0-hybrid-x-1901-1901",
+    format!("{}", locale)
+  );
+}
+
+#[test]
+fn fail_parse_unicode_locale_id_strict() {
+  // missing locale
+  assert_eq!(
+    ParserError::Missing,
+    parse_unicode_locale_id_strict("").unwrap_err()
+  );
+
+  // duplicate variants
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_unicode_locale_id_strict("de-DE-1901-1996-1901").unwrap_err()
+  );
+
+  // duplicate variants in tlang
+  assert_eq!(
+    ParserError::DuplicateVariant,
+    parse_unicode_locale_id_strict("en-t-de-1901-1901").unwrap_err()
+  );
+
+  // duplicate unicode locale extensions
+  assert_eq!(
+    ParserError::DuplicateExtension,
+    parse_unicode_locale_id_strict("en-u-ca-buddhist-U-hc-h12").unwrap_err()
+  );
+
+  // duplicate transformed extensions
+  assert_eq!(
+    ParserError::DuplicateExtension,
+    parse_unicode_locale_id_strict("en-t-ja-t-h0-hybrid").unwrap_err()
+  );
+
+  // duplicate other extensions
+  assert_eq!(
+    ParserError::DuplicateExtension,
+    parse_unicode_locale_id_strict("en-a-foo-a-bar").unwrap_err()
+  );
+
+  // duplicate ukey
+  assert_eq!(
+    ParserError::DuplicateKey,
+    parse_unicode_locale_id_strict("en-u-ca-buddhist-CA-gregory").unwrap_err()
+  );
+
+  // duplicate tkey
+  assert_eq!(
+    ParserError::DuplicateKey,
+    parse_unicode_locale_id_strict("en-t-h0-hybrid-h0-hybrid").unwrap_err()
+  );
+
+  // extensions without subtags
+  for source in ["en-u", "en-t", "en-x", "en-a", "en-u-ca-x", "en-US-u-ca-a"] {
+    let err = parse_unicode_locale_id_strict(source).unwrap_err();
+    assert_eq!(ParserError::InvalidExtension, err.kind(), "{}", source);
+    assert_eq!(source.len() - 1..source.len(), err.span(), "{}", source);
+  }
+
+  // tkey without tvalue
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    parse_unicode_locale_id_strict("en-t-h0-x-foo").unwrap_err()
+  );
+
+  // non-strict mode accepts duplicates
+  assert!(parse_unicode_locale_id("en-u-ca-buddhist-u-hc-h12").is_ok());
+}
+
+#[test]
+fn fail_parse_unicode_locale_id_malformed() {
+  // empty subtag, which would be read as a singleton before
+  assert_eq!(
+    ParserError::EmptySubtag,
+    parse_unicode_locale_id("en--US").unwrap_err()
+  );
+
+  // leading separator
+  assert_eq!(
+    ParserError::LeadingSeparator,
+    parse_unicode_locale_id("-en-US").unwrap_err()
+  );
+
+  // trailing separator
+  assert_eq!(
+    ParserError::TrailingSeparator,
+    parse_unicode_locale_id("en-US-u-").unwrap_err()
+  );
+
+  // non-ASCII character
+  assert_eq!(
+    ParserError::NonAsciiCharacter,
+    parse_unicode_locale_id("en-u-ca-japanése").unwrap_err()
+  );
+
+  // invalid singleton
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_unicode_locale_id("en-US-!!").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_unicode_locale_id("en-US-!").unwrap_err()
+  );
+
+  // not a singleton
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_unicode_locale_id("en-US-foo").unwrap_err()
+  );
+
+  // tlang after tfield
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    parse_unicode_locale_id("en-t-h0-hybrid-fr").unwrap_err()
+  );
+}
+
+#[test]
+fn fail_parse_unicode_locale_id_position() {
+  // invalid ukey
+  let err = parse_unicode_locale_id("zh-Hant-TW-u-ca-chinese-k1").unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(24..26, err.span());
+  assert_eq!(6, err.index());
+  assert_eq!(Some(Production::UKey), err.expected());
+  assert_eq!("k1", err.fragment());
+
+  // invalid singleton
+  let err = parse_unicode_locale_id("en-US-foo").unwrap_err();
+  assert_eq!(ParserError::InvalidExtension, err.kind());
+  assert_eq!(6..9, err.span());
+  assert_eq!(2, err.index());
+  assert_eq!(Some(Production::Singleton), err.expected());
+
+  // missing tvalue
+  let err = parse_unicode_locale_id("en-t-h0").unwrap_err();
+  assert_eq!(ParserError::InvalidSubtag, err.kind());
+  assert_eq!(7..7, err.span());
+  assert_eq!(3, err.index());
+  assert_eq!(Some(Production::TValue), err.expected());
+
+  // invalid tkey
+  let err = parse_unicode_locale_id("en-t-h0-hybrid-1x").unwrap_err();
+  assert_eq!(15..17, err.span());
+  assert_eq!(Some(Production::TKey), err.expected());
+
+  // invalid pu value
+  let err = parse_unicode_locale_id("en-x-abcdefghi").unwrap_err();
+  assert_eq!(5..14, err.span());
+  assert_eq!(Some(Production::PuValue), err.expected());
+
+  // duplicate key
+  let err = parse_unicode_locale_id_strict("en-u-ca-buddhist-ca-gregory").unwrap_err();
+  assert_eq!(ParserError::DuplicateKey, err.kind());
+  assert_eq!(17..19, err.span());
+  assert_eq!(4, err.index());
+
+  // duplicate extension
+  let err = parse_unicode_locale_id_strict("en-u-ca-buddhist-u-hc-h12").unwrap_err();
+  assert_eq!(ParserError::DuplicateExtension, err.kind());
+  assert_eq!(17..18, err.span());
+
+  // missing
+  let err = parse_unicode_locale_id("").unwrap_err();
+  assert_eq!(ParserError::Missing, err.kind());
+  assert_eq!(0..0, err.span());
+}
+
+#[test]
+fn success_parse_unicode_locale_id_lenient() {
+  // no errors
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("en-US-u-hc-h12");
+  assert_eq!("en-US-u-hc-h12", format!("{}", locale));
+  assert!(diagnostics.is_empty());
+
+  // bad variant is dropped, and the following variant is kept
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("de-DE-foo-1996");
+  assert_eq!("de-DE-1996", format!("{}", locale));
+  assert_eq!(
+    vec![(ParserError::InvalidSubtag, 6..9, Some(Production::Variant))],
+    diagnostics
+      .iter()
+      .map(|e| (e.kind(), e.span(), e.expected()))
+      .collect::<Vec<_>>()
+  );
+
+  // invalid language is replaced with 'und'
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("Latn-US");
+  assert_eq!("und-Latn-US", format!("{}", locale));
+  assert_eq!(ParserError::InvalidLanguage, diagnostics[0].kind());
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("12-u-ca-buddhist");
+  assert_eq!("und-u-ca-buddhist", format!("{}", locale));
+  assert_eq!(ParserError::InvalidLanguage, diagnostics[0].kind());
+
+  // invalid language is dropped, and never read as an extension singleton
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("q-US");
+  assert_eq!("und-US", format!("{}", locale));
+  assert_eq!(1, diagnostics.len());
+  assert_eq!(0..1, diagnostics[0].span());
+  assert_eq!(Some(Production::Language), diagnostics[0].expected());
+
+  // recovery resumes at the production that failed
+  for (source, expected, production) in [
+    ("en-!!-US", "en-US", Production::Script),
+    ("en-!!-Latn-US-1996", "en-Latn-US-1996", Production::Script),
+    ("en-Latn-!!-US", "en-Latn-US", Production::Region),
+    ("en-US-!!-1996", "en-US-1996", Production::Variant),
+    (
+      "en-US-Latn-u-ca-buddhist",
+      "en-US-u-ca-buddhist",
+      Production::Variant,
+    ),
+  ] {
+    let (locale, diagnostics) = parse_unicode_locale_id_lenient(source);
+    assert_eq!(expected, format!("{}", locale), "{}", source);
+    assert_eq!(
+      vec![(ParserError::InvalidSubtag, Some(production))],
+      diagnostics
+        .iter()
+        .map(|e| (e.kind(), e.expected()))
+        .collect::<Vec<_>>(),
+      "{}",
+      source
+    );
+  }
+
+  // orphan tvalue is discarded
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("ja-t-en-foo-h0-hybrid");
+  assert_eq!("ja-t-en-h0-hybrid", format!("{}", locale));
+  assert_eq!(Some(Production::TKey), diagnostics[0].expected());
+
+  // other extensions are kept, without the invalid values
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("en-a-foo-toolongvalue-bar");
+  assert_eq!("en-a-foo-bar", format!("{}", locale));
+  assert_eq!("toolongvalue", diagnostics[0].fragment());
+
+  // invalid singleton is skipped up to the next singleton
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("en-u-ca-buddhist-!-foo-bar-x-baz");
+  assert_eq!("en-u-ca-buddhist-x-baz", format!("{}", locale));
+  assert_eq!(1, diagnostics.len());
+  assert_eq!(ParserError::InvalidExtension, diagnostics[0].kind());
+
+  // extensions left without subtags are dropped
+  for (source, expected) in [
+    ("en-u-!!", "en"),
+    ("en-x-toolongvalue", "en"),
+    ("en-t-h0-x", "en"),
+    ("en-US-u-a-ca-!!-x-foo", "en-US-a-ca-x-foo"),
+  ] {
+    let (locale, diagnostics) = parse_unicode_locale_id_lenient(source);
+    assert_eq!(expected, format!("{}", locale), "{}", source);
+    assert!(
+      diagnostics
+        .iter()
+        .any(|e| e.kind() == ParserError::InvalidExtension),
+      "{}",
+      source
+    );
+    assert!(parse_unicode_locale_id(&locale.to_string()).is_ok());
+  }
+
+  // malformed subtags are skipped
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("_en--US-ÜS-");
+  assert_eq!("en-US", format!("{}", locale));
+  assert_eq!(
+    vec![
+      ParserError::LeadingSeparator,
+      ParserError::EmptySubtag,
+      ParserError::NonAsciiCharacter,
+      ParserError::TrailingSeparator
+    ],
+    diagnostics.iter().map(|e| e.kind()).collect::<Vec<_>>()
+  );
+
+  // nothing to recover
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("");
+  assert_eq!("und", format!("{}", locale));
+  assert_eq!(ParserError::Missing, diagnostics[0].kind());
+  let (locale, diagnostics) = parse_unicode_locale_id_lenient("--");
+  assert_eq!("und", format!("{}", locale));
+  assert_eq!(4, diagnostics.len());
+}
+
+#[test]
+fn success_parse_unicode_locale_id_with() {
+  // normalize case
+  let options = ParseOptions {
+    case: CasePolicy::Normalize,
+    ..Default::default()
+  };
+  let locale = parse_unicode_locale_id_with(
+    "JA-latn-jp-MACOS-U-Attr1-KZ-Value2-T-EN-latn-us-T1-Value1-A-Vue-X-Foo",
+    &options,
+  )
+  .unwrap();
+  assert_eq!(
+    "ja-Latn-JP-macos-u-attr1-kz-value2-t-en-Latn-US-t1-value1-a-vue-x-foo",
+    format!("{}", locale)
+  );
+
+  // ICU profile
+  let options = ParseOptions {
+    profile: GrammarProfile::Icu,
+    ..Default::default()
+  };
+  let locale = parse_unicode_locale_id_with("en__POSIX", &options).unwrap();
+  assert_eq!("en-POSIX", format!("{}", locale));
+  let locale =
+    parse_unicode_locale_id_with("ja_JP-u-nu-jpan@calendar=japanese;numbers=latn", &options)
+      .unwrap();
+  assert_eq!("ja-JP-u-ca-japanese-nu-jpan", format!("{}", locale));
+
+  // '@' is not special in the other profiles
+  assert_eq!(
+    ParserError::InvalidExtension,
+    parse_unicode_locale_id_with("de_DE@collation=phonebook", &ParseOptions::default())
+      .unwrap_err()
+  );
+}
+
+#[test]
+fn fail_parse_unicode_locale_id_with() {
+  // reject non-canonical case
+  let options = ParseOptions {
+    case: CasePolicy::Reject,
+    ..Default::default()
+  };
+  assert!(parse_unicode_locale_id_with("en-Latn-US-u-ca-buddhist", &options).is_ok());
+  let err = parse_unicode_locale_id_with("en-Latn-US-u-ca-Buddhist", &options).unwrap_err();
+  assert_eq!(ParserError::NonCanonicalCase, err.kind());
+  assert_eq!(16..24, err.span());
+  let err = parse_unicode_locale_id_with("en-Latn-US-U-ca-buddhist", &options).unwrap_err();
+  assert_eq!(ParserError::NonCanonicalCase, err.kind());
+  assert_eq!(11..12, err.span());
+
+  // limits
+  let options = ParseOptions {
+    max_length: Some(10),
+    max_subtags: Some(3),
+    ..Default::default()
+  };
+  assert_eq!(
+    ParserError::TooLong,
+    parse_unicode_locale_id_with("en-US-u-ca-buddhist", &options).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::TooManySubtags,
+    parse_unicode_locale_id_with("en-u-ca-x", &options).unwrap_err()
+  );
+
+  // BCP 47 profile
+  let options = ParseOptions {
+    profile: GrammarProfile::Bcp47,
+    ..Default::default()
+  };
+  assert_eq!(
+    ParserError::DuplicateExtension,
+    parse_unicode_locale_id_with("en-u-ca-buddhist-u-hc-h12", &options).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::LegacySeparator,
+    parse_unicode_locale_id_with("en_US", &options).unwrap_err()
+  );
+}
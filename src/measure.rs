@@ -1,6 +1,6 @@
 use crate::constants::SEP;
-use crate::errors::ParserError;
-use crate::shared::split_str;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::shared::{check_subtags, split_str, Fragment, ParserContext};
 
 use std::fmt::{self};
 use std::iter::Peekable;
@@ -23,7 +23,7 @@ impl fmt::Display for UnicodeMeasureUnit {
 }
 
 impl FromStr for UnicodeMeasureUnit {
-  type Err = ParserError;
+  type Err = SyntaxError;
 
   fn from_str(source: &str) -> Result<Self, Self::Err> {
     parse_unicode_measure_unit(source)
@@ -45,32 +45,45 @@ impl FromStr for UnicodeMeasureUnit {
 ///
 /// # Errors
 ///
-/// This function returns an error in the following cases:
+/// This function returns a [`SyntaxError`] with the position of the offending subtag, whose [`SyntaxError::kind`] is:
 ///
 /// - [`ParserError::Missing`] if the given measure unit is empty.
 /// - [`ParserError::InvalidSubtag`] if the given measure unit is not a valid.
-pub fn parse_unicode_measure_unit(measure_unit: &str) -> Result<UnicodeMeasureUnit, ParserError> {
+/// - [`ParserError::EmptySubtag`], [`ParserError::LeadingSeparator`] or [`ParserError::TrailingSeparator`] if the given measure unit has an empty subtag.
+/// - [`ParserError::NonAsciiCharacter`] if the given measure unit has a non-ASCII character.
+pub fn parse_unicode_measure_unit(measure_unit: &str) -> Result<UnicodeMeasureUnit, SyntaxError> {
+  parse_unicode_measure_unit_from_str(measure_unit).map_err(|e| e.locate(measure_unit))
+}
+
+fn parse_unicode_measure_unit_from_str(
+  measure_unit: &str,
+) -> Result<UnicodeMeasureUnit, SyntaxError> {
   if measure_unit.is_empty() {
-    return Err(ParserError::Missing);
+    return Err(ParserError::Missing.into());
   }
+  check_subtags(measure_unit, &mut ParserContext::default())?;
 
   parse_unicode_measure_unit_from_iter(&mut split_str(measure_unit).peekable())
 }
 
 fn parse_unicode_measure_unit_from_iter<'a>(
-  iter: &mut Peekable<impl Iterator<Item = &'a str>>,
-) -> Result<UnicodeMeasureUnit, ParserError> {
+  iter: &mut Peekable<impl Iterator<Item = Fragment<'a>>>,
+) -> Result<UnicodeMeasureUnit, SyntaxError> {
   // unicode_measure_unit
   // https://unicode.org/reports/tr35/#unicode_measure_unit
   let mut values = vec![];
 
-  while let Some(subtag) = iter.peek() {
+  while let Some(&subtag) = iter.peek() {
     let subtag_bytes = subtag.as_bytes();
 
     if !(3..=8).contains(&subtag_bytes.len())
       || !subtag_bytes.iter().all(|b: &u8| b.is_ascii_alphanumeric())
     {
-      return Err(ParserError::InvalidSubtag);
+      return Err(SyntaxError::at(
+        ParserError::InvalidSubtag,
+        subtag,
+        Some(Production::MeasureUnit),
+      ));
     }
 
     values.push(subtag.to_string());
@@ -78,7 +91,7 @@ fn parse_unicode_measure_unit_from_iter<'a>(
   }
 
   let values = if values.is_empty() {
-    return Err(ParserError::Missing);
+    return Err(ParserError::Missing.into());
   } else {
     values
   };
@@ -126,4 +139,16 @@ fn fail_parse_unicode_measure_unit() {
     ParserError::InvalidSubtag,
     parse_unicode_measure_unit("acceleration-g-force").unwrap_err()
   );
+
+  // empty subtag
+  assert_eq!(
+    ParserError::EmptySubtag,
+    parse_unicode_measure_unit("area--hectare").unwrap_err()
+  );
+
+  // position
+  let err = parse_unicode_measure_unit("acceleration-g-force").unwrap_err();
+  assert_eq!(0..12, err.span());
+  assert_eq!(0, err.index());
+  assert_eq!(Some(Production::MeasureUnit), err.expected());
 }
@@ -0,0 +1,127 @@
+/// The options to configure the parsers, used with [`parse_locale_id_with`](crate::parse_locale_id_with) and [`parse_language_id_with`](crate::parse_language_id_with).
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_locale_id_with, CasePolicy, GrammarProfile, ParseOptions};
+///
+/// let options = ParseOptions {
+///   allow_legacy_separator: false,
+///   case: CasePolicy::Normalize,
+///   max_length: Some(64),
+///   max_subtags: Some(16),
+///   profile: GrammarProfile::Bcp47,
+/// };
+/// let locale = parse_locale_id_with("EN-latn-us-U-CA-Buddhist", &options).unwrap();
+/// assert_eq!("en-Latn-US-u-ca-buddhist", format!("{}", locale));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+  /// Whether `_` is allowed as a separator in addition to `-`. The default is `true`.
+  pub allow_legacy_separator: bool,
+  /// How to handle the letter case of the subtags. The default is [`CasePolicy::Preserve`].
+  pub case: CasePolicy,
+  /// The maximum length of the input in bytes. The default is no limit.
+  pub max_length: Option<usize>,
+  /// The maximum number of subtags in the input. The default is no limit.
+  pub max_subtags: Option<usize>,
+  /// The grammar to parse with. The default is [`GrammarProfile::Unicode`].
+  pub profile: GrammarProfile,
+}
+
+impl Default for ParseOptions {
+  fn default() -> Self {
+    ParseOptions {
+      allow_legacy_separator: true,
+      case: CasePolicy::Preserve,
+      max_length: None,
+      max_subtags: None,
+      profile: GrammarProfile::Unicode,
+    }
+  }
+}
+
+/// Enum representing how the parsers handle the letter case of the subtags.
+///
+/// The canonical casing is lowercase, except for the script subtag in title case and the region subtag in uppercase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasePolicy {
+  /// Accept any letter case, and keep the subtags as written.
+  Preserve,
+  /// Accept any letter case, and convert the subtags to the canonical casing.
+  Normalize,
+  /// Reject the subtags that are not in the canonical casing with [`ParserError::NonCanonicalCase`](crate::ParserError::NonCanonicalCase).
+  Reject,
+}
+
+/// Enum representing the grammar the parsers follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrammarProfile {
+  /// The [`unicode_locale_id`](https://unicode.org/reports/tr35/#unicode_locale_id) grammar of Unicode CLDR, as [`parse_locale_id`](crate::parse_locale_id).
+  Unicode,
+  /// The BCP 47 compatible subset of the Unicode grammar.
+  ///
+  /// In addition to the strict well-formedness rules of [`parse_locale_id_strict`](crate::parse_locale_id_strict),
+  /// `_` separators and the `root` language subtag are rejected.
+  Bcp47,
+  /// The Unicode grammar, tolerating the legacy ICU locale IDs.
+  ///
+  /// Empty subtags (e.g. `en__POSIX`) are skipped, and the ICU keywords (e.g. `de_DE@collation=phonebook`) are converted to the unicode locale extensions.
+  Icu,
+}
+
+/// Enum representing the canonical casing of a subtag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Casing {
+  Lower,
+  Title,
+  Upper,
+}
+
+impl Casing {
+  pub fn apply(self, subtag: &str) -> String {
+    match self {
+      Casing::Lower => subtag.to_ascii_lowercase(),
+      Casing::Upper => subtag.to_ascii_uppercase(),
+      Casing::Title => {
+        let mut s = subtag.to_ascii_lowercase();
+        if let Some(first) = s.get_mut(0..1) {
+          first.make_ascii_uppercase();
+        }
+        s
+      }
+    }
+  }
+
+  pub fn is_applied(self, subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    match self {
+      Casing::Lower => !bytes.iter().any(|b| b.is_ascii_uppercase()),
+      Casing::Upper => !bytes.iter().any(|b| b.is_ascii_lowercase()),
+      Casing::Title => {
+        !bytes.first().is_some_and(|b| b.is_ascii_lowercase())
+          && !bytes.iter().skip(1).any(|b| b.is_ascii_uppercase())
+      }
+    }
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_casing() {
+  assert_eq!("latn", Casing::Lower.apply("LaTn"));
+  assert_eq!("Latn", Casing::Title.apply("lATN"));
+  assert_eq!("LATN", Casing::Upper.apply("Latn"));
+  assert_eq!("419", Casing::Title.apply("419"));
+
+  assert!(Casing::Lower.is_applied("1996"));
+  assert!(Casing::Title.is_applied("Latn"));
+  assert!(Casing::Upper.is_applied("US"));
+  assert!(!Casing::Lower.is_applied("macOS"));
+  assert!(!Casing::Title.is_applied("latn"));
+  assert!(!Casing::Title.is_applied("LATN"));
+  assert!(!Casing::Upper.is_applied("Us"));
+}
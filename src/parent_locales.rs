@@ -0,0 +1,129 @@
+use crate::constants::LANG_UND;
+use crate::lang::{parse_unicode_language_id, UnicodeLanguageIdentifier};
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+
+impl UnicodeLanguageIdentifier {
+  /// Find the parent locale with the parent locales data of CLDR, following [the parent locale inheritance of UTS #35](https://unicode.org/reports/tr35/#Parent_Locales).
+  ///
+  /// The parent is the one in the parent locales data, e.g. `en-001` for `en-AU`, or otherwise the identifier without the last subtag, i.e. a variant, the region or the script.
+  /// The parent of the language is the root locale `und`, which has no parent.
+  /// The identifier is expected to be canonicalized, e.g. with [`UnicodeLanguageIdentifier::canonicalize`].
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::parse_language_id;
+  ///
+  /// let lang = parse_language_id("en-AU").unwrap();
+  /// assert_eq!("en-001", format!("{}", lang.parent().unwrap()));
+  ///
+  /// let lang = parse_language_id("zh-Hant").unwrap();
+  /// assert_eq!("und", format!("{}", lang.parent().unwrap()));
+  ///
+  /// let lang = parse_language_id("de-CH-1996").unwrap();
+  /// assert_eq!("de-CH", format!("{}", lang.parent().unwrap()));
+  ///
+  /// let lang = parse_language_id("und").unwrap();
+  /// assert_eq!(None, lang.parent());
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn parent(&self) -> Option<UnicodeLanguageIdentifier> {
+    self.parent_with(&EmbeddedDataProvider)
+  }
+
+  /// Find the parent locale with the parent locales data of the given provider, as [`UnicodeLanguageIdentifier::parent`].
+  pub fn parent_with(
+    &self,
+    provider: &dyn LocaleDataProvider,
+  ) -> Option<UnicodeLanguageIdentifier> {
+    if let Some(parent) = provider.lookup(DataTable::ParentLocales, &self.to_string()) {
+      return parse_unicode_language_id(parent).ok();
+    }
+
+    let mut parent = self.clone();
+    if let Some(variants) = parent.variants.as_mut() {
+      variants.pop();
+      if variants.is_empty() {
+        parent.variants = None;
+      }
+    } else if parent.region.is_some() {
+      parent.region = None;
+    } else if parent.script.is_some() {
+      parent.script = None;
+    } else if !parent.language.is_empty() {
+      return parse_unicode_language_id(LANG_UND).ok();
+    } else {
+      return None;
+    }
+    Some(parent)
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[cfg(feature = "embedded-data")]
+#[allow(dead_code)] // for unit tests
+fn parents(source: &str) -> Vec<String> {
+  let mut lang = parse_unicode_language_id(source).unwrap();
+  let mut parents = vec![];
+  while let Some(parent) = lang.parent() {
+    parents.push(format!("{}", parent));
+    lang = parent;
+  }
+  parents
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_parent() {
+  // truncation
+  assert_eq!(vec!["en", "und"], parents("en-US"));
+  assert_eq!(vec!["de-CH", "de", "und"], parents("de-CH-1996"));
+  assert_eq!(
+    vec!["ja-Latn-hepburn", "ja-Latn", "ja", "und"],
+    parents("ja-Latn-hepburn-heploc")
+  );
+  assert_eq!(vec!["fr-Latn", "fr", "und"], parents("fr-Latn-CA"));
+
+  // parent locales data
+  assert_eq!(vec!["en-001", "en", "und"], parents("en-AU"));
+  assert_eq!(vec!["en-150", "en-001", "en", "und"], parents("en-DE"));
+  assert_eq!(vec!["es-419", "es", "und"], parents("es-MX"));
+  assert_eq!(vec!["zh-Hant-HK", "zh-Hant", "und"], parents("zh-Hant-MO"));
+  assert_eq!(vec!["und"], parents("az-Arab"));
+  assert_eq!(vec!["sr-Latn", "und"], parents("sr-Latn-RS"));
+
+  // root
+  assert_eq!(Vec::<String>::new(), parents("und"));
+  assert_eq!(vec!["und"], parents("und-Latn"));
+}
+
+#[allow(dead_code)] // for unit tests
+struct FakeProvider;
+
+impl LocaleDataProvider for FakeProvider {
+  fn lookup(&self, table: DataTable, key: &str) -> Option<&str> {
+    match (table, key) {
+      (DataTable::ParentLocales, "xx-YY") => Some("zz"),
+      _ => None,
+    }
+  }
+}
+
+#[test]
+fn success_parent_with() {
+  let lang = parse_unicode_language_id("xx-YY").unwrap();
+  assert_eq!(
+    "zz",
+    format!("{}", lang.parent_with(&FakeProvider).unwrap())
+  );
+  let lang = parse_unicode_language_id("xx-ZZ").unwrap();
+  assert_eq!(
+    "xx",
+    format!("{}", lang.parent_with(&FakeProvider).unwrap())
+  );
+}
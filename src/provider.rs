@@ -0,0 +1,183 @@
+#[cfg(feature = "json-data")]
+pub mod json;
+
+/// Enum representing the data tables of CLDR that a [`LocaleDataProvider`] looks up.
+///
+/// Each table maps a key to a value, both in the forms of the tables embedded in this crate:
+/// the subtags in the canonical casing joined with `-`, the keywords as `key-type`, and the statuses of the validity data as the `idStatus` of CLDR, e.g. `regular` or `deprecated`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DataTable {
+  /// The `languageAlias` rules, e.g. `iw` to `he` and `und-aaland` to `und-AX`.
+  LanguageAliases,
+  /// The `scriptAlias` rules, e.g. `Qaai` to `Zinh`.
+  ScriptAliases,
+  /// The `territoryAlias` rules, whose replacements are separated by spaces, e.g. `SU` to `RU AM AZ ...`.
+  RegionAliases,
+  /// The `variantAlias` rules, e.g. `heploc` to `alalc97`.
+  VariantAliases,
+  /// The `subdivisionAlias` rules, whose replacements are separated by spaces, and may be the regions.
+  SubdivisionAliases,
+  /// The type aliases of the ukeys, e.g. `ca-islamicc` to `islamic-civil`.
+  UnicodeKeywordAliases,
+  /// The type aliases of the tkeys, e.g. `m0-names` to `prprname`.
+  TransformedKeywordAliases,
+  /// The likely subtags, e.g. `zh-TW` to `zh-Hant-TW`.
+  LikelySubtags,
+  /// The parent locales that are not found by truncating the last subtag, e.g. `en-AU` to `en-001` and `zh-Hant` to `und`.
+  ParentLocales,
+  /// The `territoryContainment` data, whose contained regions are separated by spaces, e.g. `155` to `AT BE CH DE FR LI LU MC NL`.
+  RegionContainment,
+  /// The statuses of the language subtags, e.g. `en` to `regular`.
+  Languages,
+  /// The statuses of the script subtags, e.g. `Zzzz` to `unknown`.
+  Scripts,
+  /// The statuses of the region subtags, e.g. `001` to `macroregion`.
+  Regions,
+  /// The statuses of the variant subtags.
+  Variants,
+  /// The statuses of the subdivisions, e.g. `gbsct` to `regular`.
+  Subdivisions,
+  /// The statuses of the measure units, e.g. `length-meter` to `regular`.
+  Units,
+  /// The statuses of the currencies, in lowercase, e.g. `jpy` to `regular`.
+  Currencies,
+  /// The ukeys, with the kind of their values: `type`, `currency`, `script`, `region`, `subdivision`, `reorder` or `codepoints`,
+  /// followed by `deprecated` for the deprecated keys, e.g. `vt` to `codepoints deprecated`.
+  UnicodeKeys,
+  /// The statuses of the types of the ukeys, e.g. `ca-gregory` to `regular`.
+  UnicodeTypes,
+  /// The tkeys, with the kind of their values: `type` or `private_use`, followed by `deprecated` for the deprecated keys.
+  TransformedKeys,
+  /// The statuses of the types of the tkeys, e.g. `m0-ungegn` to `regular`.
+  TransformedTypes,
+}
+
+/// A provider of the CLDR data for the data-driven operations, e.g. [`UnicodeLocaleIdentifier::canonicalize_with`](crate::UnicodeLocaleIdentifier::canonicalize_with).
+///
+/// The operations without a provider, e.g. [`UnicodeLocaleIdentifier::canonicalize`](crate::UnicodeLocaleIdentifier::canonicalize), use [`EmbeddedDataProvider`].
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_language_id, DataTable, LocaleDataProvider};
+///
+/// struct FakeProvider;
+///
+/// impl LocaleDataProvider for FakeProvider {
+///   fn lookup(&self, table: DataTable, key: &str) -> Option<&str> {
+///     match (table, key) {
+///       (DataTable::LanguageAliases, "xx") => Some("yy"),
+///       _ => None,
+///     }
+///   }
+/// }
+///
+/// let mut lang = parse_language_id("xx-US").unwrap();
+/// lang.canonicalize_with(&FakeProvider);
+/// assert_eq!("yy-US", format!("{}", lang));
+/// ```
+pub trait LocaleDataProvider {
+  /// Find the value of the key in the table.
+  fn lookup(&self, table: DataTable, key: &str) -> Option<&str>;
+}
+
+/// The provider of the CLDR data embedded in this crate, with the `embedded-data` feature.
+#[cfg(feature = "embedded-data")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmbeddedDataProvider;
+
+#[cfg(feature = "embedded-data")]
+impl LocaleDataProvider for EmbeddedDataProvider {
+  fn lookup(&self, table: DataTable, key: &str) -> Option<&str> {
+    use crate::data::{aliases, containment, likely_subtags, parent_locales, validity};
+
+    let table = match table {
+      DataTable::LanguageAliases => aliases::LANGUAGE_ALIASES,
+      DataTable::ScriptAliases => aliases::SCRIPT_ALIASES,
+      DataTable::RegionAliases => aliases::REGION_ALIASES,
+      DataTable::VariantAliases => aliases::VARIANT_ALIASES,
+      DataTable::SubdivisionAliases => aliases::SUBDIVISION_ALIASES,
+      DataTable::UnicodeKeywordAliases => aliases::UNICODE_KEYWORD_ALIASES,
+      DataTable::TransformedKeywordAliases => aliases::TRANSFORMED_KEYWORD_ALIASES,
+      DataTable::LikelySubtags => likely_subtags::LIKELY_SUBTAGS,
+      DataTable::ParentLocales => parent_locales::PARENT_LOCALES,
+      DataTable::RegionContainment => containment::REGION_CONTAINMENT,
+      DataTable::Languages => validity::LANGUAGES,
+      DataTable::Scripts => validity::SCRIPTS,
+      DataTable::Regions => validity::REGIONS,
+      DataTable::Variants => validity::VARIANTS,
+      DataTable::Subdivisions => validity::SUBDIVISIONS,
+      DataTable::Units => validity::UNITS,
+      DataTable::Currencies => validity::CURRENCIES,
+      DataTable::UnicodeKeys => validity::UNICODE_KEYS,
+      DataTable::UnicodeTypes => validity::UNICODE_TYPES,
+      DataTable::TransformedKeys => validity::TRANSFORMED_KEYS,
+      DataTable::TransformedTypes => validity::TRANSFORMED_TYPES,
+    };
+    crate::data::lookup(table, key)
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+struct FakeProvider;
+
+impl LocaleDataProvider for FakeProvider {
+  fn lookup(&self, table: DataTable, key: &str) -> Option<&str> {
+    match (table, key) {
+      (DataTable::LanguageAliases, "xx") => Some("yy"),
+      (DataTable::LikelySubtags, "yy") => Some("yy-Cyrl-KZ"),
+      (DataTable::Languages, "yy") => Some("regular"),
+      (DataTable::Scripts, "Cyrl") => Some("regular"),
+      (DataTable::Regions, "KZ") => Some("regular"),
+      _ => None,
+    }
+  }
+}
+
+#[test]
+fn success_fake_provider() {
+  use crate::locale::parse_unicode_locale_id;
+
+  let mut locale = parse_unicode_locale_id("xx").unwrap();
+  assert!(!locale.validate_with(&FakeProvider).is_valid());
+  locale.canonicalize_with(&FakeProvider);
+  assert_eq!("yy", format!("{}", locale));
+  locale.maximize_with(&FakeProvider);
+  assert_eq!("yy-Cyrl-KZ", format!("{}", locale));
+  assert!(locale.validate_with(&FakeProvider).is_valid());
+  locale.minimize_with(&FakeProvider);
+  assert_eq!("yy", format!("{}", locale));
+
+  // no data in the fake provider
+  let mut locale = parse_unicode_locale_id("iw").unwrap();
+  locale.canonicalize_with(&FakeProvider);
+  assert_eq!("iw", format!("{}", locale));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_embedded_provider() {
+  let provider = EmbeddedDataProvider;
+  assert_eq!(
+    Some("he"),
+    provider.lookup(DataTable::LanguageAliases, "iw")
+  );
+  assert_eq!(
+    Some("zh-Hant-TW"),
+    provider.lookup(DataTable::LikelySubtags, "zh-TW")
+  );
+  assert_eq!(
+    Some("en-001"),
+    provider.lookup(DataTable::ParentLocales, "en-AU")
+  );
+  assert_eq!(
+    Some("AT BE CH DE FR LI LU MC NL"),
+    provider.lookup(DataTable::RegionContainment, "155")
+  );
+  assert_eq!(Some("regular"), provider.lookup(DataTable::Scripts, "Latn"));
+  assert_eq!(None, provider.lookup(DataTable::Regions, "XQ"));
+}
@@ -0,0 +1,429 @@
+use crate::constants::{LEGACY_SEP, SEP};
+use crate::provider::{DataTable, LocaleDataProvider};
+use crate::subtags::{Value, Variant};
+
+use serde_json::{Map, Value as Json};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const ALIASES: &str = "supplemental/aliases.json";
+const LIKELY_SUBTAGS: &str = "supplemental/likelySubtags.json";
+const BCP47: &str = "bcp47";
+// the packages of cldr-json, when the directory is the whole release
+const PACKAGES: [&str; 2] = ["cldr-core", "cldr-bcp47"];
+
+const STATUS_REGULAR: &str = "regular";
+const STATUS_DEPRECATED: &str = "deprecated";
+
+/// An error loading CLDR JSON with [`JsonDataProvider::load`].
+#[derive(Debug)]
+pub enum JsonDataError {
+  /// A file that cannot be read.
+  Io(PathBuf, io::Error),
+  /// A file that is not JSON, or not in the format of CLDR JSON.
+  Format(PathBuf, String),
+}
+
+impl Display for JsonDataError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      JsonDataError::Io(path, err) => write!(f, "Cannot read {}: {}", path.display(), err),
+      JsonDataError::Format(path, message) => {
+        write!(f, "Invalid CLDR JSON {}: {}", path.display(), message)
+      }
+    }
+  }
+}
+
+impl Error for JsonDataError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      JsonDataError::Io(_, err) => Some(err),
+      JsonDataError::Format(_, _) => None,
+    }
+  }
+}
+
+/// The provider of the CLDR data loaded from a local directory of [CLDR JSON](https://github.com/unicode-org/cldr-json) at runtime, with the `json-data` feature.
+///
+/// The directory is either an unpacked release of CLDR JSON, with the `cldr-core` and `cldr-bcp47` packages, or a directory with the contents of both.
+/// The alias data is read from `supplemental/aliases.json`, the likely subtags from `supplemental/likelySubtags.json`, and the keys and types of the extensions from `bcp47/*.json`, which is optional.
+///
+/// CLDR JSON has no validity data of the languages, scripts, regions, variants, subdivisions and units.
+/// The tables that are not loaded are looked up in the fallback provider set with [`JsonDataProvider::with_fallback`], if any.
+///
+/// # Examples
+///
+/// ```no_run
+/// use unicode_locale_parser::{parse_locale_id, EmbeddedDataProvider, JsonDataProvider};
+///
+/// let provider = JsonDataProvider::load("cldr-json")
+///   .unwrap()
+///   .with_fallback(EmbeddedDataProvider);
+/// let mut locale = parse_locale_id("iw-u-ca-islamicc").unwrap();
+/// locale.canonicalize_with(&provider);
+/// assert_eq!("he-u-ca-islamic-civil", format!("{}", locale));
+/// ```
+#[derive(Default)]
+pub struct JsonDataProvider {
+  tables: HashMap<DataTable, HashMap<String, String>>,
+  fallback: Option<Box<dyn LocaleDataProvider + Send + Sync>>,
+}
+
+impl Debug for JsonDataProvider {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut tables = self.tables.keys().collect::<Vec<_>>();
+    tables.sort();
+    f.debug_struct("JsonDataProvider")
+      .field("tables", &tables)
+      .field("fallback", &self.fallback.is_some())
+      .finish()
+  }
+}
+
+impl LocaleDataProvider for JsonDataProvider {
+  fn lookup(&self, table: DataTable, key: &str) -> Option<&str> {
+    match self.tables.get(&table) {
+      Some(entries) => entries.get(key).map(String::as_str),
+      None => self.fallback.as_ref()?.lookup(table, key),
+    }
+  }
+}
+
+impl JsonDataProvider {
+  /// Load CLDR JSON from the given directory.
+  ///
+  /// # Errors
+  ///
+  /// This function returns a [`JsonDataError`] if `supplemental/aliases.json` or `supplemental/likelySubtags.json` cannot be read, or any file is not in the format of CLDR JSON.
+  pub fn load(dir: impl AsRef<Path>) -> Result<Self, JsonDataError> {
+    let dir = dir.as_ref();
+    let mut provider = JsonDataProvider::default();
+
+    let path = find(dir, ALIASES);
+    provider.load_aliases(&path, &read_json(&path)?)?;
+    let path = find(dir, LIKELY_SUBTAGS);
+    provider.load_likely_subtags(&path, &read_json(&path)?)?;
+
+    let bcp47 = find(dir, BCP47);
+    if bcp47.is_dir() {
+      let mut paths = fs::read_dir(&bcp47)
+        .map_err(|err| JsonDataError::Io(bcp47.clone(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+      paths.sort();
+      for table in [
+        DataTable::UnicodeKeys,
+        DataTable::UnicodeTypes,
+        DataTable::UnicodeKeywordAliases,
+        DataTable::Currencies,
+        DataTable::TransformedKeys,
+        DataTable::TransformedTypes,
+        DataTable::TransformedKeywordAliases,
+      ] {
+        provider.tables.entry(table).or_default();
+      }
+      for path in paths {
+        provider.load_bcp47(&path, &read_json(&path)?)?;
+      }
+    }
+    Ok(provider)
+  }
+
+  /// Set the provider to look up the tables that are not loaded.
+  pub fn with_fallback(
+    mut self,
+    fallback: impl LocaleDataProvider + Send + Sync + 'static,
+  ) -> Self {
+    self.fallback = Some(Box::new(fallback));
+    self
+  }
+
+  fn insert(&mut self, table: DataTable, key: &str, value: &str) {
+    self
+      .tables
+      .entry(table)
+      .or_default()
+      .insert(key.to_string(), value.to_string());
+  }
+
+  fn load_aliases(&mut self, path: &Path, json: &Json) -> Result<(), JsonDataError> {
+    let aliases = object(path, json, &["supplemental", "metadata", "alias"])?;
+    for (name, table) in [
+      ("languageAlias", DataTable::LanguageAliases),
+      ("scriptAlias", DataTable::ScriptAliases),
+      ("territoryAlias", DataTable::RegionAliases),
+      ("variantAlias", DataTable::VariantAliases),
+      ("subdivisionAlias", DataTable::SubdivisionAliases),
+    ] {
+      self.tables.entry(table).or_default();
+      let Some(rules) = aliases.get(name).and_then(Json::as_object) else {
+        continue;
+      };
+      for (rule, alias) in rules {
+        let Some(replacement) = alias.get("_replacement").and_then(Json::as_str) else {
+          continue;
+        };
+        let rule = rule.replace(LEGACY_SEP, &SEP.to_string());
+        let replacement = replacement.replace(LEGACY_SEP, &SEP.to_string());
+        // the variants replaced with the other subtags are in the language aliases
+        if table == DataTable::VariantAliases && Variant::try_from_str(&replacement).is_err() {
+          continue;
+        }
+        self.insert(table, &rule, &replacement);
+      }
+    }
+    Ok(())
+  }
+
+  fn load_likely_subtags(&mut self, path: &Path, json: &Json) -> Result<(), JsonDataError> {
+    let likely_subtags = object(path, json, &["supplemental", "likelySubtags"])?;
+    self.tables.entry(DataTable::LikelySubtags).or_default();
+    for (from, to) in likely_subtags {
+      if let Some(to) = to.as_str() {
+        self.insert(DataTable::LikelySubtags, from, to);
+      }
+    }
+    Ok(())
+  }
+
+  fn load_bcp47(&mut self, path: &Path, json: &Json) -> Result<(), JsonDataError> {
+    let keyword = object(path, json, &["keyword"])?;
+    for (extension, keys) in keyword {
+      let tables = match extension.as_str() {
+        "u" => (
+          DataTable::UnicodeKeys,
+          DataTable::UnicodeTypes,
+          DataTable::UnicodeKeywordAliases,
+        ),
+        "t" => (
+          DataTable::TransformedKeys,
+          DataTable::TransformedTypes,
+          DataTable::TransformedKeywordAliases,
+        ),
+        _ => continue,
+      };
+      for (key, types) in keys.as_object().into_iter().flatten() {
+        if let Some(types) = types.as_object() {
+          self.load_bcp47_key(key, types, tables);
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn load_bcp47_key(
+    &mut self,
+    key: &str,
+    types: &Map<String, Json>,
+    (keys, statuses, aliases): (DataTable, DataTable, DataTable),
+  ) {
+    let deprecated = is_true(types.get("_deprecated"));
+    let types = types
+      .iter()
+      .filter(|(name, _)| !name.starts_with('_'))
+      .collect::<Vec<_>>();
+    // the special types are in uppercase, e.g. `SCRIPT_CODE`
+    let special = |special: &str| types.iter().any(|(name, _)| *name == special);
+    let kind = if special("SCRIPT_CODE") {
+      "script"
+    } else if special("RG_KEY_VALUE") {
+      "region"
+    } else if special("SUBDIVISION_CODE") {
+      "subdivision"
+    } else if special("PRIVATE_USE") {
+      "private_use"
+    } else if special("CODEPOINTS") {
+      "codepoints"
+    } else if special("REORDER_CODE") {
+      "reorder"
+    } else if key == "cu" {
+      "currency"
+    } else {
+      "type"
+    };
+    if deprecated {
+      self.insert(keys, key, &format!("{} {}", kind, STATUS_DEPRECATED));
+    } else {
+      self.insert(keys, key, kind);
+    }
+    if !matches!(kind, "type" | "currency" | "reorder") {
+      return;
+    }
+
+    for (name, attributes) in types {
+      if !is_type(name) {
+        continue;
+      }
+      let status = if is_true(attributes.get("_deprecated")) {
+        STATUS_DEPRECATED
+      } else {
+        STATUS_REGULAR
+      };
+      if kind == "currency" {
+        self.insert(DataTable::Currencies, name, status);
+        continue;
+      }
+      let keyword = format!("{}{}{}", key, SEP, name);
+      self.insert(statuses, &keyword, status);
+      if let Some(preferred) = attributes.get("_preferred").and_then(Json::as_str) {
+        if is_type(preferred) {
+          self.insert(aliases, &keyword, preferred);
+        }
+      }
+      let names = attributes.get("_alias").and_then(Json::as_str);
+      for alias in names.into_iter().flat_map(|names| names.split(' ')) {
+        if alias != name && is_type(alias) {
+          self.insert(aliases, &format!("{}{}{}", key, SEP, alias), name);
+        }
+      }
+    }
+  }
+}
+
+/// Find the file in the directory, or in the packages of cldr-json in the directory.
+fn find(dir: &Path, file: &str) -> PathBuf {
+  PACKAGES
+    .iter()
+    .map(|package| dir.join(package).join(file))
+    .find(|path| path.exists())
+    .unwrap_or_else(|| dir.join(file))
+}
+
+fn read_json(path: &Path) -> Result<Json, JsonDataError> {
+  let text = fs::read_to_string(path).map_err(|err| JsonDataError::Io(path.to_path_buf(), err))?;
+  serde_json::from_str(&text)
+    .map_err(|err| JsonDataError::Format(path.to_path_buf(), err.to_string()))
+}
+
+fn object<'a>(
+  path: &Path,
+  json: &'a Json,
+  pointer: &[&str],
+) -> Result<&'a Map<String, Json>, JsonDataError> {
+  pointer
+    .iter()
+    .try_fold(json, |json, name| json.get(name))
+    .and_then(Json::as_object)
+    .ok_or_else(|| JsonDataError::Format(path.to_path_buf(), format!("no `{}`", pointer.join("."))))
+}
+
+fn is_true(json: Option<&Json>) -> bool {
+  json.and_then(Json::as_str) == Some("true")
+}
+
+/// Whether the type is an uvalue or a tvalue, i.e. 3 to 8 alphanumerics separated by `-`.
+fn is_type(name: &str) -> bool {
+  name
+    .split(SEP)
+    .all(|value| value.len() >= 3 && Value::try_from_str(value).is_ok())
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn fixture() -> JsonDataProvider {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/cldr-json");
+  JsonDataProvider::load(dir).unwrap()
+}
+
+#[test]
+fn success_load() {
+  use crate::locale::parse_unicode_locale_id;
+
+  let provider = fixture();
+  assert_eq!(
+    Some("he"),
+    provider.lookup(DataTable::LanguageAliases, "iw")
+  );
+  assert_eq!(
+    Some("und-alalc97"),
+    provider.lookup(DataTable::LanguageAliases, "und-hepburn-heploc")
+  );
+  assert_eq!(
+    Some("RU AM AZ BY EE GE KZ KG LV LT MD TJ TM UA UZ"),
+    provider.lookup(DataTable::RegionAliases, "SU")
+  );
+  assert_eq!(None, provider.lookup(DataTable::VariantAliases, "aaland"));
+  assert_eq!(
+    Some("zh-Hant-TW"),
+    provider.lookup(DataTable::LikelySubtags, "zh-TW")
+  );
+  assert_eq!(Some("type"), provider.lookup(DataTable::UnicodeKeys, "ca"));
+  assert_eq!(
+    Some("deprecated"),
+    provider.lookup(DataTable::UnicodeTypes, "ca-islamicc")
+  );
+  assert_eq!(
+    Some("islamic-civil"),
+    provider.lookup(DataTable::UnicodeKeywordAliases, "ca-islamicc")
+  );
+  assert_eq!(
+    Some("ethioaa"),
+    provider.lookup(DataTable::UnicodeKeywordAliases, "ca-ethiopic-amete-alem")
+  );
+  assert_eq!(
+    Some("private_use"),
+    provider.lookup(DataTable::TransformedKeys, "x0")
+  );
+  assert_eq!(
+    Some("prprname"),
+    provider.lookup(DataTable::TransformedKeywordAliases, "m0-names")
+  );
+
+  // the operations with the loaded data
+  let mut locale = parse_unicode_locale_id("iw-SU-u-ca-islamicc-t-m0-names").unwrap();
+  locale.canonicalize_with(&provider);
+  assert_eq!(
+    "he-RU-u-ca-islamic-civil-t-m0-prprname",
+    format!("{}", locale)
+  );
+  let mut locale = parse_unicode_locale_id("zh-TW").unwrap();
+  locale.maximize_with(&provider);
+  assert_eq!("zh-Hant-TW", format!("{}", locale));
+
+  // not in the loaded data
+  let mut locale = parse_unicode_locale_id("sh").unwrap();
+  locale.canonicalize_with(&provider);
+  assert_eq!("sh", format!("{}", locale));
+  assert_eq!(None, provider.lookup(DataTable::Languages, "en"));
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_load_with_fallback() {
+  use crate::locale::parse_unicode_locale_id;
+  use crate::provider::EmbeddedDataProvider;
+
+  let provider = fixture().with_fallback(EmbeddedDataProvider);
+  assert_eq!(Some("regular"), provider.lookup(DataTable::Languages, "en"));
+  // the loaded tables are not looked up in the fallback
+  assert_eq!(None, provider.lookup(DataTable::LanguageAliases, "sh"));
+
+  let locale = parse_unicode_locale_id("en-US-u-ca-gregory").unwrap();
+  assert!(locale.validate_with(&provider).is_valid());
+}
+
+#[test]
+fn fail_load() {
+  let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/cldr");
+  assert!(matches!(
+    JsonDataProvider::load(dir),
+    Err(JsonDataError::Io(_, _))
+  ));
+
+  let path = Path::new("aliases.json");
+  let mut provider = JsonDataProvider::default();
+  let json = serde_json::json!({ "supplemental": {} });
+  assert_eq!(
+    "Invalid CLDR JSON aliases.json: no `supplemental.metadata.alias`",
+    provider.load_aliases(path, &json).unwrap_err().to_string()
+  );
+}
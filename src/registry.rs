@@ -0,0 +1,674 @@
+use crate::constants::LANG_UND;
+use crate::lang::{parse_unicode_language_id, UnicodeLanguageIdentifier};
+use crate::subtags::{Language, Region, Script, Variant};
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const RECORD_SEP: &str = "%%";
+const RANGE_SEP: &str = "..";
+
+/// Enum representing the `Type` field of a record in the [IANA Language Subtag Registry](https://www.rfc-editor.org/rfc/rfc5646#section-3.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistryRecordType {
+  Language,
+  Extlang,
+  Script,
+  Region,
+  Variant,
+  /// A grandfathered tag, e.g. `i-klingon`.
+  Grandfathered,
+  /// A redundant tag, e.g. `zh-Hant`.
+  Redundant,
+}
+
+impl FromStr for RegistryRecordType {
+  type Err = ();
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "language" => Ok(RegistryRecordType::Language),
+      "extlang" => Ok(RegistryRecordType::Extlang),
+      "script" => Ok(RegistryRecordType::Script),
+      "region" => Ok(RegistryRecordType::Region),
+      "variant" => Ok(RegistryRecordType::Variant),
+      "grandfathered" => Ok(RegistryRecordType::Grandfathered),
+      "redundant" => Ok(RegistryRecordType::Redundant),
+      _ => Err(()),
+    }
+  }
+}
+
+/// Enum representing the `Scope` field of a language or an extlang record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistryScope {
+  /// A macrolanguage, e.g. `zh`.
+  Macrolanguage,
+  /// A collection of languages, e.g. `sla`.
+  Collection,
+  /// A special code, e.g. `und` or `mul`.
+  Special,
+  /// The private use codes, i.e. `qaa..qtz`.
+  PrivateUse,
+}
+
+impl FromStr for RegistryScope {
+  type Err = ();
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "macrolanguage" => Ok(RegistryScope::Macrolanguage),
+      "collection" => Ok(RegistryScope::Collection),
+      "special" => Ok(RegistryScope::Special),
+      "private-use" => Ok(RegistryScope::PrivateUse),
+      _ => Err(()),
+    }
+  }
+}
+
+/// A record of the IANA Language Subtag Registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistryRecord {
+  pub record_type: RegistryRecordType,
+  /// The `Subtag` field, or the `Tag` field of the grandfathered and redundant tags.
+  /// The private use subtags are a range, e.g. `qaa..qtz`.
+  pub subtag: String,
+  /// The `Description` fields, at least one.
+  pub descriptions: Vec<String>,
+  /// The `Added` field, e.g. `2005-10-16`.
+  pub added: String,
+  /// The `Deprecated` field, if the record is deprecated.
+  pub deprecated: Option<String>,
+  pub preferred_value: Option<String>,
+  pub suppress_script: Option<Script>,
+  pub macrolanguage: Option<Language>,
+  /// The `Prefix` fields of an extlang or a variant.
+  pub prefixes: Vec<String>,
+  pub scope: Option<RegistryScope>,
+  pub comments: Vec<String>,
+}
+
+impl RegistryRecord {
+  /// Returns the first description, e.g. `English`.
+  pub fn description(&self) -> &str {
+    &self.descriptions[0]
+  }
+
+  /// Returns whether the record is deprecated.
+  pub fn is_deprecated(&self) -> bool {
+    self.deprecated.is_some()
+  }
+}
+
+/// Enum representing an error parsing the IANA Language Subtag Registry, with the line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+  /// A line that is neither a field nor a continuation of a field.
+  InvalidField(usize),
+  /// A record without a required field, e.g. `Type` or `Added`.
+  MissingField(usize, &'static str),
+  /// A field whose value is not valid, e.g. an unknown `Type`.
+  InvalidValue(usize, String),
+}
+
+impl Display for RegistryError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      RegistryError::InvalidField(line) => write!(f, "Invalid field at line {}", line),
+      RegistryError::MissingField(line, field) => {
+        write!(f, "Missing `{}` in the record at line {}", field, line)
+      }
+      RegistryError::InvalidValue(line, field) => {
+        write!(f, "Invalid value of `{}` at line {}", field, line)
+      }
+    }
+  }
+}
+
+impl Error for RegistryError {}
+
+/// The [IANA Language Subtag Registry](https://www.iana.org/assignments/language-subtag-registry), parsed with [`parse_language_subtag_registry`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LanguageSubtagRegistry {
+  file_date: String,
+  records: Vec<RegistryRecord>,
+  // the indexes of the records by the type and the subtag in lowercase
+  index: HashMap<(RegistryRecordType, String), usize>,
+  // the ranges of the private use subtags in lowercase
+  ranges: Vec<(RegistryRecordType, String, String, usize)>,
+}
+
+impl FromStr for LanguageSubtagRegistry {
+  type Err = RegistryError;
+
+  fn from_str(source: &str) -> Result<Self, Self::Err> {
+    parse_language_subtag_registry(source)
+  }
+}
+
+impl LanguageSubtagRegistry {
+  /// Returns the `File-Date` of the registry, e.g. `2023-10-16`.
+  pub fn file_date(&self) -> &str {
+    &self.file_date
+  }
+
+  /// Returns all the records, in the order of the registry.
+  pub fn records(&self) -> &[RegistryRecord] {
+    &self.records
+  }
+
+  /// Find the record of the language subtag. The empty language is `und`.
+  pub fn language(&self, language: &Language) -> Option<&RegistryRecord> {
+    match language.as_str() {
+      "" => self.find(RegistryRecordType::Language, LANG_UND),
+      language => self.find(RegistryRecordType::Language, language),
+    }
+  }
+
+  /// Find the record of the extlang subtag, e.g. `yue`.
+  pub fn extlang(&self, extlang: &str) -> Option<&RegistryRecord> {
+    self.find(RegistryRecordType::Extlang, extlang)
+  }
+
+  /// Find the record of the script subtag.
+  pub fn script(&self, script: &Script) -> Option<&RegistryRecord> {
+    self.find(RegistryRecordType::Script, script)
+  }
+
+  /// Find the record of the region subtag.
+  pub fn region(&self, region: &Region) -> Option<&RegistryRecord> {
+    self.find(RegistryRecordType::Region, region)
+  }
+
+  /// Find the record of the variant subtag.
+  pub fn variant(&self, variant: &Variant) -> Option<&RegistryRecord> {
+    self.find(RegistryRecordType::Variant, variant)
+  }
+
+  /// Find the record of the grandfathered or redundant tag, e.g. `i-klingon`.
+  pub fn tag(&self, tag: &str) -> Option<&RegistryRecord> {
+    self
+      .find(RegistryRecordType::Grandfathered, tag)
+      .or_else(|| self.find(RegistryRecordType::Redundant, tag))
+  }
+
+  /// Find the variants of the language identifier that do not match any of their `Prefix` fields.
+  ///
+  /// A prefix matches if the language identifier has all the subtags of the prefix, regardless of the order of the variants, e.g. `biske` with the prefix `sl-rozaj` matches `sl-biske-rozaj`.
+  /// The variants without `Prefix` fields, and the ones not in the registry, match any language identifier.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{parse_language_id, parse_language_subtag_registry};
+  ///
+  /// let registry = parse_language_subtag_registry(
+  ///   "File-Date: 2023-10-16
+  /// %%
+  /// Type: variant
+  /// Subtag: hepburn
+  /// Description: Hepburn romanization
+  /// Added: 2009-10-01
+  /// Prefix: ja-Latn",
+  /// )
+  /// .unwrap();
+  /// let lang = parse_language_id("ja-Latn-hepburn").unwrap();
+  /// assert!(registry.unprefixed_variants(&lang).is_empty());
+  /// let lang = parse_language_id("en-hepburn").unwrap();
+  /// assert_eq!(vec!["hepburn"], registry.unprefixed_variants(&lang));
+  /// ```
+  pub fn unprefixed_variants(&self, lang: &UnicodeLanguageIdentifier) -> Vec<Variant> {
+    lang
+      .variants
+      .iter()
+      .flatten()
+      .filter(|variant| {
+        self.variant(variant).is_some_and(|record| {
+          !record.prefixes.is_empty()
+            && !record
+              .prefixes
+              .iter()
+              .filter_map(|prefix| parse_unicode_language_id(prefix).ok())
+              .any(|prefix| matches_prefix(lang, &prefix))
+        })
+      })
+      .copied()
+      .collect()
+  }
+
+  fn find(&self, record_type: RegistryRecordType, subtag: &str) -> Option<&RegistryRecord> {
+    let subtag = subtag.to_ascii_lowercase();
+    let index = self.index.get(&(record_type, subtag.clone())).or_else(|| {
+      self
+        .ranges
+        .iter()
+        .find(|(t, start, end, _)| {
+          *t == record_type && subtag.len() == start.len() && *start <= subtag && subtag <= *end
+        })
+        .map(|(_, _, _, index)| index)
+    })?;
+    self.records.get(*index)
+  }
+
+  fn push(&mut self, record: RegistryRecord) {
+    let index = self.records.len();
+    let subtag = record.subtag.to_ascii_lowercase();
+    match subtag.split_once(RANGE_SEP) {
+      Some((start, end)) => self.ranges.push((
+        record.record_type,
+        start.to_string(),
+        end.to_string(),
+        index,
+      )),
+      None => {
+        self.index.insert((record.record_type, subtag), index);
+      }
+    }
+    self.records.push(record);
+  }
+}
+
+/// Parse the given string as the [IANA Language Subtag Registry](https://www.iana.org/assignments/language-subtag-registry).
+///
+/// The registry is in the record-jar format, as [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646#section-3.1.1) defines:
+/// the records are separated by `%%`, the fields are `Name: body`, and the lines beginning with whitespace continue the previous field.
+/// The non-ASCII characters escaped like `&#x00C5;` are unescaped.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_language_id, parse_language_subtag_registry};
+///
+/// let registry = parse_language_subtag_registry(
+///   "File-Date: 2023-10-16
+/// %%
+/// Type: language
+/// Subtag: en
+/// Description: English
+/// Added: 2005-10-16
+/// Suppress-Script: Latn",
+/// )
+/// .unwrap();
+/// assert_eq!("2023-10-16", registry.file_date());
+///
+/// let lang = parse_language_id("en-US").unwrap();
+/// let record = registry.language(&lang.language).unwrap();
+/// assert_eq!("English", record.description());
+/// assert_eq!(Some("Latn"), record.suppress_script.as_deref());
+/// ```
+///
+/// # Errors
+///
+/// This function returns a [`RegistryError`] with the line number if:
+///
+/// - a line is neither a field nor a continuation ([`RegistryError::InvalidField`]).
+/// - a record has no `Type`, `Subtag` or `Tag`, `Description` or `Added` field ([`RegistryError::MissingField`]).
+/// - a `Type`, `Scope`, `Suppress-Script` or `Macrolanguage` field is not valid ([`RegistryError::InvalidValue`]).
+pub fn parse_language_subtag_registry(
+  source: &str,
+) -> Result<LanguageSubtagRegistry, RegistryError> {
+  let mut registry = LanguageSubtagRegistry::default();
+  let mut fields: Vec<(usize, &str, String)> = vec![];
+  let mut start = 1;
+  let mut first = true;
+
+  for (index, line) in source.lines().enumerate() {
+    let number = index + 1;
+    if line.trim_end() == RECORD_SEP {
+      flush(&mut registry, &fields, start, first)?;
+      fields.clear();
+      start = number + 1;
+      first = false;
+    } else if line.starts_with([' ', '\t']) {
+      // a folded line
+      let (_, _, body) = fields
+        .last_mut()
+        .ok_or(RegistryError::InvalidField(number))?;
+      body.push(' ');
+      body.push_str(&unescape(line.trim()));
+    } else if !line.trim().is_empty() {
+      let (name, body) = line
+        .split_once(':')
+        .ok_or(RegistryError::InvalidField(number))?;
+      fields.push((number, name.trim(), unescape(body.trim())));
+    }
+  }
+  flush(&mut registry, &fields, start, first)?;
+  Ok(registry)
+}
+
+fn flush(
+  registry: &mut LanguageSubtagRegistry,
+  fields: &[(usize, &str, String)],
+  start: usize,
+  first: bool,
+) -> Result<(), RegistryError> {
+  if fields.is_empty() {
+    return Ok(());
+  }
+  let values = |name: &'static str| {
+    fields
+      .iter()
+      .filter(move |(_, n, _)| n.eq_ignore_ascii_case(name))
+      .map(|(number, _, body)| (*number, body.clone()))
+  };
+  let value = |name: &'static str| values(name).next();
+  let required = |name: &'static str| value(name).ok_or(RegistryError::MissingField(start, name));
+
+  if first {
+    registry.file_date = required("File-Date")?.1;
+    return Ok(());
+  }
+
+  let (number, record_type) = required("Type")?;
+  let record_type = record_type
+    .parse::<RegistryRecordType>()
+    .map_err(|_| RegistryError::InvalidValue(number, "Type".to_string()))?;
+  let subtag = match record_type {
+    RegistryRecordType::Grandfathered | RegistryRecordType::Redundant => required("Tag")?,
+    _ => required("Subtag")?,
+  };
+  let descriptions = values("Description")
+    .map(|(_, body)| body)
+    .collect::<Vec<_>>();
+  if descriptions.is_empty() {
+    return Err(RegistryError::MissingField(start, "Description"));
+  }
+
+  registry.push(RegistryRecord {
+    record_type,
+    subtag: subtag.1,
+    descriptions,
+    added: required("Added")?.1,
+    deprecated: value("Deprecated").map(|(_, body)| body),
+    preferred_value: value("Preferred-Value").map(|(_, body)| body),
+    suppress_script: typed(
+      value("Suppress-Script"),
+      "Suppress-Script",
+      Script::try_from_str,
+    )?,
+    macrolanguage: typed(
+      value("Macrolanguage"),
+      "Macrolanguage",
+      Language::try_from_str,
+    )?,
+    prefixes: values("Prefix").map(|(_, body)| body).collect(),
+    scope: typed(value("Scope"), "Scope", RegistryScope::from_str)?,
+    comments: values("Comments").map(|(_, body)| body).collect(),
+  });
+  Ok(())
+}
+
+fn typed<T, E>(
+  field: Option<(usize, String)>,
+  name: &str,
+  parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<Option<T>, RegistryError> {
+  field
+    .map(|(number, body)| {
+      parse(&body).map_err(|_| RegistryError::InvalidValue(number, name.to_string()))
+    })
+    .transpose()
+}
+
+/// Unescape the characters like `&#x00C5;`.
+fn unescape(body: &str) -> String {
+  let mut unescaped = String::with_capacity(body.len());
+  let mut rest = body;
+  while let Some(start) = rest.find("&#x") {
+    unescaped.push_str(&rest[..start]);
+    let escaped = &rest[start + 3..];
+    let c = escaped.split_once(';').and_then(|(hex, _)| {
+      u32::from_str_radix(hex, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .map(|c| (c, hex.len() + 1))
+    });
+    match c {
+      Some((c, len)) => {
+        unescaped.push(c);
+        rest = &escaped[len..];
+      }
+      None => {
+        unescaped.push_str("&#x");
+        rest = escaped;
+      }
+    }
+  }
+  unescaped.push_str(rest);
+  unescaped
+}
+
+/// Whether the language identifier has all the subtags of the prefix.
+fn matches_prefix(lang: &UnicodeLanguageIdentifier, prefix: &UnicodeLanguageIdentifier) -> bool {
+  let eq = |a: &str, b: &str| a.eq_ignore_ascii_case(b);
+  eq(&lang.language, &prefix.language)
+    && prefix
+      .script
+      .map_or(true, |script| lang.script.is_some_and(|s| eq(&s, &script)))
+    && prefix
+      .region
+      .map_or(true, |region| lang.region.is_some_and(|r| eq(&r, &region)))
+    && prefix
+      .variants
+      .iter()
+      .flatten()
+      .all(|variant| lang.variants.iter().flatten().any(|v| eq(v, variant)))
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn fixture() -> LanguageSubtagRegistry {
+  let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/corpus/iana/language-subtag-registry");
+  parse_language_subtag_registry(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn success_parse_language_subtag_registry() {
+  let registry = fixture();
+  assert_eq!("2023-10-16", registry.file_date());
+  assert_eq!(22, registry.records().len());
+
+  let record = &registry.records()[2];
+  assert_eq!(RegistryRecordType::Language, record.record_type);
+  assert_eq!("iw", record.subtag);
+  assert_eq!(vec!["Hebrew"], record.descriptions);
+  assert_eq!("2005-10-16", record.added);
+  assert_eq!(Some("1989-01-01"), record.deprecated.as_deref());
+  assert_eq!(Some("he"), record.preferred_value.as_deref());
+  assert_eq!(Some("Hebr"), record.suppress_script.as_deref());
+  assert!(record.is_deprecated());
+
+  // unescaped
+  let record = registry
+    .region(&Region::try_from_str("AX").unwrap())
+    .unwrap();
+  assert_eq!("\u{00C5}land Islands", record.description());
+  // folded
+  let record = registry
+    .variant(&Variant::try_from_str("biske").unwrap())
+    .unwrap();
+  assert_eq!(
+    vec!["The dialect of San Giorgio/Bila is one of the four major local dialects of Resian"],
+    record.comments
+  );
+  assert_eq!(
+    vec![
+      "The San Giorgio dialect of Resian",
+      "The Bila dialect of Resian"
+    ],
+    record.descriptions
+  );
+
+  // from `str`
+  let registry: LanguageSubtagRegistry = "File-Date: 2023-10-16\n%%\nType: region\nSubtag: US\nDescription: United States\nAdded: 2005-10-16\n%%\n".parse().unwrap();
+  assert_eq!(1, registry.records().len());
+  let registry = parse_language_subtag_registry("").unwrap();
+  assert_eq!("", registry.file_date());
+}
+
+#[test]
+fn fail_parse_language_subtag_registry() {
+  let registry = |records: &str| {
+    parse_language_subtag_registry(&format!("File-Date: 2023-10-16\n%%\n{}", records))
+  };
+
+  assert_eq!(
+    Err(RegistryError::InvalidField(1)),
+    parse_language_subtag_registry("  continued")
+  );
+  assert_eq!(
+    Err(RegistryError::InvalidField(5)),
+    registry("Type: language\nSubtag: en\nDescription\n")
+  );
+  assert_eq!(
+    Err(RegistryError::MissingField(3, "Added")),
+    registry("Type: language\nSubtag: en\nDescription: English\n")
+  );
+  assert_eq!(
+    Err(RegistryError::MissingField(3, "Tag")),
+    registry("Type: grandfathered\nSubtag: i-klingon\nDescription: Klingon\nAdded: 1999-05-26\n")
+  );
+  assert_eq!(
+    Err(RegistryError::MissingField(1, "File-Date")),
+    parse_language_subtag_registry("Type: language\n%%\n")
+  );
+  assert_eq!(
+    Err(RegistryError::InvalidValue(3, "Type".to_string())),
+    registry("Type: dialect\nSubtag: en\nDescription: English\nAdded: 2005-10-16\n")
+  );
+  assert_eq!(
+    "Invalid value of `Suppress-Script` at line 7",
+    registry("Type: language\nSubtag: en\nDescription: English\nAdded: 2005-10-16\nSuppress-Script: Latin\n")
+      .unwrap_err()
+      .to_string()
+  );
+}
+
+#[test]
+fn success_lookup() {
+  let registry = fixture();
+
+  let lang = parse_unicode_language_id("zh-Hant-US-hepburn").unwrap();
+  assert_eq!(
+    "Chinese",
+    registry.language(&lang.language).unwrap().description()
+  );
+  assert_eq!(
+    Some(RegistryScope::Macrolanguage),
+    registry.language(&lang.language).unwrap().scope
+  );
+  assert_eq!(
+    "Han (Traditional variant)",
+    registry
+      .script(&lang.script.unwrap())
+      .unwrap()
+      .description()
+  );
+  assert_eq!(
+    "United States",
+    registry
+      .region(&lang.region.unwrap())
+      .unwrap()
+      .description()
+  );
+  assert_eq!(
+    "Hepburn romanization",
+    registry
+      .variant(&lang.variants.unwrap()[0])
+      .unwrap()
+      .description()
+  );
+
+  // case insensitive
+  let lang = parse_unicode_language_id("EN-latn-us").unwrap();
+  assert_eq!(
+    "English",
+    registry.language(&lang.language).unwrap().description()
+  );
+  assert_eq!(
+    "Latin",
+    registry
+      .script(&lang.script.unwrap())
+      .unwrap()
+      .description()
+  );
+
+  // `und` and the private use ranges
+  assert_eq!(
+    "Undetermined",
+    registry
+      .language(&Language::default())
+      .unwrap()
+      .description()
+  );
+  let lang = parse_unicode_language_id("qfz-Qaba").unwrap();
+  assert_eq!(
+    "Private use",
+    registry.language(&lang.language).unwrap().description()
+  );
+  assert_eq!(
+    "Private use",
+    registry
+      .script(&lang.script.unwrap())
+      .unwrap()
+      .description()
+  );
+  assert_eq!(
+    None,
+    registry.language(&Language::try_from_str("qua").unwrap())
+  );
+
+  // extlang, grandfathered and redundant tags
+  let record = registry.extlang("yue").unwrap();
+  assert_eq!(RegistryRecordType::Extlang, record.record_type);
+  assert_eq!(Some("zh"), record.macrolanguage.as_deref());
+  assert_eq!(vec!["zh"], record.prefixes);
+  assert_eq!(
+    Some("tlh"),
+    registry
+      .tag("I-Klingon")
+      .unwrap()
+      .preferred_value
+      .as_deref()
+  );
+  assert_eq!(
+    RegistryRecordType::Redundant,
+    registry.tag("zh-Hant").unwrap().record_type
+  );
+  assert_eq!(None, registry.tag("en"));
+  assert_eq!(None, registry.region(&Region::try_from_str("JP").unwrap()));
+}
+
+#[test]
+fn success_unprefixed_variants() {
+  let registry = fixture();
+  let unprefixed = |source: &str| {
+    registry
+      .unprefixed_variants(&parse_unicode_language_id(source).unwrap())
+      .iter()
+      .map(|variant| variant.to_string())
+      .collect::<Vec<_>>()
+  };
+
+  assert!(unprefixed("sl-rozaj").is_empty());
+  assert!(unprefixed("sl-rozaj-biske-1994").is_empty());
+  // the order of the variants
+  assert!(unprefixed("sl-1994-biske-rozaj").is_empty());
+  assert!(unprefixed("sl-IT-rozaj").is_empty());
+  assert!(unprefixed("ja-Latn-JP-hepburn").is_empty());
+  // no prefix, or not in the registry
+  assert!(unprefixed("en-US").is_empty());
+  assert!(unprefixed("en-fonipa").is_empty());
+
+  assert_eq!(vec!["rozaj"], unprefixed("en-rozaj"));
+  assert_eq!(vec!["biske"], unprefixed("sl-biske"));
+  assert_eq!(vec!["hepburn"], unprefixed("ja-hepburn"));
+  assert_eq!(vec!["1994", "biske"], unprefixed("de-1994-biske"));
+}
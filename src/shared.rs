@@ -1,7 +1,321 @@
 use crate::constants::{LEGACY_SEP, SEP};
+use crate::errors::{ParserError, SyntaxError};
+use crate::options::{CasePolicy, Casing, GrammarProfile, ParseOptions};
+use crate::subtags::Subtag;
+use crate::tokenizer::TokenKind;
 
-use std::str::Split;
+use std::ops::{Deref, Range};
 
-pub fn split_str(s: &str) -> Split<'_, impl Fn(char) -> bool> {
-  s.split(|c| c == SEP || c == LEGACY_SEP)
+/// A subtag with its byte offset in the source, which the errors take as their span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fragment<'a> {
+  pub start: usize,
+  pub text: &'a str,
+}
+
+impl<'a> Fragment<'a> {
+  pub fn new(start: usize, text: &'a str) -> Self {
+    Fragment { start, text }
+  }
+
+  pub fn end(&self) -> usize {
+    self.start + self.text.len()
+  }
+
+  pub fn span(&self) -> Range<usize> {
+    self.start..self.end()
+  }
+}
+
+impl Deref for Fragment<'_> {
+  type Target = str;
+
+  fn deref(&self) -> &str {
+    self.text
+  }
+}
+
+/// Split the given string into the subtags with their offsets, including the empty ones.
+pub fn split_str(s: &str) -> impl Iterator<Item = Fragment<'_>> {
+  let mut start = 0;
+  s.split(|c| c == SEP || c == LEGACY_SEP).map(move |text| {
+    let fragment = Fragment::new(start, text);
+    start += text.len() + 1;
+    fragment
+  })
+}
+
+/// Split the given string into the subtags with their offsets, without the empty or non-ASCII ones.
+///
+/// The skipped subtags are reported by [`check_subtags`].
+pub fn split_subtags(s: &str) -> impl Iterator<Item = Fragment<'_>> {
+  split_str(s).filter(|subtag| !subtag.is_empty() && subtag.is_ascii())
+}
+
+pub fn is_sep(b: &u8) -> bool {
+  *b == SEP as u8 || *b == LEGACY_SEP as u8
+}
+
+pub fn check_subtags(s: &str, ctx: &mut ParserContext) -> Result<(), SyntaxError> {
+  let options = ctx.options;
+
+  // the limits are checked first, without looking into the subtags
+  if options.max_length.is_some_and(|max| s.len() > max) {
+    return Err(SyntaxError::at(
+      ParserError::TooLong,
+      Fragment::new(0, ""),
+      None,
+    ));
+  }
+  if let Some(max) = options.max_subtags {
+    let over = if max == 0 {
+      Some(0)
+    } else {
+      s.bytes()
+        .enumerate()
+        .filter(|(_, b)| is_sep(b))
+        .nth(max - 1)
+        .map(|(i, _)| i + 1)
+    };
+    if let Some(i) = over {
+      return Err(SyntaxError::at(
+        ParserError::TooManySubtags,
+        Fragment::new(i, ""),
+        None,
+      ));
+    }
+  }
+
+  if !options.allow_legacy_separator || options.profile == GrammarProfile::Bcp47 {
+    for (i, b) in s.bytes().enumerate() {
+      if b == LEGACY_SEP as u8 {
+        ctx.recover(SyntaxError::at(
+          ParserError::LegacySeparator,
+          Fragment::new(i, &s[i..i + 1]),
+          None,
+        ))?;
+      }
+    }
+  }
+
+  let mut iter = split_str(s).enumerate().peekable();
+  while let Some((index, subtag)) = iter.next() {
+    if !subtag.is_ascii() {
+      ctx.recover(SyntaxError::at(
+        ParserError::NonAsciiCharacter,
+        subtag,
+        None,
+      ))?;
+    } else if subtag.is_empty() && options.profile != GrammarProfile::Icu {
+      let kind = if index == 0 {
+        ParserError::LeadingSeparator
+      } else if iter.peek().is_none() {
+        ParserError::TrailingSeparator
+      } else {
+        ParserError::EmptySubtag
+      };
+      ctx.recover(SyntaxError::at(kind, subtag, None))?;
+    }
+  }
+  Ok(())
+}
+
+/// The state shared by the parsers while parsing an identifier.
+#[derive(Debug, Default)]
+pub struct ParserContext {
+  /// Whether to apply the strict well-formedness rules.
+  pub strict: bool,
+  /// Whether to recover from the errors, instead of failing on the first one.
+  pub lenient: bool,
+  /// The options given by the user.
+  pub options: ParseOptions,
+  /// The errors recovered from in the lenient mode.
+  pub diagnostics: Vec<SyntaxError>,
+  /// The kinds and the spans of the accepted subtags in the source order, collected only if `Some`, e.g. for the syntax tree.
+  pub tokens: Option<Vec<(TokenKind, Range<usize>)>>,
+}
+
+impl ParserContext {
+  pub fn new(strict: bool) -> Self {
+    ParserContext {
+      strict,
+      ..Default::default()
+    }
+  }
+
+  pub fn with_options(options: &ParseOptions) -> Self {
+    ParserContext {
+      strict: options.profile == GrammarProfile::Bcp47,
+      options: *options,
+      ..Default::default()
+    }
+  }
+
+  pub fn lenient() -> Self {
+    ParserContext {
+      lenient: true,
+      ..Default::default()
+    }
+  }
+
+  /// Fail with the given error, or record it in the lenient mode so that the caller can skip the offending subtag.
+  pub fn recover(&mut self, err: SyntaxError) -> Result<(), SyntaxError> {
+    if self.lenient {
+      self.diagnostics.push(err);
+      Ok(())
+    } else {
+      Err(err)
+    }
+  }
+
+  /// Record the kind of the accepted subtag, if the tokens are collected.
+  pub fn token(&mut self, kind: TokenKind, subtag: Fragment) {
+    if let Some(tokens) = self.tokens.as_mut() {
+      tokens.push((kind, subtag.span()));
+    }
+  }
+
+  /// Make a subtag of the given type, applying the case policy of the options.
+  pub fn subtag<T: Subtag>(&mut self, subtag: Fragment, casing: Casing) -> Result<T, SyntaxError> {
+    let value = T::from_subtag(&subtag).map_err(|e| SyntaxError::at(e, subtag, None))?;
+    match self.options.case {
+      CasePolicy::Preserve => Ok(value),
+      CasePolicy::Normalize => Ok(value.with_casing(casing)),
+      CasePolicy::Reject => {
+        if !casing.is_applied(&subtag) {
+          self.recover(SyntaxError::at(ParserError::NonCanonicalCase, subtag, None))?;
+        }
+        Ok(value)
+      }
+    }
+  }
+
+  /// Make an owned subtag, applying the case policy of the options.
+  pub fn cased(&mut self, subtag: Fragment, casing: Casing) -> Result<String, SyntaxError> {
+    match self.options.case {
+      CasePolicy::Preserve => Ok(String::from(subtag.text)),
+      CasePolicy::Normalize => Ok(casing.apply(&subtag)),
+      CasePolicy::Reject => {
+        if !casing.is_applied(&subtag) {
+          self.recover(SyntaxError::at(ParserError::NonCanonicalCase, subtag, None))?;
+        }
+        Ok(String::from(subtag.text))
+      }
+    }
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_check_subtags() {
+  assert!(check_subtags("en-US", &mut ParserContext::default()).is_ok());
+  assert!(check_subtags("en_US", &mut ParserContext::default()).is_ok());
+  assert!(check_subtags("en", &mut ParserContext::default()).is_ok());
+}
+
+#[test]
+fn fail_check_subtags() {
+  // empty subtag
+  assert_eq!(
+    ParserError::EmptySubtag,
+    check_subtags("en--US", &mut ParserContext::default()).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::EmptySubtag,
+    check_subtags("en-_US", &mut ParserContext::default()).unwrap_err()
+  );
+
+  // leading separator
+  assert_eq!(
+    ParserError::LeadingSeparator,
+    check_subtags("-en", &mut ParserContext::default()).unwrap_err()
+  );
+  assert_eq!(
+    ParserError::LeadingSeparator,
+    check_subtags("-", &mut ParserContext::default()).unwrap_err()
+  );
+
+  // trailing separator
+  assert_eq!(
+    ParserError::TrailingSeparator,
+    check_subtags("en_", &mut ParserContext::default()).unwrap_err()
+  );
+
+  // non-ASCII character
+  let source = "en-ÜS";
+  let err = check_subtags(source, &mut ParserContext::default())
+    .unwrap_err()
+    .locate(source);
+  assert_eq!(ParserError::NonAsciiCharacter, err);
+  assert_eq!(3..6, err.span());
+  assert_eq!("ÜS", err.fragment());
+}
+
+#[test]
+fn lenient_check_subtags() {
+  let mut ctx = ParserContext::lenient();
+  assert!(check_subtags("-en--ÜS-", &mut ctx).is_ok());
+  assert_eq!(
+    vec![
+      ParserError::LeadingSeparator,
+      ParserError::EmptySubtag,
+      ParserError::NonAsciiCharacter,
+      ParserError::TrailingSeparator
+    ],
+    ctx
+      .diagnostics
+      .iter()
+      .map(|e| e.kind())
+      .collect::<Vec<ParserError>>()
+  );
+  assert_eq!(
+    vec![Fragment::new(1, "en")],
+    split_subtags("-en--ÜS-").collect::<Vec<Fragment>>()
+  );
+}
+
+#[test]
+fn fail_check_subtags_with_options() {
+  // too long
+  let mut ctx = ParserContext::with_options(&ParseOptions {
+    max_length: Some(5),
+    ..Default::default()
+  });
+  assert!(check_subtags("en-US", &mut ctx).is_ok());
+  assert_eq!(
+    ParserError::TooLong,
+    check_subtags("en-GB-oed", &mut ctx).unwrap_err()
+  );
+
+  // too many subtags
+  let mut ctx = ParserContext::with_options(&ParseOptions {
+    max_subtags: Some(2),
+    ..Default::default()
+  });
+  assert!(check_subtags("en-US", &mut ctx).is_ok());
+  let source = "en-GB-oed";
+  let err = check_subtags(source, &mut ctx).unwrap_err().locate(source);
+  assert_eq!(ParserError::TooManySubtags, err.kind());
+  assert_eq!(6..6, err.span());
+  assert_eq!(2, err.index());
+
+  // legacy separator
+  let mut ctx = ParserContext::with_options(&ParseOptions {
+    allow_legacy_separator: false,
+    ..Default::default()
+  });
+  assert_eq!(
+    ParserError::LegacySeparator,
+    check_subtags("en_US", &mut ctx).unwrap_err()
+  );
+
+  // empty subtags are tolerated in the ICU profile
+  let mut ctx = ParserContext::with_options(&ParseOptions {
+    profile: GrammarProfile::Icu,
+    ..Default::default()
+  });
+  assert!(check_subtags("en__POSIX", &mut ctx).is_ok());
 }
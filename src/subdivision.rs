@@ -1,4 +1,5 @@
-use crate::errors::ParserError;
+use crate::errors::{ParserError, Production, SyntaxError};
+use crate::shared::Fragment;
 
 use std::fmt::{self};
 use std::str;
@@ -18,7 +19,7 @@ impl fmt::Display for UnicodeSubdivisionIdentifier {
 }
 
 impl FromStr for UnicodeSubdivisionIdentifier {
-  type Err = ParserError;
+  type Err = SyntaxError;
 
   fn from_str(source: &str) -> Result<Self, Self::Err> {
     parse_unicode_subdivision_id(source)
@@ -41,12 +42,30 @@ impl FromStr for UnicodeSubdivisionIdentifier {
 ///
 /// # Errors
 ///
-/// This function returns an error in the following cases:
+/// This function returns a [`SyntaxError`] with the position of the offending subtag, whose [`SyntaxError::kind`] is:
 ///
 /// - [`ParserError::Missing`] if the given subdivision id is empty.
 /// - [`ParserError::InvalidSubdivision`] if the given subdivision id is not a valid subdivision identifier.
+/// - [`ParserError::NonAsciiCharacter`] if the given subdivision id has a non-ASCII character.
 pub fn parse_unicode_subdivision_id(
   subdivision_id: &str,
+) -> Result<UnicodeSubdivisionIdentifier, SyntaxError> {
+  parse_unicode_subdivision_id_from_str(subdivision_id).map_err(|e| {
+    // the subdivision id has no separators, so the whole id is at fault
+    let err = match e {
+      ParserError::Missing => SyntaxError::new(e),
+      _ => SyntaxError::at(
+        e,
+        Fragment::new(0, subdivision_id),
+        Some(Production::Subdivision),
+      ),
+    };
+    err.locate(subdivision_id)
+  })
+}
+
+fn parse_unicode_subdivision_id_from_str(
+  subdivision_id: &str,
 ) -> Result<UnicodeSubdivisionIdentifier, ParserError> {
   // unicode_subdivision_id
   // https://unicode.org/reports/tr35/#unicode_subdivision_id
@@ -56,6 +75,9 @@ pub fn parse_unicode_subdivision_id(
   if chunks.is_empty() {
     return Err(ParserError::Missing);
   }
+  if !chunks.is_ascii() {
+    return Err(ParserError::NonAsciiCharacter);
+  }
 
   let len = chunks.len();
   if !(2..=7).contains(&len) {
@@ -68,8 +90,9 @@ pub fn parse_unicode_subdivision_id(
     Err(_) => return Err(ParserError::Unexpected),
   };
 
+  // unicode_subdivision_suffix = alphanum{1,4}
   let suffix_len = len - region_index;
-  if !(3..7).contains(&suffix_len)
+  if !(1..=4).contains(&suffix_len)
     || !chunks[region_index..]
       .iter()
       .all(|b: &u8| b.is_ascii_alphanumeric())
@@ -88,9 +111,9 @@ pub fn parse_unicode_subdivision_id(
 }
 
 fn region_index(chunks: &[u8]) -> Result<usize, ParserError> {
-  if chunks[0..2].iter().all(|b| b.is_ascii_alphabetic()) {
+  if chunks.len() >= 2 && chunks[0..2].iter().all(|b| b.is_ascii_alphabetic()) {
     Ok(2)
-  } else if chunks[0..3].iter().all(|b| b.is_ascii_digit()) {
+  } else if chunks.len() >= 3 && chunks[0..3].iter().all(|b| b.is_ascii_digit()) {
     Ok(3)
   } else {
     Err(ParserError::InvalidSubdivision)
@@ -108,6 +131,16 @@ fn success_parse_unicode_subdivision_id() {
   assert_eq!("us", subdivision.region);
   assert_eq!("sct", subdivision.suffix);
 
+  // 1 character suffix
+  let subdivision = parse_unicode_subdivision_id("fr2").unwrap();
+  assert_eq!("fr", subdivision.region);
+  assert_eq!("2", subdivision.suffix);
+
+  // 2 characters suffix
+  let subdivision = parse_unicode_subdivision_id("usca").unwrap();
+  assert_eq!("us", subdivision.region);
+  assert_eq!("ca", subdivision.suffix);
+
   // digit region + suffix
   let subdivision = parse_unicode_subdivision_id("123abcd").unwrap();
   assert_eq!("123", subdivision.region);
@@ -151,6 +184,12 @@ fn fail_parse_unicode_subdivision_id() {
     parse_unicode_subdivision_id("12312345").unwrap_err()
   );
 
+  // 5 characters suffix
+  assert_eq!(
+    ParserError::InvalidSubdivision,
+    parse_unicode_subdivision_id("usabcde").unwrap_err()
+  );
+
   // invalid region
   assert_eq!(
     ParserError::InvalidSubdivision,
@@ -162,4 +201,21 @@ fn fail_parse_unicode_subdivision_id() {
     ParserError::InvalidSubdivision,
     parse_unicode_subdivision_id("ab{}").unwrap_err()
   );
+
+  // too short for a digit region
+  assert_eq!(
+    ParserError::InvalidSubdivision,
+    parse_unicode_subdivision_id("1b").unwrap_err()
+  );
+
+  // non-ASCII character
+  assert_eq!(
+    ParserError::NonAsciiCharacter,
+    parse_unicode_subdivision_id("usé").unwrap_err()
+  );
+
+  // position
+  let err = parse_unicode_subdivision_id("ab{}").unwrap_err();
+  assert_eq!(0..4, err.span());
+  assert_eq!(Some(Production::Subdivision), err.expected());
 }
@@ -1,5 +1,12 @@
 use crate::constants::{LANG_EMPTY, LANG_ROOT, LANG_UND};
 use crate::errors::ParserError;
+use crate::options::Casing;
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
 
 pub fn is_language_subtag(subtag: &[u8]) -> bool {
   let len = subtag.len();
@@ -11,7 +18,7 @@ pub fn language_subtag(subtag: &str) -> Result<&str, ParserError> {
   // https://unicode.org/reports/tr35/#unicode_language_subtag
 
   // 'root' is a special case
-  if matches!(subtag, LANG_ROOT) {
+  if subtag.eq_ignore_ascii_case(LANG_ROOT) {
     return Ok(LANG_EMPTY);
   }
 
@@ -19,7 +26,7 @@ pub fn language_subtag(subtag: &str) -> Result<&str, ParserError> {
     return Err(ParserError::InvalidLanguage);
   }
 
-  if matches!(subtag, LANG_UND) {
+  if subtag.eq_ignore_ascii_case(LANG_UND) {
     Ok(LANG_EMPTY)
   } else {
     Ok(subtag)
@@ -77,6 +84,269 @@ pub fn variant_subtag(subtag: &str) -> Result<&str, ParserError> {
   }
 }
 
+pub fn is_ukey(subtag: &[u8]) -> bool {
+  // ukey
+  // https://unicode.org/reports/tr35/#ukey
+  subtag.len() == 2 && subtag[0].is_ascii_alphanumeric() && subtag[1].is_ascii_alphabetic()
+}
+
+pub fn is_tkey(subtag: &[u8]) -> bool {
+  // tkey
+  // https://unicode.org/reports/tr35/#tkey
+  subtag.len() == 2 && subtag[0].is_ascii_alphabetic() && subtag[1].is_ascii_digit()
+}
+
+fn is_key(subtag: &[u8]) -> bool {
+  is_ukey(subtag) || is_tkey(subtag)
+}
+
+fn is_value(subtag: &[u8]) -> bool {
+  // attribute, uvalue, tvalue, and the values of other and private use extensions
+  (1..=8).contains(&subtag.len()) && subtag.iter().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_language(subtag: &[u8]) -> bool {
+  // empty for 'und' and 'root'
+  subtag.is_empty() || is_language_subtag(subtag)
+}
+
+fn normalize_language(subtag: &str) -> Result<&str, ParserError> {
+  // the same as the parsers, which make 'und' and 'root' the empty language
+  if subtag.is_empty() {
+    Ok(LANG_EMPTY)
+  } else {
+    language_subtag(subtag)
+  }
+}
+
+/// Fixed-size ASCII storage of a subtag, padded with zeros, so that it orders as the `str`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct TinyAscii<const N: usize>([u8; N]);
+
+impl<const N: usize> TinyAscii<N> {
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() > N || !bytes.is_ascii() || bytes.contains(&0) {
+      return None;
+    }
+    let mut array = [0; N];
+    array[..bytes.len()].copy_from_slice(bytes);
+    Some(TinyAscii(array))
+  }
+
+  fn truncated(bytes: &[u8]) -> Self {
+    let mut array = [0; N];
+    for (a, b) in array.iter_mut().zip(bytes) {
+      *a = if b.is_ascii() { *b } else { b'?' };
+    }
+    TinyAscii(array)
+  }
+
+  fn as_str(&self) -> &str {
+    let len = self.0.iter().position(|b| *b == 0).unwrap_or(N);
+    std::str::from_utf8(&self.0[..len]).unwrap_or_default()
+  }
+
+  fn with_casing(mut self, casing: Casing) -> Self {
+    for (i, b) in self.0.iter_mut().enumerate() {
+      match casing {
+        Casing::Lower => b.make_ascii_lowercase(),
+        Casing::Upper => b.make_ascii_uppercase(),
+        Casing::Title if i == 0 => b.make_ascii_uppercase(),
+        Casing::Title => b.make_ascii_lowercase(),
+      }
+    }
+    self
+  }
+}
+
+/// The subtag types that the parsers make with [`ParserContext::subtag`](crate::shared::ParserContext::subtag).
+pub trait Subtag: Sized {
+  fn from_subtag(subtag: &str) -> Result<Self, ParserError>;
+  fn with_casing(self, casing: Casing) -> Self;
+}
+
+macro_rules! subtag_type {
+  ($(#[$doc:meta])* $name:ident, $size:literal, $validate:ident, $error:expr $(, $normalize:ident)?) => {
+    $(#[$doc])*
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct $name(TinyAscii<$size>);
+
+    impl $name {
+      /// Make the subtag from the given string, validating it.
+      pub fn try_from_str(subtag: &str) -> Result<Self, ParserError> {
+        $(let subtag = $normalize(subtag)?;)?
+        if !$validate(subtag.as_bytes()) {
+          return Err($error);
+        }
+        TinyAscii::from_bytes(subtag.as_bytes())
+          .map($name)
+          .ok_or($error)
+      }
+
+      /// Make the subtag from the string already validated by the parsers, without validation.
+      pub(crate) fn from_str_unchecked(subtag: &str) -> Self {
+        $name(TinyAscii::truncated(subtag.as_bytes()))
+      }
+
+      /// Returns the subtag as `&str`.
+      pub fn as_str(&self) -> &str {
+        self.0.as_str()
+      }
+    }
+
+    impl Subtag for $name {
+      fn from_subtag(subtag: &str) -> Result<Self, ParserError> {
+        $name::try_from_str(subtag)
+      }
+
+      fn with_casing(self, casing: Casing) -> Self {
+        $name(self.0.with_casing(casing))
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = ParserError;
+
+      fn from_str(source: &str) -> Result<Self, Self::Err> {
+        $name::try_from_str(source)
+      }
+    }
+
+    impl Deref for $name {
+      type Target = str;
+
+      fn deref(&self) -> &str {
+        self.as_str()
+      }
+    }
+
+    impl AsRef<str> for $name {
+      fn as_ref(&self) -> &str {
+        self.as_str()
+      }
+    }
+
+    // `Eq`, `Ord` and `Hash` are the same as `str`, so it can be looked up with `&str` in the maps
+    impl Borrow<str> for $name {
+      fn borrow(&self) -> &str {
+        self.as_str()
+      }
+    }
+
+    impl Hash for $name {
+      fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+      }
+    }
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+      }
+    }
+
+    impl fmt::Debug for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+      }
+    }
+
+    impl PartialEq<str> for $name {
+      fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+      }
+    }
+
+    impl PartialEq<&str> for $name {
+      fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+      }
+    }
+
+    impl PartialEq<$name> for str {
+      fn eq(&self, other: &$name) -> bool {
+        self == other.as_str()
+      }
+    }
+
+    impl PartialEq<$name> for &str {
+      fn eq(&self, other: &$name) -> bool {
+        *self == other.as_str()
+      }
+    }
+  };
+}
+
+subtag_type!(
+  /// A [`unicode_language_subtag`](https://unicode.org/reports/tr35/#unicode_language_subtag), which is empty for `und` and `root`.
+  Language,
+  8,
+  is_language,
+  ParserError::InvalidLanguage,
+  normalize_language
+);
+
+subtag_type!(
+  /// A [`unicode_script_subtag`](https://unicode.org/reports/tr35/#unicode_script_subtag).
+  Script,
+  4,
+  is_scritp_subtag,
+  ParserError::InvalidSubtag
+);
+
+subtag_type!(
+  /// A [`unicode_region_subtag`](https://unicode.org/reports/tr35/#unicode_region_subtag).
+  Region,
+  3,
+  is_region_subtag,
+  ParserError::InvalidSubtag
+);
+
+subtag_type!(
+  /// A [`unicode_variant_subtag`](https://unicode.org/reports/tr35/#unicode_variant_subtag).
+  Variant,
+  8,
+  is_variant_subtag,
+  ParserError::InvalidSubtag
+);
+
+subtag_type!(
+  /// A [`ukey`](https://unicode.org/reports/tr35/#ukey), which is an alphanumeric and a letter,
+  /// or a [`tkey`](https://unicode.org/reports/tr35/#tkey), which is a letter and a digit.
+  Key,
+  2,
+  is_key,
+  ParserError::InvalidSubtag
+);
+
+subtag_type!(
+  /// A value of the extensions, i.e. an attribute, an [`uvalue`](https://unicode.org/reports/tr35/#uvalue), a [`tvalue`](https://unicode.org/reports/tr35/#tvalue),
+  /// or a value of other and private use extensions, which is 1 to 8 alphanumerics.
+  Value,
+  8,
+  is_value,
+  ParserError::InvalidSubtag
+);
+
+/// A subtag of any type in the given casing, which is compared, ordered and hashed without allocation for the canonical equivalence.
+///
+/// All subtags fit in 8 bytes, so that the longer ones are truncated.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct CanonicalSubtag(TinyAscii<8>);
+
+impl CanonicalSubtag {
+  pub(crate) fn new(subtag: &str, casing: Casing) -> Self {
+    CanonicalSubtag(TinyAscii::truncated(subtag.as_bytes()).with_casing(casing))
+  }
+}
+
+impl Default for Language {
+  /// Returns the empty language, i.e. `und`.
+  fn default() -> Self {
+    Language(TinyAscii([0; 8]))
+  }
+}
+
 /**
  * Unit tests
  */
@@ -94,6 +364,10 @@ fn success_language_subtag() {
 
   // 'und'
   assert_eq!(LANG_EMPTY, language_subtag("und").unwrap());
+
+  // 'root' and 'und' in any case
+  assert_eq!(LANG_EMPTY, language_subtag("Root").unwrap());
+  assert_eq!(LANG_EMPTY, language_subtag("UND").unwrap());
 }
 
 #[test]
@@ -211,3 +485,94 @@ fn fail_variant_subtag() {
     variant_subtag("aBCD").unwrap_err()
   );
 }
+
+#[test]
+fn success_subtag_types() {
+  let language = Language::try_from_str("en").unwrap();
+  assert_eq!("en", language);
+  assert_eq!("", Language::default().as_str());
+
+  // 'und' and 'root' are the empty language, as the parsers make
+  for source in ["und", "UND", "root", "Root"] {
+    let language = Language::try_from_str(source).unwrap();
+    assert_eq!(Language::default(), language, "{}", source);
+    assert_eq!(
+      crate::lang::parse_unicode_language_id(source)
+        .unwrap()
+        .language,
+      language,
+      "{}",
+      source
+    );
+    assert_eq!(Ok(language), source.parse::<Language>());
+  }
+  assert_eq!("Latn", Script::try_from_str("Latn").unwrap().as_str());
+  assert_eq!("419", Region::try_from_str("419").unwrap());
+  assert_eq!("abcdefgh", Variant::try_from_str("abcdefgh").unwrap());
+  assert_eq!("k1", Key::try_from_str("k1").unwrap());
+  assert_eq!("ca", Key::try_from_str("ca").unwrap());
+  assert_eq!("0a", Key::try_from_str("0a").unwrap());
+  assert_eq!("x", Value::try_from_str("x").unwrap());
+
+  // casing
+  assert_eq!(
+    "Latn",
+    Script::from_subtag("lATN")
+      .unwrap()
+      .with_casing(Casing::Title)
+  );
+  assert_eq!(
+    "US",
+    Region::from_subtag("us")
+      .unwrap()
+      .with_casing(Casing::Upper)
+  );
+
+  // ordered and hashed as str
+  let mut values: Vec<Value> = ["gregory", "buddhist", "b", "ca"]
+    .iter()
+    .map(|v| v.parse().unwrap())
+    .collect();
+  values.sort();
+  assert_eq!(vec!["b", "buddhist", "ca", "gregory"], values);
+  let map: std::collections::BTreeMap<Key, u8> = [(Key::try_from_str("ca").unwrap(), 1)].into();
+  assert_eq!(Some(&1), map.get("ca"));
+  assert_eq!(format!("{:?}", "en"), format!("{:?}", language));
+}
+
+#[test]
+fn fail_subtag_types() {
+  assert_eq!(
+    ParserError::InvalidLanguage,
+    Language::try_from_str("food").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Script::try_from_str("Latin").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Region::try_from_str("USA").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Variant::try_from_str("abc").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Key::try_from_str("k").unwrap_err()
+  );
+  // neither ukey nor tkey
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Key::try_from_str("12").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Key::try_from_str("1!").unwrap_err()
+  );
+  assert_eq!(
+    ParserError::InvalidSubtag,
+    Value::try_from_str("toolongvalue").unwrap_err()
+  );
+}
@@ -0,0 +1,468 @@
+use crate::errors::SyntaxError;
+use crate::lang::UnicodeLanguageIdentifier;
+use crate::locale::{parse_unicode_locale_id_from_str, UnicodeLocaleIdentifier};
+use crate::shared::ParserContext;
+use crate::tokenizer::TokenKind;
+
+use std::fmt;
+use std::ops::Range;
+
+/// Enum representing the kind of a [`SyntaxNode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxKind {
+  /// The root node of [`unicode_locale_id`](https://unicode.org/reports/tr35/#unicode_locale_id)
+  LocaleId,
+  /// A node of [`unicode_language_id`](https://unicode.org/reports/tr35/#unicode_language_id), also used for the `tlang` of transformed extensions
+  LanguageId,
+  /// A node of [`unicode_locale_extensions`](https://unicode.org/reports/tr35/#unicode_locale_extensions)
+  UnicodeLocaleExtensions,
+  /// A node of [`transformed_extensions`](https://unicode.org/reports/tr35/#transformed_extensions)
+  TransformedExtensions,
+  /// A node of [`other_extensions`](https://unicode.org/reports/tr35/#other_extensions)
+  OtherExtensions,
+  /// A node of [`pu_extensions`](https://unicode.org/reports/tr35/#pu_extensions)
+  PuExtensions,
+  /// A node of a [`keyword`](https://unicode.org/reports/tr35/#keyword), with the ukey and the uvalues
+  Keyword,
+  /// A node of a [`tfield`](https://unicode.org/reports/tr35/#tfield), with the tkey and the tvalues
+  TField,
+  /// A `-` or `_` separator token
+  Separator,
+  /// A language subtag token
+  Language,
+  /// A script subtag token
+  Script,
+  /// A region subtag token
+  Region,
+  /// A variant subtag token
+  Variant,
+  /// An extension singleton token
+  Singleton,
+  /// An attribute token of unicode locale extensions
+  Attribute,
+  /// A ukey token
+  UKey,
+  /// An uvalue token
+  UValue,
+  /// A tkey token
+  TKey,
+  /// A tvalue token
+  TValue,
+  /// A value token of other extensions
+  OtherValue,
+  /// A value token of private use extensions
+  PuValue,
+}
+
+/// A node of [`SyntaxTree`], with the span of the source it covers.
+///
+/// The nodes without children are the tokens, i.e. the subtags and the separators as written in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxNode {
+  kind: SyntaxKind,
+  span: Range<usize>,
+  children: Vec<SyntaxNode>,
+}
+
+impl SyntaxNode {
+  fn token(kind: SyntaxKind, span: Range<usize>) -> Self {
+    SyntaxNode {
+      kind,
+      span,
+      children: vec![],
+    }
+  }
+
+  fn node(kind: SyntaxKind, children: Vec<SyntaxNode>) -> Self {
+    let start = children.first().map_or(0, |c| c.span.start);
+    let end = children.last().map_or(start, |c| c.span.end);
+    SyntaxNode {
+      kind,
+      span: start..end,
+      children,
+    }
+  }
+
+  /// Returns the kind of the node.
+  pub fn kind(&self) -> SyntaxKind {
+    self.kind
+  }
+
+  /// Returns the byte range of the node in the source.
+  pub fn span(&self) -> Range<usize> {
+    self.span.clone()
+  }
+
+  /// Returns the child nodes, including the separator tokens between them.
+  pub fn children(&self) -> &[SyntaxNode] {
+    &self.children
+  }
+
+  /// Returns whether the node is a token.
+  pub fn is_token(&self) -> bool {
+    self.children.is_empty()
+  }
+
+  /// Returns the tokens of the node in the source order.
+  pub fn tokens(&self) -> Vec<&SyntaxNode> {
+    let mut tokens = vec![];
+    self.collect(&mut |node| {
+      if node.is_token() {
+        tokens.push(node);
+      }
+    });
+    tokens
+  }
+
+  /// Returns the nodes of the given kind under the node, including itself, in the source order.
+  pub fn find_all(&self, kind: SyntaxKind) -> Vec<&SyntaxNode> {
+    let mut nodes = vec![];
+    self.collect(&mut |node| {
+      if node.kind == kind {
+        nodes.push(node);
+      }
+    });
+    nodes
+  }
+
+  fn collect<'a>(&'a self, f: &mut impl FnMut(&'a SyntaxNode)) {
+    f(self);
+    for child in &self.children {
+      child.collect(f);
+    }
+  }
+}
+
+/// A lossless syntax tree of an Unicode Locale Identifier.
+///
+/// Unlike [`UnicodeLocaleIdentifier`], the tree keeps the source as written, with the original casing, separators and order of the subtags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxTree<'a> {
+  source: &'a str,
+  root: SyntaxNode,
+  locale: UnicodeLocaleIdentifier,
+}
+
+impl<'a> SyntaxTree<'a> {
+  /// Returns the source of the tree.
+  pub fn source(&self) -> &'a str {
+    self.source
+  }
+
+  /// Returns the root node, whose kind is [`SyntaxKind::LocaleId`].
+  pub fn root(&self) -> &SyntaxNode {
+    &self.root
+  }
+
+  /// Returns the source text of the given node.
+  pub fn text(&self, node: &SyntaxNode) -> &'a str {
+    self.source.get(node.span()).unwrap_or_default()
+  }
+
+  /// Convert the tree to an [`UnicodeLocaleIdentifier`], which the parser built together with the tree.
+  pub fn to_locale_id(&self) -> UnicodeLocaleIdentifier {
+    self.locale.clone()
+  }
+
+  /// Convert the language identifier of the tree to an [`UnicodeLanguageIdentifier`].
+  pub fn to_language_id(&self) -> UnicodeLanguageIdentifier {
+    self.locale.language.clone()
+  }
+}
+
+impl fmt::Display for SyntaxTree<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for token in self.root.tokens() {
+      f.write_str(self.text(token))?;
+    }
+    Ok(())
+  }
+}
+
+/// Parse the given string as a lossless syntax tree of an Unicode Locale Identifier.
+///
+/// The tree is built from the subtags that [`parse_locale_id`](crate::parse_locale_id) accepts, so it has the same grammar, and is built only for the well-formed source.
+/// The tree round-trips the source byte-for-byte, and maps each node to its span.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{parse_syntax_tree, SyntaxKind};
+///
+/// let tree = parse_syntax_tree("EN_us-U-CA-buddhist").unwrap();
+/// assert_eq!("EN_us-U-CA-buddhist", format!("{}", tree));
+///
+/// let ukey = tree.root().find_all(SyntaxKind::UKey)[0];
+/// assert_eq!(8..10, ukey.span());
+/// assert_eq!("CA", tree.text(ukey));
+///
+/// let locale = tree.to_locale_id();
+/// assert_eq!("EN-us-u-CA-buddhist", format!("{}", locale));
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_locale_id`](crate::parse_locale_id).
+pub fn parse_syntax_tree(source: &str) -> Result<SyntaxTree<'_>, SyntaxError> {
+  let mut ctx = ParserContext {
+    tokens: Some(vec![]),
+    ..Default::default()
+  };
+  let locale = parse_unicode_locale_id_from_str(source, &mut ctx).map_err(|e| e.locate(source))?;
+
+  let tokens = ctx.tokens.unwrap_or_default();
+  let mut builder = Builder { tokens, pos: 0 };
+  let root = builder.locale_id();
+  Ok(SyntaxTree {
+    source,
+    root,
+    locale,
+  })
+}
+
+/// Group the tokens recorded by the parsers into the nodes, in the source order.
+struct Builder {
+  tokens: Vec<(TokenKind, Range<usize>)>,
+  pos: usize,
+}
+
+impl Builder {
+  fn peek(&self) -> Option<TokenKind> {
+    self.tokens.get(self.pos).map(|(kind, _)| *kind)
+  }
+
+  /// Push the node starting at the token of `start`, with the separator before it.
+  fn attach(&self, children: &mut Vec<SyntaxNode>, start: usize, node: SyntaxNode) {
+    if !children.is_empty() && start > 0 {
+      let span = self.tokens[start - 1].1.end..self.tokens[start].1.start;
+      children.push(SyntaxNode::token(SyntaxKind::Separator, span));
+    }
+    children.push(node);
+  }
+
+  fn token(&mut self, children: &mut Vec<SyntaxNode>, kind: SyntaxKind) {
+    let start = self.pos;
+    if let Some((_, span)) = self.tokens.get(start) {
+      let token = SyntaxNode::token(kind, span.clone());
+      self.pos += 1;
+      self.attach(children, start, token);
+    }
+  }
+
+  fn locale_id(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    let language = self.language_id();
+    self.attach(&mut children, 0, language);
+
+    while let Some(TokenKind::Singleton(singleton)) = self.peek() {
+      let start = self.pos;
+      let extensions = match singleton {
+        'u' => self.unicode_locale_extensions(),
+        't' => self.transformed_extensions(),
+        'x' => self.pu_extensions(),
+        _ => self.other_extensions(),
+      };
+      self.attach(&mut children, start, extensions);
+    }
+    SyntaxNode::node(SyntaxKind::LocaleId, children)
+  }
+
+  fn language_id(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    self.token(&mut children, SyntaxKind::Language);
+    while let Some(kind) = self.peek() {
+      let kind = match kind {
+        TokenKind::Script => SyntaxKind::Script,
+        TokenKind::Region => SyntaxKind::Region,
+        TokenKind::Variant => SyntaxKind::Variant,
+        _ => break,
+      };
+      self.token(&mut children, kind);
+    }
+    SyntaxNode::node(SyntaxKind::LanguageId, children)
+  }
+
+  fn unicode_locale_extensions(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    self.token(&mut children, SyntaxKind::Singleton);
+    let mut keyword: Option<(usize, Vec<SyntaxNode>)> = None;
+    while let Some(kind) = self.peek() {
+      match (kind, keyword.as_mut()) {
+        (TokenKind::Attribute, _) => self.token(&mut children, SyntaxKind::Attribute),
+        (TokenKind::UKey, _) => {
+          self.flush(&mut children, keyword.take(), SyntaxKind::Keyword);
+          let mut field = vec![];
+          let start = self.pos;
+          self.token(&mut field, SyntaxKind::UKey);
+          keyword = Some((start, field));
+        }
+        (TokenKind::UValue, Some((_, field))) => self.token(field, SyntaxKind::UValue),
+        _ => break,
+      }
+    }
+    self.flush(&mut children, keyword, SyntaxKind::Keyword);
+    SyntaxNode::node(SyntaxKind::UnicodeLocaleExtensions, children)
+  }
+
+  fn transformed_extensions(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    self.token(&mut children, SyntaxKind::Singleton);
+    if self.peek() == Some(TokenKind::Language) {
+      let start = self.pos;
+      let tlang = self.language_id();
+      self.attach(&mut children, start, tlang);
+    }
+    let mut tfield: Option<(usize, Vec<SyntaxNode>)> = None;
+    while let Some(kind) = self.peek() {
+      match (kind, tfield.as_mut()) {
+        (TokenKind::TKey, _) => {
+          self.flush(&mut children, tfield.take(), SyntaxKind::TField);
+          let mut field = vec![];
+          let start = self.pos;
+          self.token(&mut field, SyntaxKind::TKey);
+          tfield = Some((start, field));
+        }
+        (TokenKind::TValue, Some((_, field))) => self.token(field, SyntaxKind::TValue),
+        _ => break,
+      }
+    }
+    self.flush(&mut children, tfield, SyntaxKind::TField);
+    SyntaxNode::node(SyntaxKind::TransformedExtensions, children)
+  }
+
+  fn other_extensions(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    self.token(&mut children, SyntaxKind::Singleton);
+    while self.peek() == Some(TokenKind::OtherValue) {
+      self.token(&mut children, SyntaxKind::OtherValue);
+    }
+    SyntaxNode::node(SyntaxKind::OtherExtensions, children)
+  }
+
+  fn pu_extensions(&mut self) -> SyntaxNode {
+    let mut children = vec![];
+    self.token(&mut children, SyntaxKind::Singleton);
+    while self.peek() == Some(TokenKind::PrivateUse) {
+      self.token(&mut children, SyntaxKind::PuValue);
+    }
+    SyntaxNode::node(SyntaxKind::PuExtensions, children)
+  }
+
+  fn flush(
+    &self,
+    children: &mut Vec<SyntaxNode>,
+    field: Option<(usize, Vec<SyntaxNode>)>,
+    kind: SyntaxKind,
+  ) {
+    if let Some((start, field)) = field {
+      self.attach(children, start, SyntaxNode::node(kind, field));
+    }
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[test]
+fn success_parse_syntax_tree() {
+  // round trip
+  for source in [
+    "en",
+    "root",
+    "EN_latn-us-Macos",
+    "ja-JP-U-attr1-CA-Japanese-nu-jpanfin-t-EN_us-T0-Und-h0-hybrid-a-VUE-x-Foo-1",
+    "und-x-a-b",
+  ] {
+    let tree = parse_syntax_tree(source).unwrap();
+    assert_eq!(source, format!("{}", tree));
+    assert_eq!(0..source.len(), tree.root().span());
+  }
+
+  // structure
+  let tree = parse_syntax_tree("de_DE-u-co-PHONEBK-t-en-m0-ungegn").unwrap();
+  let root = tree.root();
+  assert_eq!(SyntaxKind::LocaleId, root.kind());
+  let kinds = root
+    .children()
+    .iter()
+    .map(|c| c.kind())
+    .collect::<Vec<SyntaxKind>>();
+  assert_eq!(
+    vec![
+      SyntaxKind::LanguageId,
+      SyntaxKind::Separator,
+      SyntaxKind::UnicodeLocaleExtensions,
+      SyntaxKind::Separator,
+      SyntaxKind::TransformedExtensions,
+    ],
+    kinds
+  );
+  assert_eq!("_", tree.text(&root.children()[0].children()[1]));
+  assert_eq!("u-co-PHONEBK", tree.text(&root.children()[2]));
+  let keyword = root.find_all(SyntaxKind::Keyword)[0];
+  assert_eq!("co-PHONEBK", tree.text(keyword));
+  assert_eq!(11..18, root.find_all(SyntaxKind::UValue)[0].span());
+  let tlang = root.children()[4].find_all(SyntaxKind::LanguageId)[0];
+  assert_eq!("en", tree.text(tlang));
+  assert_eq!("m0-ungegn", tree.text(root.find_all(SyntaxKind::TField)[0]));
+
+  // same grammar as the parser, e.g. a 3 letters tlang and a tkey right after the singleton
+  for (source, tlang, tfields) in [
+    ("en-t-fil-PH", Some("fil-PH"), vec![]),
+    ("ja-t-und-m0-ungegn", Some("und"), vec!["m0-ungegn"]),
+    (
+      "en-t-h0-hybrid-m0-ungegn-bgn",
+      None,
+      vec!["h0-hybrid", "m0-ungegn-bgn"],
+    ),
+  ] {
+    let tree = parse_syntax_tree(source).unwrap();
+    let t = tree.root().find_all(SyntaxKind::TransformedExtensions)[0];
+    assert_eq!(
+      tlang,
+      t.find_all(SyntaxKind::LanguageId)
+        .first()
+        .map(|node| tree.text(node)),
+      "{}",
+      source
+    );
+    assert_eq!(
+      tfields,
+      t.find_all(SyntaxKind::TField)
+        .iter()
+        .map(|node| tree.text(node))
+        .collect::<Vec<_>>(),
+      "{}",
+      source
+    );
+  }
+
+  // tokens
+  let tokens = root.children()[0].tokens();
+  assert_eq!(
+    ["de", "_", "DE"],
+    tokens
+      .iter()
+      .map(|t| tree.text(t))
+      .collect::<Vec<&str>>()
+      .as_slice()
+  );
+
+  // conversion to the typed structs
+  assert_eq!(
+    "de-DE-u-co-PHONEBK-t-en-m0-ungegn",
+    format!("{}", tree.to_locale_id())
+  );
+  assert_eq!("de-DE", format!("{}", tree.to_language_id()));
+}
+
+#[allow(unused_imports)] // for unit tests
+use crate::errors::ParserError;
+
+#[test]
+fn fail_parse_syntax_tree() {
+  assert_eq!(ParserError::Missing, parse_syntax_tree("").unwrap_err());
+  let err = parse_syntax_tree("en-US-u-ca-").unwrap_err();
+  assert_eq!(ParserError::TrailingSeparator, err.kind());
+}
@@ -0,0 +1,463 @@
+use crate::constants::{LEGACY_SEP, SEP};
+use crate::shared::is_sep;
+use crate::subtags::{
+  is_language_subtag, is_tkey, is_ukey, language_subtag, region_subtag, script_subtag,
+  variant_subtag,
+};
+
+use std::ops::Range;
+
+/// Enum representing the kind of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+  /// A language subtag, also in the `tlang` of transformed extensions
+  Language,
+  /// A script subtag
+  Script,
+  /// A region subtag
+  Region,
+  /// A variant subtag
+  Variant,
+  /// An extension singleton, in lowercase
+  Singleton(char),
+  /// An attribute of unicode locale extensions
+  Attribute,
+  /// A ukey of unicode locale extensions
+  UKey,
+  /// An uvalue of unicode locale extensions
+  UValue,
+  /// A tkey of transformed extensions
+  TKey,
+  /// A tvalue of transformed extensions
+  TValue,
+  /// A value of other extensions
+  OtherValue,
+  /// A value of private use extensions
+  PrivateUse,
+  /// A subtag that is not valid at its position
+  Invalid,
+}
+
+/// A subtag classified by [`Tokenizer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+  pub kind: TokenKind,
+  /// The byte range of the subtag in the source
+  pub span: Range<usize>,
+  /// The subtag as written in the source
+  pub text: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+  Start,
+  // the position in `unicode_language_id`, as `parse_unicode_language_id_from_iter`
+  Language(u8),
+  UnicodeLocale { keyword: bool },
+  Transformed { tlang: Option<u8>, tfield: bool },
+  Other,
+  Pu,
+}
+
+/// A streaming tokenizer of Unicode Locale Identifiers, that never allocates.
+///
+/// The tokenizer does not fail: the subtags that are not valid at their position, including the empty ones, are [`TokenKind::Invalid`],
+/// and the following subtags are classified as if they were skipped.
+#[derive(Debug, Clone)]
+pub struct Tokenizer<'a> {
+  source: &'a str,
+  pos: usize,
+  done: bool,
+  state: State,
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+  type Item = Token<'a>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let rest = self.source.get(self.pos..).unwrap_or_default();
+    let len = rest.bytes().position(|b| is_sep(&b)).unwrap_or(rest.len());
+    let span = self.pos..self.pos + len;
+    if span.end >= self.source.len() {
+      self.done = true;
+    } else {
+      self.pos = span.end + 1;
+    }
+    let text = self.source.get(span.clone()).unwrap_or_default();
+    let kind = self.state.classify(text);
+    Some(Token { kind, span, text })
+  }
+}
+
+impl State {
+  fn classify(&mut self, subtag: &str) -> TokenKind {
+    let bytes = subtag.as_bytes();
+    let len = bytes.len();
+    let is_alphanumeric = bytes.iter().all(|b| b.is_ascii_alphanumeric());
+
+    // the singletons start an extension, except in private use extensions
+    if len == 1 && *self != State::Pu && *self != State::Start {
+      return self.singleton(bytes[0]);
+    }
+
+    match *self {
+      State::Start => {
+        *self = State::Language(1);
+        if language_subtag(subtag).is_ok() {
+          TokenKind::Language
+        } else {
+          TokenKind::Invalid
+        }
+      }
+      State::Language(current) => match language_subtag_kind(subtag, current) {
+        Some((kind, next)) => {
+          *self = State::Language(next);
+          kind
+        }
+        None => TokenKind::Invalid,
+      },
+      State::UnicodeLocale { keyword } => {
+        if is_ukey(bytes) {
+          *self = State::UnicodeLocale { keyword: true };
+          TokenKind::UKey
+        } else if (3..=8).contains(&len) && is_alphanumeric {
+          if keyword {
+            TokenKind::UValue
+          } else {
+            TokenKind::Attribute
+          }
+        } else {
+          TokenKind::Invalid
+        }
+      }
+      State::Transformed { tlang, tfield } => {
+        if tlang.is_none() && !tfield && is_language_subtag(bytes) {
+          *self = State::Transformed {
+            tlang: Some(1),
+            tfield,
+          };
+          return TokenKind::Language;
+        }
+        if let Some(current) = tlang.filter(|_| !tfield) {
+          if let Some((kind, next)) = language_subtag_kind(subtag, current) {
+            *self = State::Transformed {
+              tlang: Some(next),
+              tfield,
+            };
+            return kind;
+          }
+        }
+        if is_tkey(bytes) {
+          *self = State::Transformed {
+            tlang,
+            tfield: true,
+          };
+          TokenKind::TKey
+        } else if tfield && (3..=8).contains(&len) && is_alphanumeric {
+          TokenKind::TValue
+        } else {
+          TokenKind::Invalid
+        }
+      }
+      State::Other => {
+        if (2..=8).contains(&len) && is_alphanumeric {
+          TokenKind::OtherValue
+        } else {
+          TokenKind::Invalid
+        }
+      }
+      State::Pu => {
+        if (1..=8).contains(&len) && is_alphanumeric {
+          TokenKind::PrivateUse
+        } else {
+          TokenKind::Invalid
+        }
+      }
+    }
+  }
+
+  fn singleton(&mut self, singleton: u8) -> TokenKind {
+    let singleton = singleton.to_ascii_lowercase();
+    *self = match singleton {
+      b'u' => State::UnicodeLocale { keyword: false },
+      b't' => State::Transformed {
+        tlang: None,
+        tfield: false,
+      },
+      b'x' => State::Pu,
+      other if other.is_ascii_alphanumeric() => State::Other,
+      _ => return TokenKind::Invalid,
+    };
+    TokenKind::Singleton(char::from(singleton))
+  }
+}
+
+fn language_subtag_kind(subtag: &str, current: u8) -> Option<(TokenKind, u8)> {
+  if current == 1 && script_subtag(subtag).is_ok() {
+    Some((TokenKind::Script, 2))
+  } else if current <= 2 && region_subtag(subtag).is_ok() {
+    Some((TokenKind::Region, 3))
+  } else if variant_subtag(subtag).is_ok() {
+    Some((TokenKind::Variant, 3))
+  } else {
+    None
+  }
+}
+
+/// Tokenize the given string as an Unicode Locale Identifier.
+///
+/// The tokenizer classifies each subtag with its byte range, following [`unicode_locale_id` EBNF defined in UTS #35](https://unicode.org/reports/tr35/#unicode_locale_id).
+/// The separators are not tokens.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::{tokenize, TokenKind};
+///
+/// let mut tokens = tokenize("en-US-u-ca-buddhist");
+/// let token = tokens.next().unwrap();
+/// assert_eq!(TokenKind::Language, token.kind);
+/// assert_eq!(0..2, token.span);
+/// assert_eq!("en", token.text);
+///
+/// let kinds = tokens.map(|t| t.kind).collect::<Vec<_>>();
+/// assert_eq!(
+///   vec![TokenKind::Region, TokenKind::Singleton('u'), TokenKind::UKey, TokenKind::UValue],
+///   kinds
+/// );
+/// ```
+pub fn tokenize(source: &str) -> Tokenizer<'_> {
+  Tokenizer {
+    source,
+    pos: 0,
+    done: source.is_empty(),
+    state: State::Start,
+  }
+}
+
+/// Check whether the given string is a well-formed Unicode Locale Identifier, without allocation.
+///
+/// Every subtag must be valid at its position, as [`tokenize`] classifies, and every extension and tkey must have a subtag following it.
+/// The result is the same as whether [`parse_locale_id`](crate::parse_locale_id) succeeds.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::is_well_formed;
+///
+/// assert!(is_well_formed("en-US-u-ca-buddhist"));
+/// assert!(is_well_formed("de_DE"));
+/// assert!(!is_well_formed("en-Lat-US"));
+/// assert!(!is_well_formed("en-t-m0"));
+/// ```
+pub fn is_well_formed(source: &str) -> bool {
+  let mut previous = None;
+  for token in tokenize(source) {
+    match (previous, token.kind) {
+      (_, TokenKind::Invalid) => return false,
+      // an extension followed by another one, or a tkey followed by another one or an extension is empty
+      (Some(TokenKind::Singleton(_)), TokenKind::Singleton(_))
+      | (Some(TokenKind::TKey), TokenKind::Singleton(_) | TokenKind::TKey) => return false,
+      _ => previous = Some(token.kind),
+    }
+  }
+  !matches!(
+    previous,
+    None | Some(TokenKind::Singleton(_) | TokenKind::TKey)
+  )
+}
+
+/// Normalize the casing and the separators of the given Unicode Locale Identifier in place, without allocation.
+///
+/// The separators `_` are replaced with `-`, the script is in title case, the region is in uppercase, and the other subtags are in lowercase,
+/// including the `tlang` of transformed extensions, as the [canonical syntax](https://unicode.org/reports/tr35/#Canonical_Unicode_Locale_Identifiers).
+/// The subtags that are not valid at their position are left as they are.
+/// Unlike [`UnicodeLocaleIdentifier::canonicalize_syntax`](crate::UnicodeLocaleIdentifier::canonicalize_syntax), the subtags are not sorted.
+///
+/// # Examples
+///
+/// ```
+/// use unicode_locale_parser::normalize_in_place;
+///
+/// let mut locale = String::from("EN_latn_us_U_CA_Buddhist_T_JA_JPAN");
+/// normalize_in_place(&mut locale);
+/// assert_eq!("en-Latn-US-u-ca-buddhist-t-ja-jpan", locale);
+/// ```
+pub fn normalize_in_place(source: &mut String) {
+  let mut state = State::Start;
+  let mut extension = false;
+  let mut pos = 0;
+  while pos <= source.len() {
+    let rest = &source.as_bytes()[pos..];
+    let end = pos + rest.iter().position(is_sep).unwrap_or(rest.len());
+    let kind = state.classify(&source[pos..end]);
+    let subtag = &mut source[pos..end];
+    match kind {
+      TokenKind::Invalid => {}
+      TokenKind::Script if !extension => {
+        subtag.make_ascii_lowercase();
+        subtag[..1].make_ascii_uppercase();
+      }
+      TokenKind::Region if !extension => subtag.make_ascii_uppercase(),
+      _ => {
+        extension |= matches!(kind, TokenKind::Singleton(_));
+        subtag.make_ascii_lowercase();
+      }
+    }
+    if source.as_bytes().get(end) == Some(&(LEGACY_SEP as u8)) {
+      // the same length, so that it never reallocates
+      source.replace_range(end..end + 1, SEP.encode_utf8(&mut [0; 4]));
+    }
+    pos = end + 1;
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(dead_code)] // for unit tests
+fn kinds(source: &str) -> Vec<TokenKind> {
+  tokenize(source).map(|t| t.kind).collect()
+}
+
+#[test]
+fn success_tokenize() {
+  use TokenKind::*;
+
+  // language
+  assert_eq!(
+    vec![Language, Script, Region, Variant, Variant],
+    kinds("ja-Latn-JP-macos-1996")
+  );
+  assert_eq!(vec![Language, Variant], kinds("en_1996"));
+
+  // unicode locale extensions
+  assert_eq!(
+    vec![
+      Language,
+      Singleton('u'),
+      Attribute,
+      UKey,
+      UValue,
+      UValue,
+      UKey
+    ],
+    kinds("en-U-attr1-ca-islamic-civil-kb")
+  );
+
+  // transformed extensions
+  assert_eq!(
+    vec![
+      Language,
+      Singleton('t'),
+      Language,
+      Script,
+      Region,
+      TKey,
+      TValue,
+      TKey,
+      TValue
+    ],
+    kinds("ja-t-en-Latn-US-m0-ungegn-h0-hybrid")
+  );
+
+  // other and private use extensions
+  assert_eq!(
+    vec![
+      Language,
+      Singleton('a'),
+      OtherValue,
+      Singleton('x'),
+      PrivateUse,
+      PrivateUse
+    ],
+    kinds("en-a-vue-x-u-123")
+  );
+
+  // spans
+  let tokens = tokenize("de_DE-u-co-phonebk").collect::<Vec<Token>>();
+  assert_eq!(
+    vec![0..2, 3..5, 6..7, 8..10, 11..18],
+    tokens.iter().map(|t| t.span.clone()).collect::<Vec<_>>()
+  );
+  assert_eq!("phonebk", tokens[4].text);
+
+  // empty
+  assert_eq!(None, tokenize("").next());
+}
+
+#[test]
+fn fail_tokenize() {
+  use TokenKind::*;
+
+  // invalid subtags are classified, and do not stop the tokenizer
+  assert_eq!(vec![Invalid, Region], kinds("123-US"));
+  assert_eq!(vec![Language, Invalid, Region], kinds("en-Lat-US"));
+  assert_eq!(vec![Language, Invalid, Script], kinds("en--Latn"));
+  assert_eq!(vec![Language, Invalid], kinds("en-"));
+  assert_eq!(vec![Language, Invalid], kinds("en-!"));
+  assert_eq!(vec![Language, Singleton('t'), Invalid], kinds("en-t-a1bc"));
+  assert_eq!(vec![Language, Invalid], kinds("en-日本"));
+}
+
+#[test]
+fn success_is_well_formed() {
+  assert!(is_well_formed("en"));
+  assert!(is_well_formed("ja-Latn-JP-macos-1996"));
+  assert!(is_well_formed("en_US"));
+  assert!(is_well_formed("en-U-attr1-ca-islamic-civil-kb"));
+  assert!(is_well_formed("ja-t-en-Latn-US-m0-ungegn-h0-hybrid"));
+  assert!(is_well_formed("ja-t-und-m0-ungegn"));
+  assert!(is_well_formed("en-t-h0-hybrid"));
+  assert!(is_well_formed("en-t-yue"));
+  assert!(is_well_formed("en-t-fil-PH"));
+  assert!(is_well_formed("en-a-vue-x-u-123"));
+  assert!(is_well_formed("en-x-u"));
+}
+
+#[test]
+fn fail_is_well_formed() {
+  assert!(!is_well_formed(""));
+  assert!(!is_well_formed("123-US"));
+  assert!(!is_well_formed("en--Latn"));
+  assert!(!is_well_formed("en-"));
+  assert!(!is_well_formed("en-日本"));
+  assert!(!is_well_formed("en-t-a1bc"));
+
+  // empty extensions and tkeys
+  assert!(!is_well_formed("en-u"));
+  assert!(!is_well_formed("en-t"));
+  assert!(!is_well_formed("en-x"));
+  assert!(!is_well_formed("en-a-b"));
+  assert!(!is_well_formed("en-u-t-ja"));
+  assert!(!is_well_formed("en-t-m0"));
+  assert!(!is_well_formed("en-t-m0-h0-hybrid"));
+}
+
+#[test]
+fn success_normalize_in_place() {
+  let normalize = |source: &str| {
+    let mut source = source.to_string();
+    normalize_in_place(&mut source);
+    source
+  };
+
+  assert_eq!("en-Latn-US", normalize("EN_latn_us"));
+  assert_eq!("und-419", normalize("UND-419"));
+  assert_eq!("de-DE-1996-fonipa", normalize("de-de-1996-FONIPA"));
+  assert_eq!(
+    "en-u-attr-ca-gregory-t-ja-jpan-jp-m0-ungegn-a-vue-x-private",
+    normalize("en-U-ATTR-CA-Gregory-T-JA-JPAN-JP-M0-UNGEGN-A-Vue-X-Private")
+  );
+
+  // invalid subtags are left
+  assert_eq!("en-LAT-US", normalize("EN-LAT-us"));
+  assert_eq!("en--Latn", normalize("en__latn"));
+  assert_eq!("en-", normalize("EN_"));
+  assert_eq!("日本-JP", normalize("日本_jp"));
+  assert_eq!("", normalize(""));
+}
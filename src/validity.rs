@@ -0,0 +1,705 @@
+use crate::constants::{LANG_UND, SEP};
+use crate::errors::Production;
+use crate::lang::UnicodeLanguageIdentifier;
+use crate::locale::UnicodeLocaleIdentifier;
+use crate::measure::UnicodeMeasureUnit;
+use crate::options::Casing;
+#[cfg(feature = "embedded-data")]
+use crate::provider::EmbeddedDataProvider;
+use crate::provider::{DataTable, LocaleDataProvider};
+use crate::subdivision::UnicodeSubdivisionIdentifier;
+use crate::subtags::{Key, Region, Script, Subtag, Value};
+
+use std::fmt::{self, Display};
+use std::iter::once;
+
+const SUBDIVISION_UNKNOWN: &str = "zzzz";
+
+/// Enum representing the status of a code in the validity data of CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidityStatus {
+  /// A code in regular use, e.g. `en`, `Latn` or `US`.
+  Regular,
+  /// A special code, e.g. `und`, `mul` or `Zxxx`.
+  Special,
+  /// A region containing other regions, e.g. `001` or `EU`.
+  Macroregion,
+  /// A deprecated code, which is replaced with the canonicalization, e.g. `iw` or `BU`.
+  Deprecated,
+  /// A code reserved for private use, e.g. `qaa`, `Qaaa` or `XA`.
+  PrivateUse,
+  /// A code for an unknown value, e.g. `Zzzz` or `ZZ`.
+  Unknown,
+  /// A code that is not in the validity data.
+  Invalid,
+}
+
+impl ValidityStatus {
+  fn from_id_status(status: &str) -> Self {
+    match status {
+      "regular" => ValidityStatus::Regular,
+      "special" => ValidityStatus::Special,
+      "macroregion" => ValidityStatus::Macroregion,
+      "deprecated" => ValidityStatus::Deprecated,
+      "private_use" => ValidityStatus::PrivateUse,
+      "unknown" => ValidityStatus::Unknown,
+      _ => ValidityStatus::Invalid,
+    }
+  }
+
+  /// Returns whether the code is in the validity data.
+  ///
+  /// The deprecated codes are valid too, as [UTS #35](https://unicode.org/reports/tr35/#Validity_Data) defines.
+  pub fn is_valid(&self) -> bool {
+    *self != ValidityStatus::Invalid
+  }
+}
+
+impl Display for ValidityStatus {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let value = match self {
+      ValidityStatus::Regular => "regular",
+      ValidityStatus::Special => "special",
+      ValidityStatus::Macroregion => "macroregion",
+      ValidityStatus::Deprecated => "deprecated",
+      ValidityStatus::PrivateUse => "private_use",
+      ValidityStatus::Unknown => "unknown",
+      ValidityStatus::Invalid => "invalid",
+    };
+    f.write_str(value)
+  }
+}
+
+/// The validity of a subtag in a [`ValidityReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtagValidity {
+  /// The kind of the subtag
+  pub production: Production,
+  /// The subtag, in the canonical casing. The values of the keys are joined with their key, e.g. `ca-gregory`.
+  pub subtag: String,
+  pub status: ValidityStatus,
+}
+
+/// The report of the validity of each subtag, made by the `validate` methods.
+///
+/// The attributes, the other extensions and the private use extensions are not reported, since CLDR has no validity data for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidityReport {
+  pub subtags: Vec<SubtagValidity>,
+}
+
+impl ValidityReport {
+  /// Returns whether all the subtags are valid.
+  pub fn is_valid(&self) -> bool {
+    self.subtags.iter().all(|subtag| subtag.status.is_valid())
+  }
+
+  /// Returns the subtags that are not valid.
+  pub fn invalid(&self) -> impl Iterator<Item = &SubtagValidity> {
+    self
+      .subtags
+      .iter()
+      .filter(|subtag| !subtag.status.is_valid())
+  }
+
+  fn push(&mut self, production: Production, subtag: impl Display, status: ValidityStatus) {
+    self.subtags.push(SubtagValidity {
+      production,
+      subtag: subtag.to_string(),
+      status,
+    });
+  }
+}
+
+impl UnicodeLanguageIdentifier {
+  /// Validate each subtag against the validity data of CLDR.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{parse_language_id, ValidityStatus};
+  ///
+  /// let report = parse_language_id("qq-Zzzz-XQ").unwrap().validate();
+  /// assert!(!report.is_valid());
+  /// assert_eq!(
+  ///   vec![ValidityStatus::Invalid, ValidityStatus::Unknown, ValidityStatus::PrivateUse],
+  ///   report.subtags.iter().map(|s| s.status).collect::<Vec<_>>()
+  /// );
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn validate(&self) -> ValidityReport {
+    self.validate_with(&EmbeddedDataProvider)
+  }
+
+  /// Validate each subtag against the validity data of the given provider, as [`UnicodeLanguageIdentifier::validate`].
+  pub fn validate_with(&self, provider: &dyn LocaleDataProvider) -> ValidityReport {
+    let mut report = ValidityReport::default();
+    self.validate_into(&mut report, provider);
+    report
+  }
+
+  fn validate_into(&self, report: &mut ValidityReport, provider: &dyn LocaleDataProvider) {
+    let language = self.language.with_casing(Casing::Lower);
+    let language = if language.is_empty() {
+      LANG_UND
+    } else {
+      &language
+    };
+    report.push(
+      Production::Language,
+      language,
+      language_status(provider, language),
+    );
+    if let Some(script) = self.script {
+      let script = script.with_casing(Casing::Title);
+      report.push(Production::Script, script, script_status(provider, &script));
+    }
+    if let Some(region) = self.region {
+      let region = region.with_casing(Casing::Upper);
+      report.push(Production::Region, region, region_status(provider, &region));
+    }
+    for variant in self.variants.iter().flatten() {
+      let variant = variant.with_casing(Casing::Lower);
+      report.push(
+        Production::Variant,
+        variant,
+        status(provider, DataTable::Variants, &variant),
+      );
+    }
+  }
+}
+
+impl UnicodeLocaleIdentifier {
+  /// Validate each subtag, ukey, uvalue, tkey and tvalue against the validity data of CLDR.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use unicode_locale_parser::{parse_locale_id, Production, ValidityStatus};
+  ///
+  /// let report = parse_locale_id("en-US-u-ca-gregory").unwrap().validate();
+  /// assert!(report.is_valid());
+  ///
+  /// let report = parse_locale_id("en-US-u-ca-abcde").unwrap().validate();
+  /// let invalid = report.invalid().collect::<Vec<_>>();
+  /// assert_eq!(1, invalid.len());
+  /// assert_eq!(Production::UValue, invalid[0].production);
+  /// assert_eq!("ca-abcde", invalid[0].subtag);
+  /// ```
+  #[cfg(feature = "embedded-data")]
+  pub fn validate(&self) -> ValidityReport {
+    self.validate_with(&EmbeddedDataProvider)
+  }
+
+  /// Validate against the validity data of the given provider, as [`UnicodeLocaleIdentifier::validate`].
+  pub fn validate_with(&self, provider: &dyn LocaleDataProvider) -> ValidityReport {
+    let mut report = ValidityReport::default();
+    self.language.validate_into(&mut report, provider);
+
+    for u in self.extensions.unicode_locale.iter().flatten() {
+      for (key, values) in &u.ufield {
+        validate_ukey(&mut report, provider, key, values);
+      }
+    }
+    for t in self.extensions.transformed.iter().flatten() {
+      if let Some(tlang) = &t.tlang {
+        tlang.validate_into(&mut report, provider);
+      }
+      for (key, values) in &t.tfield {
+        validate_tkey(&mut report, provider, key, values);
+      }
+    }
+    report
+  }
+}
+
+impl UnicodeSubdivisionIdentifier {
+  /// Validate against the subdivision validity data of CLDR.
+  ///
+  /// The subdivision of a whole region, e.g. `uszzzz`, is [`ValidityStatus::Unknown`] if the region is valid.
+  #[cfg(feature = "embedded-data")]
+  pub fn validate(&self) -> ValidityReport {
+    self.validate_with(&EmbeddedDataProvider)
+  }
+
+  /// Validate against the subdivision validity data of the given provider, as [`UnicodeSubdivisionIdentifier::validate`].
+  pub fn validate_with(&self, provider: &dyn LocaleDataProvider) -> ValidityReport {
+    let mut report = ValidityReport::default();
+    let subdivision = self.to_string().to_ascii_lowercase();
+    report.push(
+      Production::Subdivision,
+      &subdivision,
+      subdivision_status(provider, &subdivision),
+    );
+    report
+  }
+}
+
+impl UnicodeMeasureUnit {
+  /// Validate against the unit validity data of CLDR.
+  #[cfg(feature = "embedded-data")]
+  pub fn validate(&self) -> ValidityReport {
+    self.validate_with(&EmbeddedDataProvider)
+  }
+
+  /// Validate against the unit validity data of the given provider, as [`UnicodeMeasureUnit::validate`].
+  pub fn validate_with(&self, provider: &dyn LocaleDataProvider) -> ValidityReport {
+    let mut report = ValidityReport::default();
+    let unit = self.to_string().to_ascii_lowercase();
+    let status = status(provider, DataTable::Units, &unit);
+    report.push(Production::MeasureUnit, &unit, status);
+    report
+  }
+}
+
+pub(crate) fn validate_ukey(
+  report: &mut ValidityReport,
+  provider: &dyn LocaleDataProvider,
+  key: &Key,
+  values: &[Value],
+) {
+  let key = key.with_casing(Casing::Lower);
+  let Some(kind) = provider.lookup(DataTable::UnicodeKeys, &key) else {
+    report.push(Production::UKey, key, ValidityStatus::Invalid);
+    return;
+  };
+  let (kind, key_status) = key_kind(kind);
+  report.push(Production::UKey, key, key_status);
+
+  let values = values
+    .iter()
+    .map(|value| value.with_casing(Casing::Lower))
+    .collect::<Vec<_>>();
+  match kind {
+    "script" | "reorder" | "codepoints" => {
+      for value in &values {
+        let status = match kind {
+          // the reorder codes are the types of the key, e.g. `space`, or the scripts
+          "reorder" => match provider.lookup(DataTable::UnicodeTypes, &keyword(&key, &[*value])) {
+            Some(status) => ValidityStatus::from_id_status(status),
+            None => value_script_status(provider, value),
+          },
+          "codepoints" => codepoint_status(value),
+          _ => value_script_status(provider, value),
+        };
+        report.push(Production::UValue, keyword(&key, &[*value]), status);
+      }
+    }
+    _ => {
+      let status = match (kind, values.as_slice()) {
+        ("type", _) => {
+          // the key without a value has the value `true`
+          let value = [Value::from_str_unchecked("true")];
+          let values = if values.is_empty() {
+            &value
+          } else {
+            &values[..]
+          };
+          type_status(
+            provider,
+            DataTable::UnicodeTypes,
+            DataTable::UnicodeKeywordAliases,
+            &keyword(&key, values),
+          )
+        }
+        ("currency", [value]) => status(provider, DataTable::Currencies, value),
+        ("region", [value]) => subdivision_status(provider, value),
+        // the `sd` key takes only the subdivisions, not the whole regions
+        ("subdivision", [value]) if !value.ends_with(SUBDIVISION_UNKNOWN) => {
+          subdivision_status(provider, value)
+        }
+        _ => ValidityStatus::Invalid,
+      };
+      report.push(Production::UValue, keyword(&key, &values), status);
+    }
+  }
+}
+
+fn validate_tkey(
+  report: &mut ValidityReport,
+  provider: &dyn LocaleDataProvider,
+  key: &Key,
+  values: &[Value],
+) {
+  let key = key.with_casing(Casing::Lower);
+  let Some(kind) = provider.lookup(DataTable::TransformedKeys, &key) else {
+    report.push(Production::TKey, key, ValidityStatus::Invalid);
+    return;
+  };
+  let (kind, key_status) = key_kind(kind);
+  report.push(Production::TKey, key, key_status);
+
+  let values = values
+    .iter()
+    .map(|value| value.with_casing(Casing::Lower))
+    .collect::<Vec<_>>();
+  let keyword = keyword(&key, &values);
+  let status = match kind {
+    "private_use" => ValidityStatus::PrivateUse,
+    _ => type_status(
+      provider,
+      DataTable::TransformedTypes,
+      DataTable::TransformedKeywordAliases,
+      &keyword,
+    ),
+  };
+  report.push(Production::TValue, keyword, status);
+}
+
+/// The kind of the values of the key, and the status of the key, e.g. `codepoints deprecated` of `vt`.
+fn key_kind(kind: &str) -> (&str, ValidityStatus) {
+  match kind.split_once(' ') {
+    Some((kind, status)) => (kind, ValidityStatus::from_id_status(status)),
+    None => (kind, ValidityStatus::Regular),
+  }
+}
+
+fn value_script_status(provider: &dyn LocaleDataProvider, value: &str) -> ValidityStatus {
+  Script::try_from_str(value)
+    .map(|script| script_status(provider, &script.with_casing(Casing::Title)))
+    .unwrap_or(ValidityStatus::Invalid)
+}
+
+/// The code point of 4 to 6 hex digits, e.g. `0020` of `vt-0020`.
+fn codepoint_status(value: &str) -> ValidityStatus {
+  let valid = (4..=6).contains(&value.len())
+    && value.bytes().all(|b| b.is_ascii_hexdigit())
+    && u32::from_str_radix(value, 16).is_ok_and(|codepoint| codepoint <= 0x10FFFF);
+  if valid {
+    ValidityStatus::Regular
+  } else {
+    ValidityStatus::Invalid
+  }
+}
+
+fn keyword(key: &str, values: &[Value]) -> String {
+  once(key)
+    .chain(values.iter().map(|value| value.as_str()))
+    .collect::<Vec<_>>()
+    .join(&SEP.to_string())
+}
+
+fn status(provider: &dyn LocaleDataProvider, table: DataTable, code: &str) -> ValidityStatus {
+  provider
+    .lookup(table, code)
+    .map(ValidityStatus::from_id_status)
+    .unwrap_or(ValidityStatus::Invalid)
+}
+
+/// The status of the type of the key, or `deprecated` for the aliases of the types, e.g. `ms-imperial` of `ms-uksystem`.
+fn type_status(
+  provider: &dyn LocaleDataProvider,
+  types: DataTable,
+  aliases: DataTable,
+  keyword: &str,
+) -> ValidityStatus {
+  match provider.lookup(types, keyword) {
+    Some(status) => ValidityStatus::from_id_status(status),
+    None if provider.lookup(aliases, keyword).is_some() => ValidityStatus::Deprecated,
+    None => ValidityStatus::Invalid,
+  }
+}
+
+fn language_status(provider: &dyn LocaleDataProvider, language: &str) -> ValidityStatus {
+  // qaa..qtz
+  match language.as_bytes() {
+    [b'q', b'a'..=b't', b'a'..=b'z'] => ValidityStatus::PrivateUse,
+    _ => status(provider, DataTable::Languages, language),
+  }
+}
+
+pub(crate) fn script_status(provider: &dyn LocaleDataProvider, script: &str) -> ValidityStatus {
+  match provider.lookup(DataTable::Scripts, script) {
+    Some(status) => ValidityStatus::from_id_status(status),
+    // Qaaa..Qabx
+    None => match script.as_bytes() {
+      [b'Q', b'a', b'a', b'a'..=b'z'] | [b'Q', b'a', b'b', b'a'..=b'x'] => {
+        ValidityStatus::PrivateUse
+      }
+      _ => ValidityStatus::Invalid,
+    },
+  }
+}
+
+pub(crate) fn region_status(provider: &dyn LocaleDataProvider, region: &str) -> ValidityStatus {
+  match provider.lookup(DataTable::Regions, region) {
+    Some(status) => ValidityStatus::from_id_status(status),
+    // AA, QM..QZ and XA..XZ
+    None => match region.as_bytes() {
+      [b'A', b'A'] | [b'Q', b'M'..=b'Z'] | [b'X', b'A'..=b'Z'] => ValidityStatus::PrivateUse,
+      _ => ValidityStatus::Invalid,
+    },
+  }
+}
+
+fn subdivision_status(provider: &dyn LocaleDataProvider, subdivision: &str) -> ValidityStatus {
+  if let Some(status) = provider.lookup(DataTable::Subdivisions, subdivision) {
+    return ValidityStatus::from_id_status(status);
+  }
+  // the whole region, e.g. `uszzzz`
+  match subdivision.strip_suffix(SUBDIVISION_UNKNOWN) {
+    Some(region)
+      if Region::try_from_str(region).is_ok_and(|region| {
+        region_status(provider, &region.with_casing(Casing::Upper)).is_valid()
+      }) =>
+    {
+      ValidityStatus::Unknown
+    }
+    _ => ValidityStatus::Invalid,
+  }
+}
+
+/*
+ * Unit tests
+ */
+
+#[allow(unused_imports)] // for unit tests
+use crate::locale::parse_unicode_locale_id;
+
+#[cfg(feature = "embedded-data")]
+#[allow(dead_code)] // for unit tests
+fn statuses(source: &str) -> Vec<(String, ValidityStatus)> {
+  parse_unicode_locale_id(source)
+    .unwrap()
+    .validate()
+    .subtags
+    .into_iter()
+    .map(|subtag| (subtag.subtag, subtag.status))
+    .collect()
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn success_validate() {
+  use ValidityStatus::*;
+
+  // language
+  assert_eq!(
+    vec![
+      ("sr".to_string(), Regular),
+      ("Latn".to_string(), Regular),
+      ("RS".to_string(), Regular)
+    ],
+    statuses("sr-Latn-RS")
+  );
+  assert_eq!(vec![("und".to_string(), Unknown)], statuses("und"));
+  assert_eq!(vec![("zxx".to_string(), Special)], statuses("zxx"));
+  assert_eq!(
+    vec![("gsw".to_string(), Regular), ("CH".to_string(), Regular)],
+    statuses("gsw-CH")
+  );
+  assert_eq!(
+    vec![("chr".to_string(), Regular), ("Cher".to_string(), Regular)],
+    statuses("chr-Cher")
+  );
+  assert_eq!(
+    vec![
+      ("iw".to_string(), Deprecated),
+      ("BU".to_string(), Deprecated)
+    ],
+    statuses("iw-BU")
+  );
+  assert_eq!(
+    vec![
+      ("qaa".to_string(), PrivateUse),
+      ("Qaaa".to_string(), PrivateUse),
+      ("XC".to_string(), PrivateUse)
+    ],
+    statuses("qaa-Qaaa-XC")
+  );
+  assert_eq!(
+    vec![("en".to_string(), Regular), ("XA".to_string(), Special)],
+    statuses("en-XA")
+  );
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("Zzzz".to_string(), Unknown),
+      ("419".to_string(), Macroregion),
+      ("1996".to_string(), Regular)
+    ],
+    statuses("EN-zzzz-419-1996")
+  );
+
+  // unicode locale extensions
+  assert_eq!(
+    vec![
+      ("de".to_string(), Regular),
+      ("ca".to_string(), Regular),
+      ("ca-islamic-civil".to_string(), Regular),
+      ("kn".to_string(), Regular),
+      ("kn".to_string(), Regular),
+      ("ms".to_string(), Regular),
+      ("ms-imperial".to_string(), Deprecated),
+      ("rg".to_string(), Regular),
+      ("rg-uszzzz".to_string(), Unknown),
+      ("sd".to_string(), Regular),
+      ("sd-gbsct".to_string(), Regular),
+    ],
+    statuses("de-u-ca-islamic-civil-kn-ms-imperial-rg-uszzzz-sd-gbsct")
+  );
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("US".to_string(), Regular),
+      ("rg".to_string(), Regular),
+      ("rg-usca".to_string(), Regular),
+      ("sd".to_string(), Regular),
+      ("sd-usny".to_string(), Regular),
+    ],
+    statuses("en-US-u-rg-usca-sd-usny")
+  );
+  assert_eq!(
+    vec![
+      ("ja".to_string(), Regular),
+      ("cu".to_string(), Regular),
+      ("cu-jpy".to_string(), Regular),
+      ("dx".to_string(), Regular),
+      ("dx-thai".to_string(), Regular),
+    ],
+    statuses("ja-u-cu-JPY-dx-thai")
+  );
+
+  // the special-typed and deprecated keys
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("kr".to_string(), Regular),
+      ("kr-space".to_string(), Regular),
+      ("kr-digit".to_string(), Regular),
+      ("kr-grek".to_string(), Regular),
+      ("kr-zzzz".to_string(), Unknown),
+    ],
+    statuses("en-u-kr-space-digit-grek-zzzz")
+  );
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("vt".to_string(), Deprecated),
+      ("vt-0020".to_string(), Regular),
+      ("vt-10ffff".to_string(), Regular),
+    ],
+    statuses("en-u-vt-0020-10FFFF")
+  );
+  assert_eq!(
+    vec![
+      ("ja".to_string(), Regular),
+      ("kh".to_string(), Deprecated),
+      ("kh".to_string(), Regular),
+    ],
+    statuses("ja-u-kh")
+  );
+
+  // transformed extensions
+  assert_eq!(
+    vec![
+      ("ja".to_string(), Regular),
+      ("en".to_string(), Regular),
+      ("m0".to_string(), Regular),
+      ("m0-ungegn".to_string(), Regular),
+      ("x0".to_string(), Regular),
+      ("x0-foo".to_string(), PrivateUse),
+    ],
+    statuses("ja-t-en-m0-ungegn-x0-foo")
+  );
+  assert_eq!(
+    vec![
+      ("ja".to_string(), Regular),
+      ("m0".to_string(), Regular),
+      ("m0-names".to_string(), Deprecated),
+    ],
+    statuses("ja-t-m0-names")
+  );
+
+  // subdivisions and measure units
+  let subdivision = crate::subdivision::parse_unicode_subdivision_id("GBSCT").unwrap();
+  assert!(subdivision.validate().is_valid());
+  for subdivision in ["usca", "usny", "USNY"] {
+    let subdivision = crate::subdivision::parse_unicode_subdivision_id(subdivision).unwrap();
+    assert_eq!(Regular, subdivision.validate().subtags[0].status);
+  }
+  let subdivision = crate::subdivision::parse_unicode_subdivision_id("cz10a").unwrap();
+  assert_eq!(Deprecated, subdivision.validate().subtags[0].status);
+  let subdivision = crate::subdivision::parse_unicode_subdivision_id("uszzzz").unwrap();
+  assert_eq!(Unknown, subdivision.validate().subtags[0].status);
+  let unit = crate::measure::parse_unicode_measure_unit("length-meter").unwrap();
+  assert!(unit.validate().is_valid());
+}
+
+#[cfg(feature = "embedded-data")]
+#[test]
+fn fail_validate() {
+  use ValidityStatus::*;
+
+  assert_eq!(
+    vec![
+      ("qq".to_string(), Invalid),
+      ("Zzzz".to_string(), Unknown),
+      ("XQ".to_string(), PrivateUse)
+    ],
+    statuses("qq-Zzzz-XQ")
+  );
+  assert_eq!(
+    vec![("en".to_string(), Regular), ("Abcd".to_string(), Invalid)],
+    statuses("en-Abcd")
+  );
+  assert_eq!(
+    vec![("en".to_string(), Regular), ("AB".to_string(), Invalid)],
+    statuses("en-AB")
+  );
+  assert_eq!(
+    vec![("en".to_string(), Regular), ("macos".to_string(), Invalid)],
+    statuses("en-macos")
+  );
+
+  // unknown keys and values
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("ca".to_string(), Regular),
+      ("ca-abcde".to_string(), Invalid),
+      ("sd".to_string(), Regular),
+      ("sd-uszzzz".to_string(), Invalid),
+      ("zz".to_string(), Invalid),
+    ],
+    statuses("en-u-ca-abcde-sd-uszzzz-zz-abc")
+  );
+  assert_eq!(
+    vec![("en".to_string(), Regular), ("a1".to_string(), Invalid)],
+    statuses("en-t-a1-foo")
+  );
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("kr".to_string(), Regular),
+      ("kr-abc".to_string(), Invalid),
+      ("kr-abcd".to_string(), Invalid),
+    ],
+    statuses("en-u-kr-abc-abcd")
+  );
+  assert_eq!(
+    vec![
+      ("en".to_string(), Regular),
+      ("vt".to_string(), Deprecated),
+      ("vt-abc".to_string(), Invalid),
+      ("vt-110000".to_string(), Invalid),
+      ("vt-0020x".to_string(), Invalid),
+    ],
+    statuses("en-u-vt-abc-110000-0020x")
+  );
+
+  let subdivision = crate::subdivision::parse_unicode_subdivision_id("usxxx").unwrap();
+  assert!(!subdivision.validate().is_valid());
+  let unit = crate::measure::parse_unicode_measure_unit("length-foo").unwrap();
+  assert!(!unit.validate().is_valid());
+
+  let report = parse_unicode_locale_id("qq-Zzzz-XQ").unwrap().validate();
+  assert!(!report.is_valid());
+  assert_eq!(
+    vec![Production::Language],
+    report
+      .invalid()
+      .map(|subtag| subtag.production)
+      .collect::<Vec<_>>()
+  );
+}
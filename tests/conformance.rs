@@ -0,0 +1,281 @@
+//! Runs the conformance test data, and reports the results per file and per CLDR version.
+//!
+//! - `tests/corpus/cases`: hand-written cases, which always run.
+//! - `tests/corpus/cldr/<version>`: the files of `common/testData/localeIdentifiers` in a CLDR release, as they are.
+//!   The directory of the CLDR version that `src/data` is generated from must have both `localeCanonicalization.txt` and `likelySubtags.txt`,
+//!   e.g. from `https://raw.githubusercontent.com/unicode-org/cldr/release-43/common/testData/localeIdentifiers/`.
+//!
+//! The cases that do not conform yet are listed in `tests/corpus/cldr/known_failures.txt`.
+//! The test fails when a listed case passes, or is not in the test data any more.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use unicode_locale_parser::{is_well_formed, parse_locale_id};
+
+type Check = fn(&[&str]) -> Result<(), String>;
+
+#[derive(Default)]
+struct Report {
+  passed: usize,
+  known: usize,
+  failures: Vec<String>,
+  fixed: Vec<String>,
+}
+
+fn check_for(file: &str) -> Option<Check> {
+  match file {
+    // CLDR `common/testData/localeIdentifiers`
+    "localeCanonicalization.txt" => Some(check_canonicalization),
+    "likelySubtags.txt" => Some(check_likely_subtags),
+    // hand-written cases
+    "canonicalization.txt" => Some(check_canonicalization),
+    "likely_subtags.txt" => Some(check_likely_subtags),
+    "well_formedness.txt" => Some(check_well_formedness),
+    "validity.txt" => Some(check_validity),
+    _ => None,
+  }
+}
+
+fn normalize(id: &str) -> String {
+  id.replace('_', "-")
+}
+
+fn check_canonicalization(fields: &[&str]) -> Result<(), String> {
+  let [source, expected, ..] = fields else {
+    return Err("missing fields".to_string());
+  };
+  let mut locale = parse_locale_id(source).map_err(|e| e.to_string())?;
+  locale.canonicalize();
+  expect("Canonicalized", expected, &locale)
+}
+
+fn check_likely_subtags(fields: &[&str]) -> Result<(), String> {
+  let [source, add_likely, remove_favor_script, remove_favor_region, ..] = fields else {
+    return Err("missing fields".to_string());
+  };
+  let source_locale = parse_locale_id(source).map_err(|e| e.to_string())?;
+
+  // the failed one is left as it is
+  let expected = if *add_likely == "FAIL" {
+    source
+  } else {
+    add_likely
+  };
+  let mut maximized = source_locale.language.clone();
+  maximized.maximize();
+  expect("AddLikely", expected, &maximized)?;
+
+  // the empty columns are the same as the previous ones
+  let remove_favor_script = Some(*remove_favor_script)
+    .filter(|column| !column.is_empty())
+    .unwrap_or(expected);
+  let remove_favor_region = Some(*remove_favor_region)
+    .filter(|column| !column.is_empty())
+    .unwrap_or(remove_favor_script);
+  let mut minimized = source_locale.language.clone();
+  minimized.minimize_favor_script();
+  expect("RemoveFavorScript", remove_favor_script, &minimized)?;
+  let mut minimized = source_locale.language;
+  minimized.minimize();
+  expect("RemoveFavorRegion", remove_favor_region, &minimized)
+}
+
+fn expect(column: &str, expected: &str, actual: &impl ToString) -> Result<(), String> {
+  let actual = actual.to_string();
+  if actual == normalize(expected) {
+    Ok(())
+  } else {
+    Err(format!("{}: expected {}, but {}", column, expected, actual))
+  }
+}
+
+fn check_well_formedness(fields: &[&str]) -> Result<(), String> {
+  let [source, expected, ..] = fields else {
+    return Err("missing fields".to_string());
+  };
+  let actual = parse_locale_id(source).is_ok();
+  if actual.to_string() != *expected {
+    return Err(format!("expected {}, but {}", expected, actual));
+  }
+  let actual = is_well_formed(source);
+  if actual.to_string() != *expected {
+    return Err(format!(
+      "is_well_formed: expected {}, but {}",
+      expected, actual
+    ));
+  }
+  Ok(())
+}
+
+fn check_validity(fields: &[&str]) -> Result<(), String> {
+  let [source, expected, ..] = fields else {
+    return Err("missing fields".to_string());
+  };
+  let report = parse_locale_id(source)
+    .map_err(|e| e.to_string())?
+    .validate();
+  if report.is_valid().to_string() == *expected {
+    Ok(())
+  } else {
+    let invalid = report
+      .invalid()
+      .map(|subtag| subtag.subtag.as_str())
+      .collect::<Vec<_>>();
+    Err(format!(
+      "expected {}, but {} (invalid: {})",
+      expected,
+      report.is_valid(),
+      invalid.join(", ")
+    ))
+  }
+}
+
+fn data_lines(data: &str) -> impl Iterator<Item = (usize, &str)> {
+  data
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+    .map(|(number, line)| (number + 1, line))
+}
+
+fn run(
+  path: &Path,
+  check: Check,
+  known_failures: &HashSet<String>,
+  seen: &mut HashSet<String>,
+  name: &str,
+) -> Report {
+  let mut report = Report::default();
+  let data = fs::read_to_string(path).unwrap();
+  for (number, line) in data_lines(&data) {
+    let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+    let case = format!("{}: {}", name, fields[0]);
+    let known = known_failures.contains(&case);
+    seen.insert(case);
+    match check(&fields) {
+      Ok(()) if known => report
+        .fixed
+        .push(format!("line {}: {}: passes", number, fields[0])),
+      Ok(()) => report.passed += 1,
+      Err(_) if known => report.known += 1,
+      Err(message) => report
+        .failures
+        .push(format!("line {}: {}: {}", number, fields[0], message)),
+    }
+  }
+  report
+}
+
+/// The CLDR version that the embedded data is generated from, in the headers of `src/data`.
+fn data_version() -> String {
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/data/aliases.rs");
+  let header = fs::read_to_string(path).unwrap();
+  header
+    .lines()
+    .find_map(|line| line.strip_prefix("//! Generated by `unicode-locale-datagen` from CLDR "))
+    .and_then(|version| version.strip_suffix('.'))
+    .expect("the CLDR version in the header of src/data/aliases.rs")
+    .to_string()
+}
+
+/// Run the files in the given directory, and returns the number of the failures.
+fn run_dir(
+  dir: &Path,
+  name: &str,
+  known_failures: &HashSet<String>,
+  seen: &mut HashSet<String>,
+) -> usize {
+  let mut files = fs::read_dir(dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .collect::<Vec<_>>();
+  files.sort();
+
+  let (mut passed, mut failed) = (0, 0);
+  for file in files {
+    let file_name = file.file_name().unwrap().to_string_lossy();
+    let Some(check) = check_for(&file_name) else {
+      println!("{}: {}: skipped", name, file_name);
+      continue;
+    };
+    let report = run(
+      &file,
+      check,
+      known_failures,
+      seen,
+      &format!("{}/{}", name, file_name),
+    );
+    println!(
+      "{}: {}: {} passed, {} failed, {} known failures",
+      name,
+      file_name,
+      report.passed,
+      report.failures.len() + report.fixed.len(),
+      report.known
+    );
+    for failure in report.failures.iter().chain(&report.fixed) {
+      println!("  {}", failure);
+    }
+    passed += report.passed;
+    failed += report.failures.len() + report.fixed.len();
+  }
+  println!("{}: {} passed, {} failed", name, passed, failed);
+  failed
+}
+
+#[test]
+fn conformance() {
+  let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+  let root = corpus.join("cldr");
+  let known_failures = fs::read_to_string(root.join("known_failures.txt")).unwrap();
+  let known_failures = data_lines(&known_failures)
+    .map(|(_, line)| line.to_string())
+    .collect::<HashSet<_>>();
+
+  let mut seen = HashSet::new();
+  let mut failed = run_dir(&corpus.join("cases"), "cases", &known_failures, &mut seen);
+
+  // the test data of the CLDR version of the embedded data is complete
+  let version = root.join(data_version());
+  if version.is_dir() {
+    for file in ["localeCanonicalization.txt", "likelySubtags.txt"] {
+      assert!(
+        version.join(file).is_file(),
+        "{} is missing in {}",
+        file,
+        version.display()
+      );
+    }
+  } else {
+    println!(
+      "CLDR: no test data in {}, which the embedded data is generated from",
+      version.display()
+    );
+  }
+
+  let mut versions = fs::read_dir(root)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| path.is_dir())
+    .collect::<Vec<_>>();
+  versions.sort();
+  for version in versions {
+    let name = format!("CLDR {}", version.file_name().unwrap().to_string_lossy());
+    failed += run_dir(&version, &name, &known_failures, &mut seen);
+  }
+
+  // the known failures not in the test data are out of date
+  let mut stale = known_failures.difference(&seen).collect::<Vec<_>>();
+  stale.sort();
+  for case in &stale {
+    println!("known_failures.txt: {}: not in the test data", case);
+  }
+  failed += stale.len();
+
+  assert_eq!(
+    0, failed,
+    "run with `--nocapture` to see the failures, and update `known_failures.txt` for the fixed ones"
+  );
+}
@@ -0,0 +1,237 @@
+//! Checks that no public parser panics, neither for the checked-in corpus in `tests/corpus`
+//! nor for the generated inputs below, and that the borrowed views agree with the owned parser
+//! and the canonical syntax is stable.
+
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+use unicode_locale_parser::{
+  is_well_formed, normalize_in_place, parse_bcp47_tag, parse_language_id, parse_language_id_strict,
+  parse_language_id_with, parse_language_subtag_registry, parse_locale_id, parse_locale_id_lenient,
+  parse_locale_id_ref, parse_locale_id_strict, parse_locale_id_with, parse_measure_unit,
+  parse_subdivision_id, parse_syntax_tree, tokenize, CasePolicy, GrammarProfile, ParseOptions,
+};
+
+// a record of the IANA Language Subtag Registry, whose lines are mutated
+const REGISTRY: &str = "File-Date: 2023-10-16
+%%
+Type: language
+Subtag: sgn
+Description: Sign languages
+Added: 2005-10-16
+Scope: collection
+%%
+Type: variant
+Subtag: 1996
+Description: German orthography of 1996
+  continued
+Added: 2005-10-16
+Prefix: de
+Deprecated: 2023-10-16
+Preferred-Value: de";
+
+fn parse_all(input: &str) {
+  let _ = parse_locale_id(input);
+  let _ = parse_locale_id_strict(input);
+  // the lenient parser recovers only to well-formed identifiers
+  let (locale, _) = parse_locale_id_lenient(input);
+  let recovered = locale.to_string();
+  assert!(
+    parse_locale_id(&recovered).is_ok(),
+    "{:?} is recovered as {:?}",
+    input,
+    recovered
+  );
+  for profile in [
+    GrammarProfile::Unicode,
+    GrammarProfile::Bcp47,
+    GrammarProfile::Icu,
+  ] {
+    let options = ParseOptions {
+      allow_legacy_separator: profile == GrammarProfile::Icu,
+      case: CasePolicy::Reject,
+      max_length: Some(64),
+      max_subtags: Some(8),
+      profile,
+    };
+    let _ = parse_locale_id_with(input, &options);
+    let _ = parse_language_id_with(input, &options);
+  }
+  let _ = parse_language_id(input);
+  let _ = parse_language_id_strict(input);
+  let _ = parse_subdivision_id(input);
+  let _ = parse_measure_unit(input);
+  if let Ok(tag) = parse_bcp47_tag(input) {
+    let _ = tag.to_unicode_locale_id();
+  }
+  if let Ok(tree) = parse_syntax_tree(input) {
+    for token in tree.root().tokens() {
+      let _ = tree.text(token);
+    }
+    let _ = tree.to_string();
+  }
+  let _ = parse_language_subtag_registry(input);
+  for record in registry_records(input) {
+    let _ = parse_language_subtag_registry(&record);
+  }
+  let _ = tokenize(input).count();
+  assert_eq!(
+    is_well_formed(input),
+    parse_locale_id(input).is_ok(),
+    "{:?}",
+    input
+  );
+  let mut normalized = input.to_string();
+  normalize_in_place(&mut normalized);
+  assert_eq!(is_well_formed(input), is_well_formed(&normalized));
+  let _ = parse_locale_id_ref(input);
+  #[cfg(feature = "embedded-data")]
+  if let Ok(mut locale) = parse_locale_id(input) {
+    let _ = locale.validate();
+    let _ = locale.lint();
+    locale.canonicalize();
+    locale.maximize();
+    locale.minimize();
+  }
+}
+
+/// The registry with the input in place of each field value, and with the input as an extra line.
+fn registry_records(input: &str) -> Vec<String> {
+  let lines: Vec<&str> = REGISTRY.lines().collect();
+  let mut records = vec![];
+  for (i, line) in lines.iter().enumerate() {
+    let mut mutated = lines.clone();
+    let replaced = match line.split_once(": ") {
+      Some((name, _)) => format!("{}: {}", name, input),
+      None => input.to_string(),
+    };
+    mutated[i] = &replaced;
+    records.push(mutated.join("\n"));
+  }
+  records.push(format!("{}\n{}", REGISTRY, input));
+  records
+}
+
+fn assert_no_panic(input: &str) {
+  let owned = input.to_string();
+  if panic::catch_unwind(move || parse_all(&owned)).is_err() {
+    panic!("parser panicked on {:?}", input);
+  }
+}
+
+fn unescape(line: &str) -> String {
+  if line == "\\e" {
+    return String::new();
+  }
+  line.replace("\\s", " ").replace("\\t", "\t")
+}
+
+#[test]
+fn corpus() {
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus/panic_free.txt");
+  let corpus = fs::read_to_string(path).unwrap();
+  for line in corpus.lines() {
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    assert_no_panic(&unescape(line));
+    assert_borrowed_agrees(&unescape(line));
+  }
+}
+
+#[test]
+fn exhaustive_short_inputs() {
+  // every string up to 4 characters over an alphabet covering all the character classes
+  let alphabet = ['a', 'Z', '1', '-', '_', 'u', 't', 'x', '@', 'é'];
+  let mut inputs = vec![String::new()];
+  for _ in 0..4 {
+    let mut next = vec![];
+    for input in &inputs {
+      for c in alphabet {
+        let mut s = input.clone();
+        s.push(c);
+        next.push(s);
+      }
+    }
+    for input in &next {
+      assert_no_panic(input);
+      assert_borrowed_agrees(input);
+    }
+    inputs = next;
+  }
+}
+
+#[test]
+fn generated_subtag_sequences() {
+  // deterministic xorshift, so that failures are reproducible
+  let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+  let mut next = move || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+  };
+
+  let subtags = [
+    "",
+    "en",
+    "und",
+    "root",
+    "Latn",
+    "US",
+    "419",
+    "1996",
+    "macos",
+    "u",
+    "U",
+    "t",
+    "x",
+    "a",
+    "1",
+    "ca",
+    "k1",
+    "h0",
+    "buddhist",
+    "hybrid",
+    "attr1",
+    "toolongsubtag",
+    "!",
+    "é",
+    "us",
+    "sct",
+    "-",
+  ];
+  let seps = ["-", "_"];
+  for _ in 0..20_000 {
+    let len = (next() % 10) as usize;
+    let mut input = String::new();
+    for i in 0..len {
+      if i > 0 {
+        input.push_str(seps[(next() % 2) as usize]);
+      }
+      input.push_str(subtags[(next() % subtags.len() as u64) as usize]);
+    }
+    assert_no_panic(&input);
+    assert_borrowed_agrees(&input);
+  }
+}
+
+fn assert_borrowed_agrees(input: &str) {
+  // the borrowed view accepts the same identifiers, with the same `Display`
+  let owned = parse_locale_id(input).map(|locale| locale.to_string());
+  let borrowed = parse_locale_id_ref(input).map(|locale| locale.to_string());
+  assert_eq!(owned, borrowed, "{:?}", input);
+
+  // the canonical syntax is stable when it is parsed again
+  if let Ok(locale) = parse_locale_id(input) {
+    let canonical = locale.canonical_display().to_string();
+    let reparsed = parse_locale_id(&canonical).unwrap();
+    assert_eq!(
+      canonical,
+      reparsed.canonical_display().to_string(),
+      "{:?}",
+      input
+    );
+  }
+}